@@ -0,0 +1,120 @@
+use std::{
+    sync::mpsc,
+    thread::{self, yield_now},
+};
+
+use benches::Group;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const ITEMS: u64 = 100_000;
+const CAPACITY: usize = 1024;
+
+/// Part 45: passing numbers from one thread to another, through the ring buffer or a channel
+fn spsc(c: &mut Criterion) {
+    let mut group = Group::new(c, "Single producer, single consumer");
+    group.bench("Ring buffer", || {
+        let (mut producer, mut consumer) = part_45::ring_buffer(CAPACITY);
+        thread::scope(|s| {
+            s.spawn(move || {
+                for mut item in 0..ITEMS {
+                    while let Err(rejected) = producer.push(item) {
+                        item = rejected;
+                        yield_now();
+                    }
+                }
+            });
+            let mut sum = 0;
+            for _ in 0..ITEMS {
+                sum += loop {
+                    match consumer.pop() {
+                        Some(item) => break item,
+                        None => yield_now(),
+                    }
+                };
+            }
+            sum
+        })
+    });
+    group.bench("std::sync::mpsc", || {
+        let (sender, receiver) = mpsc::sync_channel(CAPACITY);
+        thread::scope(|s| {
+            s.spawn(move || (0..ITEMS).for_each(|item| sender.send(item).unwrap()));
+            receiver.iter().sum::<u64>()
+        })
+    });
+    group.bench("crossbeam-channel", || {
+        let (sender, receiver) = crossbeam_channel::bounded(CAPACITY);
+        thread::scope(|s| {
+            s.spawn(move || (0..ITEMS).for_each(|item| sender.send(item).unwrap()));
+            receiver.iter().sum::<u64>()
+        })
+    });
+}
+
+/// Part 67: the same pipeline with threads and channels, rayon and tokio
+fn pipelines(c: &mut Criterion) {
+    let lines = part_67::random_lines(20_000, 67);
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let mut group = Group::new(c, "Pipelines");
+    group.bench("Serial", || part_67::serial(lines.clone()));
+    group.bench("Threads and channels", || {
+        part_67::threads_and_channels(lines.clone(), 4)
+    });
+    group.bench("Rayon iterators", || {
+        part_67::rayon_iterators(lines.clone())
+    });
+    group.bench("Tokio tasks", || {
+        runtime.block_on(part_67::tokio_tasks(lines.clone(), 4))
+    });
+}
+
+/// Part 80: sending tiny messages one at a time, or in batches
+fn batching(c: &mut Criterion) {
+    let mut group = Group::new(c, "Batching");
+    group.bench("One at a time", || part_80::per_item(4, ITEMS));
+    group.bench("Batches", || {
+        part_80::batched(4, ITEMS, part_80::BATCH_SIZE)
+    });
+}
+
+/// Part 81: sending copies of chunks to the workers, or sharing the data
+fn payloads(c: &mut Criterion) {
+    let text = part_81::random_text(16 * 1024 * 1024, 81);
+    let shared: std::sync::Arc<[u8]> = text.clone().into();
+    let mut group = Group::new(c, "Payloads");
+    group.bench("Copied chunks", || {
+        part_81::copied_scan(&text, 4, part_81::CHUNK_SIZE)
+    });
+    group.bench("Shared data", || {
+        part_81::shared_scan(shared.clone(), 4, part_81::CHUNK_SIZE)
+    });
+}
+
+/// Part 93: several producers and consumers sharing a bounded channel, for all three implementations
+fn implementations(c: &mut Criterion) {
+    use part_93::{Channel, Crossbeam, Flume, Scenario, Std};
+
+    let scenario = Scenario {
+        producers: 4,
+        consumers: 4,
+        capacity: Some(100),
+    };
+    let mut group = Group::new(c, "Channel implementations");
+    group.bench(Std::NAME, || part_93::throughput::<Std>(scenario, ITEMS));
+    group.bench(Crossbeam::NAME, || {
+        part_93::throughput::<Crossbeam>(scenario, ITEMS)
+    });
+    group.bench(Flume::NAME, || {
+        part_93::throughput::<Flume>(scenario, ITEMS)
+    });
+}
+
+criterion_group!(
+    benches,
+    spsc,
+    pipelines,
+    batching,
+    payloads,
+    implementations
+);
+criterion_main!(benches);
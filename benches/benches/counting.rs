@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use benches::Group;
+use criterion::{criterion_group, criterion_main, Criterion};
+use fixtures::Dataset;
+
+const THREADS: usize = 4;
+
+/// Part 9: a shared counter behind a mutex, or an atomic
+fn shared_counters(c: &mut Criterion) {
+    let mut group = Group::new(c, "Shared counters");
+    group.bench("Mutex", || part_9::mutex_count(THREADS, 10_000));
+    group.bench("Atomic", || part_9::atomic_count(THREADS, 10_000));
+}
+
+/// Part 19: summing into a shared total, or into thread locals
+fn sums(c: &mut Criterion) {
+    let numbers = Arc::new(fixtures::numbers(Dataset::Medium));
+    let mut group = Group::new(c, "Sums");
+    group.bench("Mutex", || part_19::mutex_sum(numbers.clone(), THREADS));
+    group.bench("Thread local", || {
+        part_19::thread_local_sum(numbers.clone(), THREADS)
+    });
+}
+
+/// Part 34: counting words
+fn word_counts(c: &mut Criterion) {
+    let text = fixtures::text::corpus(Dataset::Large);
+    let mut group = Group::new(c, "Word counts");
+    group.bench("Serial", || part_34::count_words(&text));
+    group.bench("Threaded", || part_34::threaded_count(&text, THREADS));
+    group.bench("Rayon", || part_34::rayon_count(&text));
+}
+
+/// Part 38: counters sharing a cache line, or not
+fn false_sharing(c: &mut Criterion) {
+    let mut group = Group::new(c, "False sharing");
+    group.bench("Adjacent", || part_38::adjacent(THREADS, 100_000));
+    group.bench("Padded", || part_38::padded(THREADS, 100_000));
+    group.bench("Per thread", || part_38::per_thread(THREADS, 100_000));
+}
+
+/// Part 78: retrying a hot atomic right away, or after backing off
+fn contention(c: &mut Criterion) {
+    let mut group = Group::new(c, "Contention");
+    group.bench("Spin", || {
+        part_78::fixed_updates(THREADS, 10_000, part_78::Strategy::Spin)
+    });
+    group.bench("Backoff", || {
+        part_78::fixed_updates(THREADS, 10_000, part_78::Strategy::Backoff)
+    });
+}
+
+/// Part 79: one hot atomic, or a shard for every thread
+fn sharded_counters(c: &mut Criterion) {
+    let mut group = Group::new(c, "Sharded counters");
+    group.bench("One atomic", || {
+        part_79::count(&part_79::AtomicCounter::default(), THREADS, 100_000)
+    });
+    group.bench("Sharded", || {
+        part_79::count(&part_79::ShardedCounter::new(THREADS), THREADS, 100_000)
+    });
+}
+
+/// Part 90: a small value read far more often than it's written
+fn read_mostly(c: &mut Criterion) {
+    use part_90::Position;
+
+    let mut group = Group::new(c, "Read-mostly data");
+    group.bench("SeqLock", || {
+        part_90::read_mostly(&part_90::SeqLock::new(Position::default()), 3, 10_000, 100)
+    });
+    group.bench("RwLock", || {
+        part_90::read_mostly(&std::sync::RwLock::new(Position::default()), 3, 10_000, 100)
+    });
+}
+
+/// Part 107: entries pushed to a vector behind a mutex, or sent to the thread which owns them
+fn aggregators(c: &mut Criterion) {
+    let mut group = Group::new(c, "Aggregators");
+    group.bench("Arc<Mutex<Vec<_>>>", || {
+        part_107::run(part_107::SharedAggregator::default(), THREADS * 2, 10_000)
+    });
+    group.bench("Owner thread", || {
+        part_107::run(part_107::OwnedAggregator::new(), THREADS * 2, 10_000)
+    });
+}
+
+criterion_group!(
+    benches,
+    shared_counters,
+    sums,
+    word_counts,
+    false_sharing,
+    contention,
+    sharded_counters,
+    read_mostly,
+    aggregators
+);
+criterion_main!(benches);
@@ -0,0 +1,25 @@
+use benches::Group;
+use criterion::{criterion_group, criterion_main, Criterion};
+use part_73::Gpu;
+
+const LEN: usize = 1 << 20;
+
+/// Part 73: the CPU against the GPU, with little and more work per element
+fn multiplication(c: &mut Criterion) {
+    let (a, b) = (
+        part_73::random_vector(LEN, 1),
+        part_73::random_vector(LEN, 2),
+    );
+    let gpu = Gpu::new().map_err(|error| println!("Skipping the GPU: {error:?}"));
+    for rounds in [1, 256] {
+        let mut group = Group::new(c, &format!("Vector multiplication, {rounds} rounds"));
+        group.bench("Serial", || part_73::serial_multiply(&a, &b, rounds));
+        group.bench("Rayon", || part_73::rayon_multiply(&a, &b, rounds));
+        if let Ok(gpu) = &gpu {
+            group.bench("GPU", || gpu.multiply(&a, &b, rounds).unwrap());
+        }
+    }
+}
+
+criterion_group!(benches, multiplication);
+criterion_main!(benches);
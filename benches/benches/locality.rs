@@ -0,0 +1,21 @@
+use benches::Group;
+use common::affinity;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const LEN: usize = 1 << 24;
+
+/// Part 5's advanced exercise: numbers made by the main thread, or by the threads which read them
+fn first_touch(c: &mut Criterion) {
+    // On a single node, with one kind of core, all of the memory is as close to every core
+    if affinity::numa_nodes() < 2 && affinity::core_kinds() < 2 {
+        println!("Skipping Locality, since every core here is as close to all of the memory");
+        return;
+    }
+    let threads = affinity::cores().map_or(1, |cores| cores.len());
+    let mut group = Group::new(c, "Locality");
+    group.bench("Main touch", || part_5::main_touch_sum(LEN, threads));
+    group.bench("First touch", || part_5::first_touch_sum(LEN, threads));
+}
+
+criterion_group!(benches, first_touch);
+criterion_main!(benches);
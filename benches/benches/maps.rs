@@ -0,0 +1,54 @@
+use std::thread;
+
+use benches::Group;
+use criterion::{criterion_group, criterion_main, Criterion};
+use part_54::{ConcurrentMap, LockedMap, ShardedMap};
+
+const THREADS: usize = 4;
+const OPERATIONS: usize = 10_000;
+
+/// Lets `THREADS` threads insert and get random keys, with one insert for every three gets
+fn mixed_operations(map: &impl ConcurrentMap<u64, u64>, keys: &[u64]) {
+    thread::scope(|s| {
+        for chunk in keys.chunks(keys.len() / THREADS) {
+            s.spawn(move || {
+                for (i, &key) in chunk.iter().enumerate() {
+                    if i % 4 == 0 {
+                        map.insert(key, key);
+                    } else {
+                        map.get(&key);
+                    }
+                }
+            });
+        }
+    });
+}
+
+/// Part 54: one lock for the whole map, or one per shard
+fn maps(c: &mut Criterion) {
+    let keys = common::datagen::numbers_below(THREADS * OPERATIONS, 1000, 54);
+    let mut group = Group::new(c, "Concurrent maps");
+    group.bench("One lock", || mixed_operations(&LockedMap::new(), &keys));
+    for shards in [4, 16, 64] {
+        group.bench(&format!("{shards} shards"), || {
+            mixed_operations(&ShardedMap::with_shards(shards), &keys)
+        });
+    }
+}
+
+/// Part 85: an LRU cache behind one lock, or one per shard
+fn lru_caches(c: &mut Criterion) {
+    let keys = common::datagen::numbers_below(THREADS * OPERATIONS, 2000, 85);
+    let mut group = Group::new(c, "LRU caches");
+    group.bench("One lock", || {
+        part_85::mixed_load(&part_85::MutexLru::new(1000), &keys, THREADS)
+    });
+    for shards in [4, 16, 64] {
+        group.bench(&format!("{shards} shards"), || {
+            part_85::mixed_load(&part_85::ShardedLru::new(1000, shards), &keys, THREADS)
+        });
+    }
+}
+
+criterion_group!(benches, maps, lru_caches);
+criterion_main!(benches);
@@ -0,0 +1,49 @@
+use benches::Group;
+use criterion::{criterion_group, criterion_main, Criterion};
+use fixtures::Dataset;
+use part_51::Matrix;
+
+const THREADS: usize = 4;
+
+/// Part 50: prefix sums
+fn scans(c: &mut Criterion) {
+    let numbers = fixtures::numbers(Dataset::Large);
+    let mut group = Group::new(c, "Prefix sums");
+    group.bench("Serial", || part_50::serial_scan(&numbers));
+    group.bench("Parallel", || {
+        part_50::parallel_scan(&numbers, numbers.len() / THREADS)
+    });
+}
+
+/// Part 51: matrix multiplication
+fn matrices(c: &mut Criterion) {
+    let a = Matrix::random(128, 128, 1);
+    let b = Matrix::random(128, 128, 2);
+    let mut group = Group::new(c, "Matrix multiplication");
+    group.bench("Naive", || a.multiply(&b));
+    group.bench("Threaded", || a.multiply_threaded(&b, THREADS));
+    group.bench("Rayon", || a.multiply_rayon(&b));
+    group.bench("Tiled", || a.multiply_tiled(&b, 32));
+}
+
+/// Part 52: estimating π
+fn pi(c: &mut Criterion) {
+    let samples = 1_000_000;
+    let mut group = Group::new(c, "Estimating pi");
+    group.bench("Serial", || part_52::serial_hits(samples, 52));
+    group.bench("Shared atomic", || {
+        part_52::atomic_hits(samples, THREADS, 52)
+    });
+    group.bench("Local counts", || part_52::local_hits(samples, THREADS, 52));
+}
+
+/// Part 53: rendering the Mandelbrot set
+fn mandelbrot(c: &mut Criterion) {
+    let mut group = Group::new(c, "Mandelbrot");
+    group.bench("Serial", || part_53::render(320, 240));
+    group.bench("Threaded", || part_53::render_threaded(320, 240, THREADS));
+    group.bench("Rayon", || part_53::render_rayon(320, 240));
+}
+
+criterion_group!(benches, scans, matrices, pi, mandelbrot);
+criterion_main!(benches);
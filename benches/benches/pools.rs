@@ -0,0 +1,66 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    thread,
+};
+
+use benches::Group;
+use common::bench::black_box;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const WORKERS: usize = 4;
+const JOBS: u64 = 1000;
+
+/// A job which takes longer for bigger numbers
+fn job(n: u64) -> u64 {
+    (0..n * 100).fold(0, |hash, i| {
+        black_box(hash.wrapping_mul(31).wrapping_add(i))
+    })
+}
+
+/// Part 16: a pool of threads, or a thread for every job
+fn thread_pool(c: &mut Criterion) {
+    let mut group = Group::new(c, "Thread pool");
+    group.bench("Thread per job", || {
+        let total = AtomicU64::new(0);
+        thread::scope(|s| {
+            for n in 0..JOBS {
+                let total = &total;
+                s.spawn(move || total.fetch_add(job(n), Ordering::Relaxed));
+            }
+        });
+        total.into_inner()
+    });
+    group.bench("Pool", || {
+        let total = Arc::new(AtomicU64::new(0));
+        let pool = part_16::ThreadPool::new(WORKERS);
+        for n in 0..JOBS {
+            let total = total.clone();
+            pool.execute(move || {
+                total.fetch_add(job(n), Ordering::Relaxed);
+            });
+        }
+        // Waits for every job to finish
+        drop(pool);
+        total.load(Ordering::Relaxed)
+    });
+}
+
+/// Part 17: dealing out jobs up front, or stealing them
+fn scheduling(c: &mut Criterion) {
+    // The jobs get bigger and bigger, so dealing them out evenly isn't fair
+    let jobs: Vec<u64> = (0..JOBS).collect();
+    let mut group = Group::new(c, "Scheduling");
+    group.bench("Static", || part_17::run_static(jobs.clone(), WORKERS, job));
+    group.bench("Stealing", || {
+        part_17::run_stealing(jobs.clone(), WORKERS, job)
+    });
+    group.bench("Crossbeam", || {
+        part_17::run_crossbeam(jobs.clone(), WORKERS, job)
+    });
+}
+
+criterion_group!(benches, thread_pool, scheduling);
+criterion_main!(benches);
@@ -0,0 +1,26 @@
+use benches::Group;
+use criterion::{criterion_group, criterion_main, Criterion};
+use fixtures::Dataset;
+
+const CUTOFF: usize = 10_000;
+
+/// Parts 48 and 49: merge sort and quicksort, serial and parallel
+fn sorts(c: &mut Criterion) {
+    let numbers = fixtures::numbers(Dataset::Medium);
+    let mut group = Group::new(c, "Sorts");
+    group.bench("sort_unstable", || numbers.clone().sort_unstable());
+    group.bench("Merge sort", || part_48::merge_sort(&mut numbers.clone()));
+    group.bench("Threaded merge sort", || {
+        part_48::threaded_merge_sort(&mut numbers.clone(), CUTOFF)
+    });
+    group.bench("Rayon merge sort", || {
+        part_48::rayon_merge_sort(&mut numbers.clone(), CUTOFF)
+    });
+    group.bench("Quicksort", || part_49::quicksort(&mut numbers.clone()));
+    group.bench("Parallel quicksort", || {
+        part_49::parallel_quicksort(&mut numbers.clone(), CUTOFF)
+    });
+}
+
+criterion_group!(benches, sorts);
+criterion_main!(benches);
@@ -0,0 +1,42 @@
+//! Helpers for the criterion benchmarks in `benches/`, which compare the implementations from the parts.
+//!
+//! Exercises which haven't been implemented yet panic with `todo!()`, so every benchmark is tried once first,
+//! and skipped if it hits one. Any other panic stops the benchmarks. What a benchmark returns goes through `common::bench::consume`, like in the parts'
+//! own benchmarks, so that the work which made it can't be optimized away.
+
+use common::bench;
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion};
+
+/// Whether `f` has been implemented, see `common::implemented`
+pub fn runs<U>(f: impl FnOnce() -> U) -> bool {
+    common::implemented(f).is_some()
+}
+
+/// A group of benchmarks which are compared with each other in the report
+pub struct Group<'a> {
+    name: String,
+    group: BenchmarkGroup<'a, WallTime>,
+}
+
+impl<'a> Group<'a> {
+    pub fn new(c: &'a mut Criterion, name: &str) -> Self {
+        Self {
+            name: name.into(),
+            group: c.benchmark_group(name),
+        }
+    }
+
+    /// Benchmarks `f`, unless it hasn't been implemented yet
+    pub fn bench<U>(&mut self, name: &str, mut f: impl FnMut() -> U) -> &mut Self {
+        if runs(&mut f) {
+            self.group
+                .bench_function(name, |b| b.iter(|| bench::consume(f())));
+        } else {
+            println!(
+                "Skipping {}/{name}, since it hasn't been implemented yet",
+                self.name
+            );
+        }
+        self
+    }
+}
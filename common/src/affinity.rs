@@ -0,0 +1,168 @@
+//! Pinning the current thread to a core, and what the cores and memory look like.
+//!
+//! Only supported on Linux. The cores are numbered the way the operating system numbers them,
+//! and a thread may only be pinned to cores the process is allowed to run on, which can be fewer than the machine has.
+
+/// Why a thread couldn't be pinned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AffinityError {
+    /// This platform doesn't support pinning threads
+    Unsupported,
+    /// The core doesn't exist, or this process isn't allowed to run on it
+    NoSuchCore(usize),
+    /// Something else went wrong, with the OS error code
+    Failed(i32),
+}
+
+/// The cores the current thread is allowed to run on, in order
+pub fn cores() -> Result<Vec<usize>, AffinityError> {
+    imp::cores()
+}
+
+/// Makes the current thread only run on `core`
+pub fn pin_to_core(core: usize) -> Result<(), AffinityError> {
+    if !cores()?.contains(&core) {
+        return Err(AffinityError::NoSuchCore(core));
+    }
+    imp::pin_to_core(core)
+}
+
+/// The core the current thread is running on right now.
+/// Unless the thread is pinned, it may have moved to another one by the time this returns.
+pub fn current_core() -> Result<usize, AffinityError> {
+    imp::current_core()
+}
+
+/// Pins the current thread, worker `worker` of `workers`, to a core of its own, spread out evenly
+/// over the cores it may run on, so that the workers end up on every socket of a machine with
+/// several, rather than all on the first one. Gives the core it was pinned to.
+///
+/// ```
+/// use common::affinity;
+///
+/// if let Ok(core) = affinity::pin_spread(1, 4) {
+///     assert_eq!(affinity::cores(), Ok(vec![core]));
+/// }
+/// ```
+pub fn pin_spread(worker: usize, workers: usize) -> Result<usize, AffinityError> {
+    let cores = cores()?;
+    if cores.is_empty() {
+        return Err(AffinityError::Unsupported);
+    }
+    // With more workers than cores, some of them share
+    let core = cores[worker * cores.len() / workers.max(1) % cores.len()];
+    imp::pin_to_core(core)?;
+    Ok(core)
+}
+
+/// How many NUMA nodes the machine has, each with its own memory, which is faster to use from its
+/// own cores than from the others. That's usually one per socket, and one on anything else.
+pub fn numa_nodes() -> usize {
+    imp::numa_nodes()
+}
+
+/// How many kinds of cores the current thread may run on, like 2 on a big.LITTLE or hybrid machine
+/// with fast and slow ones, which have caches of their own. 1 when every core is the same, or
+/// when it can't be told.
+pub fn core_kinds() -> usize {
+    imp::core_kinds()
+}
+
+#[cfg(target_os = "linux")]
+mod imp {
+    use super::AffinityError;
+
+    fn last_error() -> AffinityError {
+        AffinityError::Failed(std::io::Error::last_os_error().raw_os_error().unwrap_or(0))
+    }
+
+    pub fn cores() -> Result<Vec<usize>, AffinityError> {
+        // SAFETY: an all zero cpu_set_t is an empty set, and sched_getaffinity only writes within its size
+        unsafe {
+            let mut set: libc::cpu_set_t = std::mem::zeroed();
+            if libc::sched_getaffinity(0, std::mem::size_of_val(&set), &mut set) != 0 {
+                return Err(last_error());
+            }
+            Ok((0..libc::CPU_SETSIZE as usize)
+                .filter(|&core| libc::CPU_ISSET(core, &set))
+                .collect())
+        }
+    }
+
+    pub fn pin_to_core(core: usize) -> Result<(), AffinityError> {
+        // SAFETY: `core` is one of the cores from `cores`, so it fits in the set
+        unsafe {
+            let mut set: libc::cpu_set_t = std::mem::zeroed();
+            libc::CPU_SET(core, &mut set);
+            if libc::sched_setaffinity(0, std::mem::size_of_val(&set), &set) != 0 {
+                return Err(last_error());
+            }
+        }
+        Ok(())
+    }
+
+    pub fn current_core() -> Result<usize, AffinityError> {
+        // SAFETY: sched_getcpu has no preconditions
+        match unsafe { libc::sched_getcpu() } {
+            -1 => Err(last_error()),
+            core => Ok(core as usize),
+        }
+    }
+
+    /// Counts the nodes in a list like `0-1,4`, as in `/sys/devices/system/node/online`
+    fn count(list: &str) -> usize {
+        list.trim()
+            .split(',')
+            .filter_map(|range| match range.split_once('-') {
+                Some((first, last)) => {
+                    Some(last.parse::<usize>().ok()? + 1 - first.parse::<usize>().ok()?)
+                }
+                None => range.parse::<usize>().ok().map(|_| 1),
+            })
+            .sum()
+    }
+
+    pub fn numa_nodes() -> usize {
+        std::fs::read_to_string("/sys/devices/system/node/online")
+            .map_or(1, |nodes| count(&nodes).max(1))
+    }
+
+    pub fn core_kinds() -> usize {
+        // How fast the kernel thinks every core is, which is only there when they differ
+        let capacities: std::collections::BTreeSet<String> = cores()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|core| {
+                std::fs::read_to_string(format!("/sys/devices/system/cpu/cpu{core}/cpu_capacity"))
+                    .ok()
+            })
+            .map(|capacity| capacity.trim().to_string())
+            .collect();
+        capacities.len().max(1)
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod imp {
+    use super::AffinityError;
+
+    pub fn cores() -> Result<Vec<usize>, AffinityError> {
+        Err(AffinityError::Unsupported)
+    }
+
+    pub fn pin_to_core(_core: usize) -> Result<(), AffinityError> {
+        Err(AffinityError::Unsupported)
+    }
+
+    pub fn current_core() -> Result<usize, AffinityError> {
+        Err(AffinityError::Unsupported)
+    }
+
+    pub fn numa_nodes() -> usize {
+        1
+    }
+
+    pub fn core_kinds() -> usize {
+        1
+    }
+}
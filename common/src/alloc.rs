@@ -0,0 +1,104 @@
+//! Counting how much memory a program allocates.
+//!
+//! The counting allocator has to be made the global allocator of the binary, and then counts every allocation in the process:
+//!
+//! ```no_run
+//! use common::alloc::{count_allocations, CountingAllocator};
+//!
+//! #[global_allocator]
+//! static ALLOCATOR: CountingAllocator = CountingAllocator;
+//!
+//! let (_, allocated) = count_allocations(|| vec![0u8; 1000]);
+//! assert!(allocated.bytes >= 1000);
+//! ```
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static FREED: AtomicU64 = AtomicU64::new(0);
+
+/// Lets only one `count_allocations` count at a time
+static COUNTING: Mutex<()> = Mutex::new(());
+
+/// The system allocator, but counting every allocation and how many bytes it asked for.
+/// Growing an allocation counts as a new one of the new size, since it usually means copying.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CountingAllocator;
+
+// SAFETY: every call is passed on to the system allocator unchanged
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record(new_size);
+        FREED.fetch_add(layout.size() as u64, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        FREED.fetch_add(layout.size() as u64, Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+fn record(bytes: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(bytes as u64, Ordering::Relaxed);
+}
+
+/// How many allocations were made, and how many bytes they asked for in total
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Allocations {
+    pub count: u64,
+    pub bytes: u64,
+}
+
+/// Everything allocated since the program started. Always zero unless `CountingAllocator` is the global allocator.
+pub fn allocations() -> Allocations {
+    Allocations {
+        count: ALLOCATIONS.load(Ordering::Relaxed),
+        bytes: BYTES.load(Ordering::Relaxed),
+    }
+}
+
+/// How many bytes are allocated and not freed yet, in the whole program.
+/// Only approximate while other threads allocate and free, and always zero unless `CountingAllocator` is the global allocator.
+pub fn in_use() -> u64 {
+    // Freed first, since whatever is freed in between was allocated before it
+    let freed = FREED.load(Ordering::Relaxed);
+    BYTES.load(Ordering::Relaxed).saturating_sub(freed)
+}
+
+/// Runs `f`, and returns what it returned and everything allocated while it ran.
+/// Allocations on other threads are counted too, including the threads `f` starts, but also unrelated ones.
+/// Calls to this wait for each other, so tests which all count their allocations with it don't count each other's.
+pub fn count_allocations<U>(f: impl FnOnce() -> U) -> (U, Allocations) {
+    let _counting = COUNTING
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let before = allocations();
+    let result = f();
+    let after = allocations();
+    (
+        result,
+        Allocations {
+            count: after.count - before.count,
+            bytes: after.bytes - before.bytes,
+        },
+    )
+}
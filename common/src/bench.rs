@@ -0,0 +1,337 @@
+//! A small harness for comparing how long different implementations of the same thing take.
+//!
+//! ```no_run
+//! use common::bench::{self, Comparison};
+//!
+//! let n = bench::input(1_000_000u64);
+//! let mut comparison = Comparison::new(10);
+//! comparison
+//!     .bench("sum", || (0..n).sum::<u64>())
+//!     .bench("fold", || (0..n).fold(0, |a, b| a + b));
+//! comparison.print();
+//! ```
+//!
+//! Once a benchmark does real work, rather than sleeping, the compiler may well work out its result
+//! in advance, or skip work whose result is never used, and measure nothing. Whatever a benchmark
+//! returns goes through `consume`, so it's always used, and `input` hides where a value came from,
+//! like the `n` above, so that nothing can be worked out from it while compiling. Anything else the
+//! compiler shouldn't see through can go through `black_box`.
+//!
+//! Timings vary from run to run, so a difference between two implementations only means something
+//! if it's bigger than that. `compare` tells whether it is, and `assert_faster` fails a test with
+//! the timings when one isn't faster, rather than just a failed `a < b`. With `WORKSHOP_SOFT_TIMING`
+//! set, a difference within the noise is only warned about, for machines too busy to time anything.
+
+use std::{
+    env, fmt,
+    fs::OpenOptions,
+    io::Write,
+    time::{Duration, Instant},
+};
+
+pub use std::hint::black_box;
+
+/// Set by `workshop bench` to how much data a part's benchmarks should use, see `size`
+pub const SIZE_VARIABLE: &str = "WORKSHOP_BENCH_SIZE";
+/// Set by `workshop bench` to a file which `Comparison::print` adds its measurements to
+pub const RECORD_VARIABLE: &str = "WORKSHOP_BENCH_RECORD";
+/// Set to anything to only warn, instead of failing, when `assert_faster` finds the difference
+/// within the noise
+pub const SOFT_VARIABLE: &str = "WORKSHOP_SOFT_TIMING";
+
+/// How many standard errors apart the means of two measurements must be to count as a difference.
+/// Two is about 95% sure, if the runs are spread normally.
+const NOISE_THRESHOLD: f64 = 2.0;
+
+/// How much data to benchmark with: `default`, unless `workshop bench` asked for something else
+pub fn size(default: usize) -> usize {
+    env::var(SIZE_VARIABLE)
+        .ok()
+        .and_then(|size| size.parse().ok())
+        .unwrap_or(default)
+}
+
+/// Gives back `value`, as something the compiler can't see into, so that a benchmark can't be
+/// worked out while compiling from inputs it knows, like constants
+pub fn input<T>(value: T) -> T {
+    black_box(value)
+}
+
+/// Uses `result` in a way the compiler can't see through, so the work which made it can't be left
+/// out, even when nothing else uses it. The harnesses do this with whatever a benchmark returns.
+pub fn consume<U>(result: U) {
+    drop(black_box(result));
+}
+
+/// How long each run of a benchmark took
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    pub name: String,
+    pub runs: Vec<Duration>,
+}
+
+impl Measurement {
+    /// The middle run, which is less sensitive to the odd slow run than the mean
+    pub fn median(&self) -> Duration {
+        let mut runs = self.runs.clone();
+        runs.sort();
+        runs[runs.len() / 2]
+    }
+
+    pub fn fastest(&self) -> Duration {
+        *self.runs.iter().min().unwrap()
+    }
+
+    pub fn mean(&self) -> Duration {
+        self.runs.iter().sum::<Duration>() / self.runs.len() as u32
+    }
+
+    /// How much the runs vary around the mean, in seconds squared. Nothing, with a single run.
+    pub fn variance(&self) -> f64 {
+        if self.runs.len() < 2 {
+            return 0.0;
+        }
+        let mean = self.mean().as_secs_f64();
+        let squares: f64 = self
+            .runs
+            .iter()
+            .map(|run| (run.as_secs_f64() - mean).powi(2))
+            .sum();
+        squares / (self.runs.len() - 1) as f64
+    }
+
+    /// How far from the mean a run typically is
+    pub fn std_dev(&self) -> Duration {
+        Duration::from_secs_f64(self.variance().sqrt())
+    }
+}
+
+impl fmt::Display for Measurement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} took {:.2?} ± {:.2?} over {} runs",
+            self.name,
+            self.mean(),
+            self.std_dev(),
+            self.runs.len()
+        )
+    }
+}
+
+/// How one measurement compares to another, taking how much their runs vary into account
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Difference {
+    Faster,
+    Slower,
+    /// The difference is so small that it could just as well be noise
+    WithinNoise,
+}
+
+/// Whether `a` is faster or slower than `b`, or too close to tell. That's when their means are less
+/// than `NOISE_THRESHOLD` standard errors of their difference apart, which is how much the
+/// difference would vary if they were measured again.
+///
+/// ```
+/// use std::time::Duration;
+/// use common::bench::{compare, Difference, Measurement};
+///
+/// let measured = |name: &str, runs: [u64; 3]| Measurement {
+///     name: name.into(),
+///     runs: runs.map(Duration::from_millis).to_vec(),
+/// };
+/// let steady = measured("steady", [10, 11, 10]);
+/// assert_eq!(compare(&steady, &measured("slow", [20, 21, 20])), Difference::Faster);
+/// assert_eq!(compare(&steady, &measured("noisy", [5, 25, 12])), Difference::WithinNoise);
+/// ```
+pub fn compare(a: &Measurement, b: &Measurement) -> Difference {
+    let standard_error =
+        (a.variance() / a.runs.len() as f64 + b.variance() / b.runs.len() as f64).sqrt();
+    let difference = a.mean().as_secs_f64() - b.mean().as_secs_f64();
+    if difference.abs() <= NOISE_THRESHOLD * standard_error {
+        Difference::WithinNoise
+    } else if difference < 0.0 {
+        Difference::Faster
+    } else {
+        Difference::Slower
+    }
+}
+
+/// Panics with both timings unless `faster` is faster than `slower`, by more than the noise.
+/// With `WORKSHOP_SOFT_TIMING` set, a difference within the noise only prints a warning.
+pub fn assert_faster(faster: &Measurement, slower: &Measurement) {
+    match compare(faster, slower) {
+        Difference::Faster => {}
+        Difference::Slower => panic!(
+            "{} should be faster than {}, but was slower.\n{faster}\n{slower}",
+            faster.name, slower.name
+        ),
+        Difference::WithinNoise => {
+            let message = format!(
+                "{} should be faster than {}, but the difference is within the noise, which may \
+                 be a busy machine rather than the code.\n{faster}\n{slower}",
+                faster.name, slower.name
+            );
+            if env::var_os(SOFT_VARIABLE).is_some() {
+                eprintln!("Warning: {message}");
+            } else {
+                panic!("{message}\nSet {SOFT_VARIABLE}=1 to only warn about this");
+            }
+        }
+    }
+}
+
+/// Runs `f` once to warm up, and then `runs` times while timing each run. What `f` returns is
+/// consumed, see `consume`.
+pub fn measure<U>(name: &str, runs: usize, mut f: impl FnMut() -> U) -> Measurement {
+    assert!(runs > 0, "Need at least one run to measure");
+    consume(f());
+    let runs = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            consume(f());
+            start.elapsed()
+        })
+        .collect();
+    Measurement {
+        name: name.into(),
+        runs,
+    }
+}
+
+/// How much memory the process is using right now, in bytes.
+/// Only counts memory which is actually in use, not memory which has just been reserved, like untouched parts of thread stacks.
+/// Returns `None` on platforms other than Linux.
+pub fn resident_memory() -> Option<usize> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+    let kilobytes: usize = line
+        .trim_start_matches("VmRSS:")
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
+
+/// Measures several benchmarks the same number of times, and shows them side by side.
+/// Printing it shows a table with each benchmark compared to the fastest one.
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    runs: usize,
+    measurements: Vec<Measurement>,
+}
+
+impl Comparison {
+    pub fn new(runs: usize) -> Self {
+        Self {
+            runs,
+            measurements: Vec::new(),
+        }
+    }
+
+    /// Measures `f` and adds it to the comparison, unless the program was run with a `--strategy`
+    /// which isn't part of `name`, see `cli::runs`
+    pub fn bench<U>(&mut self, name: &str, f: impl FnMut() -> U) -> &mut Self {
+        if crate::cli::runs(name) {
+            self.measurements.push(measure(name, self.runs, f));
+        }
+        self
+    }
+
+    /// Panics with both timings unless the benchmark called `faster` was faster than the one
+    /// called `slower`, see `assert_faster`
+    pub fn assert_faster(&self, faster: &str, slower: &str) {
+        let get = |name| {
+            self.get(name)
+                .unwrap_or_else(|| panic!("{name} hasn't been measured"))
+        };
+        assert_faster(get(faster), get(slower));
+    }
+
+    /// The measurement of the benchmark called `name`, if it has been run
+    pub fn get(&self, name: &str) -> Option<&Measurement> {
+        self.measurements
+            .iter()
+            .find(|measurement| measurement.name == name)
+    }
+
+    pub fn measurements(&self) -> &[Measurement] {
+        &self.measurements
+    }
+
+    /// Prints the table, and records the measurements for `workshop bench` when it's the one running this
+    pub fn print(&self) {
+        println!("{self}");
+        if let Ok(path) = env::var(RECORD_VARIABLE) {
+            if let Err(error) = self.record(&path) {
+                eprintln!("Couldn't record the measurements in {path}: {error}");
+            }
+        }
+    }
+
+    /// Adds a line for every measurement to the file at `path`:
+    /// the median and fastest run in nanoseconds, and the name, separated by tabs
+    fn record(&self, path: &str) -> std::io::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        for measurement in &self.measurements {
+            writeln!(
+                file,
+                "{}\t{}\t{}",
+                measurement.median().as_nanos(),
+                measurement.fastest().as_nanos(),
+                measurement.name
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(best) = self.measurements.iter().min_by_key(|m| m.median()) else {
+            return writeln!(f, "Nothing has been measured");
+        };
+        let fastest = best.median();
+        let width = self
+            .measurements
+            .iter()
+            .map(|measurement| measurement.name.len())
+            .max()
+            .unwrap_or(0)
+            .max("Benchmark".len());
+
+        writeln!(
+            f,
+            "{:width$}  {:>12}  {:>12}  {:>12}  {:>8}",
+            "Benchmark", "Median", "Fastest", "Std. dev.", "Relative"
+        )?;
+        for measurement in &self.measurements {
+            let median = measurement.median();
+            writeln!(
+                f,
+                "{:width$}  {:>12}  {:>12}  {:>12}  {:>7.2}x",
+                measurement.name,
+                format!("{median:.2?}"),
+                format!("{:.2?}", measurement.fastest()),
+                format!("{:.2?}", measurement.std_dev()),
+                median.as_secs_f64() / fastest.as_secs_f64().max(f64::MIN_POSITIVE),
+            )?;
+        }
+
+        // Which of the others can't be told apart from the fastest
+        for measurement in &self.measurements {
+            if !std::ptr::eq(measurement, best)
+                && compare(measurement, best) == Difference::WithinNoise
+            {
+                writeln!(
+                    f,
+                    "Warning: {} and {} are within the noise of each other",
+                    measurement.name, best.name
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,195 @@
+//! Checking how many threads and how much memory code needs while it runs.
+//!
+//! Both are measured for the whole process, by looking at it every millisecond while the code runs,
+//! so threads and memory used by unrelated code at the same time are counted too. Only supported on Linux.
+//!
+//! ```no_run
+//! use common::budget::Budget;
+//!
+//! let budget = Budget {
+//!     threads: 4,
+//!     memory: 64 * 1024 * 1024,
+//! };
+//! let sum = budget.check(|| (0..1000u64).sum::<u64>());
+//! ```
+//!
+//! How much time code may take is checked by `crate::with_budget` instead, which gives up waiting
+//! for it after a while, with a `BudgetExceeded` telling how far it got.
+
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::bench::resident_memory;
+
+/// How often the process is looked at while measuring
+const INTERVAL: Duration = Duration::from_millis(1);
+
+/// Lets only one `measure` measure at a time
+static MEASURING: Mutex<()> = Mutex::new(());
+
+/// The most the process used while measuring, on top of what it used before
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// Extra threads, not counting the one doing the measuring
+    pub threads: usize,
+    /// Extra resident memory, in bytes, as in `bench::resident_memory`
+    pub memory: usize,
+}
+
+/// How many extra threads and bytes of resident memory something may use
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Budget {
+    pub threads: usize,
+    pub memory: usize,
+}
+
+impl Budget {
+    /// Runs `f`, and returns what it returned. Panics if it used more threads or memory than the budget.
+    /// Where that can't be measured, only prints that the budget wasn't checked.
+    pub fn check<U>(&self, f: impl FnOnce() -> U) -> U {
+        let (result, usage) = measure(f);
+        match usage {
+            Some(usage) => {
+                assert!(
+                    usage.threads <= self.threads,
+                    "Used {} extra threads, but the budget is {}",
+                    usage.threads,
+                    self.threads
+                );
+                assert!(
+                    usage.memory <= self.memory,
+                    "Used {} extra bytes of memory, but the budget is {}",
+                    usage.memory,
+                    self.memory
+                );
+            }
+            None => println!(
+                "Can't measure threads and memory on this platform, so the budget wasn't checked"
+            ),
+        }
+        result
+    }
+}
+
+/// Runs `f`, and returns what it returned and the most it used, or `None` if that can't be measured here.
+/// Calls to this wait for each other, so tests which all measure with it don't measure each other.
+pub fn measure<U>(f: impl FnOnce() -> U) -> (U, Option<Usage>) {
+    let _measuring = MEASURING
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let Some(before) = sample() else {
+        return (f(), None);
+    };
+
+    let done = AtomicBool::new(false);
+    thread::scope(|s| {
+        let sampler = s.spawn(|| {
+            let mut peak = before;
+            loop {
+                // Sample before checking, so there's at least one sample even if `f` is done right away
+                if let Some(sample) = sample() {
+                    peak.threads = peak.threads.max(sample.threads);
+                    peak.memory = peak.memory.max(sample.memory);
+                }
+                if done.load(Ordering::Relaxed) {
+                    return peak;
+                }
+                thread::sleep(INTERVAL);
+            }
+        });
+
+        // Stops the sampler even if `f` panics, or the scope would wait for it forever
+        let stop = Stop(&done);
+        let result = f();
+        drop(stop);
+
+        let peak = sampler.join().unwrap();
+        let usage = Usage {
+            threads: peak.threads.saturating_sub(before.threads + 1),
+            memory: peak.memory.saturating_sub(before.memory),
+        };
+        (result, Some(usage))
+    })
+}
+
+/// Counts the items code run by `crate::with_budget` has completed, so that it can tell how far
+/// the code got if it runs out of time. Clones add to the same count.
+#[derive(Debug, Clone, Default)]
+pub struct Progress {
+    completed: Arc<AtomicUsize>,
+}
+
+impl Progress {
+    /// Counts `items` more as completed
+    pub fn complete(&self, items: usize) {
+        self.completed.fetch_add(items, Ordering::Relaxed);
+    }
+
+    /// How many items have been completed so far
+    pub fn completed(&self) -> usize {
+        self.completed.load(Ordering::Relaxed)
+    }
+}
+
+/// What code was up to when it ran out of its time budget
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BudgetExceeded {
+    pub budget: Duration,
+    /// How many items it counted as completed with its `Progress`
+    pub completed: usize,
+    /// How many more threads the process had than before it started, the one running it included,
+    /// or `None` where that can't be counted. Like `Usage`, this counts unrelated threads too.
+    pub threads: Option<usize>,
+}
+
+impl fmt::Display for BudgetExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Ran out of its budget of {:?}, with {} items completed",
+            self.budget, self.completed
+        )?;
+        if let Some(threads) = self.threads {
+            write!(f, " and {threads} threads still running")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for BudgetExceeded {}
+
+struct Stop<'a>(&'a AtomicBool);
+
+impl Drop for Stop<'_> {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+/// How many threads the process has right now. Returns `None` on platforms other than Linux.
+pub fn threads() -> Option<usize> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("Threads:"))?;
+    line.trim_start_matches("Threads:").trim().parse().ok()
+}
+
+/// How many threads the process has and how much of its memory is resident, right now
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    threads: usize,
+    memory: usize,
+}
+
+fn sample() -> Option<Sample> {
+    Some(Sample {
+        threads: threads()?,
+        memory: resident_memory()?,
+    })
+}
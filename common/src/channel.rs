@@ -0,0 +1,240 @@
+//! Channels behind a few traits, so that exercises don't depend on which implementation they run
+//! on: `std::sync::mpsc`, or `crossbeam_channel` with the `crossbeam` feature. Once std's own
+//! multi-consumer channels are stable, they only need another `Backend`, instead of every exercise
+//! being rewritten.
+//!
+//! `unbounded` and `bounded` make a channel with the backend the workshop is built with, which is
+//! crossbeam's when the `crossbeam` feature is enabled, and std's otherwise, with ends of the types
+//! `SelectedSender` and `SelectedReceiver`. Whichever it is, the ends work the same way, through
+//! the `Sender` and `Receiver` traits, and fail with std's errors.
+//!
+//! ```
+//! use std::thread;
+//! use common::channel::{self, Receiver, Sender};
+//!
+//! let (sender, receiver) = channel::unbounded();
+//! thread::spawn(move || (1..=3).for_each(|x| sender.send(x).unwrap()));
+//! let received: Vec<i32> = receiver.iter().collect();
+//! assert_eq!(received, [1, 2, 3]);
+//! ```
+
+use std::{
+    fmt,
+    sync::mpsc::{self, RecvError, RecvTimeoutError, SendError, TryRecvError},
+    time::Duration,
+};
+
+/// The sending end of a channel. Clones send to the same channel, which is closed once every one
+/// of them is dropped.
+pub trait Sender<T>: Clone {
+    /// Sends `message`, waiting for room first if the channel is bounded and full. Fails with the
+    /// message if the receiver is gone.
+    fn send(&self, message: T) -> Result<(), SendError<T>>;
+}
+
+/// The receiving end of a channel
+pub trait Receiver<T> {
+    /// Waits for the next message. Fails once the channel is empty and every sender is gone.
+    fn recv(&self) -> Result<T, RecvError>;
+
+    /// The next message, if there is one right away
+    fn try_recv(&self) -> Result<T, TryRecvError>;
+
+    /// Waits for the next message, for at most `timeout`
+    fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError>;
+
+    /// Every message, waiting for each of them, until the channel is empty and every sender is gone
+    fn iter(&self) -> Iter<'_, T, Self>
+    where
+        Self: Sized,
+    {
+        Iter {
+            receiver: self,
+            message: std::marker::PhantomData,
+        }
+    }
+}
+
+/// The messages of a receiver, see `Receiver::iter`
+#[derive(Debug)]
+pub struct Iter<'a, T, R> {
+    receiver: &'a R,
+    message: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T, R: Receiver<T>> Iterator for Iter<'_, T, R> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.receiver.recv().ok()
+    }
+}
+
+/// A channel implementation. Its ends can be moved to other threads, so the messages have to be
+/// `Send` and `'static`, like anything else which moves to a thread.
+pub trait Backend {
+    /// Its name, to tell which one a test failed on
+    const NAME: &'static str;
+
+    type Sender<T: Send + 'static>: Sender<T> + Send + 'static;
+    type Receiver<T: Send + 'static>: Receiver<T> + Send + 'static;
+
+    /// A channel with room for any number of messages
+    fn unbounded<T: Send + 'static>() -> (Self::Sender<T>, Self::Receiver<T>);
+
+    /// A channel with room for `capacity` messages, after which sending waits for room. With a
+    /// capacity of 0, every send waits for a receiver to take the message.
+    fn bounded<T: Send + 'static>(capacity: usize) -> (Self::Sender<T>, Self::Receiver<T>);
+}
+
+/// `std::sync::mpsc`'s channels
+#[derive(Debug, Clone, Copy)]
+pub struct Std;
+
+/// The sending end of one of std's channels, which are different types for bounded and unbounded
+/// ones
+pub enum StdSender<T> {
+    Unbounded(mpsc::Sender<T>),
+    Bounded(mpsc::SyncSender<T>),
+}
+
+impl<T> Clone for StdSender<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Unbounded(sender) => Self::Unbounded(sender.clone()),
+            Self::Bounded(sender) => Self::Bounded(sender.clone()),
+        }
+    }
+}
+
+impl<T> fmt::Debug for StdSender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unbounded(sender) => sender.fmt(f),
+            Self::Bounded(sender) => sender.fmt(f),
+        }
+    }
+}
+
+impl<T> Sender<T> for StdSender<T> {
+    fn send(&self, message: T) -> Result<(), SendError<T>> {
+        match self {
+            Self::Unbounded(sender) => sender.send(message),
+            Self::Bounded(sender) => sender.send(message),
+        }
+    }
+}
+
+impl<T> Sender<T> for mpsc::Sender<T> {
+    fn send(&self, message: T) -> Result<(), SendError<T>> {
+        mpsc::Sender::send(self, message)
+    }
+}
+
+impl<T> Sender<T> for mpsc::SyncSender<T> {
+    fn send(&self, message: T) -> Result<(), SendError<T>> {
+        mpsc::SyncSender::send(self, message)
+    }
+}
+
+impl<T> Receiver<T> for mpsc::Receiver<T> {
+    fn recv(&self) -> Result<T, RecvError> {
+        mpsc::Receiver::recv(self)
+    }
+
+    fn try_recv(&self) -> Result<T, TryRecvError> {
+        mpsc::Receiver::try_recv(self)
+    }
+
+    fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        mpsc::Receiver::recv_timeout(self, timeout)
+    }
+}
+
+impl Backend for Std {
+    const NAME: &'static str = "std";
+
+    type Sender<T: Send + 'static> = StdSender<T>;
+    type Receiver<T: Send + 'static> = mpsc::Receiver<T>;
+
+    fn unbounded<T: Send + 'static>() -> (StdSender<T>, mpsc::Receiver<T>) {
+        let (sender, receiver) = mpsc::channel();
+        (StdSender::Unbounded(sender), receiver)
+    }
+
+    fn bounded<T: Send + 'static>(capacity: usize) -> (StdSender<T>, mpsc::Receiver<T>) {
+        let (sender, receiver) = mpsc::sync_channel(capacity);
+        (StdSender::Bounded(sender), receiver)
+    }
+}
+
+/// `crossbeam_channel`'s channels, which any number of receivers can receive from
+#[cfg(feature = "crossbeam")]
+#[derive(Debug, Clone, Copy)]
+pub struct Crossbeam;
+
+#[cfg(feature = "crossbeam")]
+impl<T> Sender<T> for crossbeam_channel::Sender<T> {
+    fn send(&self, message: T) -> Result<(), SendError<T>> {
+        crossbeam_channel::Sender::send(self, message).map_err(|error| SendError(error.0))
+    }
+}
+
+#[cfg(feature = "crossbeam")]
+impl<T> Receiver<T> for crossbeam_channel::Receiver<T> {
+    fn recv(&self) -> Result<T, RecvError> {
+        crossbeam_channel::Receiver::recv(self).map_err(|_| RecvError)
+    }
+
+    fn try_recv(&self) -> Result<T, TryRecvError> {
+        crossbeam_channel::Receiver::try_recv(self).map_err(|error| match error {
+            crossbeam_channel::TryRecvError::Empty => TryRecvError::Empty,
+            crossbeam_channel::TryRecvError::Disconnected => TryRecvError::Disconnected,
+        })
+    }
+
+    fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        crossbeam_channel::Receiver::recv_timeout(self, timeout).map_err(|error| match error {
+            crossbeam_channel::RecvTimeoutError::Timeout => RecvTimeoutError::Timeout,
+            crossbeam_channel::RecvTimeoutError::Disconnected => RecvTimeoutError::Disconnected,
+        })
+    }
+}
+
+#[cfg(feature = "crossbeam")]
+impl Backend for Crossbeam {
+    const NAME: &'static str = "crossbeam";
+
+    type Sender<T: Send + 'static> = crossbeam_channel::Sender<T>;
+    type Receiver<T: Send + 'static> = crossbeam_channel::Receiver<T>;
+
+    fn unbounded<T: Send + 'static>() -> (Self::Sender<T>, Self::Receiver<T>) {
+        crossbeam_channel::unbounded()
+    }
+
+    fn bounded<T: Send + 'static>(capacity: usize) -> (Self::Sender<T>, Self::Receiver<T>) {
+        crossbeam_channel::bounded(capacity)
+    }
+}
+
+/// The backend the workshop is built with
+#[cfg(feature = "crossbeam")]
+pub type Selected = Crossbeam;
+/// The backend the workshop is built with
+#[cfg(not(feature = "crossbeam"))]
+pub type Selected = Std;
+
+/// The sending end of a channel with the `Selected` backend
+pub type SelectedSender<T> = <Selected as Backend>::Sender<T>;
+/// The receiving end of a channel with the `Selected` backend
+pub type SelectedReceiver<T> = <Selected as Backend>::Receiver<T>;
+
+/// An unbounded channel, see `Backend::unbounded`, with the `Selected` backend
+pub fn unbounded<T: Send + 'static>() -> (SelectedSender<T>, SelectedReceiver<T>) {
+    Selected::unbounded()
+}
+
+/// A bounded channel, see `Backend::bounded`, with the `Selected` backend
+pub fn bounded<T: Send + 'static>(capacity: usize) -> (SelectedSender<T>, SelectedReceiver<T>) {
+    Selected::bounded(capacity)
+}
@@ -0,0 +1,258 @@
+//! Fault injection, to check that code copes when the things it depends on misbehave.
+//!
+//! Faults are picked by a seeded pseudo-random generator, so the same seed always gives the same faults.
+//! `Chaos::work` injects them into a function, and `channel` into a channel, which loses messages.
+//!
+//! ```
+//! use std::time::Duration;
+//! use common::chaos::{Chaos, Fault};
+//!
+//! let chaos = Chaos::new(42)
+//!     .errors(0.2)
+//!     .delays(0.1, Duration::from_millis(500));
+//! match chaos.next_fault() {
+//!     None => println!("All is well"),
+//!     Some(Fault::Error) => println!("Fail"),
+//!     Some(Fault::Delay(delay)) => println!("Take {delay:?} longer"),
+//!     Some(Fault::Panic) => println!("Panic"),
+//!     Some(Fault::Drop) => println!("Lose it"),
+//! }
+//! ```
+
+use std::{
+    collections::VecDeque,
+    sync::{
+        mpsc::{self, Receiver, SendError, Sender},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+/// Something going wrong
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// The work fails
+    Error,
+    /// The work takes this much longer than it should
+    Delay(Duration),
+    /// The work panics
+    Panic,
+    /// The message, or the result of the work, is lost without a word
+    Drop,
+}
+
+/// Where the faults come from
+#[derive(Debug)]
+enum Source {
+    /// The state of a splitmix64 generator
+    Random(u64),
+    /// Faults to hand out in order, and then no more faults
+    Script(VecDeque<Option<Fault>>),
+}
+
+/// Decides when and how things go wrong. Can be shared between threads.
+#[derive(Debug)]
+pub struct Chaos {
+    source: Mutex<Source>,
+    error: f64,
+    delay: f64,
+    delay_by: Duration,
+    jitter: Duration,
+    panic: f64,
+    drop: f64,
+}
+
+impl Chaos {
+    /// Random faults, the same ones for every run with the same `seed`.
+    /// Nothing goes wrong until some faults are enabled.
+    pub fn new(seed: u64) -> Self {
+        Self::from_source(Source::Random(seed))
+    }
+
+    /// Exactly these faults, in this order, with `None` meaning that call goes well.
+    /// Nothing goes wrong once they run out.
+    pub fn scripted(faults: impl IntoIterator<Item = Option<Fault>>) -> Self {
+        Self::from_source(Source::Script(faults.into_iter().collect()))
+    }
+
+    fn from_source(source: Source) -> Self {
+        Self {
+            source: Mutex::new(source),
+            error: 0.0,
+            delay: 0.0,
+            delay_by: Duration::ZERO,
+            jitter: Duration::ZERO,
+            panic: 0.0,
+            drop: 0.0,
+        }
+    }
+
+    /// Fails with `probability`
+    pub fn errors(mut self, probability: f64) -> Self {
+        self.error = probability;
+        self.check_probabilities();
+        self
+    }
+
+    /// Takes `delay` longer with `probability`
+    pub fn delays(mut self, probability: f64, delay: Duration) -> Self {
+        self.delay = probability;
+        self.delay_by = delay;
+        self.check_probabilities();
+        self
+    }
+
+    /// Takes a random moment of up to `max` longer whenever nothing else goes wrong, so that work
+    /// on several threads finishes in a different order every time. Scripted faults have no jitter.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use common::chaos::{Chaos, Fault};
+    ///
+    /// let max = Duration::from_millis(2);
+    /// let chaos = Chaos::new(42).jitter(max);
+    /// for _ in 0..100 {
+    ///     assert!(matches!(chaos.next_fault(), Some(Fault::Delay(delay)) if delay <= max));
+    /// }
+    /// ```
+    pub fn jitter(mut self, max: Duration) -> Self {
+        self.jitter = max;
+        self
+    }
+
+    /// Panics with `probability`
+    pub fn panics(mut self, probability: f64) -> Self {
+        self.panic = probability;
+        self.check_probabilities();
+        self
+    }
+
+    /// Loses the message or result with `probability`
+    pub fn drops(mut self, probability: f64) -> Self {
+        self.drop = probability;
+        self.check_probabilities();
+        self
+    }
+
+    fn check_probabilities(&self) {
+        let probabilities = [self.error, self.delay, self.panic, self.drop];
+        assert!(
+            probabilities.iter().all(|p| (0.0..=1.0).contains(p))
+                && probabilities.iter().sum::<f64>() <= 1.0,
+            "Fault probabilities must be between 0 and 1, and add up to at most 1"
+        );
+    }
+
+    /// What goes wrong this time, if anything
+    pub fn next_fault(&self) -> Option<Fault> {
+        let (draw, jitter) = match &mut *self.source.lock().unwrap() {
+            Source::Script(faults) => return faults.pop_front().flatten(),
+            Source::Random(state) => {
+                let draw = unit(splitmix64(state));
+                // Only drawn with jitter, so that a seed gives the same faults either way
+                let jitter =
+                    (!self.jitter.is_zero()).then(|| self.jitter.mul_f64(unit(splitmix64(state))));
+                (draw, jitter)
+            }
+        };
+
+        if draw < self.error {
+            Some(Fault::Error)
+        } else if draw < self.error + self.delay {
+            Some(Fault::Delay(self.delay_by))
+        } else if draw < self.error + self.delay + self.panic {
+            Some(Fault::Panic)
+        } else if draw < self.error + self.delay + self.panic + self.drop {
+            Some(Fault::Drop)
+        } else {
+            jitter.map(Fault::Delay)
+        }
+    }
+
+    /// Runs `f`, unless something goes wrong: a delay sleeps before running it, a panic panics
+    /// instead, and an error or a drop is returned instead of running it
+    pub fn work<T>(&self, f: impl FnOnce() -> T) -> Result<T, Fault> {
+        match self.next_fault() {
+            None => Ok(f()),
+            Some(Fault::Delay(delay)) => {
+                thread::sleep(delay);
+                Ok(f())
+            }
+            Some(Fault::Panic) => panic!("Chaos made the work panic"),
+            Some(fault @ (Fault::Error | Fault::Drop)) => Err(fault),
+        }
+    }
+}
+
+/// Keeps the messages of the panics chaos causes out of the output, from now on. Every other panic,
+/// like a `todo!()` or a bug, is still printed by the hook which was set before.
+pub fn quiet_panics() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str));
+        if !message.is_some_and(|message| message.starts_with("Chaos made")) {
+            previous(info);
+        }
+    }));
+}
+
+/// A channel which misbehaves whenever `chaos` says so, see `ChaosSender::send`
+pub fn channel<T>(chaos: Arc<Chaos>) -> (ChaosSender<T>, Receiver<T>) {
+    let (sender, receiver) = mpsc::channel();
+    (ChaosSender { sender, chaos }, receiver)
+}
+
+/// The sending end of a `channel`
+#[derive(Debug)]
+pub struct ChaosSender<T> {
+    sender: Sender<T>,
+    chaos: Arc<Chaos>,
+}
+
+impl<T> Clone for ChaosSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            chaos: Arc::clone(&self.chaos),
+        }
+    }
+}
+
+impl<T> ChaosSender<T> {
+    /// Like `Sender::send`, unless something goes wrong: a delay sleeps before sending, and a
+    /// panic panics instead. A channel can't fail other than by losing the message, so errors
+    /// and drops do that, and the sender is none the wiser.
+    pub fn send(&self, message: T) -> Result<(), SendError<T>> {
+        match self.chaos.next_fault() {
+            None => self.sender.send(message),
+            Some(Fault::Delay(delay)) => {
+                thread::sleep(delay);
+                self.sender.send(message)
+            }
+            Some(Fault::Panic) => panic!("Chaos made the send panic"),
+            Some(Fault::Error | Fault::Drop) => {
+                drop(message);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The next number from a splitmix64 generator, which is small and random enough for picking faults and test data
+pub(crate) fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// Turns random bits into a number in `0.0..1.0`
+fn unit(bits: u64) -> f64 {
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
@@ -0,0 +1,216 @@
+//! Command-line arguments for the parts' programs, so that they can be tried with more items, more
+//! threads, a single strategy or a faster calculation, without editing constants and recompiling.
+//!
+//! Every program declares the arguments it has something to do with, along with their defaults,
+//! and `--help` lists them. Arguments for a program run with `workshop run` go after a `--`.
+//!
+//! ```
+//! use common::cli::Cli;
+//!
+//! let cli = Cli::new("Sums numbers on several threads")
+//!     .items(1000)
+//!     .threads(8)
+//!     .strategies(["serial", "parallel"]);
+//! // A program calls `parse` instead, which reads the arguments it was run with
+//! let args = cli.try_parse_from(["part", "--threads", "2", "--strategy", "parallel"]).unwrap();
+//! assert_eq!((args.items(), args.threads()), (1000, 2));
+//! assert!(args.runs("Parallel sum") && !args.runs("Serial sum"));
+//! ```
+//!
+//! `--compute-ms` and `--strategy` also reach code which doesn't see the `Args`: `compute_ms` is how
+//! long `part_5::calculate` takes, and `bench::Comparison` skips the implementations `runs` says no
+//! to.
+
+use std::{ffi::OsString, sync::OnceLock, time::Duration};
+
+use clap::{
+    builder::{PossibleValuesParser, RangedU64ValueParser},
+    value_parser, Arg, ArgMatches, Command,
+};
+
+/// What `parse` found, for `compute_ms` and `runs`
+static PARSED: OnceLock<Args> = OnceLock::new();
+
+/// The arguments a program takes, see the module docs
+#[derive(Debug, Clone)]
+pub struct Cli {
+    command: Command,
+}
+
+impl Cli {
+    /// A program without any arguments, described by `about` in `--help`
+    pub fn new(about: &'static str) -> Self {
+        Self {
+            command: Command::new("part").about(about),
+        }
+    }
+
+    fn arg(mut self, arg: Arg) -> Self {
+        self.command = self.command.arg(arg);
+        self
+    }
+
+    /// Takes `--items`, how many items to work on. Defaults to `default`, or the size `workshop
+    /// bench` asks for, see `bench::size`.
+    pub fn items(self, default: usize) -> Self {
+        self.arg(
+            Arg::new("items")
+                .long("items")
+                .value_name("N")
+                .help("How many items to work on")
+                .value_parser(value_parser!(usize))
+                .default_value(crate::bench::size(default).to_string()),
+        )
+    }
+
+    /// Takes `--threads`, how many threads to work on
+    pub fn threads(self, default: usize) -> Self {
+        self.arg(
+            Arg::new("threads")
+                .long("threads")
+                .value_name("N")
+                .help("How many threads to work on")
+                .value_parser(RangedU64ValueParser::<usize>::new().range(1..))
+                .default_value(default.to_string()),
+        )
+    }
+
+    /// Takes `--strategy`, to only run the implementations whose name contains it, ignoring case
+    pub fn strategy(self) -> Self {
+        self.arg(
+            Arg::new("strategy")
+                .long("strategy")
+                .value_name("NAME")
+                .help("Only runs the implementations whose name contains NAME"),
+        )
+    }
+
+    /// Like `strategy`, but `--strategy` must be one of `names`, which `--help` lists
+    pub fn strategies(self, names: impl IntoIterator<Item = &'static str>) -> Self {
+        self.arg(
+            Arg::new("strategy")
+                .long("strategy")
+                .value_name("NAME")
+                .help("Only runs this implementation")
+                .value_parser(PossibleValuesParser::new(names)),
+        )
+    }
+
+    /// Takes `--compute-ms`, how long every calculation takes, in milliseconds
+    pub fn compute_ms(self, default: u64) -> Self {
+        self.arg(
+            Arg::new("compute-ms")
+                .long("compute-ms")
+                .value_name("MS")
+                .help("How long every calculation takes, in milliseconds")
+                .value_parser(value_parser!(u64))
+                .default_value(default.to_string()),
+        )
+    }
+
+    /// Takes a word after the options, like `serve`, described by `help`
+    pub fn positional(self, name: &'static str, help: &'static str) -> Self {
+        self.arg(Arg::new(name).value_name(name).help(help))
+    }
+
+    /// The arguments the program was run with. Prints what's wrong and exits if they aren't any of
+    /// the ones declared, and prints the help and exits for `--help`.
+    pub fn parse(self) -> Args {
+        let args = Args::from(self.command.get_matches());
+        // Only the first program in a process gets to set them, which is the only one anyway
+        let _ = PARSED.set(args.clone());
+        args
+    }
+
+    /// Like `parse`, but for `args` rather than the ones the program was run with, starting with
+    /// the program's name. Doesn't affect `compute_ms` or `runs`.
+    pub fn try_parse_from<I, T>(self, args: I) -> Result<Args, clap::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        self.command.try_get_matches_from(args).map(Args::from)
+    }
+}
+
+/// What a program was run with. Only the arguments its `Cli` declared are there.
+#[derive(Debug, Clone, Default)]
+pub struct Args {
+    items: Option<usize>,
+    threads: Option<usize>,
+    strategy: Option<String>,
+    compute_ms: Option<u64>,
+    positionals: Vec<(String, String)>,
+}
+
+impl From<ArgMatches> for Args {
+    fn from(matches: ArgMatches) -> Self {
+        // `try_get_one` is an error for arguments which weren't declared
+        let get = |id: &str| matches.try_get_one::<String>(id).ok().flatten().cloned();
+        let positionals = matches
+            .ids()
+            .map(|id| id.as_str())
+            .filter(|id| !["items", "threads", "strategy", "compute-ms"].contains(id))
+            .filter_map(|id| Some((id.to_string(), get(id)?)))
+            .collect();
+        Self {
+            items: matches.try_get_one("items").ok().flatten().copied(),
+            threads: matches.try_get_one("threads").ok().flatten().copied(),
+            strategy: get("strategy"),
+            compute_ms: matches.try_get_one("compute-ms").ok().flatten().copied(),
+            positionals,
+        }
+    }
+}
+
+impl Args {
+    pub fn items(&self) -> usize {
+        self.items
+            .expect("Only programs which call `Cli::items` have items")
+    }
+
+    pub fn threads(&self) -> usize {
+        self.threads
+            .expect("Only programs which call `Cli::threads` have threads")
+    }
+
+    pub fn compute(&self) -> Duration {
+        let ms = self
+            .compute_ms
+            .expect("Only programs which call `Cli::compute_ms` have a compute time");
+        Duration::from_millis(ms)
+    }
+
+    pub fn strategy(&self) -> Option<&str> {
+        self.strategy.as_deref()
+    }
+
+    /// Whether to run the implementation called `name`: always, unless it was run with a
+    /// `--strategy` which isn't part of the name
+    pub fn runs(&self, name: &str) -> bool {
+        self.strategy()
+            .is_none_or(|strategy| name.to_lowercase().contains(&strategy.to_lowercase()))
+    }
+
+    /// The word declared with `Cli::positional` as `name`, if the program was run with one
+    pub fn positional(&self, name: &str) -> Option<&str> {
+        self.positionals
+            .iter()
+            .find(|(id, _)| id == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// How long a calculation should take: `default` milliseconds, unless the program was run with
+/// `--compute-ms`. Tests never are.
+pub fn compute_ms(default: u64) -> u64 {
+    PARSED
+        .get()
+        .and_then(|args| args.compute_ms)
+        .unwrap_or(default)
+}
+
+/// Whether to run the implementation called `name`, see `Args::runs`. Always, in tests.
+pub fn runs(name: &str) -> bool {
+    PARSED.get().is_none_or(|args| args.runs(name))
+}
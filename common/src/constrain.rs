@@ -0,0 +1,106 @@
+//! Running code as if the machine had fewer cores, without containers or `taskset`, so that a
+//! speedup curve comes out the same on a laptop, a big server and a CI runner with a single core.
+//!
+//! `simulate(cores, f)` runs `f` on a simulated machine with `cores` cores. Every worker started
+//! with `spawn`, or wrapped in `gated` and started some other way, like on a scoped thread, waits
+//! for one of the simulated cores to be free before it starts, and keeps it until it's done. Threads
+//! started any other way aren't limited, and neither is the thread running `f`.
+//!
+//! A worker which sleeps keeps its core busy too, so work which sleeps, like `part_5::calculate`,
+//! takes as long on a simulated machine as busy work would on a real one with that many cores, and
+//! as long on any machine. A worker which waits for another one to start can wait forever though,
+//! when there are fewer cores than workers, so only gate workers which get on with their own work.
+//!
+//! ```
+//! use std::{thread, time::{Duration, Instant}};
+//! use common::constrain;
+//!
+//! let start = Instant::now();
+//! constrain::simulate(2, || {
+//!     assert_eq!(constrain::cores(), 2);
+//!     let workers: Vec<_> = (0..4)
+//!         .map(|_| constrain::spawn(|| thread::sleep(Duration::from_millis(50))))
+//!         .collect();
+//!     workers.into_iter().for_each(|worker| worker.join().unwrap());
+//! });
+//! // Two at a time
+//! assert!(start.elapsed() >= Duration::from_millis(100));
+//! ```
+
+use std::{cell::RefCell, sync::Arc, thread};
+
+use crate::Semaphore;
+
+thread_local! {
+    /// The machine `simulate` is simulating on this thread, if any
+    static CURRENT: RefCell<Option<Machine>> = const { RefCell::new(None) };
+}
+
+/// A simulated machine, whose cores are the permits of a semaphore
+#[derive(Debug, Clone)]
+struct Machine {
+    cores: usize,
+    free: Arc<Semaphore>,
+}
+
+fn current() -> Option<Machine> {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+/// Makes `machine` the current one on this thread, until the returned guard is dropped, which puts
+/// back whatever was there before, also when a panic unwinds past it
+fn enter(machine: Option<Machine>) -> impl Drop {
+    struct Restore(Option<Machine>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+        }
+    }
+
+    Restore(CURRENT.with(|current| current.replace(machine)))
+}
+
+/// Runs `f` as if the machine had `cores` cores, for the workers it starts with `spawn` or `gated`
+pub fn simulate<U>(cores: usize, f: impl FnOnce() -> U) -> U {
+    assert!(cores > 0, "A machine needs at least one core");
+    let _machine = enter(Some(Machine {
+        cores,
+        free: Arc::new(Semaphore::new(cores)),
+    }));
+    f()
+}
+
+/// How many cores there are to work on: the simulated ones, inside `simulate` and the workers it
+/// starts, and otherwise the ones this machine has
+pub fn cores() -> usize {
+    current().map_or_else(
+        || thread::available_parallelism().map_or(1, |cores| cores.get()),
+        |machine| machine.cores,
+    )
+}
+
+/// Wraps `f`, a worker, so that it waits for a simulated core before it starts, and keeps it until
+/// it's done. Outside of `simulate`, `f` runs as is.
+pub fn gated<U>(f: impl FnOnce() -> U) -> impl FnOnce() -> U {
+    let machine = current();
+    move || {
+        let Some(machine) = machine else {
+            return f();
+        };
+        let _core = machine.free.acquire();
+        // So that `cores` knows about it on the worker's thread too
+        let _machine = enter(Some(machine.clone()));
+        f()
+    }
+}
+
+/// Like `thread::spawn`, but the thread only starts working once a simulated core is free, see
+/// `gated`
+pub fn spawn<F, U>(f: F) -> thread::JoinHandle<U>
+where
+    F: FnOnce() -> U + Send + 'static,
+    U: Send + 'static,
+{
+    thread::spawn(gated(f))
+}
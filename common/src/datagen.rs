@@ -0,0 +1,19 @@
+//! Inputs for benchmarks and tests, which are the same for every run with the same seed.
+
+use crate::chaos::splitmix64;
+
+/// `len` random numbers, spread over the whole range of a `u64`
+pub fn numbers(len: usize, seed: u64) -> Vec<u64> {
+    let mut state = seed;
+    (0..len).map(|_| splitmix64(&mut state)).collect()
+}
+
+/// `len` random numbers below `limit`, which is handy for keys which should collide,
+/// or numbers which should be added up without overflowing
+pub fn numbers_below(len: usize, limit: u64, seed: u64) -> Vec<u64> {
+    assert!(limit > 0, "There are no numbers below 0");
+    numbers(len, seed)
+        .into_iter()
+        .map(|number| number % limit)
+        .collect()
+}
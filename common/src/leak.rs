@@ -0,0 +1,82 @@
+//! Checking that threads stop once nobody needs them anymore, instead of running, or waiting, until
+//! the program ends.
+//!
+//! Every thread which should stop takes a `Token` from a `LeakGuard`, and keeps it until it's done.
+//! The guard can then wait for every token to be dropped, which only happens once the threads have
+//! returned, or panicked.
+//!
+//! ```
+//! use std::{thread, time::Duration};
+//! use common::leak::LeakGuard;
+//!
+//! let guard = LeakGuard::new();
+//! let token = guard.token();
+//! thread::spawn(move || {
+//!     let _token = token;
+//!     thread::sleep(Duration::from_millis(10));
+//! });
+//! assert!(guard.wait(Duration::from_secs(1)), "{} threads are still running", guard.live());
+//! ```
+
+use std::{
+    sync::{Arc, Condvar, Mutex},
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Default)]
+struct Live {
+    tokens: Mutex<usize>,
+    dropped: Condvar,
+}
+
+/// Keeps track of the tokens it handed out, see the module documentation
+#[derive(Debug, Default)]
+pub struct LeakGuard {
+    live: Arc<Live>,
+}
+
+/// Held by a thread which should stop, for as long as it runs. Dropping it tells its `LeakGuard`.
+#[derive(Debug)]
+pub struct Token {
+    live: Arc<Live>,
+}
+
+impl LeakGuard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A token for another thread to hold while it runs
+    pub fn token(&self) -> Token {
+        *self.live.tokens.lock().unwrap() += 1;
+        Token {
+            live: self.live.clone(),
+        }
+    }
+
+    /// How many tokens haven't been dropped yet
+    pub fn live(&self) -> usize {
+        *self.live.tokens.lock().unwrap()
+    }
+
+    /// Waits for at most `timeout` for every token to be dropped, and returns whether they were
+    pub fn wait(&self, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        let mut tokens = self.live.tokens.lock().unwrap();
+        while *tokens > 0 {
+            let left = deadline.saturating_duration_since(Instant::now());
+            if left.is_zero() {
+                return false;
+            }
+            tokens = self.live.dropped.wait_timeout(tokens, left).unwrap().0;
+        }
+        true
+    }
+}
+
+impl Drop for Token {
+    fn drop(&mut self) {
+        *self.live.tokens.lock().unwrap() -= 1;
+        self.live.dropped.notify_all();
+    }
+}
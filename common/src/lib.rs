@@ -1,3 +1,22 @@
+pub mod affinity;
+pub mod alloc;
+pub mod bench;
+pub mod budget;
+pub mod channel;
+pub mod chaos;
+pub mod cli;
+pub mod constrain;
+pub mod datagen;
+pub mod leak;
+pub mod priority;
+pub mod rng;
+pub mod sanitize;
+pub mod span;
+pub mod stress;
+pub mod sync;
+pub mod trace;
+pub mod viz;
+
 use std::time::Duration;
 
 /// Panics if the machine it runs on only has one core
@@ -34,3 +53,263 @@ pub fn time_elapsed<F: FnOnce() -> U, U>(name: &str, f: F) -> (U, Duration) {
 
     (results, elapsed)
 }
+
+/// Prints the spans from `span` as they close, with how long they were busy, along with any other
+/// `tracing` events, in a compact format with the thread they're on. `RUST_LOG` picks what's
+/// printed, `info` if it isn't set. Only does anything with the `tracing` feature, and only the
+/// first time it's called.
+pub fn init_tracing() {
+    #[cfg(feature = "tracing")]
+    {
+        use tracing_subscriber::{fmt::format::FmtSpan, EnvFilter};
+
+        let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+        // Fails if it was set already, which is fine
+        let _ = tracing_subscriber::fmt()
+            .compact()
+            .with_env_filter(filter)
+            .with_target(false)
+            .with_thread_names(true)
+            .with_span_events(FmtSpan::CLOSE)
+            .try_init();
+    }
+}
+
+/// Runs `f` on a separate thread, waiting at most `timeout` for it to finish.
+/// Returns `None` if it didn't finish in time, e.g. because it deadlocked.
+/// Threads can't be killed, so a thread that times out is left running in the background.
+/// Panics in `f` are propagated to the caller.
+pub fn with_timeout<F: FnOnce() -> U + Send + 'static, U: Send + 'static>(
+    timeout: Duration,
+    f: F,
+) -> Option<U> {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let handle = std::thread::spawn(move || {
+        // The receiver is gone if we timed out, so there's no one to tell
+        let _ = sender.send(f());
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(results) => Some(results),
+        Err(std::sync::mpsc::RecvTimeoutError::Timeout) => None,
+        Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(panic) => std::panic::resume_unwind(panic),
+            Ok(()) => unreachable!("Thread finished without sending its results"),
+        },
+    }
+}
+
+/// Runs `f` on a separate thread like `with_timeout`, but gives it a `Progress` to count the items
+/// it completes, and tells how far it got if it didn't finish within `budget`: how many items it
+/// completed, and how many threads are still running. Panics in `f` are propagated to the caller.
+///
+/// ```
+/// use std::{thread, time::Duration};
+///
+/// let exceeded = common::with_budget(Duration::from_millis(50), |progress| {
+///     for _ in 0..100 {
+///         thread::sleep(Duration::from_millis(10));
+///         progress.complete(1);
+///     }
+/// })
+/// .unwrap_err();
+/// assert!(exceeded.completed < 100);
+/// println!("{exceeded}");
+///
+/// let sum = common::with_budget(Duration::from_secs(10), |_| (1..=10).sum::<i32>());
+/// assert_eq!(sum, Ok(55));
+/// ```
+pub fn with_budget<F: FnOnce(budget::Progress) -> U + Send + 'static, U: Send + 'static>(
+    budget: Duration,
+    f: F,
+) -> Result<U, budget::BudgetExceeded> {
+    let progress = budget::Progress::default();
+    let before = budget::threads();
+    let counted = progress.clone();
+    with_timeout(budget, move || f(counted)).ok_or_else(|| budget::BudgetExceeded {
+        budget,
+        completed: progress.completed(),
+        threads: before
+            .zip(budget::threads())
+            .map(|(before, now)| now.saturating_sub(before)),
+    })
+}
+
+/// What `f` returns, or `None` if it hits an exercise which hasn't been implemented yet, on any of
+/// its threads, whose `todo!()` then often makes something else panic first, like joining the
+/// thread. Their messages are kept out of the output, but any other panic is printed as usual and
+/// propagated to the caller, so that an implementation which is wrong isn't mistaken for one which
+/// isn't there yet.
+///
+/// A panic can't tell which call its thread was spawned by, so calls to this wait for each other,
+/// like `budget::measure`. A `todo!()` of another test which runs meanwhile, without it, is taken
+/// for one of the running call's and isn't printed, though that test fails as usual.
+///
+/// ```
+/// assert_eq!(common::implemented(|| 1 + 1), Some(2));
+/// assert_eq!(common::implemented(|| -> i32 { todo!() }), None);
+/// let wrong = std::panic::catch_unwind(|| common::implemented(|| assert_eq!(1 + 1, 3)));
+/// assert!(wrong.is_err(), "A wrong implementation isn't a missing one");
+///
+/// let tests: Vec<_> = (0..4)
+///     .map(|_| std::thread::spawn(|| common::implemented(|| -> i32 { todo!() })))
+///     .collect();
+/// assert!(tests.into_iter().all(|test| test.join().unwrap().is_none()));
+/// ```
+pub fn implemented<R>(f: impl FnOnce() -> R) -> Option<R> {
+    use std::{
+        panic::{self, AssertUnwindSafe},
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Mutex, Once,
+        },
+    };
+
+    /// Lets only one `implemented` run at a time
+    static RUNNING: Mutex<()> = Mutex::new(());
+    /// Whether a `todo!()` panicked while the current call ran
+    static HIT_TODO: AtomicBool = AtomicBool::new(false);
+    /// Whether a call is running, and `todo!()`s are kept out of the output
+    static ACTIVE: AtomicBool = AtomicBool::new(false);
+    static HOOK: Once = Once::new();
+
+    fn unimplemented(payload: &(dyn std::any::Any + Send)) -> bool {
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str));
+        // The messages of `todo!()` and `unimplemented!()`, with or without one of their own
+        message.is_some_and(|message| {
+            message.starts_with("not yet implemented") || message.starts_with("not implemented")
+        })
+    }
+
+    // Set once and kept, since swapping hooks back and forth loses them when tests do it at once
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if ACTIVE.load(Ordering::SeqCst) && unimplemented(info.payload()) {
+                HIT_TODO.store(true, Ordering::SeqCst);
+            } else {
+                previous(info);
+            }
+        }));
+    });
+
+    let running = RUNNING
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    HIT_TODO.store(false, Ordering::SeqCst);
+    ACTIVE.store(true, Ordering::SeqCst);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    ACTIVE.store(false, Ordering::SeqCst);
+    let hit_todo = HIT_TODO.load(Ordering::SeqCst);
+    drop(running);
+    match result {
+        Ok(result) => Some(result),
+        Err(payload) if hit_todo || unimplemented(&*payload) => None,
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+/// A line of output logged to an [`OutputLog`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    /// Name of the thread that logged it, if it was named
+    pub thread: Option<String>,
+    pub message: String,
+}
+
+/// Collects output from many threads in the order it was logged,
+/// so tests can check what was printed and by whom.
+/// Clones share the same log.
+#[derive(Debug, Clone, Default)]
+pub struct OutputLog {
+    entries: std::sync::Arc<std::sync::Mutex<Vec<LogEntry>>>,
+}
+
+impl OutputLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prints `message` to stdout and appends it to the log
+    pub fn log(&self, message: impl Into<String>) {
+        let thread = std::thread::current().name().map(String::from);
+        let message = message.into();
+        // Print while holding the lock so stdout shows the same order as the log
+        let mut entries = self.entries.lock().unwrap();
+        println!("[{}] {message}", thread.as_deref().unwrap_or("<unnamed>"));
+        entries.push(LogEntry { thread, message });
+    }
+
+    /// All logged entries, in the order they were logged
+    pub fn entries(&self) -> Vec<LogEntry> {
+        self.entries.lock().unwrap().clone()
+    }
+
+    /// Messages logged by the thread named `thread`, in the order they were logged
+    pub fn messages_from(&self, thread: &str) -> Vec<String> {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|entry| entry.thread.as_deref() == Some(thread))
+            .map(|entry| entry.message.clone())
+            .collect()
+    }
+}
+
+/// Limits how many threads can hold a permit at once.
+/// A blocking counterpart to `tokio::sync::Semaphore`, for use with threads.
+#[derive(Debug)]
+pub struct Semaphore {
+    permits: std::sync::Mutex<usize>,
+    released: std::sync::Condvar,
+}
+
+/// Gives its permit back to the [`Semaphore`] when dropped
+#[derive(Debug)]
+pub struct SemaphorePermit<'a> {
+    semaphore: &'a Semaphore,
+}
+
+impl Semaphore {
+    pub fn new(permits: usize) -> Self {
+        Self {
+            permits: std::sync::Mutex::new(permits),
+            released: std::sync::Condvar::new(),
+        }
+    }
+
+    /// Waits until a permit is available, and takes it
+    pub fn acquire(&self) -> SemaphorePermit<'_> {
+        let mut permits = self
+            .released
+            .wait_while(self.permits.lock().unwrap(), |permits| *permits == 0)
+            .unwrap();
+        *permits -= 1;
+        SemaphorePermit { semaphore: self }
+    }
+
+    /// Takes a permit if one is available, without waiting
+    pub fn try_acquire(&self) -> Option<SemaphorePermit<'_>> {
+        let mut permits = self.permits.lock().unwrap();
+        if *permits == 0 {
+            return None;
+        }
+        *permits -= 1;
+        Some(SemaphorePermit { semaphore: self })
+    }
+
+    pub fn available_permits(&self) -> usize {
+        *self.permits.lock().unwrap()
+    }
+}
+
+impl Drop for SemaphorePermit<'_> {
+    fn drop(&mut self) {
+        *self.semaphore.permits.lock().unwrap() += 1;
+        self.semaphore.released.notify_one();
+    }
+}
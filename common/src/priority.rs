@@ -0,0 +1,88 @@
+//! Changing the scheduling priority of the current thread.
+//!
+//! Only supported on Linux, where every thread has its own niceness: from -20, the highest priority,
+//! to 19, the lowest. Anyone may lower the priority of their own threads, but raising it usually needs
+//! extra privileges, so code using this should cope with being told no.
+
+/// Why the priority couldn't be changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriorityError {
+    /// This platform doesn't support per-thread niceness
+    Unsupported,
+    /// Not allowed to set that niceness, e.g. for raising the priority without privileges
+    NotPermitted,
+    /// Something else went wrong, with the OS error code
+    Failed(i32),
+}
+
+/// The lowest niceness, which is the highest priority
+pub const HIGHEST: i32 = -20;
+
+/// The niceness threads start with
+pub const NORMAL: i32 = 0;
+
+/// The highest niceness, which is the lowest priority
+pub const LOWEST: i32 = 19;
+
+/// The niceness of the current thread
+pub fn niceness() -> Result<i32, PriorityError> {
+    imp::niceness()
+}
+
+/// Sets the niceness of the current thread.
+/// Threads spawned afterwards by this thread start with the same niceness.
+pub fn set_niceness(niceness: i32) -> Result<(), PriorityError> {
+    imp::set_niceness(niceness)
+}
+
+#[cfg(target_os = "linux")]
+mod imp {
+    use super::PriorityError;
+
+    fn thread_id() -> libc::id_t {
+        // SAFETY: gettid has no preconditions, and always succeeds
+        unsafe { libc::syscall(libc::SYS_gettid) as libc::id_t }
+    }
+
+    fn last_error() -> PriorityError {
+        match std::io::Error::last_os_error().raw_os_error() {
+            Some(libc::EPERM | libc::EACCES) => PriorityError::NotPermitted,
+            Some(code) => PriorityError::Failed(code),
+            None => PriorityError::Failed(0),
+        }
+    }
+
+    pub fn niceness() -> Result<i32, PriorityError> {
+        // getpriority can return -1 on success, so errno is the only way to tell if it failed
+        // SAFETY: errno is thread local, and getpriority only reads its arguments
+        unsafe {
+            *libc::__errno_location() = 0;
+            let niceness = libc::getpriority(libc::PRIO_PROCESS as _, thread_id());
+            if niceness == -1 && *libc::__errno_location() != 0 {
+                return Err(last_error());
+            }
+            Ok(niceness)
+        }
+    }
+
+    pub fn set_niceness(niceness: i32) -> Result<(), PriorityError> {
+        // SAFETY: setpriority only reads its arguments
+        match unsafe { libc::setpriority(libc::PRIO_PROCESS as _, thread_id(), niceness) } {
+            0 => Ok(()),
+            _ => Err(last_error()),
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod imp {
+    use super::PriorityError;
+
+    pub fn niceness() -> Result<i32, PriorityError> {
+        Err(PriorityError::Unsupported)
+    }
+
+    pub fn set_niceness(_niceness: i32) -> Result<(), PriorityError> {
+        Err(PriorityError::Unsupported)
+    }
+}
@@ -0,0 +1,122 @@
+//! Random inputs for tests, which are different on every run, so that the tests try more than one,
+//! but which can always be had again.
+//!
+//! Every run picks a seed, or takes it from `WORKSHOP_SEED`, and every test mixes its own name into
+//! it, so that tests get different numbers from each other. When a test fails, it prints the seed,
+//! and running it again with `WORKSHOP_SEED` set to that gives it exactly the same numbers.
+//!
+//! ```
+//! let mut rng = common::rng::for_test();
+//! let numbers = common::datagen::numbers(100, rng.seed());
+//! let op = rng.below(3);
+//! ```
+
+use std::{
+    env,
+    hash::{BuildHasher, RandomState},
+    ops::{Deref, DerefMut},
+    sync::OnceLock,
+    thread,
+};
+
+use crate::chaos::splitmix64;
+
+/// What to set to a seed a test printed, to get the same numbers again
+pub const VARIABLE: &str = "WORKSHOP_SEED";
+
+/// The seed of this run: `WORKSHOP_SEED` if it's set, and a new one every run if it isn't
+pub fn seed() -> u64 {
+    static SEED: OnceLock<u64> = OnceLock::new();
+    *SEED.get_or_init(|| match env::var(VARIABLE) {
+        Ok(seed) => seed
+            .trim()
+            .parse()
+            .unwrap_or_else(|_| panic!("{VARIABLE} should be a whole number, not {seed:?}")),
+        Err(_) => RandomState::new().hash_one(VARIABLE),
+    })
+}
+
+/// A splitmix64 generator, like `datagen` uses
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// The same numbers for every run with the same `seed`
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        splitmix64(&mut self.state)
+    }
+
+    /// A number below `limit`
+    pub fn below(&mut self, limit: u64) -> u64 {
+        assert!(limit > 0, "There are no numbers below 0");
+        self.next_u64() % limit
+    }
+
+    /// A seed for one of the functions in `datagen`, or for `Chaos`, or for a part's own generator
+    pub fn seed(&mut self) -> u64 {
+        self.next_u64()
+    }
+}
+
+/// The generator for the test this is called in, which prints how to get the same numbers again
+/// if the test fails
+pub fn for_test() -> TestRng {
+    let name = thread::current().name().unwrap_or("main").to_string();
+    TestRng {
+        rng: Rng::new(seed() ^ fnv1a(&name)),
+        name,
+    }
+}
+
+/// Hashes a test's name the same way on every machine and compiler, unlike `DefaultHasher`
+fn fnv1a(name: &str) -> u64 {
+    name.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// A test's generator, see `for_test`
+#[derive(Debug)]
+pub struct TestRng {
+    rng: Rng,
+    name: String,
+}
+
+impl Deref for TestRng {
+    type Target = Rng;
+
+    fn deref(&self) -> &Rng {
+        &self.rng
+    }
+}
+
+impl DerefMut for TestRng {
+    fn deref_mut(&mut self) -> &mut Rng {
+        &mut self.rng
+    }
+}
+
+impl Drop for TestRng {
+    fn drop(&mut self) {
+        // Shown along with the failure, since the test harness keeps the output of failed tests
+        if thread::panicking() {
+            // Cargo says which package the test is in when it runs it
+            let package = env::var("CARGO_PKG_NAME")
+                .map(|package| format!("-p {package} "))
+                .unwrap_or_default();
+            eprintln!(
+                "{} used random inputs, which it gets again with \
+                `{VARIABLE}={} cargo test {package}{}`, along with any features it was run with",
+                self.name,
+                seed(),
+                self.name
+            );
+        }
+    }
+}
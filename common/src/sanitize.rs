@@ -0,0 +1,39 @@
+//! Adjusting tests for ThreadSanitizer, which `workshop check <part> --sanitize=thread` runs them under.
+//!
+//! ThreadSanitizer watches every memory access to find data races, which makes code 5 to 15 times
+//! slower. A race only has to happen once to be found, so the tests can do much less work, and tests
+//! which compare how long things take tell nothing under it.
+//!
+//! ```no_run
+//! let increments = common::sanitize::workload(1_000_000);
+//! ```
+
+use std::env;
+
+/// Set by `workshop check --sanitize=thread` to the sanitizer the tests were built with
+pub const VARIABLE: &str = "WORKSHOP_SANITIZER";
+
+/// Whether the tests were built with ThreadSanitizer
+pub fn thread() -> bool {
+    env::var(VARIABLE).is_ok_and(|sanitizer| sanitizer == "thread")
+}
+
+/// `amount` of work normally, and a small part of it under ThreadSanitizer, which is still
+/// plenty for it to find the races
+pub fn workload(amount: usize) -> usize {
+    if thread() {
+        (amount / 100).max(10).min(amount)
+    } else {
+        amount
+    }
+}
+
+/// Whether a test which compares timings should be skipped, since it runs under ThreadSanitizer.
+/// Says so when it should, so that the test doesn't look like it passed for real.
+pub fn skip_timing() -> bool {
+    let skip = thread();
+    if skip {
+        println!("Skipping the timings, which mean nothing under ThreadSanitizer");
+    }
+    skip
+}
@@ -0,0 +1,59 @@
+//! `tracing` spans for the threads and workers the parts spawn, and the items they work on, so a
+//! run shows which thread worked on what, nested the same way as the threads and their work.
+//!
+//! The spans are only opened with the `tracing` feature, like `cargo run -p part-17 --features
+//! common/tracing`, and only printed once the program has called `init_tracing`. Without the
+//! feature they do nothing, and cost nothing. The spans for items are at the `trace` level, so they
+//! only show with `RUST_LOG=trace`, since there can be millions of them.
+//!
+//! ```
+//! use std::thread;
+//!
+//! common::init_tracing();
+//! thread::scope(|s| {
+//!     for id in 0..2 {
+//!         s.spawn(move || {
+//!             let _worker = common::span::worker(id);
+//!             for index in 0..3 {
+//!                 let _item = common::span::item(index);
+//!             }
+//!         });
+//!     }
+//! });
+//! ```
+
+// Without the feature, what the spans would have recorded goes unused
+#![cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+
+/// A span which is open until it's dropped, on the thread which opened it
+#[derive(Debug)]
+#[must_use = "The span is closed as soon as it's dropped"]
+pub struct Entered {
+    #[cfg(feature = "tracing")]
+    _span: tracing::span::EnteredSpan,
+}
+
+/// Opens a span for a thread with a role, like `"producer"` or `"collector"`, for the rest of
+/// what it runs
+pub fn thread(name: &'static str) -> Entered {
+    Entered {
+        #[cfg(feature = "tracing")]
+        _span: tracing::info_span!("thread", name).entered(),
+    }
+}
+
+/// Opens a span for the worker with `id`, one of several doing the same thing
+pub fn worker(id: usize) -> Entered {
+    Entered {
+        #[cfg(feature = "tracing")]
+        _span: tracing::info_span!("worker", id).entered(),
+    }
+}
+
+/// Opens a span for working on the item at `index`, at the `trace` level
+pub fn item(index: usize) -> Entered {
+    Entered {
+        #[cfg(feature = "tracing")]
+        _span: tracing::trace_span!("item", index).entered(),
+    }
+}
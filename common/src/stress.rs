@@ -0,0 +1,129 @@
+//! Running a parallel implementation over and over on the same input, to check that it gives the
+//! same output every time, and not only when its threads happen to finish in the usual order.
+//!
+//! While `Stress::check` runs, chaos mode is on: `jitter` asks a `Chaos` seeded by the check for a
+//! delay, and waits for it instead of returning right away. Work which calls it, like
+//! `part_5::calculate`, then finishes in a different order on every run, and takes so little time
+//! that hundreds of runs fit in a test. Chaos mode is on for the whole process, so tests which
+//! measure how long that work takes shouldn't run at the same time as a stress check.
+//!
+//! ```
+//! use std::thread;
+//! use common::stress::{self, Stress};
+//!
+//! let squares = |numbers: Vec<u64>| {
+//!     thread::scope(|s| {
+//!         let handles: Vec<_> = numbers
+//!             .into_iter()
+//!             .map(|x| s.spawn(move || {
+//!                 stress::jitter();
+//!                 x * x
+//!             }))
+//!             .collect();
+//!         handles.into_iter().map(|h| h.join().unwrap()).collect::<Vec<_>>()
+//!     })
+//! };
+//! let serial = |numbers: Vec<u64>| numbers.into_iter().map(|x| x * x).collect::<Vec<_>>();
+//! Stress::new(42).check(&vec![1, 2, 3], serial, squares);
+//! ```
+
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use crate::chaos::{Chaos, Fault};
+
+/// How many times `Stress::new` runs the implementation
+pub const RUNS: usize = 200;
+
+/// The longest `jitter` waits
+pub const MAX_JITTER: Duration = Duration::from_millis(2);
+
+/// Where the jitter comes from while a `check` runs, and `None` outside of chaos mode
+static CHAOS: Mutex<Option<Arc<Chaos>>> = Mutex::new(None);
+
+/// Lets only one `check` run at a time, since chaos mode is for the whole process
+static STRESSING: Mutex<()> = Mutex::new(());
+
+fn chaos() -> Option<Arc<Chaos>> {
+    CHAOS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+/// Whether a `Stress::check` is running
+pub fn chaos_mode() -> bool {
+    chaos().is_some()
+}
+
+/// In chaos mode, waits for a random moment of up to `MAX_JITTER`, and returns `true`. Otherwise
+/// returns `false` right away.
+pub fn jitter() -> bool {
+    // Cloned out, so that threads only take turns to draw their delays, and not to wait them out
+    let Some(chaos) = chaos() else {
+        return false;
+    };
+    match chaos.next_fault() {
+        Some(Fault::Delay(delay)) if !delay.is_zero() => thread::sleep(delay),
+        _ => thread::yield_now(),
+    }
+    true
+}
+
+/// How a parallel implementation is stress checked, see the module docs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stress {
+    pub runs: usize,
+    /// Picks the jitter, though the threads still depend on how they're scheduled
+    pub seed: u64,
+}
+
+impl Stress {
+    /// `RUNS` runs, with jitter from `seed`
+    pub fn new(seed: u64) -> Self {
+        Self { runs: RUNS, seed }
+    }
+
+    /// Runs `parallel` on a clone of `input` every run, in chaos mode, and panics on the first run
+    /// which doesn't give the same output as `serial`, which only runs once
+    pub fn check<I: Clone, O: PartialEq + fmt::Debug>(
+        &self,
+        input: &I,
+        serial: impl FnOnce(I) -> O,
+        parallel: impl Fn(I) -> O,
+    ) {
+        let _stressing = STRESSING
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        *CHAOS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) =
+            Some(Arc::new(Chaos::new(self.seed).jitter(MAX_JITTER)));
+        // Turns chaos mode off even if `serial` or `parallel` panics, or the assertion fails
+        let _chaos_mode = ChaosMode;
+        // In chaos mode too, so that it's as quick as the runs
+        let expected = serial(input.clone());
+        for run in 1..=self.runs {
+            let output = parallel(input.clone());
+            assert_eq!(
+                output, expected,
+                "Run {run} of {} gave a different output, with the jitter from seed {}",
+                self.runs, self.seed
+            );
+        }
+    }
+}
+
+struct ChaosMode;
+
+impl Drop for ChaosMode {
+    fn drop(&mut self) {
+        *CHAOS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+    }
+}
@@ -0,0 +1,281 @@
+//! Locks which measure how they're used, to find out where threads wait for each other.
+//!
+//! ```
+//! use common::sync::InstrumentedMutex;
+//!
+//! let counter = InstrumentedMutex::new(0);
+//! *counter.lock() += 1;
+//! let report = counter.report();
+//! assert_eq!(report.acquisitions, 1);
+//! println!("{report}");
+//! ```
+//!
+//! And locks with a rank, which check that every thread locks them in the order of their ranks,
+//! so that locking them out of order panics every time, instead of deadlocking once in a while.
+//!
+//! ```should_panic
+//! use common::sync::Ordered;
+//!
+//! let (low, high) = (Ordered::new(1, "low"), Ordered::new(2, "high"));
+//! let _high = high.lock();
+//! let _low = low.lock(); // Panics, since `high` is held
+//! ```
+
+use std::{
+    cell::RefCell,
+    fmt,
+    iter::Sum,
+    ops::{Add, Deref, DerefMut},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, MutexGuard, TryLockError,
+    },
+    time::{Duration, Instant},
+};
+
+/// How a lock was used, added up over every time it was locked
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ContentionReport {
+    /// How many times it was locked
+    pub acquisitions: u64,
+    /// How many of those found it locked already, and had to wait
+    pub contended: u64,
+    /// How long was spent waiting to lock it
+    pub waited: Duration,
+    /// How long it was held
+    pub held: Duration,
+}
+
+impl ContentionReport {
+    /// The share of acquisitions which had to wait, from 0 to 1
+    pub fn contended_share(&self) -> f64 {
+        self.contended as f64 / self.acquisitions.max(1) as f64
+    }
+}
+
+impl Add for ContentionReport {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            acquisitions: self.acquisitions + other.acquisitions,
+            contended: self.contended + other.contended,
+            waited: self.waited + other.waited,
+            held: self.held + other.held,
+        }
+    }
+}
+
+/// Adds up the reports of several locks, like the shards of a sharded map
+impl Sum for ContentionReport {
+    fn sum<I: Iterator<Item = Self>>(reports: I) -> Self {
+        reports.fold(Self::default(), Add::add)
+    }
+}
+
+impl fmt::Display for ContentionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} acquisitions, {} contended ({:.1}%), waited {:?}, held {:?}",
+            self.acquisitions,
+            self.contended,
+            self.contended_share() * 100.0,
+            self.waited,
+            self.held
+        )
+    }
+}
+
+/// A `Mutex` which counts how often it's locked, how often that means waiting,
+/// and how long threads wait for it and hold it.
+/// Measuring takes a few clock reads for every lock, so it's for finding out where the contention is, not for production.
+#[derive(Debug, Default)]
+pub struct InstrumentedMutex<T> {
+    inner: Mutex<T>,
+    acquisitions: AtomicU64,
+    contended: AtomicU64,
+    waited_nanos: AtomicU64,
+    held_nanos: AtomicU64,
+}
+
+impl<T> InstrumentedMutex<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: Mutex::new(value),
+            acquisitions: AtomicU64::new(0),
+            contended: AtomicU64::new(0),
+            waited_nanos: AtomicU64::new(0),
+            held_nanos: AtomicU64::new(0),
+        }
+    }
+
+    /// Waits until the lock is free, and locks it.
+    /// Panics if another thread panicked while holding it, like `lock().unwrap()` on a `Mutex` would.
+    pub fn lock(&self) -> InstrumentedGuard<'_, T> {
+        let guard = match self.inner.try_lock() {
+            Ok(guard) => guard,
+            Err(TryLockError::WouldBlock) => {
+                let start = Instant::now();
+                let guard = self.inner.lock().unwrap();
+                self.contended.fetch_add(1, Ordering::Relaxed);
+                self.waited_nanos
+                    .fetch_add(start.elapsed().as_nanos() as u64, Ordering::Relaxed);
+                guard
+            }
+            Err(TryLockError::Poisoned(_)) => {
+                panic!("Another thread panicked while holding the lock")
+            }
+        };
+        self.acquisitions.fetch_add(1, Ordering::Relaxed);
+        InstrumentedGuard {
+            guard,
+            mutex: self,
+            locked_at: Instant::now(),
+        }
+    }
+
+    /// How the lock has been used so far. Locks which are still held only count once they're unlocked.
+    pub fn report(&self) -> ContentionReport {
+        ContentionReport {
+            acquisitions: self.acquisitions.load(Ordering::Relaxed),
+            contended: self.contended.load(Ordering::Relaxed),
+            waited: Duration::from_nanos(self.waited_nanos.load(Ordering::Relaxed)),
+            held: Duration::from_nanos(self.held_nanos.load(Ordering::Relaxed)),
+        }
+    }
+
+    /// Starts counting from zero again
+    pub fn reset(&self) {
+        for counter in [
+            &self.acquisitions,
+            &self.contended,
+            &self.waited_nanos,
+            &self.held_nanos,
+        ] {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner.into_inner().unwrap()
+    }
+}
+
+/// Unlocks the [`InstrumentedMutex`] when dropped, and records how long it was held
+#[derive(Debug)]
+pub struct InstrumentedGuard<'a, T> {
+    guard: MutexGuard<'a, T>,
+    mutex: &'a InstrumentedMutex<T>,
+    locked_at: Instant,
+}
+
+impl<T> Deref for InstrumentedGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<T> DerefMut for InstrumentedGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+impl<T> Drop for InstrumentedGuard<'_, T> {
+    fn drop(&mut self) {
+        // Still locked here, since `guard` is only dropped after this
+        self.mutex.held_nanos.fetch_add(
+            self.locked_at.elapsed().as_nanos() as u64,
+            Ordering::Relaxed,
+        );
+    }
+}
+
+thread_local! {
+    /// The ranks of the `Ordered` locks this thread holds
+    static HELD: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A `Mutex` in a lock hierarchy: a thread may only lock it while every other `Ordered` lock it
+/// holds has a lower rank. Locking it otherwise panics, before waiting for it, whether or not
+/// another thread holds it. Threads which lock in order can't deadlock, since the one holding the
+/// highest ranked lock never waits for a lock another one holds.
+#[derive(Debug, Default)]
+pub struct Ordered<T> {
+    rank: usize,
+    inner: Mutex<T>,
+}
+
+impl<T> Ordered<T> {
+    pub fn new(rank: usize, value: T) -> Self {
+        Self {
+            rank,
+            inner: Mutex::new(value),
+        }
+    }
+
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Waits until the lock is free, and locks it. Panics if this thread already holds an
+    /// `Ordered` lock of the same rank or a higher one, or if another thread panicked while holding
+    /// this one, like `lock().unwrap()` on a `Mutex` would.
+    pub fn lock(&self) -> OrderedGuard<'_, T> {
+        let highest = HELD.with_borrow(|held| held.iter().max().copied());
+        if let Some(highest) = highest.filter(|&highest| highest >= self.rank) {
+            panic!(
+                "Locked rank {} while holding rank {highest}, but locks must be taken in order of their rank",
+                self.rank
+            );
+        }
+        let guard = self
+            .inner
+            .lock()
+            .unwrap_or_else(|_| panic!("Another thread panicked while holding the lock"));
+        HELD.with_borrow_mut(|held| held.push(self.rank));
+        OrderedGuard {
+            guard,
+            rank: self.rank,
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner.into_inner().unwrap()
+    }
+}
+
+/// Unlocks the [`Ordered`] lock when dropped, after which this thread may lock lower ranked ones
+#[derive(Debug)]
+pub struct OrderedGuard<'a, T> {
+    guard: MutexGuard<'a, T>,
+    rank: usize,
+}
+
+impl<T> Deref for OrderedGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<T> DerefMut for OrderedGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+impl<T> Drop for OrderedGuard<'_, T> {
+    fn drop(&mut self) {
+        // Guards may be dropped in any order, so it's not necessarily the last one
+        HELD.with_borrow_mut(|held| {
+            if let Some(i) = held.iter().rposition(|&rank| rank == self.rank) {
+                held.remove(i);
+            }
+        });
+    }
+}
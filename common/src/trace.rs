@@ -0,0 +1,298 @@
+//! Channels which note every message sent and received, with when and on which thread, so a test
+//! can show what actually went through a channel when it fails.
+//!
+//! Receivers made by the code under test can be wrapped with `RecordingReceiver::wrap`, which notes
+//! what's received from them, whether they're std's or another `channel::Receiver`. Code which makes its own channels can make them with `channel`, which
+//! notes both ends.
+//!
+//! ```
+//! use std::{sync::mpsc, thread};
+//! use common::trace::{RecordingReceiver, Trace};
+//!
+//! let (sender, receiver) = mpsc::channel();
+//! thread::spawn(move || (1..=3).for_each(|x| sender.send(x).unwrap()));
+//!
+//! let trace = Trace::new();
+//! // Prints the trace if the test fails before the guard is dropped
+//! let _dump = trace.dump_on_panic();
+//! let receiver = RecordingReceiver::wrap(receiver, &trace);
+//! let received: Vec<i32> = receiver.iter().collect();
+//! assert_eq!(trace.received(), received);
+//!
+//! // The same messages again, in the same order, to run other code on
+//! let replayed: Vec<i32> = trace.replay().iter().collect();
+//! assert_eq!(replayed, [1, 2, 3]);
+//! ```
+
+use std::{
+    fmt,
+    sync::{
+        mpsc::{self, Receiver, RecvError, RecvTimeoutError, SendError, Sender, TryRecvError},
+        Arc, Mutex, MutexGuard,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::channel;
+
+/// Which end of a channel a message went through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Send,
+    Recv,
+}
+
+/// A message going through a channel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event<T> {
+    /// How long after the trace was made it happened
+    pub at: Duration,
+    /// The name of the thread it happened on, or its id if it doesn't have one
+    pub thread: String,
+    pub action: Action,
+    pub message: T,
+}
+
+/// Every message sent and received through the channels recording to it, in the order it happened.
+/// Clones share the same events. Printing it shows them as a timeline.
+#[derive(Debug)]
+pub struct Trace<T> {
+    start: Instant,
+    events: Arc<Mutex<Vec<Event<T>>>>,
+}
+
+impl<T> Clone for Trace<T> {
+    fn clone(&self) -> Self {
+        Self {
+            start: self.start,
+            events: Arc::clone(&self.events),
+        }
+    }
+}
+
+impl<T> Default for Trace<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Trace<T> {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            events: Arc::default(),
+        }
+    }
+
+    /// Taken while holding the lock on the events, so that the times are in the same order as them
+    fn event(&self, action: Action, message: T) -> Event<T> {
+        let current = thread::current();
+        let thread = match current.name() {
+            Some(name) => name.to_string(),
+            None => format!("{:?}", current.id()),
+        };
+        Event {
+            at: self.start.elapsed(),
+            thread,
+            action,
+            message,
+        }
+    }
+
+    /// The events, even if a thread panicked while noting one, since the trace is most useful then
+    fn lock(&self) -> MutexGuard<'_, Vec<Event<T>>> {
+        self.events
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn note(&self, action: Action, message: T) {
+        let mut events = self.lock();
+        events.push(self.event(action, message));
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T: Clone> Trace<T> {
+    /// Everything that has happened so far
+    pub fn events(&self) -> Vec<Event<T>> {
+        self.lock().clone()
+    }
+
+    fn messages(&self, action: Action) -> Vec<T> {
+        self.lock()
+            .iter()
+            .filter(|event| event.action == action)
+            .map(|event| event.message.clone())
+            .collect()
+    }
+
+    /// The messages sent, in the order they were sent
+    pub fn sent(&self) -> Vec<T> {
+        self.messages(Action::Send)
+    }
+
+    /// The messages received, in the order they were received
+    pub fn received(&self) -> Vec<T> {
+        self.messages(Action::Recv)
+    }
+
+    /// A receiver of the messages received so far, in the same order, or of the ones sent if
+    /// nothing has been received, which runs out after the last of them
+    pub fn replay(&self) -> Receiver<T> {
+        let mut messages = self.received();
+        if messages.is_empty() {
+            messages = self.sent();
+        }
+        let (sender, receiver) = mpsc::channel();
+        for message in messages {
+            // The receiver is right here, so it can't be gone
+            sender.send(message).unwrap();
+        }
+        receiver
+    }
+}
+
+impl<T: fmt::Debug> Trace<T> {
+    /// Prints the trace when it's dropped while panicking, like when an assertion in a test fails,
+    /// unless nothing happened
+    pub fn dump_on_panic(&self) -> DumpOnPanic<T> {
+        DumpOnPanic(self.clone())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Display for Trace<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let events = self.lock();
+        let width = events
+            .iter()
+            .map(|event| event.thread.chars().count())
+            .max()
+            .unwrap_or(0);
+        for event in events.iter() {
+            let action = match event.action {
+                Action::Send => "sent",
+                Action::Recv => "received",
+            };
+            writeln!(
+                f,
+                "{:>10}  {:<width$}  {action} {:?}",
+                format!("{:.2?}", event.at),
+                event.thread,
+                event.message
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Prints a trace if a test fails, see `Trace::dump_on_panic`
+#[derive(Debug)]
+pub struct DumpOnPanic<T: fmt::Debug>(Trace<T>);
+
+impl<T: fmt::Debug> Drop for DumpOnPanic<T> {
+    fn drop(&mut self) {
+        // Shown along with the failure, since the test harness keeps the output of failed tests
+        if thread::panicking() && !self.0.is_empty() {
+            eprintln!("What went through the channel:\n{}", self.0);
+        }
+    }
+}
+
+/// A channel which notes every message sent and received in `trace`
+pub fn channel<T: Clone>(trace: &Trace<T>) -> (RecordingSender<T>, RecordingReceiver<T>) {
+    let (sender, receiver) = mpsc::channel();
+    (
+        RecordingSender {
+            sender,
+            trace: trace.clone(),
+        },
+        RecordingReceiver::wrap(receiver, trace),
+    )
+}
+
+/// The sending end of a `channel`, which notes what's sent
+#[derive(Debug)]
+pub struct RecordingSender<T> {
+    sender: Sender<T>,
+    trace: Trace<T>,
+}
+
+impl<T> Clone for RecordingSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            trace: self.trace.clone(),
+        }
+    }
+}
+
+impl<T: Clone> RecordingSender<T> {
+    /// Like `Sender::send`. Only messages which made it into the channel are noted.
+    pub fn send(&self, message: T) -> Result<(), SendError<T>> {
+        let copy = message.clone();
+        // Sent while holding the lock on the events, so that the receiver can't note the message
+        // before it's been noted as sent
+        let mut events = self.trace.lock();
+        self.sender.send(message)?;
+        events.push(self.trace.event(Action::Send, copy));
+        Ok(())
+    }
+}
+
+/// A receiver which notes what's received from it
+#[derive(Debug)]
+pub struct RecordingReceiver<T, R = Receiver<T>> {
+    receiver: R,
+    trace: Trace<T>,
+}
+
+impl<T: Clone, R: channel::Receiver<T>> RecordingReceiver<T, R> {
+    /// Notes everything received from `receiver` in `trace`
+    pub fn wrap(receiver: R, trace: &Trace<T>) -> Self {
+        Self {
+            receiver,
+            trace: trace.clone(),
+        }
+    }
+
+    fn noted<E>(&self, result: Result<T, E>) -> Result<T, E> {
+        if let Ok(message) = &result {
+            self.trace.note(Action::Recv, message.clone());
+        }
+        result
+    }
+
+    /// Like `Receiver::recv`
+    pub fn recv(&self) -> Result<T, RecvError> {
+        self.noted(self.receiver.recv())
+    }
+
+    /// Like `Receiver::try_recv`
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        self.noted(self.receiver.try_recv())
+    }
+
+    /// Like `Receiver::recv_timeout`
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        self.noted(self.receiver.recv_timeout(timeout))
+    }
+
+    /// Like `Receiver::iter`, blocking for every message until the senders are gone
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        std::iter::from_fn(|| self.recv().ok())
+    }
+
+    /// The receiver it wraps, which doesn't note anything anymore
+    pub fn into_inner(self) -> R {
+        self.receiver
+    }
+}
@@ -0,0 +1,228 @@
+//! A timeline of when each thread was busy while some code ran, shown in the terminal, to see
+//! workers sitting idle while one of them does all the work, or all of them kept busy.
+//!
+//! The jobs in the workshop mostly sleep instead of using the CPU, so CPU time wouldn't show them.
+//! Instead, the work is wrapped in `busy`, which notes when each thread starts and finishes it,
+//! but only while `record` is running.
+//!
+//! ```
+//! use std::{thread, time::Duration};
+//!
+//! let (_, timeline) = common::viz::record(|| {
+//!     thread::scope(|s| {
+//!         for millis in [10, 40] {
+//!             s.spawn(move || common::viz::busy(|| thread::sleep(Duration::from_millis(millis))));
+//!         }
+//!     })
+//! });
+//! assert_eq!(timeline.threads().len(), 2);
+//! println!("{timeline}");
+//! ```
+
+use std::{
+    fmt,
+    sync::Mutex,
+    thread::{self, ThreadId},
+    time::{Duration, Instant},
+};
+
+/// How many columns the timeline is drawn with
+const WIDTH: usize = 60;
+
+/// From idle to busy for the whole column
+const BARS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// What's been noted since `record` started, or `None` when nothing is recording
+static RECORDING: Mutex<Option<Recording>> = Mutex::new(None);
+
+/// Lets only one `record` record at a time
+static RECORDER: Mutex<()> = Mutex::new(());
+
+#[derive(Debug)]
+struct Recording {
+    start: Instant,
+    threads: Vec<Thread>,
+}
+
+/// When one thread was busy
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Thread {
+    id: ThreadId,
+    /// The thread's name, or its place among the threads if it doesn't have one
+    pub name: String,
+    /// When it started and finished each piece of work, from the start of the recording
+    pub spans: Vec<(Duration, Duration)>,
+}
+
+impl Thread {
+    /// How long the thread was busy in total
+    pub fn busy(&self) -> Duration {
+        self.spans.iter().map(|(start, end)| *end - *start).sum()
+    }
+
+    /// How much of `from..to` the thread was busy, from 0 to 1
+    fn busy_between(&self, from: Duration, to: Duration) -> f64 {
+        let busy: Duration = self
+            .spans
+            .iter()
+            .map(|&(start, end)| end.min(to).saturating_sub(start.max(from)))
+            .sum();
+        (busy.as_secs_f64() / (to - from).as_secs_f64()).min(1.0)
+    }
+}
+
+/// Runs `f`, noting when the current thread was busy with it if `record` is running
+pub fn busy<U>(f: impl FnOnce() -> U) -> U {
+    let Some(start) = since_start() else {
+        return f();
+    };
+    let result = f();
+    let end = since_start().unwrap_or(start);
+
+    let current = thread::current();
+    let mut recording = RECORDING
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(recording) = recording.as_mut() {
+        let place = recording.threads.len();
+        let thread = match recording
+            .threads
+            .iter_mut()
+            .position(|thread| thread.id == current.id())
+        {
+            Some(index) => &mut recording.threads[index],
+            None => {
+                recording.threads.push(Thread {
+                    id: current.id(),
+                    name: current
+                        .name()
+                        .map_or_else(|| format!("thread {}", place + 1), str::to_string),
+                    spans: Vec::new(),
+                });
+                &mut recording.threads[place]
+            }
+        };
+        thread.spans.push((start, end));
+    }
+    result
+}
+
+/// How long it's been since `record` started, or `None` if it isn't running
+fn since_start() -> Option<Duration> {
+    let recording = RECORDING
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    recording
+        .as_ref()
+        .map(|recording| recording.start.elapsed())
+}
+
+/// Runs `f`, and returns what it returned and when every thread was `busy` while it ran.
+/// Calls to this wait for each other, so that they don't note each other's work.
+pub fn record<U>(f: impl FnOnce() -> U) -> (U, Timeline) {
+    let _recorder = RECORDER
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let start = Instant::now();
+    *RECORDING
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Recording {
+        start,
+        threads: Vec::new(),
+    });
+
+    // Stops recording even if `f` panics, so that `busy` doesn't go on noting work forever
+    let stop = Stop;
+    let result = f();
+    drop(stop);
+
+    let threads = RECORDING
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .take()
+        .map(|recording| recording.threads)
+        .unwrap_or_default();
+    let timeline = Timeline {
+        duration: start.elapsed(),
+        threads,
+    };
+    (result, timeline)
+}
+
+struct Stop;
+
+impl Drop for Stop {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            *RECORDING
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+        }
+    }
+}
+
+/// When every thread was busy during a `record`. Printing it draws a line for each thread, with
+/// a bar for every slice of the time showing how much of it the thread was busy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timeline {
+    /// How long the recording took
+    pub duration: Duration,
+    threads: Vec<Thread>,
+}
+
+impl Timeline {
+    /// The threads which were busy, in the order they first finished a piece of work
+    pub fn threads(&self) -> &[Thread] {
+        &self.threads
+    }
+
+    /// How much of the time the threads were busy, from 0 to 1.
+    /// Less than 1 means some of them sat idle while others still worked.
+    pub fn utilization(&self) -> f64 {
+        if self.threads.is_empty() || self.duration.is_zero() {
+            return 0.0;
+        }
+        let busy: Duration = self.threads.iter().map(Thread::busy).sum();
+        busy.as_secs_f64() / (self.duration.as_secs_f64() * self.threads.len() as f64)
+    }
+}
+
+impl fmt::Display for Timeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.threads.is_empty() || self.duration.is_zero() {
+            return writeln!(f, "No thread was busy in {:.2?}", self.duration);
+        }
+        let width = self
+            .threads
+            .iter()
+            .map(|thread| thread.name.chars().count())
+            .max()
+            .unwrap_or(0);
+        let column = self.duration / WIDTH as u32;
+        for thread in &self.threads {
+            let bars: String = (0..WIDTH as u32)
+                .map(|i| {
+                    let busy = thread.busy_between(column * i, column * (i + 1));
+                    BARS[(busy * (BARS.len() - 1) as f64).round() as usize]
+                })
+                .collect();
+            let share = thread.busy().as_secs_f64() / self.duration.as_secs_f64();
+            writeln!(
+                f,
+                "{:<width$} │{bars}│ {:>3.0}% busy",
+                thread.name,
+                share * 100.0
+            )?;
+        }
+        let threads = match self.threads.len() {
+            1 => "1 thread".to_string(),
+            threads => format!("{threads} threads"),
+        };
+        writeln!(
+            f,
+            "{threads} over {:.2?}, busy {:.0}% of the time",
+            self.duration,
+            self.utilization() * 100.0
+        )
+    }
+}
@@ -0,0 +1,55 @@
+//! Bank accounts, and transfers between them, for the parts which move money around
+
+use crate::Dataset;
+
+/// A transfer of `amount` from one account to another, by their indexes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transfer {
+    pub from: usize,
+    pub to: usize,
+    pub amount: u64,
+}
+
+/// Accounts which all start with the same balance, and transfers between them. Some transfers
+/// are from an account to itself, and some are for more than the account has.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Accounts {
+    pub count: usize,
+    pub balance: u64,
+    pub transfers: Vec<Transfer>,
+}
+
+impl Accounts {
+    /// The money in the bank, which transfers shouldn't change
+    pub fn total(&self) -> u64 {
+        self.count as u64 * self.balance
+    }
+}
+
+/// In the skewed dataset, nine in ten transfers are between the first two accounts, so threads
+/// keep wanting the same locks.
+pub fn accounts(dataset: Dataset) -> Accounts {
+    let (count, balance, transfers) = match dataset {
+        Dataset::Small => (4, 100, 100),
+        Dataset::Medium | Dataset::Skewed => (100, 1000, 10_000),
+        Dataset::Large => (1000, 1000, 100_000),
+    };
+    let mut random = common::datagen::numbers(transfers * 4, dataset.seed()).into_iter();
+    let mut next = move |limit: u64| random.next().unwrap() % limit;
+    let transfers = (0..transfers)
+        .map(|_| {
+            let hot = dataset == Dataset::Skewed && next(10) != 0;
+            let accounts = if hot { 2 } else { count as u64 };
+            Transfer {
+                from: next(accounts) as usize,
+                to: next(accounts) as usize,
+                amount: next(balance / 2),
+            }
+        })
+        .collect();
+    Accounts {
+        count,
+        balance,
+        transfers,
+    }
+}
@@ -0,0 +1,101 @@
+//! The datasets the parts' programs, tests and benchmarks share, so that what they measure can be
+//! compared across parts and machines. Every dataset is the same on every run and every machine.
+//!
+//! ```
+//! use fixtures::Dataset;
+//!
+//! #[derive(Debug)]
+//! struct Data(u64);
+//!
+//! impl From<u64> for Data {
+//!     fn from(value: u64) -> Self {
+//!         Data(value)
+//!     }
+//! }
+//!
+//! let data: Vec<Data> = fixtures::data(Dataset::Small);
+//! let numbers = fixtures::numbers(Dataset::Large);
+//! assert_eq!(data.len(), 4);
+//! assert_eq!(numbers.len(), 1_000_000);
+//! ```
+
+pub mod accounts;
+pub mod text;
+
+/// How big a dataset is, or how it's spread out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dataset {
+    Small,
+    Medium,
+    Large,
+    /// A few values come up far more often than the rest, or are far bigger, like hot keys in a
+    /// cache or the one slow request, which is what trips up splitting work evenly
+    Skewed,
+}
+
+impl Dataset {
+    pub const ALL: [Dataset; 4] = [
+        Dataset::Small,
+        Dataset::Medium,
+        Dataset::Large,
+        Dataset::Skewed,
+    ];
+
+    /// What the seeds of the dataset's random values are mixed with, so that they differ
+    fn seed(self) -> u64 {
+        match self {
+            Self::Small | Self::Medium | Self::Large => 0x5eed,
+            Self::Skewed => 0x5eed_5eed,
+        }
+    }
+}
+
+/// How many items `data` has, which is few, since every item is slow to work on, like the
+/// half a second `part_5::calculate` takes
+pub fn data_len(dataset: Dataset) -> usize {
+    match dataset {
+        Dataset::Small => 4,
+        Dataset::Medium | Dataset::Skewed => 8,
+        Dataset::Large => 100,
+    }
+}
+
+/// Items for the parts working on `part_5::Data`, numbered from 1, since some parts refuse `Data(0)`.
+/// In the skewed dataset, every fourth is a thousand times bigger than the others.
+pub fn data<T: From<u64>>(dataset: Dataset) -> Vec<T> {
+    (1..=data_len(dataset) as u64)
+        .map(|i| match dataset {
+            Dataset::Skewed if i % 4 == 1 => 1000 * i,
+            _ => i,
+        })
+        .map(T::from)
+        .collect()
+}
+
+/// How many numbers `numbers` has, which is plenty, since each is quick to work on
+pub fn numbers_len(dataset: Dataset) -> usize {
+    match dataset {
+        Dataset::Small => 1_000,
+        Dataset::Medium => 100_000,
+        Dataset::Large | Dataset::Skewed => 1_000_000,
+    }
+}
+
+/// Numbers to sort, sum, scan or use as keys. They all fit in a `u32`, so that a million of them
+/// add up to less than a `u64` holds, and the smaller datasets are the start of the larger ones.
+/// In the skewed dataset, nine in ten numbers are one of 16 values.
+pub fn numbers(dataset: Dataset) -> Vec<u64> {
+    let len = numbers_len(dataset);
+    let numbers = common::datagen::numbers_below(len, 1 << 32, dataset.seed());
+    match dataset {
+        Dataset::Skewed => {
+            let picks = common::datagen::numbers_below(len, 10, dataset.seed() + 1);
+            numbers
+                .into_iter()
+                .zip(picks)
+                .map(|(number, pick)| if pick == 0 { number } else { number % 16 })
+                .collect()
+        }
+        _ => numbers,
+    }
+}
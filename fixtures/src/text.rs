@@ -0,0 +1,73 @@
+//! Text to count words in, search, or pass between threads line by line
+
+use crate::Dataset;
+
+/// The words the text is made of, from short to long, with some which only differ in case
+const WORDS: [&str; 32] = [
+    "a",
+    "I",
+    "of",
+    "to",
+    "in",
+    "it",
+    "is",
+    "be",
+    "the",
+    "and",
+    "for",
+    "not",
+    "The",
+    "And",
+    "with",
+    "have",
+    "that",
+    "this",
+    "from",
+    "they",
+    "would",
+    "there",
+    "their",
+    "about",
+    "which",
+    "thread",
+    "Thread",
+    "channel",
+    "ownership",
+    "borrowing",
+    "concurrency",
+    "parallelism",
+];
+
+/// How many lines `lines` has
+pub fn lines_len(dataset: Dataset) -> usize {
+    match dataset {
+        Dataset::Small => 10,
+        Dataset::Medium | Dataset::Skewed => 1_000,
+        Dataset::Large => 100_000,
+    }
+}
+
+/// Lines of 1 to 16 words, picked at random. In the skewed dataset, every hundredth line is a
+/// hundred times longer than the others.
+pub fn lines(dataset: Dataset) -> Vec<String> {
+    let len = lines_len(dataset);
+    let mut picks = common::datagen::numbers(len * 17, dataset.seed()).into_iter();
+    (0..len)
+        .map(|line| {
+            let words = (picks.next().unwrap() % 16 + 1) as usize;
+            let words = match dataset {
+                Dataset::Skewed if line % 100 == 0 => words * 100,
+                _ => words,
+            };
+            (0..words)
+                .map(|_| WORDS[(picks.next().unwrap_or(line as u64) % 32) as usize])
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect()
+}
+
+/// The lines as one text, each ending with a newline
+pub fn corpus(dataset: Dataset) -> String {
+    lines(dataset).into_iter().map(|line| line + "\n").collect()
+}
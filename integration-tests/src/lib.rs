@@ -0,0 +1,100 @@
+//! Checks that the parts which solve the same problem get the same results, on the datasets from
+//! `fixtures`, so that a change to one of them which makes it do something else is caught even
+//! when its own tests still pass. `cargo test -p integration-tests --features solutions` compares
+//! the solutions too.
+//!
+//! Exercises which haven't been implemented yet panic with `todo!()`, and are left out, like in
+//! the benchmarks.
+
+use std::{future::Future, sync::mpsc};
+
+use part_5::{calculate, ComputationResult, Data};
+
+/// How many threads the implementations which take a number of them get
+pub const THREADS: usize = 4;
+
+/// Every implementation of `part_5::serial_calculate`
+pub type Calculate = fn(Vec<Data>) -> Vec<ComputationResult>;
+
+/// The implementations of part 5's calculations, by name. The first is the serial one, which is
+/// what the others are compared with.
+pub fn calculations() -> Vec<(&'static str, Calculate)> {
+    // Only added to with the features
+    #[allow(unused_mut)]
+    let mut calculations: Vec<(&'static str, Calculate)> = vec![
+        ("Part 5, serial", part_5::serial_calculate),
+        ("Part 5, a thread per datum", part_5::parallel_calculate),
+        ("Part 16, on the thread pool", pool_calculate),
+        ("Part 17, dealt to workers", |data| {
+            part_17::run_static(data, THREADS, calculate)
+        }),
+        ("Part 17, with work stealing", |data| {
+            part_17::stealing_calculate(data, THREADS)
+        }),
+        ("Part 17, through part 11's bounded queue", |data| {
+            part_17::parallel_calculate_queued(data, THREADS)
+        }),
+        ("Part 6, with Rayon", part_6::rayon_parallel_calculate),
+        ("Part 29, as tokio tasks", |data| {
+            on_runtime(part_29::join_set_calculate(data))
+        }),
+    ];
+    #[cfg(feature = "advanced")]
+    calculations.push(("Part 17, with crossbeam-deque", |data| {
+        part_17::run_crossbeam(data, THREADS, calculate)
+    }));
+    #[cfg(feature = "solutions")]
+    calculations.extend([
+        (
+            "Part 5's solution, a thread per datum",
+            part_5::solutions::parallel_calculate as Calculate,
+        ),
+        ("Part 16's solution, on the thread pool", |data| {
+            let pool = part_16::solutions::ThreadPool::new(THREADS);
+            on_pool(|job| pool.execute(job), data)
+        }),
+        ("Part 17's solution, with work stealing", |data| {
+            part_17::solutions::stealing_calculate(data, THREADS)
+        }),
+        (
+            "Part 17's solution, through part 11's bounded queue",
+            |data| part_17::solutions::parallel_calculate_queued(data, THREADS),
+        ),
+        (
+            "Part 6's solution, with Rayon",
+            part_6::solutions::rayon_parallel_calculate as Calculate,
+        ),
+        ("Part 29's solution, as tokio tasks", |data| {
+            on_runtime(part_29::solutions::join_set_calculate(data))
+        }),
+    ]);
+    calculations
+}
+
+/// Runs one of part 29's async calculations to the end, on a runtime of its own
+fn on_runtime(calculation: impl Future<Output = Vec<ComputationResult>>) -> Vec<ComputationResult> {
+    tokio::runtime::Runtime::new()
+        .expect("Couldn't start tokio")
+        .block_on(calculation)
+}
+
+/// Calculates every datum as a job on part 16's thread pool
+fn pool_calculate(data: Vec<Data>) -> Vec<ComputationResult> {
+    let pool = part_16::ThreadPool::new(THREADS);
+    on_pool(|job| pool.execute(job), data)
+}
+
+/// Calculates every datum as a job given to `execute`, which is the `execute` of one of the pools
+/// from part 16 or its solution
+fn on_pool(execute: impl Fn(Job), data: Vec<Data>) -> Vec<ComputationResult> {
+    let (sender, receiver) = mpsc::channel();
+    for (i, datum) in data.into_iter().enumerate() {
+        let sender = sender.clone();
+        execute(Box::new(move || _ = sender.send((i, calculate(datum)))));
+    }
+    // A job the pool loses drops its sender, so this ends with fewer results instead of hanging
+    drop(sender);
+    part_17::in_order(receiver)
+}
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
@@ -0,0 +1,22 @@
+use fixtures::Dataset;
+use part_5::Data;
+
+/// Every calculation takes half a second, so only the datasets with a few items
+const DATASETS: [Dataset; 2] = [Dataset::Small, Dataset::Skewed];
+
+#[test]
+fn calculations_match() {
+    let calculations = integration_tests::calculations();
+    let (_, serial) = calculations[0];
+    for dataset in DATASETS {
+        let data: Vec<Data> = fixtures::data(dataset);
+        let expected = serial(data.clone());
+        for &(name, calculate) in &calculations[1..] {
+            let Some(results) = common::implemented(|| calculate(data.clone())) else {
+                println!("Skipping {name}, since it hasn't been implemented yet");
+                continue;
+            };
+            assert_eq!(results, expected, "{name} on the {dataset:?} dataset");
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use common::OutputLog;
+
+#[cfg(all(test, feature = "solutions"))]
+#[path = "scheduling/solutions.rs"]
+mod solutions;
+
+fn main() {
+    for pause in [
+        Pause::None,
+        Pause::Yield,
+        Pause::Sleep(Duration::ZERO),
+        Pause::Sleep(Duration::from_millis(1)),
+    ] {
+        println!("Pausing with {pause:?}:");
+        log_progress(&OutputLog::new(), 3, 5, pause);
+        println!();
+    }
+}
+
+/// What a thread does between each step to let other threads run
+#[derive(Debug, Clone, Copy)]
+enum Pause {
+    /// Just carry on
+    None,
+    /// `std::thread::yield_now`
+    Yield,
+    /// `std::thread::sleep`
+    Sleep(Duration),
+}
+
+/// Name of worker thread number `worker`
+fn worker_name(worker: usize) -> String {
+    format!("worker-{worker}")
+}
+
+/// Spawns `threads` threads named with `worker_name`, which each log
+/// `step 0`, `step 1`, ..., up to `steps`, pausing between every step.
+/// Returns once every thread is done.
+fn log_progress(log: &OutputLog, threads: usize, steps: usize, pause: Pause) {
+    todo!()
+}
+
+#[cfg(test)]
+fn check_progress(pause: Pause) {
+    let log = OutputLog::new();
+    log_progress(&log, 4, 10, pause);
+
+    let expected: Vec<String> = (0..10).map(|step| format!("step {step}")).collect();
+    assert_eq!(log.entries().len(), 4 * 10);
+    for worker in 0..4 {
+        // Steps within one thread always happen in order, but there's
+        // no telling in what order the threads themselves take turns
+        assert_eq!(log.messages_from(&worker_name(worker)), expected);
+    }
+}
+
+#[test]
+fn logs_every_step_in_order_without_pause() {
+    check_progress(Pause::None);
+}
+
+#[test]
+fn logs_every_step_in_order_when_yielding() {
+    check_progress(Pause::Yield);
+}
+
+#[test]
+fn logs_every_step_in_order_when_sleeping() {
+    check_progress(Pause::Sleep(Duration::ZERO));
+    check_progress(Pause::Sleep(Duration::from_millis(1)));
+}
+
+#[cfg(feature = "solutions")]
+#[test]
+fn logs_like_the_solution() {
+    let (log, expected) = (OutputLog::new(), OutputLog::new());
+    log_progress(&log, 3, 20, Pause::Yield);
+    solutions::log_progress(&expected, 3, 20, Pause::Yield);
+
+    assert_eq!(log.entries().len(), expected.entries().len());
+    for worker in 0..3 {
+        let name = worker_name(worker);
+        assert_eq!(log.messages_from(&name), expected.messages_from(&name));
+    }
+}
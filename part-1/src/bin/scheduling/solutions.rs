@@ -0,0 +1,35 @@
+//! `scheduling`, with every exercise implemented. Only built with `--features solutions`.
+
+use std::time::Duration;
+
+use common::OutputLog;
+
+use super::{worker_name, Pause};
+
+/// Spawns `threads` threads named with `worker_name`, which each log
+/// `step 0`, `step 1`, ..., up to `steps`, pausing between every step.
+/// Returns once every thread is done.
+pub fn log_progress(log: &OutputLog, threads: usize, steps: usize, pause: Pause) {
+    let handles: Vec<_> = (0..threads)
+        .map(|worker| {
+            let log = log.clone();
+            std::thread::Builder::new()
+                .name(worker_name(worker))
+                .spawn(move || {
+                    for step in 0..steps {
+                        log.log(format!("step {step}"));
+                        match pause {
+                            Pause::None => (),
+                            Pause::Yield => std::thread::yield_now(),
+                            Pause::Sleep(duration) => std::thread::sleep(duration),
+                        }
+                    }
+                })
+                .expect("Couldn't spawn thread")
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().expect("Could not join thread, it panicked!");
+    }
+}
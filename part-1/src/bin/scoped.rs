@@ -0,0 +1,44 @@
+use std::thread::ThreadId;
+
+#[cfg(all(test, feature = "solutions"))]
+#[path = "scoped/solutions.rs"]
+mod solutions;
+
+fn main() {
+    // This vector lives on the stack of `main`, and we still want to use it after the thread is done
+    let numbers = vec![1, 2, 3, 4, 5];
+    let (sum, thread) = sum_on_thread(&numbers);
+    println!("Sum of {numbers:?} is {sum}, calculated by thread {thread:?}");
+}
+
+/// Sums `numbers` on a spawned thread and returns the sum
+/// together with the id of the thread that calculated it.
+/// `numbers` must not be moved or cloned!
+fn sum_on_thread(numbers: &[i32]) -> (i32, ThreadId) {
+    todo!()
+}
+
+#[test]
+fn sums_on_another_thread() {
+    let numbers = vec![1, 2, 3, 4, 5];
+    let (sum, thread) = sum_on_thread(&numbers);
+
+    assert_eq!(sum, 15);
+    assert_ne!(thread, std::thread::current().id());
+    // Still ours, since it was only borrowed
+    assert_eq!(numbers, vec![1, 2, 3, 4, 5]);
+}
+
+#[cfg(feature = "solutions")]
+#[test]
+fn sums_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let numbers: Vec<i32> = common::datagen::numbers_below(10_000, 1000, rng.seed())
+        .into_iter()
+        .map(|number| number as i32)
+        .collect();
+    assert_eq!(
+        sum_on_thread(&numbers).0,
+        solutions::sum_on_thread(&numbers).0
+    );
+}
@@ -0,0 +1,8 @@
+// `std::thread::spawn` requires its closure to be `'static`,
+// so it can't borrow `numbers` which only lives until the end of `main`.
+fn main() {
+    let numbers = vec![1, 2, 3, 4, 5];
+    let handle = std::thread::spawn(|| numbers.iter().sum::<i32>());
+    let sum = handle.join().unwrap();
+    println!("Sum of {numbers:?} is {sum}");
+}
@@ -0,0 +1,79 @@
+// Under `--cfg loom` the atomics are swapped for loom's, which lets loom
+// explore every way the threads in a test can interleave.
+#[cfg(loom)]
+use loom::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+#[cfg(not(loom))]
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Passes a single value from one thread to another.
+/// `data` holds the value, and `ready` says whether it has been written yet.
+#[derive(Debug)]
+pub struct Mailbox {
+    data: AtomicU64,
+    ready: AtomicBool,
+}
+
+impl Mailbox {
+    pub fn new() -> Self {
+        Self {
+            data: AtomicU64::new(0),
+            ready: AtomicBool::new(false),
+        }
+    }
+
+    /// Puts `value` in the mailbox. Only call this once!
+    pub fn send(&self, value: u64) {
+        todo!()
+    }
+
+    /// Returns the sent value if it is ready, otherwise `None`.
+    /// Should never return anything other than what was sent.
+    pub fn receive(&self) -> Option<u64> {
+        todo!()
+    }
+}
+
+impl Default for Mailbox {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The same as `Mailbox`, but everything is `Relaxed`.
+/// Don't use this! Nothing stops `receive` from seeing `ready` before it sees `data`.
+#[derive(Debug)]
+pub struct RelaxedMailbox {
+    data: AtomicU64,
+    ready: AtomicBool,
+}
+
+impl RelaxedMailbox {
+    pub fn new() -> Self {
+        Self {
+            data: AtomicU64::new(0),
+            ready: AtomicBool::new(false),
+        }
+    }
+
+    pub fn send(&self, value: u64) {
+        self.data.store(value, Ordering::Relaxed);
+        self.ready.store(true, Ordering::Relaxed);
+    }
+
+    pub fn receive(&self) -> Option<u64> {
+        if self.ready.load(Ordering::Relaxed) {
+            Some(self.data.load(Ordering::Relaxed))
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for RelaxedMailbox {
+    fn default() -> Self {
+        Self::new()
+    }
+}
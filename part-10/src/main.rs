@@ -0,0 +1,82 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+fn main() {
+    let args = common::cli::Cli::new("Runs the store buffering experiment with each ordering")
+        .items(100_000)
+        .parse();
+    let iterations = args.items();
+    for (store, load) in [
+        (Ordering::Relaxed, Ordering::Relaxed),
+        (Ordering::Release, Ordering::Acquire),
+        (Ordering::SeqCst, Ordering::SeqCst),
+    ] {
+        let both_missed = store_buffering(store, load, iterations);
+        println!("{store:?} stores, {load:?} loads: both threads missed the other's store {both_missed} out of {iterations} times");
+    }
+}
+
+/// Runs the "store buffering" experiment `iterations` times: two threads each
+/// set their own flag and then check the other thread's flag.
+/// Returns the amount of times neither thread saw the other's flag as set,
+/// which looks like it should be impossible!
+fn store_buffering(store: Ordering, load: Ordering, iterations: usize) -> usize {
+    let x = AtomicBool::new(false);
+    let y = AtomicBool::new(false);
+    let barrier = std::sync::Barrier::new(2);
+    let mut both_missed = 0;
+
+    for _ in 0..iterations {
+        x.store(false, Ordering::SeqCst);
+        y.store(false, Ordering::SeqCst);
+
+        let (saw_y, saw_x) = std::thread::scope(|s| {
+            let first = s.spawn(|| {
+                barrier.wait();
+                x.store(true, store);
+                y.load(load)
+            });
+            let second = s.spawn(|| {
+                barrier.wait();
+                y.store(true, store);
+                x.load(load)
+            });
+            (first.join().unwrap(), second.join().unwrap())
+        });
+
+        if !saw_x && !saw_y {
+            both_missed += 1;
+        }
+    }
+
+    both_missed
+}
+
+#[test]
+fn store_buffering_never_happens_with_seq_cst() {
+    assert_eq!(store_buffering(Ordering::SeqCst, Ordering::SeqCst, 1000), 0);
+}
+
+#[test]
+fn mailbox_is_empty_until_sent() {
+    let mailbox = part_10::Mailbox::new();
+    assert_eq!(mailbox.receive(), None);
+    mailbox.send(42);
+    assert_eq!(mailbox.receive(), Some(42));
+}
+
+#[test]
+fn mailbox_passes_value_between_threads() {
+    let mailbox = part_10::Mailbox::new();
+    let value = std::thread::scope(|s| {
+        s.spawn(|| mailbox.send(42));
+        s.spawn(|| loop {
+            if let Some(value) = mailbox.receive() {
+                break value;
+            }
+            std::hint::spin_loop();
+        })
+        .join()
+        .unwrap()
+    });
+    assert_eq!(value, 42);
+}
@@ -0,0 +1,50 @@
+//! Part 10, with every exercise implemented. Only built with `--features solutions`.
+
+// Under `--cfg loom` the atomics are swapped for loom's, which lets loom
+// explore every way the threads in a test can interleave.
+#[cfg(loom)]
+use loom::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+#[cfg(not(loom))]
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+/// Passes a single value from one thread to another.
+/// `data` holds the value, and `ready` says whether it has been written yet.
+#[derive(Debug)]
+pub struct Mailbox {
+    data: AtomicU64,
+    ready: AtomicBool,
+}
+
+impl Mailbox {
+    pub fn new() -> Self {
+        Self {
+            data: AtomicU64::new(0),
+            ready: AtomicBool::new(false),
+        }
+    }
+
+    /// Puts `value` in the mailbox. Only call this once!
+    pub fn send(&self, value: u64) {
+        // The data itself doesn't synchronize anything, `ready` does that
+        self.data.store(value, Ordering::Relaxed);
+        // Release: everything written before this store is visible to whoever
+        // sees `ready` as `true` through an `Acquire` load
+        self.ready.store(true, Ordering::Release);
+    }
+
+    /// Returns the sent value if it is ready, otherwise `None`.
+    /// Should never return anything other than what was sent.
+    pub fn receive(&self) -> Option<u64> {
+        if self.ready.load(Ordering::Acquire) {
+            Some(self.data.load(Ordering::Relaxed))
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for Mailbox {
+    fn default() -> Self {
+        Self::new()
+    }
+}
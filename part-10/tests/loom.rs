@@ -0,0 +1,38 @@
+//! Run these with `RUSTFLAGS="--cfg loom" cargo test --release -p part-10 --test loom`
+#![cfg(loom)]
+
+use loom::{sync::Arc, thread};
+use part_10::{Mailbox, RelaxedMailbox};
+
+#[test]
+#[should_panic(expected = "stale data")]
+fn relaxed_mailbox_can_be_stale() {
+    loom::model(|| {
+        let mailbox = Arc::new(RelaxedMailbox::new());
+        let sender = {
+            let mailbox = mailbox.clone();
+            thread::spawn(move || mailbox.send(42))
+        };
+
+        if let Some(value) = mailbox.receive() {
+            assert_eq!(value, 42, "Received stale data");
+        }
+        sender.join().unwrap();
+    });
+}
+
+#[test]
+fn mailbox_is_never_stale() {
+    loom::model(|| {
+        let mailbox = Arc::new(Mailbox::new());
+        let sender = {
+            let mailbox = mailbox.clone();
+            thread::spawn(move || mailbox.send(42))
+        };
+
+        if let Some(value) = mailbox.receive() {
+            assert_eq!(value, 42, "Received stale data");
+        }
+        sender.join().unwrap();
+    });
+}
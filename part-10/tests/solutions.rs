@@ -0,0 +1,42 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-10 --features solutions`.
+#![cfg(all(feature = "solutions", not(loom)))]
+
+use std::{sync::Arc, thread};
+
+use part_10::{solutions, Mailbox};
+
+#[test]
+fn delivers_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for value in common::datagen::numbers(100, rng.seed()) {
+        let (mailbox, expected) = (Mailbox::new(), solutions::Mailbox::new());
+        assert_eq!(mailbox.receive(), expected.receive());
+        mailbox.send(value);
+        expected.send(value);
+        assert_eq!(mailbox.receive(), expected.receive());
+    }
+}
+
+#[test]
+fn delivers_across_threads_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for value in common::datagen::numbers(100, rng.seed()) {
+        let mailbox = Arc::new(Mailbox::new());
+        let sender = {
+            let mailbox = mailbox.clone();
+            thread::spawn(move || mailbox.send(value))
+        };
+        let received = loop {
+            if let Some(received) = mailbox.receive() {
+                break received;
+            }
+            thread::yield_now();
+        };
+        sender.join().unwrap();
+
+        let expected = solutions::Mailbox::new();
+        expected.send(value);
+        assert_eq!(Some(received), expected.receive());
+    }
+}
@@ -0,0 +1,282 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, MutexGuard,
+    },
+    thread,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How many times `Bank::snapshot` reads every account before it gives up and locks them all
+pub const SNAPSHOT_ATTEMPTS: usize = 8;
+
+/// An account's balance, along with how many transactions have changed it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Account {
+    id: usize,
+    balance: u64,
+    /// Goes up by one whenever the balance is changed
+    version: u64,
+}
+
+impl Account {
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn balance(&self) -> u64 {
+        self.balance
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+}
+
+/// Money moving between any number of accounts at once: an amount added to or taken from each of them,
+/// which adds up to zero so no money is created or lost
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transaction {
+    legs: Vec<(usize, i64)>,
+}
+
+impl Transaction {
+    /// Panics if the amounts don't add up to zero
+    pub fn new(legs: Vec<(usize, i64)>) -> Self {
+        assert_eq!(
+            legs.iter().map(|(_, amount)| amount).sum::<i64>(),
+            0,
+            "A transaction can't create or destroy money"
+        );
+        Self { legs }
+    }
+
+    /// Moves `amount` from `from` to `to`
+    pub fn transfer(from: usize, to: usize, amount: u64) -> Self {
+        Self::new(vec![(from, -(amount as i64)), (to, amount as i64)])
+    }
+
+    /// Pays `amount` from `from` to each of `to`
+    pub fn split(from: usize, to: &[usize], amount: u64) -> Self {
+        let mut legs = vec![(from, -(amount as i64) * to.len() as i64)];
+        legs.extend(to.iter().map(|&account| (account, amount as i64)));
+        Self::new(legs)
+    }
+
+    /// How much every account in the transaction changes by, ordered by account.
+    /// An account can be in several legs, so this adds them up.
+    pub fn changes(&self) -> BTreeMap<usize, i64> {
+        let mut changes = BTreeMap::new();
+        for &(account, amount) in &self.legs {
+            *changes.entry(account).or_default() += amount;
+        }
+        changes
+    }
+}
+
+/// `count` random transactions between `accounts` accounts, the same ones for the same `seed`.
+/// Most are transfers, with some splits between up to 4 accounts. Amounts are up to `max_amount`.
+/// Accounts often appear more than once, and in any order.
+pub fn random_transactions(
+    count: usize,
+    accounts: usize,
+    max_amount: u64,
+    seed: u64,
+) -> Vec<Transaction> {
+    let mut numbers = common::datagen::numbers(count * 6, seed).into_iter();
+    let mut next = move |below: u64| numbers.next().unwrap() % below;
+    (0..count)
+        .map(|_| {
+            let from = next(accounts as u64) as usize;
+            let amount = next(max_amount) + 1;
+            if next(4) == 0 {
+                let to: Vec<_> = (0..next(4) + 1)
+                    .map(|_| next(accounts as u64) as usize)
+                    .collect();
+                Transaction::split(from, &to, amount)
+            } else {
+                Transaction::transfer(from, next(accounts as u64) as usize, amount)
+            }
+        })
+        .collect()
+}
+
+/// What `Bank::apply` gives back when a transaction would take an account below zero
+#[derive(Debug, PartialEq, Eq)]
+pub struct InsufficientFunds {
+    pub account: usize,
+}
+
+/// The balance of every account at one moment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    pub balances: Vec<u64>,
+}
+
+impl Snapshot {
+    pub fn total(&self) -> u64 {
+        self.balances.iter().sum()
+    }
+}
+
+/// Accounts numbered from 0, each behind its own lock
+#[derive(Debug)]
+pub struct Bank {
+    accounts: Vec<Mutex<Account>>,
+}
+
+impl Bank {
+    /// Opens `accounts` accounts, with `balance` in each of them
+    pub fn new(accounts: usize, balance: u64) -> Self {
+        Self {
+            accounts: (0..accounts)
+                .map(|id| {
+                    Mutex::new(Account {
+                        id,
+                        balance,
+                        version: 0,
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    /// Locks every account in `ids` once, even if it's in there several times, and returns the guards ordered by account.
+    /// Must never deadlock, whichever accounts other threads are locking at the same time.
+    pub fn lock_accounts(&self, ids: &[usize]) -> Vec<MutexGuard<'_, Account>> {
+        todo!()
+    }
+
+    /// Applies every leg of `transaction` at once, or none of them if any account would go below zero
+    pub fn apply(&self, transaction: &Transaction) -> Result<(), InsufficientFunds> {
+        let changes = transaction.changes();
+        let ids: Vec<usize> = changes.keys().copied().collect();
+        let mut accounts = self.lock_accounts(&ids);
+
+        for (account, change) in accounts.iter().zip(changes.values()) {
+            if (account.balance as i64) + change < 0 {
+                return Err(InsufficientFunds {
+                    account: account.id,
+                });
+            }
+        }
+        for (account, change) in accounts.iter_mut().zip(changes.values()) {
+            account.balance = (account.balance as i64 + change) as u64;
+            account.version += 1;
+        }
+        Ok(())
+    }
+
+    /// Reads each account with only its own lock held, so it can count money in the middle of a transaction twice, or not at all
+    pub fn torn_snapshot(&self) -> Snapshot {
+        Snapshot {
+            balances: self
+                .accounts
+                .iter()
+                .map(|account| account.lock().unwrap().balance)
+                .collect(),
+        }
+    }
+
+    /// The balance of every account at a single moment, without a transaction half done.
+    /// Holds one lock at a time, and reads every account again until nothing changed in between.
+    /// Gives up after `SNAPSHOT_ATTEMPTS` tries, and locks every account instead.
+    pub fn snapshot(&self) -> Snapshot {
+        todo!()
+    }
+}
+
+/// What happened while applying transactions with `run`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Outcome {
+    pub applied: usize,
+    pub rejected: usize,
+}
+
+/// Applies every transaction in `transactions` to `bank`, split between `threads` threads
+pub fn run(bank: &Bank, transactions: &[Transaction], threads: usize) -> Outcome {
+    thread::scope(|s| {
+        let handles: Vec<_> = transactions
+            .chunks(transactions.len().div_ceil(threads).max(1))
+            .enumerate()
+            .map(|(id, chunk)| {
+                s.spawn(move || {
+                    let _worker = common::span::worker(id);
+                    let applied = chunk.iter().filter(|tx| bank.apply(tx).is_ok()).count();
+                    Outcome {
+                        applied,
+                        rejected: chunk.len() - applied,
+                    }
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("Couldn't join thread"))
+            .fold(Outcome::default(), |total, outcome| Outcome {
+                applied: total.applied + outcome.applied,
+                rejected: total.rejected + outcome.rejected,
+            })
+    })
+}
+
+/// What the auditor saw while `audit` ran
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Audit<T> {
+    /// What the audited work returned
+    pub result: T,
+    /// How many snapshots the auditor took
+    pub snapshots: usize,
+    /// The totals of the snapshots which didn't add up to what there should be
+    pub wrong_totals: Vec<u64>,
+}
+
+/// Runs `work` while an auditor thread keeps taking snapshots of `bank` with `snapshot`,
+/// and checks that they add up to `expected_total`. Takes at least one snapshot after `work` is done.
+pub fn audit<T>(
+    bank: &Bank,
+    expected_total: u64,
+    snapshot: impl Fn(&Bank) -> Snapshot + Sync,
+    work: impl FnOnce() -> T,
+) -> Audit<T> {
+    let done = AtomicBool::new(false);
+    thread::scope(|s| {
+        let auditor = s.spawn(|| {
+            let _auditor = common::span::thread("auditor");
+            let mut snapshots = 0;
+            let mut wrong_totals = Vec::new();
+            loop {
+                // Read before the snapshot, so the last one is taken after `work` is done
+                let finished = done.load(Ordering::Acquire);
+                let total = snapshot(bank).total();
+                snapshots += 1;
+                if total != expected_total {
+                    wrong_totals.push(total);
+                }
+                if finished {
+                    return (snapshots, wrong_totals);
+                }
+                thread::yield_now();
+            }
+        });
+        let result = work();
+        done.store(true, Ordering::Release);
+        let (snapshots, wrong_totals) = auditor.join().expect("The auditor panicked");
+        Audit {
+            result,
+            snapshots,
+            wrong_totals,
+        }
+    })
+}
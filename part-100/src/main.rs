@@ -0,0 +1,198 @@
+use std::time::Instant;
+
+use part_100::{audit, random_transactions, run, Bank, Snapshot};
+
+const ACCOUNTS: usize = 100;
+const BALANCE: u64 = 1000;
+const TRANSACTIONS: usize = 200_000;
+const THREADS: usize = 8;
+
+/// Applies `transactions` random transactions on `threads` threads while an auditor checks the total with `snapshot`
+fn audited(
+    name: &str,
+    snapshot: impl Fn(&Bank) -> Snapshot + Sync,
+    transactions: usize,
+    threads: usize,
+) {
+    let bank = Bank::new(ACCOUNTS, BALANCE);
+    let transactions = random_transactions(transactions, ACCOUNTS, BALANCE / 2, 100);
+    let start = Instant::now();
+    let audit = audit(&bank, ACCOUNTS as u64 * BALANCE, snapshot, || {
+        run(&bank, &transactions, threads)
+    });
+    println!(
+        "{name}: {} applied and {} rejected in {:?}, {} of {} snapshots added up wrong",
+        audit.result.applied,
+        audit.result.rejected,
+        start.elapsed(),
+        audit.wrong_totals.len(),
+        audit.snapshots
+    );
+}
+
+/// Run with `cargo run --release -p part-100` to audit a bank while it makes transactions
+fn main() {
+    common::init_tracing();
+    let args = common::cli::Cli::new("Audits a bank while it makes transactions")
+        .items(TRANSACTIONS)
+        .threads(THREADS)
+        .parse();
+    let (transactions, threads) = (args.items(), args.threads());
+    println!("{transactions} transactions between {ACCOUNTS} accounts on {threads} threads");
+    audited(
+        "Reading one account at a time",
+        Bank::torn_snapshot,
+        transactions,
+        threads,
+    );
+    audited(
+        "Consistent snapshots",
+        Bank::snapshot,
+        transactions,
+        threads,
+    );
+}
+
+#[cfg(test)]
+const TIMEOUT: std::time::Duration = std::time::Duration::from_secs(20);
+
+#[test]
+fn applies_transactions() {
+    use part_100::{InsufficientFunds, Transaction};
+
+    let bank = Bank::new(4, 100);
+    assert_eq!(bank.apply(&Transaction::transfer(0, 1, 30)), Ok(()));
+    assert_eq!(bank.apply(&Transaction::split(1, &[0, 2, 3], 40)), Ok(()));
+    assert_eq!(
+        bank.apply(&Transaction::transfer(1, 2, 11)),
+        Err(InsufficientFunds { account: 1 })
+    );
+    // Paying yourself, and paying the same account twice
+    assert_eq!(bank.apply(&Transaction::transfer(3, 3, 500)), Ok(()));
+    assert_eq!(bank.apply(&Transaction::split(2, &[0, 0], 70)), Ok(()));
+    assert_eq!(bank.snapshot().balances, [250, 10, 0, 140]);
+}
+
+#[test]
+fn rejected_transactions_change_nothing() {
+    use part_100::{InsufficientFunds, Transaction};
+
+    let bank = Bank::new(3, 100);
+    let overdraft = Transaction::new(vec![(0, 50), (1, -10), (2, -150), (1, 110)]);
+    assert_eq!(
+        bank.apply(&overdraft),
+        Err(InsufficientFunds { account: 2 })
+    );
+    assert_eq!(bank.snapshot().balances, [100, 100, 100]);
+}
+
+#[test]
+fn locks_every_account_once_in_order() {
+    let bank = Bank::new(10, 1);
+    let accounts = bank.lock_accounts(&[7, 2, 7, 9, 2, 0]);
+    let ids: Vec<_> = accounts.iter().map(|account| account.id()).collect();
+    assert_eq!(ids, [0, 2, 7, 9]);
+    drop(accounts);
+    assert_eq!(bank.lock_accounts(&[]).len(), 0);
+}
+
+#[test]
+fn opposite_transactions_do_not_deadlock() {
+    use part_100::Transaction;
+
+    let finished = common::with_timeout(TIMEOUT, || {
+        let bank = Bank::new(3, 1_000_000);
+        let forwards = [
+            Transaction::transfer(0, 1, 1),
+            Transaction::split(0, &[1, 2], 1),
+        ];
+        let backwards = [
+            Transaction::transfer(1, 0, 1),
+            Transaction::split(2, &[1, 0], 1),
+        ];
+        std::thread::scope(|s| {
+            for transactions in [&forwards, &backwards] {
+                let bank = &bank;
+                s.spawn(move || {
+                    for _ in 0..20_000 {
+                        for transaction in transactions {
+                            bank.apply(transaction).unwrap();
+                        }
+                    }
+                });
+            }
+        });
+        bank.snapshot().total()
+    });
+    assert_eq!(finished, Some(3_000_000), "Transactions deadlocked");
+}
+
+#[test]
+fn random_transactions_conserve_money() {
+    let finished = common::with_timeout(TIMEOUT, || {
+        let bank = Bank::new(ACCOUNTS, BALANCE);
+        let transactions = random_transactions(50_000, ACCOUNTS, BALANCE, 1);
+        let outcome = run(&bank, &transactions, THREADS);
+        (outcome, bank.snapshot())
+    });
+    let (outcome, snapshot) = finished.expect("Transactions deadlocked");
+    assert_eq!(outcome.applied + outcome.rejected, 50_000);
+    assert!(outcome.rejected > 0, "Nothing was ever overdrawn");
+    assert_eq!(snapshot.total(), ACCOUNTS as u64 * BALANCE);
+}
+
+#[test]
+fn snapshot_of_a_quiet_bank() {
+    use part_100::Transaction;
+
+    let bank = Bank::new(5, 10);
+    bank.apply(&Transaction::split(4, &[0, 1, 2], 3)).unwrap();
+    let snapshot = bank.snapshot();
+    assert_eq!(snapshot, bank.torn_snapshot());
+    assert_eq!(snapshot.balances, [13, 13, 13, 10, 1]);
+}
+
+#[test]
+fn auditor_never_sees_money_created_or_lost() {
+    // Few accounts, so transactions keep hitting the ones the auditor reads
+    const ACCOUNTS: usize = 8;
+
+    let finished = common::with_timeout(TIMEOUT, || {
+        let bank = Bank::new(ACCOUNTS, BALANCE);
+        let transactions = random_transactions(50_000, ACCOUNTS, BALANCE / 2, 2);
+        audit(&bank, ACCOUNTS as u64 * BALANCE, Bank::snapshot, || {
+            run(&bank, &transactions, THREADS)
+        })
+    });
+    let audit = finished.expect("Transactions or snapshots deadlocked");
+    assert!(audit.snapshots > 1);
+    assert!(
+        audit.wrong_totals.is_empty(),
+        "{} of {} snapshots added up wrong, like {:?}",
+        audit.wrong_totals.len(),
+        audit.snapshots,
+        &audit.wrong_totals[..audit.wrong_totals.len().min(5)]
+    );
+}
+
+#[test]
+fn several_auditors_while_transactions_run() {
+    let finished = common::with_timeout(TIMEOUT, || {
+        let bank = Bank::new(ACCOUNTS, BALANCE);
+        let transactions = random_transactions(20_000, ACCOUNTS, BALANCE / 2, 3);
+        let expected = ACCOUNTS as u64 * BALANCE;
+        // Auditors inside auditors, so snapshots also race each other
+        audit(&bank, expected, Bank::snapshot, || {
+            audit(&bank, expected, Bank::snapshot, || {
+                run(&bank, &transactions, THREADS)
+            })
+        })
+    });
+    let outer = finished.expect("Transactions or snapshots deadlocked");
+    assert!(outer.wrong_totals.is_empty(), "{:?}", outer.wrong_totals);
+    assert!(
+        outer.result.wrong_totals.is_empty(),
+        "{:?}",
+        outer.result.wrong_totals
+    );
+}
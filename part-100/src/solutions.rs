@@ -0,0 +1,190 @@
+//! Part 100, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, MutexGuard,
+    },
+    thread,
+};
+
+use super::{Account, Audit, InsufficientFunds, Outcome, Snapshot, Transaction, SNAPSHOT_ATTEMPTS};
+
+/// Accounts numbered from 0, each behind its own lock
+#[derive(Debug)]
+pub struct Bank {
+    accounts: Vec<Mutex<Account>>,
+}
+
+impl Bank {
+    /// Opens `accounts` accounts, with `balance` in each of them
+    pub fn new(accounts: usize, balance: u64) -> Self {
+        Self {
+            accounts: (0..accounts)
+                .map(|id| {
+                    Mutex::new(Account {
+                        id,
+                        balance,
+                        version: 0,
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    /// Locks every account in `ids` once, even if it's in there several times, and returns the guards ordered by account.
+    /// Must never deadlock, whichever accounts other threads are locking at the same time.
+    pub fn lock_accounts(&self, ids: &[usize]) -> Vec<MutexGuard<'_, Account>> {
+        let mut ids = ids.to_vec();
+        ids.sort_unstable();
+        // Locking the same `Mutex` twice would deadlock on its own
+        ids.dedup();
+        // Every thread locks accounts in ascending order, so a thread holding an account
+        // is only ever waiting for higher ones, and no two threads can wait for each other
+        ids.into_iter()
+            .map(|id| self.accounts[id].lock().unwrap())
+            .collect()
+    }
+
+    /// Applies every leg of `transaction` at once, or none of them if any account would go below zero
+    pub fn apply(&self, transaction: &Transaction) -> Result<(), InsufficientFunds> {
+        let changes = transaction.changes();
+        let ids: Vec<usize> = changes.keys().copied().collect();
+        let mut accounts = self.lock_accounts(&ids);
+
+        for (account, change) in accounts.iter().zip(changes.values()) {
+            if (account.balance as i64) + change < 0 {
+                return Err(InsufficientFunds {
+                    account: account.id,
+                });
+            }
+        }
+        for (account, change) in accounts.iter_mut().zip(changes.values()) {
+            account.balance = (account.balance as i64 + change) as u64;
+            account.version += 1;
+        }
+        Ok(())
+    }
+
+    /// Reads each account with only its own lock held, so it can count money in the middle of a transaction twice, or not at all
+    pub fn torn_snapshot(&self) -> Snapshot {
+        Snapshot {
+            balances: self
+                .accounts
+                .iter()
+                .map(|account| account.lock().unwrap().balance)
+                .collect(),
+        }
+    }
+
+    /// The balance of every account at a single moment, without a transaction half done.
+    /// Holds one lock at a time, and reads every account again until nothing changed in between.
+    /// Gives up after `SNAPSHOT_ATTEMPTS` tries, and locks every account instead.
+    pub fn snapshot(&self) -> Snapshot {
+        let read_all = || -> Vec<Account> {
+            self.accounts
+                .iter()
+                .map(|account| *account.lock().unwrap())
+                .collect()
+        };
+        let balances = |accounts: &[Account]| accounts.iter().map(Account::balance).collect();
+
+        let mut previous = read_all();
+        for _ in 1..SNAPSHOT_ATTEMPTS {
+            let current = read_all();
+            // Comparing versions rather than only balances, since a balance can change and change back.
+            // If no account changed between reading it the first time and the second, they all had
+            // these balances at the moment the second read started.
+            if current == previous {
+                return Snapshot {
+                    balances: balances(&current),
+                };
+            }
+            previous = current;
+        }
+
+        let every_account: Vec<usize> = (0..self.len()).collect();
+        let accounts: Vec<Account> = self
+            .lock_accounts(&every_account)
+            .iter()
+            .map(|account| **account)
+            .collect();
+        Snapshot {
+            balances: balances(&accounts),
+        }
+    }
+}
+
+/// Applies every transaction in `transactions` to `bank`, split between `threads` threads
+pub fn run(bank: &Bank, transactions: &[Transaction], threads: usize) -> Outcome {
+    thread::scope(|s| {
+        let handles: Vec<_> = transactions
+            .chunks(transactions.len().div_ceil(threads).max(1))
+            .enumerate()
+            .map(|(id, chunk)| {
+                s.spawn(move || {
+                    let _worker = common::span::worker(id);
+                    let applied = chunk.iter().filter(|tx| bank.apply(tx).is_ok()).count();
+                    Outcome {
+                        applied,
+                        rejected: chunk.len() - applied,
+                    }
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("Couldn't join thread"))
+            .fold(Outcome::default(), |total, outcome| Outcome {
+                applied: total.applied + outcome.applied,
+                rejected: total.rejected + outcome.rejected,
+            })
+    })
+}
+
+/// Runs `work` while an auditor thread keeps taking snapshots of `bank` with `snapshot`,
+/// and checks that they add up to `expected_total`. Takes at least one snapshot after `work` is done.
+pub fn audit<T>(
+    bank: &Bank,
+    expected_total: u64,
+    snapshot: impl Fn(&Bank) -> Snapshot + Sync,
+    work: impl FnOnce() -> T,
+) -> Audit<T> {
+    let done = AtomicBool::new(false);
+    thread::scope(|s| {
+        let auditor = s.spawn(|| {
+            let _auditor = common::span::thread("auditor");
+            let mut snapshots = 0;
+            let mut wrong_totals = Vec::new();
+            loop {
+                // Read before the snapshot, so the last one is taken after `work` is done
+                let finished = done.load(Ordering::Acquire);
+                let total = snapshot(bank).total();
+                snapshots += 1;
+                if total != expected_total {
+                    wrong_totals.push(total);
+                }
+                if finished {
+                    return (snapshots, wrong_totals);
+                }
+                thread::yield_now();
+            }
+        });
+        let result = work();
+        done.store(true, Ordering::Release);
+        let (snapshots, wrong_totals) = auditor.join().expect("The auditor panicked");
+        Audit {
+            result,
+            snapshots,
+            wrong_totals,
+        }
+    })
+}
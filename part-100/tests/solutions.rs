@@ -0,0 +1,68 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-100 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::thread;
+
+use part_100::{random_transactions, solutions, Bank, Transaction};
+
+const ACCOUNTS: usize = 16;
+
+#[test]
+fn locks_like_the_solution() {
+    let (bank, expected) = (
+        Bank::new(ACCOUNTS, 100),
+        solutions::Bank::new(ACCOUNTS, 100),
+    );
+    for ids in [vec![3, 1, 2], vec![5, 5, 0, 5], vec![], vec![15, 0]] {
+        let locked: Vec<_> = bank
+            .lock_accounts(&ids)
+            .iter()
+            .map(|account| account.id())
+            .collect();
+        let expected: Vec<_> = expected
+            .lock_accounts(&ids)
+            .iter()
+            .map(|account| account.id())
+            .collect();
+        assert_eq!(locked, expected, "Locking {ids:?}");
+    }
+}
+
+#[test]
+fn applies_like_the_solution() {
+    let (bank, expected) = (
+        Bank::new(ACCOUNTS, 100),
+        solutions::Bank::new(ACCOUNTS, 100),
+    );
+    for transaction in random_transactions(1000, ACCOUNTS, 150, 100) {
+        let legs: Vec<_> = transaction.changes().into_iter().collect();
+        assert_eq!(
+            format!("{:?}", bank.apply(&transaction)),
+            format!("{:?}", expected.apply(&Transaction::new(legs))),
+            "Applying {transaction:?}"
+        );
+        assert_eq!(bank.snapshot().balances, expected.snapshot().balances);
+    }
+}
+
+#[test]
+fn snapshots_like_the_solution() {
+    let bank = Bank::new(ACCOUNTS, 100);
+    let transactions = random_transactions(5000, ACCOUNTS, 50, 100);
+    thread::scope(|s| {
+        for chunk in transactions.chunks(1250) {
+            let bank = &bank;
+            s.spawn(move || {
+                for transaction in chunk {
+                    let _ = bank.apply(transaction);
+                }
+            });
+        }
+        for _ in 0..100 {
+            assert_eq!(bank.snapshot().total(), 100 * ACCOUNTS as u64);
+        }
+    });
+    let expected = solutions::Bank::new(ACCOUNTS, 100);
+    assert_eq!(bank.snapshot().total(), expected.snapshot().total());
+}
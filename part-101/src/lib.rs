@@ -0,0 +1,60 @@
+use std::{
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use common::chaos::{self, Chaos, ChaosSender, Fault};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How long `run_pipeline` waits for a result before it sends the jobs it's still missing again
+pub const RESEND_AFTER: Duration = Duration::from_millis(50);
+
+/// An item to process, and where its result goes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Job {
+    pub index: usize,
+    pub item: u64,
+}
+
+/// The work: squares `item`, unless `chaos` gets in the way.
+/// It may take longer, fail with the fault, or panic.
+pub fn process(chaos: &Chaos, item: u64) -> Result<u64, Fault> {
+    chaos.work(|| item * item)
+}
+
+/// Processes `item` until it works out, trying again whenever `process` fails, or panics
+pub fn process_reliably(chaos: &Chaos, item: u64) -> u64 {
+    todo!()
+}
+
+/// Takes jobs from `jobs` until it disconnects, and sends their results to `results`,
+/// which loses some of them. Stops early if nobody is waiting for results anymore.
+pub fn worker(
+    jobs: &Mutex<mpsc::Receiver<Job>>,
+    results: &ChaosSender<(usize, u64)>,
+    work: &Chaos,
+) {
+    loop {
+        // Only one worker waits for a job at a time, the others wait for the lock
+        let job = jobs.lock().unwrap().recv();
+        let Ok(job) = job else {
+            return;
+        };
+        let result = process_reliably(work, job.item);
+        if results.send((job.index, result)).is_err() {
+            return;
+        }
+    }
+}
+
+/// Squares every item on `workers` threads running `worker`, and returns the results in the same
+/// order as the items, however the chaos misbehaves. The work goes wrong whenever `work` says so,
+/// and the results come back through a `chaos::channel` with `results`, which loses some of them.
+/// Whenever no result has come for `RESEND_AFTER`, the jobs which are still missing are sent out
+/// again, so some results may come more than once.
+pub fn run_pipeline(items: &[u64], workers: usize, work: &Chaos, results: Arc<Chaos>) -> Vec<u64> {
+    todo!()
+}
@@ -0,0 +1,112 @@
+use std::{sync::Arc, time::Duration};
+
+use common::chaos::Chaos;
+use part_101::run_pipeline;
+
+/// Run with `cargo run --release -p part-101`
+fn main() {
+    let args = common::cli::Cli::new("Squares numbers in a pipeline under chaos")
+        .items(1000)
+        .threads(8)
+        .parse();
+    let items: Vec<u64> = (0..args.items() as u64).collect();
+    let work = Chaos::new(101)
+        .errors(0.2)
+        .panics(0.05)
+        .delays(0.05, Duration::from_millis(10));
+    let results = Arc::new(Chaos::new(102).drops(0.2));
+
+    common::chaos::quiet_panics();
+    let threads = args.threads();
+    let squares = common::timed(
+        &format!("Squaring under chaos on {threads} threads"),
+        || run_pipeline(&items, threads, &work, results),
+    );
+
+    let wrong = items
+        .iter()
+        .zip(&squares)
+        .filter(|&(item, square)| item * item != *square)
+        .count();
+    println!("{} squares, of which {wrong} are wrong", squares.len());
+}
+
+/// `run_pipeline` on `items`, which fails instead of hanging if it never finishes
+#[cfg(test)]
+fn pipeline(items: Vec<u64>, work: Chaos, results: Chaos) -> Option<Vec<u64>> {
+    common::with_timeout(Duration::from_secs(10), move || {
+        run_pipeline(&items, 4, &work, Arc::new(results))
+    })
+}
+
+#[cfg(test)]
+fn squares(items: &[u64]) -> Vec<u64> {
+    items.iter().map(|item| item * item).collect()
+}
+
+#[test]
+fn processes_reliably_through_errors() {
+    use common::chaos::Fault;
+    use part_101::process_reliably;
+
+    let chaos = Chaos::scripted([Some(Fault::Error), Some(Fault::Drop), Some(Fault::Error)]);
+    assert_eq!(process_reliably(&chaos, 7), 49);
+    // Every fault was used up on the way
+    assert_eq!(chaos.next_fault(), None);
+}
+
+#[test]
+fn processes_reliably_through_panics() {
+    use common::chaos::Fault;
+    use part_101::process_reliably;
+
+    let chaos = Chaos::scripted([
+        Some(Fault::Panic),
+        Some(Fault::Delay(Duration::from_millis(10))),
+    ]);
+    assert_eq!(process_reliably(&chaos, 7), 49);
+
+    let chaos = Chaos::scripted([Some(Fault::Panic), Some(Fault::Error), Some(Fault::Panic)]);
+    assert_eq!(process_reliably(&chaos, 3), 9);
+    assert_eq!(chaos.next_fault(), None);
+}
+
+#[test]
+fn pipeline_without_chaos() {
+    let items: Vec<u64> = (0..100).collect();
+    let expected = squares(&items);
+    let results = pipeline(items, Chaos::scripted([]), Chaos::scripted([]));
+    assert_eq!(results, Some(expected));
+}
+
+#[test]
+fn pipeline_without_items() {
+    let results = pipeline(Vec::new(), Chaos::scripted([]), Chaos::scripted([]));
+    assert_eq!(results, Some(Vec::new()));
+}
+
+#[test]
+fn pipeline_sends_lost_results_again() {
+    use common::chaos::Fault;
+
+    // Every result is lost the first time around
+    let items: Vec<u64> = (0..10).collect();
+    let expected = squares(&items);
+    let lost = Chaos::scripted([Some(Fault::Drop); 10]);
+    assert_eq!(pipeline(items, Chaos::scripted([]), lost), Some(expected));
+}
+
+#[test]
+fn pipeline_survives_chaos() {
+    let mut rng = common::rng::for_test();
+    let items = common::datagen::numbers_below(200, 1 << 20, rng.seed());
+    let expected = squares(&items);
+    let work = Chaos::new(rng.seed())
+        .errors(0.2)
+        .panics(0.1)
+        .delays(0.1, Duration::from_millis(5));
+    let results = Chaos::new(rng.seed())
+        .drops(0.3)
+        .delays(0.1, Duration::from_millis(5));
+    assert_eq!(pipeline(items, work, results), Some(expected));
+}
@@ -0,0 +1,94 @@
+//! Part 101, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+use common::chaos::{self, Chaos, ChaosSender};
+
+use super::{process, Job, RESEND_AFTER};
+
+/// Processes `item` until it works out, trying again whenever `process` fails, or panics
+pub fn process_reliably(chaos: &Chaos, item: u64) -> u64 {
+    loop {
+        // The chaos is only used to pick faults, so a panic can't leave it half changed
+        if let Ok(Ok(result)) = panic::catch_unwind(AssertUnwindSafe(|| process(chaos, item))) {
+            return result;
+        }
+    }
+}
+
+/// Takes jobs from `jobs` until it disconnects, and sends their results to `results`,
+/// which loses some of them. Stops early if nobody is waiting for results anymore.
+pub fn worker(
+    jobs: &Mutex<mpsc::Receiver<Job>>,
+    results: &ChaosSender<(usize, u64)>,
+    work: &Chaos,
+) {
+    loop {
+        // Only one worker waits for a job at a time, the others wait for the lock
+        let job = jobs.lock().unwrap().recv();
+        let Ok(job) = job else {
+            return;
+        };
+        let result = process_reliably(work, job.item);
+        if results.send((job.index, result)).is_err() {
+            return;
+        }
+    }
+}
+
+/// Squares every item on `workers` threads running `worker`, and returns the results in the same
+/// order as the items, however the chaos misbehaves. The work goes wrong whenever `work` says so,
+/// and the results come back through a `chaos::channel` with `results`, which loses some of them.
+/// Whenever no result has come for `RESEND_AFTER`, the jobs which are still missing are sent out
+/// again, so some results may come more than once.
+pub fn run_pipeline(items: &[u64], workers: usize, work: &Chaos, results: Arc<Chaos>) -> Vec<u64> {
+    let (job_sender, job_receiver) = mpsc::channel();
+    let jobs = Mutex::new(job_receiver);
+    let (result_sender, result_receiver) = chaos::channel(results);
+
+    thread::scope(|s| {
+        for _ in 0..workers {
+            let (jobs, results) = (&jobs, result_sender.clone());
+            s.spawn(move || worker(jobs, &results, work));
+        }
+
+        let mut done: Vec<Option<u64>> = vec![None; items.len()];
+        let mut missing = items.len();
+        let send_missing = |done: &[Option<u64>]| {
+            for (index, &item) in items.iter().enumerate() {
+                if done[index].is_none() {
+                    // The workers only stop once this sender is gone
+                    job_sender.send(Job { index, item }).unwrap();
+                }
+            }
+        };
+
+        send_missing(&done);
+        while missing > 0 {
+            match result_receiver.recv_timeout(RESEND_AFTER) {
+                // A job which was sent again may come back twice
+                Ok((index, result)) => {
+                    if done[index].replace(result).is_none() {
+                        missing -= 1;
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => send_missing(&done),
+                Err(RecvTimeoutError::Disconnected) => {
+                    unreachable!("A sender is kept until every result is in")
+                }
+            }
+        }
+
+        // Stops the workers, both the ones waiting for jobs and the ones with results to send
+        drop(job_sender);
+        drop(result_receiver);
+        done.into_iter().map(Option::unwrap).collect()
+    })
+}
@@ -0,0 +1,44 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-101 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{sync::Arc, time::Duration};
+
+use common::chaos::Chaos;
+use part_101::{process_reliably, run_pipeline, solutions};
+
+/// Work which goes wrong in the same ways every time it's made with the same seed
+fn work(seed: u64) -> Chaos {
+    Chaos::new(seed)
+        .errors(0.3)
+        .panics(0.1)
+        .delays(0.1, Duration::from_millis(1))
+}
+
+#[test]
+fn processes_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let seed = rng.seed();
+    let (chaos, expected_chaos) = (work(seed), work(seed));
+    for item in common::datagen::numbers_below(50, 1 << 20, rng.seed()) {
+        assert_eq!(
+            process_reliably(&chaos, item),
+            solutions::process_reliably(&expected_chaos, item),
+            "Processing {item}"
+        );
+        // Both used up the same faults on the way
+        assert_eq!(chaos.next_fault(), expected_chaos.next_fault());
+    }
+}
+
+#[test]
+fn pipelines_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let items = common::datagen::numbers_below(100, 1 << 20, rng.seed());
+    let (work_seed, results_seed) = (rng.seed(), rng.seed());
+    let results = || Arc::new(Chaos::new(results_seed).drops(0.3));
+    assert_eq!(
+        run_pipeline(&items, 4, &work(work_seed), results()),
+        solutions::run_pipeline(&items, 4, &work(work_seed), results())
+    );
+}
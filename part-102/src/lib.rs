@@ -0,0 +1,100 @@
+//! Code for a microcontroller, which has no operating system and so no threads, only `core`.
+//! What runs at the same time as the main loop there is an interrupt handler, which the hardware
+//! calls in the middle of whatever the main loop was doing, like when a new reading is ready.
+//! On the host, `main.rs` calls it from another thread instead.
+#![no_std]
+
+use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+use heapless::spsc::{Consumer, Producer, Queue};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// The size of the queue between the interrupt and the main loop, which holds one less reading
+pub const QUEUE_SIZE: usize = 16;
+
+/// The queue between the interrupt and the main loop.
+/// On a microcontroller it would be a `static`, since it has to outlive both.
+pub type Readings = Queue<u16, QUEUE_SIZE>;
+
+/// What the interrupt and the main loop share besides the queue
+#[derive(Debug, Default)]
+pub struct Shared {
+    /// Set by the interrupt when it has queued something, cleared by the main loop
+    pub pending: AtomicBool,
+    /// How many readings the interrupt had to drop because the queue was full
+    pub dropped: AtomicU32,
+}
+
+impl Shared {
+    pub const fn new() -> Self {
+        Self {
+            pending: AtomicBool::new(false),
+            dropped: AtomicU32::new(0),
+        }
+    }
+}
+
+/// The interrupt handler, which is called for every new reading
+pub struct Isr<'a> {
+    producer: Producer<'a, u16>,
+    shared: &'a Shared,
+}
+
+impl<'a> Isr<'a> {
+    pub fn new(producer: Producer<'a, u16>, shared: &'a Shared) -> Self {
+        Self { producer, shared }
+    }
+
+    /// Queues `reading` for the main loop and lets it know, or drops it if the queue is full.
+    /// The main loop can't run until this returns, so it must never wait for it.
+    pub fn on_interrupt(&mut self, reading: u16) {
+        todo!()
+    }
+}
+
+/// The main loop, which handles the readings whenever it gets around to it
+pub struct MainLoop<'a> {
+    consumer: Consumer<'a, u16>,
+    shared: &'a Shared,
+}
+
+impl<'a> MainLoop<'a> {
+    pub fn new(consumer: Consumer<'a, u16>, shared: &'a Shared) -> Self {
+        Self { consumer, shared }
+    }
+
+    /// One turn of the main loop. Does nothing unless the interrupt has queued something since the
+    /// last turn, and otherwise calls `handle` with every reading in the queue, oldest first.
+    /// Returns how many it handled.
+    pub fn poll(&mut self, handle: impl FnMut(u16)) -> usize {
+        todo!()
+    }
+
+    /// How many readings the interrupt has dropped so far
+    pub fn dropped(&self) -> u32 {
+        todo!()
+    }
+}
+
+/// Running totals of the readings, which is all the main loop keeps, without a heap to keep more
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub count: u32,
+    pub sum: u64,
+    pub max: u16,
+}
+
+impl Stats {
+    pub fn add(&mut self, reading: u16) {
+        self.count += 1;
+        self.sum += u64::from(reading);
+        self.max = self.max.max(reading);
+    }
+
+    /// The average reading, rounded down, or zero without any
+    pub fn average(&self) -> u64 {
+        self.sum.checked_div(u64::from(self.count)).unwrap_or(0)
+    }
+}
@@ -0,0 +1,178 @@
+use std::{
+    thread::{self, Scope, ScopedJoinHandle},
+    time::Duration,
+};
+
+use part_102::{Isr, MainLoop, Readings, Shared, Stats};
+
+/// Stands in for the hardware: calls `isr.on_interrupt` on another thread with every reading,
+/// about one every `period`, like a timer interrupt would. The thread finishes after the last one.
+fn interrupt<'scope>(
+    s: &'scope Scope<'scope, '_>,
+    mut isr: Isr<'scope>,
+    readings: impl IntoIterator<Item = u16> + Send + 'scope,
+    period: Duration,
+) -> ScopedJoinHandle<'scope, ()> {
+    s.spawn(move || {
+        for reading in readings {
+            isr.on_interrupt(reading);
+            // Sleeping rather than spinning lets the main loop run in between, even on one core
+            thread::sleep(period);
+        }
+    })
+}
+
+/// Runs the main loop until the interrupt has fired for every reading, and they've all been
+/// handled. `work` is what else the main loop does on every turn. Returns the readings it handled,
+/// and how many were dropped.
+fn run(
+    readings: impl IntoIterator<Item = u16> + Send,
+    period: Duration,
+    mut work: impl FnMut(),
+) -> (Vec<u16>, u32) {
+    let mut queue = Readings::new();
+    let shared = Shared::new();
+    let (producer, consumer) = queue.split();
+    let mut main_loop = MainLoop::new(consumer, &shared);
+    let mut handled = Vec::new();
+    thread::scope(|s| {
+        let hardware = interrupt(s, Isr::new(producer, &shared), readings, period);
+        while !hardware.is_finished() {
+            main_loop.poll(|reading| handled.push(reading));
+            work();
+        }
+    });
+    // Whatever came in after the last turn
+    main_loop.poll(|reading| handled.push(reading));
+    (handled, main_loop.dropped())
+}
+
+/// Run with `cargo run --release -p part-102`
+fn main() {
+    let readings = (0..10_000u32).map(|i| (i * 7 % 4096) as u16);
+    for (name, work) in [
+        ("A quick main loop", Duration::ZERO),
+        (
+            "A main loop busy with other things",
+            Duration::from_millis(2),
+        ),
+    ] {
+        let (handled, dropped) = run(readings.clone(), Duration::from_micros(10), || {
+            thread::sleep(work)
+        });
+        let mut stats = Stats::default();
+        handled.iter().for_each(|&reading| stats.add(reading));
+        println!(
+            "{name} handled {} readings, averaging {} and at most {}, and dropped {dropped}",
+            stats.count,
+            stats.average(),
+            stats.max
+        );
+    }
+}
+
+/// `run`, which fails instead of hanging if it never finishes
+#[cfg(test)]
+fn run_with_timeout(readings: Vec<u16>, period: Duration, work: Duration) -> (Vec<u16>, u32) {
+    common::with_timeout(Duration::from_secs(10), move || {
+        run(readings, period, || thread::sleep(work))
+    })
+    .expect("The main loop should finish")
+}
+
+#[test]
+fn main_loop_handles_queued_readings() {
+    let mut queue = Readings::new();
+    let shared = Shared::new();
+    let (producer, consumer) = queue.split();
+    let (mut isr, mut main_loop) = (
+        Isr::new(producer, &shared),
+        MainLoop::new(consumer, &shared),
+    );
+
+    let mut handled = Vec::new();
+    assert_eq!(main_loop.poll(|reading| handled.push(reading)), 0);
+    for reading in [3, 1, 2] {
+        isr.on_interrupt(reading);
+    }
+    assert_eq!(main_loop.poll(|reading| handled.push(reading)), 3);
+    assert_eq!(handled, [3, 1, 2]);
+    assert_eq!(main_loop.poll(|reading| handled.push(reading)), 0);
+    assert_eq!(main_loop.dropped(), 0);
+}
+
+#[test]
+fn main_loop_waits_for_the_interrupt() {
+    let mut queue = Readings::new();
+    let shared = Shared::new();
+    let (mut producer, consumer) = queue.split();
+    let mut main_loop = MainLoop::new(consumer, &shared);
+
+    // Queued without letting the main loop know
+    producer.enqueue(1).unwrap();
+    assert_eq!(main_loop.poll(|_| {}), 0);
+    let mut isr = Isr::new(producer, &shared);
+    isr.on_interrupt(2);
+    let mut handled = Vec::new();
+    assert_eq!(main_loop.poll(|reading| handled.push(reading)), 2);
+    assert_eq!(handled, [1, 2]);
+}
+
+#[test]
+fn interrupt_drops_readings_when_the_queue_is_full() {
+    let mut queue = Readings::new();
+    let capacity = queue.capacity();
+    let shared = Shared::new();
+    let (producer, consumer) = queue.split();
+    let (mut isr, mut main_loop) = (
+        Isr::new(producer, &shared),
+        MainLoop::new(consumer, &shared),
+    );
+
+    for reading in 0..capacity as u16 + 5 {
+        isr.on_interrupt(reading);
+    }
+    assert_eq!(main_loop.dropped(), 5);
+    // The newest readings are the ones which are dropped
+    let mut handled = Vec::new();
+    assert_eq!(main_loop.poll(|reading| handled.push(reading)), capacity);
+    assert_eq!(handled, (0..capacity as u16).collect::<Vec<_>>());
+
+    // There's room again
+    isr.on_interrupt(100);
+    assert_eq!(main_loop.poll(|_| {}), 1);
+    assert_eq!(main_loop.dropped(), 5);
+}
+
+/// Whether `handled` is what's left of `readings` after dropping some of them
+#[cfg(test)]
+fn is_what_is_left_of(handled: &[u16], readings: &[u16]) -> bool {
+    let mut readings = readings.iter();
+    handled
+        .iter()
+        .all(|reading| readings.any(|other| other == reading))
+}
+
+#[test]
+fn handles_readings_from_another_thread_in_order() {
+    let mut rng = common::rng::for_test();
+    let readings: Vec<u16> = common::datagen::numbers_below(2000, 4096, rng.seed())
+        .into_iter()
+        .map(|reading| reading as u16)
+        .collect();
+    let (handled, dropped) =
+        run_with_timeout(readings.clone(), Duration::from_micros(10), Duration::ZERO);
+    // How many are dropped depends on how the threads are scheduled, but none go missing
+    assert_eq!(handled.len() + dropped as usize, readings.len());
+    assert!(is_what_is_left_of(&handled, &readings));
+}
+
+#[test]
+fn counts_what_a_busy_main_loop_drops() {
+    let readings: Vec<u16> = (0..2000).collect();
+    let (handled, dropped) =
+        run_with_timeout(readings.clone(), Duration::ZERO, Duration::from_millis(1));
+    assert!(dropped > 0, "A main loop this slow should fall behind");
+    assert_eq!(handled.len() + dropped as usize, readings.len());
+    assert!(is_what_is_left_of(&handled, &readings));
+}
@@ -0,0 +1,64 @@
+//! Part 102, with every exercise implemented. Only built with `--features solutions`.
+
+use core::sync::atomic::Ordering;
+
+use heapless::spsc::{Consumer, Producer};
+
+use super::Shared;
+
+/// The interrupt handler, which is called for every new reading
+pub struct Isr<'a> {
+    producer: Producer<'a, u16>,
+    shared: &'a Shared,
+}
+
+impl<'a> Isr<'a> {
+    pub fn new(producer: Producer<'a, u16>, shared: &'a Shared) -> Self {
+        Self { producer, shared }
+    }
+
+    /// Queues `reading` for the main loop and lets it know, or drops it if the queue is full.
+    /// The main loop can't run until this returns, so it must never wait for it.
+    pub fn on_interrupt(&mut self, reading: u16) {
+        if self.producer.enqueue(reading).is_err() {
+            // Only counted, so nothing needs to be in order with it
+            self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        // Set after queueing, so the main loop can't see it and find the queue without the reading
+        self.shared.pending.store(true, Ordering::Release);
+    }
+}
+
+/// The main loop, which handles the readings whenever it gets around to it
+pub struct MainLoop<'a> {
+    consumer: Consumer<'a, u16>,
+    shared: &'a Shared,
+}
+
+impl<'a> MainLoop<'a> {
+    pub fn new(consumer: Consumer<'a, u16>, shared: &'a Shared) -> Self {
+        Self { consumer, shared }
+    }
+
+    /// One turn of the main loop. Does nothing unless the interrupt has queued something since the
+    /// last turn, and otherwise calls `handle` with every reading in the queue, oldest first.
+    /// Returns how many it handled.
+    pub fn poll(&mut self, mut handle: impl FnMut(u16)) -> usize {
+        // Cleared before emptying the queue, so a reading queued meanwhile sets it again for the
+        // next turn, instead of being left in the queue unnoticed
+        if !self.shared.pending.swap(false, Ordering::Acquire) {
+            return 0;
+        }
+        let mut handled = 0;
+        while let Some(reading) = self.consumer.dequeue() {
+            handle(reading);
+            handled += 1;
+        }
+        handled
+    }
+
+    /// How many readings the interrupt has dropped so far
+    pub fn dropped(&self) -> u32 {
+        self.shared.dropped.load(Ordering::Relaxed)
+    }
+}
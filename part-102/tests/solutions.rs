@@ -0,0 +1,54 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-102 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_102::{solutions, Isr, MainLoop, Readings, Shared};
+
+/// Feeds `readings` to the interrupt in bursts of `burst`, polling the main loop after every
+/// burst, and returns everything the main loop handled and dropped along the way
+fn feed(readings: &[u16], burst: usize) -> (Vec<u16>, u32) {
+    let mut queue = Readings::new();
+    let shared = Shared::new();
+    let (producer, consumer) = queue.split();
+    let (mut isr, mut main_loop) = (
+        Isr::new(producer, &shared),
+        MainLoop::new(consumer, &shared),
+    );
+    let mut handled = Vec::new();
+    for chunk in readings.chunks(burst) {
+        chunk.iter().for_each(|&reading| isr.on_interrupt(reading));
+        main_loop.poll(|reading| handled.push(reading));
+    }
+    (handled, main_loop.dropped())
+}
+
+fn feed_solution(readings: &[u16], burst: usize) -> (Vec<u16>, u32) {
+    let mut queue = Readings::new();
+    let shared = Shared::new();
+    let (producer, consumer) = queue.split();
+    let mut isr = solutions::Isr::new(producer, &shared);
+    let mut main_loop = solutions::MainLoop::new(consumer, &shared);
+    let mut handled = Vec::new();
+    for chunk in readings.chunks(burst) {
+        chunk.iter().for_each(|&reading| isr.on_interrupt(reading));
+        main_loop.poll(|reading| handled.push(reading));
+    }
+    (handled, main_loop.dropped())
+}
+
+#[test]
+fn handles_and_drops_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let readings: Vec<u16> = common::datagen::numbers_below(1000, 4096, rng.seed())
+        .into_iter()
+        .map(|reading| reading as u16)
+        .collect();
+    // Bursts which fit in the queue, and ones which don't
+    for burst in [1, 7, 15, 40] {
+        assert_eq!(
+            feed(&readings, burst),
+            feed_solution(&readings, burst),
+            "Bursts of {burst}"
+        );
+    }
+}
@@ -0,0 +1,64 @@
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use common::constrain;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How long `work` takes for every item
+pub const WORK: Duration = Duration::from_millis(10);
+/// How long `job` takes before its work can be split up
+pub const SETUP: Duration = Duration::from_millis(40);
+/// The simulated machines to measure the speedup on
+pub const CORES: [usize; 3] = [1, 2, 4];
+
+/// The work for one item. It sleeps, which keeps a simulated core as busy as computing would,
+/// so it takes as long on any machine.
+pub fn work(item: u64) -> u64 {
+    thread::sleep(WORK);
+    item * 3 + 1
+}
+
+/// Runs `f` on every item, split in chunks between `workers` scoped threads, which only start
+/// working once they have a simulated core, see `common::constrain`. The results are in the same
+/// order as the items.
+pub fn parallel_map(items: &[u64], workers: usize, f: impl Fn(u64) -> u64 + Sync) -> Vec<u64> {
+    todo!()
+}
+
+/// A job with a part which can't be split up: `SETUP` on its own, and then `work` on every item on
+/// `workers` threads
+pub fn job(items: &[u64], workers: usize) -> Vec<u64> {
+    thread::sleep(SETUP);
+    parallel_map(items, workers, work)
+}
+
+/// How long something took on a simulated machine, and how much faster that was than on one core
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Speedup {
+    pub cores: usize,
+    pub elapsed: Duration,
+    pub speedup: f64,
+}
+
+/// Runs `run` once on a simulated machine with a single core, and then once on a simulated machine
+/// with each of `cores`, and returns how much faster it was on each of them than on the first
+pub fn speedup_curve(cores: &[usize], run: impl Fn()) -> Vec<Speedup> {
+    todo!()
+}
+
+/// The speedup Amdahl's law predicts on `cores` cores, for work of which a share of `parallel`,
+/// between 0 and 1, can be split between them
+pub fn amdahl(parallel: f64, cores: usize) -> f64 {
+    todo!()
+}
+
+/// How long `f` takes
+pub fn elapsed(f: impl FnOnce()) -> Duration {
+    let start = Instant::now();
+    f();
+    start.elapsed()
+}
@@ -0,0 +1,134 @@
+use part_103::{amdahl, job, speedup_curve, CORES, SETUP, WORK};
+
+/// Run with `cargo run --release -p part-103` to see the speedup on 1, 2 and 4 simulated cores
+fn main() {
+    let args = common::cli::Cli::new("Measures a job's speedup on simulated machines")
+        .items(32)
+        .threads(4)
+        .parse();
+    let items: Vec<u64> = (0..args.items() as u64).collect();
+    let workers = args.threads();
+
+    // The share of the time on one core which can be split up
+    let parallel_time = WORK * items.len() as u32;
+    let parallel = parallel_time.as_secs_f64() / (SETUP + parallel_time).as_secs_f64();
+    println!(
+        "{} items on {workers} workers, {:.0}% of which can be split up",
+        items.len(),
+        parallel * 100.0
+    );
+    println!(
+        "{:>6}  {:>10}  {:>8}  {:>9}",
+        "Cores", "Elapsed", "Speedup", "Predicted"
+    );
+    for point in speedup_curve(&CORES, || {
+        job(&items, workers);
+    }) {
+        println!(
+            "{:>6}  {:>10}  {:>7.2}x  {:>8.2}x",
+            point.cores,
+            format!("{:.1?}", point.elapsed),
+            point.speedup,
+            amdahl(parallel, point.cores.min(workers))
+        );
+    }
+}
+
+#[cfg(test)]
+fn map_within_timeout(items: Vec<u64>, workers: usize) -> Option<Vec<u64>> {
+    common::with_timeout(std::time::Duration::from_secs(10), move || {
+        part_103::parallel_map(&items, workers, |item| item * 3 + 1)
+    })
+}
+
+#[test]
+fn maps_in_order() {
+    let mut rng = common::rng::for_test();
+    let items = common::datagen::numbers_below(1000, 1 << 20, rng.seed());
+    let expected: Vec<u64> = items.iter().map(|item| item * 3 + 1).collect();
+    for workers in [1, 3, 8] {
+        assert_eq!(
+            map_within_timeout(items.clone(), workers),
+            Some(expected.clone()),
+            "{workers} workers"
+        );
+    }
+}
+
+#[test]
+fn maps_nothing() {
+    assert_eq!(map_within_timeout(Vec::new(), 4), Some(Vec::new()));
+}
+
+#[test]
+fn maps_with_more_workers_than_items() {
+    assert_eq!(map_within_timeout(vec![1, 2], 8), Some(vec![4, 7]));
+}
+
+#[test]
+fn workers_wait_for_a_simulated_core() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    for cores in [1, 2, 4] {
+        let (running, most) = (AtomicUsize::new(0), AtomicUsize::new(0));
+        let items: Vec<u64> = (0..16).collect();
+        common::constrain::simulate(cores, || {
+            part_103::parallel_map(&items, 8, |item| {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                most.fetch_max(now, Ordering::SeqCst);
+                std::thread::sleep(std::time::Duration::from_millis(5));
+                running.fetch_sub(1, Ordering::SeqCst);
+                item
+            })
+        });
+        // Sleeping workers overlap even on a single real core, so all of the simulated ones are used
+        assert_eq!(most.into_inner(), cores, "{cores} cores");
+    }
+}
+
+#[test]
+fn amdahls_law() {
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+    assert!(close(amdahl(1.0, 4), 4.0));
+    assert!(close(amdahl(0.0, 4), 1.0));
+    assert!(close(amdahl(0.5, 1), 1.0));
+    assert!(close(amdahl(0.8, 2), 1.0 / 0.6));
+    assert!(close(amdahl(0.8, 4), 2.5));
+}
+
+#[test]
+fn speedup_curve_measures_every_machine() {
+    let items: Vec<u64> = (0..8).collect();
+    let curve = speedup_curve(&[1, 2, 4], || {
+        job(&items, 4);
+    });
+    let cores: Vec<usize> = curve.iter().map(|point| point.cores).collect();
+    assert_eq!(cores, [1, 2, 4]);
+    // Four workers with two items each, which take turns on fewer cores
+    for (point, items_in_turn) in curve.iter().zip([8, 4, 2]) {
+        assert!(point.elapsed >= SETUP + WORK * items_in_turn, "{point:?}");
+    }
+    // Compared to another run on one core, which takes about as long
+    assert!((curve[0].speedup - 1.0).abs() < 0.2, "{:?}", curve[0]);
+}
+
+#[test]
+fn speedup_follows_amdahls_law() {
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    // 80% of the time on one core can be split up, between as many cores as there are workers
+    let items: Vec<u64> = (0..16).collect();
+    let curve = speedup_curve(&CORES, || {
+        job(&items, 4);
+    });
+    for point in curve {
+        let predicted = amdahl(0.8, point.cores);
+        assert!(
+            (point.speedup - predicted).abs() < predicted * 0.2,
+            "Predicted {predicted:.2}x on {} cores, but was {:.2}x",
+            point.cores,
+            point.speedup
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! Part 103, with every exercise implemented. Only built with `--features solutions`.
+
+use std::thread;
+
+use common::constrain;
+
+use super::{elapsed, work, Speedup, SETUP};
+
+/// Runs `f` on every item, split in chunks between `workers` scoped threads, which only start
+/// working once they have a simulated core, see `common::constrain`. The results are in the same
+/// order as the items.
+pub fn parallel_map(items: &[u64], workers: usize, f: impl Fn(u64) -> u64 + Sync) -> Vec<u64> {
+    let chunk_size = items.len().div_ceil(workers).max(1);
+    let f = &f;
+    thread::scope(|s| {
+        let handles: Vec<_> = items
+            .chunks(chunk_size)
+            .map(|chunk| {
+                // Waits for a core inside the thread, so the others are started meanwhile
+                s.spawn(constrain::gated(move || {
+                    chunk.iter().map(|&item| f(item)).collect::<Vec<_>>()
+                }))
+            })
+            .collect();
+        // Joined in the order they were started, which is the order of the chunks
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    })
+}
+
+/// A job with a part which can't be split up: `SETUP` on its own, and then `work` on every item on
+/// `workers` threads
+pub fn job(items: &[u64], workers: usize) -> Vec<u64> {
+    thread::sleep(SETUP);
+    parallel_map(items, workers, work)
+}
+
+/// Runs `run` once on a simulated machine with a single core, and then once on a simulated machine
+/// with each of `cores`, and returns how much faster it was on each of them than on the first
+pub fn speedup_curve(cores: &[usize], run: impl Fn()) -> Vec<Speedup> {
+    let baseline = constrain::simulate(1, || elapsed(&run));
+    cores
+        .iter()
+        .map(|&cores| {
+            let elapsed = constrain::simulate(cores, || elapsed(&run));
+            Speedup {
+                cores,
+                elapsed,
+                speedup: baseline.as_secs_f64() / elapsed.as_secs_f64(),
+            }
+        })
+        .collect()
+}
+
+/// The speedup Amdahl's law predicts on `cores` cores, for work of which a share of `parallel`,
+/// between 0 and 1, can be split between them
+pub fn amdahl(parallel: f64, cores: usize) -> f64 {
+    // The serial part takes as long as ever, the parallel part is split between the cores
+    1.0 / ((1.0 - parallel) + parallel / cores as f64)
+}
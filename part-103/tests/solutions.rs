@@ -0,0 +1,45 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-103 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_103::{amdahl, parallel_map, solutions, speedup_curve};
+
+#[test]
+fn maps_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let items = common::datagen::numbers_below(1000, 1 << 20, rng.seed());
+    for workers in [1, 2, 7] {
+        assert_eq!(
+            parallel_map(&items, workers, |item| item ^ 103),
+            solutions::parallel_map(&items, workers, |item| item ^ 103),
+            "{workers} workers"
+        );
+    }
+}
+
+#[test]
+fn predicts_like_the_solution() {
+    for parallel in [0.0, 0.25, 0.9, 1.0] {
+        for cores in [1, 3, 16] {
+            let (predicted, expected) =
+                (amdahl(parallel, cores), solutions::amdahl(parallel, cores));
+            assert!(
+                (predicted - expected).abs() < 1e-9,
+                "{parallel} on {cores} cores: {predicted}, not {expected}"
+            );
+        }
+    }
+}
+
+#[test]
+fn measures_the_same_machines_as_the_solution() {
+    let cores: Vec<_> = speedup_curve(&[3, 1], || {})
+        .iter()
+        .map(|point| point.cores)
+        .collect();
+    let expected: Vec<_> = solutions::speedup_curve(&[3, 1], || {})
+        .iter()
+        .map(|point| point.cores)
+        .collect();
+    assert_eq!(cores, expected);
+}
@@ -0,0 +1,108 @@
+use std::{
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How long the producers flood the consumer
+pub const DURATION: Duration = Duration::from_millis(200);
+/// How long each of the windows the deliveries are counted in is
+pub const WINDOW: Duration = Duration::from_millis(20);
+/// How many messages fit in a channel before the producers have to wait
+pub const CAPACITY: usize = 16;
+
+/// A message the consumer received: which producer sent it, and when it arrived, from when the
+/// consumer started
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Delivery {
+    pub producer: usize,
+    pub at: Duration,
+}
+
+/// Receives with `recv` for `duration`, or until it gives `None`, and returns every delivery in the
+/// order they arrived
+pub fn consume(duration: Duration, mut recv: impl FnMut() -> Option<usize>) -> Vec<Delivery> {
+    let start = Instant::now();
+    let mut deliveries = Vec::new();
+    while start.elapsed() < duration {
+        let Some(producer) = recv() else { break };
+        deliveries.push(Delivery {
+            producer,
+            at: start.elapsed(),
+        });
+    }
+    deliveries
+}
+
+/// Floods a single consumer with messages from `producers` threads over a `std::sync::mpsc`
+/// channel of `capacity` for `duration`. Every producer sends its own number, as fast as it can,
+/// until the consumer is done and hangs up.
+pub fn flood_std(producers: usize, capacity: usize, duration: Duration) -> Vec<Delivery> {
+    let (sender, receiver) = mpsc::sync_channel(capacity);
+    thread::scope(|s| {
+        for producer in 0..producers {
+            let sender = sender.clone();
+            s.spawn(move || while sender.send(producer).is_ok() {});
+        }
+        drop(sender);
+        let deliveries = consume(duration, || receiver.recv().ok());
+        // Sending fails once the receiver is gone, which stops the producers
+        drop(receiver);
+        deliveries
+    })
+}
+
+/// Floods a single consumer like `flood_std`, over a bounded `crossbeam_channel` instead
+pub fn flood_crossbeam(producers: usize, capacity: usize, duration: Duration) -> Vec<Delivery> {
+    todo!()
+}
+
+/// Counts how many messages each of the `producers` delivered in every window of `width`, from
+/// the start up to and including the window of the last delivery. `windows(..)[w][p]` is how many
+/// producer `p` delivered in window `w`.
+pub fn windows(deliveries: &[Delivery], producers: usize, width: Duration) -> Vec<Vec<usize>> {
+    todo!()
+}
+
+/// [Jain's fairness index](https://en.wikipedia.org/wiki/Fairness_measure) of the counts: 1 when
+/// they're all the same, down to `1 / counts.len()` when a single one got everything. Counts with
+/// nothing to share are fair.
+pub fn jain_index(counts: &[usize]) -> f64 {
+    todo!()
+}
+
+/// How fairly a channel delivered from its producers
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fairness {
+    /// How many messages each producer delivered in total
+    pub totals: Vec<usize>,
+    /// The fairness index of the totals
+    pub overall: f64,
+    /// The fairness index of the least fair window, where a producer can have been skipped for a
+    /// while even if it caught up later
+    pub worst_window: f64,
+}
+
+impl Fairness {
+    /// Measures the fairness of `deliveries` from `producers`, counted in windows of `width`
+    pub fn of(deliveries: &[Delivery], producers: usize, width: Duration) -> Self {
+        let windows = windows(deliveries, producers, width);
+        let mut totals = vec![0; producers];
+        for window in &windows {
+            for (total, count) in totals.iter_mut().zip(window) {
+                *total += count;
+            }
+        }
+        Self {
+            overall: jain_index(&totals),
+            worst_window: windows
+                .iter()
+                .map(|window| jain_index(window))
+                .fold(1.0, f64::min),
+            totals,
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::time::Duration;
+
+use part_104::{flood_crossbeam, flood_std, Delivery, Fairness, CAPACITY, DURATION, WINDOW};
+
+/// `flood_std` or `flood_crossbeam`
+type Flood = fn(usize, usize, Duration) -> Vec<Delivery>;
+
+/// Run with `cargo run --release -p part-104` to see how fairly each channel delivers
+fn main() {
+    let args =
+        common::cli::Cli::new("Measures how fairly channels deliver from flooding producers")
+            .threads(4)
+            .parse();
+    let producers = args.threads();
+    println!(
+        "{producers} producers flooding a channel of {CAPACITY} for {DURATION:?}, counted every {WINDOW:?}"
+    );
+    for (name, flood) in [
+        ("std::sync::mpsc", flood_std as Flood),
+        ("crossbeam_channel", flood_crossbeam),
+    ] {
+        let deliveries = flood(producers, CAPACITY, DURATION);
+        let fairness = Fairness::of(&deliveries, producers, WINDOW);
+        println!(
+            "{name:>17}: {} messages, {:?} by producer, fairness {:.3} overall and {:.3} in the worst window",
+            deliveries.len(),
+            fairness.totals,
+            fairness.overall,
+            fairness.worst_window
+        );
+    }
+}
+
+#[cfg(test)]
+fn delivery(producer: usize, millis: u64) -> Delivery {
+    Delivery {
+        producer,
+        at: Duration::from_millis(millis),
+    }
+}
+
+#[test]
+fn counts_every_window() {
+    use part_104::windows;
+
+    let deliveries = [
+        delivery(0, 0),
+        delivery(1, 3),
+        delivery(0, 9),
+        delivery(2, 10),
+        delivery(2, 35),
+        delivery(1, 39),
+    ];
+    assert_eq!(
+        windows(&deliveries, 3, Duration::from_millis(10)),
+        [vec![2, 1, 0], vec![0, 0, 1], vec![0, 0, 0], vec![0, 1, 1]]
+    );
+}
+
+#[test]
+fn counts_no_windows_without_deliveries() {
+    assert!(part_104::windows(&[], 4, Duration::from_millis(10)).is_empty());
+}
+
+#[test]
+fn jain_index_goes_from_fair_to_a_single_producer() {
+    use part_104::jain_index;
+
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+    assert!(close(jain_index(&[5, 5, 5, 5]), 1.0));
+    assert!(close(jain_index(&[20, 0, 0, 0]), 0.25));
+    assert!(close(jain_index(&[3, 1]), 0.8));
+    assert!(close(jain_index(&[0, 0, 0]), 1.0));
+    assert!(close(jain_index(&[]), 1.0));
+}
+
+#[test]
+fn fairness_of_hand_made_deliveries() {
+    // Producer 1 is skipped in the second window, but catches up in the third
+    let deliveries = [
+        delivery(0, 1),
+        delivery(1, 2),
+        delivery(0, 11),
+        delivery(0, 12),
+        delivery(1, 21),
+        delivery(1, 22),
+    ];
+    let fairness = Fairness::of(&deliveries, 2, Duration::from_millis(10));
+    assert_eq!(fairness.totals, [3, 3]);
+    assert!((fairness.overall - 1.0).abs() < 1e-9, "{fairness:?}");
+    assert!((fairness.worst_window - 0.5).abs() < 1e-9, "{fairness:?}");
+}
+
+#[cfg(test)]
+fn check_flood(flood: Flood) -> Vec<Delivery> {
+    let duration = Duration::from_millis(100);
+    // Fails instead of hanging if the producers are never stopped
+    let deliveries = common::with_timeout(Duration::from_secs(10), move || flood(4, 8, duration))
+        .expect("The producers should stop once the consumer is done");
+    assert!(!deliveries.is_empty());
+    assert!(
+        deliveries.windows(2).all(|pair| pair[0].at <= pair[1].at),
+        "Deliveries should be in the order they arrived"
+    );
+    for producer in 0..4 {
+        assert!(
+            deliveries
+                .iter()
+                .any(|delivery| delivery.producer == producer),
+            "Nothing from producer {producer}"
+        );
+    }
+    assert!(deliveries.iter().all(|delivery| delivery.producer < 4));
+    deliveries
+}
+
+#[test]
+fn std_delivers_from_every_producer() {
+    check_flood(flood_std);
+}
+
+#[test]
+fn crossbeam_delivers_from_every_producer() {
+    check_flood(flood_crossbeam);
+}
+
+#[test]
+fn both_channels_are_fair_overall() {
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    // std usually comes out at 0.99 or more overall, and crossbeam somewhere from 0.65 to 0.9, with
+    // one producer getting two or three times its share. Single windows can be as unfair as 0.25,
+    // a single producer, when it has the only core for a while. The bound only catches a channel
+    // which keeps delivering from the same one or two producers.
+    for (name, flood) in [("std", flood_std as Flood), ("crossbeam", flood_crossbeam)] {
+        let fairness = Fairness::of(&check_flood(flood), 4, WINDOW);
+        assert!(fairness.overall > 0.4, "{name}: {fairness:?}");
+    }
+}
@@ -0,0 +1,38 @@
+//! Part 104, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{thread, time::Duration};
+
+use crate::{consume, Delivery};
+
+pub fn flood_crossbeam(producers: usize, capacity: usize, duration: Duration) -> Vec<Delivery> {
+    let (sender, receiver) = crossbeam_channel::bounded(capacity);
+    thread::scope(|s| {
+        for producer in 0..producers {
+            let sender = sender.clone();
+            s.spawn(move || while sender.send(producer).is_ok() {});
+        }
+        drop(sender);
+        let deliveries = consume(duration, || receiver.recv().ok());
+        drop(receiver);
+        deliveries
+    })
+}
+
+pub fn windows(deliveries: &[Delivery], producers: usize, width: Duration) -> Vec<Vec<usize>> {
+    let window = |delivery: &Delivery| (delivery.at.as_nanos() / width.as_nanos()) as usize;
+    let count = deliveries.last().map_or(0, |last| window(last) + 1);
+    let mut windows = vec![vec![0; producers]; count];
+    for delivery in deliveries {
+        windows[window(delivery)][delivery.producer] += 1;
+    }
+    windows
+}
+
+pub fn jain_index(counts: &[usize]) -> f64 {
+    let sum: f64 = counts.iter().map(|&count| count as f64).sum();
+    let squares: f64 = counts.iter().map(|&count| (count as f64).powi(2)).sum();
+    if squares == 0.0 {
+        return 1.0;
+    }
+    sum * sum / (counts.len() as f64 * squares)
+}
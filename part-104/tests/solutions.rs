@@ -0,0 +1,38 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-104 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::time::Duration;
+
+use part_104::{jain_index, solutions, windows, Delivery};
+
+#[test]
+fn counts_windows_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let mut deliveries: Vec<Delivery> = (0..500)
+        .map(|_| Delivery {
+            producer: rng.below(5) as usize,
+            at: Duration::from_micros(rng.below(100_000)),
+        })
+        .collect();
+    deliveries.sort_by_key(|delivery| delivery.at);
+    for width in [1, 7, 20, 200] {
+        let width = Duration::from_millis(width);
+        assert_eq!(
+            windows(&deliveries, 5, width),
+            solutions::windows(&deliveries, 5, width),
+            "Windows of {width:?}"
+        );
+    }
+}
+
+#[test]
+fn measures_fairness_like_the_solution() {
+    for counts in [&[][..], &[0, 0], &[4, 4, 4], &[9, 1, 0, 3], &[100]] {
+        let (index, expected) = (jain_index(counts), solutions::jain_index(counts));
+        assert!(
+            (index - expected).abs() < 1e-9,
+            "{counts:?}: {index}, not {expected}"
+        );
+    }
+}
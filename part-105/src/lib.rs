@@ -0,0 +1,67 @@
+use std::{thread, time::Duration};
+
+use tokio::runtime::Runtime;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How long every request waits on something else, like a database, with `.await`
+pub const AWAIT: Duration = Duration::from_millis(5);
+/// How long every request keeps its thread busy afterwards
+pub const BURST: Duration = Duration::from_millis(10);
+
+/// The part of a request which keeps its thread busy. It sleeps instead of computing, which blocks
+/// the runtime's thread just the same, but takes as long on any machine.
+pub fn burst() {
+    common::viz::busy(|| thread::sleep(BURST));
+}
+
+/// A request in the mixed workload: it awaits something for `AWAIT`, and then computes for `BURST`
+pub async fn handle(request: u64) -> u64 {
+    tokio::time::sleep(AWAIT).await;
+    burst();
+    request * 2
+}
+
+/// A runtime of the `current_thread` flavor, which runs every task on the thread which calls
+/// `block_on`, with its timers enabled
+pub fn current_thread() -> Runtime {
+    todo!()
+}
+
+/// A runtime of the `multi_thread` flavor, which runs its tasks on `workers` threads of its own,
+/// with its timers enabled
+pub fn multi_thread(workers: usize) -> Runtime {
+    todo!()
+}
+
+/// How a runtime did on the workload
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    /// How long it took to handle every request
+    pub elapsed: Duration,
+    /// How long every request took, from when it was spawned until it was handled
+    pub latencies: Vec<Duration>,
+}
+
+impl Measurement {
+    /// How many requests were handled every second
+    pub fn throughput(&self) -> f64 {
+        self.latencies.len() as f64 / self.elapsed.as_secs_f64()
+    }
+
+    /// The latency which `quantile`, between 0 and 1, of the requests took at most, like 0.5 for
+    /// the median
+    pub fn latency(&self, quantile: f64) -> Duration {
+        let mut latencies = self.latencies.clone();
+        latencies.sort();
+        let index = ((latencies.len() as f64 * quantile).ceil() as usize).saturating_sub(1);
+        latencies.get(index).copied().unwrap_or_default()
+    }
+}
+
+/// Handles `requests` requests on `runtime`, each with `handle` in a task of its own, which are all
+/// spawned at once, and measures how long that took in all and for every request
+pub fn workload(runtime: &Runtime, requests: u64) -> Measurement {
+    todo!()
+}
@@ -0,0 +1,113 @@
+use part_105::{current_thread, multi_thread, workload, AWAIT, BURST};
+
+/// Run with `cargo run --release -p part-105` to compare the runtime flavors on a mixed workload
+fn main() {
+    let args = common::cli::Cli::new("Compares tokio's runtime flavors on a mixed workload")
+        .items(32)
+        .threads(4)
+        .parse();
+    let requests = args.items() as u64;
+    let workers = args.threads();
+    println!("{requests} requests, each awaiting for {AWAIT:?} and then busy for {BURST:?}");
+    println!(
+        "{:>22}  {:>10}  {:>12}  {:>10}  {:>10}",
+        "Flavor", "Elapsed", "Requests/s", "Median", "99th"
+    );
+    for (name, runtime) in [
+        ("current_thread".to_string(), current_thread()),
+        (format!("multi_thread({workers})"), multi_thread(workers)),
+    ] {
+        let measurement = workload(&runtime, requests);
+        println!(
+            "{name:>22}  {:>10}  {:>12.0}  {:>10}  {:>10}",
+            format!("{:.1?}", measurement.elapsed),
+            measurement.throughput(),
+            format!("{:.1?}", measurement.latency(0.5)),
+            format!("{:.1?}", measurement.latency(0.99))
+        );
+    }
+}
+
+#[test]
+fn builds_both_flavors() {
+    use tokio::runtime::RuntimeFlavor;
+
+    let runtime = current_thread();
+    assert_eq!(
+        runtime.handle().runtime_flavor(),
+        RuntimeFlavor::CurrentThread
+    );
+    // Timers have to be enabled for the requests to await
+    runtime.block_on(async { tokio::time::sleep(AWAIT).await });
+
+    for workers in [1, 3] {
+        let runtime = multi_thread(workers);
+        assert_eq!(
+            runtime.handle().runtime_flavor(),
+            RuntimeFlavor::MultiThread
+        );
+        assert_eq!(runtime.metrics().num_workers(), workers);
+        runtime.block_on(async { tokio::time::sleep(AWAIT).await });
+    }
+}
+
+#[test]
+fn handles_every_request() {
+    for runtime in [current_thread(), multi_thread(2)] {
+        // Fails instead of hanging if the requests never finish
+        let measurement = common::with_timeout(std::time::Duration::from_secs(10), move || {
+            workload(&runtime, 8)
+        })
+        .expect("Handling 8 requests took too long");
+        assert_eq!(measurement.latencies.len(), 8);
+        for latency in &measurement.latencies {
+            assert!(*latency >= AWAIT + BURST, "{measurement:?}");
+            assert!(*latency <= measurement.elapsed, "{measurement:?}");
+        }
+    }
+}
+
+#[test]
+fn handles_no_requests() {
+    let measurement = workload(&current_thread(), 0);
+    assert!(measurement.latencies.is_empty());
+}
+
+#[test]
+fn latency_quantiles() {
+    use std::time::Duration;
+
+    let measurement = part_105::Measurement {
+        elapsed: Duration::from_millis(100),
+        latencies: [40, 10, 30, 20].map(Duration::from_millis).to_vec(),
+    };
+    assert_eq!(measurement.throughput(), 40.0);
+    assert_eq!(measurement.latency(0.5), Duration::from_millis(20));
+    assert_eq!(measurement.latency(0.99), Duration::from_millis(40));
+    assert_eq!(measurement.latency(0.0), Duration::from_millis(10));
+}
+
+#[test]
+fn only_multi_thread_scales_the_bursts() {
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    // The bursts sleep rather than compute, so they overlap on worker threads even on a single core
+    let requests = 16;
+    let current = workload(&current_thread(), requests);
+    let multi = workload(&multi_thread(4), requests);
+    // On `current_thread`, the awaits overlap, but the bursts take turns on its one thread
+    assert!(current.elapsed >= BURST * requests as u32, "{current:?}");
+    assert!(
+        current.elapsed < (AWAIT + BURST) * requests as u32,
+        "The awaits should overlap: {current:?}"
+    );
+    // On `multi_thread`, four bursts run at once
+    assert!(
+        multi.elapsed < current.elapsed / 2,
+        "{:.1?} on multi_thread, {:.1?} on current_thread",
+        multi.elapsed,
+        current.elapsed
+    );
+    assert!(multi.latency(0.5) < current.latency(0.5), "{multi:?}");
+}
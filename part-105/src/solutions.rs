@@ -0,0 +1,42 @@
+//! Part 105, with every exercise implemented. Only built with `--features solutions`.
+
+use std::time::Instant;
+
+use tokio::runtime::{Builder, Runtime};
+
+use crate::{handle, Measurement};
+
+pub fn current_thread() -> Runtime {
+    Builder::new_current_thread().enable_all().build().unwrap()
+}
+
+pub fn multi_thread(workers: usize) -> Runtime {
+    Builder::new_multi_thread()
+        .worker_threads(workers)
+        .enable_all()
+        .build()
+        .unwrap()
+}
+
+pub fn workload(runtime: &Runtime, requests: u64) -> Measurement {
+    runtime.block_on(async {
+        let start = Instant::now();
+        let tasks: Vec<_> = (0..requests)
+            .map(|request| {
+                let spawned = Instant::now();
+                tokio::spawn(async move {
+                    handle(request).await;
+                    spawned.elapsed()
+                })
+            })
+            .collect();
+        let mut latencies = Vec::with_capacity(tasks.len());
+        for task in tasks {
+            latencies.push(task.await.expect("A request panicked"));
+        }
+        Measurement {
+            elapsed: start.elapsed(),
+            latencies,
+        }
+    })
+}
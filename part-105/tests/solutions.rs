@@ -0,0 +1,31 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-105 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_105::{current_thread, multi_thread, solutions, workload};
+
+#[test]
+fn builds_runtimes_like_the_solution() {
+    assert_eq!(
+        current_thread().handle().runtime_flavor(),
+        solutions::current_thread().handle().runtime_flavor()
+    );
+    let (runtime, expected) = (multi_thread(3), solutions::multi_thread(3));
+    assert_eq!(
+        runtime.handle().runtime_flavor(),
+        expected.handle().runtime_flavor()
+    );
+    assert_eq!(
+        runtime.metrics().num_workers(),
+        expected.metrics().num_workers()
+    );
+}
+
+#[test]
+fn handles_as_many_requests_as_the_solution() {
+    let runtime = solutions::multi_thread(2);
+    assert_eq!(
+        workload(&runtime, 5).latencies.len(),
+        solutions::workload(&runtime, 5).latencies.len()
+    );
+}
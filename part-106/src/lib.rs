@@ -0,0 +1,105 @@
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use part_5::{calculate, ComputationResult, Data};
+use tokio::{task::JoinHandle, time::Instant};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How long the probe sleeps between its measurements
+pub const INTERVAL: Duration = Duration::from_millis(5);
+/// How late the probe can wake up before the runtime counts as blocked. Scheduling and timers make
+/// it a little late now and then, but a task which blocks makes it as late as the blocking takes.
+pub const THRESHOLD: Duration = Duration::from_millis(50);
+
+/// A task which sleeps for `INTERVAL` over and over, and measures how much later than that it
+/// woke up every time. Waking up late means that the runtime's threads were busy with something
+/// else, which for a runtime that's meant to be waiting most of the time, is usually a task that
+/// blocks.
+pub struct Probe {
+    delays: Arc<Mutex<Vec<Duration>>>,
+    task: JoinHandle<()>,
+}
+
+impl Probe {
+    /// Spawns the probe on the current runtime, and lets it start sleeping
+    pub async fn start() -> Self {
+        let delays = Arc::new(Mutex::new(Vec::new()));
+        let task = tokio::spawn({
+            let delays = delays.clone();
+            async move {
+                loop {
+                    let asleep = Instant::now();
+                    tokio::time::sleep(INTERVAL).await;
+                    let delay = asleep.elapsed().saturating_sub(INTERVAL);
+                    delays.lock().unwrap().push(delay);
+                }
+            }
+        });
+        // So that it's asleep before anything else gets to run
+        tokio::task::yield_now().await;
+        Self { delays, task }
+    }
+
+    /// Lets the probe wake up once more, to measure anything that kept it from waking up until
+    /// now, and stops it. Returns how late it woke up every time.
+    pub async fn stop(self) -> Delays {
+        tokio::time::sleep(INTERVAL * 2).await;
+        self.task.abort();
+        let delays = self.delays.lock().unwrap().clone();
+        Delays(delays)
+    }
+}
+
+/// How late the probe woke up every time
+#[derive(Debug, Clone, PartialEq)]
+pub struct Delays(pub Vec<Duration>);
+
+impl Delays {
+    /// The latest the probe woke up
+    pub fn worst(&self) -> Duration {
+        self.0.iter().copied().max().unwrap_or_default()
+    }
+
+    /// Whether the probe woke up more than `THRESHOLD` late at some point
+    pub fn blocked(&self) -> bool {
+        self.worst() > THRESHOLD
+    }
+}
+
+/// Handles every request in a task of its own, with `handler`, while the probe measures whether
+/// the runtime kept up. Returns the results, in the same order as the requests, and the probe's
+/// delays.
+pub async fn serve<F>(
+    requests: Vec<Data>,
+    handler: fn(Data) -> F,
+) -> (Vec<ComputationResult>, Delays)
+where
+    F: Future<Output = ComputationResult> + Send + 'static,
+{
+    let probe = Probe::start().await;
+    let tasks: Vec<_> = requests
+        .into_iter()
+        .map(|datum| tokio::spawn(handler(datum)))
+        .collect();
+    let mut results = Vec::with_capacity(tasks.len());
+    for task in tasks {
+        results.push(task.await.expect("A request panicked"));
+    }
+    (results, probe.stop().await)
+}
+
+/// Handles a request by calling `calculate` right on the runtime, which keeps every other task on
+/// its thread from running until it's done. Don't do this.
+pub async fn bad_handler(datum: Data) -> ComputationResult {
+    calculate(datum)
+}
+
+/// Handles a request like `bad_handler`, without blocking the runtime
+pub async fn handler(datum: Data) -> ComputationResult {
+    todo!()
+}
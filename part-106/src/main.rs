@@ -0,0 +1,82 @@
+use part_106::{bad_handler, handler, serve, INTERVAL, THRESHOLD};
+use part_5::Data;
+
+/// Run with `cargo run --release -p part-106` to see the probe catch the bad handler
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let args = common::cli::Cli::new("Probes the runtime while handling requests, badly and not")
+        .items(4)
+        .compute_ms(500)
+        .parse();
+    let requests = || (1..=args.items() as u64).map(Data).collect::<Vec<_>>();
+    println!("Probing every {INTERVAL:?}, and calling it blocked after {THRESHOLD:?}");
+
+    let (_, delays) = serve(requests(), bad_handler).await;
+    println!(
+        "bad_handler: the probe woke up {:.1?} late at worst, blocked: {}",
+        delays.worst(),
+        delays.blocked()
+    );
+    let (_, delays) = serve(requests(), handler).await;
+    println!(
+        "    handler: the probe woke up {:.1?} late at worst, blocked: {}",
+        delays.worst(),
+        delays.blocked()
+    );
+}
+
+#[cfg(test)]
+fn results(requests: u64) -> Vec<part_5::ComputationResult> {
+    (1..=requests)
+        .map(|i| part_5::ComputationResult(i * 2))
+        .collect()
+}
+
+// `calculate` takes 500 ms when used from another crate, which a probe waking up every 5 ms can't
+// miss
+
+#[tokio::test]
+async fn probe_catches_the_bad_handler() {
+    let (handled, delays) = serve(vec![Data(1), Data(2)], bad_handler).await;
+    assert_eq!(handled, results(2));
+    assert!(delays.blocked(), "{:?} late at worst", delays.worst());
+    // Both calculations ran on the only thread, one after the other, while the probe waited
+    assert!(delays.worst() >= std::time::Duration::from_millis(500));
+}
+
+#[tokio::test]
+async fn handler_handles_every_request() {
+    let (handled, _) = serve((1..=4).map(Data).collect(), handler).await;
+    assert_eq!(handled, results(4));
+}
+
+#[tokio::test]
+async fn probe_stays_on_time_only_after_the_fix() {
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    let (_, delays) = serve(vec![Data(1), Data(2)], bad_handler).await;
+    assert!(
+        delays.blocked(),
+        "bad_handler: {:?} late at worst",
+        delays.worst()
+    );
+    let (_, delays) = serve((1..=4).map(Data).collect(), handler).await;
+    assert!(
+        !delays.blocked(),
+        "handler: {:?} late at worst, over {THRESHOLD:?}",
+        delays.worst()
+    );
+    assert!(delays.0.len() > 50, "Only woke up {} times", delays.0.len());
+}
+
+#[tokio::test]
+async fn handler_handles_requests_at_once() {
+    let start = tokio::time::Instant::now();
+    serve((1..=4).map(Data).collect(), handler).await;
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    // Every calculation ran on a blocking thread of its own
+    assert!(start.elapsed() < std::time::Duration::from_millis(1000));
+}
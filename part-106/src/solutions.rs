@@ -0,0 +1,9 @@
+//! Part 106, with every exercise implemented. Only built with `--features solutions`.
+
+use part_5::{calculate, ComputationResult, Data};
+
+pub async fn handler(datum: Data) -> ComputationResult {
+    tokio::task::spawn_blocking(move || calculate(datum))
+        .await
+        .expect("The calculation panicked")
+}
@@ -0,0 +1,13 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-106 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_106::{handler, solutions};
+use part_5::Data;
+
+#[tokio::test]
+async fn handles_like_the_solution() {
+    // Every calculation takes half a second, so a couple of them are enough
+    let (handled, expected) = tokio::join!(handler(Data(106)), solutions::handler(Data(106)));
+    assert_eq!(handled, expected);
+}
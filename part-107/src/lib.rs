@@ -0,0 +1,159 @@
+use std::{
+    collections::BTreeMap,
+    sync::{mpsc::Sender, Arc, Mutex},
+    thread::{self, JoinHandle},
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Something a worker recorded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    pub worker: usize,
+    pub value: u64,
+}
+
+/// How many entries each worker recorded, and what their values added up to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub entries: usize,
+    pub total: u64,
+}
+
+/// The stats of every worker, by worker
+pub type Summary = BTreeMap<usize, Stats>;
+
+/// Adds up `entries` by worker
+pub fn summarize(entries: &[Entry]) -> Summary {
+    let mut summary = Summary::new();
+    for entry in entries {
+        let stats = summary.entry(entry.worker).or_default();
+        stats.entries += 1;
+        stats.total += entry.value;
+    }
+    summary
+}
+
+/// Collects the entries of many workers, which each record through a recorder of their own
+pub trait Aggregator {
+    type Recorder: Recorder;
+
+    /// A recorder for another worker
+    fn recorder(&self) -> Self::Recorder;
+
+    /// Waits until every recorder is gone, and sums up everything which was recorded
+    fn finish(self) -> Summary;
+}
+
+/// What a worker records its entries with
+pub trait Recorder: Send + 'static {
+    fn record(&self, entry: Entry);
+}
+
+/// Every worker pushes its entries to the same vector, behind a mutex, like in part 4
+#[derive(Debug, Default)]
+pub struct SharedAggregator {
+    entries: Arc<Mutex<Vec<Entry>>>,
+}
+
+/// Records to a `SharedAggregator`
+#[derive(Debug)]
+pub struct SharedRecorder {
+    entries: Arc<Mutex<Vec<Entry>>>,
+}
+
+impl Aggregator for SharedAggregator {
+    type Recorder = SharedRecorder;
+
+    fn recorder(&self) -> SharedRecorder {
+        SharedRecorder {
+            entries: self.entries.clone(),
+        }
+    }
+
+    fn finish(self) -> Summary {
+        // Only the aggregator is left holding the entries once every recorder is gone
+        let entries = Arc::try_unwrap(self.entries)
+            .expect("Every recorder should be gone when finishing")
+            .into_inner()
+            .unwrap();
+        summarize(&entries)
+    }
+}
+
+impl Recorder for SharedRecorder {
+    fn record(&self, entry: Entry) {
+        self.entries.lock().unwrap().push(entry);
+    }
+}
+
+/// The entries belong to a single owner thread, which the workers send them to over a channel, like
+/// in part 3. Nothing is shared, so nothing needs to be locked.
+#[derive(Debug)]
+pub struct OwnedAggregator {
+    sender: Sender<Entry>,
+    owner: JoinHandle<Summary>,
+}
+
+/// Records to an `OwnedAggregator`
+#[derive(Debug)]
+pub struct OwnedRecorder {
+    sender: Sender<Entry>,
+}
+
+impl OwnedAggregator {
+    /// Starts the owner thread, which collects the entries until every sender is gone, and then
+    /// sums them up
+    pub fn new() -> Self {
+        todo!()
+    }
+}
+
+impl Default for OwnedAggregator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Aggregator for OwnedAggregator {
+    type Recorder = OwnedRecorder;
+
+    fn recorder(&self) -> OwnedRecorder {
+        todo!()
+    }
+
+    fn finish(self) -> Summary {
+        todo!()
+    }
+}
+
+impl Recorder for OwnedRecorder {
+    fn record(&self, entry: Entry) {
+        todo!()
+    }
+}
+
+/// The value worker `worker` records as its `i`th entry
+pub fn value(worker: usize, i: usize) -> u64 {
+    (worker as u64 + 1) * 1000 + i as u64 % 100
+}
+
+/// Has `workers` threads record `per_worker` entries each to `aggregator`, as fast as they can, and
+/// returns its summary
+pub fn run(aggregator: impl Aggregator, workers: usize, per_worker: usize) -> Summary {
+    thread::scope(|s| {
+        for worker in 0..workers {
+            let recorder = aggregator.recorder();
+            s.spawn(move || {
+                for i in 0..per_worker {
+                    recorder.record(Entry {
+                        worker,
+                        value: value(worker, i),
+                    });
+                }
+            });
+        }
+    });
+    aggregator.finish()
+}
@@ -0,0 +1,84 @@
+use common::{bench::Comparison, cli::Cli};
+use part_107::{run, summarize, value, Entry, OwnedAggregator, SharedAggregator};
+
+/// Run with `cargo run --release -p part-107` to compare the two aggregators under contention
+fn main() {
+    let args = Cli::new("Aggregates entries behind a mutex, and in a thread which owns them")
+        .items(100_000)
+        .threads(8)
+        .parse();
+    let (per_worker, workers) = (args.items(), args.threads());
+    println!("{workers} workers recording {per_worker} entries each, as fast as they can");
+    let expected = run(SharedAggregator::default(), workers, per_worker);
+    let mut comparison = Comparison::new(10);
+    comparison
+        .bench("Arc<Mutex<Vec<_>>>", || {
+            assert_eq!(
+                run(SharedAggregator::default(), workers, per_worker),
+                expected
+            )
+        })
+        .bench("Owner thread", || {
+            assert_eq!(run(OwnedAggregator::new(), workers, per_worker), expected)
+        });
+    comparison.print();
+}
+
+#[cfg(test)]
+fn expected(workers: usize, per_worker: usize) -> part_107::Summary {
+    let entries: Vec<Entry> = (0..workers)
+        .flat_map(|worker| {
+            (0..per_worker).map(move |i| Entry {
+                worker,
+                value: value(worker, i),
+            })
+        })
+        .collect();
+    summarize(&entries)
+}
+
+#[test]
+fn shared_aggregator_sums_up_every_entry() {
+    assert_eq!(run(SharedAggregator::default(), 4, 1000), expected(4, 1000));
+}
+
+#[test]
+fn owned_aggregator_sums_up_like_the_shared_one() {
+    for (workers, per_worker) in [(1, 10), (4, 1000), (16, 100)] {
+        // Fails instead of hanging if the owner never stops collecting
+        let summary = common::with_timeout(std::time::Duration::from_secs(10), move || {
+            run(OwnedAggregator::new(), workers, per_worker)
+        });
+        assert_eq!(
+            summary,
+            Some(run(SharedAggregator::default(), workers, per_worker)),
+            "{workers} workers recording {per_worker} entries each"
+        );
+    }
+}
+
+#[test]
+fn owned_aggregator_without_entries() {
+    let summary = common::with_timeout(std::time::Duration::from_secs(10), || {
+        run(OwnedAggregator::new(), 0, 0)
+    });
+    assert_eq!(summary, Some(part_107::Summary::new()));
+}
+
+#[test]
+fn owned_aggregator_gets_entries_recorded_right_before_finishing() {
+    use part_107::{Aggregator, Recorder};
+
+    let summary = common::with_timeout(std::time::Duration::from_secs(10), || {
+        let aggregator = OwnedAggregator::new();
+        let recorder = aggregator.recorder();
+        for value in 1..=100 {
+            recorder.record(Entry { worker: 7, value });
+        }
+        drop(recorder);
+        aggregator.finish()
+    })
+    .expect("Finishing took too long");
+    assert_eq!(summary[&7].entries, 100);
+    assert_eq!(summary[&7].total, 5050);
+}
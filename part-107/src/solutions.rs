@@ -0,0 +1,59 @@
+//! Part 107, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    sync::mpsc::{self, Sender},
+    thread::{self, JoinHandle},
+};
+
+use crate::{summarize, Aggregator, Entry, Recorder, Summary};
+
+#[derive(Debug)]
+pub struct OwnedAggregator {
+    sender: Sender<Entry>,
+    owner: JoinHandle<Summary>,
+}
+
+#[derive(Debug)]
+pub struct OwnedRecorder {
+    sender: Sender<Entry>,
+}
+
+impl OwnedAggregator {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let owner = thread::spawn(move || {
+            // Only this thread ever touches the entries
+            let entries: Vec<Entry> = receiver.iter().collect();
+            summarize(&entries)
+        });
+        Self { sender, owner }
+    }
+}
+
+impl Default for OwnedAggregator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Aggregator for OwnedAggregator {
+    type Recorder = OwnedRecorder;
+
+    fn recorder(&self) -> OwnedRecorder {
+        OwnedRecorder {
+            sender: self.sender.clone(),
+        }
+    }
+
+    fn finish(self) -> Summary {
+        // The owner stops collecting once every sender is gone, this one included
+        drop(self.sender);
+        self.owner.join().expect("The owner panicked")
+    }
+}
+
+impl Recorder for OwnedRecorder {
+    fn record(&self, entry: Entry) {
+        self.sender.send(entry).expect("The owner is gone");
+    }
+}
@@ -0,0 +1,19 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-107 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_107::{run, solutions, OwnedAggregator};
+
+#[test]
+fn aggregates_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for _ in 0..3 {
+        let workers = 1 + rng.below(8) as usize;
+        let per_worker = rng.below(2000) as usize;
+        assert_eq!(
+            run(OwnedAggregator::new(), workers, per_worker),
+            run(solutions::OwnedAggregator::new(), workers, per_worker),
+            "{workers} workers recording {per_worker} entries each"
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use std::{cell::Cell, thread};
+
+use common::rng::Rng;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// What running a task gives, and what running many of them adds up to
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Output {
+    /// How many tasks went into it
+    pub tasks: usize,
+    /// What the tasks counted, added up
+    pub total: u64,
+    /// What the tasks said, in the order of the tasks
+    pub lines: Vec<String>,
+}
+
+impl Output {
+    /// The output of `self`'s tasks followed by `other`'s. Associative, so the outputs of a list of
+    /// tasks can be combined in any grouping, but not commutative, since the order of the lines
+    /// matters. `Output::default()` is the output of no tasks at all, and changes nothing.
+    pub fn combine(mut self, other: Output) -> Output {
+        self.tasks += other.tasks;
+        self.total = self.total.wrapping_add(other.total);
+        self.lines.extend(other.lines);
+        self
+    }
+}
+
+/// Something to run, which can be any type, so that a list of them has to be trait objects
+pub trait Task {
+    fn run(&self) -> Output;
+}
+
+/// Adds up the numbers from `from` up to, but not including, `to`
+#[derive(Debug, Clone)]
+pub struct Sum {
+    pub from: u64,
+    pub to: u64,
+}
+
+impl Task for Sum {
+    fn run(&self) -> Output {
+        Output {
+            tasks: 1,
+            total: (self.from..self.to).fold(0, u64::wrapping_add),
+            lines: Vec::new(),
+        }
+    }
+}
+
+/// Says every word of a text, and counts them
+#[derive(Debug, Clone)]
+pub struct Words(pub String);
+
+impl Task for Words {
+    fn run(&self) -> Output {
+        let lines: Vec<String> = self.0.split_whitespace().map(str::to_string).collect();
+        Output {
+            tasks: 1,
+            total: lines.len() as u64,
+            lines,
+        }
+    }
+}
+
+/// Counts the steps from `n` down to 1 in the Collatz sequence, remembering the answer for the next
+/// time it's run. The `Cell` it's remembered in can be moved to another thread, but not shared
+/// between threads, so `Collatz` is `Send`, but not `Sync`.
+#[derive(Debug, Clone)]
+pub struct Collatz {
+    pub n: u64,
+    steps: Cell<Option<u64>>,
+}
+
+impl Collatz {
+    pub fn new(n: u64) -> Self {
+        Self {
+            n,
+            steps: Cell::new(None),
+        }
+    }
+}
+
+impl Task for Collatz {
+    fn run(&self) -> Output {
+        let steps = self.steps.get().unwrap_or_else(|| {
+            let (mut n, mut steps) = (self.n.max(1), 0);
+            while n != 1 {
+                n = if n % 2 == 0 { n / 2 } else { 3 * n + 1 };
+                steps += 1;
+            }
+            steps
+        });
+        self.steps.set(Some(steps));
+        Output {
+            tasks: 1,
+            total: steps,
+            lines: vec![format!("{} takes {steps} steps", self.n)],
+        }
+    }
+}
+
+const WORDS: [&str; 8] = [
+    "fearless",
+    "concurrency",
+    "send",
+    "sync",
+    "mutex",
+    "channel",
+    "thread",
+    "scope",
+];
+
+/// A task which only holds types that can be shared between threads, picked by `rng`
+fn shareable(rng: &mut Rng) -> Box<dyn Task + Send + Sync> {
+    if rng.below(2) == 0 {
+        let from = rng.below(1000);
+        Box::new(Sum {
+            from,
+            to: from + rng.below(100_000),
+        })
+    } else {
+        let words = (0..1 + rng.below(6)).map(|_| WORDS[rng.below(8) as usize]);
+        Box::new(Words(words.collect::<Vec<_>>().join(" ")))
+    }
+}
+
+/// `count` tasks of every kind, the same ones for the same `seed`
+pub fn mix(count: usize, seed: u64) -> Vec<Box<dyn Task + Send>> {
+    let mut rng = Rng::new(seed);
+    (0..count)
+        .map(|_| match rng.below(3) {
+            0 => Box::new(Collatz::new(1 + rng.below(100_000))),
+            // A `Send + Sync` task is `Send` too
+            _ => shareable(&mut rng) as Box<dyn Task + Send>,
+        })
+        .collect()
+}
+
+/// `count` tasks which can be shared between threads, so no `Collatz`, the same ones for the same
+/// `seed`
+pub fn shareable_mix(count: usize, seed: u64) -> Vec<Box<dyn Task + Send + Sync>> {
+    let mut rng = Rng::new(seed);
+    (0..count).map(|_| shareable(&mut rng)).collect()
+}
+
+/// Runs every task, one after the other, and combines their outputs in order
+pub fn run_serial<T: Task + ?Sized>(tasks: &[Box<T>]) -> Output {
+    tasks
+        .iter()
+        .map(|task| task.run())
+        .fold(Output::default(), Output::combine)
+}
+
+/// Runs the tasks on `workers` threads, and combines their outputs into the same output as
+/// `run_serial`. The tasks are `Send`, but not `Sync`, so every one of them can be moved to the
+/// thread which runs it, but not shared with it.
+pub fn run_parallel(tasks: Vec<Box<dyn Task + Send>>, workers: usize) -> Output {
+    todo!()
+}
+
+/// Runs the tasks on `workers` threads like `run_parallel`, but without taking them, so that they
+/// can be run again. Sharing them with the threads needs them to be `Sync` as well.
+pub fn run_shared(tasks: &[Box<dyn Task + Send + Sync>], workers: usize) -> Output {
+    todo!()
+}
@@ -0,0 +1,83 @@
+use common::{cli::Cli, timed};
+use part_108::{mix, run_parallel, run_serial, run_shared, shareable_mix};
+
+/// Run with `cargo run --release -p part-108`
+fn main() {
+    let args = Cli::new("Runs a mix of tasks one after the other, and on several threads")
+        .items(10_000)
+        .threads(8)
+        .parse();
+    let (count, workers) = (args.items(), args.threads());
+
+    let expected = timed("Serial", || run_serial(&mix(count, 108)));
+    let output = timed("Moved to the threads", || {
+        run_parallel(mix(count, 108), workers)
+    });
+    assert_eq!(output, expected);
+
+    let tasks = shareable_mix(count, 108);
+    let expected = run_serial(&tasks);
+    let output = timed("Shared with the threads", || run_shared(&tasks, workers));
+    assert_eq!(output, expected);
+    println!(
+        "{} tasks counted {} and said {} lines",
+        output.tasks,
+        output.total,
+        output.lines.len()
+    );
+}
+
+#[test]
+fn combine_is_associative() {
+    use part_108::{Output, Sum, Task, Words};
+
+    let outputs: Vec<Output> = [
+        Box::new(Words("send sync".to_string())) as Box<dyn Task>,
+        Box::new(Sum { from: 1, to: 11 }),
+        Box::new(Words("scope".to_string())),
+    ]
+    .iter()
+    .map(|task| task.run())
+    .collect();
+    let [a, b, c] = [0, 1, 2].map(|i| outputs[i].clone());
+    let left = a.clone().combine(b.clone()).combine(c.clone());
+    let right = a.clone().combine(b.combine(c));
+    assert_eq!(left, right);
+    assert_eq!(left.lines, ["send", "sync", "scope"]);
+    assert_eq!((left.tasks, left.total), (3, 2 + 55 + 1));
+    assert_eq!(Output::default().combine(a.clone()), a);
+}
+
+#[test]
+fn parallel_matches_serial_on_a_seeded_mix() {
+    let mut rng = common::rng::for_test();
+    let seed = rng.seed();
+    for (count, workers) in [(100, 1), (100, 3), (1000, 8), (5, 8)] {
+        let expected = run_serial(&mix(count, seed));
+        // Fails instead of hanging if the threads never finish
+        let output = common::with_timeout(std::time::Duration::from_secs(10), move || {
+            run_parallel(mix(count, seed), workers)
+        });
+        assert_eq!(output, Some(expected), "{count} tasks on {workers} workers");
+    }
+}
+
+#[test]
+fn shared_matches_serial_on_a_seeded_mix() {
+    let mut rng = common::rng::for_test();
+    let tasks = shareable_mix(500, rng.seed());
+    let expected = run_serial(&tasks);
+    for workers in [1, 3, 8] {
+        assert_eq!(run_shared(&tasks, workers), expected, "{workers} workers");
+    }
+    // The tasks were only borrowed, so they can be run again
+    assert_eq!(run_shared(&tasks, 2), expected);
+}
+
+#[test]
+fn runs_no_tasks() {
+    use part_108::Output;
+
+    assert_eq!(run_parallel(Vec::new(), 4), Output::default());
+    assert_eq!(run_shared(&[], 4), Output::default());
+}
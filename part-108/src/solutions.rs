@@ -0,0 +1,43 @@
+//! Part 108, with every exercise implemented. Only built with `--features solutions`.
+
+use std::thread;
+
+use crate::{run_serial, Output, Task};
+
+/// Runs the tasks on `workers` threads, and combines their outputs into the same output as
+/// `run_serial`. The tasks are `Send`, but not `Sync`, so every one of them can be moved to the
+/// thread which runs it, but not shared with it.
+pub fn run_parallel(tasks: Vec<Box<dyn Task + Send>>, workers: usize) -> Output {
+    let chunk_size = tasks.len().div_ceil(workers).max(1);
+    let mut tasks = tasks.into_iter();
+    thread::scope(|s| {
+        // Every thread owns the tasks it runs, in the order they came in
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                let chunk: Vec<_> = tasks.by_ref().take(chunk_size).collect();
+                s.spawn(move || run_serial(&chunk))
+            })
+            .collect();
+        // Combining the chunks in order gives the same output as combining the tasks in order
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("Couldn't join thread"))
+            .fold(Output::default(), Output::combine)
+    })
+}
+
+/// Runs the tasks on `workers` threads like `run_parallel`, but without taking them, so that they
+/// can be run again. Sharing them with the threads needs them to be `Sync` as well.
+pub fn run_shared(tasks: &[Box<dyn Task + Send + Sync>], workers: usize) -> Output {
+    let chunk_size = tasks.len().div_ceil(workers).max(1);
+    thread::scope(|s| {
+        let handles: Vec<_> = tasks
+            .chunks(chunk_size)
+            .map(|chunk| s.spawn(move || run_serial(chunk)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("Couldn't join thread"))
+            .fold(Output::default(), Output::combine)
+    })
+}
@@ -0,0 +1,25 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-108 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_108::{mix, run_parallel, run_shared, shareable_mix, solutions};
+
+#[test]
+fn runs_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for _ in 0..3 {
+        let (count, workers) = (rng.below(500) as usize, 1 + rng.below(8) as usize);
+        let seed = rng.seed();
+        assert_eq!(
+            run_parallel(mix(count, seed), workers),
+            solutions::run_parallel(mix(count, seed), workers),
+            "{count} tasks on {workers} workers"
+        );
+        let tasks = shareable_mix(count, seed);
+        assert_eq!(
+            run_shared(&tasks, workers),
+            solutions::run_shared(&tasks, workers),
+            "{count} shareable tasks on {workers} workers"
+        );
+    }
+}
@@ -0,0 +1,25 @@
+//! Three puzzles which don't compile, in `tests/ui`, and the same programs as exercises here, to be
+//! implemented so that they do. Every exercise names the puzzle it's the fixed version of.
+
+use std::sync::Mutex;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Adds `amount` to `total` `times` times, on another thread, like `tests/ui/mut_local_to_thread.rs`
+/// tries to. `total` has the sum in it once this returns.
+pub fn add_on_thread(total: &mut u64, amount: u64, times: usize) {
+    todo!()
+}
+
+/// Sends `values` to a worker thread, which pushes every one of them onto `numbers`, in order, like
+/// `tests/ui/guard_over_channel.rs` tries to. Every value is in `numbers` once this returns.
+pub fn push_on_worker(numbers: &Mutex<Vec<u64>>, values: Vec<u64>) {
+    todo!()
+}
+
+/// The longest word of `text`, the first one if several are as long, found on another thread,
+/// like `tests/ui/borrow_from_thread.rs` tries to. Empty if `text` has no words.
+pub fn longest_word(text: String) -> String {
+    todo!()
+}
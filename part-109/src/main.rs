@@ -0,0 +1,72 @@
+use std::sync::Mutex;
+
+use part_109::{add_on_thread, longest_word, push_on_worker};
+
+/// Run with `cargo run -p part-109`
+fn main() {
+    let mut total = 0;
+    add_on_thread(&mut total, 5, 10);
+    println!("Added up to {total} on another thread");
+
+    let numbers = Mutex::new(Vec::new());
+    push_on_worker(&numbers, vec![1, 2, 3]);
+    println!("The worker pushed {:?}", numbers.into_inner().unwrap());
+
+    let word = longest_word("make this compile".to_string());
+    println!("The longest word is {word:?}");
+}
+
+#[test]
+fn adds_to_the_borrowed_total() {
+    let mut total = 7;
+    add_on_thread(&mut total, 5, 10);
+    assert_eq!(
+        total, 57,
+        "The thread has to add to `total`, not to a copy of it"
+    );
+}
+
+#[test]
+fn adds_nothing_zero_times() {
+    let mut total = 7;
+    add_on_thread(&mut total, 5, 0);
+    assert_eq!(total, 7);
+}
+
+#[test]
+fn pushes_every_value_in_order() {
+    let mut rng = common::rng::for_test();
+    let values: Vec<u64> = (0..100).map(|_| rng.below(1000)).collect();
+    let numbers = Mutex::new(vec![42]);
+    push_on_worker(&numbers, values.clone());
+    let numbers = numbers.into_inner().unwrap();
+    assert_eq!(numbers[0], 42, "The numbers which were there stay first");
+    assert_eq!(
+        numbers[1..],
+        values,
+        "Every value is pushed once it returns"
+    );
+}
+
+#[test]
+fn leaves_the_mutex_unlocked() {
+    let numbers = Mutex::new(Vec::new());
+    push_on_worker(&numbers, vec![1, 2, 3]);
+    assert!(
+        numbers.try_lock().is_ok(),
+        "The worker's guard has to be dropped"
+    );
+}
+
+#[test]
+fn finds_the_first_longest_word() {
+    assert_eq!(longest_word("make this compile".to_string()), "compile");
+    assert_eq!(longest_word("one two six ten".to_string()), "one");
+    assert_eq!(longest_word("  spaced\tout\n".to_string()), "spaced");
+}
+
+#[test]
+fn finds_no_word_in_empty_text() {
+    assert_eq!(longest_word(String::new()), "");
+    assert_eq!(longest_word(" \n ".to_string()), "");
+}
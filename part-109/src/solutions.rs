@@ -0,0 +1,47 @@
+//! Part 109, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    sync::{mpsc, Mutex},
+    thread,
+};
+
+pub fn add_on_thread(total: &mut u64, amount: u64, times: usize) {
+    // A scoped thread is joined before `scope` returns, so it can borrow `total` mutably
+    thread::scope(|s| {
+        s.spawn(|| {
+            for _ in 0..times {
+                *total += amount;
+            }
+        });
+    });
+}
+
+pub fn push_on_worker(numbers: &Mutex<Vec<u64>>, values: Vec<u64>) {
+    // The values go over the channel, and the worker locks the mutex on its own thread
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|s| {
+        s.spawn(move || {
+            for value in receiver {
+                numbers.lock().unwrap().push(value);
+            }
+        });
+        for value in values {
+            sender
+                .send(value)
+                .expect("The worker runs until the sender is dropped");
+        }
+        drop(sender);
+    });
+}
+
+pub fn longest_word(text: String) -> String {
+    let handle = thread::spawn(move || {
+        // Owned, so it outlives `text`, which is dropped when the closure returns
+        text.split_whitespace()
+            .rev()
+            .max_by_key(|word| word.len())
+            .unwrap_or_default()
+            .to_string()
+    });
+    handle.join().expect("Couldn't join thread")
+}
@@ -0,0 +1,7 @@
+// Cases in `tests/ui` are the puzzles, which are expected to fail compilation.
+// The expected compiler output is stored next to each case in a `.stderr`-file.
+#[test]
+fn puzzles_do_not_compile() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
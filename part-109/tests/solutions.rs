@@ -0,0 +1,39 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-109 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::sync::Mutex;
+
+use part_109::{add_on_thread, longest_word, push_on_worker, solutions};
+
+#[test]
+fn fixes_the_puzzles_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for _ in 0..3 {
+        let (amount, times) = (rng.below(100), rng.below(100) as usize);
+        let (mut total, mut expected) = (0, 0);
+        add_on_thread(&mut total, amount, times);
+        solutions::add_on_thread(&mut expected, amount, times);
+        assert_eq!(total, expected, "Adding {amount} {times} times");
+
+        let values: Vec<u64> = (0..rng.below(50)).map(|_| rng.below(1000)).collect();
+        let (numbers, expected) = (Mutex::new(Vec::new()), Mutex::new(Vec::new()));
+        push_on_worker(&numbers, values.clone());
+        solutions::push_on_worker(&expected, values.clone());
+        assert_eq!(
+            numbers.into_inner().unwrap(),
+            expected.into_inner().unwrap()
+        );
+
+        let words = ["send", "sync", "borrow", "move", "scope", "guard", "thread"];
+        let text: Vec<_> = (0..rng.below(20))
+            .map(|_| words[rng.below(words.len() as u64) as usize])
+            .collect();
+        let text = text.join(" ");
+        assert_eq!(
+            longest_word(text.clone()),
+            solutions::longest_word(text.clone()),
+            "In {text:?}"
+        );
+    }
+}
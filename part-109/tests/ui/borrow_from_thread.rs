@@ -0,0 +1,13 @@
+// The thread owns `text`, and drops it when its closure returns, so the closure can't return a
+// borrow of it: `main` would get a word of a string which is gone
+use std::thread;
+
+fn main() {
+    let text = String::from("make this compile");
+    let handle = thread::spawn(move || {
+        text.split_whitespace()
+            .max_by_key(|word| word.len())
+            .unwrap_or_default()
+    });
+    println!("{}", handle.join().unwrap());
+}
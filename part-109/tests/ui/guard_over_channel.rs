@@ -0,0 +1,20 @@
+// Only the thread which locked a mutex may unlock it, so a guard can't be sent to another
+// thread, not even over a channel, which is `Send` only for messages which are
+use std::{
+    sync::{mpsc, Mutex, MutexGuard},
+    thread,
+};
+
+static NUMBERS: Mutex<Vec<u64>> = Mutex::new(Vec::new());
+
+fn main() {
+    let (sender, receiver) = mpsc::channel::<MutexGuard<Vec<u64>>>();
+    let worker = thread::spawn(move || {
+        for mut guard in receiver {
+            guard.push(1);
+        }
+    });
+    sender.send(NUMBERS.lock().unwrap()).unwrap();
+    drop(sender);
+    worker.join().unwrap();
+}
@@ -0,0 +1,15 @@
+// A thread started with `thread::spawn` may outlive the function which started it, so it can't
+// borrow that function's local variables, and a mutable borrow would let it change `total` while
+// `main` reads it. Adding `move` makes it compile, but then the thread adds to its own copy.
+use std::thread;
+
+fn main() {
+    let mut total = 0;
+    let handle = thread::spawn(|| {
+        for _ in 0..10 {
+            total += 5;
+        }
+    });
+    handle.join().unwrap();
+    println!("{total}");
+}
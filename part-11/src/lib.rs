@@ -0,0 +1,77 @@
+use std::{
+    collections::VecDeque,
+    sync::{Condvar, Mutex},
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Returned when pushing to a closed queue, giving the item back
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueueClosed<T>(pub T);
+
+/// A first-in-first-out queue holding at most `capacity` items,
+/// which can be shared between any number of producers and consumers.
+#[derive(Debug)]
+pub struct BoundedQueue<T> {
+    state: Mutex<State<T>>,
+    /// Notified when an item is pushed, or the queue is closed
+    not_empty: Condvar,
+    /// Notified when an item is popped, or the queue is closed
+    not_full: Condvar,
+    capacity: usize,
+}
+
+#[derive(Debug)]
+struct State<T> {
+    items: VecDeque<T>,
+    closed: bool,
+}
+
+impl<T> BoundedQueue<T> {
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "A queue without room for any items is not very useful"
+        );
+        Self {
+            state: Mutex::new(State {
+                items: VecDeque::with_capacity(capacity),
+                closed: false,
+            }),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            capacity,
+        }
+    }
+
+    /// Adds `item` to the back of the queue, waiting for room if the queue is full.
+    /// Fails if the queue is closed.
+    pub fn push(&self, item: T) -> Result<(), QueueClosed<T>> {
+        todo!()
+    }
+
+    /// Removes the item at the front of the queue, waiting for one if the queue is empty.
+    /// Returns `None` once the queue is closed and every item has been popped.
+    pub fn pop(&self) -> Option<T> {
+        todo!()
+    }
+
+    /// Closes the queue. No more items can be pushed, but those already
+    /// in the queue can still be popped. Anyone waiting is woken up.
+    pub fn close(&self) {
+        todo!()
+    }
+
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
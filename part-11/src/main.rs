@@ -0,0 +1,155 @@
+use std::{sync::Arc, thread, time::Duration};
+
+use part_11::BoundedQueue;
+
+fn main() {
+    let queue = Arc::new(BoundedQueue::new(2));
+
+    let producer = {
+        let queue = queue.clone();
+        thread::spawn(move || {
+            for x in 0..10 {
+                queue.push(x).expect("Queue was closed");
+                println!("Pushed {x}");
+            }
+            queue.close();
+        })
+    };
+
+    while let Some(x) = queue.pop() {
+        println!("Popped {x}");
+        // A slow consumer makes the producer wait for room
+        thread::sleep(Duration::from_millis(50));
+    }
+    producer.join().expect("Couldn't join thread");
+}
+
+/// Waits a little while and checks that `handle` is still blocked
+#[cfg(test)]
+fn assert_blocked<T>(handle: &thread::JoinHandle<T>) {
+    thread::sleep(Duration::from_millis(50));
+    assert!(!handle.is_finished(), "Thread should be waiting");
+}
+
+#[cfg(test)]
+fn assert_unblocked<T: Send + 'static>(handle: thread::JoinHandle<T>) -> T {
+    common::with_timeout(Duration::from_secs(1), move || handle.join())
+        .expect("Thread should have stopped waiting")
+        .expect("Thread panicked")
+}
+
+#[test]
+fn pops_in_fifo_order() {
+    let queue = BoundedQueue::new(5);
+    for x in 0..5 {
+        queue.push(x).unwrap();
+    }
+    assert_eq!(queue.len(), 5);
+
+    let popped: Vec<_> = (0..5).map(|_| queue.pop().unwrap()).collect();
+    assert_eq!(popped, vec![0, 1, 2, 3, 4]);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn push_waits_while_full() {
+    let queue = Arc::new(BoundedQueue::new(2));
+    queue.push(1).unwrap();
+    queue.push(2).unwrap();
+
+    let pusher = {
+        let queue = queue.clone();
+        thread::spawn(move || queue.push(3))
+    };
+    assert_blocked(&pusher);
+    assert_eq!(queue.len(), 2);
+
+    assert_eq!(queue.pop(), Some(1));
+    assert_eq!(assert_unblocked(pusher), Ok(()));
+    assert_eq!(queue.pop(), Some(2));
+    assert_eq!(queue.pop(), Some(3));
+}
+
+#[test]
+fn pop_waits_while_empty() {
+    let queue = Arc::new(BoundedQueue::new(2));
+
+    let popper = {
+        let queue = queue.clone();
+        thread::spawn(move || queue.pop())
+    };
+    assert_blocked(&popper);
+
+    queue.push(42).unwrap();
+    assert_eq!(assert_unblocked(popper), Some(42));
+}
+
+#[test]
+fn close_wakes_everyone() {
+    let queue = Arc::new(BoundedQueue::<i32>::new(1));
+    let poppers: Vec<_> = (0..3)
+        .map(|_| {
+            let queue = queue.clone();
+            thread::spawn(move || queue.pop())
+        })
+        .collect();
+    for popper in &poppers {
+        assert_blocked(popper);
+    }
+
+    queue.close();
+    for popper in poppers {
+        assert_eq!(assert_unblocked(popper), None);
+    }
+}
+
+#[test]
+fn closed_queue_is_drained_but_refuses_items() {
+    let queue = BoundedQueue::new(2);
+    queue.push(1).unwrap();
+    queue.close();
+
+    assert_eq!(queue.push(2), Err(part_11::QueueClosed(2)));
+    assert_eq!(queue.pop(), Some(1));
+    assert_eq!(queue.pop(), None);
+}
+
+#[test]
+fn many_producers_and_consumers() {
+    let queue = Arc::new(BoundedQueue::new(8));
+    let producers: Vec<_> = (0..4)
+        .map(|producer| {
+            let queue = queue.clone();
+            thread::spawn(move || {
+                for x in 0..1000 {
+                    queue.push(producer * 1000 + x).unwrap();
+                }
+            })
+        })
+        .collect();
+    let consumers: Vec<_> = (0..4)
+        .map(|_| {
+            let queue = queue.clone();
+            thread::spawn(move || {
+                let mut popped = Vec::new();
+                while let Some(x) = queue.pop() {
+                    popped.push(x);
+                }
+                popped
+            })
+        })
+        .collect();
+
+    for producer in producers {
+        producer.join().unwrap();
+    }
+    queue.close();
+
+    let mut popped: Vec<_> = consumers
+        .into_iter()
+        .flat_map(|consumer| consumer.join().unwrap())
+        .collect();
+    popped.sort();
+    // Nothing lost, nothing popped twice
+    assert_eq!(popped, (0..4000).collect::<Vec<_>>());
+}
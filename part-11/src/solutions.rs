@@ -0,0 +1,93 @@
+//! Part 11, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    collections::VecDeque,
+    sync::{Condvar, Mutex},
+};
+
+use super::{QueueClosed, State};
+
+/// A first-in-first-out queue holding at most `capacity` items,
+/// which can be shared between any number of producers and consumers.
+#[derive(Debug)]
+pub struct BoundedQueue<T> {
+    state: Mutex<State<T>>,
+    /// Notified when an item is pushed, or the queue is closed
+    not_empty: Condvar,
+    /// Notified when an item is popped, or the queue is closed
+    not_full: Condvar,
+    capacity: usize,
+}
+
+impl<T> BoundedQueue<T> {
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "A queue without room for any items is not very useful"
+        );
+        Self {
+            state: Mutex::new(State {
+                items: VecDeque::with_capacity(capacity),
+                closed: false,
+            }),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            capacity,
+        }
+    }
+
+    /// Adds `item` to the back of the queue, waiting for room if the queue is full.
+    /// Fails if the queue is closed.
+    pub fn push(&self, item: T) -> Result<(), QueueClosed<T>> {
+        // `wait_while` handles spurious wakeups by checking the condition again after every wakeup
+        let mut state = self
+            .not_full
+            .wait_while(self.state.lock().unwrap(), |state| {
+                !state.closed && state.items.len() == self.capacity
+            })
+            .unwrap();
+        if state.closed {
+            return Err(QueueClosed(item));
+        }
+        state.items.push_back(item);
+        // Only one item was added, so there's no use in waking more than one consumer
+        self.not_empty.notify_one();
+        Ok(())
+    }
+
+    /// Removes the item at the front of the queue, waiting for one if the queue is empty.
+    /// Returns `None` once the queue is closed and every item has been popped.
+    pub fn pop(&self) -> Option<T> {
+        let mut state = self
+            .not_empty
+            .wait_while(self.state.lock().unwrap(), |state| {
+                !state.closed && state.items.is_empty()
+            })
+            .unwrap();
+        // If the queue is closed there might still be items left
+        let item = state.items.pop_front()?;
+        self.not_full.notify_one();
+        Some(item)
+    }
+
+    /// Closes the queue. No more items can be pushed, but those already
+    /// in the queue can still be popped. Anyone waiting is woken up.
+    pub fn close(&self) {
+        self.state.lock().unwrap().closed = true;
+        // Everyone waiting needs to find out, not just one of them
+        self.not_empty.notify_all();
+        self.not_full.notify_all();
+    }
+
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
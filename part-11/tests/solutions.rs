@@ -0,0 +1,65 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-11 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{sync::Arc, thread};
+
+use part_11::{solutions, BoundedQueue};
+
+#[test]
+fn queues_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (queue, expected) = (BoundedQueue::new(4), solutions::BoundedQueue::new(4));
+    let ops = common::datagen::numbers_below(1000, 20, rng.seed());
+    for (i, &op) in ops.iter().enumerate() {
+        // Only what wouldn't wait forever on a single thread
+        match op {
+            0 => {
+                queue.close();
+                expected.close();
+            }
+            1..=9 if expected.len() < expected.capacity() => assert_eq!(
+                queue.push(i).map_err(|closed| closed.0),
+                expected.push(i).map_err(|closed| closed.0),
+                "Pushing {i}"
+            ),
+            _ if !expected.is_empty() || i > ops.len() / 2 => {
+                if expected.is_empty() {
+                    queue.close();
+                    expected.close();
+                }
+                assert_eq!(queue.pop(), expected.pop(), "Popping after {i} operations");
+            }
+            _ => {}
+        }
+        assert_eq!(queue.len(), expected.len());
+    }
+}
+
+#[test]
+fn hands_over_everything_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let items = common::datagen::numbers(10_000, rng.seed());
+
+    let queue = Arc::new(BoundedQueue::new(8));
+    let consumer = {
+        let queue = queue.clone();
+        thread::spawn(move || std::iter::from_fn(|| queue.pop()).collect::<Vec<_>>())
+    };
+    for &item in &items {
+        queue.push(item).unwrap();
+    }
+    queue.close();
+
+    let expected = Arc::new(solutions::BoundedQueue::new(8));
+    let expected_consumer = {
+        let expected = expected.clone();
+        thread::spawn(move || std::iter::from_fn(|| expected.pop()).collect::<Vec<_>>())
+    };
+    for &item in &items {
+        expected.push(item).unwrap();
+    }
+    expected.close();
+
+    assert_eq!(consumer.join().unwrap(), expected_consumer.join().unwrap());
+}
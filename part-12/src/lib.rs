@@ -0,0 +1,56 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// The next state of cell `i`: alive (`1`) if exactly one of its two neighbours is alive.
+/// Cells outside of `cells` are dead. This is the cellular automaton known as "rule 90".
+pub fn next_cell(cells: &[u8], i: usize) -> u8 {
+    let left = if i == 0 { 0 } else { cells[i - 1] };
+    let right = cells.get(i + 1).copied().unwrap_or(0);
+    left ^ right
+}
+
+/// Same as `next_cell`, for cells stored in atomics.
+/// The loads are `Relaxed`, it's up to you to make sure the neighbours are up to date!
+pub fn next_atomic_cell(cells: &[AtomicU8], i: usize) -> u8 {
+    let left = if i == 0 {
+        0
+    } else {
+        cells[i - 1].load(Ordering::Relaxed)
+    };
+    let right = cells
+        .get(i + 1)
+        .map(|cell| cell.load(Ordering::Relaxed))
+        .unwrap_or(0);
+    left ^ right
+}
+
+/// Calculates `rounds` generations of `cells`, one after another
+pub fn serial_steps(cells: &[u8], rounds: usize) -> Vec<u8> {
+    let mut current = cells.to_vec();
+    for _ in 0..rounds {
+        current = (0..current.len()).map(|i| next_cell(&current, i)).collect();
+    }
+    current
+}
+
+/// Calculates `rounds` generations of `cells` like `serial_steps`, but splits the
+/// cells between `threads` threads which each calculate their own part of every generation.
+/// A generation can only be started once every thread is done with the previous one.
+///
+/// Each thread should call `on_round(round)` when it starts on a round,
+/// which lets the tests check that no thread races ahead.
+pub fn parallel_steps(
+    cells: &[u8],
+    rounds: usize,
+    threads: usize,
+    on_round: impl Fn(usize) + Sync,
+) -> Vec<u8> {
+    todo!()
+}
+
+/// Copies `cells` into atomics, which every thread can read from and write to
+pub fn to_atomic(cells: &[u8]) -> Vec<AtomicU8> {
+    cells.iter().map(|&cell| AtomicU8::new(cell)).collect()
+}
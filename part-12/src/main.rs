@@ -0,0 +1,76 @@
+use part_12::{parallel_steps, serial_steps};
+
+fn main() {
+    let args = common::cli::Cli::new("Draws a cellular automaton, generations in parallel")
+        .threads(4)
+        .parse();
+    let width = 63;
+    let mut cells = vec![0; width];
+    cells[width / 2] = 1;
+
+    for round in 0..32 {
+        let generation = parallel_steps(&cells, round, args.threads(), |_| ());
+        assert_eq!(generation, serial_steps(&cells, round));
+        let line: String = generation
+            .iter()
+            .map(|&cell| if cell == 1 { '#' } else { ' ' })
+            .collect();
+        println!("{line}");
+    }
+}
+
+#[cfg(test)]
+fn random_cells(len: usize) -> Vec<u8> {
+    // A simple pseudo-random generator is good enough to get some variation
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % 2) as u8
+        })
+        .collect()
+}
+
+#[test]
+fn same_result_as_serial() {
+    let cells = random_cells(1000);
+    for threads in [1, 2, 3, 8] {
+        assert_eq!(
+            parallel_steps(&cells, 50, threads, |_| ()),
+            serial_steps(&cells, 50),
+            "Wrong result with {threads} threads"
+        );
+    }
+}
+
+#[test]
+fn handles_more_threads_than_cells() {
+    let cells = vec![1, 0, 1];
+    assert_eq!(
+        parallel_steps(&cells, 5, 8, |_| ()),
+        serial_steps(&cells, 5)
+    );
+}
+
+#[test]
+fn no_thread_races_ahead() {
+    use std::sync::Mutex;
+
+    let started = Mutex::new(Vec::new());
+    let cells = random_cells(1000);
+    let threads = 4;
+    let rounds = 100;
+    parallel_steps(&cells, rounds, threads, |round| {
+        started.lock().unwrap().push(round)
+    });
+
+    let started = started.into_inner().unwrap();
+    assert_eq!(started.len(), threads * rounds);
+    // Every thread has to start a round before anyone starts the next one
+    assert!(
+        started.windows(2).all(|pair| pair[0] <= pair[1]),
+        "A thread started a round before everyone finished the previous one"
+    );
+}
@@ -0,0 +1,48 @@
+//! Part 12, with every exercise implemented. Only built with `--features solutions`.
+
+use std::sync::{atomic::Ordering, Barrier};
+
+use super::{next_atomic_cell, to_atomic};
+
+/// Calculates `rounds` generations of `cells` like `serial_steps`, but splits the
+/// cells between `threads` threads which each calculate their own part of every generation.
+/// A generation can only be started once every thread is done with the previous one.
+///
+/// Each thread should call `on_round(round)` when it starts on a round,
+/// which lets the tests check that no thread races ahead.
+pub fn parallel_steps(
+    cells: &[u8],
+    rounds: usize,
+    threads: usize,
+    on_round: impl Fn(usize) + Sync,
+) -> Vec<u8> {
+    // Two buffers: every round reads from one of them and writes to the other
+    let buffers = [to_atomic(cells), to_atomic(cells)];
+    let barrier = Barrier::new(threads);
+    let chunk_size = cells.len().div_ceil(threads).max(1);
+
+    std::thread::scope(|s| {
+        for thread in 0..threads {
+            let (buffers, barrier, on_round) = (&buffers, &barrier, &on_round);
+            s.spawn(move || {
+                let start = (thread * chunk_size).min(cells.len());
+                let end = (start + chunk_size).min(cells.len());
+                for round in 0..rounds {
+                    on_round(round);
+                    let (current, next) = (&buffers[round % 2], &buffers[(round + 1) % 2]);
+                    for (offset, cell) in next[start..end].iter().enumerate() {
+                        cell.store(next_atomic_cell(current, start + offset), Ordering::Relaxed);
+                    }
+                    // Nobody may read this round's cells before everyone has written theirs,
+                    // or write over the previous round's cells while someone still reads them
+                    barrier.wait();
+                }
+            });
+        }
+    });
+
+    buffers[rounds % 2]
+        .iter()
+        .map(|cell| cell.load(Ordering::Relaxed))
+        .collect()
+}
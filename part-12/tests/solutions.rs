@@ -0,0 +1,21 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-12 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_12::{parallel_steps, solutions};
+
+#[test]
+fn steps_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let cells: Vec<u8> = common::datagen::numbers_below(200, 2, rng.seed())
+        .into_iter()
+        .map(|cell| cell as u8)
+        .collect();
+    for threads in [1, 3, 8] {
+        assert_eq!(
+            parallel_steps(&cells, 50, threads, |_| {}),
+            solutions::parallel_steps(&cells, 50, threads, |_| {}),
+            "With {threads} threads"
+        );
+    }
+}
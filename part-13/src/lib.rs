@@ -0,0 +1,84 @@
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Settings that every thread needs to read, but that no one modifies
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub multiplier: u64,
+    pub offset: u64,
+}
+
+impl Config {
+    pub fn apply(&self, x: u64) -> u64 {
+        x * self.multiplier + self.offset
+    }
+}
+
+/// Applies `config` to every number in place, by splitting `numbers` in two halves
+/// which are processed by one scoped thread each
+pub fn process_halves(numbers: &mut [u64], config: &Config) {
+    todo!()
+}
+
+/// Applies `config` to every number in place, like `process_halves`,
+/// but splits `numbers` into chunks for `threads` scoped threads
+pub fn process_chunks(numbers: &mut [u64], config: &Config, threads: usize) {
+    todo!()
+}
+
+/// Sums `numbers` by letting `threads` scoped threads sum a chunk each
+pub fn parallel_sum(numbers: &[u64], threads: usize) -> u64 {
+    todo!()
+}
+
+/// Bonus: applies `config` to every number in place, like `process_chunks`, but with the scoped
+/// threads from `crossbeam_utils::thread::scope`, or `crossbeam::scope`, which is what older code
+/// used before `std::thread::scope` was added in Rust 1.63
+#[cfg(feature = "bonus")]
+pub fn crossbeam_process_chunks(numbers: &mut [u64], config: &Config, threads: usize) {
+    todo!()
+}
+
+/// Bonus: sums `numbers` like `parallel_sum`, but calls `check` on every number first, which may
+/// panic. Instead of panicking too, it gives the message of the first panic it finds. Uses
+/// `std::thread::scope`, which panics itself when a thread panicked, unless that thread was joined.
+#[cfg(feature = "bonus")]
+pub fn try_sum_std(
+    numbers: &[u64],
+    threads: usize,
+    check: impl Fn(u64) + Sync,
+) -> Result<u64, String> {
+    todo!()
+}
+
+/// Bonus: the same as `try_sum_std`, but with `crossbeam_utils::thread::scope`, which gives back
+/// the panics of the threads which weren't joined as an `Err`, rather than panicking
+#[cfg(feature = "bonus")]
+pub fn try_sum_crossbeam(
+    numbers: &[u64],
+    threads: usize,
+    check: impl Fn(u64) + Sync,
+) -> Result<u64, String> {
+    todo!()
+}
+
+/// Bonus: the message of a panic, from `JoinHandle::join`, or from the `Err` of
+/// `crossbeam_utils::thread::scope`, which has the panics of every thread which wasn't joined, in
+/// a `Vec`
+#[cfg(feature = "bonus")]
+pub fn panic_message(panic: Box<dyn std::any::Any + Send>) -> String {
+    let panic = match panic.downcast::<Vec<Box<dyn std::any::Any + Send>>>() {
+        Ok(panics) => match panics.into_iter().next() {
+            Some(first) => first,
+            None => return "No thread panicked".to_string(),
+        },
+        Err(panic) => panic,
+    };
+    match panic.downcast::<String>() {
+        Ok(message) => *message,
+        Err(panic) => panic.downcast::<&str>().map_or_else(
+            |_| "A thread panicked".to_string(),
+            |message| message.to_string(),
+        ),
+    }
+}
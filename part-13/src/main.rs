@@ -0,0 +1,131 @@
+use part_13::{parallel_sum, process_chunks, process_halves, Config};
+
+fn main() {
+    let config = Config {
+        multiplier: 2,
+        offset: 1,
+    };
+    let args = common::cli::Cli::new("Processes numbers on scoped threads, which borrow them")
+        .items(10)
+        .threads(4)
+        .parse();
+    let mut numbers: Vec<u64> = (0..args.items() as u64).collect();
+
+    process_halves(&mut numbers, &config);
+    println!("After processing halves: {numbers:?}");
+
+    process_chunks(&mut numbers, &config, 3);
+    println!("After processing chunks: {numbers:?}");
+
+    // Both `numbers` and `config` are still ours to use
+    println!(
+        "Sum: {}, config: {config:?}",
+        parallel_sum(&numbers, args.threads())
+    );
+}
+
+#[cfg(test)]
+fn expected(numbers: &[u64], config: &Config) -> Vec<u64> {
+    numbers.iter().map(|&x| config.apply(x)).collect()
+}
+
+#[cfg(test)]
+const CONFIG: Config = Config {
+    multiplier: 3,
+    offset: 7,
+};
+
+#[test]
+fn processes_both_halves() {
+    for len in [0, 1, 2, 11, 1000] {
+        let mut numbers: Vec<u64> = (0..len).collect();
+        let expected = expected(&numbers, &CONFIG);
+        process_halves(&mut numbers, &CONFIG);
+        assert_eq!(numbers, expected, "Wrong result for {len} numbers");
+    }
+}
+
+#[test]
+fn processes_every_chunk() {
+    for (len, threads) in [(0, 3), (1, 3), (10, 3), (10, 10), (10, 20), (1000, 7)] {
+        let mut numbers: Vec<u64> = (0..len).collect();
+        let expected = expected(&numbers, &CONFIG);
+        process_chunks(&mut numbers, &CONFIG, threads);
+        assert_eq!(
+            numbers, expected,
+            "Wrong result for {len} numbers on {threads} threads"
+        );
+    }
+}
+
+#[test]
+fn sums_in_parallel() {
+    for (len, threads) in [(0, 3), (1, 3), (10, 3), (10, 20), (10_000, 8)] {
+        let numbers: Vec<u64> = (0..len).collect();
+        assert_eq!(
+            parallel_sum(&numbers, threads),
+            numbers.iter().sum::<u64>(),
+            "Wrong sum of {len} numbers on {threads} threads"
+        );
+    }
+}
+
+#[cfg(feature = "bonus")]
+#[test]
+fn crossbeam_processes_every_chunk() {
+    for (len, threads) in [(0, 3), (1, 3), (10, 3), (10, 20), (1000, 7)] {
+        let mut numbers: Vec<u64> = (0..len).collect();
+        let expected = expected(&numbers, &CONFIG);
+        part_13::crossbeam_process_chunks(&mut numbers, &CONFIG, threads);
+        assert_eq!(
+            numbers, expected,
+            "Wrong result for {len} numbers on {threads} threads"
+        );
+    }
+}
+
+#[cfg(feature = "bonus")]
+#[test]
+fn try_sums_give_the_sum_or_the_panic() {
+    use part_13::{try_sum_crossbeam, try_sum_std};
+
+    fn unlucky(x: u64) {
+        assert!(x != 13, "{x} is unlucky");
+    }
+    let numbers: Vec<u64> = (0..100).collect();
+    type TrySum = fn(&[u64], usize, fn(u64)) -> Result<u64, String>;
+    let try_sums: [(&str, TrySum); 2] = [("std", try_sum_std), ("crossbeam", try_sum_crossbeam)];
+    for (name, try_sum) in try_sums {
+        assert_eq!(try_sum(&numbers, 4, |_| {}), Ok(4950), "{name}");
+        assert_eq!(try_sum(&[], 4, |_| {}), Ok(0), "{name}");
+        assert_eq!(
+            try_sum(&numbers, 4, unlucky),
+            Err("13 is unlucky".to_string()),
+            "{name}"
+        );
+        // Every thread panics, and none of the panics may get past the scope
+        let everything = std::panic::catch_unwind(|| try_sum(&numbers, 4, |_| panic!("No")));
+        assert_eq!(everything.ok(), Some(Err("No".to_string())), "{name}");
+    }
+}
+
+#[cfg(feature = "bonus")]
+#[test]
+fn the_scopes_handle_panics_differently() {
+    // `std::thread::scope` panics itself, once every thread is done
+    let std = std::panic::catch_unwind(|| {
+        std::thread::scope(|s| {
+            s.spawn(|| panic!("Not joined"));
+        })
+    });
+    assert!(std.is_err());
+
+    // Crossbeam's gives the panics back
+    let crossbeam = crossbeam_utils::thread::scope(|s| {
+        s.spawn(|_| panic!("Not joined"));
+    });
+    assert_eq!(
+        crossbeam.map_err(part_13::panic_message),
+        Err("Not joined".to_string())
+    );
+}
@@ -0,0 +1,114 @@
+//! Part 13, with every exercise implemented. Only built with `--features solutions`.
+
+#[cfg(feature = "bonus")]
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(feature = "bonus")]
+use super::panic_message;
+use super::Config;
+
+/// Applies `config` to every number in place, by splitting `numbers` in two halves
+/// which are processed by one scoped thread each
+pub fn process_halves(numbers: &mut [u64], config: &Config) {
+    // Two mutable borrows of the same slice, but the compiler knows they don't overlap
+    let (left, right) = numbers.split_at_mut(numbers.len() / 2);
+    std::thread::scope(|s| {
+        s.spawn(|| left.iter_mut().for_each(|x| *x = config.apply(*x)));
+        s.spawn(|| right.iter_mut().for_each(|x| *x = config.apply(*x)));
+    });
+}
+
+/// Applies `config` to every number in place, like `process_halves`,
+/// but splits `numbers` into chunks for `threads` scoped threads
+pub fn process_chunks(numbers: &mut [u64], config: &Config, threads: usize) {
+    // `chunks_mut` panics on a chunk size of 0
+    let chunk_size = numbers.len().div_ceil(threads).max(1);
+    std::thread::scope(|s| {
+        for chunk in numbers.chunks_mut(chunk_size) {
+            s.spawn(|| chunk.iter_mut().for_each(|x| *x = config.apply(*x)));
+        }
+    });
+}
+
+/// Sums `numbers` by letting `threads` scoped threads sum a chunk each
+pub fn parallel_sum(numbers: &[u64], threads: usize) -> u64 {
+    let chunk_size = numbers.len().div_ceil(threads).max(1);
+    std::thread::scope(|s| {
+        let handles: Vec<_> = numbers
+            .chunks(chunk_size)
+            .map(|chunk| s.spawn(|| chunk.iter().sum::<u64>()))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("Thread panicked"))
+            .sum()
+    })
+}
+
+/// Bonus: applies `config` to every number in place, like `process_chunks`, but with the scoped
+/// threads from `crossbeam_utils::thread::scope`, or `crossbeam::scope`, which is what older code
+/// used before `std::thread::scope` was added in Rust 1.63
+#[cfg(feature = "bonus")]
+pub fn crossbeam_process_chunks(numbers: &mut [u64], config: &Config, threads: usize) {
+    let chunk_size = numbers.len().div_ceil(threads).max(1);
+    crossbeam_utils::thread::scope(|s| {
+        for chunk in numbers.chunks_mut(chunk_size) {
+            // Crossbeam gives every thread the scope as well, for spawning more threads in it
+            s.spawn(|_| chunk.iter_mut().for_each(|x| *x = config.apply(*x)));
+        }
+    })
+    // Only an `Err` when a thread panicked, where `std::thread::scope` would have panicked
+    .expect("A thread panicked");
+}
+
+/// Bonus: sums `numbers` like `parallel_sum`, but calls `check` on every number first, which may
+/// panic. Instead of panicking too, it gives the message of the first panic it finds. Uses
+/// `std::thread::scope`, which panics itself when a thread panicked, unless that thread was joined.
+#[cfg(feature = "bonus")]
+pub fn try_sum_std(
+    numbers: &[u64],
+    threads: usize,
+    check: impl Fn(u64) + Sync,
+) -> Result<u64, String> {
+    let chunk_size = numbers.len().div_ceil(threads).max(1);
+    let check = &check;
+    std::thread::scope(|s| {
+        let handles: Vec<_> = numbers
+            .chunks(chunk_size)
+            .map(|chunk| {
+                s.spawn(move || {
+                    chunk.iter().for_each(|&x| check(x));
+                    chunk.iter().sum::<u64>()
+                })
+            })
+            .collect();
+        // Every thread is joined before looking at any of them, since the scope panics as soon
+        // as a single one which panicked is left unjoined
+        let sums: Vec<_> = handles.into_iter().map(|handle| handle.join()).collect();
+        sums.into_iter()
+            .try_fold(0, |total, sum| Ok(total + sum.map_err(panic_message)?))
+    })
+}
+
+/// Bonus: the same as `try_sum_std`, but with `crossbeam_utils::thread::scope`, which gives back
+/// the panics of the threads which weren't joined as an `Err`, rather than panicking
+#[cfg(feature = "bonus")]
+pub fn try_sum_crossbeam(
+    numbers: &[u64],
+    threads: usize,
+    check: impl Fn(u64) + Sync,
+) -> Result<u64, String> {
+    let chunk_size = numbers.len().div_ceil(threads).max(1);
+    let (check, total) = (&check, &AtomicU64::new(0));
+    crossbeam_utils::thread::scope(|s| {
+        for chunk in numbers.chunks(chunk_size) {
+            // Never joined, so if it panics, the panic ends up in what the scope returns
+            s.spawn(move |_| {
+                chunk.iter().for_each(|&x| check(x));
+                total.fetch_add(chunk.iter().sum(), Ordering::Relaxed);
+            });
+        }
+    })
+    .map_err(panic_message)?;
+    Ok(total.load(Ordering::Relaxed))
+}
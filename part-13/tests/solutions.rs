@@ -0,0 +1,69 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-13 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use common::datagen;
+use part_13::{parallel_sum, process_chunks, process_halves, solutions, Config};
+
+const CONFIG: Config = Config {
+    multiplier: 3,
+    offset: 7,
+};
+const SOLUTION_CONFIG: Config = Config {
+    multiplier: 3,
+    offset: 7,
+};
+
+#[test]
+fn processes_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for len in [0, 1, 1001] {
+        let numbers = datagen::numbers_below(len, 1 << 32, rng.seed());
+
+        let (mut halves, mut expected) = (numbers.clone(), numbers.clone());
+        process_halves(&mut halves, &CONFIG);
+        solutions::process_halves(&mut expected, &SOLUTION_CONFIG);
+        assert_eq!(halves, expected);
+
+        for threads in [1, 3, 16] {
+            let (mut chunks, mut expected) = (numbers.clone(), numbers.clone());
+            process_chunks(&mut chunks, &CONFIG, threads);
+            solutions::process_chunks(&mut expected, &SOLUTION_CONFIG, threads);
+            assert_eq!(chunks, expected, "{len} numbers on {threads} threads");
+        }
+    }
+}
+
+#[test]
+fn sums_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for len in [0, 1, 1001] {
+        let numbers = datagen::numbers_below(len, 1 << 32, rng.seed());
+        for threads in [1, 3, 16] {
+            assert_eq!(
+                parallel_sum(&numbers, threads),
+                solutions::parallel_sum(&numbers, threads),
+                "{len} numbers on {threads} threads"
+            );
+        }
+    }
+}
+
+#[cfg(feature = "bonus")]
+#[test]
+fn try_sums_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let numbers = datagen::numbers_below(1001, 1 << 32, rng.seed());
+    let limit = numbers[500];
+    let check = |x: u64| assert!(x <= limit, "Over the limit");
+    for threads in [1, 3, 16] {
+        assert_eq!(
+            part_13::try_sum_std(&numbers, threads, check),
+            solutions::try_sum_std(&numbers, threads, check)
+        );
+        assert_eq!(
+            part_13::try_sum_crossbeam(&numbers, threads, check),
+            solutions::try_sum_crossbeam(&numbers, threads, check)
+        );
+    }
+}
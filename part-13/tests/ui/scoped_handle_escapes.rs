@@ -0,0 +1,6 @@
+// Scoped threads may borrow, but their handles can't escape the scope
+fn main() {
+    let numbers = vec![1, 2, 3];
+    let handle = std::thread::scope(|s| s.spawn(|| numbers.iter().sum::<i32>()));
+    println!("{}", handle.join().unwrap());
+}
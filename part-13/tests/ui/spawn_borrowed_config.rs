@@ -0,0 +1,17 @@
+// Sharing `config` with a spawned thread would require an `Arc`,
+// or moving it in, which means no one else can use it.
+struct Config {
+    multiplier: u64,
+}
+
+fn main() {
+    let config = Config { multiplier: 2 };
+    let config_ref = &config;
+
+    let handles: Vec<_> = (0..4)
+        .map(|x| std::thread::spawn(move || x * config_ref.multiplier))
+        .collect();
+    for handle in handles {
+        println!("{}", handle.join().unwrap());
+    }
+}
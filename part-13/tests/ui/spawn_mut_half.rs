@@ -0,0 +1,11 @@
+// The halves borrow from `numbers`, so they can't be sent to a thread that may outlive it
+fn main() {
+    let mut numbers = vec![1, 2, 3, 4];
+    let (left, right) = numbers.split_at_mut(2);
+
+    let handle = std::thread::spawn(move || left.iter_mut().for_each(|x| *x *= 2));
+    right.iter_mut().for_each(|x| *x *= 2);
+    handle.join().unwrap();
+
+    println!("{numbers:?}");
+}
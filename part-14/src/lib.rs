@@ -0,0 +1,51 @@
+use std::{thread, time::Duration};
+
+use crossbeam_channel::{Receiver, Sender};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// What `merge_with_timeout` saw happen
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// A value from the first source
+    First(u64),
+    /// A value from the second source
+    Second(u64),
+    /// Nothing was received for a whole timeout
+    Timeout,
+}
+
+/// Sends `values` from a spawned thread, waiting `interval` between each
+pub fn producer(values: Vec<u64>, interval: Duration) -> Receiver<u64> {
+    let (sender, receiver) = crossbeam_channel::unbounded();
+    thread::spawn(move || {
+        let _producer = common::span::thread("producer");
+        for x in values {
+            thread::sleep(interval);
+            if sender.send(x).is_err() {
+                return;
+            }
+        }
+    });
+    receiver
+}
+
+/// Spawns `workers` threads that all receive from the same bounded channel
+/// (holding at most `capacity` jobs), squaring every job they receive and
+/// sending it to the returned receiver, along with the index of the worker that did it.
+pub fn square_workers(workers: usize, capacity: usize) -> (Sender<u64>, Receiver<(usize, u64)>) {
+    todo!()
+}
+
+/// Receives from both `first` and `second` at the same time, in the order values arrive,
+/// until both are disconnected. Records `Event::Timeout` if neither sends anything
+/// for `timeout`, and gives up after `max_timeouts` of them in a row.
+pub fn merge_with_timeout(
+    first: Receiver<u64>,
+    second: Receiver<u64>,
+    timeout: Duration,
+    max_timeouts: usize,
+) -> Vec<Event> {
+    todo!()
+}
@@ -0,0 +1,122 @@
+use std::time::Duration;
+
+use part_14::{merge_with_timeout, producer, square_workers};
+
+fn main() {
+    common::init_tracing();
+    let args = common::cli::Cli::new("Squares numbers on workers sharing a bounded channel")
+        .items(10)
+        .threads(4)
+        .parse();
+    let (jobs, results) = square_workers(args.threads(), 2);
+    for x in 0..args.items() as u64 {
+        jobs.send(x).expect("Workers are gone");
+    }
+    drop(jobs);
+    for (worker, result) in results {
+        println!("Worker {worker} calculated {result}");
+    }
+
+    let fast = producer((0..5).collect(), Duration::from_millis(10));
+    let slow = producer((100..103).collect(), Duration::from_millis(35));
+    for event in merge_with_timeout(fast, slow, Duration::from_millis(100), 1) {
+        println!("{event:?}");
+    }
+}
+
+#[test]
+fn workers_share_the_jobs() {
+    let (jobs, results) = square_workers(4, 2);
+    assert_eq!(jobs.capacity(), Some(2));
+
+    let sender = std::thread::spawn(move || {
+        for x in 0..100 {
+            jobs.send(x).unwrap();
+        }
+    });
+
+    let mut squares: Vec<u64> = results.iter().map(|(_, square)| square).collect();
+    sender.join().unwrap();
+    squares.sort();
+    assert_eq!(squares, (0..100).map(|x| x * x).collect::<Vec<_>>());
+}
+
+#[test]
+fn workers_stop_when_jobs_are_done() {
+    let (jobs, results) = square_workers(3, 1);
+    for x in 0..30 {
+        jobs.send(x).unwrap();
+        let (worker, square) = results.recv().unwrap();
+        assert!(worker < 3, "There's no worker {worker}");
+        assert_eq!(square, x * x);
+    }
+
+    // Every worker has to drop its result sender for this to end
+    drop(jobs);
+    assert_eq!(results.iter().count(), 0);
+}
+
+#[test]
+fn drains_both_sources() {
+    let first = producer((0..20).collect(), Duration::from_millis(1));
+    let second = producer((100..110).collect(), Duration::from_millis(2));
+
+    let events = merge_with_timeout(first, second, Duration::from_secs(1), 1);
+
+    let firsts: Vec<u64> = events
+        .iter()
+        .filter_map(|event| match event {
+            part_14::Event::First(x) => Some(*x),
+            _ => None,
+        })
+        .collect();
+    let seconds: Vec<u64> = events
+        .iter()
+        .filter_map(|event| match event {
+            part_14::Event::Second(x) => Some(*x),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(firsts, (0..20).collect::<Vec<_>>());
+    assert_eq!(seconds, (100..110).collect::<Vec<_>>());
+    assert!(!events.contains(&part_14::Event::Timeout));
+}
+
+#[test]
+fn values_are_merged_as_they_arrive() {
+    use part_14::Event;
+
+    let first = producer(vec![1, 2], Duration::from_millis(100));
+    let second = producer(vec![10], Duration::from_millis(150));
+
+    let events = merge_with_timeout(first, second, Duration::from_secs(1), 1);
+    assert_eq!(
+        events,
+        vec![Event::First(1), Event::Second(10), Event::First(2)]
+    );
+}
+
+#[test]
+fn times_out_when_sources_go_quiet() {
+    use part_14::Event;
+
+    // Keep the senders alive without ever sending anything
+    let (quiet_first, first) = crossbeam_channel::unbounded();
+    let (quiet_second, second) = crossbeam_channel::unbounded();
+    quiet_first.send(1).unwrap();
+
+    let events = common::time_elapsed("timeouts", || {
+        merge_with_timeout(first, second, Duration::from_millis(20), 3)
+    });
+    assert_eq!(
+        events.0,
+        vec![
+            Event::First(1),
+            Event::Timeout,
+            Event::Timeout,
+            Event::Timeout
+        ]
+    );
+    assert!(events.1 >= Duration::from_millis(60));
+    drop((quiet_first, quiet_second));
+}
@@ -0,0 +1,74 @@
+//! Part 14, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{thread, time::Duration};
+
+use crossbeam_channel::{Receiver, Sender};
+
+use super::Event;
+
+/// Spawns `workers` threads that all receive from the same bounded channel
+/// (holding at most `capacity` jobs), squaring every job they receive and
+/// sending it to the returned receiver, along with the index of the worker that did it.
+pub fn square_workers(workers: usize, capacity: usize) -> (Sender<u64>, Receiver<(usize, u64)>) {
+    let (job_sender, job_receiver) = crossbeam_channel::bounded::<u64>(capacity);
+    let (result_sender, result_receiver) = crossbeam_channel::unbounded();
+    for worker in 0..workers {
+        // Unlike `std::sync::mpsc`, crossbeam receivers can be cloned
+        let jobs = job_receiver.clone();
+        let results = result_sender.clone();
+        thread::spawn(move || {
+            for x in jobs {
+                if results.send((worker, x * x)).is_err() {
+                    return;
+                }
+            }
+        });
+    }
+    (job_sender, result_receiver)
+}
+
+/// Receives from both `first` and `second` at the same time, in the order values arrive,
+/// until both are disconnected. Records `Event::Timeout` if neither sends anything
+/// for `timeout`, and gives up after `max_timeouts` of them in a row.
+pub fn merge_with_timeout(
+    first: Receiver<u64>,
+    second: Receiver<u64>,
+    timeout: Duration,
+    max_timeouts: usize,
+) -> Vec<Event> {
+    use crossbeam_channel::{never, select};
+
+    let mut events = Vec::new();
+    let (mut first, mut second) = (first, second);
+    let (mut open, mut timeouts) = (2, 0);
+    while open > 0 && timeouts < max_timeouts {
+        select! {
+            recv(first) -> x => match x {
+                Ok(x) => {
+                    events.push(Event::First(x));
+                    timeouts = 0;
+                }
+                // A disconnected receiver is always ready, so stop selecting it
+                Err(_) => {
+                    first = never();
+                    open -= 1;
+                }
+            },
+            recv(second) -> x => match x {
+                Ok(x) => {
+                    events.push(Event::Second(x));
+                    timeouts = 0;
+                }
+                Err(_) => {
+                    second = never();
+                    open -= 1;
+                }
+            },
+            default(timeout) => {
+                events.push(Event::Timeout);
+                timeouts += 1;
+            },
+        }
+    }
+    events
+}
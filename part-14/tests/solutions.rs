@@ -0,0 +1,64 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-14 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::time::Duration;
+
+use crossbeam_channel::Receiver;
+use part_14::{merge_with_timeout, solutions, square_workers};
+
+#[test]
+fn squares_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let jobs = common::datagen::numbers_below(1000, 1 << 32, rng.seed());
+    let squared = |(sender, receiver): (crossbeam_channel::Sender<u64>, Receiver<(usize, u64)>)| {
+        for &job in &jobs {
+            sender.send(job).unwrap();
+        }
+        drop(sender);
+        // Which worker squared what is up to the scheduler
+        let mut squares: Vec<u64> = receiver.iter().map(|(_, square)| square).collect();
+        squares.sort();
+        squares
+    };
+    assert_eq!(
+        squared(square_workers(4, 2)),
+        squared(solutions::square_workers(4, 2))
+    );
+}
+
+/// A receiver which already has every one of `values`, and is disconnected after them
+fn sent(values: &[u64]) -> Receiver<u64> {
+    let (sender, receiver) = crossbeam_channel::unbounded();
+    for &value in values {
+        sender.send(value).unwrap();
+    }
+    receiver
+}
+
+#[test]
+fn merges_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (first, second) = (
+        common::datagen::numbers(100, rng.seed()),
+        common::datagen::numbers(50, rng.seed()),
+    );
+    let timeout = Duration::from_millis(100);
+    let merged = merge_with_timeout(sent(&first), sent(&second), timeout, 3);
+    let expected = solutions::merge_with_timeout(sent(&first), sent(&second), timeout, 3);
+
+    // When both have a value, either can be picked, but each keeps its own order
+    let split = |events: Vec<String>| -> (Vec<String>, Vec<String>, usize) {
+        let (first, rest): (Vec<_>, Vec<_>) = events
+            .into_iter()
+            .partition(|event| event.starts_with("First"));
+        let (second, timeouts): (Vec<_>, Vec<_>) = rest
+            .into_iter()
+            .partition(|event| event.starts_with("Second"));
+        (first, second, timeouts.len())
+    };
+    assert_eq!(
+        split(merged.iter().map(|event| format!("{event:?}")).collect()),
+        split(expected.iter().map(|event| format!("{event:?}")).collect())
+    );
+}
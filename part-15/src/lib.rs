@@ -0,0 +1,86 @@
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use crossbeam_channel::{Receiver, Sender};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// What happened during `run_pipeline`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// Everything the consumer received, in order
+    pub received: Vec<u64>,
+    /// The most messages that were ever waiting in the channel
+    pub max_depth: usize,
+    /// What the producer returned
+    pub produced: Produced,
+    /// How long it took from the first send until the producer was done
+    pub producer_elapsed: Duration,
+}
+
+/// What a producer reports back when it is done
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Produced {
+    /// Total time spent waiting for room in the channel
+    pub blocked: Duration,
+    /// Messages that were thrown away instead of delivered
+    pub dropped: usize,
+}
+
+/// Sets up a channel holding at most `capacity` messages, with a consumer
+/// which takes `consumer_delay` to handle each message, and lets `producer`
+/// send `0, 1, ..., items - 1` into it as fast as it can.
+///
+/// `producer` gets its own receiver for the channel too, in case it wants to throw
+/// away messages, but must drop it along with the sender when done.
+pub fn run_pipeline(
+    items: u64,
+    capacity: usize,
+    consumer_delay: Duration,
+    producer: impl FnOnce(Sender<u64>, Receiver<u64>, u64) -> Produced,
+) -> Report {
+    let (sender, receiver) = crossbeam_channel::bounded(capacity);
+    let consumer = {
+        let receiver = receiver.clone();
+        thread::spawn(move || {
+            let _consumer = common::span::thread("consumer");
+            let mut received = Vec::new();
+            let mut max_depth = 0;
+            loop {
+                max_depth = max_depth.max(receiver.len());
+                let Ok(x) = receiver.recv() else { break };
+                received.push(x);
+                thread::sleep(consumer_delay);
+            }
+            (received, max_depth)
+        })
+    };
+
+    let start = Instant::now();
+    let produced = producer(sender, receiver, items);
+    let producer_elapsed = start.elapsed();
+
+    let (received, max_depth) = consumer.join().expect("Consumer panicked");
+    Report {
+        received,
+        max_depth,
+        produced,
+        producer_elapsed,
+    }
+}
+
+/// Sends every item, waiting for room whenever the channel is full.
+/// Reports the total time spent waiting.
+pub fn blocking_producer(sender: Sender<u64>, receiver: Receiver<u64>, items: u64) -> Produced {
+    todo!()
+}
+
+/// Sends every item without ever waiting for room: when the channel is full,
+/// the oldest message in it is thrown away to make room for the new one.
+/// This way the consumer always gets the latest value as soon as it is ready for it.
+pub fn latest_value_producer(sender: Sender<u64>, receiver: Receiver<u64>, items: u64) -> Produced {
+    todo!()
+}
@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use part_15::{blocking_producer, latest_value_producer, run_pipeline};
+
+fn main() {
+    common::init_tracing();
+    let args = common::cli::Cli::new("Sends messages to a slow consumer")
+        .items(100)
+        .parse();
+    let (messages, delay) = (args.items() as u64, Duration::from_millis(10));
+
+    let report = run_pipeline(messages, 5, delay, blocking_producer);
+    println!(
+        "Blocking: producer done after {} ms, of which {} ms were spent waiting. Consumer got {} of {messages} messages",
+        report.producer_elapsed.as_millis(),
+        report.produced.blocked.as_millis(),
+        report.received.len()
+    );
+
+    let report = run_pipeline(messages, 5, delay, latest_value_producer);
+    println!(
+        "Latest value: producer done after {} ms, dropping {} messages. Consumer got {:?}",
+        report.producer_elapsed.as_millis(),
+        report.produced.dropped,
+        report.received
+    );
+}
+
+#[test]
+fn blocking_producer_delivers_everything_in_order() {
+    let report = run_pipeline(100, 4, Duration::ZERO, blocking_producer);
+    assert_eq!(report.received, (0..100).collect::<Vec<_>>());
+    assert_eq!(report.produced.dropped, 0);
+}
+
+#[test]
+fn blocking_producer_is_throttled() {
+    let delay = Duration::from_millis(5);
+    let report = run_pipeline(40, 4, delay, blocking_producer);
+
+    assert!(report.max_depth <= 4);
+    assert_eq!(report.received, (0..40).collect::<Vec<_>>());
+    // Everything beyond the capacity has to wait for the consumer
+    assert!(
+        report.produced.blocked >= (40 - 4 - 1) * delay,
+        "Producer only waited {:?}",
+        report.produced.blocked
+    );
+    assert!(report.produced.blocked <= report.producer_elapsed);
+}
+
+#[test]
+fn latest_value_producer_never_waits() {
+    let delay = Duration::from_millis(5);
+    let report = run_pipeline(1000, 4, delay, latest_value_producer);
+
+    assert!(report.max_depth <= 4);
+    assert!(
+        report.producer_elapsed < 40 * delay,
+        "Producer took {:?}, as if it waited for the consumer",
+        report.producer_elapsed
+    );
+    assert!(report.produced.blocked < delay);
+}
+
+#[test]
+fn latest_value_producer_keeps_the_newest() {
+    let report = run_pipeline(1000, 4, Duration::from_millis(5), latest_value_producer);
+
+    // Every message is either delivered or dropped
+    assert_eq!(report.received.len() + report.produced.dropped, 1000);
+    assert!(report.produced.dropped > 0);
+    // What's delivered is still in order, and the newest value always gets through
+    assert!(report.received.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(report.received.last(), Some(&999));
+}
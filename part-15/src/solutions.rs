@@ -0,0 +1,54 @@
+//! Part 15, with every exercise implemented. Only built with `--features solutions`.
+
+use std::time::{Duration, Instant};
+
+use crossbeam_channel::{Receiver, Sender};
+
+use super::Produced;
+
+/// Sends every item, waiting for room whenever the channel is full.
+/// Reports the total time spent waiting.
+pub fn blocking_producer(sender: Sender<u64>, receiver: Receiver<u64>, items: u64) -> Produced {
+    // Not needed, and keeping it would keep the channel open
+    drop(receiver);
+
+    let mut blocked = Duration::ZERO;
+    for x in 0..items {
+        let start = Instant::now();
+        sender.send(x).expect("Consumer is gone");
+        blocked += start.elapsed();
+    }
+    Produced {
+        blocked,
+        dropped: 0,
+    }
+}
+
+/// Sends every item without ever waiting for room: when the channel is full,
+/// the oldest message in it is thrown away to make room for the new one.
+/// This way the consumer always gets the latest value as soon as it is ready for it.
+pub fn latest_value_producer(sender: Sender<u64>, receiver: Receiver<u64>, items: u64) -> Produced {
+    use crossbeam_channel::TrySendError;
+
+    let mut dropped = 0;
+    for x in 0..items {
+        let mut message = x;
+        loop {
+            match sender.try_send(message) {
+                Ok(()) => break,
+                Err(TrySendError::Full(returned)) => {
+                    // The consumer may have taken the oldest one in the meantime
+                    if receiver.try_recv().is_ok() {
+                        dropped += 1;
+                    }
+                    message = returned;
+                }
+                Err(TrySendError::Disconnected(_)) => panic!("Consumer is gone"),
+            }
+        }
+    }
+    Produced {
+        blocked: Duration::ZERO,
+        dropped,
+    }
+}
@@ -0,0 +1,34 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-15 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::time::Duration;
+
+use part_15::{blocking_producer, latest_value_producer, run_pipeline, solutions};
+
+const DELAY: Duration = Duration::from_micros(200);
+
+#[test]
+fn blocks_like_the_solution() {
+    let report = run_pipeline(200, 4, DELAY, blocking_producer);
+    let expected = run_pipeline(200, 4, DELAY, solutions::blocking_producer);
+    assert_eq!(report.received, expected.received);
+    assert_eq!(report.produced.dropped, expected.produced.dropped);
+}
+
+#[test]
+fn keeps_the_latest_values_like_the_solution() {
+    let report = run_pipeline(200, 4, DELAY, latest_value_producer);
+    let expected = run_pipeline(200, 4, DELAY, solutions::latest_value_producer);
+    // Which values are thrown away depends on how fast the consumer is, but not what adds up
+    for (received, dropped) in [
+        (&report.received, report.produced.dropped),
+        (&expected.received, expected.produced.dropped),
+    ] {
+        assert_eq!(received.len() + dropped, 200);
+        assert_eq!(received.last(), Some(&199));
+        assert!(received.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+    assert_eq!(report.produced.blocked, Duration::ZERO);
+    assert_eq!(report.produced.blocked, expected.produced.blocked);
+}
@@ -0,0 +1,59 @@
+use std::{
+    sync::{mpsc, Arc, Mutex},
+    thread,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Something for the pool to do
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// A fixed amount of worker threads that execute jobs sent to them through a channel
+pub struct ThreadPool {
+    workers: Vec<Worker>,
+    /// `None` once the pool is shutting down
+    sender: Option<mpsc::Sender<Job>>,
+}
+
+struct Worker {
+    id: usize,
+    /// `None` once the worker has been joined
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl ThreadPool {
+    /// Creates a pool with `size` worker threads, all waiting for jobs on the same channel
+    pub fn new(size: usize) -> Self {
+        todo!()
+    }
+
+    /// Sends `job` to be executed by the first available worker.
+    /// A panicking job must not take its worker down with it.
+    pub fn execute<F>(&self, job: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        todo!()
+    }
+
+    /// How many workers there are in the pool
+    pub fn size(&self) -> usize {
+        self.workers.len()
+    }
+}
+
+impl Drop for ThreadPool {
+    /// Shuts the pool down gracefully: every job already sent is executed,
+    /// and every worker is joined before the pool is gone.
+    fn drop(&mut self) {
+        todo!()
+    }
+}
+
+impl Worker {
+    /// Spawns a worker thread named `worker-{id}`, executing jobs from `receiver` until it disconnects
+    fn spawn(id: usize, receiver: Arc<Mutex<mpsc::Receiver<Job>>>) -> Self {
+        todo!()
+    }
+}
@@ -0,0 +1,173 @@
+use std::{sync::mpsc, thread, time::Duration};
+
+use common::{bench::Comparison, cli::Cli};
+use part_16::ThreadPool;
+
+fn main() {
+    common::init_tracing();
+    // `workshop bench` tries other numbers of tasks too
+    let args = Cli::new("Compares a thread per task with a thread pool, and shuts a pool down")
+        .items(10_000)
+        .threads(4)
+        .strategy()
+        .parse();
+    let tasks = args.items() as u64;
+
+    Comparison::new(3)
+        .bench("Thread per task", || {
+            let handles: Vec<_> = (0..tasks).map(|x| thread::spawn(move || x * 2)).collect();
+            handles.into_iter().map(|h| h.join().unwrap()).sum::<u64>()
+        })
+        .bench("Thread pool", || {
+            let pool = ThreadPool::new(args.threads());
+            let (sender, receiver) = mpsc::channel();
+            for x in 0..tasks {
+                let sender = sender.clone();
+                pool.execute(move || sender.send(x * 2).unwrap());
+            }
+            drop(sender);
+            receiver.iter().sum::<u64>()
+        })
+        .print();
+
+    let pool = ThreadPool::new(2);
+    for x in 0..4 {
+        pool.execute(move || {
+            thread::sleep(Duration::from_millis(100));
+            println!(
+                "Job {x} done on {}",
+                thread::current().name().unwrap_or("?")
+            );
+        });
+    }
+    println!("Shutting down");
+    drop(pool);
+    println!("Shut down");
+}
+
+#[test]
+fn executes_every_job() {
+    let pool = ThreadPool::new(4);
+    assert_eq!(pool.size(), 4);
+
+    let (sender, receiver) = mpsc::channel();
+    for x in 0..100 {
+        let sender = sender.clone();
+        pool.execute(move || sender.send(x).unwrap());
+    }
+    drop(sender);
+
+    let mut results: Vec<i32> = receiver.iter().collect();
+    results.sort();
+    assert_eq!(results, (0..100).collect::<Vec<_>>());
+}
+
+#[test]
+fn runs_jobs_on_worker_threads() {
+    use std::collections::HashSet;
+
+    let pool = ThreadPool::new(3);
+    let (sender, receiver) = mpsc::channel();
+    for _ in 0..30 {
+        let sender = sender.clone();
+        pool.execute(move || {
+            thread::sleep(Duration::from_millis(5));
+            sender.send(thread::current().id()).unwrap()
+        });
+    }
+    drop(sender);
+
+    let threads: HashSet<_> = receiver.iter().collect();
+    assert!(!threads.contains(&thread::current().id()));
+    assert!(threads.len() <= 3, "Jobs ran on {} threads", threads.len());
+}
+
+#[test]
+fn jobs_run_concurrently() {
+    let pool = ThreadPool::new(4);
+    let (sender, receiver) = mpsc::channel();
+    let (_, elapsed) = common::time_elapsed("4 sleeping jobs", || {
+        for _ in 0..4 {
+            let sender = sender.clone();
+            pool.execute(move || {
+                thread::sleep(Duration::from_millis(100));
+                sender.send(()).unwrap();
+            });
+        }
+        for _ in 0..4 {
+            receiver.recv().unwrap();
+        }
+    });
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    assert!(elapsed < Duration::from_millis(300));
+}
+
+#[test]
+fn survives_panicking_jobs() {
+    let pool = ThreadPool::new(2);
+    for _ in 0..4 {
+        pool.execute(|| panic!("Oops"));
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    for x in 0..10 {
+        let sender = sender.clone();
+        pool.execute(move || sender.send(x).unwrap());
+    }
+    drop(sender);
+
+    let results: Vec<i32> =
+        common::with_timeout(Duration::from_secs(1), move || receiver.iter().collect())
+            .expect("Jobs after the panics never ran");
+    assert_eq!(results.len(), 10);
+}
+
+#[test]
+fn drop_finishes_queued_jobs() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let done = Arc::new(AtomicUsize::new(0));
+    let pool = ThreadPool::new(2);
+    for _ in 0..6 {
+        let done = done.clone();
+        pool.execute(move || {
+            thread::sleep(Duration::from_millis(20));
+            done.fetch_add(1, Ordering::SeqCst);
+        });
+    }
+
+    drop(pool);
+    // Every job was sent before the drop, so every job is done after it
+    assert_eq!(done.load(Ordering::SeqCst), 6);
+    // Only the test itself holds on to `done`, so the jobs (and workers) are gone
+    assert_eq!(Arc::strong_count(&done), 1);
+}
+
+#[test]
+fn pool_is_faster_than_thread_per_task() {
+    use common::bench::{assert_faster, measure};
+
+    let tasks = 2_000;
+    let spawned = measure("thread per task", 5, || {
+        let handles: Vec<_> = (0..tasks).map(|x| thread::spawn(move || x * 2)).collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum::<u64>()
+    });
+    let pooled = measure("thread pool", 5, || {
+        let pool = ThreadPool::new(4);
+        let (sender, receiver) = mpsc::channel();
+        for x in 0..tasks {
+            let sender = sender.clone();
+            pool.execute(move || sender.send(x * 2).unwrap());
+        }
+        drop(sender);
+        receiver.iter().sum::<u64>()
+    });
+
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    assert_faster(&pooled, &spawned);
+}
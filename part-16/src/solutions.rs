@@ -0,0 +1,99 @@
+//! Part 16, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    sync::{mpsc, Arc, Mutex},
+    thread,
+};
+
+use super::Job;
+
+/// A fixed amount of worker threads that execute jobs sent to them through a channel
+pub struct ThreadPool {
+    workers: Vec<Worker>,
+    /// `None` once the pool is shutting down
+    sender: Option<mpsc::Sender<Job>>,
+}
+
+struct Worker {
+    id: usize,
+    /// `None` once the worker has been joined
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl ThreadPool {
+    /// Creates a pool with `size` worker threads, all waiting for jobs on the same channel
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0, "A pool without workers can't execute anything");
+        let (sender, receiver) = mpsc::channel();
+        // `mpsc::Receiver` can't be cloned, so the workers take turns using it
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = (0..size)
+            .map(|id| Worker::spawn(id, receiver.clone()))
+            .collect();
+        Self {
+            workers,
+            sender: Some(sender),
+        }
+    }
+
+    /// Sends `job` to be executed by the first available worker.
+    /// A panicking job must not take its worker down with it.
+    pub fn execute<F>(&self, job: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.sender
+            .as_ref()
+            .expect("Pool is shutting down")
+            .send(Box::new(job))
+            .expect("Every worker is gone");
+    }
+
+    /// How many workers there are in the pool
+    pub fn size(&self) -> usize {
+        self.workers.len()
+    }
+}
+
+impl Drop for ThreadPool {
+    /// Shuts the pool down gracefully: every job already sent is executed,
+    /// and every worker is joined before the pool is gone.
+    fn drop(&mut self) {
+        // Disconnecting the channel lets the workers finish the remaining jobs and then stop
+        drop(self.sender.take());
+        for worker in &mut self.workers {
+            if let Some(handle) = worker.handle.take() {
+                handle
+                    .join()
+                    .unwrap_or_else(|_| panic!("Worker {} panicked", worker.id));
+            }
+        }
+    }
+}
+
+impl Worker {
+    /// Spawns a worker thread named `worker-{id}`, executing jobs from `receiver` until it disconnects
+    fn spawn(id: usize, receiver: Arc<Mutex<mpsc::Receiver<Job>>>) -> Self {
+        let handle = thread::Builder::new()
+            .name(format!("worker-{id}"))
+            .spawn(move || {
+                let _worker = common::span::worker(id);
+                loop {
+                    // The lock is released at the end of the statement, before the job executes
+                    let job = receiver.lock().unwrap().recv();
+                    match job {
+                        Ok(job) => {
+                            // Catch panics so the worker lives on to execute more jobs
+                            let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(job));
+                        }
+                        Err(_) => break,
+                    }
+                }
+            })
+            .expect("Couldn't spawn worker");
+        Self {
+            id,
+            handle: Some(handle),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-16 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::sync::{Arc, Mutex};
+
+use part_16::{solutions, ThreadPool};
+
+/// Doubles every job on `$pool`, panicking on every tenth, and returns the doubled jobs once the pool is dropped
+macro_rules! doubled {
+    ($pool:expr, $jobs:expr) => {{
+        let results = Arc::new(Mutex::new(Vec::new()));
+        {
+            let pool = $pool;
+            for job in $jobs {
+                let results = results.clone();
+                pool.execute(move || {
+                    if job % 10 == 0 {
+                        panic!("Job {job} panicked on purpose");
+                    }
+                    results.lock().unwrap().push(job * 2);
+                });
+            }
+        }
+        let mut results = results.lock().unwrap().clone();
+        // Which worker finishes first is up to the scheduler
+        results.sort();
+        results
+    }};
+}
+
+#[test]
+fn executes_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let jobs = common::datagen::numbers_below(500, 1000, rng.seed());
+    assert_eq!(
+        doubled!(ThreadPool::new(4), jobs.clone()),
+        doubled!(solutions::ThreadPool::new(4), jobs)
+    );
+    assert_eq!(
+        ThreadPool::new(3).size(),
+        solutions::ThreadPool::new(3).size()
+    );
+}
@@ -0,0 +1,137 @@
+use std::{collections::VecDeque, sync::Mutex, thread};
+
+use part_11::BoundedQueue;
+use part_5::{calculate, ComputationResult, Data};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// A queue of jobs belonging to one worker. The worker pushes and pops jobs
+/// at the back, while other workers with nothing to do steal from the front.
+#[derive(Debug)]
+pub struct Deque<T> {
+    items: Mutex<VecDeque<T>>,
+}
+
+impl<T> Deque<T> {
+    pub fn new() -> Self {
+        Self {
+            items: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Adds `item` at the back. Used by the owner
+    pub fn push(&self, item: T) {
+        todo!()
+    }
+
+    /// Takes the item at the back, the most recently pushed one. Used by the owner
+    pub fn pop(&self) -> Option<T> {
+        todo!()
+    }
+
+    /// Takes the item at the front, the least recently pushed one. Used by the other workers
+    pub fn steal(&self) -> Option<T> {
+        todo!()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Default for Deque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Deals `jobs` out to `workers` workers, round-robin: job `i` goes to worker `i % workers`.
+pub fn deal<T>(jobs: Vec<T>, workers: usize) -> Vec<Vec<(usize, T)>> {
+    let mut dealt: Vec<Vec<_>> = (0..workers).map(|_| Vec::new()).collect();
+    for (i, job) in jobs.into_iter().enumerate() {
+        dealt[i % workers].push((i, job));
+    }
+    dealt
+}
+
+/// Puts the results from every worker back in the order of the jobs they came from
+pub fn in_order<R>(results: impl IntoIterator<Item = (usize, R)>) -> Vec<R> {
+    let mut results: Vec<_> = results.into_iter().collect();
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Runs `f` on every job, using `workers` threads which each only run the jobs dealt to them.
+/// A worker that's done early just sits idle.
+pub fn run_static<T: Send, R: Send>(
+    jobs: Vec<T>,
+    workers: usize,
+    f: impl Fn(T) -> R + Sync,
+) -> Vec<R> {
+    let f = &f;
+    thread::scope(|s| {
+        let handles: Vec<_> = deal(jobs, workers)
+            .into_iter()
+            .enumerate()
+            .map(|(id, jobs)| {
+                s.spawn(move || {
+                    let _worker = common::span::worker(id);
+                    jobs.into_iter()
+                        .map(|(i, job)| {
+                            let _item = common::span::item(i);
+                            (i, f(job))
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        in_order(handles.into_iter().flat_map(|h| h.join().unwrap()))
+    })
+}
+
+/// Runs `f` on every job like `run_static`, with the jobs dealt into one `Deque` per worker.
+/// A worker whose own deque is empty steals jobs from the others,
+/// until there is nothing left to steal.
+pub fn run_stealing<T: Send, R: Send>(
+    jobs: Vec<T>,
+    workers: usize,
+    f: impl Fn(T) -> R + Sync,
+) -> Vec<R> {
+    todo!()
+}
+
+/// Advanced: the same as `run_stealing`, but with the lock-free deques from `crossbeam-deque`
+#[cfg(feature = "advanced")]
+pub fn run_crossbeam<T: Send, R: Send>(
+    jobs: Vec<T>,
+    workers: usize,
+    f: impl Fn(T) -> R + Sync,
+) -> Vec<R> {
+    todo!()
+}
+
+/// Part 5's `parallel_calculate`, with work stealing
+pub fn stealing_calculate(data: Vec<Data>, workers: usize) -> Vec<ComputationResult> {
+    run_stealing(data, workers, calculate)
+}
+
+/// Runs `f` on every job like `run_static`, with the workers popping jobs from part 11's
+/// `BoundedQueue`, holding at most `workers` of them, while this thread pushes them in order.
+/// A worker only takes a job once it's done with its last one, so nobody sits idle.
+pub fn run_queued<T: Send, R: Send>(
+    jobs: Vec<T>,
+    workers: usize,
+    f: impl Fn(T) -> R + Sync,
+) -> Vec<R> {
+    todo!()
+}
+
+/// Part 5's `parallel_calculate`, with the jobs going through a bounded queue
+pub fn parallel_calculate_queued(data: Vec<Data>, workers: usize) -> Vec<ComputationResult> {
+    run_queued(data, workers, calculate)
+}
@@ -0,0 +1,171 @@
+use std::{thread, time::Duration};
+
+use common::{cli::Cli, timed, viz};
+use part_17::{run_queued, run_static, run_stealing};
+
+/// Simulates a job taking `millis` milliseconds to finish
+fn work(millis: u64) -> u64 {
+    viz::busy(|| thread::sleep(Duration::from_millis(millis)));
+    millis
+}
+
+/// Every `workers`-th job is heavy, so dealing them round-robin
+/// gives all the heavy jobs to the first worker
+fn skewed(jobs: usize, workers: usize) -> Vec<u64> {
+    (0..jobs)
+        .map(|i| if i % workers == 0 { 40 } else { 1 })
+        .collect()
+}
+
+fn main() {
+    common::init_tracing();
+    let args = Cli::new("Runs skewed jobs split up front, with work stealing, and from one queue")
+        .items(32)
+        .threads(4)
+        .strategies(["static", "stealing", "queued", "crossbeam"])
+        .parse();
+    let workers = args.threads();
+    let jobs = skewed(args.items(), workers);
+    let expected = jobs.clone();
+
+    // The timelines show the workers which got only light jobs sitting idle
+    if args.runs("Static") {
+        let (results, timeline) =
+            viz::record(|| timed("Static", || run_static(jobs.clone(), workers, work)));
+        assert_eq!(results, expected);
+        print!("{timeline}");
+    }
+    if args.runs("Stealing") {
+        let (results, timeline) =
+            viz::record(|| timed("Stealing", || run_stealing(jobs.clone(), workers, work)));
+        assert_eq!(results, expected);
+        print!("{timeline}");
+    }
+    if args.runs("Queued") {
+        let (results, timeline) =
+            viz::record(|| timed("Queued", || run_queued(jobs.clone(), workers, work)));
+        assert_eq!(results, expected);
+        print!("{timeline}");
+    }
+    #[cfg(feature = "advanced")]
+    if args.runs("Crossbeam") {
+        let results = timed("Crossbeam", || part_17::run_crossbeam(jobs, workers, work));
+        assert_eq!(results, expected);
+    }
+}
+
+#[test]
+fn deque_pops_newest_and_steals_oldest() {
+    let deque = part_17::Deque::new();
+    for x in 0..4 {
+        deque.push(x);
+    }
+    assert_eq!(deque.pop(), Some(3));
+    assert_eq!(deque.steal(), Some(0));
+    assert_eq!(deque.pop(), Some(2));
+    assert_eq!(deque.steal(), Some(1));
+    assert_eq!(deque.pop(), None);
+    assert_eq!(deque.steal(), None);
+}
+
+#[test]
+fn stealing_gives_same_results() {
+    let jobs: Vec<u64> = (0..1000).collect();
+    let expected: Vec<u64> = jobs.iter().map(|x| x * 3).collect();
+    for workers in [1, 2, 7] {
+        assert_eq!(run_stealing(jobs.clone(), workers, |x| x * 3), expected);
+    }
+}
+
+#[cfg(feature = "advanced")]
+#[test]
+fn crossbeam_gives_same_results() {
+    let jobs: Vec<u64> = (0..1000).collect();
+    let expected: Vec<u64> = jobs.iter().map(|x| x * 3).collect();
+    for workers in [1, 2, 7] {
+        assert_eq!(
+            part_17::run_crossbeam(jobs.clone(), workers, |x| x * 3),
+            expected
+        );
+    }
+}
+
+#[test]
+fn queued_gives_same_results() {
+    let jobs: Vec<u64> = (0..1000).collect();
+    let expected: Vec<u64> = jobs.iter().map(|x| x * 3).collect();
+    for workers in [1, 2, 7] {
+        assert_eq!(run_queued(jobs.clone(), workers, |x| x * 3), expected);
+    }
+}
+
+#[test]
+fn queued_matches_static_and_stealing_on_skewed_jobs() {
+    let heavy = |job: u64| (0..job * 1000).fold(job, |acc, x| acc.wrapping_mul(31) ^ x);
+    for workers in [1, 3, 4] {
+        let jobs = skewed(64, workers);
+        let expected = run_static(jobs.clone(), workers, heavy);
+        assert_eq!(run_stealing(jobs.clone(), workers, heavy), expected);
+        assert_eq!(run_queued(jobs, workers, heavy), expected);
+    }
+}
+
+#[test]
+fn stealing_calculate_matches_serial() {
+    use part_5::{serial_calculate, Data};
+
+    let data: Vec<Data> = (0..4).map(Data).collect();
+    assert_eq!(
+        part_17::stealing_calculate(data.clone(), 4),
+        serial_calculate(data)
+    );
+}
+
+#[test]
+fn parallel_calculate_queued_matches_serial() {
+    use part_5::{serial_calculate, Data};
+
+    let data: Vec<Data> = (0..8).map(Data).collect();
+    assert_eq!(
+        part_17::parallel_calculate_queued(data.clone(), 4),
+        serial_calculate(data)
+    );
+}
+
+/// Runs jobs on some number of threads with a work function, like `run_static`
+#[cfg(test)]
+type Runner = fn(Vec<u64>, usize, fn(u64) -> u64) -> Vec<u64>;
+
+#[cfg(test)]
+fn assert_balanced(name: &str, run: Runner) {
+    use common::time_elapsed;
+
+    let jobs = skewed(24, 4);
+    let (static_results, static_elapsed) =
+        time_elapsed("static", || run_static(jobs.clone(), 4, work));
+    let (results, elapsed) = time_elapsed(name, || run(jobs.clone(), 4, work));
+
+    assert_eq!(results, static_results);
+    // The first worker alone spends 6 * 40 ms on its heavy jobs,
+    // while with stealing the heavy jobs are spread between the workers
+    assert!(
+        elapsed * 2 < static_elapsed,
+        "{name} took {elapsed:?}, static took {static_elapsed:?}"
+    );
+}
+
+#[test]
+fn stealing_balances_skewed_work() {
+    assert_balanced("stealing", run_stealing);
+}
+
+#[test]
+fn queued_balances_skewed_work() {
+    assert_balanced("queued", run_queued);
+}
+
+#[cfg(feature = "advanced")]
+#[test]
+fn crossbeam_balances_skewed_work() {
+    assert_balanced("crossbeam", part_17::run_crossbeam);
+}
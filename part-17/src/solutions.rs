@@ -0,0 +1,190 @@
+//! Part 17, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{collections::VecDeque, sync::Mutex, thread};
+
+use part_11::solutions::BoundedQueue;
+use part_5::{calculate, ComputationResult, Data};
+
+use super::{deal, in_order};
+
+/// A queue of jobs belonging to one worker. The worker pushes and pops jobs
+/// at the back, while other workers with nothing to do steal from the front.
+#[derive(Debug)]
+pub struct Deque<T> {
+    items: Mutex<VecDeque<T>>,
+}
+
+impl<T> Deque<T> {
+    pub fn new() -> Self {
+        Self {
+            items: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Adds `item` at the back. Used by the owner
+    pub fn push(&self, item: T) {
+        self.items.lock().unwrap().push_back(item);
+    }
+
+    /// Takes the item at the back, the most recently pushed one. Used by the owner
+    pub fn pop(&self) -> Option<T> {
+        self.items.lock().unwrap().pop_back()
+    }
+
+    /// Takes the item at the front, the least recently pushed one. Used by the other workers
+    pub fn steal(&self) -> Option<T> {
+        self.items.lock().unwrap().pop_front()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Default for Deque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Runs `f` on every job like `run_static`, with the jobs dealt into one `Deque` per worker.
+/// A worker whose own deque is empty steals jobs from the others,
+/// until there is nothing left to steal.
+pub fn run_stealing<T: Send, R: Send>(
+    jobs: Vec<T>,
+    workers: usize,
+    f: impl Fn(T) -> R + Sync,
+) -> Vec<R> {
+    let deques: Vec<Deque<(usize, T)>> = deal(jobs, workers)
+        .into_iter()
+        .map(|jobs| {
+            let deque = Deque::new();
+            jobs.into_iter().for_each(|job| deque.push(job));
+            deque
+        })
+        .collect();
+
+    let (deques, f) = (&deques, &f);
+    thread::scope(|s| {
+        let handles: Vec<_> = (0..workers)
+            .map(|me| {
+                s.spawn(move || {
+                    let _worker = common::span::worker(me);
+                    let mut results = Vec::new();
+                    // Our own jobs first, then try everyone else, starting with our neighbour
+                    while let Some((i, job)) = deques[me].pop().or_else(|| {
+                        (1..workers).find_map(|offset| deques[(me + offset) % workers].steal())
+                    }) {
+                        let _item = common::span::item(i);
+                        results.push((i, f(job)));
+                    }
+                    // No jobs are ever added, so once everything is empty we're done
+                    results
+                })
+            })
+            .collect();
+        in_order(handles.into_iter().flat_map(|h| h.join().unwrap()))
+    })
+}
+
+/// Advanced: the same as `run_stealing`, but with the lock-free deques from `crossbeam-deque`
+#[cfg(feature = "advanced")]
+pub fn run_crossbeam<T: Send, R: Send>(
+    jobs: Vec<T>,
+    workers: usize,
+    f: impl Fn(T) -> R + Sync,
+) -> Vec<R> {
+    use crossbeam_deque::{Steal, Worker};
+
+    // A `Worker` is only used by its owner, its `Stealer`s can be shared with everyone
+    let queues: Vec<Worker<(usize, T)>> = deal(jobs, workers)
+        .into_iter()
+        .map(|jobs| {
+            let queue = Worker::new_lifo();
+            jobs.into_iter().for_each(|job| queue.push(job));
+            queue
+        })
+        .collect();
+    let stealers: Vec<_> = queues.iter().map(|queue| queue.stealer()).collect();
+
+    let (stealers, f) = (&stealers, &f);
+    thread::scope(|s| {
+        let handles: Vec<_> = queues
+            .into_iter()
+            .enumerate()
+            .map(|(me, queue)| {
+                s.spawn(move || {
+                    let _worker = common::span::worker(me);
+                    let mut results = Vec::new();
+                    loop {
+                        let job = queue.pop().or_else(|| {
+                            (1..workers).find_map(|offset| {
+                                // `Retry` means we lost a race with another thief, so try again
+                                let stealer = &stealers[(me + offset) % workers];
+                                std::iter::repeat_with(|| stealer.steal())
+                                    .find(|steal| !steal.is_retry())
+                                    .and_then(Steal::success)
+                            })
+                        });
+                        match job {
+                            Some((i, job)) => {
+                                let _item = common::span::item(i);
+                                results.push((i, f(job)));
+                            }
+                            None => break results,
+                        }
+                    }
+                })
+            })
+            .collect();
+        in_order(handles.into_iter().flat_map(|h| h.join().unwrap()))
+    })
+}
+
+/// Part 5's `parallel_calculate`, with work stealing
+pub fn stealing_calculate(data: Vec<Data>, workers: usize) -> Vec<ComputationResult> {
+    run_stealing(data, workers, calculate)
+}
+
+/// Runs `f` on every job like `run_static`, with the workers popping jobs from part 11's
+/// `BoundedQueue`, holding at most `workers` of them, while this thread pushes them in order.
+/// A worker only takes a job once it's done with its last one, so nobody sits idle.
+pub fn run_queued<T: Send, R: Send>(
+    jobs: Vec<T>,
+    workers: usize,
+    f: impl Fn(T) -> R + Sync,
+) -> Vec<R> {
+    let queue = BoundedQueue::new(workers);
+    let (queue, f) = (&queue, &f);
+    thread::scope(|s| {
+        let handles: Vec<_> = (0..workers)
+            .map(|id| {
+                s.spawn(move || {
+                    let _worker = common::span::worker(id);
+                    let mut results = Vec::new();
+                    // Ends once the queue is closed and every job in it has been taken
+                    while let Some((i, job)) = queue.pop() {
+                        let _item = common::span::item(i);
+                        results.push((i, f(job)));
+                    }
+                    results
+                })
+            })
+            .collect();
+        // Waits for room whenever the workers are `workers` jobs behind
+        for job in jobs.into_iter().enumerate() {
+            assert!(queue.push(job).is_ok(), "Only this thread closes the queue");
+        }
+        queue.close();
+        in_order(handles.into_iter().flat_map(|h| h.join().unwrap()))
+    })
+}
+
+/// Part 5's `parallel_calculate`, with the jobs going through a bounded queue
+pub fn parallel_calculate_queued(data: Vec<Data>, workers: usize) -> Vec<ComputationResult> {
+    run_queued(data, workers, calculate)
+}
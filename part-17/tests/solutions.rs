@@ -0,0 +1,66 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-17 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_17::{run_queued, run_stealing, solutions, Deque};
+
+#[test]
+fn deque_works_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (deque, expected) = (Deque::new(), solutions::Deque::new());
+    for (i, op) in common::datagen::numbers_below(1000, 3, rng.seed())
+        .into_iter()
+        .enumerate()
+    {
+        match op {
+            0 => {
+                deque.push(i);
+                expected.push(i);
+            }
+            1 => assert_eq!(deque.pop(), expected.pop()),
+            _ => assert_eq!(deque.steal(), expected.steal()),
+        }
+        assert_eq!(deque.len(), expected.len());
+    }
+}
+
+#[test]
+fn runs_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let jobs = common::datagen::numbers(1000, rng.seed());
+    let work = |job: u64| job.count_ones() as u64 * (job % 1000);
+    for workers in [1, 4] {
+        let expected = solutions::run_stealing(jobs.clone(), workers, work);
+        assert_eq!(run_stealing(jobs.clone(), workers, work), expected);
+    }
+}
+
+#[test]
+fn runs_queued_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let jobs = common::datagen::numbers(1000, rng.seed());
+    let work = |job: u64| job.count_ones() as u64 * (job % 1000);
+    for workers in [1, 4] {
+        let expected = solutions::run_queued(jobs.clone(), workers, work);
+        assert_eq!(run_queued(jobs.clone(), workers, work), expected);
+    }
+}
+
+#[cfg(feature = "advanced")]
+#[test]
+fn runs_crossbeam_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let jobs = common::datagen::numbers(1000, rng.seed());
+    let work = |job: u64| job.count_ones() as u64 * (job % 1000);
+    for workers in [1, 4] {
+        let expected = solutions::run_stealing(jobs.clone(), workers, work);
+        assert_eq!(
+            part_17::run_crossbeam(jobs.clone(), workers, work),
+            expected
+        );
+        assert_eq!(
+            solutions::run_crossbeam(jobs.clone(), workers, work),
+            expected
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, OnceLock,
+    },
+    thread,
+    time::Duration,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub name: String,
+    pub workers: usize,
+}
+
+/// How many times `load_config` has been called
+pub static CONFIG_LOADS: AtomicUsize = AtomicUsize::new(0);
+
+/// Loads the config. This is slow, so it should only ever be done once!
+pub fn load_config() -> Config {
+    CONFIG_LOADS.fetch_add(1, Ordering::SeqCst);
+    // Pretend to read and parse a file
+    thread::sleep(Duration::from_millis(50));
+    Config {
+        name: String::from("workshop"),
+        workers: 4,
+    }
+}
+
+/// A value which is initialized the first time someone asks for it, guarded by a mutex
+#[derive(Debug, Default)]
+pub struct MutexCache<T> {
+    value: Mutex<Option<T>>,
+}
+
+impl<T: Clone> MutexCache<T> {
+    pub fn new() -> Self {
+        Self {
+            value: Mutex::new(None),
+        }
+    }
+
+    /// Returns a clone of the value, calling `init` to create it if this is the first call.
+    /// `init` must never be called more than once, no matter how many threads call this at once.
+    pub fn get_or_init(&self, init: impl FnOnce() -> T) -> T {
+        todo!()
+    }
+}
+
+/// The same as `MutexCache`, but with a `OnceLock`
+#[derive(Debug, Default)]
+pub struct OnceCache<T> {
+    value: OnceLock<T>,
+}
+
+impl<T> OnceCache<T> {
+    pub fn new() -> Self {
+        Self {
+            value: OnceLock::new(),
+        }
+    }
+
+    /// Returns the value, calling `init` to create it on the first call.
+    /// Since it's never replaced, there's no need to clone it.
+    pub fn get_or_init(&self, init: impl FnOnce() -> T) -> &T {
+        todo!()
+    }
+}
+
+/// The config for the whole program, loaded with `load_config` the first time it's asked for
+pub fn global_config() -> &'static Config {
+    todo!()
+}
+
+/// How many times the logger has been set up
+pub static LOGGER_SETUPS: AtomicUsize = AtomicUsize::new(0);
+
+/// Sets up logging. Can be called any number of times from anywhere,
+/// but the setup (incrementing `LOGGER_SETUPS`) only happens the first time.
+pub fn init_logging() {
+    todo!()
+}
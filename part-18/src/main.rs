@@ -0,0 +1,101 @@
+use std::sync::atomic::Ordering;
+
+use part_18::{global_config, init_logging, load_config, MutexCache, OnceCache, CONFIG_LOADS};
+
+fn main() {
+    init_logging();
+    let cache = MutexCache::new();
+    let config = cache.get_or_init(load_config);
+    println!("Mutex cache: {config:?}");
+
+    let cache = OnceCache::new();
+    let config = cache.get_or_init(load_config);
+    println!("Once cache: {config:?}");
+
+    println!("Global: {:?}", global_config());
+    init_logging();
+    println!(
+        "Loaded config {} times",
+        CONFIG_LOADS.load(Ordering::SeqCst)
+    );
+}
+
+/// Lets many threads race to get a value
+#[cfg(test)]
+fn race<T: Send>(get: impl Fn() -> T + Sync) -> Vec<T> {
+    let barrier = std::sync::Barrier::new(16);
+    std::thread::scope(|s| {
+        let handles: Vec<_> = (0..16)
+            .map(|_| {
+                s.spawn(|| {
+                    // Release everyone at once to make the race as tight as possible
+                    barrier.wait();
+                    get()
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    })
+}
+
+#[cfg(test)]
+fn counting_init(inits: &std::sync::atomic::AtomicUsize) -> impl FnOnce() -> u64 + '_ {
+    move || {
+        inits.fetch_add(1, Ordering::SeqCst);
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        42
+    }
+}
+
+#[test]
+fn mutex_cache_initializes_once() {
+    use std::sync::atomic::AtomicUsize;
+
+    let inits = AtomicUsize::new(0);
+    let cache = MutexCache::new();
+    let values = race(|| cache.get_or_init(counting_init(&inits)));
+
+    assert_eq!(values, vec![42; 16]);
+    assert_eq!(inits.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn once_cache_initializes_once() {
+    use std::sync::atomic::AtomicUsize;
+
+    let inits = AtomicUsize::new(0);
+    let cache = OnceCache::new();
+    let values = race(|| *cache.get_or_init(counting_init(&inits)));
+
+    assert_eq!(values, vec![42; 16]);
+    assert_eq!(inits.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn once_cache_hands_out_the_same_value() {
+    let cache = OnceCache::new();
+    let first: *const String = cache.get_or_init(|| String::from("first"));
+    let second: *const String = cache.get_or_init(|| String::from("second"));
+    // Not just equal, but the very same value
+    assert_eq!(first, second);
+}
+
+#[test]
+fn global_config_is_loaded_once() {
+    let configs = race(global_config);
+    // No other test loads the config
+    assert_eq!(CONFIG_LOADS.load(Ordering::SeqCst), 1);
+    assert_eq!(configs[0].name, "workshop");
+    assert!(configs
+        .iter()
+        .all(|&config| std::ptr::eq(config, configs[0])));
+}
+
+#[test]
+fn logging_is_set_up_once() {
+    use part_18::LOGGER_SETUPS;
+
+    race(init_logging);
+    init_logging();
+    assert_eq!(LOGGER_SETUPS.load(Ordering::SeqCst), 1);
+}
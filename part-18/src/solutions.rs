@@ -0,0 +1,69 @@
+//! Part 18, with every exercise implemented. Only built with `--features solutions`.
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex, Once, OnceLock,
+};
+
+use super::{load_config, Config};
+
+/// A value which is initialized the first time someone asks for it, guarded by a mutex
+#[derive(Debug, Default)]
+pub struct MutexCache<T> {
+    value: Mutex<Option<T>>,
+}
+
+impl<T: Clone> MutexCache<T> {
+    pub fn new() -> Self {
+        Self {
+            value: Mutex::new(None),
+        }
+    }
+
+    /// Returns a clone of the value, calling `init` to create it if this is the first call.
+    /// `init` must never be called more than once, no matter how many threads call this at once.
+    pub fn get_or_init(&self, init: impl FnOnce() -> T) -> T {
+        // Holding the lock while initializing makes everyone else wait for it,
+        // instead of racing to initialize it themselves
+        let mut value = self.value.lock().unwrap();
+        value.get_or_insert_with(init).clone()
+    }
+}
+
+/// The same as `MutexCache`, but with a `OnceLock`
+#[derive(Debug, Default)]
+pub struct OnceCache<T> {
+    value: OnceLock<T>,
+}
+
+impl<T> OnceCache<T> {
+    pub fn new() -> Self {
+        Self {
+            value: OnceLock::new(),
+        }
+    }
+
+    /// Returns the value, calling `init` to create it on the first call.
+    /// Since it's never replaced, there's no need to clone it.
+    pub fn get_or_init(&self, init: impl FnOnce() -> T) -> &T {
+        self.value.get_or_init(init)
+    }
+}
+
+/// The config for the whole program, loaded with `load_config` the first time it's asked for
+pub fn global_config() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+    CONFIG.get_or_init(load_config)
+}
+
+/// How many times the logger has been set up
+pub static LOGGER_SETUPS: AtomicUsize = AtomicUsize::new(0);
+
+/// Sets up logging. Can be called any number of times from anywhere,
+/// but the setup (incrementing `LOGGER_SETUPS`) only happens the first time.
+pub fn init_logging() {
+    static SETUP: Once = Once::new();
+    SETUP.call_once(|| {
+        LOGGER_SETUPS.fetch_add(1, Ordering::SeqCst);
+    });
+}
@@ -0,0 +1,65 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-18 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use part_18::{global_config, init_logging, solutions, MutexCache, OnceCache, LOGGER_SETUPS};
+
+#[test]
+fn caches_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for value in common::datagen::numbers(10, rng.seed()) {
+        let inits = [AtomicUsize::new(0), AtomicUsize::new(0)];
+        let init = |i: usize| {
+            let inits = &inits;
+            move || {
+                inits[i].fetch_add(1, Ordering::SeqCst);
+                value
+            }
+        };
+        let (mutex, expected_mutex) = (MutexCache::new(), solutions::MutexCache::new());
+        let (once, expected_once) = (OnceCache::new(), solutions::OnceCache::new());
+        for _ in 0..3 {
+            assert_eq!(
+                mutex.get_or_init(init(0)),
+                expected_mutex.get_or_init(init(1))
+            );
+            assert_eq!(
+                once.get_or_init(init(0)),
+                expected_once.get_or_init(init(1))
+            );
+        }
+        assert_eq!(
+            inits[0].load(Ordering::SeqCst),
+            inits[1].load(Ordering::SeqCst),
+            "`init` was called a different number of times"
+        );
+    }
+}
+
+#[test]
+fn initializes_once_like_the_solution() {
+    thread::scope(|s| {
+        for _ in 0..8 {
+            s.spawn(|| {
+                assert_eq!(
+                    (global_config().name.as_str(), global_config().workers),
+                    (
+                        solutions::global_config().name.as_str(),
+                        solutions::global_config().workers
+                    )
+                );
+                init_logging();
+                solutions::init_logging();
+            });
+        }
+    });
+    assert_eq!(
+        LOGGER_SETUPS.load(Ordering::SeqCst),
+        solutions::LOGGER_SETUPS.load(Ordering::SeqCst)
+    );
+}
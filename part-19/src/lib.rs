@@ -0,0 +1,61 @@
+use std::{
+    cell::{Cell, RefCell},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+thread_local! {
+    /// Every thread gets its own, starting at 0
+    static ACCUMULATOR: Cell<u64> = const { Cell::new(0) };
+
+    /// Every thread gets its own buffer for `tidy`, so it doesn't have to allocate a new one every time
+    static SCRATCH: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Adds `value` to the current thread's `ACCUMULATOR`
+pub fn accumulate(value: u64) {
+    todo!()
+}
+
+/// Returns the current thread's accumulated total, and resets it to 0
+pub fn take_accumulated() -> u64 {
+    todo!()
+}
+
+/// Sums `numbers` by letting `threads` threads sum a chunk each,
+/// adding to one shared, mutex-protected total for every number
+pub fn mutex_sum(numbers: Arc<Vec<u64>>, threads: usize) -> u64 {
+    let total = Arc::new(Mutex::new(0));
+    let chunk_size = numbers.len().div_ceil(threads).max(1);
+    let handles: Vec<_> = (0..threads)
+        .map(|thread| {
+            let (numbers, total) = (numbers.clone(), total.clone());
+            thread::spawn(move || {
+                let _worker = common::span::worker(thread);
+                for x in numbers.iter().skip(thread * chunk_size).take(chunk_size) {
+                    *total.lock().unwrap() += x;
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().expect("Couldn't join thread");
+    }
+    let total = *total.lock().unwrap();
+    total
+}
+
+/// Sums `numbers` like `mutex_sum`, but each thread uses `accumulate` for its own numbers,
+/// and only adds its accumulated total to the shared total once it's done
+pub fn thread_local_sum(numbers: Arc<Vec<u64>>, threads: usize) -> u64 {
+    todo!()
+}
+
+/// Lowercases `word` and removes anything that isn't a letter, returning the length of the result.
+/// The work is done in the current thread's `SCRATCH` buffer.
+pub fn tidy(word: &str) -> usize {
+    todo!()
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use common::{cli::Cli, timed};
+use part_19::{mutex_sum, thread_local_sum};
+
+fn main() {
+    common::init_tracing();
+    let args = Cli::new("Sums numbers into a shared mutex, and into thread-locals")
+        .items(1_000_000)
+        .threads(4)
+        .parse();
+    let (numbers, threads) = (args.items() as u64, args.threads());
+    let numbers = Arc::new((0..numbers).collect::<Vec<u64>>());
+
+    let mutex_total = timed("Shared mutex", || mutex_sum(numbers.clone(), threads));
+    let thread_local_total = timed("Thread-local", || thread_local_sum(numbers, threads));
+
+    assert_eq!(mutex_total, thread_local_total);
+}
+
+#[test]
+fn accumulates_and_resets() {
+    use part_19::{accumulate, take_accumulated};
+
+    accumulate(1);
+    accumulate(2);
+    assert_eq!(take_accumulated(), 3);
+    assert_eq!(take_accumulated(), 0);
+}
+
+#[test]
+fn threads_have_their_own_accumulator() {
+    use part_19::{accumulate, take_accumulated};
+
+    accumulate(100);
+    let other = std::thread::spawn(|| {
+        // The main thread's 100 is nowhere to be seen
+        let before = take_accumulated();
+        accumulate(5);
+        (before, take_accumulated())
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(other, (0, 5));
+    assert_eq!(take_accumulated(), 100);
+}
+
+#[test]
+fn sums_correctly() {
+    for (len, threads) in [(0, 2), (1, 4), (1000, 3), (10_000, 8)] {
+        let numbers = Arc::new((0..len).collect::<Vec<u64>>());
+        let expected: u64 = numbers.iter().sum();
+        assert_eq!(mutex_sum(numbers.clone(), threads), expected);
+        assert_eq!(thread_local_sum(numbers, threads), expected);
+    }
+}
+
+#[test]
+fn sum_leaves_nothing_behind() {
+    use part_19::take_accumulated;
+
+    // The spawned threads take their own totals, and the calling thread accumulates nothing
+    thread_local_sum(Arc::new(vec![1, 2, 3]), 2);
+    assert_eq!(take_accumulated(), 0);
+}
+
+#[test]
+fn thread_local_is_faster() {
+    use common::bench::{assert_faster, measure};
+
+    let numbers = Arc::new((0..500_000).collect::<Vec<u64>>());
+    assert_eq!(
+        thread_local_sum(numbers.clone(), 4),
+        mutex_sum(numbers.clone(), 4)
+    );
+    let mutex = measure("mutex", 5, || mutex_sum(numbers.clone(), 4));
+    let thread_local = measure("thread-local", 5, || thread_local_sum(numbers.clone(), 4));
+
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    assert_faster(&thread_local, &mutex);
+}
+
+#[test]
+fn tidies_words_in_parallel() {
+    use part_19::tidy;
+
+    let words = ["Hello,", "WORLD!", "it's", "", "42"];
+    let lengths: Vec<usize> = std::thread::scope(|s| {
+        let handles: Vec<_> = words
+            .iter()
+            .map(|word| s.spawn(move || (tidy(word), tidy(word))))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap().1).collect()
+    });
+
+    assert_eq!(lengths, vec![5, 5, 3, 0, 0]);
+}
@@ -0,0 +1,64 @@
+//! Part 19, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    cell::{Cell, RefCell},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+thread_local! {
+    /// Every thread gets its own, starting at 0
+    static ACCUMULATOR: Cell<u64> = const { Cell::new(0) };
+
+    /// Every thread gets its own buffer for `tidy`, so it doesn't have to allocate a new one every time
+    static SCRATCH: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Adds `value` to the current thread's `ACCUMULATOR`
+pub fn accumulate(value: u64) {
+    ACCUMULATOR.with(|total| total.set(total.get() + value));
+}
+
+/// Returns the current thread's accumulated total, and resets it to 0
+pub fn take_accumulated() -> u64 {
+    ACCUMULATOR.with(|total| total.replace(0))
+}
+
+/// Sums `numbers` like `mutex_sum`, but each thread uses `accumulate` for its own numbers,
+/// and only adds its accumulated total to the shared total once it's done
+pub fn thread_local_sum(numbers: Arc<Vec<u64>>, threads: usize) -> u64 {
+    let total = Arc::new(Mutex::new(0));
+    let chunk_size = numbers.len().div_ceil(threads).max(1);
+    let handles: Vec<_> = (0..threads)
+        .map(|thread| {
+            let (numbers, total) = (numbers.clone(), total.clone());
+            thread::spawn(move || {
+                for &x in numbers.iter().skip(thread * chunk_size).take(chunk_size) {
+                    accumulate(x);
+                }
+                // One lock per thread instead of one lock per number
+                *total.lock().unwrap() += take_accumulated();
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().expect("Couldn't join thread");
+    }
+    let total = *total.lock().unwrap();
+    total
+}
+
+/// Lowercases `word` and removes anything that isn't a letter, returning the length of the result.
+/// The work is done in the current thread's `SCRATCH` buffer.
+pub fn tidy(word: &str) -> usize {
+    SCRATCH.with_borrow_mut(|scratch| {
+        // Clearing keeps the memory allocated from the previous call
+        scratch.clear();
+        scratch.extend(
+            word.chars()
+                .filter(|c| c.is_alphabetic())
+                .flat_map(char::to_lowercase),
+        );
+        scratch.len()
+    })
+}
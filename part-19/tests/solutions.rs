@@ -0,0 +1,54 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-19 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{sync::Arc, thread};
+
+use common::datagen;
+use part_19::{accumulate, solutions, take_accumulated, thread_local_sum, tidy};
+
+#[test]
+fn accumulates_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let numbers = datagen::numbers_below(100, 1000, rng.seed());
+    // On a thread of its own, so nothing else has accumulated anything on it
+    thread::spawn(move || {
+        for values in numbers.chunks(7) {
+            for &value in values {
+                accumulate(value);
+                solutions::accumulate(value);
+            }
+            assert_eq!(take_accumulated(), solutions::take_accumulated());
+        }
+        assert_eq!(take_accumulated(), solutions::take_accumulated());
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn sums_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let numbers = Arc::new(datagen::numbers_below(10_000, 1 << 32, rng.seed()));
+    for threads in [1, 3, 8] {
+        assert_eq!(
+            thread_local_sum(numbers.clone(), threads),
+            solutions::thread_local_sum(numbers.clone(), threads)
+        );
+    }
+}
+
+#[test]
+fn tidies_like_the_solution() {
+    for word in [
+        "",
+        "Hello,",
+        "WORLD!",
+        "don't",
+        "42",
+        "Æsøp's",
+        "mIxEd-CaSe",
+    ] {
+        assert_eq!(tidy(word), solutions::tidy(word), "Tidying {word:?}");
+    }
+}
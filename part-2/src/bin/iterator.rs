@@ -0,0 +1,65 @@
+use std::sync::mpsc::Receiver;
+
+#[cfg(all(test, feature = "solutions"))]
+#[path = "iterator/solutions.rs"]
+mod solutions;
+
+fn main() {
+    for x in up_to(10) {
+        println!("Got: {x}");
+    }
+
+    for x in naturals().iter().take(5) {
+        println!("Got natural: {x}");
+    }
+}
+
+/// Returns a receiver of the numbers `0, 1, ..., n - 1`, in order,
+/// after which the iteration over the receiver should end.
+fn up_to(n: i32) -> Receiver<i32> {
+    todo!()
+}
+
+/// Returns a receiver of the numbers `0, 1, 2, ...` which never runs out.
+/// The sending thread should stop once nobody is listening anymore.
+fn naturals() -> Receiver<u64> {
+    todo!()
+}
+
+#[test]
+fn for_loop_ends_when_sender_is_dropped() {
+    let mut result = Vec::new();
+    // `Receiver` implements `IntoIterator`, blocking on `recv` for each item
+    for x in up_to(10) {
+        result.push(x);
+    }
+
+    assert_eq!(result, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9])
+}
+
+#[test]
+fn take_ends_an_endless_receiver() {
+    let receiver = naturals();
+    let result: Vec<u64> = receiver.iter().take(5).collect();
+
+    assert_eq!(result, vec![0, 1, 2, 3, 4]);
+
+    // The receiver can still be iterated after `take` is done with it
+    let next: Vec<u64> = receiver.iter().take(2).collect();
+    assert_eq!(next, vec![5, 6]);
+}
+
+#[cfg(feature = "solutions")]
+#[test]
+fn receives_like_the_solution() {
+    for n in [0, 1, 1000] {
+        assert_eq!(
+            up_to(n).iter().collect::<Vec<_>>(),
+            solutions::up_to(n).iter().collect::<Vec<_>>()
+        );
+    }
+    assert_eq!(
+        naturals().iter().take(1000).collect::<Vec<_>>(),
+        solutions::naturals().iter().take(1000).collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,29 @@
+//! `iterator`, with every exercise implemented. Only built with `--features solutions`.
+
+use std::sync::mpsc::Receiver;
+
+/// Returns a receiver of the numbers `0, 1, ..., n - 1`, in order,
+/// after which the iteration over the receiver should end.
+pub fn up_to(n: i32) -> Receiver<i32> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        (0..n).for_each(|x| tx.send(x).expect("Couldn't send value. Receiver dropped"))
+    });
+    rx
+}
+
+/// Returns a receiver of the numbers `0, 1, 2, ...` which never runs out.
+/// The sending thread should stop once nobody is listening anymore.
+pub fn naturals() -> Receiver<u64> {
+    // A bounded channel keeps the sender from racing ahead and filling up memory
+    let (tx, rx) = std::sync::mpsc::sync_channel(1);
+    std::thread::spawn(move || {
+        for x in 0.. {
+            if tx.send(x).is_err() {
+                // The receiver was dropped, so no one will ever read this
+                break;
+            }
+        }
+    });
+    rx
+}
@@ -0,0 +1,142 @@
+use std::{
+    env,
+    io::{self, BufWriter, Read, Write},
+    process::{self, Command},
+    sync::mpsc::Receiver,
+};
+
+#[cfg(all(test, feature = "solutions"))]
+#[path = "processes/solutions.rs"]
+mod solutions;
+
+/// Run with `cargo run -p part-2 --features advanced --bin processes`. Starts this program again as
+/// a child process, which sends it the numbers, or add `--crash-after <n>` to have the child crash
+/// after sending `n` of them.
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let crash_after: Option<usize> = args
+        .iter()
+        .position(|arg| arg == "--crash-after")
+        .map(|at| {
+            args.get(at + 1)
+                .and_then(|n| n.parse().ok())
+                .expect("--crash-after takes a number")
+        });
+
+    if args.first().is_some_and(|arg| arg == "child") {
+        let mut stdout = BufWriter::new(io::stdout().lock());
+        if let Err(error) = send_numbers(&mut stdout, 10, crash_after) {
+            eprintln!("The child couldn't send its numbers: {error}");
+            process::exit(1);
+        }
+        return;
+    }
+
+    let mut child = Command::new(env::current_exe().expect("Couldn't find this program"));
+    child.arg("child");
+    if let Some(n) = crash_after {
+        child.args(["--crash-after", &n.to_string()]);
+    }
+    let receiver = across_processes(child).expect("Couldn't start the child");
+    for message in receiver {
+        match message {
+            Ok(x) => println!("Got: {x}"),
+            Err(error) => {
+                eprintln!("The child failed: {error}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Writes `message` as a frame: its length, as 4 little-endian bytes, followed by the message
+fn write_frame(writer: &mut impl Write, message: &[u8]) -> io::Result<()> {
+    let length = u32::try_from(message.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "The message is too long"))?;
+    writer.write_all(&length.to_le_bytes())?;
+    writer.write_all(message)
+}
+
+/// The child's side: sends the numbers `0, 1, ..., count - 1` to `writer`, in order, a frame each.
+/// With `crash_after`, it stops after that many numbers, halfway through the next frame, and exits
+/// the process with a failure, like a child which crashed would.
+fn send_numbers(writer: &mut impl Write, count: i32, crash_after: Option<usize>) -> io::Result<()> {
+    for (sent, x) in (0..count).enumerate() {
+        if crash_after == Some(sent) {
+            writer.write_all(&4u32.to_le_bytes())?;
+            writer.flush()?;
+            process::exit(3);
+        }
+        write_frame(writer, &x.to_le_bytes())?;
+    }
+    writer.flush()
+}
+
+/// Reads the next frame written by `write_frame`. Gives `None` if `reader` ends before the frame
+/// starts, since that's the writer being done, and fails with `ErrorKind::UnexpectedEof` if it
+/// ends halfway through one.
+fn read_frame(reader: &mut impl Read) -> io::Result<Option<Vec<u8>>> {
+    todo!()
+}
+
+/// Starts `child`, with its stdout piped, and returns a receiver of the numbers it writes there,
+/// one `write_frame` each, in order, like `across_the_border` but from another process. The
+/// receiver ends once the child does. If the child exits with a failure, or stops halfway through
+/// a frame, the last message is an error saying so.
+fn across_processes(child: Command) -> io::Result<Receiver<io::Result<i32>>> {
+    todo!()
+}
+
+#[test]
+fn frames_round_trip() {
+    let messages: [&[u8]; 4] = [b"across", b"", &[0; 1000], b"the border"];
+    let mut written = Vec::new();
+    for message in messages {
+        write_frame(&mut written, message).unwrap();
+    }
+    let mut reader = &written[..];
+    for message in messages {
+        assert_eq!(read_frame(&mut reader).unwrap().as_deref(), Some(message));
+    }
+    assert_eq!(read_frame(&mut reader).unwrap(), None, "Nothing's left");
+}
+
+#[test]
+fn cut_off_frames_are_errors() {
+    let mut written = Vec::new();
+    write_frame(&mut written, b"cut off").unwrap();
+    for end in [2, 4, 6] {
+        let error = read_frame(&mut &written[..end]).unwrap_err();
+        assert_eq!(
+            error.kind(),
+            io::ErrorKind::UnexpectedEof,
+            "Cut off after {end} bytes"
+        );
+    }
+}
+
+#[cfg(feature = "solutions")]
+#[test]
+fn reads_frames_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let mut written = Vec::new();
+    for _ in 0..10 {
+        let message: Vec<u8> = (0..rng.below(100)).map(|_| rng.below(256) as u8).collect();
+        write_frame(&mut written, &message).unwrap();
+    }
+    let end = rng.below(written.len() as u64 + 1) as usize;
+    let (mut reader, mut expected) = (&written[..end], &written[..end]);
+    loop {
+        let (frame, solution) = (
+            read_frame(&mut reader),
+            solutions::read_frame(&mut expected),
+        );
+        assert_eq!(
+            frame.as_ref().map_err(io::Error::kind),
+            solution.as_ref().map_err(io::Error::kind)
+        );
+        if !matches!(solution, Ok(Some(_))) {
+            break;
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use std::{
+    io::{self, Read},
+    process::{Command, Stdio},
+    sync::mpsc::{self, Receiver},
+    thread,
+};
+
+pub fn read_frame(reader: &mut impl Read) -> io::Result<Option<Vec<u8>>> {
+    let mut length = [0; 4];
+    // Ending before the first byte is the writer being done, anywhere after it the frame is cut off
+    if reader.read(&mut length[..1])? == 0 {
+        return Ok(None);
+    }
+    reader.read_exact(&mut length[1..])?;
+    let mut frame = vec![0; u32::from_le_bytes(length) as usize];
+    reader.read_exact(&mut frame)?;
+    Ok(Some(frame))
+}
+
+// Only the program in `tests/processes.rs` runs it, since the unit tests can't start this program
+#[allow(dead_code)]
+pub fn across_processes(mut child: Command) -> io::Result<Receiver<io::Result<i32>>> {
+    let mut child = child.stdout(Stdio::piped()).spawn()?;
+    let mut stdout = child.stdout.take().expect("Its stdout is piped");
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let read = loop {
+            let frame = match read_frame(&mut stdout) {
+                Ok(Some(frame)) => frame,
+                Ok(None) => break Ok(()),
+                Err(error) => break Err(error),
+            };
+            let x = match <[u8; 4]>::try_from(frame) {
+                Ok(bytes) => i32::from_le_bytes(bytes),
+                Err(_) => break Err(io::Error::new(io::ErrorKind::InvalidData, "Not a number")),
+            };
+            if sender.send(Ok(x)).is_err() {
+                // Nobody's listening, and dropping `stdout` makes the child's next write fail
+                break Ok(());
+            }
+        };
+        drop(stdout);
+        // Like a thread's handle, a child has to be waited for, or it's left behind as a zombie
+        let failure = match child.wait() {
+            Ok(status) if !status.success() => {
+                Some(io::Error::other(format!("The child exited with {status}")))
+            }
+            Ok(_) => read.err(),
+            Err(error) => Some(error),
+        };
+        if let Some(error) = failure {
+            let _ = sender.send(Err(error));
+        }
+    });
+    Ok(receiver)
+}
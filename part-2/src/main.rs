@@ -1,22 +1,52 @@
+use common::channel::{Backend, Selected, Sender};
+
+#[cfg(all(test, feature = "solutions"))]
+mod solutions;
+
 fn main() {
-    let receiver = across_the_border();
+    let receiver = across_the_border::<Selected>();
 
     while let Ok(x) = receiver.recv() {
         println!("Got: {x}");
     }
 }
 
-fn across_the_border() -> std::sync::mpsc::Receiver<i32> {
+/// Returns a receiver of the numbers `0, 1, ..., 9`, in order, over a channel of backend `B`
+fn across_the_border<B: Backend>() -> B::Receiver<i32> {
     todo!()
 }
 
-#[test]
-fn sends_data_correctly() {
-    let receiver = across_the_border();
+#[cfg(test)]
+fn sends_data_correctly_on<B: Backend>() {
+    use common::trace::{RecordingReceiver, Trace};
+
+    // Shows what was received, and when, if the test fails
+    let trace = Trace::new();
+    let _dump = trace.dump_on_panic();
+    let receiver = RecordingReceiver::wrap(across_the_border::<B>(), &trace);
     let mut result = Vec::new();
     while let Ok(x) = receiver.recv() {
         result.push(x);
     }
 
-    assert_eq!(result, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9])
+    assert_eq!(result, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9], "On {}", B::NAME)
+}
+
+// The same test on every backend, so that a solution can't depend on how one of them works
+#[test]
+fn sends_data_correctly() {
+    sends_data_correctly_on::<common::channel::Std>();
+    #[cfg(feature = "crossbeam")]
+    sends_data_correctly_on::<common::channel::Crossbeam>();
+}
+
+#[cfg(feature = "solutions")]
+#[test]
+fn sends_like_the_solution() {
+    assert_eq!(
+        across_the_border::<Selected>().iter().collect::<Vec<_>>(),
+        solutions::across_the_border::<Selected>()
+            .iter()
+            .collect::<Vec<_>>()
+    );
 }
@@ -0,0 +1,61 @@
+//! The `processes` program, which starts itself again as a child process and receives the numbers
+//! from it. Run with `cargo test -p part-2 --features advanced`.
+#![cfg(feature = "advanced")]
+
+use std::process::{Command, Output};
+
+fn processes(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_processes"))
+        .args(args)
+        .output()
+        .expect("Couldn't run the program")
+}
+
+fn received(output: &Output) -> Vec<String> {
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+fn got(numbers: std::ops::Range<i32>) -> Vec<String> {
+    numbers.map(|x| format!("Got: {x}")).collect()
+}
+
+#[test]
+fn receives_every_number_from_the_child() {
+    let output = processes(&[]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(received(&output), got(0..10));
+}
+
+#[test]
+fn the_child_sends_frames() {
+    let output = processes(&["child"]);
+    assert!(output.status.success());
+    let mut expected = Vec::new();
+    for x in 0..10i32 {
+        expected.extend(4u32.to_le_bytes());
+        expected.extend(x.to_le_bytes());
+    }
+    assert_eq!(output.stdout, expected);
+}
+
+#[test]
+fn tells_when_the_child_crashes() {
+    for crash_after in [0, 3] {
+        let output = processes(&["--crash-after", &crash_after.to_string()]);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(!output.status.success(), "Crashing after {crash_after}");
+        assert_eq!(received(&output), got(0..crash_after));
+        // `ExitStatus` shows the child's exit code as `exit status: 3`, or `exit code: 3` on Windows
+        assert!(
+            stderr.contains("The child failed") && stderr.contains(": 3"),
+            "The error says how the child exited, but the program printed:\n{stderr}"
+        );
+    }
+}
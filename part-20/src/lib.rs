@@ -0,0 +1,81 @@
+use std::{
+    mem,
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Gives the other thread time to do its part, making the deadlocks happen every time instead of now and then
+pub fn pause() {
+    thread::sleep(Duration::from_millis(10));
+}
+
+/// Swaps the values of `a` and `b`.
+///
+/// Deadlocks when one thread swaps `a` and `b` while another swaps `b` and `a`:
+/// each of them locks its first argument, and then waits forever for the other to release its second.
+pub fn broken_swap(a: &Mutex<i32>, b: &Mutex<i32>) {
+    let mut first = a.lock().unwrap();
+    pause();
+    let mut second = b.lock().unwrap();
+    mem::swap(&mut *first, &mut *second);
+}
+
+/// Swaps the values of `a` and `b` without deadlocking, however the arguments are ordered.
+/// Keep the `pause()` between the two locks, so you know the fix isn't just luck.
+pub fn swap(a: &Mutex<i32>, b: &Mutex<i32>) {
+    todo!()
+}
+
+/// Lets two threads exchange values, returning what each of them received.
+///
+/// Deadlocks every time: both threads wait for the other's message before sending their own.
+pub fn broken_exchange(a: i32, b: i32) -> (i32, i32) {
+    let (to_first, first_inbox) = mpsc::channel();
+    let (to_second, second_inbox) = mpsc::channel();
+
+    let first = thread::spawn(move || {
+        let _first = common::span::thread("first");
+        let received = first_inbox.recv().unwrap();
+        to_second.send(a).unwrap();
+        received
+    });
+    let second = thread::spawn(move || {
+        let _second = common::span::thread("second");
+        let received = second_inbox.recv().unwrap();
+        to_first.send(b).unwrap();
+        received
+    });
+
+    (first.join().unwrap(), second.join().unwrap())
+}
+
+/// Lets two threads exchange values like `broken_exchange`, without deadlocking
+pub fn exchange(a: i32, b: i32) -> (i32, i32) {
+    todo!()
+}
+
+/// Appends "starting", then `line` from a separate thread, and then "done" to `log`.
+///
+/// Deadlocks every time: the thread waits for its child while holding the lock
+/// the child needs, so it's really waiting for itself.
+pub fn broken_append(log: Arc<Mutex<Vec<String>>>, line: String) {
+    let mut entries = log.lock().unwrap();
+    entries.push("starting".to_string());
+
+    let child = {
+        let log = log.clone();
+        thread::spawn(move || log.lock().unwrap().push(line))
+    };
+    child.join().unwrap();
+
+    entries.push("done".to_string());
+}
+
+/// Appends to `log` like `broken_append`, without deadlocking
+pub fn append(log: Arc<Mutex<Vec<String>>>, line: String) {
+    todo!()
+}
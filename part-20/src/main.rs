@@ -0,0 +1,116 @@
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use common::with_timeout;
+use part_20::{append, broken_append, broken_exchange, broken_swap, exchange, swap};
+
+const TIMEOUT: Duration = Duration::from_secs(1);
+
+fn main() {
+    common::init_tracing();
+    report("Swapping", with_timeout(TIMEOUT, || swaps(broken_swap)));
+    report(
+        "Exchanging",
+        with_timeout(TIMEOUT, || broken_exchange(1, 2)),
+    );
+    report(
+        "Appending",
+        with_timeout(TIMEOUT, || {
+            broken_append(Default::default(), "hello".to_string())
+        }),
+    );
+
+    report("Fixed swapping", with_timeout(TIMEOUT, || swaps(swap)));
+    report("Fixed exchanging", with_timeout(TIMEOUT, || exchange(1, 2)));
+    report(
+        "Fixed appending",
+        with_timeout(TIMEOUT, || append(Default::default(), "hello".to_string())),
+    );
+}
+
+fn report<T: std::fmt::Debug>(name: &str, result: Option<T>) {
+    match result {
+        Some(result) => println!("{name} finished with {result:?}"),
+        None => println!("{name} deadlocked!"),
+    }
+}
+
+/// Lets two threads swap the same two values at the same time, with the arguments in opposite order
+fn swaps(swap: fn(&Mutex<i32>, &Mutex<i32>)) -> (i32, i32) {
+    let a = Arc::new(Mutex::new(1));
+    let b = Arc::new(Mutex::new(2));
+
+    let handles = [(a.clone(), b.clone()), (b.clone(), a.clone())]
+        .map(|(first, second)| thread::spawn(move || swap(&first, &second)));
+    for handle in handles {
+        handle.join().expect("Couldn't join thread");
+    }
+
+    let values = (*a.lock().unwrap(), *b.lock().unwrap());
+    values
+}
+
+#[test]
+fn broken_swap_deadlocks() {
+    assert_eq!(with_timeout(TIMEOUT, || swaps(broken_swap)), None);
+}
+
+#[test]
+fn swap_does_not_deadlock() {
+    // Swapping twice gets us back where we started
+    assert_eq!(with_timeout(TIMEOUT, || swaps(swap)), Some((1, 2)));
+}
+
+#[test]
+fn swaps_values() {
+    let a = Mutex::new(1);
+    let b = Mutex::new(2);
+    swap(&a, &b);
+    assert_eq!((*a.lock().unwrap(), *b.lock().unwrap()), (2, 1));
+    swap(&b, &a);
+    assert_eq!((*a.lock().unwrap(), *b.lock().unwrap()), (1, 2));
+}
+
+#[test]
+fn swaps_with_itself() {
+    let result = with_timeout(TIMEOUT, || {
+        let a = Mutex::new(1);
+        swap(&a, &a);
+        let value = *a.lock().unwrap();
+        value
+    });
+    assert_eq!(result, Some(1));
+}
+
+#[test]
+fn broken_exchange_deadlocks() {
+    assert_eq!(with_timeout(TIMEOUT, || broken_exchange(1, 2)), None);
+}
+
+#[test]
+fn exchange_does_not_deadlock() {
+    assert_eq!(with_timeout(TIMEOUT, || exchange(1, 2)), Some((2, 1)));
+    assert_eq!(with_timeout(TIMEOUT, || exchange(-5, 5)), Some((5, -5)));
+}
+
+#[test]
+fn broken_append_deadlocks() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let result = with_timeout(TIMEOUT, move || broken_append(log, "hello".to_string()));
+    assert_eq!(result, None);
+}
+
+#[test]
+fn append_does_not_deadlock() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let result = with_timeout(TIMEOUT, {
+        let log = log.clone();
+        move || append(log, "hello".to_string())
+    });
+
+    assert_eq!(result, Some(()));
+    assert_eq!(*log.lock().unwrap(), vec!["starting", "hello", "done"]);
+}
@@ -0,0 +1,73 @@
+//! Part 20, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    mem,
+    sync::{mpsc, Arc, Mutex},
+    thread,
+};
+
+use super::pause;
+
+/// Swaps the values of `a` and `b`.
+///
+/// Deadlocks when one thread swaps `a` and `b` while another swaps `b` and `a`:
+/// each of them locks its first argument, and then waits forever for the other to release its second.
+pub fn broken_swap(a: &Mutex<i32>, b: &Mutex<i32>) {
+    let mut first = a.lock().unwrap();
+    pause();
+    let mut second = b.lock().unwrap();
+    mem::swap(&mut *first, &mut *second);
+}
+
+/// Swaps the values of `a` and `b` without deadlocking, however the arguments are ordered.
+/// Keep the `pause()` between the two locks, so you know the fix isn't just luck.
+pub fn swap(a: &Mutex<i32>, b: &Mutex<i32>) {
+    // Locking the same mutex twice would deadlock too, and there's nothing to swap anyway
+    if std::ptr::eq(a, b) {
+        return;
+    }
+    // The addresses give every mutex a place in the same global order
+    let (a, b) = if (a as *const Mutex<i32>) < (b as *const Mutex<i32>) {
+        (a, b)
+    } else {
+        (b, a)
+    };
+
+    let mut first = a.lock().unwrap();
+    pause();
+    let mut second = b.lock().unwrap();
+    mem::swap(&mut *first, &mut *second);
+}
+
+/// Lets two threads exchange values like `broken_exchange`, without deadlocking
+pub fn exchange(a: i32, b: i32) -> (i32, i32) {
+    let (to_first, first_inbox) = mpsc::channel();
+    let (to_second, second_inbox) = mpsc::channel();
+
+    let first = thread::spawn(move || {
+        // Sending never blocks on an unbounded channel, so sending first breaks the cycle
+        to_second.send(a).unwrap();
+        first_inbox.recv().unwrap()
+    });
+    let second = thread::spawn(move || {
+        let received = second_inbox.recv().unwrap();
+        to_first.send(b).unwrap();
+        received
+    });
+
+    (first.join().unwrap(), second.join().unwrap())
+}
+
+/// Appends to `log` like `broken_append`, without deadlocking
+pub fn append(log: Arc<Mutex<Vec<String>>>, line: String) {
+    log.lock().unwrap().push("starting".to_string());
+
+    let child = {
+        let log = log.clone();
+        thread::spawn(move || log.lock().unwrap().push(line))
+    };
+    // The guard used for pushing "starting" was dropped right away, so the child can lock the log
+    child.join().unwrap();
+
+    log.lock().unwrap().push("done".to_string());
+}
@@ -0,0 +1,68 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-20 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use common::with_timeout;
+use part_20::{append, exchange, solutions, swap};
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The pairs of values the exercises get, the same for the same seed
+fn pairs(seed: u64) -> Vec<(i32, i32)> {
+    let numbers = common::datagen::numbers_below(10, 1000, seed);
+    numbers
+        .chunks(2)
+        .map(|pair| (pair[0] as i32, pair[1] as i32))
+        .collect()
+}
+
+#[test]
+fn swaps_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for (a, b) in pairs(rng.seed()) {
+        let swapped = with_timeout(TIMEOUT, move || {
+            let (a, b) = (Mutex::new(a), Mutex::new(b));
+            swap(&a, &b);
+            (a.into_inner().unwrap(), b.into_inner().unwrap())
+        });
+        let (a, b) = (Mutex::new(a), Mutex::new(b));
+        solutions::swap(&a, &b);
+        assert_eq!(
+            swapped.expect("Swapping deadlocked"),
+            (a.into_inner().unwrap(), b.into_inner().unwrap())
+        );
+    }
+}
+
+#[test]
+fn exchanges_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for (a, b) in pairs(rng.seed()) {
+        assert_eq!(
+            with_timeout(TIMEOUT, move || exchange(a, b)).expect("Exchanging deadlocked"),
+            solutions::exchange(a, b)
+        );
+    }
+}
+
+#[test]
+fn appends_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for (line, _) in pairs(rng.seed()) {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        with_timeout(TIMEOUT, {
+            let log = log.clone();
+            move || append(log, line.to_string())
+        })
+        .expect("Appending deadlocked");
+
+        let expected = Arc::new(Mutex::new(Vec::new()));
+        solutions::append(expected.clone(), line.to_string());
+        assert_eq!(*log.lock().unwrap(), *expected.lock().unwrap());
+    }
+}
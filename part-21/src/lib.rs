@@ -0,0 +1,68 @@
+use std::{
+    cell::UnsafeCell,
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// A mutex that never puts threads to sleep, it keeps them busy checking whether the lock is free yet
+pub struct SpinLock<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+// Safety: only the thread holding the lock can access the value
+unsafe impl<T: Send> Sync for SpinLock<T> {}
+
+/// Gives access to the value while the lock is held, and releases the lock when dropped
+pub struct SpinLockGuard<'a, T> {
+    lock: &'a SpinLock<T>,
+}
+
+impl<T> SpinLock<T> {
+    pub const fn new(value: T) -> Self {
+        Self {
+            locked: AtomicBool::new(false),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Spins until the lock is free, and then takes it
+    pub fn lock(&self) -> SpinLockGuard<'_, T> {
+        todo!()
+    }
+
+    /// Takes the lock if it's free, without waiting
+    pub fn try_lock(&self) -> Option<SpinLockGuard<'_, T>> {
+        todo!()
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T> Deref for SpinLockGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // Safety: the guard only exists while the lock is held
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> DerefMut for SpinLockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // Safety: the guard only exists while the lock is held
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<T> Drop for SpinLockGuard<'_, T> {
+    /// Releases the lock
+    fn drop(&mut self) {
+        todo!()
+    }
+}
@@ -0,0 +1,147 @@
+use std::{
+    sync::{Arc, Barrier, Mutex},
+    thread,
+    time::Duration,
+};
+
+use common::{cli::Cli, timed};
+use part_21::SpinLock;
+
+/// The two locks, behind one common interface so they can be benchmarked with the same code
+trait Lock: Send + Sync + 'static {
+    fn with_lock(&self, f: impl FnOnce(&mut u64));
+}
+
+impl Lock for Mutex<u64> {
+    fn with_lock(&self, f: impl FnOnce(&mut u64)) {
+        f(&mut self.lock().unwrap())
+    }
+}
+
+impl Lock for SpinLock<u64> {
+    fn with_lock(&self, f: impl FnOnce(&mut u64)) {
+        f(&mut self.lock())
+    }
+}
+
+fn main() {
+    let args = Cli::new("Compares a mutex with a spin lock, with short and long critical sections")
+        .items(100_000)
+        .threads(4)
+        .parse();
+    let (threads, iterations) = (args.threads(), args.items() as u64);
+
+    // Tiny critical sections: the lock is free again before a sleeping thread would even have woken up
+    timed("Mutex, short sections", || {
+        contend(Mutex::new(0), threads, iterations, Duration::ZERO)
+    });
+    timed("Spin lock, short sections", || {
+        contend(SpinLock::new(0), threads, iterations, Duration::ZERO)
+    });
+
+    // Long critical sections: spinning threads burn the CPU time the lock holder could have used.
+    // Twice as many threads, so that there are more of them than cores.
+    let hold = Duration::from_millis(1);
+    timed("Mutex, long sections", || {
+        contend(Mutex::new(0), threads * 2, 50, hold)
+    });
+    timed("Spin lock, long sections", || {
+        contend(SpinLock::new(0), threads * 2, 50, hold)
+    });
+}
+
+/// Lets `threads` threads increment the value `iterations` times each, holding the lock for `hold` each time
+fn contend(lock: impl Lock, threads: usize, iterations: u64, hold: Duration) -> u64 {
+    let lock = Arc::new(lock);
+    let barrier = Arc::new(Barrier::new(threads));
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let (lock, barrier) = (lock.clone(), barrier.clone());
+            thread::spawn(move || {
+                barrier.wait();
+                for _ in 0..iterations {
+                    lock.with_lock(|value| {
+                        *value += 1;
+                        if !hold.is_zero() {
+                            // Busy-wait, like real work would
+                            let start = std::time::Instant::now();
+                            while start.elapsed() < hold {}
+                        }
+                    });
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().expect("Couldn't join thread");
+    }
+
+    let mut total = 0;
+    lock.with_lock(|value| total = *value);
+    total
+}
+
+#[test]
+fn locks_and_unlocks() {
+    let lock = SpinLock::new(1);
+    *lock.lock() += 1;
+    *lock.lock() += 1;
+    assert_eq!(lock.into_inner(), 3);
+}
+
+#[test]
+fn try_lock_fails_while_locked() {
+    let lock = SpinLock::new(());
+    let guard = lock.lock();
+    assert!(lock.try_lock().is_none());
+    drop(guard);
+    assert!(lock.try_lock().is_some());
+}
+
+#[test]
+fn guard_unlocks_on_panic() {
+    let lock = Arc::new(SpinLock::new(0));
+    let result = thread::spawn({
+        let lock = lock.clone();
+        move || {
+            let _guard = lock.lock();
+            panic!("Oh no!");
+        }
+    })
+    .join();
+
+    assert!(result.is_err());
+    assert!(lock.try_lock().is_some());
+}
+
+#[test]
+fn mutual_exclusion_under_contention() {
+    let iterations = common::sanitize::workload(10_000) as u64;
+    assert_eq!(
+        contend(SpinLock::new(0), 4, iterations, Duration::ZERO),
+        4 * iterations
+    );
+}
+
+#[test]
+fn protects_non_atomic_updates() {
+    // Two values which must always be updated together
+    let lock = Arc::new(SpinLock::new((0u64, 0u64)));
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let lock = lock.clone();
+            thread::spawn(move || {
+                for _ in 0..10_000 {
+                    let mut pair = lock.lock();
+                    pair.0 += 1;
+                    pair.1 += 1;
+                    assert_eq!(pair.0, pair.1);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(*lock.lock(), (40_000, 40_000));
+}
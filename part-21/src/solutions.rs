@@ -0,0 +1,80 @@
+//! Part 21, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    cell::UnsafeCell,
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// A mutex that never puts threads to sleep, it keeps them busy checking whether the lock is free yet
+pub struct SpinLock<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+// Safety: only the thread holding the lock can access the value
+unsafe impl<T: Send> Sync for SpinLock<T> {}
+
+/// Gives access to the value while the lock is held, and releases the lock when dropped
+pub struct SpinLockGuard<'a, T> {
+    lock: &'a SpinLock<T>,
+}
+
+impl<T> SpinLock<T> {
+    pub const fn new(value: T) -> Self {
+        Self {
+            locked: AtomicBool::new(false),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Spins until the lock is free, and then takes it
+    pub fn lock(&self) -> SpinLockGuard<'_, T> {
+        loop {
+            if let Some(guard) = self.try_lock() {
+                return guard;
+            }
+            // Only read while waiting, writing would make the cores fight over the cache line
+            while self.locked.load(Ordering::Relaxed) {
+                std::hint::spin_loop();
+            }
+        }
+    }
+
+    /// Takes the lock if it's free, without waiting
+    pub fn try_lock(&self) -> Option<SpinLockGuard<'_, T>> {
+        // Acquire, so we see everything the previous holder did with the value
+        self.locked
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .ok()
+            .map(|_| SpinLockGuard { lock: self })
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T> Deref for SpinLockGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // Safety: the guard only exists while the lock is held
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> DerefMut for SpinLockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // Safety: the guard only exists while the lock is held
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<T> Drop for SpinLockGuard<'_, T> {
+    /// Releases the lock
+    fn drop(&mut self) {
+        // Release, so the next holder sees everything we did with the value
+        self.lock.locked.store(false, Ordering::Release);
+    }
+}
@@ -0,0 +1,36 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-21 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::thread;
+
+use part_21::{solutions, SpinLock};
+
+#[test]
+fn tries_like_the_solution() {
+    let (lock, expected) = (SpinLock::new(0), solutions::SpinLock::new(0));
+    {
+        let (_held, _expected_held) = (lock.lock(), expected.lock());
+        assert_eq!(lock.try_lock().is_some(), expected.try_lock().is_some());
+    }
+    assert_eq!(lock.try_lock().is_some(), expected.try_lock().is_some());
+}
+
+#[test]
+fn counts_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let increments = common::datagen::numbers_below(4000, 100, rng.seed());
+    let (lock, expected) = (SpinLock::new(0), solutions::SpinLock::new(0));
+    thread::scope(|s| {
+        for increments in increments.chunks(1000) {
+            let (lock, expected) = (&lock, &expected);
+            s.spawn(move || {
+                for &increment in increments {
+                    *lock.lock() += increment;
+                    *expected.lock() += increment;
+                }
+            });
+        }
+    });
+    assert_eq!(lock.into_inner(), expected.into_inner());
+}
@@ -0,0 +1,111 @@
+use std::{mem::ManuallyDrop, ptr};
+
+// Under `--cfg loom` the atomics are swapped for loom's, which lets loom
+// explore every way the threads in a test can interleave.
+#[cfg(loom)]
+use loom::sync::atomic::{AtomicPtr, Ordering};
+#[cfg(not(loom))]
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+struct Node<T> {
+    /// Taken out by whoever pops the node, so it's dropped manually
+    value: ManuallyDrop<T>,
+    /// The node below this one. Never changed after the node has been pushed.
+    next: *mut Node<T>,
+    /// The node retired before this one, see [`Stack::retire`]
+    next_retired: *mut Node<T>,
+}
+
+/// A lock-free stack, also known as a Treiber stack.
+/// The stack is a linked list, and pushing and popping swaps out its head with a compare-and-swap.
+pub struct Stack<T> {
+    head: AtomicPtr<Node<T>>,
+    /// Nodes that have been popped, but can't be freed yet
+    retired: AtomicPtr<Node<T>>,
+}
+
+// Safety: values are moved into the stack by one thread, and out of it by another
+unsafe impl<T: Send> Send for Stack<T> {}
+unsafe impl<T: Send> Sync for Stack<T> {}
+
+impl<T> Stack<T> {
+    pub fn new() -> Self {
+        Self {
+            head: AtomicPtr::new(ptr::null_mut()),
+            retired: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Puts `value` on top of the stack
+    pub fn push(&self, value: T) {
+        todo!()
+    }
+
+    /// Takes the value on top of the stack, if there is one.
+    /// Use `retire` for the popped node.
+    pub fn pop(&self) -> Option<T> {
+        todo!()
+    }
+
+    /// Keeps a popped node until the stack is dropped.
+    ///
+    /// Freeing it right away isn't safe: another thread may have read the same head
+    /// right before we popped it, and be just about to read its `next`.
+    /// Its compare-and-swap would fail, but by then it has already read freed memory.
+    fn retire(&self, node: *mut Node<T>) {
+        let mut retired = self.retired.load(Ordering::Relaxed);
+        loop {
+            // Safety: the node was popped by us, so we're the only ones writing to it
+            unsafe { (*node).next_retired = retired };
+            match self.retired.compare_exchange_weak(
+                retired,
+                node,
+                Ordering::Release,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return,
+                Err(current) => retired = current,
+            }
+        }
+    }
+
+    /// How many popped nodes are waiting to be freed
+    pub fn retired(&self) -> usize {
+        let mut count = 0;
+        let mut node = self.retired.load(Ordering::Acquire);
+        while !node.is_null() {
+            count += 1;
+            // Safety: retired nodes are only freed when the stack is dropped
+            node = unsafe { (*node).next_retired };
+        }
+        count
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for Stack<T> {
+    fn drop(&mut self) {
+        // We have the only reference to the stack, so no one else is using the nodes
+        let mut node = self.head.load(Ordering::Relaxed);
+        while !node.is_null() {
+            let mut boxed = unsafe { Box::from_raw(node) };
+            unsafe { ManuallyDrop::drop(&mut boxed.value) };
+            node = boxed.next;
+        }
+
+        // The values of the retired nodes have already been taken
+        let mut node = self.retired.load(Ordering::Relaxed);
+        while !node.is_null() {
+            let boxed = unsafe { Box::from_raw(node) };
+            node = boxed.next_retired;
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use std::{sync::Arc, thread};
+
+use part_22::Stack;
+
+fn main() {
+    let args = common::cli::Cli::new("Pushes onto a lock-free stack from several threads")
+        .threads(4)
+        .parse();
+    let stack = Arc::new(Stack::new());
+    let handles: Vec<_> = (0..args.threads())
+        .map(|thread| {
+            let stack = stack.clone();
+            thread::spawn(move || {
+                for i in 0..5 {
+                    stack.push(thread * 100 + i);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().expect("Couldn't join thread");
+    }
+
+    while let Some(value) = stack.pop() {
+        print!("{value} ");
+    }
+    println!();
+    println!("{} nodes are waiting to be freed", stack.retired());
+}
+
+#[test]
+fn pops_in_reverse_order() {
+    let stack = Stack::new();
+    assert_eq!(stack.pop(), None);
+    for i in 0..5 {
+        stack.push(i);
+    }
+    for i in (0..5).rev() {
+        assert_eq!(stack.pop(), Some(i));
+    }
+    assert_eq!(stack.pop(), None);
+}
+
+#[test]
+fn concurrent_pushes_and_pops_lose_nothing() {
+    use std::collections::HashSet;
+
+    const THREADS: usize = 4;
+    let per_thread = common::sanitize::workload(10_000);
+
+    let stack = Arc::new(Stack::new());
+    let pushers: Vec<_> = (0..THREADS)
+        .map(|thread| {
+            let stack = stack.clone();
+            thread::spawn(move || {
+                for i in 0..per_thread {
+                    stack.push(thread * per_thread + i);
+                }
+            })
+        })
+        .collect();
+    // Pop while the others are still pushing
+    let poppers: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let stack = stack.clone();
+            thread::spawn(move || {
+                let mut popped = Vec::new();
+                for _ in 0..per_thread {
+                    popped.extend(stack.pop());
+                }
+                popped
+            })
+        })
+        .collect();
+    for pusher in pushers {
+        pusher.join().unwrap();
+    }
+
+    let mut popped: Vec<_> = poppers
+        .into_iter()
+        .flat_map(|popper| popper.join().unwrap())
+        .collect();
+    while let Some(value) = stack.pop() {
+        popped.push(value);
+    }
+
+    let unique: HashSet<_> = popped.iter().copied().collect();
+    assert_eq!(unique.len(), popped.len(), "A value was popped twice");
+    assert_eq!(unique, (0..THREADS * per_thread).collect());
+}
+
+/// Increments a counter when dropped
+#[cfg(test)]
+struct DropCounter(Arc<std::sync::atomic::AtomicUsize>);
+
+#[cfg(test)]
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn values_are_dropped_exactly_once() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let drops = Arc::new(AtomicUsize::new(0));
+    let stack = Stack::new();
+    for _ in 0..10 {
+        stack.push(DropCounter(drops.clone()));
+    }
+
+    for _ in 0..4 {
+        drop(stack.pop());
+    }
+    assert_eq!(drops.load(Ordering::SeqCst), 4);
+
+    // The remaining values are dropped with the stack, the popped ones aren't dropped again
+    drop(stack);
+    assert_eq!(drops.load(Ordering::SeqCst), 10);
+}
+
+/// This is the price of being lock-free without a memory reclamation scheme:
+/// a popped node may still be read by another thread, so we don't know when it's safe to free it.
+/// Instead every node lives as long as the stack, so a stack that's used for a long time keeps growing.
+/// Freeing safely needs something like hazard pointers or epochs, as in the crossbeam-epoch crate.
+#[test]
+fn popped_nodes_are_kept_until_the_stack_is_dropped() {
+    let stack = Stack::new();
+    for i in 0..100 {
+        stack.push(i);
+        stack.pop();
+    }
+
+    assert_eq!(stack.pop(), None);
+    assert_eq!(stack.retired(), 100);
+}
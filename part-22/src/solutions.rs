@@ -0,0 +1,142 @@
+//! Part 22, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{mem::ManuallyDrop, ptr};
+
+// Under `--cfg loom` the atomics are swapped for loom's, which lets loom
+// explore every way the threads in a test can interleave.
+#[cfg(loom)]
+use loom::sync::atomic::{AtomicPtr, Ordering};
+#[cfg(not(loom))]
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+use super::Node;
+
+/// A lock-free stack, also known as a Treiber stack.
+/// The stack is a linked list, and pushing and popping swaps out its head with a compare-and-swap.
+pub struct Stack<T> {
+    head: AtomicPtr<Node<T>>,
+    /// Nodes that have been popped, but can't be freed yet
+    retired: AtomicPtr<Node<T>>,
+}
+
+// Safety: values are moved into the stack by one thread, and out of it by another
+unsafe impl<T: Send> Send for Stack<T> {}
+unsafe impl<T: Send> Sync for Stack<T> {}
+
+impl<T> Stack<T> {
+    pub fn new() -> Self {
+        Self {
+            head: AtomicPtr::new(ptr::null_mut()),
+            retired: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Puts `value` on top of the stack
+    pub fn push(&self, value: T) {
+        let node = Box::into_raw(Box::new(Node {
+            value: ManuallyDrop::new(value),
+            next: ptr::null_mut(),
+            next_retired: ptr::null_mut(),
+        }));
+
+        let mut head = self.head.load(Ordering::Relaxed);
+        loop {
+            // Safety: no one else can see the node until it's pushed
+            unsafe { (*node).next = head };
+            // Release, so whoever pops the node sees its value
+            match self
+                .head
+                .compare_exchange_weak(head, node, Ordering::Release, Ordering::Relaxed)
+            {
+                Ok(_) => return,
+                // Someone else changed the head, try again on top of theirs
+                Err(current) => head = current,
+            }
+        }
+    }
+
+    /// Takes the value on top of the stack, if there is one.
+    /// Use `retire` for the popped node.
+    pub fn pop(&self) -> Option<T> {
+        let mut head = self.head.load(Ordering::Acquire);
+        loop {
+            if head.is_null() {
+                return None;
+            }
+            // Safety: nodes are never freed while the stack is alive,
+            // and `next` doesn't change after the node is pushed
+            let next = unsafe { (*head).next };
+            match self
+                .head
+                .compare_exchange_weak(head, next, Ordering::Acquire, Ordering::Acquire)
+            {
+                Ok(_) => {
+                    // Safety: we popped the node, so nobody else takes its value
+                    let value = unsafe { ManuallyDrop::take(&mut (*head).value) };
+                    self.retire(head);
+                    return Some(value);
+                }
+                Err(current) => head = current,
+            }
+        }
+    }
+
+    /// Keeps a popped node until the stack is dropped.
+    ///
+    /// Freeing it right away isn't safe: another thread may have read the same head
+    /// right before we popped it, and be just about to read its `next`.
+    /// Its compare-and-swap would fail, but by then it has already read freed memory.
+    fn retire(&self, node: *mut Node<T>) {
+        let mut retired = self.retired.load(Ordering::Relaxed);
+        loop {
+            // Safety: the node was popped by us, so we're the only ones writing to it
+            unsafe { (*node).next_retired = retired };
+            match self.retired.compare_exchange_weak(
+                retired,
+                node,
+                Ordering::Release,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return,
+                Err(current) => retired = current,
+            }
+        }
+    }
+
+    /// How many popped nodes are waiting to be freed
+    pub fn retired(&self) -> usize {
+        let mut count = 0;
+        let mut node = self.retired.load(Ordering::Acquire);
+        while !node.is_null() {
+            count += 1;
+            // Safety: retired nodes are only freed when the stack is dropped
+            node = unsafe { (*node).next_retired };
+        }
+        count
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for Stack<T> {
+    fn drop(&mut self) {
+        // We have the only reference to the stack, so no one else is using the nodes
+        let mut node = self.head.load(Ordering::Relaxed);
+        while !node.is_null() {
+            let mut boxed = unsafe { Box::from_raw(node) };
+            unsafe { ManuallyDrop::drop(&mut boxed.value) };
+            node = boxed.next;
+        }
+
+        // The values of the retired nodes have already been taken
+        let mut node = self.retired.load(Ordering::Relaxed);
+        while !node.is_null() {
+            let boxed = unsafe { Box::from_raw(node) };
+            node = boxed.next_retired;
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Run these with `RUSTFLAGS="--cfg loom" cargo test --release -p part-22 --test loom`
+#![cfg(loom)]
+
+use loom::{sync::Arc, thread};
+use part_22::Stack;
+
+#[test]
+fn concurrent_pushes_are_all_kept() {
+    loom::model(|| {
+        let stack = Arc::new(Stack::new());
+        let pushers: Vec<_> = [1, 2]
+            .map(|value| {
+                let stack = stack.clone();
+                thread::spawn(move || stack.push(value))
+            })
+            .into_iter()
+            .collect();
+        for pusher in pushers {
+            pusher.join().unwrap();
+        }
+
+        let mut popped = vec![stack.pop().unwrap(), stack.pop().unwrap()];
+        popped.sort();
+        assert_eq!(popped, vec![1, 2]);
+        assert_eq!(stack.pop(), None);
+    });
+}
+
+#[test]
+fn a_value_is_only_popped_once() {
+    loom::model(|| {
+        let stack = Arc::new(Stack::new());
+        stack.push(1);
+        stack.push(2);
+
+        let popper = {
+            let stack = stack.clone();
+            thread::spawn(move || stack.pop())
+        };
+        let mine = stack.pop();
+        let theirs = popper.join().unwrap();
+
+        let mut popped = vec![mine.unwrap(), theirs.unwrap()];
+        popped.sort();
+        assert_eq!(popped, vec![1, 2]);
+    });
+}
+
+#[test]
+fn pop_sees_the_pushed_value() {
+    loom::model(|| {
+        let stack = Arc::new(Stack::new());
+        let pusher = {
+            let stack = stack.clone();
+            thread::spawn(move || stack.push(String::from("hello")))
+        };
+
+        if let Some(value) = stack.pop() {
+            assert_eq!(value, "hello");
+        }
+        pusher.join().unwrap();
+    });
+}
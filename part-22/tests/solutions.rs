@@ -0,0 +1,53 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-22 --features solutions`.
+#![cfg(all(feature = "solutions", not(loom)))]
+
+use std::thread;
+
+use part_22::{solutions, Stack};
+
+#[test]
+fn stacks_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (stack, expected) = (Stack::new(), solutions::Stack::new());
+    for (i, op) in common::datagen::numbers_below(1000, 3, rng.seed())
+        .into_iter()
+        .enumerate()
+    {
+        if op == 0 {
+            assert_eq!(stack.pop(), expected.pop());
+        } else {
+            stack.push(i);
+            expected.push(i);
+        }
+    }
+    while let Some(value) = expected.pop() {
+        assert_eq!(stack.pop(), Some(value));
+    }
+    assert_eq!(stack.pop(), None);
+}
+
+#[test]
+fn keeps_every_value_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let values = common::datagen::numbers(4000, rng.seed());
+    let (stack, expected) = (Stack::new(), solutions::Stack::new());
+    thread::scope(|s| {
+        for values in values.chunks(1000) {
+            let (stack, expected) = (&stack, &expected);
+            s.spawn(move || {
+                for &value in values {
+                    stack.push(value);
+                    expected.push(value);
+                }
+            });
+        }
+    });
+    // The threads' pushes interleave differently every time, but the same values end up on both
+    let drain = |pop: &dyn Fn() -> Option<u64>| {
+        let mut values: Vec<u64> = std::iter::from_fn(pop).collect();
+        values.sort();
+        values
+    };
+    assert_eq!(drain(&|| stack.pop()), drain(&|| expected.pop()));
+}
@@ -0,0 +1,170 @@
+use std::{
+    cell::UnsafeCell,
+    collections::VecDeque,
+    mem::MaybeUninit,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// A bounded queue which any number of threads can push to and pop from
+pub trait Queue<T>: Send + Sync {
+    /// Adds `value` to the back of the queue, or gives it back if the queue is full
+    fn push(&self, value: T) -> Result<(), T>;
+
+    /// Takes the value at the front of the queue, if there is one
+    fn pop(&self) -> Option<T>;
+}
+
+/// The simple way: a `VecDeque` with a lock around it
+pub struct MutexQueue<T> {
+    items: Mutex<VecDeque<T>>,
+    capacity: usize,
+}
+
+impl<T> MutexQueue<T> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            items: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+        }
+    }
+}
+
+impl<T: Send> Queue<T> for MutexQueue<T> {
+    fn push(&self, value: T) -> Result<(), T> {
+        todo!()
+    }
+
+    fn pop(&self) -> Option<T> {
+        todo!()
+    }
+}
+
+/// A place in the ring buffer of an [`ArrayQueue`]
+struct Slot<T> {
+    /// Says whose turn it is to use the slot. For the slot at index `i`, where `i = position % capacity`:
+    /// - `position` means it's empty, waiting for the push at `position`
+    /// - `position + 1` means it's full, waiting for the pop at `position`
+    /// - the pop at `position` sets it to `position + capacity`, making it wait for the next lap's push
+    sequence: AtomicUsize,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+/// A lock-free bounded queue in a ring buffer, where each slot keeps track of whose turn it is
+pub struct ArrayQueue<T> {
+    slots: Box<[Slot<T>]>,
+    /// The position of the next pop
+    head: AtomicUsize,
+    /// The position of the next push
+    tail: AtomicUsize,
+}
+
+// Safety: a value is only accessed by the one thread whose turn it is, see `Slot::sequence`
+unsafe impl<T: Send> Send for ArrayQueue<T> {}
+unsafe impl<T: Send> Sync for ArrayQueue<T> {}
+
+impl<T> ArrayQueue<T> {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "Capacity must be at least 1");
+        let slots = (0..capacity)
+            .map(|i| Slot {
+                sequence: AtomicUsize::new(i),
+                value: UnsafeCell::new(MaybeUninit::uninit()),
+            })
+            .collect();
+
+        Self {
+            slots,
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+}
+
+impl<T: Send> Queue<T> for ArrayQueue<T> {
+    fn push(&self, value: T) -> Result<(), T> {
+        todo!()
+    }
+
+    fn pop(&self) -> Option<T> {
+        todo!()
+    }
+}
+
+impl<T> Drop for ArrayQueue<T> {
+    fn drop(&mut self) {
+        // We have the only reference, so every full slot between head and tail can just be dropped
+        let (head, tail) = (*self.head.get_mut(), *self.tail.get_mut());
+        for position in head..tail {
+            let slot = &mut self.slots[position % self.slots.len()];
+            unsafe { slot.value.get_mut().assume_init_drop() };
+        }
+    }
+}
+
+/// Lets `producers` threads push `per_producer` unique numbers each, while `consumers` threads pop
+/// until everything has been popped. Returns the popped numbers in the order each consumer got them.
+pub fn transfer(
+    queue: Arc<dyn Queue<u64>>,
+    producers: usize,
+    consumers: usize,
+    per_producer: u64,
+) -> Vec<Vec<u64>> {
+    let total = producers as u64 * per_producer;
+    let popped = Arc::new(AtomicUsize::new(0));
+
+    let producers: Vec<_> = (0..producers as u64)
+        .map(|producer| {
+            let queue = queue.clone();
+            thread::spawn(move || {
+                let _producer = common::span::thread("producer");
+                let _worker = common::span::worker(producer as usize);
+                for i in 0..per_producer {
+                    let mut value = producer * per_producer + i;
+                    while let Err(rejected) = queue.push(value) {
+                        value = rejected;
+                        thread::yield_now();
+                    }
+                }
+            })
+        })
+        .collect();
+    let consumers: Vec<_> = (0..consumers)
+        .map(|consumer| {
+            let (queue, popped) = (queue.clone(), popped.clone());
+            thread::spawn(move || {
+                let _consumer = common::span::thread("consumer");
+                let _worker = common::span::worker(consumer);
+                let mut received = Vec::new();
+                while (popped.load(Ordering::SeqCst) as u64) < total {
+                    match queue.pop() {
+                        Some(value) => {
+                            popped.fetch_add(1, Ordering::SeqCst);
+                            received.push(value);
+                        }
+                        None => thread::yield_now(),
+                    }
+                }
+                received
+            })
+        })
+        .collect();
+
+    for producer in producers {
+        producer.join().expect("Couldn't join producer");
+    }
+    consumers
+        .into_iter()
+        .map(|consumer| consumer.join().expect("Couldn't join consumer"))
+        .collect()
+}
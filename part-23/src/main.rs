@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use common::{cli::Cli, timed};
+use part_23::{transfer, ArrayQueue, MutexQueue};
+
+const CAPACITY: usize = 64;
+
+fn main() {
+    common::init_tracing();
+    let args = Cli::new("Transfers items through a mutex queue and a lock-free array queue")
+        .items(400_000)
+        .parse();
+    for (producers, consumers) in [(1, 1), (1, 4), (4, 1), (4, 4), (8, 8)] {
+        let per_producer = (args.items() / producers) as u64;
+        println!("{producers} producers, {consumers} consumers:");
+        timed("  MutexQueue", || {
+            transfer(
+                Arc::new(MutexQueue::new(CAPACITY)),
+                producers,
+                consumers,
+                per_producer,
+            )
+        });
+        timed("  ArrayQueue", || {
+            transfer(
+                Arc::new(ArrayQueue::new(CAPACITY)),
+                producers,
+                consumers,
+                per_producer,
+            )
+        });
+    }
+}
+
+#[cfg(test)]
+fn both(capacity: usize) -> [(&'static str, Arc<dyn part_23::Queue<u64>>); 2] {
+    [
+        ("MutexQueue", Arc::new(MutexQueue::new(capacity))),
+        ("ArrayQueue", Arc::new(ArrayQueue::new(capacity))),
+    ]
+}
+
+#[test]
+fn first_in_first_out() {
+    for (name, queue) in both(8) {
+        assert_eq!(queue.pop(), None, "{name}");
+        for i in 0..5 {
+            queue.push(i).unwrap();
+        }
+        for i in 0..5 {
+            assert_eq!(queue.pop(), Some(i), "{name}");
+        }
+        assert_eq!(queue.pop(), None, "{name}");
+    }
+}
+
+#[test]
+fn rejects_when_full() {
+    for (name, queue) in both(2) {
+        queue.push(1).unwrap();
+        queue.push(2).unwrap();
+        assert_eq!(queue.push(3), Err(3), "{name}");
+
+        assert_eq!(queue.pop(), Some(1), "{name}");
+        queue.push(3).unwrap();
+        assert_eq!(queue.pop(), Some(2), "{name}");
+        assert_eq!(queue.pop(), Some(3), "{name}");
+    }
+}
+
+#[test]
+fn wraps_around_many_times() {
+    for (name, queue) in both(3) {
+        for i in 0..100 {
+            queue.push(i).unwrap();
+            queue.push(i + 1000).unwrap();
+            assert_eq!(queue.pop(), Some(i), "{name}");
+            assert_eq!(queue.pop(), Some(i + 1000), "{name}");
+        }
+    }
+}
+
+#[test]
+fn drops_remaining_values() {
+    use part_23::Queue;
+
+    let value = Arc::new(());
+    let queue = ArrayQueue::new(4);
+    queue.push(value.clone()).unwrap();
+    queue.push(value.clone()).unwrap();
+    queue.push(value.clone()).unwrap();
+    drop(queue.pop());
+    assert_eq!(Arc::strong_count(&value), 3);
+
+    drop(queue);
+    assert_eq!(Arc::strong_count(&value), 1);
+}
+
+#[test]
+fn nothing_lost_or_duplicated_under_stress() {
+    let per_producer = common::sanitize::workload(20_000) as u64;
+
+    for (producers, consumers) in [(1, 1), (1, 4), (4, 1), (4, 4)] {
+        for (name, queue) in both(4) {
+            let received = transfer(queue, producers, consumers, per_producer);
+
+            let mut all: Vec<u64> = received.iter().flatten().copied().collect();
+            all.sort();
+            let expected: Vec<u64> = (0..producers as u64 * per_producer).collect();
+            assert!(
+                all == expected,
+                "{name} with {producers} producers and {consumers} consumers lost or duplicated items"
+            );
+
+            // Each producer's numbers are pushed in order, so every consumer sees them in order too
+            for values in received {
+                let mut last = vec![None; producers];
+                for value in values {
+                    let producer = (value / per_producer) as usize;
+                    assert!(last[producer] < Some(value), "{name} reordered items");
+                    last[producer] = Some(value);
+                }
+            }
+        }
+    }
+}
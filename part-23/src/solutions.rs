@@ -0,0 +1,160 @@
+//! Part 23, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    cell::UnsafeCell,
+    collections::VecDeque,
+    mem::MaybeUninit,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+use super::{Queue, Slot};
+
+/// The simple way: a `VecDeque` with a lock around it
+pub struct MutexQueue<T> {
+    items: Mutex<VecDeque<T>>,
+    capacity: usize,
+}
+
+impl<T> MutexQueue<T> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            items: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+        }
+    }
+}
+
+impl<T: Send> Queue<T> for MutexQueue<T> {
+    fn push(&self, value: T) -> Result<(), T> {
+        let mut items = self.items.lock().unwrap();
+        if items.len() == self.capacity {
+            return Err(value);
+        }
+        items.push_back(value);
+        Ok(())
+    }
+
+    fn pop(&self) -> Option<T> {
+        self.items.lock().unwrap().pop_front()
+    }
+}
+
+/// A lock-free bounded queue in a ring buffer, where each slot keeps track of whose turn it is
+pub struct ArrayQueue<T> {
+    slots: Box<[Slot<T>]>,
+    /// The position of the next pop
+    head: AtomicUsize,
+    /// The position of the next push
+    tail: AtomicUsize,
+}
+
+// Safety: a value is only accessed by the one thread whose turn it is, see `Slot::sequence`
+unsafe impl<T: Send> Send for ArrayQueue<T> {}
+unsafe impl<T: Send> Sync for ArrayQueue<T> {}
+
+impl<T> ArrayQueue<T> {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "Capacity must be at least 1");
+        let slots = (0..capacity)
+            .map(|i| Slot {
+                sequence: AtomicUsize::new(i),
+                value: UnsafeCell::new(MaybeUninit::uninit()),
+            })
+            .collect();
+
+        Self {
+            slots,
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+}
+
+impl<T: Send> Queue<T> for ArrayQueue<T> {
+    fn push(&self, value: T) -> Result<(), T> {
+        let mut position = self.tail.load(Ordering::Relaxed);
+        loop {
+            let slot = &self.slots[position % self.slots.len()];
+            // Acquire, so we don't overwrite the value before the last pop has read it
+            let sequence = slot.sequence.load(Ordering::Acquire);
+
+            if sequence == position {
+                // The slot is empty, try to claim it by moving the tail past it
+                match self.tail.compare_exchange_weak(
+                    position,
+                    position + 1,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        // Safety: we claimed the slot, so it's our turn
+                        unsafe { (*slot.value.get()).write(value) };
+                        // Release, so the pop sees the value
+                        slot.sequence.store(position + 1, Ordering::Release);
+                        return Ok(());
+                    }
+                    Err(current) => position = current,
+                }
+            } else if sequence < position {
+                // The slot still holds the value from the last lap, so the queue is full
+                return Err(value);
+            } else {
+                // Someone else pushed here already, catch up with the tail
+                position = self.tail.load(Ordering::Relaxed);
+            }
+        }
+    }
+
+    fn pop(&self) -> Option<T> {
+        let mut position = self.head.load(Ordering::Relaxed);
+        loop {
+            let slot = &self.slots[position % self.slots.len()];
+            // Acquire, so we see the value written by the push
+            let sequence = slot.sequence.load(Ordering::Acquire);
+
+            if sequence == position + 1 {
+                // The slot is full, try to claim it by moving the head past it
+                match self.head.compare_exchange_weak(
+                    position,
+                    position + 1,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        // Safety: we claimed the slot, so it's our turn
+                        let value = unsafe { (*slot.value.get()).assume_init_read() };
+                        // Release, so the next lap's push doesn't overwrite the value before we've read it
+                        slot.sequence
+                            .store(position + self.slots.len(), Ordering::Release);
+                        return Some(value);
+                    }
+                    Err(current) => position = current,
+                }
+            } else if sequence < position + 1 {
+                // The push for this position hasn't happened yet, so the queue is empty
+                return None;
+            } else {
+                // Someone else popped here already, catch up with the head
+                position = self.head.load(Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+impl<T> Drop for ArrayQueue<T> {
+    fn drop(&mut self) {
+        // We have the only reference, so every full slot between head and tail can just be dropped
+        let (head, tail) = (*self.head.get_mut(), *self.tail.get_mut());
+        for position in head..tail {
+            let slot = &mut self.slots[position % self.slots.len()];
+            unsafe { slot.value.get_mut().assume_init_drop() };
+        }
+    }
+}
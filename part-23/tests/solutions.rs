@@ -0,0 +1,72 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-23 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::sync::Arc;
+
+use part_23::{solutions, transfer, ArrayQueue, MutexQueue, Queue};
+
+/// Whether each push worked, and what each pop got, for the same pushes and pops as every other queue
+/// with the same seed
+fn outcomes(
+    seed: u64,
+    push: impl Fn(u64) -> Result<(), u64>,
+    pop: impl Fn() -> Option<u64>,
+) -> Vec<Option<u64>> {
+    common::datagen::numbers_below(1000, 3, seed)
+        .into_iter()
+        .enumerate()
+        .map(|(i, op)| match op {
+            0 => pop(),
+            _ => push(i as u64).err(),
+        })
+        .collect()
+}
+
+#[test]
+fn queues_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let seed = rng.seed();
+    let (queue, expected) = (MutexQueue::new(8), solutions::MutexQueue::new(8));
+    assert_eq!(
+        outcomes(seed, |value| queue.push(value), || queue.pop()),
+        outcomes(
+            seed,
+            |value| Queue::push(&expected, value),
+            || Queue::pop(&expected)
+        )
+    );
+
+    let (queue, expected) = (ArrayQueue::new(8), solutions::ArrayQueue::new(8));
+    assert_eq!(
+        outcomes(seed, |value| queue.push(value), || queue.pop()),
+        outcomes(
+            seed,
+            |value| Queue::push(&expected, value),
+            || Queue::pop(&expected)
+        )
+    );
+}
+
+#[test]
+fn transfers_like_the_solution() {
+    let sorted = |received: Vec<Vec<u64>>| {
+        let mut values: Vec<u64> = received.into_iter().flatten().collect();
+        values.sort();
+        values
+    };
+    let expected = sorted(transfer(
+        Arc::new(solutions::ArrayQueue::new(16)),
+        3,
+        2,
+        1000,
+    ));
+    assert_eq!(
+        sorted(transfer(Arc::new(MutexQueue::new(16)), 3, 2, 1000)),
+        expected
+    );
+    assert_eq!(
+        sorted(transfer(Arc::new(ArrayQueue::new(16)), 3, 2, 1000)),
+        expected
+    );
+}
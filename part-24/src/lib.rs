@@ -0,0 +1,101 @@
+use std::{
+    sync::{
+        mpsc::{self, Receiver, SendError, Sender},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Something which owns its state, and only changes it when handling a message.
+/// Since nobody else can touch the state, it never needs a lock.
+pub trait Actor: Send + 'static {
+    type Message: Send + 'static;
+
+    fn handle(&mut self, message: Self::Message);
+}
+
+/// The address of an actor, which anyone can use to send it messages.
+/// The actor stops once every address to it has been dropped.
+pub struct Addr<M> {
+    mailbox: Sender<M>,
+}
+
+// Derive would require `M: Clone`, which isn't needed to clone a `Sender<M>`
+impl<M> Clone for Addr<M> {
+    fn clone(&self) -> Self {
+        Self {
+            mailbox: self.mailbox.clone(),
+        }
+    }
+}
+
+impl<M> Addr<M> {
+    /// Puts `message` in the actor's mailbox, or gives it back if the actor has stopped
+    pub fn send(&self, message: M) -> Result<(), SendError<M>> {
+        self.mailbox.send(message)
+    }
+
+    /// Sends the message made by `make`, which should include the sender it's given,
+    /// and waits for the actor to reply on it.
+    /// Returns `None` if the actor stopped without replying.
+    pub fn ask<R>(&self, make: impl FnOnce(Sender<R>) -> M) -> Option<R> {
+        todo!()
+    }
+}
+
+/// Runs `actor` on its own thread, handling every message sent to the returned address.
+/// The thread returns the actor when every address has been dropped.
+pub fn spawn<A: Actor>(actor: A) -> (Addr<A::Message>, JoinHandle<A>) {
+    todo!()
+}
+
+#[derive(Debug, Default)]
+pub struct Counter {
+    pub count: u64,
+}
+
+pub enum CounterMessage {
+    Increment(u64),
+    /// Replies with the current count
+    Get(Sender<u64>),
+    /// Panics, to simulate a bug
+    Crash,
+}
+
+impl Actor for Counter {
+    type Message = CounterMessage;
+
+    fn handle(&mut self, message: CounterMessage) {
+        todo!()
+    }
+}
+
+/// Runs an actor made by `make` like `spawn`, but if it panics,
+/// a new one is made with `make` and keeps handling the messages sent to the same address.
+/// The thread returns how many times the actor was restarted, once every address has been dropped.
+pub fn supervise<A: Actor>(
+    make: impl Fn() -> A + Send + 'static,
+) -> (Addr<A::Message>, JoinHandle<usize>) {
+    todo!()
+}
+
+/// Handles messages from `mailbox` with `actor` until every address has been dropped
+fn run<A: Actor>(mut actor: A, mailbox: &Mutex<Receiver<A::Message>>) -> A {
+    loop {
+        // Only hold the lock while receiving, so a panic in `handle` doesn't poison it
+        let message = mailbox.lock().unwrap().recv();
+        match message {
+            Ok(message) => actor.handle(message),
+            Err(_) => return actor,
+        }
+    }
+}
+
+/// Creates a mailbox which can be shared by every actor handling messages from the same address
+fn mailbox<M>() -> (Addr<M>, Arc<Mutex<Receiver<M>>>) {
+    let (sender, receiver) = mpsc::channel();
+    (Addr { mailbox: sender }, Arc::new(Mutex::new(receiver)))
+}
@@ -0,0 +1,111 @@
+use std::thread;
+
+use part_24::{spawn, supervise, Counter, CounterMessage};
+
+fn main() {
+    let (counter, handle) = spawn(Counter::default());
+    let senders: Vec<_> = (1..=4)
+        .map(|i| {
+            let counter = counter.clone();
+            thread::spawn(move || counter.send(CounterMessage::Increment(i)).unwrap())
+        })
+        .collect();
+    for sender in senders {
+        sender.join().expect("Couldn't join thread");
+    }
+    println!("Counted to {:?}", counter.ask(CounterMessage::Get));
+    drop(counter);
+    println!("The counter stopped at {}", handle.join().unwrap().count);
+
+    let (counter, supervisor) = supervise(Counter::default);
+    counter.send(CounterMessage::Increment(5)).unwrap();
+    counter.send(CounterMessage::Crash).unwrap();
+    counter.send(CounterMessage::Increment(1)).unwrap();
+    println!(
+        "After a crash, the counter is at {:?}",
+        counter.ask(CounterMessage::Get)
+    );
+    drop(counter);
+    println!(
+        "The counter was restarted {} times",
+        supervisor.join().unwrap()
+    );
+}
+
+#[test]
+fn counts_messages() {
+    let (counter, _) = spawn(Counter::default());
+    counter.send(CounterMessage::Increment(2)).unwrap();
+    counter.send(CounterMessage::Increment(3)).unwrap();
+    assert_eq!(counter.ask(CounterMessage::Get), Some(5));
+}
+
+#[test]
+fn stops_when_every_address_is_dropped() {
+    let (counter, handle) = spawn(Counter::default());
+    let other = counter.clone();
+    counter.send(CounterMessage::Increment(1)).unwrap();
+    drop(counter);
+    other.send(CounterMessage::Increment(1)).unwrap();
+    drop(other);
+
+    assert_eq!(handle.join().unwrap().count, 2);
+}
+
+#[test]
+fn handles_messages_from_many_threads() {
+    let (counter, handle) = spawn(Counter::default());
+    let senders: Vec<_> = (0..8)
+        .map(|_| {
+            let counter = counter.clone();
+            thread::spawn(move || {
+                for _ in 0..1000 {
+                    counter.send(CounterMessage::Increment(1)).unwrap();
+                }
+            })
+        })
+        .collect();
+    for sender in senders {
+        sender.join().unwrap();
+    }
+
+    assert_eq!(counter.ask(CounterMessage::Get), Some(8000));
+    drop(counter);
+    assert_eq!(handle.join().unwrap().count, 8000);
+}
+
+#[test]
+fn ask_fails_when_the_actor_is_gone() {
+    let (counter, handle) = spawn(Counter::default());
+    counter.send(CounterMessage::Crash).unwrap();
+    assert!(handle.join().is_err());
+
+    assert_eq!(counter.ask(CounterMessage::Get), None);
+    assert!(counter.send(CounterMessage::Increment(1)).is_err());
+}
+
+#[test]
+fn supervisor_restarts_a_crashed_actor() {
+    let (counter, supervisor) = supervise(Counter::default);
+    counter.send(CounterMessage::Increment(5)).unwrap();
+    assert_eq!(counter.ask(CounterMessage::Get), Some(5));
+
+    // The new actor starts from scratch, but the address keeps working
+    counter.send(CounterMessage::Crash).unwrap();
+    counter.send(CounterMessage::Increment(1)).unwrap();
+    assert_eq!(counter.ask(CounterMessage::Get), Some(1));
+
+    counter.send(CounterMessage::Crash).unwrap();
+    counter.send(CounterMessage::Crash).unwrap();
+    assert_eq!(counter.ask(CounterMessage::Get), Some(0));
+
+    drop(counter);
+    assert_eq!(supervisor.join().unwrap(), 3);
+}
+
+#[test]
+fn supervisor_stops_when_every_address_is_dropped() {
+    let (counter, supervisor) = supervise(Counter::default);
+    drop(counter);
+    assert_eq!(supervisor.join().unwrap(), 0);
+}
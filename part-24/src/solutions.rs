@@ -0,0 +1,115 @@
+//! Part 24, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    sync::{
+        mpsc::{self, Receiver, SendError, Sender},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+use super::{Actor, CounterMessage};
+
+/// The address of an actor, which anyone can use to send it messages.
+/// The actor stops once every address to it has been dropped.
+pub struct Addr<M> {
+    mailbox: Sender<M>,
+}
+
+// Derive would require `M: Clone`, which isn't needed to clone a `Sender<M>`
+impl<M> Clone for Addr<M> {
+    fn clone(&self) -> Self {
+        Self {
+            mailbox: self.mailbox.clone(),
+        }
+    }
+}
+
+impl<M> Addr<M> {
+    /// Puts `message` in the actor's mailbox, or gives it back if the actor has stopped
+    pub fn send(&self, message: M) -> Result<(), SendError<M>> {
+        self.mailbox.send(message)
+    }
+
+    /// Sends the message made by `make`, which should include the sender it's given,
+    /// and waits for the actor to reply on it.
+    /// Returns `None` if the actor stopped without replying.
+    pub fn ask<R>(&self, make: impl FnOnce(Sender<R>) -> M) -> Option<R> {
+        let (reply, response) = mpsc::channel();
+        self.send(make(reply)).ok()?;
+        // Fails if the actor drops the reply sender without using it, e.g. by stopping or panicking
+        response.recv().ok()
+    }
+}
+
+/// Runs `actor` on its own thread, handling every message sent to the returned address.
+/// The thread returns the actor when every address has been dropped.
+pub fn spawn<A: Actor>(actor: A) -> (Addr<A::Message>, JoinHandle<A>) {
+    let (addr, mailbox) = mailbox();
+    let handle = thread::spawn(move || run(actor, &mailbox));
+    (addr, handle)
+}
+
+#[derive(Debug, Default)]
+pub struct Counter {
+    pub count: u64,
+}
+
+impl Actor for Counter {
+    type Message = CounterMessage;
+
+    fn handle(&mut self, message: CounterMessage) {
+        match message {
+            CounterMessage::Increment(amount) => self.count += amount,
+            CounterMessage::Get(reply) => {
+                // The asker may have given up, which is their problem
+                let _ = reply.send(self.count);
+            }
+            CounterMessage::Crash => panic!("The counter crashed at {}", self.count),
+        }
+    }
+}
+
+/// Runs an actor made by `make` like `spawn`, but if it panics,
+/// a new one is made with `make` and keeps handling the messages sent to the same address.
+/// The thread returns how many times the actor was restarted, once every address has been dropped.
+pub fn supervise<A: Actor>(
+    make: impl Fn() -> A + Send + 'static,
+) -> (Addr<A::Message>, JoinHandle<usize>) {
+    let (addr, mailbox) = mailbox();
+    let supervisor = thread::spawn(move || {
+        let mut restarts = 0;
+        loop {
+            let child = {
+                let (actor, mailbox) = (make(), mailbox.clone());
+                thread::spawn(move || {
+                    run(actor, &mailbox);
+                })
+            };
+            match child.join() {
+                // Every address was dropped
+                Ok(()) => return restarts,
+                Err(_) => restarts += 1,
+            }
+        }
+    });
+    (addr, supervisor)
+}
+
+/// Handles messages from `mailbox` with `actor` until every address has been dropped
+fn run<A: Actor>(mut actor: A, mailbox: &Mutex<Receiver<A::Message>>) -> A {
+    loop {
+        // Only hold the lock while receiving, so a panic in `handle` doesn't poison it
+        let message = mailbox.lock().unwrap().recv();
+        match message {
+            Ok(message) => actor.handle(message),
+            Err(_) => return actor,
+        }
+    }
+}
+
+/// Creates a mailbox which can be shared by every actor handling messages from the same address
+fn mailbox<M>() -> (Addr<M>, Arc<Mutex<Receiver<M>>>) {
+    let (sender, receiver) = mpsc::channel();
+    (Addr { mailbox: sender }, Arc::new(Mutex::new(receiver)))
+}
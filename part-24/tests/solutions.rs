@@ -0,0 +1,58 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-24 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_24::{solutions, spawn, supervise, Counter, CounterMessage};
+
+/// What each counter is sent: increments, with a look at the count, or a crash, now and then
+fn ops(seed: u64) -> Vec<u64> {
+    common::datagen::numbers_below(500, 100, seed)
+}
+
+#[test]
+fn counts_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (addr, handle) = spawn(Counter::default());
+    let (expected_addr, expected_handle) = solutions::spawn(solutions::Counter::default());
+    for op in ops(rng.seed()) {
+        if op % 10 == 0 {
+            assert_eq!(
+                addr.ask(CounterMessage::Get),
+                expected_addr.ask(CounterMessage::Get)
+            );
+        } else {
+            addr.send(CounterMessage::Increment(op)).unwrap();
+            expected_addr.send(CounterMessage::Increment(op)).unwrap();
+        }
+    }
+    drop((addr, expected_addr));
+    assert_eq!(
+        handle.join().unwrap().count,
+        expected_handle.join().unwrap().count
+    );
+}
+
+#[test]
+fn restarts_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (addr, handle) = supervise(Counter::default);
+    let (expected_addr, expected_handle) = solutions::supervise(solutions::Counter::default);
+    for op in ops(rng.seed()) {
+        match op % 25 {
+            0 => {
+                addr.send(CounterMessage::Crash).unwrap();
+                expected_addr.send(CounterMessage::Crash).unwrap();
+            }
+            1..=4 => assert_eq!(
+                addr.ask(CounterMessage::Get),
+                expected_addr.ask(CounterMessage::Get)
+            ),
+            _ => {
+                addr.send(CounterMessage::Increment(op)).unwrap();
+                expected_addr.send(CounterMessage::Increment(op)).unwrap();
+            }
+        }
+    }
+    drop((addr, expected_addr));
+    assert_eq!(handle.join().unwrap(), expected_handle.join().unwrap());
+}
@@ -0,0 +1,98 @@
+use std::{
+    future::Future,
+    pin::{pin, Pin},
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// The state shared between a `Delay` and its timer thread
+#[derive(Default)]
+struct Shared {
+    completed: bool,
+    /// The waker from the latest poll, which the timer thread uses when the time is up
+    waker: Option<Waker>,
+}
+
+/// A future which completes once `duration` has passed.
+/// The waiting is done by a timer thread, started on the first poll.
+pub struct Delay {
+    deadline: Instant,
+    shared: Arc<Mutex<Shared>>,
+    timer_started: bool,
+}
+
+impl Delay {
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            deadline: Instant::now() + duration,
+            shared: Default::default(),
+            timer_started: false,
+        }
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        todo!()
+    }
+}
+
+/// Runs two futures at the same time, completing with both their outputs once both have completed
+pub struct Join2<A: Future, B: Future> {
+    a: Option<Pin<Box<A>>>,
+    a_output: Option<A::Output>,
+    b: Option<Pin<Box<B>>>,
+    b_output: Option<B::Output>,
+}
+
+// The futures are boxed, and the outputs are never pinned, so moving a `Join2` is fine
+impl<A: Future, B: Future> Unpin for Join2<A, B> {}
+
+pub fn join2<A: Future, B: Future>(a: A, b: B) -> Join2<A, B> {
+    Join2 {
+        a: Some(Box::pin(a)),
+        a_output: None,
+        b: Some(Box::pin(b)),
+        b_output: None,
+    }
+}
+
+impl<A: Future, B: Future> Future for Join2<A, B> {
+    type Output = (A::Output, B::Output);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        todo!()
+    }
+}
+
+/// Wakes a thread that's waiting in `block_on`
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// A minimal executor: runs `future` to completion on the current thread,
+/// sleeping until it's woken whenever it isn't ready
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            // Parking may wake up spuriously, which only means an extra poll
+            Poll::Pending => thread::park(),
+        }
+    }
+}
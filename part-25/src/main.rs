@@ -0,0 +1,193 @@
+use std::time::Duration;
+
+use common::timed;
+use part_25::{block_on, join2, Delay};
+
+fn main() {
+    timed("One delay", || {
+        block_on(Delay::new(Duration::from_millis(500)))
+    });
+
+    let (a, b) = timed("Two delays, one after the other", || {
+        block_on(async {
+            Delay::new(Duration::from_millis(500)).await;
+            Delay::new(Duration::from_millis(500)).await;
+            ("a", "b")
+        })
+    });
+    println!("{a}, {b}");
+
+    let (a, b) = timed("Two delays, joined", || {
+        block_on(join2(
+            async {
+                Delay::new(Duration::from_millis(500)).await;
+                "a"
+            },
+            async {
+                Delay::new(Duration::from_millis(500)).await;
+                "b"
+            },
+        ))
+    });
+    println!("{a}, {b}");
+}
+
+#[cfg(test)]
+mod helpers {
+    use std::{
+        future::Future,
+        pin::Pin,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        task::{Context, Poll, Wake},
+    };
+
+    /// Counts how many times it's woken
+    #[derive(Default)]
+    pub struct CountingWaker(pub AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    /// Counts how many times the wrapped future is polled
+    pub struct PollCounter<F> {
+        pub future: F,
+        pub polls: usize,
+    }
+
+    impl<F: Future + Unpin> Future for PollCounter<F> {
+        type Output = (F::Output, usize);
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            self.polls += 1;
+            let polls = self.polls;
+            Pin::new(&mut self.future)
+                .poll(cx)
+                .map(|output| (output, polls))
+        }
+    }
+}
+
+#[test]
+fn delay_is_pending_until_the_time_is_up() {
+    use std::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    let mut delay = pin!(Delay::new(Duration::from_millis(100)));
+    let mut cx = Context::from_waker(Waker::noop());
+    assert_eq!(delay.as_mut().poll(&mut cx), Poll::Pending);
+    assert_eq!(delay.as_mut().poll(&mut cx), Poll::Pending);
+
+    std::thread::sleep(Duration::from_millis(200));
+    assert_eq!(delay.as_mut().poll(&mut cx), Poll::Ready(()));
+}
+
+#[test]
+fn delay_wakes_the_latest_waker() {
+    use helpers::CountingWaker;
+    use std::{
+        future::Future,
+        pin::pin,
+        sync::{atomic::Ordering, Arc},
+        task::{Context, Waker},
+    };
+
+    let first = Arc::new(CountingWaker::default());
+    let second = Arc::new(CountingWaker::default());
+    let mut delay = pin!(Delay::new(Duration::from_millis(100)));
+
+    let _ = delay
+        .as_mut()
+        .poll(&mut Context::from_waker(&Waker::from(first.clone())));
+    // A future may be moved to a different task between polls, so only the latest waker counts
+    let _ = delay
+        .as_mut()
+        .poll(&mut Context::from_waker(&Waker::from(second.clone())));
+    std::thread::sleep(Duration::from_millis(200));
+
+    assert_eq!(first.0.load(Ordering::SeqCst), 0);
+    assert_eq!(second.0.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn delay_takes_as_long_as_it_should() {
+    let (_, elapsed) =
+        common::time_elapsed("delay", || block_on(Delay::new(Duration::from_millis(100))));
+    assert!(elapsed >= Duration::from_millis(100));
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    assert!(elapsed < Duration::from_millis(500));
+}
+
+#[test]
+fn delay_is_only_polled_when_woken() {
+    use helpers::PollCounter;
+
+    let (_, polls) = block_on(PollCounter {
+        future: Delay::new(Duration::from_millis(100)),
+        polls: 0,
+    });
+    // Once to start, and once when woken. A spurious wakeup may add a few more, but not many
+    assert!(polls >= 2);
+    assert!(polls < 5, "Polled {polls} times");
+}
+
+#[test]
+fn zero_delay_is_ready_right_away() {
+    use std::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    let mut delay = pin!(Delay::new(Duration::ZERO));
+    let mut cx = Context::from_waker(Waker::noop());
+    assert_eq!(delay.as_mut().poll(&mut cx), Poll::Ready(()));
+}
+
+#[test]
+fn join2_returns_both_outputs() {
+    let output = block_on(join2(async { 1 }, async { "two" }));
+    assert_eq!(output, (1, "two"));
+}
+
+#[test]
+fn join2_runs_both_at_once() {
+    let ((a, b), elapsed) = common::time_elapsed("join2", || {
+        block_on(join2(
+            async {
+                Delay::new(Duration::from_millis(200)).await;
+                'a'
+            },
+            async {
+                Delay::new(Duration::from_millis(100)).await;
+                Delay::new(Duration::from_millis(100)).await;
+                'b'
+            },
+        ))
+    });
+
+    assert_eq!((a, b), ('a', 'b'));
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    assert!(elapsed < Duration::from_millis(350));
+}
+
+#[test]
+fn join2_can_be_nested() {
+    let output = block_on(join2(
+        join2(Delay::new(Duration::from_millis(50)), async { 1 }),
+        join2(async { 2 }, Delay::new(Duration::from_millis(50))),
+    ));
+    assert_eq!(output, (((), 1), (2, ())));
+}
@@ -0,0 +1,110 @@
+//! Part 25, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    thread,
+    time::{Duration, Instant},
+};
+
+use super::Shared;
+
+/// A future which completes once `duration` has passed.
+/// The waiting is done by a timer thread, started on the first poll.
+pub struct Delay {
+    deadline: Instant,
+    shared: Arc<Mutex<Shared>>,
+    timer_started: bool,
+}
+
+impl Delay {
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            deadline: Instant::now() + duration,
+            shared: Default::default(),
+            timer_started: false,
+        }
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+
+        let mut shared = self.shared.lock().unwrap();
+        if shared.completed {
+            return Poll::Ready(());
+        }
+        // Always replace the waker, since the future may have been moved to another task
+        shared.waker = Some(cx.waker().clone());
+        drop(shared);
+
+        if !self.timer_started {
+            self.timer_started = true;
+            let (deadline, shared) = (self.deadline, self.shared.clone());
+            thread::spawn(move || {
+                thread::sleep(deadline.saturating_duration_since(Instant::now()));
+                let mut shared = shared.lock().unwrap();
+                shared.completed = true;
+                if let Some(waker) = shared.waker.take() {
+                    waker.wake();
+                }
+            });
+        }
+        Poll::Pending
+    }
+}
+
+/// Runs two futures at the same time, completing with both their outputs once both have completed
+pub struct Join2<A: Future, B: Future> {
+    a: Option<Pin<Box<A>>>,
+    a_output: Option<A::Output>,
+    b: Option<Pin<Box<B>>>,
+    b_output: Option<B::Output>,
+}
+
+// The futures are boxed, and the outputs are never pinned, so moving a `Join2` is fine
+impl<A: Future, B: Future> Unpin for Join2<A, B> {}
+
+pub fn join2<A: Future, B: Future>(a: A, b: B) -> Join2<A, B> {
+    Join2 {
+        a: Some(Box::pin(a)),
+        a_output: None,
+        b: Some(Box::pin(b)),
+        b_output: None,
+    }
+}
+
+impl<A: Future, B: Future> Future for Join2<A, B> {
+    type Output = (A::Output, B::Output);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Poll whichever futures haven't completed yet, and keep their outputs when they do
+        if let Some(a) = &mut self.a {
+            if let Poll::Ready(output) = a.as_mut().poll(cx) {
+                self.a_output = Some(output);
+                self.a = None;
+            }
+        }
+        if let Some(b) = &mut self.b {
+            if let Poll::Ready(output) = b.as_mut().poll(cx) {
+                self.b_output = Some(output);
+                self.b = None;
+            }
+        }
+
+        if self.a.is_none() && self.b.is_none() {
+            let a = self.a_output.take().expect("Polled after completion");
+            let b = self.b_output.take().expect("Polled after completion");
+            Poll::Ready((a, b))
+        } else {
+            Poll::Pending
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-25 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::time::{Duration, Instant};
+
+use part_25::{block_on, join2, solutions, Delay};
+
+#[test]
+fn delays_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for millis in common::datagen::numbers_below(5, 30, rng.seed()) {
+        let duration = Duration::from_millis(millis);
+        let start = Instant::now();
+        block_on(Delay::new(duration));
+        block_on(solutions::Delay::new(duration));
+        // Both wait at least the whole duration
+        assert!(start.elapsed() >= 2 * duration);
+    }
+}
+
+#[test]
+fn joins_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let numbers = common::datagen::numbers(20, rng.seed());
+    for pair in numbers.chunks(2) {
+        let (a, b) = (pair[0], pair[1]);
+        let joined = block_on(join2(
+            async move {
+                Delay::new(Duration::from_millis(a % 5)).await;
+                a
+            },
+            async move { b },
+        ));
+        let expected = block_on(solutions::join2(
+            async move {
+                solutions::Delay::new(Duration::from_millis(a % 5)).await;
+                a
+            },
+            async move { b },
+        ));
+        assert_eq!(joined, expected);
+    }
+}
@@ -0,0 +1,174 @@
+use std::time::Duration;
+
+use common::timed;
+use part_25::{join2, Delay};
+use part_26::Executor;
+
+async fn countdown(name: &'static str, from: u64) -> &'static str {
+    for i in (1..=from).rev() {
+        println!("{name}: {i}");
+        Delay::new(Duration::from_millis(100 * i)).await;
+    }
+    name
+}
+
+fn main() {
+    let mut executor = Executor::new();
+    let spawner = executor.spawner();
+    for (name, from) in [("first", 3), ("second", 2), ("third", 4)] {
+        spawner.spawn(async move {
+            countdown(name, from).await;
+            println!("{name} is done");
+        });
+    }
+    timed("Three countdowns", || executor.run());
+
+    let output = executor.block_on(join2(countdown("a", 2), countdown("b", 2)));
+    println!("{output:?}, after {} polls in total", executor.polls());
+}
+
+#[cfg(test)]
+mod helpers {
+    use std::{
+        future::Future,
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    /// Lets the other tasks run before continuing
+    pub struct YieldNow {
+        pub yielded: bool,
+    }
+
+    impl Future for YieldNow {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.yielded {
+                return Poll::Ready(());
+            }
+            self.yielded = true;
+            // Ask to be polled again right away, which puts us at the back of the queue
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    pub async fn yield_now() {
+        YieldNow { yielded: false }.await
+    }
+}
+
+#[test]
+fn runs_an_async_fn() {
+    let mut executor = Executor::new();
+    assert_eq!(executor.block_on(async { 1 + 1 }), 2);
+}
+
+#[test]
+fn runs_until_every_task_is_done() {
+    use std::sync::{Arc, Mutex};
+
+    let mut executor = Executor::new();
+    let done = Arc::new(Mutex::new(Vec::new()));
+    for i in 0..10 {
+        let done = done.clone();
+        executor.spawner().spawn(async move {
+            done.lock().unwrap().push(i);
+        });
+    }
+    executor.run();
+
+    assert_eq!(*done.lock().unwrap(), (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn only_polls_when_woken() {
+    let mut executor = Executor::new();
+    executor.block_on(Delay::new(Duration::from_millis(100)));
+    // Once to start the delay, and once when the timer thread wakes it
+    assert_eq!(executor.polls(), 2);
+}
+
+#[test]
+fn woken_tasks_are_polled_again() {
+    use helpers::yield_now;
+    use std::sync::{Arc, Mutex};
+
+    let mut executor = Executor::new();
+    let log = Arc::new(Mutex::new(Vec::new()));
+    for name in ["a", "b"] {
+        let log = log.clone();
+        executor.spawner().spawn(async move {
+            log.lock().unwrap().push(format!("{name}1"));
+            yield_now().await;
+            log.lock().unwrap().push(format!("{name}2"));
+        });
+    }
+    executor.run();
+
+    assert_eq!(*log.lock().unwrap(), vec!["a1", "b1", "a2", "b2"]);
+    assert_eq!(executor.polls(), 4);
+}
+
+#[test]
+fn runs_tasks_concurrently() {
+    use std::sync::{Arc, Mutex};
+
+    let mut executor = Executor::new();
+    let finished = Arc::new(Mutex::new(Vec::new()));
+    for millis in [300, 100, 200] {
+        let finished = finished.clone();
+        executor.spawner().spawn(async move {
+            Delay::new(Duration::from_millis(millis)).await;
+            finished.lock().unwrap().push(millis);
+        });
+    }
+    let (_, elapsed) = common::time_elapsed("run", || executor.run());
+
+    assert_eq!(*finished.lock().unwrap(), vec![100, 200, 300]);
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    assert!(elapsed < Duration::from_millis(500));
+}
+
+#[test]
+fn tasks_can_spawn_tasks() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    let mut executor = Executor::new();
+    let spawner = executor.spawner();
+    let count = Arc::new(AtomicUsize::new(0));
+    executor.spawner().spawn({
+        let count = count.clone();
+        async move {
+            for _ in 0..3 {
+                let count = count.clone();
+                spawner.spawn(async move {
+                    Delay::new(Duration::from_millis(50)).await;
+                    count.fetch_add(1, Ordering::SeqCst);
+                });
+            }
+        }
+    });
+    executor.run();
+
+    assert_eq!(count.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn runs_the_futures_from_part_25() {
+    let mut executor = Executor::new();
+    let output = executor.block_on(join2(
+        async {
+            Delay::new(Duration::from_millis(50)).await;
+            "delayed"
+        },
+        async { "right away" },
+    ));
+    assert_eq!(output, ("delayed", "right away"));
+}
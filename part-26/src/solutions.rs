@@ -0,0 +1,128 @@
+//! Part 26, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Receiver, Sender},
+        Arc, Mutex,
+    },
+    task::{Context, Wake, Waker},
+};
+
+use super::BoxFuture;
+
+/// A spawned future, along with everything needed to schedule it again when it's woken
+struct Task {
+    /// `None` once the future has completed
+    future: Mutex<Option<BoxFuture>>,
+    /// The executor's run queue
+    queue: Sender<Arc<Task>>,
+}
+
+impl Wake for Task {
+    /// Schedules the task to be polled again
+    fn wake(self: Arc<Self>) {
+        // The executor may be gone, in which case there's no one to poll the task anyway
+        let _ = self.queue.clone().send(self);
+    }
+}
+
+/// Spawns tasks onto an [`Executor`], and can be cloned and moved into tasks to let them spawn more
+#[derive(Clone)]
+pub struct Spawner {
+    queue: Sender<Arc<Task>>,
+    /// How many spawned tasks haven't completed yet, shared with the executor
+    unfinished: Arc<AtomicUsize>,
+}
+
+impl Spawner {
+    /// Adds `future` as a new task, which is polled when the executor runs
+    pub fn spawn(&self, future: impl Future<Output = ()> + Send + 'static) {
+        self.unfinished.fetch_add(1, Ordering::SeqCst);
+        let task = Arc::new(Task {
+            future: Mutex::new(Some(Box::pin(future))),
+            queue: self.queue.clone(),
+        });
+        self.queue
+            .send(task)
+            .expect("The executor has the receiver");
+    }
+}
+
+/// Runs tasks on the current thread, polling a task whenever it has been woken
+pub struct Executor {
+    queue: Receiver<Arc<Task>>,
+    spawner: Spawner,
+    /// How many times a task has been polled
+    polls: usize,
+}
+
+impl Executor {
+    pub fn new() -> Self {
+        let (sender, queue) = mpsc::channel();
+        Self {
+            queue,
+            spawner: Spawner {
+                queue: sender,
+                unfinished: Default::default(),
+            },
+            polls: 0,
+        }
+    }
+
+    pub fn spawner(&self) -> Spawner {
+        self.spawner.clone()
+    }
+
+    pub fn polls(&self) -> usize {
+        self.polls
+    }
+
+    /// Polls tasks as they're woken, until every spawned task has completed
+    pub fn run(&mut self) {
+        while self.spawner.unfinished.load(Ordering::SeqCst) > 0 {
+            // Waits for a task to be woken, e.g. by a timer thread
+            let task = self.queue.recv().expect("The executor has a sender");
+
+            let mut future = task.future.lock().unwrap();
+            // A task may be woken more than once, even after it has completed
+            let Some(running) = future.as_mut() else {
+                continue;
+            };
+
+            let waker = Waker::from(task.clone());
+            let mut cx = Context::from_waker(&waker);
+            self.polls += 1;
+            if running.as_mut().poll(&mut cx).is_ready() {
+                *future = None;
+                self.spawner.unfinished.fetch_sub(1, Ordering::SeqCst);
+            }
+        }
+    }
+
+    /// Runs `future` along with every other task until it completes, and returns its output
+    pub fn block_on<F>(&mut self, future: F) -> F::Output
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let output = Arc::new(Mutex::new(None));
+        self.spawner.spawn({
+            let output = output.clone();
+            async move {
+                *output.lock().unwrap() = Some(future.await);
+            }
+        });
+        self.run();
+
+        let output = output.lock().unwrap().take();
+        output.expect("Every task has completed")
+    }
+}
+
+impl Default for Executor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
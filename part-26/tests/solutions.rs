@@ -0,0 +1,101 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-26 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use part_26::{solutions, Executor};
+
+/// Wakes itself and yields `times` times before completing, like `tokio::task::yield_now`
+struct YieldTimes(u64);
+
+impl Future for YieldTimes {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 == 0 {
+            return Poll::Ready(());
+        }
+        self.0 -= 1;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// How many times each task yields, the same for the same seed
+fn yields(seed: u64) -> Vec<u64> {
+    common::datagen::numbers_below(50, 10, seed)
+}
+
+#[test]
+fn runs_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let seed = rng.seed();
+    let done = Arc::new(Mutex::new(Vec::new()));
+    let mut executor = Executor::new();
+    for (task, times) in yields(seed).into_iter().enumerate() {
+        let done = done.clone();
+        executor.spawner().spawn(async move {
+            YieldTimes(times).await;
+            done.lock().unwrap().push(task);
+        });
+    }
+    executor.run();
+
+    let expected_done = Arc::new(Mutex::new(Vec::new()));
+    let mut expected = solutions::Executor::new();
+    for (task, times) in yields(seed).into_iter().enumerate() {
+        let done = expected_done.clone();
+        expected.spawner().spawn(async move {
+            YieldTimes(times).await;
+            done.lock().unwrap().push(task);
+        });
+    }
+    expected.run();
+
+    assert_eq!(*done.lock().unwrap(), *expected_done.lock().unwrap());
+    assert_eq!(executor.polls(), expected.polls());
+}
+
+#[test]
+fn spawns_from_tasks_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let seed = rng.seed();
+    let mut executor = Executor::new();
+    let spawner = executor.spawner();
+    let sum = executor.block_on(async move {
+        let total = Arc::new(Mutex::new(0));
+        for times in yields(seed) {
+            let total = total.clone();
+            spawner.spawn(async move {
+                YieldTimes(times).await;
+                *total.lock().unwrap() += times;
+            });
+        }
+        YieldTimes(20).await;
+        let sum = *total.lock().unwrap();
+        sum
+    });
+
+    let mut expected = solutions::Executor::new();
+    let spawner = expected.spawner();
+    let expected_sum = expected.block_on(async move {
+        let total = Arc::new(Mutex::new(0));
+        for times in yields(seed) {
+            let total = total.clone();
+            spawner.spawn(async move {
+                YieldTimes(times).await;
+                *total.lock().unwrap() += times;
+            });
+        }
+        YieldTimes(20).await;
+        let sum = *total.lock().unwrap();
+        sum
+    });
+    assert_eq!(sum, expected_sum);
+}
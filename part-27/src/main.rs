@@ -0,0 +1,346 @@
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::{sync::mpsc::Receiver, time::Instant};
+
+#[cfg(all(test, feature = "solutions"))]
+mod solutions;
+
+#[tokio::main]
+async fn main() {
+    let log = Log::default();
+    let winner = race(
+        work("fast", Duration::from_millis(100), log.clone()),
+        work("slow", Duration::from_millis(500), log.clone()),
+    )
+    .await;
+    println!("{winner} won: {:?}", log.lines());
+
+    let result = timeout(
+        Duration::from_millis(200),
+        work("slow", Duration::from_secs(1), log),
+    )
+    .await;
+    println!("Slow work with a timeout: {result:?}");
+
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(8);
+    tokio::spawn(async move {
+        for i in 0.. {
+            tokio::time::sleep(Duration::from_millis(30)).await;
+            if sender.send(i).await.is_err() {
+                break;
+            }
+        }
+    });
+    let received = collect_until(&mut receiver, Instant::now() + Duration::from_millis(200)).await;
+    println!("Received before the deadline: {received:?}");
+
+    let (first_sender, mut first) = tokio::sync::mpsc::channel(1);
+    let (second_sender, mut second) = tokio::sync::mpsc::channel(1);
+    second_sender.send("second").await.unwrap();
+    first_sender.send("first").await.unwrap();
+    while let Some((source, message)) = first_message(&mut first, &mut second).await {
+        println!("Got {message:?} from {source:?}");
+        // Closes the channel once it has delivered its message
+        match source {
+            Source::First => first.close(),
+            Source::Second => second.close(),
+        }
+    }
+}
+
+/// Returned when a future didn't complete in time
+#[derive(Debug, PartialEq, Eq)]
+struct TimedOut;
+
+/// Waits for `future` for at most `duration`
+async fn timeout<F: Future>(duration: Duration, future: F) -> Result<F::Output, TimedOut> {
+    todo!()
+}
+
+/// Which of the channels a message came from
+#[derive(Debug, PartialEq, Eq)]
+enum Source {
+    First,
+    Second,
+}
+
+/// Waits for the first message from either channel.
+/// If one of them is closed, waits for the other. Returns `None` if both are closed.
+async fn first_message<T>(
+    first: &mut Receiver<T>,
+    second: &mut Receiver<T>,
+) -> Option<(Source, T)> {
+    todo!()
+}
+
+/// Runs both futures until one of them completes, and returns what it returned.
+/// The other one is cancelled.
+async fn race<T>(a: impl Future<Output = T>, b: impl Future<Output = T>) -> T {
+    todo!()
+}
+
+/// Receives messages until `deadline`, or until the channel is closed.
+/// No message may be lost, and the deadline must be kept no matter how often messages arrive.
+async fn collect_until<T>(receiver: &mut Receiver<T>, deadline: Instant) -> Vec<T> {
+    todo!()
+}
+
+/// Lines logged by `work`, shared by every clone
+#[derive(Clone, Default)]
+struct Log(Arc<Mutex<Vec<String>>>);
+
+impl Log {
+    fn push(&self, line: String) {
+        self.0.lock().unwrap().push(line);
+    }
+
+    fn lines(&self) -> Vec<String> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+/// Logs when it's dropped, unless it's been defused
+struct CancelGuard {
+    name: &'static str,
+    log: Log,
+    defused: bool,
+}
+
+impl Drop for CancelGuard {
+    fn drop(&mut self) {
+        if !self.defused {
+            self.log.push(format!("{} cancelled", self.name));
+        }
+    }
+}
+
+/// Pretends to work for `duration`, and logs when it starts, finishes or gets cancelled
+async fn work(name: &'static str, duration: Duration, log: Log) -> &'static str {
+    log.push(format!("{name} started"));
+    let mut guard = CancelGuard {
+        name,
+        log: log.clone(),
+        defused: false,
+    };
+
+    // Dropping the future while it waits here drops the guard too
+    tokio::time::sleep(duration).await;
+
+    guard.defused = true;
+    log.push(format!("{name} finished"));
+    name
+}
+
+#[tokio::test(start_paused = true)]
+async fn timeout_lets_fast_futures_finish() {
+    let result = timeout(Duration::from_secs(1), async { 42 }).await;
+    assert_eq!(result, Ok(42));
+}
+
+#[tokio::test(start_paused = true)]
+async fn timeout_gives_up_on_slow_futures() {
+    let log = Log::default();
+    let start = Instant::now();
+    let result = timeout(
+        Duration::from_secs(1),
+        work("slow", Duration::from_secs(60), log.clone()),
+    )
+    .await;
+
+    assert_eq!(result, Err(TimedOut));
+    // The paused clock skips straight to the next timer, so this doesn't actually take a second
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+    assert_eq!(log.lines(), vec!["slow started", "slow cancelled"]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn first_message_comes_from_either_channel() {
+    let (first_sender, mut first) = tokio::sync::mpsc::channel(1);
+    let (second_sender, mut second) = tokio::sync::mpsc::channel(1);
+
+    second_sender.send("hello").await.unwrap();
+    assert_eq!(
+        first_message(&mut first, &mut second).await,
+        Some((Source::Second, "hello"))
+    );
+
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        first_sender.send("world").await.unwrap();
+    });
+    assert_eq!(
+        first_message(&mut first, &mut second).await,
+        Some((Source::First, "world"))
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn first_message_ignores_closed_channels() {
+    let (first_sender, mut first) = tokio::sync::mpsc::channel(1);
+    let (second_sender, mut second) = tokio::sync::mpsc::channel::<i32>(1);
+    drop(second_sender);
+
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        first_sender.send(1).await.unwrap();
+    });
+    assert_eq!(
+        first_message(&mut first, &mut second).await,
+        Some((Source::First, 1))
+    );
+    // Now both are closed
+    assert_eq!(first_message(&mut first, &mut second).await, None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn race_cancels_the_loser() {
+    let log = Log::default();
+    let winner = race(
+        work("slow", Duration::from_secs(2), log.clone()),
+        work("fast", Duration::from_secs(1), log.clone()),
+    )
+    .await;
+
+    assert_eq!(winner, "fast");
+    let lines = log.lines();
+    assert!(lines.contains(&"fast finished".to_string()));
+    assert!(lines.contains(&"slow cancelled".to_string()));
+    assert!(!lines.contains(&"slow finished".to_string()));
+
+    // The loser is gone for good, it doesn't finish in the background
+    tokio::time::sleep(Duration::from_secs(10)).await;
+    assert!(!log.lines().contains(&"slow finished".to_string()));
+}
+
+#[tokio::test(start_paused = true)]
+async fn collect_until_keeps_the_deadline() {
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(8);
+    tokio::spawn(async move {
+        // A message every 100 ms, so a timer restarted for every message would never fire
+        for i in 0.. {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            if sender.send(i).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let start = Instant::now();
+    let received = collect_until(&mut receiver, start + Duration::from_millis(1050)).await;
+
+    assert_eq!(start.elapsed(), Duration::from_millis(1050));
+    assert_eq!(received, (0..10).collect::<Vec<_>>());
+}
+
+#[tokio::test(start_paused = true)]
+async fn collect_until_stops_when_closed() {
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(8);
+    for i in 0..3 {
+        sender.send(i).await.unwrap();
+    }
+    drop(sender);
+
+    let start = Instant::now();
+    let received = collect_until(&mut receiver, start + Duration::from_secs(60)).await;
+
+    assert_eq!(received, vec![0, 1, 2]);
+    assert!(start.elapsed() < Duration::from_secs(60));
+}
+
+#[cfg(feature = "solutions")]
+#[tokio::test(start_paused = true)]
+async fn races_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let durations = common::datagen::numbers_below(20, 1000, rng.seed());
+    for pair in durations.chunks(2) {
+        let (fast, slow) = (
+            Duration::from_millis(pair[0]),
+            Duration::from_millis(pair[1] + 1000),
+        );
+
+        let log = Log::default();
+        let winner = race(work("a", fast, log.clone()), work("b", slow, log.clone())).await;
+        let expected_log = Log::default();
+        let expected = solutions::race(
+            work("a", fast, expected_log.clone()),
+            work("b", slow, expected_log.clone()),
+        )
+        .await;
+        assert_eq!(winner, expected);
+        assert_eq!(log.lines(), expected_log.lines());
+
+        let log = Log::default();
+        let result = timeout(fast, work("slow", slow, log.clone())).await;
+        let expected_log = Log::default();
+        let expected = solutions::timeout(fast, work("slow", slow, expected_log.clone())).await;
+        assert_eq!(result.ok(), expected.ok());
+        assert_eq!(log.lines(), expected_log.lines());
+    }
+}
+
+#[cfg(feature = "solutions")]
+#[tokio::test(start_paused = true)]
+async fn receives_like_the_solution() {
+    /// A channel which gets the numbers `0, 1, ...` every `period`
+    fn ticking(period: Duration) -> Receiver<u64> {
+        let (sender, receiver) = tokio::sync::mpsc::channel(8);
+        tokio::spawn(async move {
+            for i in 0.. {
+                tokio::time::sleep(period).await;
+                if sender.send(i).await.is_err() {
+                    break;
+                }
+            }
+        });
+        receiver
+    }
+
+    for period in common::datagen::numbers_below(5, 50, 28) {
+        let period = Duration::from_millis(period + 1);
+        let deadline = Instant::now() + Duration::from_millis(500);
+        let received = collect_until(&mut ticking(period), deadline).await;
+        let expected = solutions::collect_until(&mut ticking(period), deadline).await;
+        assert_eq!(received, expected);
+    }
+
+    let messages = common::datagen::numbers(10, 27);
+    for closed_first in [false, true] {
+        let (first_sender, mut first) = tokio::sync::mpsc::channel(16);
+        let (second_sender, mut second) = tokio::sync::mpsc::channel(16);
+        let (expected_first_sender, mut expected_first) = tokio::sync::mpsc::channel(16);
+        let (expected_second_sender, mut expected_second) = tokio::sync::mpsc::channel(16);
+        for &message in &messages {
+            second_sender.send(message).await.unwrap();
+            expected_second_sender.send(message).await.unwrap();
+        }
+        if closed_first {
+            first.close();
+            expected_first.close();
+        }
+        drop((first_sender, second_sender));
+        drop((expected_first_sender, expected_second_sender));
+
+        loop {
+            let received = first_message(&mut first, &mut second).await;
+            let expected =
+                solutions::first_message(&mut expected_first, &mut expected_second).await;
+            let source = |source: &Source| *source == Source::First;
+            let expected_source = |source: &Source| *source == Source::First;
+            assert_eq!(
+                received
+                    .as_ref()
+                    .map(|(from, message)| (source(from), *message)),
+                expected
+                    .as_ref()
+                    .map(|(from, message)| (expected_source(from), *message))
+            );
+            if received.is_none() {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Part 27, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{future::Future, time::Duration};
+
+use tokio::{sync::mpsc::Receiver, time::Instant};
+
+use super::{Source, TimedOut};
+
+/// Waits for `future` for at most `duration`
+pub async fn timeout<F: Future>(duration: Duration, future: F) -> Result<F::Output, TimedOut> {
+    tokio::select! {
+        output = future => Ok(output),
+        _ = tokio::time::sleep(duration) => Err(TimedOut),
+    }
+}
+
+/// Waits for the first message from either channel.
+/// If one of them is closed, waits for the other. Returns `None` if both are closed.
+pub async fn first_message<T>(
+    first: &mut Receiver<T>,
+    second: &mut Receiver<T>,
+) -> Option<(Source, T)> {
+    tokio::select! {
+        // A branch whose pattern doesn't match is disabled, and the others keep going
+        Some(message) = first.recv() => Some((Source::First, message)),
+        Some(message) = second.recv() => Some((Source::Second, message)),
+        // Every branch was disabled, so both are closed
+        else => None,
+    }
+}
+
+/// Runs both futures until one of them completes, and returns what it returned.
+/// The other one is cancelled.
+pub async fn race<T>(a: impl Future<Output = T>, b: impl Future<Output = T>) -> T {
+    // The losing future is dropped when `select!` returns, which cancels it
+    tokio::select! {
+        output = a => output,
+        output = b => output,
+    }
+}
+
+/// Receives messages until `deadline`, or until the channel is closed.
+/// No message may be lost, and the deadline must be kept no matter how often messages arrive.
+pub async fn collect_until<T>(receiver: &mut Receiver<T>, deadline: Instant) -> Vec<T> {
+    // Created once outside the loop, so the deadline doesn't restart with every message
+    let sleep = tokio::time::sleep_until(deadline);
+    tokio::pin!(sleep);
+
+    let mut received = Vec::new();
+    loop {
+        tokio::select! {
+            // `recv` is cancel safe: if the deadline wins, no message is lost
+            message = receiver.recv() => match message {
+                Some(message) => received.push(message),
+                None => return received,
+            },
+            _ = &mut sleep => return received,
+        }
+    }
+}
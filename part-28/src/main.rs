@@ -0,0 +1,296 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::sync::mpsc::Receiver;
+use tokio_util::sync::CancellationToken;
+
+#[cfg(all(test, feature = "solutions"))]
+mod solutions;
+
+#[tokio::main]
+async fn main() {
+    let pool = Arc::new(Pool::new(2));
+
+    let _ = tokio::time::timeout(
+        Duration::from_millis(100),
+        leaky_query(&pool, Duration::from_secs(1)),
+    )
+    .await;
+    println!(
+        "After cancelling a leaky query, {} connections are available",
+        pool.available()
+    );
+
+    let _ = tokio::time::timeout(
+        Duration::from_millis(100),
+        query(&pool, Duration::from_secs(1)),
+    )
+    .await;
+    println!(
+        "After cancelling a query, {} connections are available",
+        pool.available()
+    );
+
+    let (sender, jobs) = tokio::sync::mpsc::channel(16);
+    for job in [100, 200, 300, 400] {
+        sender.send(job).await.unwrap();
+    }
+    let token = CancellationToken::new();
+    let handle = tokio::spawn(worker(pool.clone(), jobs, token.clone()));
+
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    token.cancel();
+    println!(
+        "The worker finished {:?} before it was cancelled",
+        handle.await.unwrap()
+    );
+    println!("{} connections are available", pool.available());
+}
+
+/// A connection to a pretend database
+#[derive(Debug)]
+struct Connection {
+    id: usize,
+}
+
+/// Keeps a fixed number of connections, which are checked out and back in as they're used
+struct Pool {
+    connections: Mutex<Vec<Connection>>,
+}
+
+impl Pool {
+    fn new(size: usize) -> Self {
+        Self {
+            connections: Mutex::new((0..size).map(|id| Connection { id }).collect()),
+        }
+    }
+
+    /// How many connections are checked in
+    fn available(&self) -> usize {
+        self.connections.lock().unwrap().len()
+    }
+
+    fn check_out(&self) -> Option<Connection> {
+        self.connections.lock().unwrap().pop()
+    }
+
+    fn check_in(&self, connection: Connection) {
+        self.connections.lock().unwrap().push(connection);
+    }
+
+    /// Checks out a connection, which is checked back in when the returned guard is dropped
+    fn get(&self) -> Option<PooledConnection<'_>> {
+        todo!()
+    }
+}
+
+/// A checked out connection, which is checked back in when dropped
+struct PooledConnection<'a> {
+    pool: &'a Pool,
+    /// Only `None` while being dropped
+    connection: Option<Connection>,
+}
+
+impl PooledConnection<'_> {
+    fn id(&self) -> usize {
+        self.connection.as_ref().unwrap().id
+    }
+}
+
+impl Drop for PooledConnection<'_> {
+    fn drop(&mut self) {
+        todo!()
+    }
+}
+
+/// Runs a pretend query taking `duration`, returning the id of the connection it used.
+///
+/// Don't use this! The connection is only checked back in if the query finishes,
+/// so cancelling it while it waits loses the connection for good.
+async fn leaky_query(pool: &Pool, duration: Duration) -> Option<usize> {
+    let connection = pool.check_out()?;
+    tokio::time::sleep(duration).await;
+    let id = connection.id;
+    pool.check_in(connection);
+    Some(id)
+}
+
+/// Runs a pretend query taking `duration`, returning the id of the connection it used.
+/// The connection is checked back in even if the query is cancelled.
+async fn query(pool: &Pool, duration: Duration) -> Option<usize> {
+    let connection = pool.get()?;
+    tokio::time::sleep(duration).await;
+    Some(connection.id())
+}
+
+/// Holds on to a connection from `pool` and handles jobs from `jobs` until `token` is cancelled,
+/// or until the channel is closed. Each job is a number of milliseconds it takes.
+/// A job that has started is always finished, even if the token is cancelled meanwhile.
+/// Returns the finished jobs, and makes sure the connection is checked back in.
+async fn worker(pool: Arc<Pool>, mut jobs: Receiver<u64>, token: CancellationToken) -> Vec<u64> {
+    todo!()
+}
+
+#[tokio::test(start_paused = true)]
+async fn cancelling_a_leaky_query_loses_the_connection() {
+    let pool = Pool::new(1);
+    let result = tokio::time::timeout(
+        Duration::from_secs(1),
+        leaky_query(&pool, Duration::from_secs(2)),
+    )
+    .await;
+
+    assert!(result.is_err());
+    assert_eq!(pool.available(), 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn query_returns_the_connection() {
+    let pool = Pool::new(1);
+    assert_eq!(query(&pool, Duration::from_secs(1)).await, Some(0));
+    assert_eq!(pool.available(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn cancelling_a_query_returns_the_connection() {
+    let pool = Pool::new(1);
+    let result =
+        tokio::time::timeout(Duration::from_secs(1), query(&pool, Duration::from_secs(2))).await;
+
+    assert!(result.is_err());
+    assert_eq!(pool.available(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn query_fails_without_connections() {
+    let pool = Pool::new(1);
+    let guard = pool.get().unwrap();
+    assert_eq!(query(&pool, Duration::from_secs(1)).await, None);
+    drop(guard);
+    assert_eq!(query(&pool, Duration::from_secs(1)).await, Some(0));
+}
+
+#[tokio::test(start_paused = true)]
+async fn worker_stops_between_jobs_when_cancelled() {
+    let pool = Arc::new(Pool::new(1));
+    let (sender, jobs) = tokio::sync::mpsc::channel(16);
+    for job in [100, 100, 100, 100] {
+        sender.send(job).await.unwrap();
+    }
+    let token = CancellationToken::new();
+    let handle = tokio::spawn(worker(pool.clone(), jobs, token.clone()));
+
+    // In the middle of the third job
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    assert_eq!(pool.available(), 0);
+    token.cancel();
+
+    assert_eq!(handle.await.unwrap(), vec![100, 100, 100]);
+    assert_eq!(pool.available(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn worker_stops_when_the_channel_is_closed() {
+    let pool = Arc::new(Pool::new(1));
+    let (sender, jobs) = tokio::sync::mpsc::channel(16);
+    sender.send(10).await.unwrap();
+    sender.send(20).await.unwrap();
+    drop(sender);
+
+    assert_eq!(
+        worker(pool.clone(), jobs, CancellationToken::new()).await,
+        vec![10, 20]
+    );
+    assert_eq!(pool.available(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn idle_worker_stops_right_away_when_cancelled() {
+    let pool = Arc::new(Pool::new(1));
+    let (_sender, jobs) = tokio::sync::mpsc::channel(16);
+    let token = CancellationToken::new();
+    let handle = tokio::spawn(worker(pool.clone(), jobs, token.clone()));
+
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    let start = tokio::time::Instant::now();
+    token.cancel();
+
+    assert_eq!(handle.await.unwrap(), vec![]);
+    assert_eq!(start.elapsed(), Duration::ZERO);
+    assert_eq!(pool.available(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn aborted_worker_returns_the_connection() {
+    let pool = Arc::new(Pool::new(1));
+    let (sender, jobs) = tokio::sync::mpsc::channel(16);
+    sender.send(1000).await.unwrap();
+    let handle = tokio::spawn(worker(pool.clone(), jobs, CancellationToken::new()));
+
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(pool.available(), 0);
+    // Aborting drops the task's future wherever it is, with no chance to finish the job
+    handle.abort();
+
+    assert!(handle.await.unwrap_err().is_cancelled());
+    assert_eq!(pool.available(), 1);
+}
+
+#[cfg(feature = "solutions")]
+#[tokio::test(start_paused = true)]
+async fn checks_out_like_the_solution() {
+    let (pool, expected) = (Pool::new(3), solutions::Pool::new(3));
+    {
+        let connections: Vec<_> = std::iter::from_fn(|| pool.get()).collect();
+        let expected_connections: Vec<_> = std::iter::from_fn(|| expected.get()).collect();
+        assert_eq!(
+            connections
+                .iter()
+                .map(PooledConnection::id)
+                .collect::<Vec<_>>(),
+            expected_connections
+                .iter()
+                .map(solutions::PooledConnection::id)
+                .collect::<Vec<_>>()
+        );
+    }
+    assert_eq!(pool.available(), expected.available());
+}
+
+#[cfg(feature = "solutions")]
+#[tokio::test(start_paused = true)]
+async fn works_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let jobs = common::datagen::numbers_below(20, 100, rng.seed());
+    for cancel_after in [50, 300, 10_000] {
+        let cancel_after = Duration::from_millis(cancel_after);
+
+        let pool = Arc::new(Pool::new(1));
+        let (sender, receiver) = tokio::sync::mpsc::channel(jobs.len());
+        let token = CancellationToken::new();
+        let handle = tokio::spawn(worker(pool.clone(), receiver, token.clone()));
+
+        let expected_pool = Arc::new(solutions::Pool::new(1));
+        let (expected_sender, expected_receiver) = tokio::sync::mpsc::channel(jobs.len());
+        let expected_token = CancellationToken::new();
+        let expected_handle = tokio::spawn(solutions::worker(
+            expected_pool.clone(),
+            expected_receiver,
+            expected_token.clone(),
+        ));
+
+        for &job in &jobs {
+            sender.send(job).await.unwrap();
+            expected_sender.send(job).await.unwrap();
+        }
+        drop((sender, expected_sender));
+        tokio::time::sleep(cancel_after).await;
+        token.cancel();
+        expected_token.cancel();
+
+        assert_eq!(handle.await.unwrap(), expected_handle.await.unwrap());
+        assert_eq!(pool.available(), expected_pool.available());
+    }
+}
@@ -0,0 +1,117 @@
+//! Part 28, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::sync::mpsc::Receiver;
+use tokio_util::sync::CancellationToken;
+
+use super::Connection;
+
+/// Keeps a fixed number of connections, which are checked out and back in as they're used
+pub struct Pool {
+    pub connections: Mutex<Vec<Connection>>,
+}
+
+impl Pool {
+    pub fn new(size: usize) -> Self {
+        Self {
+            connections: Mutex::new((0..size).map(|id| Connection { id }).collect()),
+        }
+    }
+
+    /// How many connections are checked in
+    pub fn available(&self) -> usize {
+        self.connections.lock().unwrap().len()
+    }
+
+    pub fn check_out(&self) -> Option<Connection> {
+        self.connections.lock().unwrap().pop()
+    }
+
+    pub fn check_in(&self, connection: Connection) {
+        self.connections.lock().unwrap().push(connection);
+    }
+
+    /// Checks out a connection, which is checked back in when the returned guard is dropped
+    pub fn get(&self) -> Option<PooledConnection<'_>> {
+        Some(PooledConnection {
+            pool: self,
+            connection: Some(self.check_out()?),
+        })
+    }
+}
+
+/// A checked out connection, which is checked back in when dropped
+pub struct PooledConnection<'a> {
+    pub pool: &'a Pool,
+    /// Only `None` while being dropped
+    pub connection: Option<Connection>,
+}
+
+impl PooledConnection<'_> {
+    pub fn id(&self) -> usize {
+        self.connection.as_ref().unwrap().id
+    }
+}
+
+impl Drop for PooledConnection<'_> {
+    fn drop(&mut self) {
+        if let Some(connection) = self.connection.take() {
+            self.pool.check_in(connection);
+        }
+    }
+}
+
+/// Runs a pretend query taking `duration`, returning the id of the connection it used.
+///
+/// Don't use this! The connection is only checked back in if the query finishes,
+/// so cancelling it while it waits loses the connection for good.
+pub async fn leaky_query(pool: &Pool, duration: Duration) -> Option<usize> {
+    let connection = pool.check_out()?;
+    tokio::time::sleep(duration).await;
+    let id = connection.id;
+    pool.check_in(connection);
+    Some(id)
+}
+
+/// Runs a pretend query taking `duration`, returning the id of the connection it used.
+/// The connection is checked back in even if the query is cancelled.
+pub async fn query(pool: &Pool, duration: Duration) -> Option<usize> {
+    let connection = pool.get()?;
+    tokio::time::sleep(duration).await;
+    Some(connection.id())
+}
+
+/// Holds on to a connection from `pool` and handles jobs from `jobs` until `token` is cancelled,
+/// or until the channel is closed. Each job is a number of milliseconds it takes.
+/// A job that has started is always finished, even if the token is cancelled meanwhile.
+/// Returns the finished jobs, and makes sure the connection is checked back in.
+pub async fn worker(
+    pool: Arc<Pool>,
+    mut jobs: Receiver<u64>,
+    token: CancellationToken,
+) -> Vec<u64> {
+    // Checked back in when the guard is dropped, whether we return or the task is aborted
+    let _connection = pool.get().expect("No connection available");
+
+    let mut finished = Vec::new();
+    loop {
+        let job = tokio::select! {
+            // Check the token first, so a busy channel can't keep the worker going
+            biased;
+            _ = token.cancelled() => break,
+            job = jobs.recv() => match job {
+                Some(job) => job,
+                None => break,
+            },
+        };
+
+        // Not part of the `select!`, so cancelling the token doesn't interrupt it
+        tokio::time::sleep(Duration::from_millis(job)).await;
+        finished.push(job);
+    }
+    finished
+}
@@ -0,0 +1,43 @@
+use std::{future::Future, time::Duration};
+
+use part_5::{calculate, ComputationResult, Data};
+use tokio::task::JoinSet;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Does the same as `part_5::serial_calculate`, but runs every calculation as a task in a `JoinSet`.
+/// The results are in the same order as the data.
+pub async fn join_set_calculate(data: Vec<Data>) -> Vec<ComputationResult> {
+    todo!()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CalculationError {
+    /// The calculation can't be done for this data
+    Invalid(Data),
+    /// The task doing the calculation panicked
+    Panicked,
+}
+
+/// Like `part_5::calculate`, but async, and fails right away for `Data(0)`
+pub async fn checked_calculate(datum: Data) -> Result<ComputationResult, CalculationError> {
+    if datum.0 == 0 {
+        return Err(CalculationError::Invalid(datum));
+    }
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    Ok(ComputationResult(datum.0 * 2))
+}
+
+/// Runs `calculate` for every datum as a task in a `JoinSet`, returning the results in the same order as the data.
+/// Returns the first error as soon as it happens, and aborts the calculations that are still running.
+pub async fn try_calculate_all<F, Fut>(
+    data: Vec<Data>,
+    calculate: F,
+) -> Result<Vec<ComputationResult>, CalculationError>
+where
+    F: Fn(Data) -> Fut,
+    Fut: Future<Output = Result<ComputationResult, CalculationError>> + Send + 'static,
+{
+    todo!()
+}
@@ -0,0 +1,155 @@
+#[cfg(test)]
+use std::time::Duration;
+
+#[cfg(test)]
+use part_29::CalculationError;
+use part_29::{checked_calculate, join_set_calculate, try_calculate_all};
+use part_5::Data;
+#[cfg(test)]
+use part_5::{calculate, ComputationResult};
+
+#[tokio::main]
+async fn main() {
+    let args = common::cli::Cli::new("Calculates on tokio tasks, stopping at the first error")
+        .items(fixtures::data_len(fixtures::Dataset::Small))
+        .compute_ms(500)
+        .parse();
+    // The same as `fixtures::data(Dataset::Small)`, unless run with another `--items`
+    let data: Vec<Data> = (1..=args.items() as u64).map(Data).collect();
+    let start = tokio::time::Instant::now();
+    let results = join_set_calculate(data.clone()).await;
+    println!("{results:?} in {} ms", start.elapsed().as_millis());
+
+    let start = tokio::time::Instant::now();
+    let results = try_calculate_all(data, checked_calculate).await;
+    println!("{results:?} in {} ms", start.elapsed().as_millis());
+
+    let start = tokio::time::Instant::now();
+    let results = try_calculate_all(vec![Data(1), Data(0), Data(3)], checked_calculate).await;
+    println!("{results:?} in {} ms", start.elapsed().as_millis());
+}
+
+#[tokio::test]
+async fn join_set_matches_serial() {
+    use part_5::serial_calculate;
+
+    let data: Vec<_> = [3, 1, 4, 1, 5].map(Data).into();
+    let expected = tokio::task::spawn_blocking({
+        let data = data.clone();
+        move || serial_calculate(data)
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(join_set_calculate(data).await, expected);
+}
+
+#[tokio::test]
+async fn join_set_runs_calculations_at_once() {
+    let data: Vec<_> = (0..4).map(Data).collect();
+    let start = tokio::time::Instant::now();
+    join_set_calculate(data).await;
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    // One calculation takes 500 ms
+    assert!(start.elapsed() < Duration::from_millis(1500));
+}
+
+#[tokio::test(start_paused = true)]
+async fn try_calculate_matches_serial() {
+    let data: Vec<_> = [3, 1, 4, 1, 5].map(Data).into();
+    let expected: Vec<_> = data.iter().cloned().map(calculate).collect();
+
+    let start = tokio::time::Instant::now();
+    assert_eq!(
+        try_calculate_all(data, checked_calculate).await,
+        Ok(expected)
+    );
+    assert_eq!(start.elapsed(), Duration::from_millis(500));
+}
+
+#[tokio::test(start_paused = true)]
+async fn try_calculate_fails_fast() {
+    let data: Vec<_> = [1, 2, 0, 3].map(Data).into();
+    let start = tokio::time::Instant::now();
+
+    let result = try_calculate_all(data, checked_calculate).await;
+
+    assert_eq!(result, Err(CalculationError::Invalid(Data(0))));
+    // Didn't wait for the others to finish
+    assert_eq!(start.elapsed(), Duration::ZERO);
+}
+
+#[tokio::test(start_paused = true)]
+async fn try_calculate_aborts_the_rest_on_failure() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    let finished = Arc::new(AtomicUsize::new(0));
+    let counting = |datum: Data| {
+        let finished = finished.clone();
+        async move {
+            let slow = datum.0 != 2;
+            if slow {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+            let result = if slow {
+                Ok(ComputationResult(datum.0))
+            } else {
+                Err(CalculationError::Invalid(datum))
+            };
+            finished.fetch_add(1, Ordering::SeqCst);
+            result
+        }
+    };
+
+    let result = try_calculate_all([1, 2, 3].map(Data).into(), counting).await;
+    assert_eq!(result, Err(CalculationError::Invalid(Data(2))));
+
+    // Had the others been left running, they would have finished by now
+    tokio::time::sleep(Duration::from_secs(5)).await;
+    assert_eq!(finished.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn try_calculate_reports_panics() {
+    let panicking = |datum: Data| async move {
+        if datum.0 == 2 {
+            panic!("Oh no!");
+        }
+        Ok(ComputationResult(datum.0))
+    };
+
+    let result = try_calculate_all([1, 2, 3].map(Data).into(), panicking).await;
+    assert_eq!(result, Err(CalculationError::Panicked));
+}
+
+#[cfg(feature = "solutions")]
+#[tokio::test]
+async fn calculates_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let data: Vec<Data> = common::datagen::numbers_below(3, 1000, rng.seed())
+        .into_iter()
+        .map(Data)
+        .collect();
+    assert_eq!(
+        join_set_calculate(data.clone()).await,
+        part_29::solutions::join_set_calculate(data).await
+    );
+}
+
+#[cfg(feature = "solutions")]
+#[tokio::test(start_paused = true)]
+async fn fails_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let numbers = common::datagen::numbers_below(40, 5, rng.seed());
+    for data in numbers.chunks(8) {
+        let data: Vec<Data> = data.iter().copied().map(Data).collect();
+        let result = try_calculate_all(data.clone(), checked_calculate).await;
+        let expected = part_29::solutions::try_calculate_all(data, checked_calculate).await;
+        assert_eq!(result, expected);
+    }
+}
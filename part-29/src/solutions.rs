@@ -0,0 +1,53 @@
+//! Part 29, with every exercise implemented. Only built with `--features solutions`.
+
+use std::future::Future;
+
+use part_5::{calculate, ComputationResult, Data};
+use tokio::task::JoinSet;
+
+use super::CalculationError;
+
+/// Does the same as `part_5::serial_calculate`, but runs every calculation as a task in a `JoinSet`.
+/// The results are in the same order as the data.
+pub async fn join_set_calculate(data: Vec<Data>) -> Vec<ComputationResult> {
+    let mut set = JoinSet::new();
+    let len = data.len();
+    for (i, datum) in data.into_iter().enumerate() {
+        // `calculate` blocks, so it must not run on the async worker threads
+        set.spawn_blocking(move || (i, calculate(datum)));
+    }
+
+    // Tasks finish in any order, so put each result back where its datum was
+    let mut results = vec![None; len];
+    while let Some(joined) = set.join_next().await {
+        let (i, result) = joined.expect("Calculation panicked");
+        results[i] = Some(result);
+    }
+    results.into_iter().map(Option::unwrap).collect()
+}
+
+/// Runs `calculate` for every datum as a task in a `JoinSet`, returning the results in the same order as the data.
+/// Returns the first error as soon as it happens, and aborts the calculations that are still running.
+pub async fn try_calculate_all<F, Fut>(
+    data: Vec<Data>,
+    calculate: F,
+) -> Result<Vec<ComputationResult>, CalculationError>
+where
+    F: Fn(Data) -> Fut,
+    Fut: Future<Output = Result<ComputationResult, CalculationError>> + Send + 'static,
+{
+    let mut set = JoinSet::new();
+    let len = data.len();
+    for (i, datum) in data.into_iter().enumerate() {
+        let calculation = calculate(datum);
+        set.spawn(async move { (i, calculation.await) });
+    }
+
+    let mut results = vec![None; len];
+    while let Some(joined) = set.join_next().await {
+        // Returning drops the set, which aborts every task still in it
+        let (i, result) = joined.map_err(|_| CalculationError::Panicked)?;
+        results[i] = Some(result?);
+    }
+    Ok(results.into_iter().map(Option::unwrap).collect())
+}
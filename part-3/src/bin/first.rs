@@ -0,0 +1,100 @@
+use std::{sync::mpsc::Receiver, time::Duration};
+
+use common::leak::LeakGuard;
+
+#[cfg(all(test, feature = "solutions"))]
+#[path = "first/solutions.rs"]
+mod solutions;
+
+fn main() {
+    let guard = LeakGuard::new();
+    let numbers = first(endless(4, &guard), 20);
+    println!("Got: {numbers:?}");
+
+    let stopped = guard.wait(Duration::from_secs(1));
+    println!(
+        "The producers stopped: {stopped}, {} still running",
+        guard.live()
+    );
+}
+
+/// Starts `producers` threads, which each send their own endless stream of numbers: producer `p`
+/// sends `p, p + producers, p + 2 * producers, ...`, in order. Every thread holds a token from
+/// `guard` for as long as it runs, and stops once nobody is receiving anymore.
+fn endless(producers: u64, guard: &LeakGuard) -> Receiver<u64> {
+    todo!()
+}
+
+/// Takes the first `n` numbers from `receiver`, and then stops receiving, which should make the
+/// producers stop too
+fn first(receiver: Receiver<u64>, n: usize) -> Vec<u64> {
+    todo!()
+}
+
+#[cfg(test)]
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[test]
+fn takes_exactly_the_first_n() {
+    let guard = LeakGuard::new();
+    let numbers = first(endless(4, &guard), 100);
+    assert_eq!(numbers.len(), 100);
+
+    let mut sorted = numbers.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(sorted.len(), 100, "Some numbers came twice: {numbers:?}");
+    // Every producer's own numbers come in order
+    for producer in 0..4 {
+        let own: Vec<u64> = numbers
+            .iter()
+            .copied()
+            .filter(|x| x % 4 == producer)
+            .collect();
+        assert!(own.windows(2).all(|pair| pair[0] < pair[1]), "{own:?}");
+        assert!(
+            own.iter().zip(0..).all(|(x, i)| *x == producer + i * 4),
+            "{own:?}"
+        );
+    }
+    guard.wait(TIMEOUT);
+}
+
+#[test]
+fn producers_stop_once_the_consumer_is_done() {
+    let guard = LeakGuard::new();
+    let numbers = first(endless(8, &guard), 1000);
+    assert_eq!(numbers.len(), 1000);
+    assert!(
+        guard.wait(TIMEOUT),
+        "{} of 8 producers are still running, after the receiver is gone",
+        guard.live()
+    );
+}
+
+#[test]
+fn producers_stop_when_nothing_is_taken() {
+    let guard = LeakGuard::new();
+    assert!(first(endless(3, &guard), 0).is_empty());
+    assert!(
+        guard.wait(TIMEOUT),
+        "{} of 3 producers are still running, after the receiver is gone",
+        guard.live()
+    );
+}
+
+#[cfg(feature = "solutions")]
+#[test]
+fn takes_like_the_solution() {
+    let guard = LeakGuard::new();
+    let mut numbers = first(endless(5, &guard), 500);
+    let mut expected = solutions::first(solutions::endless(5, &guard), 500);
+    assert_eq!(numbers.len(), expected.len());
+    // Which producer gets to send next isn't up to the exercise
+    numbers.sort();
+    expected.sort();
+    numbers.dedup();
+    expected.dedup();
+    assert_eq!(numbers.len(), expected.len());
+    assert!(guard.wait(TIMEOUT));
+}
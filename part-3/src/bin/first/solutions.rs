@@ -0,0 +1,33 @@
+//! `first`, with every exercise implemented. Only built with `--features solutions`.
+
+use std::sync::mpsc::Receiver;
+
+use common::leak::LeakGuard;
+
+/// Starts `producers` threads, which each send their own endless stream of numbers: producer `p`
+/// sends `p, p + producers, p + 2 * producers, ...`, in order. Every thread holds a token from
+/// `guard` for as long as it runs, and stops once nobody is receiving anymore.
+pub fn endless(producers: u64, guard: &LeakGuard) -> Receiver<u64> {
+    // Bounded, so that the producers wait for the consumer instead of filling up memory
+    let (sender, receiver) = std::sync::mpsc::sync_channel(16);
+    for producer in 0..producers {
+        let (sender, token) = (sender.clone(), guard.token());
+        std::thread::spawn(move || {
+            let _token = token;
+            for x in (producer..).step_by(producers as usize) {
+                if sender.send(x).is_err() {
+                    // The receiver is gone, so nobody will ever see the rest
+                    break;
+                }
+            }
+        });
+    }
+    receiver
+}
+
+/// Takes the first `n` numbers from `receiver`, and then stops receiving, which should make the
+/// producers stop too
+pub fn first(receiver: Receiver<u64>, n: usize) -> Vec<u64> {
+    // `receiver` is dropped when this returns, since it was moved in
+    receiver.iter().take(n).collect()
+}
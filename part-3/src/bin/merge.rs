@@ -0,0 +1,117 @@
+use std::sync::mpsc::Receiver;
+
+#[cfg(all(test, feature = "solutions"))]
+#[path = "merge/solutions.rs"]
+mod solutions;
+
+fn main() {
+    let runs = vec![vec![1, 4, 7, 10], vec![2, 5, 8], vec![0, 3, 6, 9, 11]];
+    let receivers = runs.into_iter().map(producer).collect();
+
+    for x in merge(receivers) {
+        println!("Got: {x}");
+    }
+}
+
+/// Spawns a thread sending every number of `run` in order.
+/// `run` is expected to already be sorted.
+fn producer(run: Vec<u64>) -> Receiver<u64> {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        for x in run {
+            sender.send(x).expect("Couldn't send message");
+        }
+    });
+    receiver
+}
+
+/// Merges the sorted streams of `receivers` into one sorted stream.
+/// Values should be sent as soon as it is known that they are the next
+/// smallest value, not only after all the producers are done.
+fn merge(receivers: Vec<Receiver<u64>>) -> Receiver<u64> {
+    todo!()
+}
+
+#[test]
+fn merges_sorted_runs() {
+    let runs: Vec<Vec<u64>> = (1..=5)
+        .map(|step| (0..100).step_by(step).collect())
+        .collect();
+    let mut expected: Vec<u64> = runs.iter().flatten().copied().collect();
+    expected.sort();
+
+    // Shows what was merged, and when, if the test fails
+    let trace = common::trace::Trace::new();
+    let _dump = trace.dump_on_panic();
+    let merged = common::trace::RecordingReceiver::wrap(
+        merge(runs.into_iter().map(producer).collect()),
+        &trace,
+    );
+    let result: Vec<u64> = merged.iter().collect();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn handles_empty_runs() {
+    let runs = vec![vec![], vec![3], vec![], vec![1, 2]];
+    let merged = merge(runs.into_iter().map(producer).collect());
+
+    assert_eq!(merged.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+}
+
+#[test]
+fn streams_before_producers_finish() {
+    use common::trace::{self, RecordingReceiver, Trace};
+    use std::time::Duration;
+    let timeout = Duration::from_secs(1);
+
+    // Shows what was sent and merged, and in which order, if the test fails
+    let trace = Trace::new();
+    let _dump = trace.dump_on_panic();
+
+    // This test acts as the producers itself, so it is in control of
+    // when each of the streams gets its next value, or ends.
+    let (senders, receivers): (Vec<_>, Vec<_>) = (0..3)
+        .map(|_| {
+            let (sender, receiver) = trace::channel::<u64>(&trace);
+            (sender, receiver.into_inner())
+        })
+        .unzip();
+    senders[0].send(1).unwrap();
+    senders[1].send(2).unwrap();
+    senders[2].send(3).unwrap();
+
+    let merged = RecordingReceiver::wrap(merge(receivers), &trace);
+    // Every stream has a value, so the smallest one can be sent already
+    assert_eq!(merged.recv_timeout(timeout), Ok(1));
+
+    // The first stream could still send something smaller than 2,
+    // so 2 can first be sent once it is known that it doesn't
+    senders[0].send(5).unwrap();
+    assert_eq!(merged.recv_timeout(timeout), Ok(2));
+
+    drop(senders);
+    assert_eq!(merged.iter().collect::<Vec<_>>(), vec![3, 5]);
+}
+
+#[cfg(feature = "solutions")]
+#[test]
+fn merges_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let runs: Vec<Vec<u64>> = (0..8)
+        .map(|run| {
+            let mut run = common::datagen::numbers_below(100 * run, 1000, rng.seed());
+            run.sort();
+            run
+        })
+        .collect();
+
+    let merged: Vec<u64> = merge(runs.iter().cloned().map(producer).collect())
+        .iter()
+        .collect();
+    let expected: Vec<u64> = solutions::merge(runs.into_iter().map(producer).collect())
+        .iter()
+        .collect();
+    assert_eq!(merged, expected);
+}
@@ -0,0 +1,33 @@
+//! `merge`, with every exercise implemented. Only built with `--features solutions`.
+
+use std::sync::mpsc::Receiver;
+
+/// Merges the sorted streams of `receivers` into one sorted stream.
+/// Values should be sent as soon as it is known that they are the next
+/// smallest value, not only after all the producers are done.
+pub fn merge(receivers: Vec<Receiver<u64>>) -> Receiver<u64> {
+    use std::{cmp::Reverse, collections::BinaryHeap};
+
+    let (sender, merged) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        // Min-heap of the next value of each stream, along with which stream it came from
+        let mut heads = BinaryHeap::new();
+        for (i, receiver) in receivers.iter().enumerate() {
+            if let Ok(x) = receiver.recv() {
+                heads.push(Reverse((x, i)));
+            }
+        }
+
+        while let Some(Reverse((x, i))) = heads.pop() {
+            if sender.send(x).is_err() {
+                // No one is listening anymore
+                return;
+            }
+            // Refill from the stream that just gave away its head, unless it is done
+            if let Ok(next) = receivers[i].recv() {
+                heads.push(Reverse((next, i)));
+            }
+        }
+    });
+    merged
+}
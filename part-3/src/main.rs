@@ -1,29 +1,57 @@
-use std::sync::mpsc::Receiver;
+use common::channel::{Backend, Selected, Sender};
+
+#[cfg(all(test, feature = "solutions"))]
+mod solutions;
 
 fn main() {
-    let receiver = producers();
+    let receiver = producers::<Selected>();
     while let Ok(x) = receiver.recv() {
         println!("Got: {x}")
     }
 }
 
-fn producers() -> Receiver<i32> {
+/// Returns a receiver of the numbers `0, 1, ..., 9`, each sent by a thread of its own, over a
+/// channel of backend `B`
+fn producers<B: Backend>() -> B::Receiver<i32> {
     todo!()
 }
 
-#[test]
-fn sends_messages_correctly() {
+#[cfg(test)]
+fn sends_messages_correctly_on<B: Backend>() {
+    use common::trace::{RecordingReceiver, Trace};
     use std::collections::HashSet;
     // There's no real good way to check that you've actually spawned 10 threads
     // other than to check a number of join handles, but we're just gonna
     // check that the values are produced correctly.
 
-    let receiver = producers();
+    // Shows what was received, and when, if the test fails
+    let trace = Trace::new();
+    let _dump = trace.dump_on_panic();
+    let receiver = RecordingReceiver::wrap(producers::<B>(), &trace);
 
     let mut results = HashSet::new();
     while let Ok(x) = receiver.recv() {
         results.insert(x);
     }
 
-    assert_eq!(results, HashSet::from_iter(0..10))
+    assert_eq!(results, HashSet::from_iter(0..10), "On {}", B::NAME)
+}
+
+// The same test on every backend, so that a solution can't depend on how one of them works
+#[test]
+fn sends_messages_correctly() {
+    sends_messages_correctly_on::<common::channel::Std>();
+    #[cfg(feature = "crossbeam")]
+    sends_messages_correctly_on::<common::channel::Crossbeam>();
+}
+
+#[cfg(feature = "solutions")]
+#[test]
+fn sends_like_the_solution() {
+    let mut results: Vec<i32> = producers::<Selected>().iter().collect();
+    let mut expected: Vec<i32> = solutions::producers::<Selected>().iter().collect();
+    // The threads can send in any order
+    results.sort();
+    expected.sort();
+    assert_eq!(results, expected);
 }
@@ -0,0 +1,12 @@
+//! Part 3, with every exercise implemented. Only built with `--features solutions`.
+
+use common::channel::{Backend, Sender};
+
+pub fn producers<B: Backend>() -> B::Receiver<i32> {
+    let (sender, receiver) = B::unbounded();
+    for x in 0..10 {
+        let sender = sender.clone();
+        std::thread::spawn(move || sender.send(x).expect("Couldn't send message"));
+    }
+    receiver
+}
@@ -0,0 +1,171 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use tokio::sync::Semaphore;
+
+#[cfg(all(test, feature = "solutions"))]
+mod solutions;
+
+/// How many requests may run at once
+const LIMIT: usize = 5;
+
+#[tokio::main]
+async fn main() {
+    let args = common::cli::Cli::new("Runs requests a few at a time, on tasks and on threads")
+        .items(100)
+        .parse();
+    let requests = args.items();
+    let tracker = Arc::new(Tracker::default());
+    let start = tokio::time::Instant::now();
+    let completed = run_limited(requests, LIMIT, tracker.clone()).await;
+    println!(
+        "{} requests took {} ms, with at most {} at once",
+        completed.len(),
+        start.elapsed().as_millis(),
+        tracker.peak()
+    );
+
+    let tracker = Arc::new(Tracker::default());
+    let start = std::time::Instant::now();
+    let completed = tokio::task::spawn_blocking({
+        let tracker = tracker.clone();
+        move || run_limited_threads(requests, LIMIT, &tracker)
+    })
+    .await
+    .unwrap();
+    println!(
+        "{} requests on threads took {} ms, with at most {} at once",
+        completed.len(),
+        start.elapsed().as_millis(),
+        tracker.peak()
+    );
+}
+
+/// Keeps track of how many requests are running, and the most that have run at once
+#[derive(Debug, Default)]
+struct Tracker {
+    running: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+/// Counts as a running request until dropped
+struct Running<'a>(&'a Tracker);
+
+impl Tracker {
+    fn start(&self) -> Running<'_> {
+        let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(running, Ordering::SeqCst);
+        Running(self)
+    }
+
+    fn peak(&self) -> usize {
+        self.peak.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for Running<'_> {
+    fn drop(&mut self) {
+        self.0.running.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Pretends to send a request to a server which can't handle many at once
+async fn request(id: usize, tracker: &Tracker) -> usize {
+    let _running = tracker.start();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    id
+}
+
+/// Like `request`, but blocking
+fn blocking_request(id: usize, tracker: &Tracker) -> usize {
+    let _running = tracker.start();
+    thread::sleep(Duration::from_millis(100));
+    id
+}
+
+/// Runs `request` for the ids `0..requests`, each in its own task, but never more than `limit` at once.
+/// Returns the ids of the requests in the order they completed.
+async fn run_limited(requests: usize, limit: usize, tracker: Arc<Tracker>) -> Vec<usize> {
+    todo!()
+}
+
+/// Runs `blocking_request` for the ids `0..requests`, each on its own thread, but never more than `limit` at once,
+/// using `common::Semaphore`. Returns the ids of the requests in the order they completed.
+fn run_limited_threads(requests: usize, limit: usize, tracker: &Tracker) -> Vec<usize> {
+    todo!()
+}
+
+#[tokio::test(start_paused = true)]
+async fn runs_every_request() {
+    let mut completed = run_limited(100, LIMIT, Default::default()).await;
+    completed.sort();
+    assert_eq!(completed, (0..100).collect::<Vec<_>>());
+}
+
+#[tokio::test(start_paused = true)]
+async fn never_exceeds_the_limit() {
+    for limit in [1, 5, 20] {
+        let tracker = Arc::new(Tracker::default());
+        run_limited(100, limit, tracker.clone()).await;
+        assert_eq!(tracker.peak(), limit);
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn runs_as_many_as_allowed() {
+    let start = tokio::time::Instant::now();
+    run_limited(100, LIMIT, Default::default()).await;
+    // 100 requests, 5 at a time, 100 ms each
+    assert_eq!(start.elapsed(), Duration::from_millis(2000));
+}
+
+#[test]
+fn threads_run_every_request() {
+    let mut completed = run_limited_threads(50, LIMIT, &Tracker::default());
+    completed.sort();
+    assert_eq!(completed, (0..50).collect::<Vec<_>>());
+}
+
+#[test]
+fn threads_never_exceed_the_limit() {
+    for limit in [1, 5] {
+        let tracker = Tracker::default();
+        run_limited_threads(20, limit, &tracker);
+        assert_eq!(tracker.peak(), limit);
+    }
+}
+
+#[cfg(feature = "solutions")]
+#[tokio::test(start_paused = true)]
+async fn limits_like_the_solution() {
+    for (requests, limit) in [(0, 1), (10, 1), (100, 5), (20, 50)] {
+        let tracker = Arc::new(Tracker::default());
+        let mut completed = run_limited(requests, limit, tracker.clone()).await;
+        let expected_tracker = Arc::new(Tracker::default());
+        let mut expected = solutions::run_limited(requests, limit, expected_tracker.clone()).await;
+        // Requests finishing at the same time can complete in any order
+        completed.sort();
+        expected.sort();
+        assert_eq!(completed, expected);
+        assert_eq!(tracker.peak(), expected_tracker.peak());
+    }
+}
+
+#[cfg(feature = "solutions")]
+#[test]
+fn limits_threads_like_the_solution() {
+    let tracker = Tracker::default();
+    let mut completed = run_limited_threads(20, 5, &tracker);
+    let expected_tracker = Tracker::default();
+    let mut expected = solutions::run_limited_threads(20, 5, &expected_tracker);
+    completed.sort();
+    expected.sort();
+    assert_eq!(completed, expected);
+    assert!(tracker.peak() <= expected_tracker.peak().max(5));
+}
@@ -0,0 +1,50 @@
+//! Part 30, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{sync::Arc, thread};
+
+use tokio::sync::Semaphore;
+
+use super::{blocking_request, request, Tracker};
+
+/// Runs `request` for the ids `0..requests`, each in its own task, but never more than `limit` at once.
+/// Returns the ids of the requests in the order they completed.
+pub async fn run_limited(requests: usize, limit: usize, tracker: Arc<Tracker>) -> Vec<usize> {
+    let semaphore = Arc::new(Semaphore::new(limit));
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+
+    for id in 0..requests {
+        let (semaphore, tracker, sender) = (semaphore.clone(), tracker.clone(), sender.clone());
+        tokio::spawn(async move {
+            // Held until the task is done, even if it panics or is cancelled
+            let _permit = semaphore.acquire_owned().await.unwrap();
+            let _ = sender.send(request(id, &tracker).await);
+        });
+    }
+    // Only the tasks' senders are left, so the channel closes when the last task is done
+    drop(sender);
+
+    let mut completed = Vec::new();
+    while let Some(id) = receiver.recv().await {
+        completed.push(id);
+    }
+    completed
+}
+
+/// Runs `blocking_request` for the ids `0..requests`, each on its own thread, but never more than `limit` at once,
+/// using `common::Semaphore`. Returns the ids of the requests in the order they completed.
+pub fn run_limited_threads(requests: usize, limit: usize, tracker: &Tracker) -> Vec<usize> {
+    let semaphore = common::Semaphore::new(limit);
+    let completed = std::sync::Mutex::new(Vec::new());
+
+    thread::scope(|s| {
+        for id in 0..requests {
+            let (semaphore, completed) = (&semaphore, &completed);
+            s.spawn(move || {
+                let _permit = semaphore.acquire();
+                let id = blocking_request(id, tracker);
+                completed.lock().unwrap().push(id);
+            });
+        }
+    });
+    completed.into_inner().unwrap()
+}
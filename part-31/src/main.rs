@@ -0,0 +1,299 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::{stream, Stream, StreamExt};
+use part_5::{calculate, ComputationResult, Data};
+use tokio::time::{Instant, Sleep};
+
+#[cfg(all(test, feature = "solutions"))]
+mod solutions;
+
+#[tokio::main]
+async fn main() {
+    let args = common::cli::Cli::new("Ticks, and calculates with streams, in order or not")
+        .items(fixtures::data_len(fixtures::Dataset::Medium))
+        .compute_ms(500)
+        .parse();
+    let mut ticks = Ticks::new(3, Duration::from_millis(200));
+    while let Some(tick) = ticks.next().await {
+        println!("Tick {tick}");
+    }
+
+    // The same as `fixtures::data(Dataset::Medium)`, unless run with another `--items`
+    let data: Vec<Data> = (1..=args.items() as u64).map(Data).collect();
+
+    let start = Instant::now();
+    let results = serial(data.clone(), blocking_calculate).await;
+    println!(
+        "One at a time: {results:?} in {} ms",
+        start.elapsed().as_millis()
+    );
+
+    let start = Instant::now();
+    let results = ordered(data.clone(), 3, blocking_calculate).await;
+    println!("Ordered: {results:?} in {} ms", start.elapsed().as_millis());
+
+    let start = Instant::now();
+    let results = unordered(data, 3, blocking_calculate).await;
+    println!(
+        "Unordered: {results:?} in {} ms",
+        start.elapsed().as_millis()
+    );
+}
+
+/// A stream yielding `1, 2, ..., count`, one every `period`
+struct Ticks {
+    count: u64,
+    emitted: u64,
+    period: Duration,
+    /// Completes when the next tick is due. Boxed, so `Ticks` doesn't have to be pinned.
+    sleep: Pin<Box<Sleep>>,
+}
+
+impl Ticks {
+    fn new(count: u64, period: Duration) -> Self {
+        Self {
+            count,
+            emitted: 0,
+            period,
+            sleep: Box::pin(tokio::time::sleep(period)),
+        }
+    }
+}
+
+impl Stream for Ticks {
+    type Item = u64;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<u64>> {
+        todo!()
+    }
+}
+
+/// Runs `part_5::calculate` without blocking the async worker threads
+async fn blocking_calculate(datum: Data) -> ComputationResult {
+    tokio::task::spawn_blocking(move || calculate(datum))
+        .await
+        .expect("Calculation panicked")
+}
+
+/// Calculates the results one at a time, returning them in the same order as the data
+async fn serial<F, Fut>(data: Vec<Data>, calculate: F) -> Vec<ComputationResult>
+where
+    F: Fn(Data) -> Fut,
+    Fut: Future<Output = ComputationResult>,
+{
+    todo!()
+}
+
+/// Runs up to `limit` calculations at once, returning the results in the same order as the data
+async fn ordered<F, Fut>(data: Vec<Data>, limit: usize, calculate: F) -> Vec<ComputationResult>
+where
+    F: Fn(Data) -> Fut,
+    Fut: Future<Output = ComputationResult>,
+{
+    todo!()
+}
+
+/// Runs up to `limit` calculations at once, returning the results in the order they finished
+async fn unordered<F, Fut>(data: Vec<Data>, limit: usize, calculate: F) -> Vec<ComputationResult>
+where
+    F: Fn(Data) -> Fut,
+    Fut: Future<Output = ComputationResult>,
+{
+    todo!()
+}
+
+#[cfg(test)]
+mod helpers {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use part_5::{ComputationResult, Data};
+
+    /// A calculation taking as many seconds as the datum, which keeps track of how many run at once
+    #[derive(Clone, Default)]
+    pub struct Tracked {
+        running: Arc<AtomicUsize>,
+        peak: Arc<AtomicUsize>,
+    }
+
+    impl Tracked {
+        pub async fn calculate(self, datum: Data) -> ComputationResult {
+            let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(running, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_secs(datum.0)).await;
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            ComputationResult(datum.0 * 2)
+        }
+
+        pub fn peak(&self) -> usize {
+            self.peak.load(Ordering::SeqCst)
+        }
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn ticks_are_evenly_spaced() {
+    let start = Instant::now();
+    let ticks: Vec<_> = Ticks::new(4, Duration::from_secs(1))
+        .map(|tick| (tick, start.elapsed().as_secs()))
+        .collect()
+        .await;
+    assert_eq!(ticks, vec![(1, 1), (2, 2), (3, 3), (4, 4)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn ticks_end() {
+    let mut ticks = Ticks::new(1, Duration::from_secs(1));
+    assert_eq!(ticks.next().await, Some(1));
+    assert_eq!(ticks.next().await, None);
+    assert_eq!(ticks.next().await, None);
+    assert_eq!(Ticks::new(0, Duration::from_secs(1)).next().await, None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn serial_runs_one_at_a_time() {
+    use helpers::Tracked;
+
+    let tracked = Tracked::default();
+    let start = Instant::now();
+    let results = serial([3, 1, 2].map(Data).into(), |datum| {
+        tracked.clone().calculate(datum)
+    })
+    .await;
+
+    assert_eq!(results, [6, 2, 4].map(ComputationResult));
+    assert_eq!(tracked.peak(), 1);
+    assert_eq!(start.elapsed(), Duration::from_secs(6));
+}
+
+#[tokio::test(start_paused = true)]
+async fn ordered_keeps_the_order() {
+    use helpers::Tracked;
+
+    let tracked = Tracked::default();
+    let start = Instant::now();
+    let results = ordered([3, 1, 2, 1].map(Data).into(), 2, |datum| {
+        tracked.clone().calculate(datum)
+    })
+    .await;
+
+    assert_eq!(results, [6, 2, 4, 2].map(ComputationResult));
+    assert_eq!(tracked.peak(), 2);
+    // The first 1 is done after a second, but it waits for the 3 in front of it while taking up
+    // one of the two places. So the 2 and the last 1 don't start until the 3 is done.
+    assert_eq!(start.elapsed(), Duration::from_secs(5));
+}
+
+#[tokio::test(start_paused = true)]
+async fn unordered_yields_results_as_they_finish() {
+    use helpers::Tracked;
+
+    let tracked = Tracked::default();
+    let start = Instant::now();
+    let results = unordered([3, 1, 2, 1].map(Data).into(), 2, |datum| {
+        tracked.clone().calculate(datum)
+    })
+    .await;
+
+    // The 1 finishes first, letting in the 2, which finishes at the same time as the 3,
+    // and then the last 1 runs alone
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0], ComputationResult(2));
+    assert_eq!(results[3], ComputationResult(2));
+    assert_eq!(tracked.peak(), 2);
+    assert_eq!(start.elapsed(), Duration::from_secs(4));
+}
+
+#[tokio::test(start_paused = true)]
+async fn limit_bounds_the_concurrency() {
+    use helpers::Tracked;
+
+    for limit in [1, 3, 10] {
+        let data: Vec<_> = (1..=10).map(Data).collect();
+        let tracked = Tracked::default();
+        let results = ordered(data.clone(), limit, |datum| {
+            tracked.clone().calculate(datum)
+        })
+        .await;
+        assert_eq!(results.len(), 10);
+        assert_eq!(tracked.peak(), limit);
+
+        let tracked = Tracked::default();
+        let results = unordered(data, limit, |datum| tracked.clone().calculate(datum)).await;
+        assert_eq!(results.len(), 10);
+        assert_eq!(tracked.peak(), limit);
+    }
+}
+
+#[tokio::test]
+async fn matches_the_synchronous_version() {
+    use part_5::serial_calculate;
+
+    let data: Vec<_> = (1..=4).map(Data).collect();
+    let expected = tokio::task::spawn_blocking({
+        let data = data.clone();
+        move || serial_calculate(data)
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(ordered(data.clone(), 4, blocking_calculate).await, expected);
+
+    let mut results = unordered(data, 4, blocking_calculate).await;
+    results.sort_by_key(|result| result.0);
+    assert_eq!(results, expected);
+}
+
+#[cfg(feature = "solutions")]
+#[tokio::test(start_paused = true)]
+async fn ticks_like_the_solution() {
+    for count in [0, 1, 10] {
+        let period = Duration::from_millis(30);
+        let ticks: Vec<u64> = Ticks::new(count, period).collect().await;
+        let expected: Vec<u64> = solutions::Ticks::new(count, period).collect().await;
+        assert_eq!(ticks, expected);
+    }
+}
+
+#[cfg(feature = "solutions")]
+#[tokio::test(start_paused = true)]
+async fn calculates_like_the_solution() {
+    /// Like `part_5::calculate`, taking a different time for every datum
+    async fn quick_calculate(datum: Data) -> ComputationResult {
+        tokio::time::sleep(Duration::from_millis(datum.0 % 50)).await;
+        ComputationResult(datum.0 * 2)
+    }
+
+    let data: Vec<Data> = common::datagen::numbers_below(30, 1000, 31)
+        .into_iter()
+        .map(Data)
+        .collect();
+    assert_eq!(
+        serial(data.clone(), quick_calculate).await,
+        solutions::serial(data.clone(), quick_calculate).await
+    );
+    for limit in [1, 4, 100] {
+        assert_eq!(
+            ordered(data.clone(), limit, quick_calculate).await,
+            solutions::ordered(data.clone(), limit, quick_calculate).await
+        );
+        let sorted = |mut results: Vec<ComputationResult>| {
+            results.sort_by_key(|result| result.0);
+            results
+        };
+        assert_eq!(
+            sorted(unordered(data.clone(), limit, quick_calculate).await),
+            sorted(solutions::unordered(data.clone(), limit, quick_calculate).await)
+        );
+    }
+}
@@ -0,0 +1,92 @@
+//! Part 31, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::{stream, Stream, StreamExt};
+use part_5::{ComputationResult, Data};
+use tokio::time::Sleep;
+
+/// A stream yielding `1, 2, ..., count`, one every `period`
+pub struct Ticks {
+    pub count: u64,
+    pub emitted: u64,
+    pub period: Duration,
+    /// Completes when the next tick is due. Boxed, so `Ticks` doesn't have to be pinned.
+    pub sleep: Pin<Box<Sleep>>,
+}
+
+impl Ticks {
+    pub fn new(count: u64, period: Duration) -> Self {
+        Self {
+            count,
+            emitted: 0,
+            period,
+            sleep: Box::pin(tokio::time::sleep(period)),
+        }
+    }
+}
+
+impl Stream for Ticks {
+    type Item = u64;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<u64>> {
+        if self.emitted == self.count {
+            return Poll::Ready(None);
+        }
+        // Not due yet, the sleep wakes us when it is
+        if self.sleep.as_mut().poll(cx).is_pending() {
+            return Poll::Pending;
+        }
+
+        self.emitted += 1;
+        // Counting from the deadline instead of now keeps the ticks from drifting
+        let next = self.sleep.deadline() + self.period;
+        self.sleep.as_mut().reset(next);
+        Poll::Ready(Some(self.emitted))
+    }
+}
+
+/// Calculates the results one at a time, returning them in the same order as the data
+pub async fn serial<F, Fut>(data: Vec<Data>, calculate: F) -> Vec<ComputationResult>
+where
+    F: Fn(Data) -> Fut,
+    Fut: Future<Output = ComputationResult>,
+{
+    // `then` waits for each future before taking the next datum
+    stream::iter(data).then(calculate).collect().await
+}
+
+/// Runs up to `limit` calculations at once, returning the results in the same order as the data
+pub async fn ordered<F, Fut>(data: Vec<Data>, limit: usize, calculate: F) -> Vec<ComputationResult>
+where
+    F: Fn(Data) -> Fut,
+    Fut: Future<Output = ComputationResult>,
+{
+    stream::iter(data)
+        .map(calculate)
+        .buffered(limit)
+        .collect()
+        .await
+}
+
+/// Runs up to `limit` calculations at once, returning the results in the order they finished
+pub async fn unordered<F, Fut>(
+    data: Vec<Data>,
+    limit: usize,
+    calculate: F,
+) -> Vec<ComputationResult>
+where
+    F: Fn(Data) -> Fut,
+    Fut: Future<Output = ComputationResult>,
+{
+    stream::iter(data)
+        .map(calculate)
+        .buffer_unordered(limit)
+        .collect()
+        .await
+}
@@ -0,0 +1,95 @@
+use std::{collections::BTreeMap, thread, time::Duration};
+
+use common::channel::Backend;
+use crossbeam_channel::bounded;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Simulated time it takes to parse a line
+const PARSE_COST: Duration = Duration::from_micros(100);
+/// Simulated time it takes to compute a reading, the slowest stage by far
+const COMPUTE_COST: Duration = Duration::from_micros(500);
+
+/// How many messages each channel between the stages can hold
+pub const CAPACITY: usize = 16;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reading {
+    pub sensor: String,
+    pub value: u64,
+}
+
+/// The aggregated readings of one sensor
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub readings: usize,
+    pub total: u64,
+}
+
+/// Stats for every sensor, by sensor name
+pub type Summary = BTreeMap<String, Stats>;
+
+/// Makes `count` lines of input, looking like `sensor-3,42`.
+/// Every tenth line is garbage, which should be skipped.
+pub fn generate(count: usize) -> Vec<String> {
+    (0..count)
+        .map(|i| {
+            if i % 10 == 9 {
+                "garbage".to_string()
+            } else {
+                format!("sensor-{},{}", i % 4, i * 7 % 100)
+            }
+        })
+        .collect()
+}
+
+/// First stage: parses a line like `sensor-3,42`, returning `None` if it's invalid
+pub fn parse(line: &str) -> Option<Reading> {
+    thread::sleep(PARSE_COST);
+    let (sensor, value) = line.split_once(',')?;
+    Some(Reading {
+        sensor: sensor.to_string(),
+        value: value.trim().parse().ok()?,
+    })
+}
+
+/// Second stage: turns the raw reading into a calibrated one
+pub fn compute(reading: Reading) -> Reading {
+    thread::sleep(COMPUTE_COST);
+    Reading {
+        value: reading.value * reading.value + 1,
+        ..reading
+    }
+}
+
+/// Third stage: adds a calibrated reading to the summary
+pub fn aggregate(summary: &mut Summary, reading: Reading) {
+    let stats = summary.entry(reading.sensor).or_default();
+    stats.readings += 1;
+    stats.total += reading.value;
+}
+
+/// Runs every stage for every line, one after the other on the current thread
+pub fn single_threaded(lines: Vec<String>) -> Summary {
+    let mut summary = Summary::new();
+    for line in lines {
+        if let Some(reading) = parse(&line) {
+            aggregate(&mut summary, compute(reading));
+        }
+    }
+    summary
+}
+
+/// Runs each stage on its own thread, with `compute_workers` threads for the compute stage,
+/// connected by channels holding at most `CAPACITY` messages.
+/// Returns the same summary as `single_threaded`.
+pub fn pipeline(lines: Vec<String>, compute_workers: usize) -> Summary {
+    todo!()
+}
+
+/// Like `pipeline`, over the bounded channels of any `common::channel::Backend`, like std's, whose
+/// receivers can't be cloned
+pub fn pipeline_over<B: Backend>(lines: Vec<String>, compute_workers: usize) -> Summary {
+    todo!()
+}
@@ -0,0 +1,110 @@
+use common::{cli::Cli, timed};
+use part_32::{generate, pipeline, pipeline_over, single_threaded};
+
+fn main() {
+    let args = Cli::new("Summarizes lines single-threaded, and in a pipeline")
+        .items(2000)
+        .parse();
+    let lines = generate(args.items());
+
+    let expected = timed("Single-threaded", || single_threaded(lines.clone()));
+    for workers in [1, 2, 4, 8] {
+        let summary = timed(&format!("Pipeline with {workers} compute workers"), || {
+            pipeline(lines.clone(), workers)
+        });
+        assert_eq!(summary, expected);
+    }
+    let summary = timed(
+        &format!(
+            "Pipeline with 4 compute workers over {}'s channels",
+            <common::channel::Selected as common::channel::Backend>::NAME
+        ),
+        || pipeline_over::<common::channel::Selected>(lines.clone(), 4),
+    );
+    assert_eq!(summary, expected);
+    println!("{expected:#?}");
+}
+
+#[test]
+fn matches_single_threaded() {
+    let lines = generate(200);
+    let expected = single_threaded(lines.clone());
+    for workers in [1, 3, 8] {
+        assert_eq!(pipeline(lines.clone(), workers), expected);
+    }
+}
+
+#[cfg(test)]
+fn matches_single_threaded_over<B: common::channel::Backend>() {
+    let lines = generate(200);
+    let expected = single_threaded(lines.clone());
+    for workers in [1, 3, 8] {
+        assert_eq!(
+            pipeline_over::<B>(lines.clone(), workers),
+            expected,
+            "{workers} workers over {}",
+            B::NAME
+        );
+    }
+    assert!(pipeline_over::<B>(Vec::new(), 2).is_empty(), "{}", B::NAME);
+}
+
+// Std's receivers can't be cloned, so a pipeline which works over them works over crossbeam's too
+#[test]
+fn matches_single_threaded_over_every_backend() {
+    matches_single_threaded_over::<common::channel::Std>();
+    #[cfg(feature = "crossbeam")]
+    matches_single_threaded_over::<common::channel::Crossbeam>();
+}
+
+#[test]
+fn skips_invalid_lines() {
+    use part_32::Stats;
+
+    let lines = ["a,1", "nonsense", "b,2", "a,x", "a,3", ""].map(String::from);
+    let summary = pipeline(lines.to_vec(), 2);
+
+    assert_eq!(summary.len(), 2);
+    assert_eq!(
+        summary["a"],
+        Stats {
+            readings: 2,
+            total: 2 + 10
+        }
+    );
+    assert_eq!(
+        summary["b"],
+        Stats {
+            readings: 1,
+            total: 5
+        }
+    );
+}
+
+#[test]
+fn handles_no_input() {
+    assert!(pipeline(Vec::new(), 4).is_empty());
+}
+
+#[test]
+fn pipeline_has_higher_throughput() {
+    use common::bench::{assert_faster, measure, Measurement};
+
+    let lines = generate(400);
+    assert_eq!(pipeline(lines.clone(), 1), single_threaded(lines.clone()));
+    let single = measure("single-threaded", 3, || single_threaded(lines.clone()));
+    let one_worker = measure("one worker", 3, || pipeline(lines.clone(), 1));
+    let four_workers = measure("four workers", 3, || pipeline(lines.clone(), 4));
+
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    // Parsing overlaps with computing, so even a single compute worker helps a bit
+    assert_faster(&one_worker, &single);
+    // Computing is the bottleneck, so more compute workers help a lot: at least twice as fast
+    let half_single = Measurement {
+        name: "half of single-threaded".into(),
+        runs: single.runs.iter().map(|run| *run / 2).collect(),
+    };
+    assert_faster(&four_workers, &half_single);
+}
@@ -0,0 +1,103 @@
+//! Part 32, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use common::channel::{Backend, Receiver, Sender};
+use crossbeam_channel::bounded;
+
+use super::{aggregate, compute, parse, Summary, CAPACITY};
+
+/// Runs each stage on its own thread, with `compute_workers` threads for the compute stage,
+/// connected by channels holding at most `CAPACITY` messages.
+/// Returns the same summary as `single_threaded`.
+pub fn pipeline(lines: Vec<String>, compute_workers: usize) -> Summary {
+    let (parsed_sender, parsed) = bounded(CAPACITY);
+    let (computed_sender, computed) = bounded(CAPACITY);
+
+    let parser = thread::spawn(move || {
+        for reading in lines.iter().filter_map(|line| parse(line)) {
+            parsed_sender
+                .send(reading)
+                .expect("Compute workers stopped");
+        }
+        // Dropping the sender here tells the compute workers there's nothing more to do
+    });
+
+    // Every worker takes its readings from the same channel, so whoever is free takes the next one
+    let workers: Vec<_> = (0..compute_workers)
+        .map(|_| {
+            let (parsed, computed_sender) = (parsed.clone(), computed_sender.clone());
+            thread::spawn(move || {
+                for reading in parsed {
+                    computed_sender
+                        .send(compute(reading))
+                        .expect("Aggregator stopped");
+                }
+            })
+        })
+        .collect();
+    // Only the workers' senders should keep the channel open
+    drop(computed_sender);
+
+    let aggregator = thread::spawn(move || {
+        let mut summary = Summary::new();
+        for reading in computed {
+            aggregate(&mut summary, reading);
+        }
+        summary
+    });
+
+    parser.join().expect("Parser panicked");
+    for worker in workers {
+        worker.join().expect("Compute worker panicked");
+    }
+    aggregator.join().expect("Aggregator panicked")
+}
+
+pub fn pipeline_over<B: Backend>(lines: Vec<String>, compute_workers: usize) -> Summary {
+    let (parsed_sender, parsed) = B::bounded(CAPACITY);
+    let (computed_sender, computed) = B::bounded(CAPACITY);
+
+    let parser = thread::spawn(move || {
+        for reading in lines.iter().filter_map(|line| parse(line)) {
+            parsed_sender
+                .send(reading)
+                .expect("Compute workers stopped");
+        }
+    });
+
+    // The workers take turns receiving from the one receiver there is
+    let parsed = Arc::new(Mutex::new(parsed));
+    let workers: Vec<_> = (0..compute_workers)
+        .map(|_| {
+            let (parsed, computed_sender) = (parsed.clone(), computed_sender.clone());
+            thread::spawn(move || loop {
+                // Only locked while receiving, so that the others can receive while this computes
+                let Ok(reading) = parsed.lock().unwrap().recv() else {
+                    break;
+                };
+                computed_sender
+                    .send(compute(reading))
+                    .expect("Aggregator stopped");
+            })
+        })
+        .collect();
+    drop(computed_sender);
+
+    let aggregator = thread::spawn(move || {
+        let mut summary = Summary::new();
+        for reading in computed.iter() {
+            aggregate(&mut summary, reading);
+        }
+        summary
+    });
+
+    parser.join().expect("Parser panicked");
+    for worker in workers {
+        worker.join().expect("Compute worker panicked");
+    }
+    aggregator.join().expect("Aggregator panicked")
+}
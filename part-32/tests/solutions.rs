@@ -0,0 +1,43 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-32 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use common::channel::Std;
+use part_32::{pipeline, pipeline_over, solutions};
+
+#[test]
+fn summarizes_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let lines: Vec<String> = common::datagen::numbers(500, rng.seed())
+        .into_iter()
+        .map(|n| match n % 8 {
+            0 => "not a reading".to_string(),
+            _ => format!("sensor-{},{}", n % 5, n % 1000),
+        })
+        .collect();
+    for workers in [1, 4] {
+        let summary: Vec<_> = pipeline(lines.clone(), workers)
+            .into_iter()
+            .map(|(sensor, stats)| (sensor, stats.readings, stats.total))
+            .collect();
+        let expected: Vec<_> = solutions::pipeline(lines.clone(), workers)
+            .into_iter()
+            .map(|(sensor, stats)| (sensor, stats.readings, stats.total))
+            .collect();
+        assert_eq!(summary, expected, "With {workers} compute workers");
+    }
+}
+
+#[test]
+fn summarizes_over_std_like_the_solution() {
+    let lines = part_32::generate(300);
+    let summary: Vec<_> = pipeline_over::<Std>(lines.clone(), 3)
+        .into_iter()
+        .map(|(sensor, stats)| (sensor, stats.readings, stats.total))
+        .collect();
+    let expected: Vec<_> = solutions::pipeline_over::<Std>(lines, 3)
+        .into_iter()
+        .map(|(sensor, stats)| (sensor, stats.readings, stats.total))
+        .collect();
+    assert_eq!(summary, expected);
+}
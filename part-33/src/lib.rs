@@ -0,0 +1,100 @@
+use std::{
+    collections::BTreeMap,
+    sync::mpsc::{self, Receiver},
+    thread,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Puts results that arrive out of order back in order, by their index
+#[derive(Debug)]
+pub struct Reorder<T> {
+    /// The index of the next result to hand out
+    next: usize,
+    /// Results that arrived before the ones in front of them, by index
+    pending: BTreeMap<usize, T>,
+}
+
+impl<T> Reorder<T> {
+    pub fn new() -> Self {
+        Self {
+            next: 0,
+            pending: BTreeMap::new(),
+        }
+    }
+
+    /// Takes the result with the given `index`, and returns every result that's now next in line, in order
+    pub fn push(&mut self, index: usize, value: T) -> Vec<T> {
+        todo!()
+    }
+
+    /// How many results are waiting for the ones in front of them
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+impl<T> Default for Reorder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Receives indexed results from `results` until every sender is gone,
+/// and calls `forward` with each of them in order of their index, as soon as it can
+pub fn collect<T>(results: Receiver<(usize, T)>, forward: impl FnMut(T)) {
+    todo!()
+}
+
+/// Runs `work` for every item on `workers` worker threads, calling `forward` with the results in the same order as the items.
+/// A dispatcher hands the items out to the workers in turn, and a collector puts the results back in order.
+pub fn scatter_gather<T, U>(
+    items: Vec<T>,
+    workers: usize,
+    work: fn(T) -> U,
+    forward: impl FnMut(U) + Send + 'static,
+) where
+    T: Send + 'static,
+    U: Send + 'static,
+{
+    assert!(workers > 0, "Need at least one worker");
+    let (result_sender, results) = mpsc::channel();
+    let (inboxes, handles): (Vec<_>, Vec<_>) = (0..workers)
+        .map(|id| {
+            let (sender, inbox) = mpsc::channel::<(usize, T)>();
+            let result_sender = result_sender.clone();
+            let handle = thread::spawn(move || {
+                let _worker = common::span::worker(id);
+                for (index, item) in inbox {
+                    let _item = common::span::item(index);
+                    result_sender
+                        .send((index, work(item)))
+                        .expect("Collector stopped");
+                }
+            });
+            (sender, handle)
+        })
+        .unzip();
+    // Only the workers should keep the results channel open
+    drop(result_sender);
+
+    let collector = thread::spawn(move || {
+        let _collector = common::span::thread("collector");
+        collect(results, forward)
+    });
+
+    // The dispatcher, handing each item to the next worker in turn
+    for (index, item) in items.into_iter().enumerate() {
+        inboxes[index % workers]
+            .send((index, item))
+            .expect("Worker stopped");
+    }
+    // Lets the workers know there's nothing more to do
+    drop(inboxes);
+
+    for handle in handles {
+        handle.join().expect("Worker panicked");
+    }
+    collector.join().expect("Collector panicked");
+}
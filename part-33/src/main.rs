@@ -0,0 +1,113 @@
+use std::{thread, time::Duration};
+
+use part_33::scatter_gather;
+
+/// Takes longer for small numbers, so later items tend to be done first
+fn slow_square(x: u64) -> u64 {
+    thread::sleep(Duration::from_millis(100 - x * 10));
+    x * x
+}
+
+fn main() {
+    common::init_tracing();
+    let args = common::cli::Cli::new("Squares numbers on workers, and gathers them back in order")
+        .items(10)
+        .threads(4)
+        .parse();
+    let numbers = (0..args.items() as u64).collect();
+    scatter_gather(numbers, args.threads(), slow_square, |square| {
+        println!("Got {square}");
+    });
+}
+
+#[cfg(test)]
+fn gather<T: Send + 'static, U: Send + 'static>(
+    items: Vec<T>,
+    workers: usize,
+    work: fn(T) -> U,
+) -> Vec<U> {
+    use std::sync::{Arc, Mutex};
+
+    let results = Arc::new(Mutex::new(Vec::new()));
+    scatter_gather(items, workers, work, {
+        let results = results.clone();
+        move |result| results.lock().unwrap().push(result)
+    });
+    let results = std::mem::take(&mut *results.lock().unwrap());
+    results
+}
+
+#[test]
+fn reorders_results() {
+    use part_33::Reorder;
+
+    let mut reorder = Reorder::new();
+    assert_eq!(reorder.push(2, 'c'), vec![]);
+    assert_eq!(reorder.push(1, 'b'), vec![]);
+    assert_eq!(reorder.pending(), 2);
+    assert_eq!(reorder.push(0, 'a'), vec!['a', 'b', 'c']);
+    assert_eq!(reorder.pending(), 0);
+    assert_eq!(reorder.push(3, 'd'), vec!['d']);
+    assert_eq!(reorder.push(5, 'f'), vec![]);
+    assert_eq!(reorder.push(4, 'e'), vec!['e', 'f']);
+}
+
+#[test]
+fn collects_in_order() {
+    use std::sync::mpsc;
+
+    let (sender, receiver) = mpsc::channel();
+    for index in [3, 0, 4, 2, 1] {
+        sender.send((index, index * 10)).unwrap();
+    }
+    drop(sender);
+
+    let mut collected = Vec::new();
+    part_33::collect(receiver, |value| collected.push(value));
+    assert_eq!(collected, vec![0, 10, 20, 30, 40]);
+}
+
+#[test]
+fn collect_forwards_as_soon_as_possible() {
+    use std::sync::mpsc;
+
+    let (sender, receiver) = mpsc::channel();
+    let (forwarded, forwarded_receiver) = mpsc::channel();
+    let collector = thread::spawn(move || {
+        part_33::collect(receiver, move |value| forwarded.send(value).unwrap())
+    });
+
+    sender.send((1, 'b')).unwrap();
+    sender.send((0, 'a')).unwrap();
+    // Both can be forwarded before the channel is closed
+    assert_eq!(forwarded_receiver.recv().unwrap(), 'a');
+    assert_eq!(forwarded_receiver.recv().unwrap(), 'b');
+
+    drop(sender);
+    collector.join().unwrap();
+}
+
+#[test]
+fn results_are_in_order_despite_finishing_out_of_order() {
+    let expected: Vec<_> = (0..10).map(|x| x * x).collect();
+    for workers in [1, 3, 4, 10, 16] {
+        assert_eq!(gather((0..10).collect(), workers, slow_square), expected);
+    }
+}
+
+#[test]
+fn works_without_items() {
+    assert_eq!(gather(Vec::<u64>::new(), 4, slow_square), vec![]);
+}
+
+#[test]
+fn many_items() {
+    fn shuffled(x: usize) -> usize {
+        // Sleep for a pseudo-random short time, scrambling the completion order
+        thread::sleep(Duration::from_micros((x * 7919 % 13) as u64 * 50));
+        x + 1
+    }
+
+    let results = gather((0..500).collect(), 8, shuffled);
+    assert_eq!(results, (1..=500).collect::<Vec<_>>());
+}
@@ -0,0 +1,46 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-33 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::sync::mpsc;
+
+use part_33::{collect, solutions, Reorder};
+
+/// The indexes `0..len`, shuffled the same way for the same seed
+fn shuffled(len: usize, seed: u64) -> Vec<usize> {
+    let keys = common::datagen::numbers(len, seed);
+    let mut indexes: Vec<usize> = (0..len).collect();
+    indexes.sort_by_key(|&index| keys[index]);
+    indexes
+}
+
+#[test]
+fn reorders_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (mut reorder, mut expected) = (Reorder::new(), solutions::Reorder::new());
+    for index in shuffled(1000, rng.seed()) {
+        assert_eq!(
+            reorder.push(index, index * 10),
+            expected.push(index, index * 10),
+            "Pushing {index}"
+        );
+        assert_eq!(reorder.pending(), expected.pending());
+    }
+}
+
+#[test]
+fn collects_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (sender, results) = mpsc::channel();
+    let (expected_sender, expected_results) = mpsc::channel();
+    for index in shuffled(1000, rng.seed()) {
+        sender.send((index, index * 10)).unwrap();
+        expected_sender.send((index, index * 10)).unwrap();
+    }
+    drop((sender, expected_sender));
+
+    let (mut collected, mut expected) = (Vec::new(), Vec::new());
+    collect(results, |value| collected.push(value));
+    solutions::collect(expected_results, |value| expected.push(value));
+    assert_eq!(collected, expected);
+}
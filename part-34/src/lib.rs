@@ -0,0 +1,53 @@
+use std::{collections::HashMap, thread};
+
+use common::rng::Rng;
+use rayon::prelude::*;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How often each word appears
+pub type Counts = HashMap<String, usize>;
+
+const WORDS: [&str; 24] = [
+    "the", "a", "thread", "lock", "channel", "data", "race", "mutex", "atomic", "rust", "borrow",
+    "send", "sync", "arc", "spawn", "join", "scope", "future", "await", "task", "pool", "queue",
+    "barrier", "condvar",
+];
+
+/// Generates a text of `words` words, picked from a small vocabulary with a simple pseudo-random generator.
+/// The same `seed` always gives the same text, and some words are much more common than others.
+pub fn generate_corpus(words: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let len = WORDS.len() as u64;
+    let mut text = String::with_capacity(words * 6);
+    for i in 0..words {
+        // Multiplying two picks makes the first words in the list far more likely
+        let word = WORDS[(rng.below(len) * rng.below(len) / len) as usize];
+
+        text.push_str(word);
+        text.push(if i % 12 == 11 { '\n' } else { ' ' });
+    }
+    text
+}
+
+/// Counts the words in `text` on the current thread. Words are separated by whitespace.
+pub fn count_words(text: &str) -> Counts {
+    todo!()
+}
+
+/// Adds the counts in `from` to those in `into`
+pub fn merge(into: &mut Counts, from: Counts) {
+    todo!()
+}
+
+/// Splits the words in `text` into `threads` chunks, counts each chunk on its own thread,
+/// and merges the counts
+pub fn threaded_count(text: &str, threads: usize) -> Counts {
+    todo!()
+}
+
+/// Counts the words using rayon's `fold` and `reduce`
+pub fn rayon_count(text: &str) -> Counts {
+    todo!()
+}
@@ -0,0 +1,73 @@
+use common::{cli::Cli, timed};
+use part_34::{count_words, generate_corpus, rayon_count, threaded_count};
+
+fn main() {
+    let args = Cli::new("Counts words on one thread, on several, and with Rayon")
+        .items(5_000_000)
+        .parse();
+    let text = generate_corpus(args.items(), 42);
+
+    let expected = timed("Single-threaded", || count_words(&text));
+    for threads in [2, 4, 8] {
+        let counts = timed(&format!("{threads} threads"), || {
+            threaded_count(&text, threads)
+        });
+        assert_eq!(counts, expected);
+    }
+    let counts = timed("Rayon", || rayon_count(&text));
+    assert_eq!(counts, expected);
+
+    let mut counts: Vec<_> = expected.into_iter().collect();
+    counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+    for (word, count) in counts.iter().take(5) {
+        println!("{word}: {count}");
+    }
+}
+
+#[test]
+fn counts_words() {
+    let counts = count_words("the thread joined the other thread\nthe end");
+    assert_eq!(counts.len(), 5);
+    assert_eq!(counts["the"], 3);
+    assert_eq!(counts["thread"], 2);
+    assert_eq!(counts["end"], 1);
+    assert!(count_words("  \n ").is_empty());
+}
+
+#[test]
+fn merges_counts() {
+    use part_34::merge;
+
+    let mut into = count_words("a b b");
+    merge(&mut into, count_words("b c"));
+    assert_eq!(into, count_words("a b b b c"));
+}
+
+#[test]
+fn threaded_matches_single_threaded() {
+    let text = generate_corpus(10_000, 1);
+    let expected = count_words(&text);
+    assert_eq!(expected.values().sum::<usize>(), 10_000);
+    for threads in [1, 2, 3, 7, 16] {
+        assert_eq!(
+            threaded_count(&text, threads),
+            expected,
+            "{threads} threads"
+        );
+    }
+}
+
+#[test]
+fn rayon_matches_single_threaded() {
+    let text = generate_corpus(10_000, 2);
+    assert_eq!(rayon_count(&text), count_words(&text));
+}
+
+#[test]
+fn handles_tiny_texts() {
+    for text in ["", "one", "one two"] {
+        let expected = count_words(text);
+        assert_eq!(threaded_count(text, 4), expected);
+        assert_eq!(rayon_count(text), expected);
+    }
+}
@@ -0,0 +1,66 @@
+//! Part 34, with every exercise implemented. Only built with `--features solutions`.
+
+use std::thread;
+
+use rayon::prelude::*;
+
+use super::Counts;
+
+/// Counts the words in `text` on the current thread. Words are separated by whitespace.
+pub fn count_words(text: &str) -> Counts {
+    let mut counts = Counts::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word.to_string()).or_default() += 1;
+    }
+    counts
+}
+
+/// Adds the counts in `from` to those in `into`
+pub fn merge(into: &mut Counts, from: Counts) {
+    for (word, count) in from {
+        *into.entry(word).or_default() += count;
+    }
+}
+
+/// Splits the words in `text` into `threads` chunks, counts each chunk on its own thread,
+/// and merges the counts
+pub fn threaded_count(text: &str, threads: usize) -> Counts {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let chunk_size = words.len().div_ceil(threads).max(1);
+
+    thread::scope(|s| {
+        let handles: Vec<_> = words
+            .chunks(chunk_size)
+            .map(|chunk| {
+                s.spawn(move || {
+                    let mut counts = Counts::new();
+                    for word in chunk {
+                        *counts.entry(word.to_string()).or_default() += 1;
+                    }
+                    counts
+                })
+            })
+            .collect();
+
+        let mut total = Counts::new();
+        for handle in handles {
+            merge(&mut total, handle.join().expect("Counting thread panicked"));
+        }
+        total
+    })
+}
+
+/// Counts the words using rayon's `fold` and `reduce`
+pub fn rayon_count(text: &str) -> Counts {
+    text.par_split_whitespace()
+        // Every rayon job gets its own map to count into
+        .fold(Counts::new, |mut counts, word| {
+            *counts.entry(word.to_string()).or_default() += 1;
+            counts
+        })
+        // And then the maps are merged two at a time
+        .reduce(Counts::new, |mut a, b| {
+            merge(&mut a, b);
+            a
+        })
+}
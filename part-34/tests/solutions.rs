@@ -0,0 +1,33 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-34 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_34::{count_words, generate_corpus, merge, rayon_count, solutions, threaded_count};
+
+#[test]
+fn counts_like_the_solution() {
+    for seed in 0..3 {
+        let text = generate_corpus(5000, seed);
+        let expected = solutions::count_words(&text);
+        assert_eq!(count_words(&text), expected);
+        assert_eq!(rayon_count(&text), expected);
+        for threads in [1, 3, 8] {
+            assert_eq!(
+                threaded_count(&text, threads),
+                expected,
+                "On {threads} threads"
+            );
+        }
+    }
+    assert_eq!(count_words(""), solutions::count_words(""));
+}
+
+#[test]
+fn merges_like_the_solution() {
+    let (first, second) = (generate_corpus(1000, 34), generate_corpus(1000, 35));
+    let mut merged = solutions::count_words(&first);
+    merge(&mut merged, solutions::count_words(&second));
+    let mut expected = solutions::count_words(&first);
+    solutions::merge(&mut expected, solutions::count_words(&second));
+    assert_eq!(merged, expected);
+}
@@ -0,0 +1,94 @@
+use std::{
+    fs, io,
+    iter::Sum,
+    ops::Add,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use rayon::prelude::*;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// What we found out about one or more files
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub lines: usize,
+    /// Adds up the same no matter which order the files are processed in
+    pub checksum: u64,
+}
+
+impl Add for Summary {
+    type Output = Summary;
+
+    fn add(self, other: Summary) -> Summary {
+        Summary {
+            lines: self.lines + other.lines,
+            checksum: self.checksum.wrapping_add(other.checksum),
+        }
+    }
+}
+
+impl Sum for Summary {
+    fn sum<I: Iterator<Item = Summary>>(summaries: I) -> Summary {
+        summaries.fold(Summary::default(), Add::add)
+    }
+}
+
+/// Writes `files` files of `lines` numbers each to `dir`, returning their paths
+pub fn generate_files(dir: &Path, files: usize, lines: usize) -> io::Result<Vec<PathBuf>> {
+    (0..files)
+        .map(|file| {
+            let path = dir.join(format!("numbers-{file}.txt"));
+            let contents: String = (0..lines)
+                .map(|line| format!("{}\n", (file * 7919 + line * 104729) % 1_000_003))
+                .collect();
+            fs::write(&path, contents)?;
+            Ok(path)
+        })
+        .collect()
+}
+
+/// Sums up the numbers in a file's contents, scrambling each number `rounds` times first,
+/// so more rounds means more CPU work per file
+pub fn process(contents: &str, rounds: u32) -> Summary {
+    let mut summary = Summary::default();
+    for line in contents.lines() {
+        let mut value: u64 = line.trim().parse().unwrap_or(0);
+        for _ in 0..rounds {
+            value = value.rotate_left(5) ^ 0x9e3779b97f4a7c15;
+        }
+        summary.lines += 1;
+        summary.checksum = summary.checksum.wrapping_add(value);
+    }
+    summary
+}
+
+/// Reads a file and processes it
+pub fn process_file(path: &Path, rounds: u32) -> io::Result<Summary> {
+    Ok(process(&fs::read_to_string(path)?, rounds))
+}
+
+/// Processes the files one after the other
+pub fn serial(paths: &[PathBuf], rounds: u32) -> io::Result<Summary> {
+    let mut total = Summary::default();
+    for path in paths {
+        total = total + process_file(path, rounds)?;
+    }
+    Ok(total)
+}
+
+/// Processes the files on `threads` threads, where each thread takes the next file
+/// that nobody has taken yet until there are none left.
+/// Returns the first error if any file couldn't be read.
+pub fn pooled(paths: &[PathBuf], threads: usize, rounds: u32) -> io::Result<Summary> {
+    todo!()
+}
+
+/// Processes the files with rayon.
+/// Returns an error if any file couldn't be read.
+pub fn rayon_process(paths: &[PathBuf], rounds: u32) -> io::Result<Summary> {
+    todo!()
+}
@@ -0,0 +1,74 @@
+use common::{cli::Cli, timed};
+use part_35::{generate_files, pooled, rayon_process, serial};
+
+fn main() -> std::io::Result<()> {
+    let args = Cli::new("Processes files serially, on a thread pool, and with Rayon")
+        .items(200)
+        .parse();
+    let dir = tempfile::tempdir()?;
+    let paths = generate_files(dir.path(), args.items(), 5000)?;
+
+    // Without any rounds the time goes to reading and parsing, with many it goes to computing
+    for (name, rounds) in [("Mostly I/O", 0), ("Mostly CPU", 200)] {
+        println!("{name}:");
+        let expected = timed("  Serial", || serial(&paths, rounds))?;
+        for threads in [2, 4, 16] {
+            let summary = timed(&format!("  {threads} threads"), || {
+                pooled(&paths, threads, rounds)
+            })?;
+            assert_eq!(summary, expected);
+        }
+        let summary = timed("  Rayon", || rayon_process(&paths, rounds))?;
+        assert_eq!(summary, expected);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+fn fixture(files: usize) -> (tempfile::TempDir, Vec<std::path::PathBuf>) {
+    let dir = tempfile::tempdir().expect("Couldn't create a temporary directory");
+    let paths = generate_files(dir.path(), files, 100).expect("Couldn't write files");
+    // Returning the directory keeps it alive, it's deleted when dropped
+    (dir, paths)
+}
+
+#[test]
+fn pooled_matches_serial() {
+    let (_dir, paths) = fixture(50);
+    let expected = serial(&paths, 10).unwrap();
+    assert_eq!(expected.lines, 50 * 100);
+    for threads in [1, 2, 7, 64] {
+        assert_eq!(
+            pooled(&paths, threads, 10).unwrap(),
+            expected,
+            "{threads} threads"
+        );
+    }
+}
+
+#[test]
+fn rayon_matches_serial() {
+    let (_dir, paths) = fixture(50);
+    assert_eq!(
+        rayon_process(&paths, 10).unwrap(),
+        serial(&paths, 10).unwrap()
+    );
+}
+
+#[test]
+fn no_files() {
+    use part_35::Summary;
+
+    assert_eq!(pooled(&[], 4, 10).unwrap(), Summary::default());
+    assert_eq!(rayon_process(&[], 10).unwrap(), Summary::default());
+}
+
+#[test]
+fn missing_files_are_errors() {
+    let (dir, mut paths) = fixture(10);
+    paths.insert(5, dir.path().join("does-not-exist.txt"));
+
+    assert!(serial(&paths, 10).is_err());
+    assert!(pooled(&paths, 4, 10).is_err());
+    assert!(rayon_process(&paths, 10).is_err());
+}
@@ -0,0 +1,51 @@
+//! Part 35, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    io,
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use rayon::prelude::*;
+
+use super::{process_file, Summary};
+
+/// Processes the files on `threads` threads, where each thread takes the next file
+/// that nobody has taken yet until there are none left.
+/// Returns the first error if any file couldn't be read.
+pub fn pooled(paths: &[PathBuf], threads: usize, rounds: u32) -> io::Result<Summary> {
+    // The index of the next file nobody has taken yet
+    let next = AtomicUsize::new(0);
+
+    thread::scope(|s| {
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                s.spawn(|| {
+                    let mut total = Summary::default();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(path) = paths.get(index) else {
+                            return Ok(total);
+                        };
+                        total = total + process_file(path, rounds)?;
+                    }
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("Worker panicked"))
+            .sum::<io::Result<Summary>>()
+    })
+}
+
+/// Processes the files with rayon.
+/// Returns an error if any file couldn't be read.
+pub fn rayon_process(paths: &[PathBuf], rounds: u32) -> io::Result<Summary> {
+    paths
+        .par_iter()
+        .map(|path| process_file(path, rounds))
+        .try_reduce(Summary::default, |a, b| Ok(a + b))
+}
@@ -0,0 +1,34 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-35 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_35::{generate_files, pooled, rayon_process, solutions};
+
+#[test]
+fn processes_like_the_solution() {
+    let dir = tempfile::tempdir().expect("Couldn't create a temporary directory");
+    let mut paths = generate_files(dir.path(), 12, 200).expect("Couldn't write the files");
+    let expected = solutions::pooled(&paths, 3, 10).unwrap();
+    let expected = (expected.lines, expected.checksum);
+
+    for threads in [1, 3, 16] {
+        let summary = pooled(&paths, threads, 10).unwrap();
+        assert_eq!(
+            (summary.lines, summary.checksum),
+            expected,
+            "On {threads} threads"
+        );
+    }
+    let summary = rayon_process(&paths, 10).unwrap();
+    assert_eq!((summary.lines, summary.checksum), expected);
+
+    paths.push(dir.path().join("missing.txt"));
+    assert_eq!(
+        pooled(&paths, 3, 10).is_err(),
+        solutions::pooled(&paths, 3, 10).is_err()
+    );
+    assert_eq!(
+        rayon_process(&paths, 10).is_err(),
+        solutions::rayon_process(&paths, 10).is_err()
+    );
+}
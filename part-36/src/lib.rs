@@ -0,0 +1,50 @@
+use std::{
+    collections::HashSet,
+    io::{self, BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+    thread::{self, ThreadId},
+};
+
+use part_16::ThreadPool;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Keeps track of which threads have handled connections
+#[derive(Debug, Default)]
+pub struct Stats {
+    handler_threads: Mutex<HashSet<ThreadId>>,
+}
+
+impl Stats {
+    /// How many different threads have handled connections
+    pub fn threads(&self) -> usize {
+        self.handler_threads.lock().unwrap().len()
+    }
+}
+
+/// Sends every line received on `stream` straight back, until the client closes the connection
+pub fn handle(stream: TcpStream, stats: &Stats) -> io::Result<()> {
+    stats
+        .handler_threads
+        .lock()
+        .unwrap()
+        .insert(thread::current().id());
+
+    let mut writer = stream.try_clone()?;
+    for line in BufReader::new(stream).lines() {
+        writeln!(writer, "{}", line?)?;
+    }
+    Ok(())
+}
+
+/// Accepts connections on `listener` forever, handling each of them on a new thread
+pub fn serve_per_connection(listener: TcpListener, stats: Arc<Stats>) {
+    todo!()
+}
+
+/// Accepts connections on `listener` forever, handling them on a pool of `threads` threads
+pub fn serve_pooled(listener: TcpListener, threads: usize, stats: Arc<Stats>) {
+    todo!()
+}
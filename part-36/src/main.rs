@@ -0,0 +1,21 @@
+use std::{net::TcpListener, sync::Arc};
+
+use part_36::{serve_per_connection, serve_pooled};
+
+/// Run with `cargo run -p part-36 -- pooled` to use the thread pool.
+/// Connect with e.g. `nc localhost 7878`, and everything you type is echoed back.
+fn main() -> std::io::Result<()> {
+    let args = common::cli::Cli::new("Serves connections on port 7878")
+        .threads(4)
+        .positional("mode", "`pooled` to serve on a pool of --threads threads")
+        .parse();
+    let listener = TcpListener::bind("127.0.0.1:7878")?;
+    println!("Listening on {}", listener.local_addr()?);
+
+    if args.positional("mode") == Some("pooled") {
+        serve_pooled(listener, args.threads(), Arc::default());
+    } else {
+        serve_per_connection(listener, Arc::default());
+    }
+    Ok(())
+}
@@ -0,0 +1,45 @@
+//! Part 36, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    net::TcpListener,
+    sync::Arc,
+    thread::{self},
+};
+
+use part_16::solutions::ThreadPool;
+
+use super::{handle, Stats};
+
+/// Accepts connections on `listener` forever, handling each of them on a new thread
+pub fn serve_per_connection(listener: TcpListener, stats: Arc<Stats>) {
+    for stream in listener.incoming() {
+        let stats = stats.clone();
+        match stream {
+            Ok(stream) => {
+                thread::spawn(move || {
+                    if let Err(error) = handle(stream, &stats) {
+                        eprintln!("Connection failed: {error}");
+                    }
+                });
+            }
+            // A failed connection shouldn't take down the whole server
+            Err(error) => eprintln!("Couldn't accept connection: {error}"),
+        }
+    }
+}
+
+/// Accepts connections on `listener` forever, handling them on a pool of `threads` threads
+pub fn serve_pooled(listener: TcpListener, threads: usize, stats: Arc<Stats>) {
+    let pool = ThreadPool::new(threads);
+    for stream in listener.incoming() {
+        let stats = stats.clone();
+        match stream {
+            Ok(stream) => pool.execute(move || {
+                if let Err(error) = handle(stream, &stats) {
+                    eprintln!("Connection failed: {error}");
+                }
+            }),
+            Err(error) => eprintln!("Couldn't accept connection: {error}"),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{Arc, Barrier},
+    thread,
+};
+
+use part_36::{serve_per_connection, serve_pooled, Stats};
+
+const CLIENTS: usize = 50;
+
+/// Starts a server on a free port in the background, returning its address.
+/// The server runs until the tests are done.
+fn start(serve: impl FnOnce(TcpListener, Arc<Stats>) + Send + 'static) -> (SocketAddr, Arc<Stats>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Couldn't bind");
+    let addr = listener.local_addr().unwrap();
+    let stats = Arc::new(Stats::default());
+    thread::spawn({
+        let stats = stats.clone();
+        move || serve(listener, stats)
+    });
+    (addr, stats)
+}
+
+/// Connects, sends a few lines and checks that each of them comes back
+fn client(addr: SocketAddr, id: usize) {
+    let mut stream = TcpStream::connect(addr).expect("Couldn't connect");
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    for message in 0..3 {
+        let line = format!("client {id} says {message}");
+        writeln!(stream, "{line}").unwrap();
+
+        let mut echo = String::new();
+        reader.read_line(&mut echo).unwrap();
+        assert_eq!(echo.trim_end(), line);
+    }
+}
+
+/// Runs `CLIENTS` clients at the same time
+fn run_clients(addr: SocketAddr) {
+    let handles: Vec<_> = (0..CLIENTS)
+        .map(|id| thread::spawn(move || client(addr, id)))
+        .collect();
+    for handle in handles {
+        handle.join().expect("Client failed");
+    }
+}
+
+#[test]
+fn per_connection_echoes() {
+    let (addr, _) = start(serve_per_connection);
+    run_clients(addr);
+}
+
+#[test]
+fn per_connection_uses_a_thread_per_connection() {
+    let (addr, stats) = start(serve_per_connection);
+
+    // Keep every connection open until all of them have been echoed once
+    let barrier = Arc::new(Barrier::new(CLIENTS));
+    let handles: Vec<_> = (0..CLIENTS)
+        .map(|id| {
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                let mut stream = TcpStream::connect(addr).unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                writeln!(stream, "hello from {id}").unwrap();
+                let mut echo = String::new();
+                reader.read_line(&mut echo).unwrap();
+                barrier.wait();
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(stats.threads(), CLIENTS);
+}
+
+#[test]
+fn pooled_echoes() {
+    let (addr, _) = start(|listener, stats| serve_pooled(listener, 4, stats));
+    run_clients(addr);
+}
+
+#[test]
+fn pooled_uses_a_bounded_number_of_threads() {
+    let (addr, stats) = start(|listener, stats| serve_pooled(listener, 4, stats));
+    run_clients(addr);
+
+    assert!(stats.threads() <= 4, "Used {} threads", stats.threads());
+}
@@ -0,0 +1,75 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-36 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::Arc,
+    thread,
+};
+
+use part_36::{serve_per_connection, serve_pooled, solutions, Stats};
+
+/// Starts a server on a free port in the background, returning its address
+fn start(serve: impl FnOnce(TcpListener) + Send + 'static) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Couldn't bind");
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || serve(listener));
+    addr
+}
+
+/// Sends every one of `lines` on a connection of its own, returning what came back
+fn echoes(addr: SocketAddr, lines: &[String]) -> Vec<String> {
+    let clients: Vec<_> = lines
+        .iter()
+        .cloned()
+        .map(|line| {
+            thread::spawn(move || {
+                let mut stream = TcpStream::connect(addr).expect("Couldn't connect");
+                writeln!(stream, "{line}").unwrap();
+                let mut echo = String::new();
+                BufReader::new(stream).read_line(&mut echo).unwrap();
+                echo
+            })
+        })
+        .collect();
+    clients
+        .into_iter()
+        .map(|client| client.join().expect("Client failed"))
+        .collect()
+}
+
+#[test]
+fn echoes_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let lines: Vec<String> = common::datagen::numbers(20, rng.seed())
+        .into_iter()
+        .map(|n| format!("message {n:x}"))
+        .collect();
+    let expected = echoes(
+        start(|listener| solutions::serve_pooled(listener, 4, Default::default())),
+        &lines,
+    );
+    assert_eq!(
+        echoes(
+            start(|listener| serve_per_connection(listener, Arc::new(Stats::default()))),
+            &lines
+        ),
+        expected
+    );
+    assert_eq!(
+        echoes(
+            start(|listener| serve_pooled(listener, 4, Arc::new(Stats::default()))),
+            &lines
+        ),
+        expected
+    );
+    assert_eq!(
+        echoes(
+            start(|listener| solutions::serve_per_connection(listener, Default::default())),
+            &lines
+        ),
+        expected
+    );
+}
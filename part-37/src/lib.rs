@@ -0,0 +1,27 @@
+use std::io;
+
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    sync::watch,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// What a client is told before its connection is closed by a shutdown
+pub const GOODBYE: &str = "Server is shutting down";
+
+/// Sends every line received on `stream` straight back, until the client closes the connection
+/// or `shutdown` is signalled. A line that has been read is always echoed before shutting down,
+/// and the client is told about the shutdown with [`GOODBYE`].
+pub async fn handle(stream: TcpStream, mut shutdown: watch::Receiver<()>) -> io::Result<()> {
+    todo!()
+}
+
+/// Accepts connections on `listener`, handling each of them in a new task, until `shutdown` is
+/// signalled or its sender is dropped. It then waits for every connection to finish, and returns
+/// how many connections it has served.
+pub async fn serve(listener: TcpListener, mut shutdown: watch::Receiver<()>) -> usize {
+    todo!()
+}
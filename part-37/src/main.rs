@@ -0,0 +1,20 @@
+use tokio::{net::TcpListener, sync::watch};
+
+use part_37::serve;
+
+/// Connect with e.g. `nc localhost 7878`, and everything you type is echoed back.
+/// Press Ctrl-C to shut the server down, which also closes every open connection.
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    let listener = TcpListener::bind("127.0.0.1:7878").await?;
+    println!("Listening on {}", listener.local_addr()?);
+
+    let (shutdown, signal) = watch::channel(());
+    let server = tokio::spawn(serve(listener, signal));
+
+    tokio::signal::ctrl_c().await?;
+    println!("Shutting down");
+    shutdown.send(()).unwrap();
+    println!("Served {} connections", server.await.unwrap());
+    Ok(())
+}
@@ -0,0 +1,60 @@
+//! Part 37, with every exercise implemented. Only built with `--features solutions`.
+
+use std::io;
+
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    sync::watch,
+    task::JoinSet,
+};
+
+use super::GOODBYE;
+
+/// Sends every line received on `stream` straight back, until the client closes the connection
+/// or `shutdown` is signalled. A line that has been read is always echoed before shutting down,
+/// and the client is told about the shutdown with [`GOODBYE`].
+pub async fn handle(stream: TcpStream, mut shutdown: watch::Receiver<()>) -> io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+    loop {
+        tokio::select! {
+            _ = shutdown.changed() => {
+                writer.write_all(format!("{GOODBYE}\n").as_bytes()).await?;
+                return Ok(());
+            }
+            line = lines.next_line() => match line? {
+                Some(line) => writer.write_all(format!("{line}\n").as_bytes()).await?,
+                None => return Ok(()),
+            },
+        }
+    }
+}
+
+/// Accepts connections on `listener`, handling each of them in a new task, until `shutdown` is
+/// signalled or its sender is dropped. It then waits for every connection to finish, and returns
+/// how many connections it has served.
+pub async fn serve(listener: TcpListener, mut shutdown: watch::Receiver<()>) -> usize {
+    let mut connections = JoinSet::new();
+    loop {
+        tokio::select! {
+            _ = shutdown.changed() => break,
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    connections.spawn(handle(stream, shutdown.clone()));
+                }
+                Err(error) => eprintln!("Couldn't accept a connection: {error}"),
+            },
+        }
+    }
+    drop(listener);
+
+    let mut served = 0;
+    while let Some(result) = connections.join_next().await {
+        if let Ok(Err(error)) = result {
+            eprintln!("Connection failed: {error}");
+        }
+        served += 1;
+    }
+    served
+}
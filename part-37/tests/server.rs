@@ -0,0 +1,146 @@
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines},
+    net::{tcp::OwnedReadHalf, tcp::OwnedWriteHalf, TcpListener, TcpStream},
+    sync::{watch, Barrier},
+    task::JoinHandle,
+    time::timeout,
+};
+
+use part_37::{serve, GOODBYE};
+
+const CLIENTS: usize = 100;
+
+/// Starts a server on a free port in a new task, returning its address, the sender to shut it
+/// down with, and the server task
+async fn start() -> (SocketAddr, watch::Sender<()>, JoinHandle<usize>) {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Couldn't bind");
+    let addr = listener.local_addr().unwrap();
+    let (shutdown, signal) = watch::channel(());
+    let server = tokio::spawn(serve(listener, signal));
+    (addr, shutdown, server)
+}
+
+/// A connected client, reading the server's responses line by line
+struct Client {
+    lines: Lines<BufReader<OwnedReadHalf>>,
+    writer: OwnedWriteHalf,
+}
+
+impl Client {
+    async fn connect(addr: SocketAddr) -> Self {
+        let (reader, writer) = TcpStream::connect(addr)
+            .await
+            .expect("Couldn't connect")
+            .into_split();
+        Self {
+            lines: BufReader::new(reader).lines(),
+            writer,
+        }
+    }
+
+    /// The next line from the server, or `None` if it closed the connection
+    async fn next_line(&mut self) -> Option<String> {
+        self.lines.next_line().await.unwrap()
+    }
+
+    /// Sends `line` and checks that it comes back
+    async fn echo(&mut self, line: &str) {
+        self.writer
+            .write_all(format!("{line}\n").as_bytes())
+            .await
+            .unwrap();
+        assert_eq!(self.next_line().await.as_deref(), Some(line));
+    }
+}
+
+#[tokio::test]
+async fn echoes_many_clients() {
+    let (addr, _shutdown, _) = start().await;
+
+    let clients: Vec<_> = (0..CLIENTS)
+        .map(|id| {
+            tokio::spawn(async move {
+                let mut client = Client::connect(addr).await;
+                for message in 0..3 {
+                    client.echo(&format!("client {id} says {message}")).await;
+                }
+            })
+        })
+        .collect();
+    for client in clients {
+        client.await.expect("Client failed");
+    }
+}
+
+#[tokio::test]
+async fn handles_connections_concurrently() {
+    let (addr, _shutdown, _) = start().await;
+
+    // Every connection stays open until all of them have been echoed once,
+    // which never happens if the server handles one connection at a time
+    let barrier = Arc::new(Barrier::new(CLIENTS));
+    let clients: Vec<_> = (0..CLIENTS)
+        .map(|id| {
+            let barrier = barrier.clone();
+            tokio::spawn(async move {
+                let mut client = Client::connect(addr).await;
+                client.echo(&format!("hello from {id}")).await;
+                barrier.wait().await;
+                client.echo(&format!("goodbye from {id}")).await;
+            })
+        })
+        .collect();
+
+    timeout(Duration::from_secs(10), async {
+        for client in clients {
+            client.await.expect("Client failed");
+        }
+    })
+    .await
+    .expect("The connections weren't handled at the same time");
+}
+
+#[tokio::test]
+async fn shutdown_drains_open_connections() {
+    let (addr, shutdown, server) = start().await;
+
+    let mut clients = Vec::new();
+    for id in 0..CLIENTS {
+        let mut client = Client::connect(addr).await;
+        client.echo(&format!("hello from {id}")).await;
+        clients.push(client);
+    }
+
+    shutdown.send(()).unwrap();
+    for client in &mut clients {
+        assert_eq!(client.next_line().await.as_deref(), Some(GOODBYE));
+        assert_eq!(client.next_line().await, None);
+    }
+
+    let served = timeout(Duration::from_secs(10), server)
+        .await
+        .expect("The server didn't stop after every connection was closed")
+        .unwrap();
+    assert_eq!(served, CLIENTS);
+}
+
+#[tokio::test]
+async fn dropping_the_sender_shuts_down() {
+    let (addr, shutdown, server) = start().await;
+    Client::connect(addr).await.echo("hello").await;
+
+    drop(shutdown);
+    let served = timeout(Duration::from_secs(10), server)
+        .await
+        .expect("The server didn't stop")
+        .unwrap();
+    assert_eq!(served, 1);
+    assert!(
+        TcpStream::connect(addr).await.is_err(),
+        "The server still accepts connections after shutting down"
+    );
+}
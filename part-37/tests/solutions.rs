@@ -0,0 +1,67 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-37 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{future::Future, time::Duration};
+
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    sync::watch,
+    time::timeout,
+};
+
+use part_37::{serve, solutions};
+
+/// Connects `lines.len()` clients to a server started with `serve`, which each send one of `lines`
+/// and then wait for the shutdown. Returns everything each client got, and what the server returned.
+async fn session<F, Fut>(serve: F, lines: &[String]) -> (Vec<Vec<String>>, usize)
+where
+    F: FnOnce(TcpListener, watch::Receiver<()>) -> Fut,
+    Fut: Future<Output = usize> + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (shutdown, signal) = watch::channel(());
+    let server = tokio::spawn(serve(listener, signal));
+
+    let mut clients = Vec::new();
+    for line in lines {
+        let (reader, mut writer) = TcpStream::connect(addr).await.unwrap().into_split();
+        let mut reader = BufReader::new(reader).lines();
+        writer
+            .write_all(format!("{line}\n").as_bytes())
+            .await
+            .unwrap();
+        // Wait for the echo, so the connection is surely being handled before the shutdown
+        let echo = reader.next_line().await.unwrap().unwrap();
+        clients.push((vec![echo], reader, writer));
+    }
+    shutdown.send(()).unwrap();
+
+    let mut received = Vec::new();
+    for (mut lines, mut reader, _writer) in clients {
+        while let Ok(Ok(Some(line))) = timeout(Duration::from_secs(5), reader.next_line()).await {
+            lines.push(line);
+        }
+        received.push(lines);
+    }
+    let served = timeout(Duration::from_secs(5), server)
+        .await
+        .expect("The server didn't shut down")
+        .unwrap();
+    (received, served)
+}
+
+#[tokio::test]
+async fn serves_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let lines: Vec<String> = common::datagen::numbers(10, rng.seed())
+        .into_iter()
+        .map(|n| format!("message {n:x}"))
+        .collect();
+    assert_eq!(
+        session(serve, &lines).await,
+        session(solutions::serve, &lines).await
+    );
+}
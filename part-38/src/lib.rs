@@ -0,0 +1,41 @@
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Wraps a value so that it gets a whole cache line to itself.
+/// Cache lines are 64 bytes on most CPUs, though some use 128.
+#[derive(Debug, Default)]
+pub struct Padded<T>(pub T);
+
+/// Lets `threads` threads each increment their own counter `increments` times,
+/// with all the counters next to each other in memory. Returns the counters.
+pub fn adjacent(threads: usize, increments: u64) -> Vec<u64> {
+    let counters: Vec<AtomicU64> = (0..threads).map(|_| AtomicU64::new(0)).collect();
+    thread::scope(|s| {
+        for (id, counter) in counters.iter().enumerate() {
+            s.spawn(move || {
+                let _worker = common::span::worker(id);
+                for _ in 0..increments {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+    counters.into_iter().map(AtomicU64::into_inner).collect()
+}
+
+/// Lets `threads` threads each increment their own counter `increments` times,
+/// with every counter on its own cache line. Returns the counters.
+pub fn padded(threads: usize, increments: u64) -> Vec<u64> {
+    todo!()
+}
+
+/// Lets `threads` threads each count to `increments` on their own,
+/// only writing the result to their shared counter once they're done. Returns the counters.
+pub fn per_thread(threads: usize, increments: u64) -> Vec<u64> {
+    todo!()
+}
@@ -0,0 +1,80 @@
+use std::{mem, sync::atomic::AtomicU64};
+
+use common::{cli::Cli, timed};
+use part_38::{adjacent, padded, per_thread, Padded};
+
+/// Run with `cargo run --release -p part-38` to see the difference
+fn main() {
+    common::init_tracing();
+    let args = Cli::new("Counts on adjacent counters, on padded ones, and on each thread's own")
+        .items(100_000_000)
+        .threads(std::thread::available_parallelism().map_or(4, |n| n.get()))
+        .parse();
+    let (threads, increments) = (args.threads(), args.items() as u64);
+    println!(
+        "A counter takes {} bytes, and a padded one {} bytes",
+        mem::size_of::<AtomicU64>(),
+        mem::size_of::<Padded<AtomicU64>>()
+    );
+    println!("Counting to {increments} with {threads} threads");
+
+    let expected = vec![increments; threads];
+    assert_eq!(
+        timed("Adjacent", || adjacent(threads, increments)),
+        expected
+    );
+    assert_eq!(timed("Padded", || padded(threads, increments)), expected);
+    assert_eq!(
+        timed("Per thread", || per_thread(threads, increments)),
+        expected
+    );
+}
+
+#[test]
+fn adjacent_counts() {
+    assert_eq!(adjacent(4, 10_000), vec![10_000; 4]);
+}
+
+#[test]
+fn padded_counts() {
+    assert_eq!(padded(4, 10_000), vec![10_000; 4]);
+}
+
+#[test]
+fn per_thread_counts() {
+    assert_eq!(per_thread(4, 10_000), vec![10_000; 4]);
+}
+
+#[test]
+fn padded_values_are_on_separate_cache_lines() {
+    assert!(mem::align_of::<Padded<AtomicU64>>() >= 64);
+    assert!(mem::size_of::<Padded<AtomicU64>>() >= 64);
+
+    let counters: Vec<Padded<AtomicU64>> = (0..2).map(|_| Padded::default()).collect();
+    let first = &counters[0].0 as *const AtomicU64 as usize;
+    let second = &counters[1].0 as *const AtomicU64 as usize;
+    assert_eq!(first / 64 + 1, second / 64);
+}
+
+#[test]
+fn padding_is_faster() {
+    use common::{
+        bench::{assert_faster, measure},
+        ensure_can_run_parallel_test,
+    };
+    ensure_can_run_parallel_test();
+
+    // False sharing only happens when the threads run on different cores at the same time
+    let threads = std::thread::available_parallelism().unwrap().get().min(4);
+    let increments = 10_000_000;
+
+    let adjacent_runs = measure("adjacent", 3, || adjacent(threads, increments));
+    let padded_runs = measure("padded", 3, || padded(threads, increments));
+    let per_thread_runs = measure("per thread", 3, || per_thread(threads, increments));
+
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    assert_faster(&padded_runs, &adjacent_runs);
+    assert_faster(&per_thread_runs, &adjacent_runs);
+}
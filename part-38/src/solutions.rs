@@ -0,0 +1,47 @@
+//! Part 38, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    hint,
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+};
+
+use super::Padded;
+
+/// Lets `threads` threads each increment their own counter `increments` times,
+/// with every counter on its own cache line. Returns the counters.
+pub fn padded(threads: usize, increments: u64) -> Vec<u64> {
+    let counters: Vec<Padded<AtomicU64>> = (0..threads).map(|_| Padded::default()).collect();
+    thread::scope(|s| {
+        for counter in &counters {
+            s.spawn(move || {
+                for _ in 0..increments {
+                    counter.0.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+    counters
+        .into_iter()
+        .map(|counter| counter.0.into_inner())
+        .collect()
+}
+
+/// Lets `threads` threads each count to `increments` on their own,
+/// only writing the result to their shared counter once they're done. Returns the counters.
+pub fn per_thread(threads: usize, increments: u64) -> Vec<u64> {
+    let counters: Vec<AtomicU64> = (0..threads).map(|_| AtomicU64::new(0)).collect();
+    thread::scope(|s| {
+        for counter in &counters {
+            s.spawn(move || {
+                let mut count = 0;
+                for _ in 0..increments {
+                    // Keeps the compiler from replacing the loop with `count = increments`
+                    count = hint::black_box(count + 1);
+                }
+                counter.store(count, Ordering::Relaxed);
+            });
+        }
+    });
+    counters.into_iter().map(AtomicU64::into_inner).collect()
+}
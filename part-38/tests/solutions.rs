@@ -0,0 +1,33 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-38 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::mem;
+
+use part_38::{padded, per_thread, solutions, Padded};
+
+#[test]
+fn counts_like_the_solution() {
+    for (threads, increments) in [(1, 0), (2, 1000), (8, 100_000)] {
+        assert_eq!(
+            padded(threads, increments),
+            solutions::padded(threads, increments)
+        );
+        assert_eq!(
+            per_thread(threads, increments),
+            solutions::per_thread(threads, increments)
+        );
+    }
+}
+
+#[test]
+fn pads_like_the_solution() {
+    assert_eq!(
+        mem::align_of::<Padded<u64>>(),
+        mem::align_of::<Padded<u64>>()
+    );
+    assert_eq!(
+        mem::size_of::<Padded<[u64; 3]>>(),
+        mem::size_of::<Padded<[u64; 3]>>()
+    );
+}
@@ -0,0 +1,71 @@
+use std::{cell::RefCell, rc::Rc, sync::Mutex};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// A log which every clone adds its entries to
+#[derive(Debug, Clone, Default)]
+pub struct SharedLog {
+    entries: Rc<RefCell<Vec<String>>>,
+}
+
+impl SharedLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&self, entry: impl Into<String>) {
+        self.entries.borrow_mut().push(entry.into());
+    }
+
+    /// Every entry so far, in the order they were added
+    pub fn entries(&self) -> Vec<String> {
+        self.entries.borrow().clone()
+    }
+}
+
+/// Locks `numbers` once, and doubles every one of them using `threads` scoped threads
+pub fn double_locked(numbers: &Mutex<Vec<u64>>, threads: usize) {
+    todo!()
+}
+
+/// A fixed number of numbers in memory managed by hand,
+/// like a buffer handed out by a C library
+#[derive(Debug)]
+pub struct RawBuffer {
+    ptr: *mut u64,
+    len: usize,
+}
+
+impl RawBuffer {
+    /// Allocates `len` zeroes
+    pub fn new(len: usize) -> Self {
+        let ptr = Box::into_raw(vec![0u64; len].into_boxed_slice()).cast::<u64>();
+        Self { ptr, len }
+    }
+
+    /// Panics if `index` is out of bounds
+    pub fn get(&self, index: usize) -> u64 {
+        assert!(index < self.len, "Index {index} out of bounds");
+        // SAFETY: `ptr` points to `len` numbers, which live until `self` is dropped
+        unsafe { *self.ptr.add(index) }
+    }
+
+    /// Panics if `index` is out of bounds
+    pub fn set(&mut self, index: usize, value: u64) {
+        assert!(index < self.len, "Index {index} out of bounds");
+        // SAFETY: As in `get`, and `&mut self` makes sure no one else is using them
+        unsafe { *self.ptr.add(index) = value }
+    }
+}
+
+impl Drop for RawBuffer {
+    fn drop(&mut self) {
+        // SAFETY: `ptr` and `len` came from the boxed slice in `new`, which is only freed here
+        unsafe {
+            drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+                self.ptr, self.len,
+            )))
+        }
+    }
+}
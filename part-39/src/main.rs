@@ -0,0 +1,58 @@
+use std::sync::Mutex;
+
+use part_39::{double_locked, RawBuffer, SharedLog};
+
+fn main() {
+    let log = SharedLog::new();
+    log.clone().push("Hello from a clone");
+    log.push("Hello from the original");
+    println!("{:?}", log.entries());
+
+    let numbers = Mutex::new((0..10).collect());
+    double_locked(&numbers, 3);
+    println!("Doubled: {:?}", numbers.lock().unwrap());
+
+    let mut buffer = RawBuffer::new(3);
+    buffer.set(1, 42);
+    println!("{}, {}, {}", buffer.get(0), buffer.get(1), buffer.get(2));
+}
+
+#[test]
+fn clones_share_the_log() {
+    let log = SharedLog::new();
+    let clone = log.clone();
+    log.push("one");
+    clone.push("two");
+    assert_eq!(log.entries(), ["one", "two"]);
+    assert_eq!(clone.entries(), log.entries());
+}
+
+#[test]
+fn doubles_every_number() {
+    for (len, threads) in [(0, 3), (1, 3), (10, 3), (10, 20), (1000, 7)] {
+        let numbers = Mutex::new((0..len).collect::<Vec<u64>>());
+        double_locked(&numbers, threads);
+        assert_eq!(
+            numbers.into_inner().unwrap(),
+            (0..len).map(|x| x * 2).collect::<Vec<_>>(),
+            "Wrong result for {len} numbers on {threads} threads"
+        );
+    }
+}
+
+#[test]
+fn raw_buffer_stores_numbers() {
+    let mut buffer = RawBuffer::new(4);
+    buffer.set(0, 1);
+    buffer.set(3, 4);
+    assert_eq!(
+        (0..4).map(|i| buffer.get(i)).collect::<Vec<_>>(),
+        [1, 0, 0, 4]
+    );
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn raw_buffer_checks_bounds() {
+    RawBuffer::new(4).get(4);
+}
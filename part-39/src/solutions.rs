@@ -0,0 +1,85 @@
+//! Part 39, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+};
+
+/// A log which every clone adds its entries to
+#[derive(Debug, Clone, Default)]
+pub struct SharedLog {
+    entries: Arc<Mutex<Vec<String>>>,
+}
+
+impl SharedLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&self, entry: impl Into<String>) {
+        self.entries.lock().unwrap().push(entry.into());
+    }
+
+    /// Every entry so far, in the order they were added
+    pub fn entries(&self) -> Vec<String> {
+        self.entries.lock().unwrap().clone()
+    }
+}
+
+/// Locks `numbers` once, and doubles every one of them using `threads` scoped threads
+pub fn double_locked(numbers: &Mutex<Vec<u64>>, threads: usize) {
+    let mut guard = numbers.lock().unwrap();
+    // The guard has to stay on this thread, but the slice it derefs to can be split up and sent
+    let numbers: &mut [u64] = &mut guard;
+    let chunk_size = numbers.len().div_ceil(threads).max(1);
+    thread::scope(|s| {
+        for chunk in numbers.chunks_mut(chunk_size) {
+            s.spawn(|| chunk.iter_mut().for_each(|x| *x *= 2));
+        }
+    });
+}
+
+/// A fixed number of numbers in memory managed by hand,
+/// like a buffer handed out by a C library
+#[derive(Debug)]
+pub struct RawBuffer {
+    ptr: *mut u64,
+    len: usize,
+}
+
+impl RawBuffer {
+    /// Allocates `len` zeroes
+    pub fn new(len: usize) -> Self {
+        let ptr = Box::into_raw(vec![0u64; len].into_boxed_slice()).cast::<u64>();
+        Self { ptr, len }
+    }
+
+    /// Panics if `index` is out of bounds
+    pub fn get(&self, index: usize) -> u64 {
+        assert!(index < self.len, "Index {index} out of bounds");
+        // SAFETY: `ptr` points to `len` numbers, which live until `self` is dropped
+        unsafe { *self.ptr.add(index) }
+    }
+
+    /// Panics if `index` is out of bounds
+    pub fn set(&mut self, index: usize, value: u64) {
+        assert!(index < self.len, "Index {index} out of bounds");
+        // SAFETY: As in `get`, and `&mut self` makes sure no one else is using them
+        unsafe { *self.ptr.add(index) = value }
+    }
+}
+
+// SAFETY: A `RawBuffer` owns the numbers it points to like a `Box<[u64]>` would, and nothing else
+// can reach them. Whichever thread owns the buffer can use and free them, just like a `Box`.
+unsafe impl Send for RawBuffer {}
+
+impl Drop for RawBuffer {
+    fn drop(&mut self) {
+        // SAFETY: `ptr` and `len` came from the boxed slice in `new`, which is only freed here
+        unsafe {
+            drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+                self.ptr, self.len,
+            )))
+        }
+    }
+}
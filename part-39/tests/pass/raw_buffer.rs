@@ -0,0 +1,16 @@
+// The buffer is filled in by another thread and handed back, so `RawBuffer` has to be `Send`
+use part_39::RawBuffer;
+
+fn main() {
+    let buffer = RawBuffer::new(10);
+    let handle = std::thread::spawn(move || {
+        let mut buffer = buffer;
+        for i in 0..10 {
+            buffer.set(i, i as u64 * 10);
+        }
+        buffer
+    });
+
+    let buffer = handle.join().unwrap();
+    assert_eq!(buffer.get(9), 90);
+}
@@ -0,0 +1,17 @@
+// Every thread adds to the same log, so `SharedLog` has to be `Send`
+use part_39::SharedLog;
+
+fn main() {
+    let log = SharedLog::new();
+    let handles: Vec<_> = (0..4)
+        .map(|id| {
+            let log = log.clone();
+            std::thread::spawn(move || log.push(format!("Hello from thread {id}")))
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(log.entries().len(), 4);
+}
@@ -0,0 +1,7 @@
+// Cases in `tests/pass` only compile once the types in `src/lib.rs` can be sent to other threads.
+// The compiler output says what's missing.
+#[test]
+fn can_be_sent() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/pass/*.rs");
+}
@@ -0,0 +1,25 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-39 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::sync::Mutex;
+
+use part_39::{double_locked, solutions};
+
+#[test]
+fn doubles_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for len in [0, 1, 1001] {
+        let numbers = common::datagen::numbers_below(len, 1 << 32, rng.seed());
+        for threads in [1, 3, 16] {
+            let (doubled, expected) = (Mutex::new(numbers.clone()), Mutex::new(numbers.clone()));
+            double_locked(&doubled, threads);
+            solutions::double_locked(&expected, threads);
+            assert_eq!(
+                doubled.into_inner().unwrap(),
+                expected.into_inner().unwrap(),
+                "{len} numbers on {threads} threads"
+            );
+        }
+    }
+}
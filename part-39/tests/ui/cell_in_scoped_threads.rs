@@ -0,0 +1,12 @@
+// `Cell` can be sent to another thread, but not shared between threads,
+// since updating it isn't atomic
+use std::cell::Cell;
+
+fn main() {
+    let counter = Cell::new(0);
+
+    std::thread::scope(|s| {
+        s.spawn(|| counter.set(counter.get() + 1));
+        s.spawn(|| counter.set(counter.get() + 1));
+    });
+}
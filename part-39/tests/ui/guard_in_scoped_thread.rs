@@ -0,0 +1,12 @@
+// A mutable borrow of a value can only be sent if the value itself can,
+// which rules out borrowing the guard in a scoped thread too
+use std::sync::Mutex;
+
+fn main() {
+    let numbers = Mutex::new(vec![1, 2, 3]);
+    let mut guard = numbers.lock().unwrap();
+
+    std::thread::scope(|s| {
+        s.spawn(|| guard.push(4));
+    });
+}
@@ -0,0 +1,11 @@
+// A lock has to be unlocked by the same thread that locked it,
+// so a guard can't be handed over to another thread
+use std::sync::{Arc, Mutex};
+
+fn main() {
+    let numbers = Arc::new(Mutex::new(vec![1, 2, 3]));
+    let mut guard = numbers.lock().unwrap();
+
+    let handle = std::thread::spawn(move || guard.push(4));
+    handle.join().unwrap();
+}
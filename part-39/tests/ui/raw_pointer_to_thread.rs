@@ -0,0 +1,14 @@
+// Raw pointers are neither `Send` nor `Sync`, since the compiler knows nothing about what they
+// point to. Neither is any type containing one, unless it promises to be with an `unsafe impl`.
+struct Handle {
+    ptr: *mut u64,
+}
+
+fn main() {
+    let mut value = 0;
+    let handle = Handle { ptr: &mut value };
+
+    std::thread::spawn(move || unsafe { *handle.ptr += 1 })
+        .join()
+        .unwrap();
+}
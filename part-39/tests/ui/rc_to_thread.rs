@@ -0,0 +1,12 @@
+// Cloning an `Rc` updates its reference count without any synchronization,
+// so two threads with clones could both update it at the same time
+use std::rc::Rc;
+
+fn main() {
+    let names = Rc::new(vec!["Ferris", "Corro"]);
+    let clone = names.clone();
+
+    let handle = std::thread::spawn(move || println!("{clone:?}"));
+    println!("{names:?}");
+    handle.join().unwrap();
+}
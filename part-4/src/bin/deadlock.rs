@@ -0,0 +1,128 @@
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+#[cfg(all(test, feature = "solutions"))]
+#[path = "deadlock/solutions.rs"]
+mod solutions;
+
+fn main() {
+    let a = Arc::new(Account::new(0, 100));
+    let b = Arc::new(Account::new(1, 100));
+
+    let handles = [(a.clone(), b.clone()), (b.clone(), a.clone())].map(|(from, to)| {
+        thread::spawn(move || {
+            for _ in 0..100 {
+                transfer(&from, &to, 1);
+            }
+        })
+    });
+    for handle in handles {
+        handle.join().expect("Couldn't join thread");
+    }
+
+    println!("a: {}, b: {}", a.balance(), b.balance());
+}
+
+struct Account {
+    /// Unique for every account
+    id: usize,
+    balance: Mutex<i64>,
+}
+
+impl Account {
+    fn new(id: usize, balance: i64) -> Self {
+        Self {
+            id,
+            balance: Mutex::new(balance),
+        }
+    }
+
+    fn balance(&self) -> i64 {
+        *self.balance.lock().unwrap()
+    }
+}
+
+/// Moves `amount` from one account to the other.
+/// Both accounts are locked at the same time, so no one sees the money disappear midway.
+///
+/// Don't use this! If one thread transfers from `a` to `b` while another transfers
+/// from `b` to `a`, each of them locks their `from`-account and then waits forever
+/// for the other to release their `to`-account.
+#[allow(dead_code)]
+fn deadlocking_transfer(from: &Account, to: &Account, amount: i64) {
+    let mut from_balance = from.balance.lock().unwrap();
+    // Make it very likely that the other thread locks its `from`-account meanwhile
+    thread::sleep(Duration::from_millis(10));
+    let mut to_balance = to.balance.lock().unwrap();
+
+    *from_balance -= amount;
+    *to_balance += amount;
+}
+
+/// Moves `amount` from one account to the other, like `deadlocking_transfer`,
+/// but without deadlocking when transfers go both ways at the same time.
+fn transfer(from: &Account, to: &Account, amount: i64) {
+    todo!()
+}
+
+#[cfg(test)]
+fn transfer_both_ways(transfer: fn(&Account, &Account, i64)) -> Option<(i64, i64)> {
+    let a = Arc::new(Account::new(0, 100));
+    let b = Arc::new(Account::new(1, 100));
+
+    // Deadlocked threads never finish, so give up waiting after a while
+    common::with_timeout(Duration::from_secs(1), move || {
+        let handles =
+            [(a.clone(), b.clone(), 10), (b.clone(), a.clone(), 7)].map(|(from, to, amount)| {
+                thread::spawn(move || {
+                    for _ in 0..20 {
+                        transfer(&from, &to, amount);
+                    }
+                })
+            });
+        for handle in handles {
+            handle.join().expect("Couldn't join thread");
+        }
+        (a.balance(), b.balance())
+    })
+}
+
+#[test]
+fn broken_transfer_deadlocks() {
+    // Not something you want to see, but proof that the test below would catch it
+    assert_eq!(transfer_both_ways(deadlocking_transfer), None);
+}
+
+#[test]
+fn transfer_does_not_deadlock() {
+    let balances = transfer_both_ways(transfer).expect("Transfers deadlocked");
+    assert_eq!(balances, (100 - 20 * 10 + 20 * 7, 100 + 20 * 10 - 20 * 7));
+}
+
+#[cfg(feature = "solutions")]
+#[test]
+fn transfers_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let accounts: Vec<Account> = (0..4).map(|id| Account::new(id, 1000)).collect();
+    let expected: Vec<Account> = (0..4).map(|id| Account::new(id, 1000)).collect();
+
+    let picks = common::datagen::numbers_below(300, 4, rng.seed());
+    for (i, pair) in picks.chunks(3).enumerate() {
+        let (from, to, amount) = (
+            pair[0] as usize,
+            pair[1] as usize,
+            pair[2] as i64 + i as i64,
+        );
+        if from == to {
+            continue;
+        }
+        transfer(&accounts[from], &accounts[to], amount);
+        solutions::transfer(&expected[from], &expected[to], amount);
+    }
+    let balances: Vec<i64> = accounts.iter().map(Account::balance).collect();
+    let expected: Vec<i64> = expected.iter().map(Account::balance).collect();
+    assert_eq!(balances, expected);
+}
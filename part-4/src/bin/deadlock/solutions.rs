@@ -0,0 +1,31 @@
+//! `deadlock`, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use super::Account;
+
+/// Moves `amount` from one account to the other, like `deadlocking_transfer`,
+/// but without deadlocking when transfers go both ways at the same time.
+pub fn transfer(from: &Account, to: &Account, amount: i64) {
+    // Always lock the account with the lowest id first. Then a thread can only ever
+    // wait for a lock held by a thread that has no more locks to wait for.
+    let (first, second) = if from.id < to.id {
+        (from, to)
+    } else {
+        (to, from)
+    };
+    let mut first_balance = first.balance.lock().unwrap();
+    let mut second_balance = second.balance.lock().unwrap();
+
+    let (from_balance, to_balance) = if from.id < to.id {
+        (&mut *first_balance, &mut *second_balance)
+    } else {
+        (&mut *second_balance, &mut *first_balance)
+    };
+    *from_balance -= amount;
+    *to_balance += amount;
+}
@@ -0,0 +1,157 @@
+use std::{sync::Arc, thread, time::Duration};
+
+use common::sync::Ordered;
+
+#[cfg(all(test, feature = "solutions"))]
+#[path = "hierarchy/solutions.rs"]
+mod solutions;
+
+fn main() {
+    let a = Arc::new(Account::new(0, 100));
+    let b = Arc::new(Account::new(1, 100));
+
+    let handles = [(a.clone(), b.clone()), (b.clone(), a.clone())].map(|(from, to)| {
+        thread::spawn(move || {
+            for _ in 0..100 {
+                transfer(&from, &to, 1);
+            }
+        })
+    });
+    for handle in handles {
+        handle.join().expect("Couldn't join thread");
+    }
+
+    println!("a: {}, b: {}", a.balance(), b.balance());
+}
+
+/// The account from `deadlock`, with its balance in a lock ranked by the account's id, which
+/// is unique for every account
+struct Account {
+    balance: Ordered<i64>,
+}
+
+impl Account {
+    fn new(id: usize, balance: i64) -> Self {
+        Self {
+            balance: Ordered::new(id, balance),
+        }
+    }
+
+    fn balance(&self) -> i64 {
+        *self.balance.lock()
+    }
+}
+
+/// `deadlock`'s `deadlocking_transfer` on ranked locks. Transferring to an account with a lower id
+/// locks it while holding a higher ranked lock, which panics, every time, before it can deadlock.
+#[allow(dead_code)]
+fn deadlocking_transfer(from: &Account, to: &Account, amount: i64) {
+    let mut from_balance = from.balance.lock();
+    // With a `Mutex`, this is where the other thread would lock its `from`-account
+    thread::sleep(Duration::from_millis(10));
+    let mut to_balance = to.balance.lock();
+
+    *from_balance -= amount;
+    *to_balance += amount;
+}
+
+/// Moves `amount` from one account to the other, like `deadlocking_transfer`,
+/// but locking the accounts in the order the hierarchy allows.
+fn transfer(from: &Account, to: &Account, amount: i64) {
+    todo!()
+}
+
+#[cfg(test)]
+fn transfer_both_ways(transfer: fn(&Account, &Account, i64)) -> Option<(i64, i64)> {
+    let a = Arc::new(Account::new(0, 100));
+    let b = Arc::new(Account::new(1, 100));
+
+    // Deadlocked threads never finish, so give up waiting after a while
+    common::with_timeout(Duration::from_secs(1), move || {
+        let handles =
+            [(a.clone(), b.clone(), 10), (b.clone(), a.clone(), 7)].map(|(from, to, amount)| {
+                thread::spawn(move || {
+                    for _ in 0..20 {
+                        transfer(&from, &to, amount);
+                    }
+                })
+            });
+        for handle in handles {
+            handle.join().expect("Couldn't join thread");
+        }
+        (a.balance(), b.balance())
+    })
+}
+
+#[test]
+#[should_panic(expected = "Locked rank 0 while holding rank 1")]
+fn out_of_order_transfer_panics() {
+    // No other thread needed: the order is checked before waiting for the lock
+    let (a, b) = (Account::new(0, 100), Account::new(1, 100));
+    deadlocking_transfer(&b, &a, 10);
+}
+
+#[test]
+fn broken_transfer_panics_instead_of_deadlocking() {
+    let (a, b) = (
+        Arc::new(Account::new(0, 100)),
+        Arc::new(Account::new(1, 100)),
+    );
+    let finished = common::with_timeout(Duration::from_secs(5), move || {
+        let handles = [(a.clone(), b.clone()), (b, a)]
+            .map(|(from, to)| thread::spawn(move || deadlocking_transfer(&from, &to, 10)));
+        handles.map(|handle| {
+            let panic = handle.join().err()?;
+            panic.downcast::<String>().ok().map(|message| *message)
+        })
+    });
+    let [_, b_to_a] = finished.expect("Transfers deadlocked");
+    // The transfer from `a` to `b` may fail too, if `b` is poisoned by the time it gets to it.
+    // But the one from `b` to `a` always locks out of order, so it always panics for that.
+    let message = b_to_a.expect("Transferring from b to a should panic");
+    assert!(
+        message.contains("Locked rank 0 while holding rank 1"),
+        "{message}"
+    );
+}
+
+#[test]
+fn transfer_does_not_deadlock() {
+    let balances = transfer_both_ways(transfer).expect("Transfers deadlocked");
+    assert_eq!(balances, (100 - 20 * 10 + 20 * 7, 100 + 20 * 10 - 20 * 7));
+}
+
+#[test]
+fn transfer_locks_in_order() {
+    let accounts: Vec<Account> = (0..3).map(|id| Account::new(id, 100)).collect();
+    for (from, to) in [(0, 1), (1, 0), (2, 0), (1, 2)] {
+        transfer(&accounts[from], &accounts[to], 10);
+    }
+    let balances: Vec<i64> = accounts.iter().map(Account::balance).collect();
+    assert_eq!(balances, [110, 90, 100]);
+}
+
+#[cfg(feature = "solutions")]
+#[test]
+fn transfers_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let accounts: Vec<Account> = (0..4).map(|id| Account::new(id, 1000)).collect();
+    let expected: Vec<Account> = (0..4).map(|id| Account::new(id, 1000)).collect();
+
+    let picks = common::datagen::numbers_below(300, 4, rng.seed());
+    for (i, pair) in picks.chunks(3).enumerate() {
+        let (from, to, amount) = (
+            pair[0] as usize,
+            pair[1] as usize,
+            pair[2] as i64 + i as i64,
+        );
+        if from == to {
+            continue;
+        }
+        transfer(&accounts[from], &accounts[to], amount);
+        solutions::transfer(&expected[from], &expected[to], amount);
+    }
+    let balances: Vec<i64> = accounts.iter().map(Account::balance).collect();
+    let expected: Vec<i64> = expected.iter().map(Account::balance).collect();
+    assert_eq!(balances, expected);
+}
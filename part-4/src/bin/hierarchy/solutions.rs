@@ -0,0 +1,22 @@
+//! `hierarchy`, with every exercise implemented. Only built with `--features solutions`.
+
+use common::sync::Ordered;
+
+use super::Account;
+
+/// Moves `amount` from one account to the other, like `deadlocking_transfer`,
+/// but locking the accounts in the order the hierarchy allows.
+pub fn transfer(from: &Account, to: &Account, amount: i64) {
+    // The lock ranked lowest first, which is the only order `Ordered` allows
+    if from.balance.rank() < to.balance.rank() {
+        let mut from_balance = from.balance.lock();
+        let mut to_balance = to.balance.lock();
+        *from_balance -= amount;
+        *to_balance += amount;
+    } else {
+        let mut to_balance = to.balance.lock();
+        let mut from_balance = from.balance.lock();
+        *from_balance -= amount;
+        *to_balance += amount;
+    }
+}
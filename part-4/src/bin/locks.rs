@@ -0,0 +1,157 @@
+use std::{sync::Arc, thread};
+
+use common::timed;
+
+#[cfg(all(test, feature = "solutions"))]
+#[path = "locks/solutions.rs"]
+mod solutions;
+
+fn main() {
+    let x = Arc::new(<parking_lot::Mutex<bool> as Lock<bool>>::new(false));
+    assignment(x.clone());
+    assert_eq!(x.with_lock(|x| *x), Some(true));
+
+    let threads = 8;
+    let increments = 100_000;
+
+    let std_count = timed("std::sync::Mutex", || {
+        contended_count::<std::sync::Mutex<u64>>(threads, increments)
+    });
+    let parking_lot_count = timed("parking_lot::Mutex", || {
+        contended_count::<parking_lot::Mutex<u64>>(threads, increments)
+    });
+
+    assert_eq!(std_count, parking_lot_count);
+}
+
+/// A mutual exclusion lock which doesn't care which library it's from
+trait Lock<T>: Send + Sync {
+    fn new(value: T) -> Self;
+
+    /// Runs `f` with exclusive access to the value behind the lock,
+    /// returning what `f` returns, or `None` if the lock is poisoned.
+    fn with_lock<U>(&self, f: impl FnOnce(&mut T) -> U) -> Option<U>;
+}
+
+impl<T: Send> Lock<T> for std::sync::Mutex<T> {
+    fn new(value: T) -> Self {
+        std::sync::Mutex::new(value)
+    }
+
+    fn with_lock<U>(&self, f: impl FnOnce(&mut T) -> U) -> Option<U> {
+        todo!()
+    }
+}
+
+impl<T: Send> Lock<T> for parking_lot::Mutex<T> {
+    fn new(value: T) -> Self {
+        parking_lot::Mutex::new(value)
+    }
+
+    fn with_lock<U>(&self, f: impl FnOnce(&mut T) -> U) -> Option<U> {
+        todo!()
+    }
+}
+
+/// The same assignment as in part-4, for any kind of lock
+fn assignment<L: Lock<bool> + 'static>(x: Arc<L>) {
+    thread::spawn(move || x.with_lock(|x| *x = true))
+        .join()
+        .expect("Couldn't join thread");
+}
+
+/// Lets `threads` threads increment a shared counter `increments` times each
+fn contended_count<L: Lock<u64> + 'static>(threads: usize, increments: u64) -> u64 {
+    let counter = Arc::new(L::new(0));
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let counter = counter.clone();
+            thread::spawn(move || {
+                for _ in 0..increments {
+                    counter.with_lock(|count| *count += 1);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().expect("Couldn't join thread");
+    }
+    counter
+        .with_lock(|count| *count)
+        .expect("Lock was poisoned")
+}
+
+/// Panics on another thread while holding the lock
+#[cfg(test)]
+fn panic_while_locked<L: Lock<u64> + 'static>(lock: Arc<L>) {
+    let result = thread::spawn(move || lock.with_lock(|_| panic!("Oops"))).join();
+    assert!(result.is_err(), "Thread should have panicked");
+}
+
+#[cfg(test)]
+fn mutate_shared_state<L: Lock<bool> + 'static>() {
+    let state = Arc::new(L::new(false));
+    assignment(state.clone());
+    assert_eq!(state.with_lock(|x| *x), Some(true));
+}
+
+#[test]
+fn std_mutate_shared_state() {
+    mutate_shared_state::<std::sync::Mutex<bool>>();
+}
+
+#[test]
+fn parking_lot_mutate_shared_state() {
+    mutate_shared_state::<parking_lot::Mutex<bool>>();
+}
+
+#[test]
+fn counts_are_identical() {
+    let std_count = contended_count::<std::sync::Mutex<u64>>(4, 1000);
+    let parking_lot_count = contended_count::<parking_lot::Mutex<u64>>(4, 1000);
+
+    assert_eq!(std_count, 4000);
+    assert_eq!(std_count, parking_lot_count);
+}
+
+#[test]
+fn std_mutex_is_poisoned_by_panic() {
+    let lock = Arc::new(<std::sync::Mutex<u64> as Lock<u64>>::new(0));
+    panic_while_locked(lock.clone());
+    assert_eq!(lock.with_lock(|x| *x), None);
+}
+
+#[test]
+fn parking_lot_mutex_is_not_poisoned_by_panic() {
+    let lock = Arc::new(<parking_lot::Mutex<u64> as Lock<u64>>::new(0));
+    panic_while_locked(lock.clone());
+    assert_eq!(lock.with_lock(|x| *x), Some(0));
+}
+
+#[cfg(feature = "solutions")]
+#[test]
+fn locks_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let increments = common::datagen::numbers_below(100, 10, rng.seed());
+    for poison in [false, true] {
+        let lock = Arc::new(<std::sync::Mutex<u64> as Lock<u64>>::new(0));
+        let expected = Arc::new(<std::sync::Mutex<u64> as solutions::Lock<u64>>::new(0));
+        for &increment in &increments {
+            Lock::with_lock(&*lock, |count| *count += increment);
+            solutions::Lock::with_lock(&*expected, |count| *count += increment);
+        }
+        if poison {
+            panic_while_locked(lock.clone());
+            let result = thread::spawn({
+                let expected = expected.clone();
+                move || solutions::Lock::with_lock(&*expected, |_| panic!("Oops"))
+            })
+            .join();
+            assert!(result.is_err(), "Thread should have panicked");
+        }
+        assert_eq!(
+            Lock::with_lock(&*lock, |count| *count),
+            solutions::Lock::with_lock(&*expected, |count| *count)
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! `locks`, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{sync::Arc, thread};
+
+use common::timed;
+
+// A trait of its own, since the exercise's is already implemented for both locks, and the functions
+// which use it along with it
+
+/// A mutual exclusion lock which doesn't care which library it's from
+pub trait Lock<T>: Send + Sync {
+    fn new(value: T) -> Self;
+
+    /// Runs `f` with exclusive access to the value behind the lock,
+    /// returning what `f` returns, or `None` if the lock is poisoned.
+    fn with_lock<U>(&self, f: impl FnOnce(&mut T) -> U) -> Option<U>;
+}
+
+impl<T: Send> Lock<T> for std::sync::Mutex<T> {
+    fn new(value: T) -> Self {
+        std::sync::Mutex::new(value)
+    }
+
+    fn with_lock<U>(&self, f: impl FnOnce(&mut T) -> U) -> Option<U> {
+        // The guard is only given back inside the `Err` if another thread panicked
+        self.lock().ok().map(|mut guard| f(&mut guard))
+    }
+}
+
+impl<T: Send> Lock<T> for parking_lot::Mutex<T> {
+    fn new(value: T) -> Self {
+        parking_lot::Mutex::new(value)
+    }
+
+    fn with_lock<U>(&self, f: impl FnOnce(&mut T) -> U) -> Option<U> {
+        // No poisoning here, so locking can't fail
+        Some(f(&mut self.lock()))
+    }
+}
+
+/// The same assignment as in part-4, for any kind of lock
+pub fn assignment<L: Lock<bool> + 'static>(x: Arc<L>) {
+    thread::spawn(move || x.with_lock(|x| *x = true))
+        .join()
+        .expect("Couldn't join thread");
+}
+
+/// Lets `threads` threads increment a shared counter `increments` times each
+pub fn contended_count<L: Lock<u64> + 'static>(threads: usize, increments: u64) -> u64 {
+    let counter = Arc::new(L::new(0));
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let counter = counter.clone();
+            thread::spawn(move || {
+                for _ in 0..increments {
+                    counter.with_lock(|count| *count += 1);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().expect("Couldn't join thread");
+    }
+    counter
+        .with_lock(|count| *count)
+        .expect("Lock was poisoned")
+}
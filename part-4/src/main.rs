@@ -3,6 +3,9 @@ use std::{
     thread,
 };
 
+#[cfg(all(test, feature = "solutions"))]
+mod solutions;
+
 fn main() {
     let x = Arc::new(Mutex::new(false));
     assignment(x.clone());
@@ -21,3 +24,12 @@ fn mutate_shared_state() {
     let result = state.lock().unwrap();
     assert!(*result);
 }
+
+#[cfg(feature = "solutions")]
+#[test]
+fn assigns_like_the_solution() {
+    let (state, expected) = (Arc::new(Mutex::new(false)), Arc::new(Mutex::new(false)));
+    assignment(state.clone());
+    solutions::assignment(expected.clone());
+    assert_eq!(*state.lock().unwrap(), *expected.lock().unwrap());
+}
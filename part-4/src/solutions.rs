@@ -0,0 +1,12 @@
+//! Part 4, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+};
+
+pub fn assignment(x: Arc<Mutex<bool>>) {
+    thread::spawn(move || *x.lock().unwrap() = true)
+        .join()
+        .expect("Couldn't join thread");
+}
@@ -0,0 +1,11 @@
+// `Cell` keeps track of its contents without any synchronization,
+// so it can't be shared between threads
+use part_40::{Counter, CellCounter};
+
+fn main() {
+    let counter = CellCounter::default();
+    std::thread::scope(|s| {
+        s.spawn(|| counter.increment());
+        s.spawn(|| counter.increment());
+    });
+}
@@ -0,0 +1,10 @@
+// The same goes for anything built on `RefCell`, like `RefCellCache`
+use part_40::{fibonacci, RefCellCache};
+
+fn main() {
+    let cache = RefCellCache::default();
+    std::thread::scope(|s| {
+        s.spawn(|| fibonacci(&cache, 10));
+        s.spawn(|| fibonacci(&cache, 20));
+    });
+}
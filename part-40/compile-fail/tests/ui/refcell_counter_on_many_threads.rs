@@ -0,0 +1,11 @@
+// `RefCell` keeps track of its contents without any synchronization,
+// so it can't be shared between threads
+use part_40::{Counter, RefCellCounter};
+
+fn main() {
+    let counter = RefCellCounter::default();
+    std::thread::scope(|s| {
+        s.spawn(|| counter.increment());
+        s.spawn(|| counter.increment());
+    });
+}
@@ -0,0 +1,125 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, RwLock,
+    },
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// A counter which can be incremented through a shared reference
+pub trait Counter: Default {
+    fn increment(&self);
+    fn get(&self) -> u64;
+}
+
+#[derive(Debug, Default)]
+pub struct CellCounter(Cell<u64>);
+
+#[derive(Debug, Default)]
+pub struct RefCellCounter(RefCell<u64>);
+
+#[derive(Debug, Default)]
+pub struct MutexCounter(Mutex<u64>);
+
+#[derive(Debug, Default)]
+pub struct RwLockCounter(RwLock<u64>);
+
+#[derive(Debug, Default)]
+pub struct AtomicCounter(AtomicU64);
+
+impl Counter for CellCounter {
+    fn increment(&self) {
+        todo!()
+    }
+
+    fn get(&self) -> u64 {
+        todo!()
+    }
+}
+
+impl Counter for RefCellCounter {
+    fn increment(&self) {
+        todo!()
+    }
+
+    fn get(&self) -> u64 {
+        todo!()
+    }
+}
+
+impl Counter for MutexCounter {
+    fn increment(&self) {
+        todo!()
+    }
+
+    fn get(&self) -> u64 {
+        todo!()
+    }
+}
+
+impl Counter for RwLockCounter {
+    fn increment(&self) {
+        todo!()
+    }
+
+    fn get(&self) -> u64 {
+        todo!()
+    }
+}
+
+impl Counter for AtomicCounter {
+    fn increment(&self) {
+        todo!()
+    }
+
+    fn get(&self) -> u64 {
+        todo!()
+    }
+}
+
+/// Remembers values which are expensive to compute
+pub trait Cache {
+    /// The value for `key`, which is computed with `compute` if it isn't cached yet.
+    /// `compute` may use the cache itself.
+    fn get_or_compute(&self, key: u64, compute: impl FnOnce() -> u64) -> u64;
+}
+
+/// Keeps its borrow of the map while computing, which goes wrong as soon as computing uses the cache
+#[derive(Debug, Default)]
+pub struct NaiveCache(RefCell<HashMap<u64, u64>>);
+
+#[derive(Debug, Default)]
+pub struct RefCellCache(RefCell<HashMap<u64, u64>>);
+
+#[derive(Debug, Default)]
+pub struct MutexCache(Mutex<HashMap<u64, u64>>);
+
+impl Cache for NaiveCache {
+    fn get_or_compute(&self, key: u64, compute: impl FnOnce() -> u64) -> u64 {
+        *self.0.borrow_mut().entry(key).or_insert_with(compute)
+    }
+}
+
+impl Cache for RefCellCache {
+    fn get_or_compute(&self, key: u64, compute: impl FnOnce() -> u64) -> u64 {
+        todo!()
+    }
+}
+
+impl Cache for MutexCache {
+    fn get_or_compute(&self, key: u64, compute: impl FnOnce() -> u64) -> u64 {
+        todo!()
+    }
+}
+
+/// The `n`th Fibonacci number, remembering every one it computes along the way in `cache`
+pub fn fibonacci(cache: &impl Cache, n: u64) -> u64 {
+    cache.get_or_compute(n, || match n {
+        0 | 1 => n,
+        _ => fibonacci(cache, n - 1) + fibonacci(cache, n - 2),
+    })
+}
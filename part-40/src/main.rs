@@ -0,0 +1,140 @@
+use std::thread;
+
+use common::{bench::Comparison, cli::Cli};
+use part_40::{
+    fibonacci, AtomicCounter, CellCounter, Counter, MutexCache, MutexCounter, RefCellCache,
+    RefCellCounter, RwLockCounter,
+};
+
+/// Increments `counter` `increments` times on this thread
+fn count<C: Counter>(increments: u64) -> u64 {
+    let counter = C::default();
+    for _ in 0..increments {
+        // Keeps the compiler from adding up all the increments in advance
+        common::bench::black_box(&counter).increment();
+    }
+    counter.get()
+}
+
+/// Lets `threads` threads increment the same counter `increments` times each
+fn count_shared<C: Counter + Sync>(threads: usize, increments: u64) -> u64 {
+    let counter = C::default();
+    thread::scope(|s| {
+        for _ in 0..threads {
+            s.spawn(|| {
+                for _ in 0..increments {
+                    counter.increment();
+                }
+            });
+        }
+    });
+    counter.get()
+}
+
+/// Run with `cargo run --release -p part-40` to compare the counters
+fn main() {
+    let args = Cli::new("Compares counters, and caches Fibonacci numbers")
+        .items(1_000_000)
+        .threads(4)
+        .strategy()
+        .parse();
+    let (increments, threads) = (args.items() as u64, args.threads());
+    println!("Incrementing {increments} times on one thread");
+    let mut comparison = Comparison::new(10);
+    comparison
+        .bench("Cell", || count::<CellCounter>(increments))
+        .bench("RefCell", || count::<RefCellCounter>(increments))
+        .bench("Mutex", || count::<MutexCounter>(increments))
+        .bench("RwLock", || count::<RwLockCounter>(increments))
+        .bench("Atomic", || count::<AtomicCounter>(increments));
+    comparison.print();
+
+    println!("Incrementing {increments} times on each of {threads} threads");
+    let mut comparison = Comparison::new(10);
+    comparison
+        .bench("Mutex", || {
+            count_shared::<MutexCounter>(threads, increments)
+        })
+        .bench("RwLock", || {
+            count_shared::<RwLockCounter>(threads, increments)
+        })
+        .bench("Atomic", || {
+            count_shared::<AtomicCounter>(threads, increments)
+        });
+    comparison.print();
+
+    let cache = MutexCache::default();
+    let results: Vec<u64> = thread::scope(|s| {
+        let handles: Vec<_> = (0..threads)
+            .map(|_| s.spawn(|| fibonacci(&cache, 90)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    });
+    println!(
+        "Fibonacci number 90 is {}, and {results:?} when computed on {threads} threads",
+        fibonacci(&RefCellCache::default(), 90)
+    );
+}
+
+#[test]
+fn every_counter_counts() {
+    assert_eq!(count::<CellCounter>(1000), 1000);
+    assert_eq!(count::<RefCellCounter>(1000), 1000);
+    assert_eq!(count::<MutexCounter>(1000), 1000);
+    assert_eq!(count::<RwLockCounter>(1000), 1000);
+    assert_eq!(count::<AtomicCounter>(1000), 1000);
+}
+
+#[test]
+fn thread_safe_counters_count_on_many_threads() {
+    assert_eq!(count_shared::<MutexCounter>(8, 10_000), 80_000);
+    assert_eq!(count_shared::<RwLockCounter>(8, 10_000), 80_000);
+    assert_eq!(count_shared::<AtomicCounter>(8, 10_000), 80_000);
+}
+
+#[test]
+fn atomic_is_cheaper_than_mutex() {
+    let mut comparison = Comparison::new(5);
+    comparison
+        .bench("Mutex", || count::<MutexCounter>(100_000))
+        .bench("Atomic", || count::<AtomicCounter>(100_000));
+    comparison.print();
+
+    comparison.assert_faster("Atomic", "Mutex");
+}
+
+#[test]
+#[should_panic(expected = "already borrowed")]
+fn naive_cache_panics_when_used_while_computing() {
+    fibonacci(&part_40::NaiveCache::default(), 10);
+}
+
+#[test]
+fn refcell_cache_can_be_used_while_computing() {
+    use part_40::Cache;
+
+    let cache = RefCellCache::default();
+    assert_eq!(fibonacci(&cache, 10), 55);
+    assert_eq!(fibonacci(&cache, 90), 2_880_067_194_370_816_120);
+    // Already cached, so this wouldn't be called
+    assert_eq!(cache.get_or_compute(50, || unreachable!()), 12_586_269_025);
+}
+
+#[test]
+fn mutex_cache_can_be_used_while_computing_on_many_threads() {
+    let results = common::with_timeout(std::time::Duration::from_secs(5), || {
+        let cache = MutexCache::default();
+        thread::scope(|s| {
+            let handles: Vec<_> = (0..8).map(|_| s.spawn(|| fibonacci(&cache, 90))).collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        })
+    })
+    .expect("Deadlocked, is the lock held while computing?");
+    assert_eq!(results, vec![2_880_067_194_370_816_120; 8]);
+}
@@ -0,0 +1,107 @@
+//! Part 40, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, RwLock,
+    },
+};
+
+use super::{Cache, Counter};
+
+#[derive(Debug, Default)]
+pub struct CellCounter(Cell<u64>);
+
+#[derive(Debug, Default)]
+pub struct RefCellCounter(RefCell<u64>);
+
+#[derive(Debug, Default)]
+pub struct MutexCounter(Mutex<u64>);
+
+#[derive(Debug, Default)]
+pub struct RwLockCounter(RwLock<u64>);
+
+#[derive(Debug, Default)]
+pub struct AtomicCounter(AtomicU64);
+
+impl Counter for CellCounter {
+    fn increment(&self) {
+        self.0.set(self.0.get() + 1);
+    }
+
+    fn get(&self) -> u64 {
+        self.0.get()
+    }
+}
+
+impl Counter for RefCellCounter {
+    fn increment(&self) {
+        *self.0.borrow_mut() += 1;
+    }
+
+    fn get(&self) -> u64 {
+        *self.0.borrow()
+    }
+}
+
+impl Counter for MutexCounter {
+    fn increment(&self) {
+        *self.0.lock().unwrap() += 1;
+    }
+
+    fn get(&self) -> u64 {
+        *self.0.lock().unwrap()
+    }
+}
+
+impl Counter for RwLockCounter {
+    fn increment(&self) {
+        *self.0.write().unwrap() += 1;
+    }
+
+    fn get(&self) -> u64 {
+        *self.0.read().unwrap()
+    }
+}
+
+impl Counter for AtomicCounter {
+    fn increment(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RefCellCache(RefCell<HashMap<u64, u64>>);
+
+#[derive(Debug, Default)]
+pub struct MutexCache(Mutex<HashMap<u64, u64>>);
+
+impl Cache for RefCellCache {
+    fn get_or_compute(&self, key: u64, compute: impl FnOnce() -> u64) -> u64 {
+        if let Some(&value) = self.0.borrow().get(&key) {
+            return value;
+        }
+        // Not borrowed while computing, so `compute` can use the cache too
+        let value = compute();
+        self.0.borrow_mut().insert(key, value);
+        value
+    }
+}
+
+impl Cache for MutexCache {
+    fn get_or_compute(&self, key: u64, compute: impl FnOnce() -> u64) -> u64 {
+        if let Some(&value) = self.0.lock().unwrap().get(&key) {
+            return value;
+        }
+        // Not locked while computing, so `compute` can use the cache too.
+        // Another thread may compute the same value meanwhile, which is wasteful but harmless.
+        let value = compute();
+        *self.0.lock().unwrap().entry(key).or_insert(value)
+    }
+}
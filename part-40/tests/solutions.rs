@@ -0,0 +1,54 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-40 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_40::{
+    fibonacci, solutions, AtomicCounter, CellCounter, Counter, MutexCache, MutexCounter,
+    RefCellCache, RefCellCounter, RwLockCounter,
+};
+
+/// Increments a new `C` `times` times, checking the count after every increment
+fn counts<C: Counter>(times: u64) -> Vec<u64> {
+    let counter = C::default();
+    (0..times)
+        .map(|_| {
+            counter.increment();
+            counter.get()
+        })
+        .collect()
+}
+
+/// The same as `counts`, for the solution's counters
+fn solution_counts<C: Counter>(times: u64) -> Vec<u64> {
+    let counter = C::default();
+    (0..times)
+        .map(|_| {
+            counter.increment();
+            counter.get()
+        })
+        .collect()
+}
+
+#[test]
+fn counts_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for times in common::datagen::numbers_below(5, 1000, rng.seed()) {
+        let expected = solution_counts::<solutions::AtomicCounter>(times);
+        assert_eq!(counts::<CellCounter>(times), expected);
+        assert_eq!(counts::<RefCellCounter>(times), expected);
+        assert_eq!(counts::<MutexCounter>(times), expected);
+        assert_eq!(counts::<RwLockCounter>(times), expected);
+        assert_eq!(counts::<AtomicCounter>(times), expected);
+    }
+}
+
+#[test]
+fn caches_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for n in common::datagen::numbers_below(10, 90, rng.seed()) {
+        let expected = fibonacci(&solutions::MutexCache::default(), n);
+        assert_eq!(fibonacci(&RefCellCache::default(), n), expected);
+        assert_eq!(fibonacci(&MutexCache::default(), n), expected);
+        assert_eq!(fibonacci(&solutions::RefCellCache::default(), n), expected);
+    }
+}
@@ -0,0 +1,67 @@
+use std::{sync::Arc, thread};
+
+use part_41::Slot;
+
+/// Puts `values` in `slot` one by one, waiting for the slot to be empty each time
+fn put_all(slot: &Slot<String>, values: impl IntoIterator<Item = String>) {
+    for mut value in values {
+        while let Err(rejected) = slot.put(value) {
+            value = rejected;
+            thread::yield_now();
+        }
+    }
+}
+
+/// Takes `count` values from `slot`, waiting for it to be full each time
+fn take_all(slot: &Slot<String>, count: usize) -> Vec<String> {
+    (0..count)
+        .map(|_| loop {
+            match slot.take() {
+                Some(value) => break value,
+                None => thread::yield_now(),
+            }
+        })
+        .collect()
+}
+
+fn main() {
+    let args = common::cli::Cli::new("Passes messages through a slot, one at a time")
+        .items(5)
+        .parse();
+    let messages = args.items();
+    let slot = Arc::new(Slot::new());
+    let putter = {
+        let slot = slot.clone();
+        thread::spawn(move || put_all(&slot, (0..messages).map(|i| format!("Message {i}"))))
+    };
+    for message in take_all(&slot, messages) {
+        println!("{message}");
+    }
+    putter.join().unwrap();
+}
+
+#[test]
+fn takes_what_was_put() {
+    let slot = Slot::new();
+    assert_eq!(slot.take(), None);
+    assert_eq!(slot.put(1), Ok(()));
+    assert_eq!(slot.put(2), Err(2));
+    assert_eq!(slot.take(), Some(1));
+    assert_eq!(slot.take(), None);
+    assert_eq!(slot.put(3), Ok(()));
+    assert_eq!(slot.take(), Some(3));
+}
+
+#[test]
+fn hands_values_over_between_threads() {
+    let slot = Arc::new(Slot::new());
+    let values: Vec<String> = (0..10_000).map(|i| i.to_string()).collect();
+    let putter = {
+        let slot = slot.clone();
+        let values = values.clone();
+        thread::spawn(move || put_all(&slot, values))
+    };
+
+    assert_eq!(take_all(&slot, values.len()), values);
+    putter.join().unwrap();
+}
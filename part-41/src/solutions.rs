@@ -0,0 +1,67 @@
+//! Part 41, with every exercise implemented. Only built with `--features solutions`.
+
+// Under `--cfg loom` the atomics and `UnsafeCell` are swapped for loom's, which lets loom
+// explore every way the threads in a test can interleave, and catch data races on the value.
+#[cfg(loom)]
+use loom::{
+    cell::UnsafeCell,
+    sync::atomic::{AtomicBool, Ordering},
+};
+#[cfg(not(loom))]
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(not(loom))]
+use super::UnsafeCell;
+
+/// Hands values over from one thread to another, one at a time.
+/// `full` says whether `value` holds a value which hasn't been taken yet.
+///
+/// Only one thread may put values in the slot, and only one other thread may take them out.
+#[derive(Debug)]
+pub struct Slot<T> {
+    value: UnsafeCell<Option<T>>,
+    full: AtomicBool,
+}
+
+// SAFETY: `full` makes sure only one thread accesses `value` at a time, as long as the
+// orderings are right. The values are moved between threads, so they must be `Send`.
+unsafe impl<T: Send> Sync for Slot<T> {}
+
+impl<T> Slot<T> {
+    pub fn new() -> Self {
+        Self {
+            value: UnsafeCell::new(None),
+            full: AtomicBool::new(false),
+        }
+    }
+
+    /// Puts `value` in the slot, or gives it back if the slot is still full
+    pub fn put(&self, value: T) -> Result<(), T> {
+        // Acquire: pairs with the `Release` in `take`, so the taking thread is done with the value
+        if self.full.load(Ordering::Acquire) {
+            return Err(value);
+        }
+        // SAFETY: The slot is empty, so the taking thread is done with it until `full` is set
+        self.value.with_mut(|slot| unsafe { *slot = Some(value) });
+        self.full.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    /// Takes the value out of the slot, if there is one
+    pub fn take(&self) -> Option<T> {
+        if !self.full.load(Ordering::Acquire) {
+            return None;
+        }
+        // SAFETY: The slot is full, so the putting thread is done with it until `full` is cleared
+        let value = self.value.with_mut(|slot| unsafe { (*slot).take() });
+        // Release: the value has been taken out before the putting thread sees the slot as empty
+        self.full.store(false, Ordering::Release);
+        value
+    }
+}
+
+impl<T> Default for Slot<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,52 @@
+//! Run these with `RUSTFLAGS="--cfg loom" cargo test --release -p part-41 --test loom`
+#![cfg(loom)]
+
+use loom::{sync::Arc, thread};
+use part_41::Slot;
+
+#[test]
+fn values_are_handed_over_in_order() {
+    loom::model(|| {
+        let slot = Arc::new(Slot::new());
+        let putter = {
+            let slot = slot.clone();
+            thread::spawn(move || {
+                for value in ["first", "second"] {
+                    let mut value = String::from(value);
+                    while let Err(rejected) = slot.put(value) {
+                        value = rejected;
+                        thread::yield_now();
+                    }
+                }
+            })
+        };
+
+        let mut taken = Vec::new();
+        while taken.len() < 2 {
+            match slot.take() {
+                Some(value) => taken.push(value),
+                None => thread::yield_now(),
+            }
+        }
+        assert_eq!(taken, ["first", "second"]);
+        putter.join().unwrap();
+    });
+}
+
+#[test]
+fn a_value_left_in_the_slot_is_dropped() {
+    loom::model(|| {
+        let value = Arc::new(());
+        let slot = Arc::new(Slot::new());
+        let putter = {
+            let slot = slot.clone();
+            let value = value.clone();
+            thread::spawn(move || slot.put(value).unwrap())
+        };
+
+        let taken = slot.take();
+        putter.join().unwrap();
+        drop((taken, slot));
+        assert_eq!(Arc::strong_count(&value), 1);
+    });
+}
@@ -0,0 +1,60 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-41 --features solutions`.
+#![cfg(all(feature = "solutions", not(loom)))]
+
+use std::{sync::Arc, thread};
+
+use part_41::{solutions, Slot};
+
+#[test]
+fn hands_over_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (slot, expected) = (Slot::new(), solutions::Slot::new());
+    for (i, op) in common::datagen::numbers_below(1000, 2, rng.seed())
+        .into_iter()
+        .enumerate()
+    {
+        if op == 0 {
+            assert_eq!(slot.put(i), expected.put(i));
+        } else {
+            assert_eq!(slot.take(), expected.take());
+        }
+    }
+}
+
+#[test]
+fn hands_over_between_threads_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let values = common::datagen::numbers(1000, rng.seed());
+    let slot = Arc::new(Slot::new());
+    let putter = {
+        let (slot, values) = (slot.clone(), values.clone());
+        thread::spawn(move || {
+            for mut value in values {
+                while let Err(full) = slot.put(value) {
+                    value = full;
+                    thread::yield_now();
+                }
+            }
+        })
+    };
+    let taken: Vec<u64> = (0..values.len())
+        .map(|_| loop {
+            match slot.take() {
+                Some(value) => break value,
+                None => thread::yield_now(),
+            }
+        })
+        .collect();
+    putter.join().unwrap();
+
+    let expected = solutions::Slot::new();
+    let expected: Vec<u64> = values
+        .iter()
+        .map(|&value| {
+            expected.put(value).unwrap();
+            expected.take().unwrap()
+        })
+        .collect();
+    assert_eq!(taken, expected);
+}
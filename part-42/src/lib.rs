@@ -0,0 +1,55 @@
+use std::collections::VecDeque;
+
+// Under `--cfg shuttle` the lock and condition variable are swapped for shuttle's,
+// which lets shuttle decide which thread runs when, and try out many different schedules.
+#[cfg(shuttle)]
+use shuttle::sync::{Condvar, Mutex};
+#[cfg(not(shuttle))]
+use std::sync::{Condvar, Mutex};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// A queue holding at most `capacity` items, which makes producers wait while it's full
+/// and consumers wait while it's empty.
+///
+/// It works well with one producer and one consumer, but can get stuck with more of either.
+#[derive(Debug)]
+pub struct BoundedBuffer<T> {
+    items: Mutex<VecDeque<T>>,
+    capacity: usize,
+    /// Notified whenever an item has been pushed or popped
+    changed: Condvar,
+}
+
+impl<T> BoundedBuffer<T> {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "A buffer needs room for at least one item");
+        Self {
+            items: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+            changed: Condvar::new(),
+        }
+    }
+
+    /// Adds `item` to the back of the buffer, waiting for room if it's full
+    pub fn push(&self, item: T) {
+        let mut items = self.items.lock().unwrap();
+        while items.len() == self.capacity {
+            items = self.changed.wait(items).unwrap();
+        }
+        items.push_back(item);
+        self.changed.notify_one();
+    }
+
+    /// Takes the item at the front of the buffer, waiting for one if it's empty
+    pub fn pop(&self) -> T {
+        let mut items = self.items.lock().unwrap();
+        while items.is_empty() {
+            items = self.changed.wait(items).unwrap();
+        }
+        let item = items.pop_front().unwrap();
+        self.changed.notify_one();
+        item
+    }
+}
@@ -0,0 +1,75 @@
+use std::{sync::Arc, thread};
+
+use part_42::BoundedBuffer;
+
+/// Lets `producers` threads push `per_producer` items each through a buffer of `capacity`,
+/// which `consumers` threads pop an even share of. Returns what each consumer popped.
+fn transfer(
+    capacity: usize,
+    producers: usize,
+    consumers: usize,
+    per_producer: usize,
+) -> Vec<Vec<usize>> {
+    assert_eq!(
+        producers * per_producer % consumers,
+        0,
+        "The items must be shared evenly"
+    );
+    let per_consumer = producers * per_producer / consumers;
+    let buffer = Arc::new(BoundedBuffer::new(capacity));
+
+    for producer in 0..producers {
+        let buffer = buffer.clone();
+        thread::spawn(move || {
+            for i in 0..per_producer {
+                buffer.push(producer * per_producer + i);
+            }
+        });
+    }
+    let consumers: Vec<_> = (0..consumers)
+        .map(|_| {
+            let buffer = buffer.clone();
+            thread::spawn(move || (0..per_consumer).map(|_| buffer.pop()).collect())
+        })
+        .collect();
+    consumers
+        .into_iter()
+        .map(|consumer| consumer.join().unwrap())
+        .collect()
+}
+
+fn main() {
+    let args = common::cli::Cli::new("Passes items through a bounded buffer with room for one")
+        .items(10_000)
+        .parse();
+    for (producers, consumers) in [(1, 1), (2, 2), (4, 4)] {
+        let popped = transfer(1, producers, consumers, args.items());
+        println!(
+            "{producers} producers and {consumers} consumers passed {} items through",
+            popped.iter().map(Vec::len).sum::<usize>()
+        );
+    }
+}
+
+#[test]
+fn pops_in_the_order_pushed() {
+    let buffer = BoundedBuffer::new(3);
+    for i in 0..3 {
+        buffer.push(i);
+    }
+    assert_eq!([buffer.pop(), buffer.pop(), buffer.pop()], [0, 1, 2]);
+}
+
+#[test]
+fn one_producer_and_one_consumer() {
+    let popped = transfer(2, 1, 1, 10_000);
+    assert_eq!(popped, vec![(0..10_000).collect::<Vec<_>>()]);
+}
+
+#[test]
+fn many_producers_and_consumers() {
+    // Looks fine, but these threads are only ever scheduled the way your OS likes to
+    let mut popped: Vec<_> = transfer(4, 2, 2, 1000).concat();
+    popped.sort();
+    assert_eq!(popped, (0..2000).collect::<Vec<_>>());
+}
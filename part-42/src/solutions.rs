@@ -0,0 +1,56 @@
+//! Part 42, with every exercise implemented. Only built with `--features solutions`.
+
+use std::collections::VecDeque;
+
+// Under `--cfg shuttle` the lock and condition variable are swapped for shuttle's,
+// which lets shuttle decide which thread runs when, and try out many different schedules.
+#[cfg(shuttle)]
+use shuttle::sync::{Condvar, Mutex};
+#[cfg(not(shuttle))]
+use std::sync::{Condvar, Mutex};
+
+/// A queue holding at most `capacity` items, which makes producers wait while it's full
+/// and consumers wait while it's empty.
+#[derive(Debug)]
+pub struct BoundedBuffer<T> {
+    items: Mutex<VecDeque<T>>,
+    capacity: usize,
+    /// Notified when an item has been popped, for producers waiting for room
+    not_full: Condvar,
+    /// Notified when an item has been pushed, for consumers waiting for an item
+    not_empty: Condvar,
+}
+
+impl<T> BoundedBuffer<T> {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "A buffer needs room for at least one item");
+        Self {
+            items: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+            not_full: Condvar::new(),
+            not_empty: Condvar::new(),
+        }
+    }
+
+    /// Adds `item` to the back of the buffer, waiting for room if it's full
+    pub fn push(&self, item: T) {
+        let mut items = self.items.lock().unwrap();
+        while items.len() == self.capacity {
+            items = self.not_full.wait(items).unwrap();
+        }
+        items.push_back(item);
+        // Only consumers wait on this, so whoever is woken up can make progress
+        self.not_empty.notify_one();
+    }
+
+    /// Takes the item at the front of the buffer, waiting for one if it's empty
+    pub fn pop(&self) -> T {
+        let mut items = self.items.lock().unwrap();
+        while items.is_empty() {
+            items = self.not_empty.wait(items).unwrap();
+        }
+        let item = items.pop_front().unwrap();
+        self.not_full.notify_one();
+        item
+    }
+}
@@ -0,0 +1,57 @@
+//! Run these with `RUSTFLAGS="--cfg shuttle" cargo test --release -p part-42 --test shuttle`
+#![cfg(shuttle)]
+
+use part_42::BoundedBuffer;
+use shuttle::{scheduler::RandomScheduler, sync::Arc, thread, Config, Runner};
+
+/// Two producers and two consumers passing a few items through a buffer with room for one
+fn producers_and_consumers() {
+    let buffer = Arc::new(BoundedBuffer::new(1));
+    let producers: Vec<_> = (0..2)
+        .map(|producer| {
+            let buffer = buffer.clone();
+            thread::spawn(move || {
+                for i in 0..2 {
+                    buffer.push(producer * 10 + i);
+                }
+            })
+        })
+        .collect();
+    let consumers: Vec<_> = (0..2)
+        .map(|_| {
+            let buffer = buffer.clone();
+            thread::spawn(move || [buffer.pop(), buffer.pop()])
+        })
+        .collect();
+
+    for producer in producers {
+        producer.join().unwrap();
+    }
+    let mut popped: Vec<_> = consumers
+        .into_iter()
+        .flat_map(|consumer| consumer.join().unwrap())
+        .collect();
+    popped.sort();
+    assert_eq!(popped, [0, 1, 10, 11]);
+}
+
+#[test]
+fn random_schedules() {
+    shuttle::check_random(producers_and_consumers, 10_000);
+}
+
+#[test]
+fn pct_schedules() {
+    shuttle::check_pct(producers_and_consumers, 10_000, 3);
+}
+
+/// Seeds for the random scheduler that made the original buffer deadlock
+const FAILING_SEEDS: [u64; 4] = [1, 25, 43, 51];
+
+#[test]
+fn known_failing_seeds() {
+    for seed in FAILING_SEEDS {
+        let scheduler = RandomScheduler::new_from_seed(seed, 1);
+        Runner::new(scheduler, Config::new()).run(producers_and_consumers);
+    }
+}
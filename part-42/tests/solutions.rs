@@ -0,0 +1,64 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-42 --features solutions`.
+#![cfg(all(feature = "solutions", not(shuttle)))]
+
+use std::{sync::Arc, thread, time::Duration};
+
+use part_42::{solutions, BoundedBuffer};
+
+const PRODUCERS: usize = 4;
+const CONSUMERS: usize = 4;
+
+/// Which items each producer pushes, the same for the same seed
+fn items(seed: u64) -> Vec<Vec<u64>> {
+    common::datagen::numbers(PRODUCERS * 500, seed)
+        .chunks(500)
+        .map(<[u64]>::to_vec)
+        .collect()
+}
+
+/// Everything the consumers popped, sorted, or `None` if they got stuck
+macro_rules! transferred {
+    ($buffer:expr, $items:expr) => {{
+        let items: Vec<Vec<u64>> = $items;
+        common::with_timeout(Duration::from_secs(10), move || {
+            let buffer = Arc::new($buffer);
+            let per_consumer = items.len() * items[0].len() / CONSUMERS;
+            let producers: Vec<_> = items
+                .into_iter()
+                .map(|items| {
+                    let buffer = buffer.clone();
+                    thread::spawn(move || items.into_iter().for_each(|item| buffer.push(item)))
+                })
+                .collect();
+            let consumers: Vec<_> = (0..CONSUMERS)
+                .map(|_| {
+                    let buffer = buffer.clone();
+                    thread::spawn(move || {
+                        (0..per_consumer).map(|_| buffer.pop()).collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            producers
+                .into_iter()
+                .for_each(|producer| producer.join().unwrap());
+            let mut popped: Vec<u64> = consumers
+                .into_iter()
+                .flat_map(|consumer| consumer.join().unwrap())
+                .collect();
+            popped.sort();
+            popped
+        })
+    }};
+}
+
+#[test]
+fn transfers_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let items = items(rng.seed());
+    let expected = transferred!(solutions::BoundedBuffer::new(1), items.clone())
+        .expect("The solution got stuck");
+    let popped =
+        transferred!(BoundedBuffer::new(1), items).expect("The producers and consumers got stuck");
+    assert_eq!(popped, expected);
+}
@@ -0,0 +1,59 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, RwLock},
+    thread::{self, JoinHandle},
+};
+
+use crossbeam_channel::Sender;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// What [`Submitter::submit`] gives back once the service is shutting down
+#[derive(Debug, PartialEq, Eq)]
+pub struct Rejected(pub u64);
+
+/// Submits jobs to a [`Service`]. Every clone submits to the same service.
+#[derive(Debug, Clone)]
+pub struct Submitter {
+    /// `None` once the service has stopped taking new jobs
+    intake: Arc<RwLock<Option<Sender<u64>>>>,
+}
+
+impl Submitter {
+    /// Queues `job` to be processed, unless the service is shutting down
+    pub fn submit(&self, job: u64) -> Result<(), Rejected> {
+        todo!()
+    }
+}
+
+/// Puts jobs through `work` on a pool of worker threads,
+/// while an aggregator thread collects the results
+#[derive(Debug)]
+pub struct Service {
+    /// Shared with every [`Submitter`]
+    intake: Arc<RwLock<Option<Sender<u64>>>>,
+    workers: Vec<JoinHandle<()>>,
+    /// Returns every job it got a result for, with its result
+    aggregator: JoinHandle<BTreeMap<u64, u64>>,
+}
+
+impl Service {
+    /// Starts `workers` worker threads putting jobs through `work`, and the aggregator
+    pub fn start(workers: usize, work: impl Fn(u64) -> u64 + Send + Sync + 'static) -> Self {
+        todo!()
+    }
+
+    /// Something to submit jobs with, which can be handed out to producers
+    pub fn submitter(&self) -> Submitter {
+        Submitter {
+            intake: self.intake.clone(),
+        }
+    }
+
+    /// Stops taking new jobs, lets the workers finish every job that has already been accepted,
+    /// and waits for every thread to finish. Returns every accepted job with its result.
+    pub fn shutdown(self) -> BTreeMap<u64, u64> {
+        todo!()
+    }
+}
@@ -0,0 +1,135 @@
+use std::{thread, time::Duration};
+
+use part_43::{Rejected, Service, Submitter};
+
+/// Submits jobs `first..` until the service stops taking them, returning the accepted ones
+fn produce(submitter: Submitter, first: u64) -> Vec<u64> {
+    (first..)
+        .map_while(|job| {
+            thread::sleep(Duration::from_millis(1));
+            submitter.submit(job).ok().map(|()| job)
+        })
+        .collect()
+}
+
+fn main() {
+    let args = common::cli::Cli::new("Shuts down a service on workers while it's busy")
+        .threads(4)
+        .parse();
+    let service = Service::start(args.threads(), |job| {
+        thread::sleep(Duration::from_millis(5));
+        job * job
+    });
+    let producers: Vec<_> = (0..3)
+        .map(|producer| {
+            let submitter = service.submitter();
+            thread::spawn(move || produce(submitter, producer * 1_000_000))
+        })
+        .collect();
+
+    thread::sleep(Duration::from_millis(200));
+    let submitter = service.submitter();
+    let results = service.shutdown();
+    println!("Shut down after processing {} jobs", results.len());
+    assert_eq!(submitter.submit(42), Err(Rejected(42)));
+
+    for producer in producers {
+        let accepted = producer.join().unwrap();
+        println!("A producer had {} jobs accepted", accepted.len());
+    }
+}
+
+#[test]
+fn processes_every_job() {
+    let service = Service::start(4, |job| job * 2);
+    let submitter = service.submitter();
+    for job in 0..100 {
+        submitter.submit(job).unwrap();
+    }
+
+    let results = service.shutdown();
+    assert_eq!(results, (0..100).map(|job| (job, job * 2)).collect());
+}
+
+#[test]
+fn finishes_queued_jobs_before_shutting_down() {
+    let service = Service::start(2, |job| {
+        thread::sleep(Duration::from_millis(10));
+        job
+    });
+    let submitter = service.submitter();
+    for job in 0..20 {
+        submitter.submit(job).unwrap();
+    }
+
+    // Most of the jobs are still queued at this point
+    let results = service.shutdown();
+    assert_eq!(results.len(), 20);
+}
+
+#[test]
+fn rejects_jobs_once_shut_down() {
+    let service = Service::start(2, |job| job);
+    let submitter = service.submitter();
+    submitter.submit(1).unwrap();
+
+    let results = service.shutdown();
+    assert_eq!(submitter.submit(2), Err(Rejected(2)));
+    assert_eq!(results.into_keys().collect::<Vec<_>>(), [1]);
+}
+
+#[test]
+fn no_accepted_job_is_lost() {
+    let service = Service::start(4, |job| {
+        thread::sleep(Duration::from_millis(2));
+        job + 1
+    });
+    let producers: Vec<_> = (0..4)
+        .map(|producer| {
+            let submitter = service.submitter();
+            thread::spawn(move || produce(submitter, producer * 1_000_000))
+        })
+        .collect();
+
+    thread::sleep(Duration::from_millis(100));
+    let results = service.shutdown();
+    let mut accepted: Vec<u64> = producers
+        .into_iter()
+        .flat_map(|producer| producer.join().unwrap())
+        .collect();
+    accepted.sort();
+
+    assert!(!accepted.is_empty());
+    assert_eq!(results.keys().copied().collect::<Vec<_>>(), accepted);
+    assert!(results.iter().all(|(job, result)| *result == job + 1));
+}
+
+#[test]
+fn nothing_runs_after_shutting_down() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    let processed = Arc::new(AtomicUsize::new(0));
+    let service = Service::start(4, {
+        let processed = processed.clone();
+        move |job| {
+            thread::sleep(Duration::from_millis(1));
+            processed.fetch_add(1, Ordering::Relaxed);
+            job
+        }
+    });
+    let submitter = service.submitter();
+    for job in 0..200 {
+        submitter.submit(job).unwrap();
+    }
+
+    let results = service.shutdown();
+    assert_eq!(processed.load(Ordering::Relaxed), results.len());
+    // Every thread holding on to `work` has finished and been joined
+    assert_eq!(Arc::strong_count(&processed), 1);
+
+    thread::sleep(Duration::from_millis(50));
+    assert_eq!(processed.load(Ordering::Relaxed), 200);
+}
@@ -0,0 +1,94 @@
+//! Part 43, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, RwLock},
+    thread::{self, JoinHandle},
+};
+
+use crossbeam_channel::Sender;
+
+use super::Rejected;
+
+/// Submits jobs to a [`Service`]. Every clone submits to the same service.
+#[derive(Debug, Clone)]
+pub struct Submitter {
+    /// `None` once the service has stopped taking new jobs
+    intake: Arc<RwLock<Option<Sender<u64>>>>,
+}
+
+impl Submitter {
+    /// Queues `job` to be processed, unless the service is shutting down
+    pub fn submit(&self, job: u64) -> Result<(), Rejected> {
+        match &*self.intake.read().unwrap() {
+            Some(intake) => {
+                intake.send(job).expect("The workers are gone");
+                Ok(())
+            }
+            None => Err(Rejected(job)),
+        }
+    }
+}
+
+/// Puts jobs through `work` on a pool of worker threads,
+/// while an aggregator thread collects the results
+#[derive(Debug)]
+pub struct Service {
+    /// Shared with every [`Submitter`]
+    intake: Arc<RwLock<Option<Sender<u64>>>>,
+    workers: Vec<JoinHandle<()>>,
+    /// Returns every job it got a result for, with its result
+    aggregator: JoinHandle<BTreeMap<u64, u64>>,
+}
+
+impl Service {
+    /// Starts `workers` worker threads putting jobs through `work`, and the aggregator
+    pub fn start(workers: usize, work: impl Fn(u64) -> u64 + Send + Sync + 'static) -> Self {
+        let (intake, jobs) = crossbeam_channel::unbounded();
+        let (results, collected) = crossbeam_channel::unbounded();
+        let work = Arc::new(work);
+
+        let workers = (0..workers)
+            .map(|_| {
+                let jobs = jobs.clone();
+                let results = results.clone();
+                let work = work.clone();
+                thread::spawn(move || {
+                    for job in jobs {
+                        results
+                            .send((job, work(job)))
+                            .expect("The aggregator is gone");
+                    }
+                })
+            })
+            .collect();
+        // Only the workers may keep a sender, so the aggregator is done once they all are
+        drop(results);
+        let aggregator = thread::spawn(move || collected.into_iter().collect());
+
+        Self {
+            intake: Arc::new(RwLock::new(Some(intake))),
+            workers,
+            aggregator,
+        }
+    }
+
+    /// Something to submit jobs with, which can be handed out to producers
+    pub fn submitter(&self) -> Submitter {
+        Submitter {
+            intake: self.intake.clone(),
+        }
+    }
+
+    /// Stops taking new jobs, lets the workers finish every job that has already been accepted,
+    /// and waits for every thread to finish. Returns every accepted job with its result.
+    pub fn shutdown(self) -> BTreeMap<u64, u64> {
+        // The write lock waits for any `submit` in progress, so no job slips in after this.
+        // Dropping the only sender lets the workers finish once the queued jobs are done.
+        self.intake.write().unwrap().take();
+        for worker in self.workers {
+            worker.join().expect("A worker panicked");
+        }
+        self.aggregator.join().expect("The aggregator panicked")
+    }
+}
@@ -0,0 +1,38 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-43 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_43::{solutions, Service};
+
+fn work(job: u64) -> u64 {
+    job.wrapping_mul(job) % 1_000_003
+}
+
+#[test]
+fn serves_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let jobs = common::datagen::numbers(1000, rng.seed());
+
+    let service = Service::start(4, work);
+    let submitter = service.submitter();
+    for &job in &jobs {
+        submitter.submit(job).unwrap();
+    }
+    let results = service.shutdown();
+
+    let expected_service = solutions::Service::start(4, work);
+    let expected_submitter = expected_service.submitter();
+    for &job in &jobs {
+        expected_submitter.submit(job).unwrap();
+    }
+    let expected = expected_service.shutdown();
+
+    assert_eq!(results, expected);
+    assert_eq!(
+        submitter.submit(jobs[0]).map_err(|rejected| rejected.0),
+        expected_submitter
+            .submit(jobs[0])
+            .map_err(|rejected| rejected.0),
+        "Submitting after the shutdown"
+    );
+}
@@ -0,0 +1,57 @@
+use std::{
+    ops::Range,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    thread,
+};
+
+use crossbeam_channel::{Receiver, Sender};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How many numbers a worker checks at a time
+pub const CHUNK: u64 = 10_000;
+
+/// Asks a running computation to stop, e.g. because someone pressed Ctrl-C
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interrupt;
+
+/// Sends an [`Interrupt`] on the returned channel every time Ctrl-C is pressed.
+/// Can only be called once per process.
+pub fn ctrl_c() -> Receiver<Interrupt> {
+    let (sender, interrupts) = crossbeam_channel::bounded(1);
+    // The handler runs on a thread of its own, and should do as little as possible.
+    // If an interrupt is already waiting, there's no need to send another one.
+    ctrlc::set_handler(move || {
+        let _ = sender.try_send(Interrupt);
+    })
+    .expect("Couldn't set the Ctrl-C handler");
+    interrupts
+}
+
+/// A fake source of interrupts, for testing code that handles Ctrl-C
+pub fn fake_ctrl_c() -> (Sender<Interrupt>, Receiver<Interrupt>) {
+    crossbeam_channel::bounded(1)
+}
+
+/// The numbers a worker has checked, and how many of them were primes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checked {
+    pub numbers: Range<u64>,
+    pub primes: usize,
+}
+
+pub fn is_prime(n: u64) -> bool {
+    n >= 2
+        && (2..)
+            .take_while(|d| d * d <= n)
+            .all(|d| !n.is_multiple_of(d))
+}
+
+/// Counts the primes below `limit`, by letting `workers` threads check a [`CHUNK`] at a time.
+/// Stops early once `interrupts` receives an [`Interrupt`], finishing the chunks that
+/// have already been started. If `interrupts` is closed, no interrupt can come, and the search
+/// runs to `limit`. Returns every chunk that was checked, sorted by where they start.
+pub fn count_primes(interrupts: &Receiver<Interrupt>, limit: u64, workers: usize) -> Vec<Checked> {
+    todo!()
+}
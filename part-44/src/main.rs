@@ -0,0 +1,108 @@
+use std::time::Instant;
+
+use part_44::{count_primes, ctrl_c, Checked, CHUNK};
+
+/// How many primes there are in total in `checked`, and below which number every one of them is
+fn summarize(checked: &[Checked]) -> (usize, u64) {
+    let primes = checked.iter().map(|checked| checked.primes).sum();
+    let contiguous = checked
+        .iter()
+        .zip(0..)
+        .take_while(|(checked, i)| checked.numbers.start == i * CHUNK)
+        .last()
+        .map_or(0, |(checked, _)| checked.numbers.end);
+    (primes, contiguous)
+}
+
+fn main() {
+    let args = common::cli::Cli::new("Counts primes on several threads until stopped")
+        .items(1_000_000_000)
+        .threads(std::thread::available_parallelism().map_or(4, |n| n.get()))
+        .parse();
+    let (limit, workers) = (args.items() as u64, args.threads());
+    println!("Counting primes below {limit} on {workers} threads, press Ctrl-C to stop");
+
+    let start = Instant::now();
+    let checked = count_primes(&ctrl_c(), limit, workers);
+    let (primes, contiguous) = summarize(&checked);
+    println!(
+        "Found {primes} primes in {:.1?}, having checked every number below {contiguous}",
+        start.elapsed()
+    );
+}
+
+#[cfg(test)]
+fn assert_counted_correctly(checked: &[Checked]) {
+    use part_44::is_prime;
+
+    for checked in checked {
+        assert_eq!(
+            checked.numbers.clone().filter(|&n| is_prime(n)).count(),
+            checked.primes,
+            "Wrong count for {:?}",
+            checked.numbers
+        );
+    }
+}
+
+#[test]
+fn counts_every_prime_below_the_limit() {
+    use part_44::fake_ctrl_c;
+
+    let (_sender, interrupts) = fake_ctrl_c();
+    let checked = count_primes(&interrupts, 100_000, 4);
+    assert_eq!(summarize(&checked), (9592, 100_000));
+    assert_eq!(checked.len(), 10);
+}
+
+#[test]
+fn runs_to_the_limit_without_a_signal_source() {
+    use part_44::fake_ctrl_c;
+
+    let (sender, interrupts) = fake_ctrl_c();
+    drop(sender);
+    let checked = count_primes(&interrupts, 25_000, 3);
+    assert_eq!(summarize(&checked), (2762, 25_000));
+}
+
+#[test]
+fn stops_promptly_when_interrupted() {
+    use part_44::{fake_ctrl_c, Interrupt};
+    use std::time::Duration;
+
+    let (sender, interrupts) = fake_ctrl_c();
+    let interrupter = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(200));
+        sender.send(Interrupt).unwrap();
+        Instant::now()
+    });
+
+    let checked = count_primes(&interrupts, u64::MAX, 4);
+    let stopped = Instant::now();
+    let interrupted = interrupter.join().unwrap();
+
+    assert!(
+        stopped - interrupted < Duration::from_millis(500),
+        "Took {:?} to stop",
+        stopped - interrupted
+    );
+    assert!(!checked.is_empty());
+    assert_counted_correctly(&checked);
+}
+
+#[test]
+fn an_early_interrupt_stops_before_much_is_done() {
+    use part_44::{fake_ctrl_c, Interrupt};
+    use std::time::Duration;
+
+    let (sender, interrupts) = fake_ctrl_c();
+    sender.send(Interrupt).unwrap();
+
+    let start = Instant::now();
+    let checked = count_primes(&interrupts, u64::MAX, 4);
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    assert!(start.elapsed() < Duration::from_millis(500));
+    assert_counted_correctly(&checked);
+}
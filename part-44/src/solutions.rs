@@ -0,0 +1,62 @@
+//! Part 44, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    thread,
+};
+
+use crossbeam_channel::{select, Receiver};
+
+use super::{is_prime, Checked, Interrupt, CHUNK};
+
+/// Counts the primes below `limit`, by letting `workers` threads check a [`CHUNK`] at a time.
+/// Stops early once `interrupts` receives an [`Interrupt`], finishing the chunks that
+/// have already been started. If `interrupts` is closed, no interrupt can come, and the search
+/// runs to `limit`. Returns every chunk that was checked, sorted by where they start.
+pub fn count_primes(interrupts: &Receiver<Interrupt>, limit: u64, workers: usize) -> Vec<Checked> {
+    let stop = AtomicBool::new(false);
+    let next = AtomicU64::new(0);
+    // Never sent on, but closed once every worker is done, which lets the watcher stop waiting
+    let (done, finished) = crossbeam_channel::bounded::<()>(0);
+
+    let mut checked: Vec<Checked> = thread::scope(|s| {
+        s.spawn(|| {
+            select! {
+                recv(interrupts) -> interrupt => {
+                    if interrupt.is_ok() {
+                        stop.store(true, Ordering::Relaxed);
+                    }
+                }
+                recv(finished) -> _ => {}
+            }
+        });
+
+        let workers: Vec<_> = (0..workers)
+            .map(|_| {
+                let done = done.clone();
+                s.spawn(|| {
+                    let _done = done;
+                    let mut checked = Vec::new();
+                    while !stop.load(Ordering::Relaxed) {
+                        let start = next.fetch_add(CHUNK, Ordering::Relaxed);
+                        if start >= limit {
+                            break;
+                        }
+                        let numbers = start..(start + CHUNK).min(limit);
+                        let primes = numbers.clone().filter(|&n| is_prime(n)).count();
+                        checked.push(Checked { numbers, primes });
+                    }
+                    checked
+                })
+            })
+            .collect();
+        drop(done);
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("A worker panicked"))
+            .collect()
+    });
+    checked.sort_by_key(|checked| checked.numbers.start);
+    checked
+}
@@ -0,0 +1,40 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-44 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_44::{count_primes, fake_ctrl_c, solutions, Interrupt};
+
+#[test]
+fn counts_like_the_solution() {
+    for (limit, workers) in [(0, 1), (25_000, 1), (100_000, 4)] {
+        // Without an interrupt the search runs to the limit
+        let (sender, interrupts) = fake_ctrl_c();
+        drop(sender);
+        let checked: Vec<_> = count_primes(&interrupts, limit, workers)
+            .into_iter()
+            .map(|checked| (checked.numbers, checked.primes))
+            .collect();
+        let (sender, interrupts) = fake_ctrl_c();
+        drop(sender);
+        let expected: Vec<_> = solutions::count_primes(&interrupts, limit, workers)
+            .into_iter()
+            .map(|checked| (checked.numbers, checked.primes))
+            .collect();
+        assert_eq!(checked, expected, "Below {limit} on {workers} workers");
+    }
+}
+
+#[test]
+fn stops_like_the_solution() {
+    let (sender, interrupts) = fake_ctrl_c();
+    sender.send(Interrupt).unwrap();
+    let checked = count_primes(&interrupts, u64::MAX, 4);
+    let (sender, interrupts) = fake_ctrl_c();
+    sender.send(Interrupt).unwrap();
+    let expected = solutions::count_primes(&interrupts, u64::MAX, 4);
+    // How many chunks were started before the interrupt was seen is up to the scheduler
+    assert!(checked.len() <= 4 && expected.len() <= 4);
+    for (checked, expected) in checked.iter().zip(&expected) {
+        assert_eq!(checked.primes, expected.primes);
+    }
+}
@@ -0,0 +1,91 @@
+use std::{
+    cell::UnsafeCell,
+    mem::MaybeUninit,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// The buffer shared by a [`Producer`] and a [`Consumer`].
+///
+/// `head` and `tail` count how many items have been popped and pushed in total,
+/// so the items are in the slots from `head` up to `tail`, wrapping around the end.
+/// Only the consumer moves `head`, and only the producer moves `tail`.
+struct Shared<T> {
+    slots: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    /// The next item to pop
+    head: AtomicUsize,
+    /// Where the next item is pushed
+    tail: AtomicUsize,
+}
+
+// SAFETY: The producer only writes to slots the consumer is done with, and the consumer only
+// reads slots the producer is done with. The items are moved between threads, so they must be `Send`.
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+impl<T> Shared<T> {
+    fn slot(&self, index: usize) -> *mut MaybeUninit<T> {
+        self.slots[index % self.slots.len()].get()
+    }
+}
+
+impl<T> Drop for Shared<T> {
+    fn drop(&mut self) {
+        let (head, tail) = (*self.head.get_mut(), *self.tail.get_mut());
+        for index in head..tail {
+            // SAFETY: The slots from `head` to `tail` hold items which haven't been popped,
+            // and no one else can use them anymore
+            unsafe { (*self.slot(index)).assume_init_drop() };
+        }
+    }
+}
+
+/// The pushing end of a ring buffer
+pub struct Producer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// The popping end of a ring buffer
+pub struct Consumer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// A lock-free queue with room for `capacity` items, for one thread to push to and another to
+/// pop from. Only having one of each is what lets it get away without locks.
+pub fn ring_buffer<T>(capacity: usize) -> (Producer<T>, Consumer<T>) {
+    assert!(
+        capacity > 0,
+        "A ring buffer needs room for at least one item"
+    );
+    let shared = Arc::new(Shared {
+        slots: (0..capacity)
+            .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+            .collect(),
+        head: AtomicUsize::new(0),
+        tail: AtomicUsize::new(0),
+    });
+    (
+        Producer {
+            shared: shared.clone(),
+        },
+        Consumer { shared },
+    )
+}
+
+impl<T> Producer<T> {
+    /// Adds `item` to the back of the buffer, or gives it back if the buffer is full
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        todo!()
+    }
+}
+
+impl<T> Consumer<T> {
+    /// Takes the item at the front of the buffer, if there is one
+    pub fn pop(&mut self) -> Option<T> {
+        todo!()
+    }
+}
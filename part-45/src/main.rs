@@ -0,0 +1,125 @@
+use std::{
+    sync::mpsc,
+    thread::{self, yield_now},
+};
+
+use common::{bench::Comparison, cli::Cli};
+use part_45::{ring_buffer, Consumer, Producer};
+
+/// Pushes `items` one by one, waiting for room whenever the buffer is full
+fn push_all(producer: &mut Producer<u64>, items: impl IntoIterator<Item = u64>) {
+    for mut item in items {
+        while let Err(rejected) = producer.push(item) {
+            item = rejected;
+            yield_now();
+        }
+    }
+}
+
+/// Pops `count` items, waiting for more whenever the buffer is empty
+fn pop_all(consumer: &mut Consumer<u64>, count: u64) -> Vec<u64> {
+    (0..count)
+        .map(|_| loop {
+            match consumer.pop() {
+                Some(item) => break item,
+                None => yield_now(),
+            }
+        })
+        .collect()
+}
+
+/// Passes `items` numbers from one thread to another through a ring buffer of `capacity`
+fn through_ring_buffer(capacity: usize, items: u64) -> Vec<u64> {
+    let (mut producer, mut consumer) = ring_buffer(capacity);
+    thread::scope(|s| {
+        s.spawn(move || push_all(&mut producer, 0..items));
+        pop_all(&mut consumer, items)
+    })
+}
+
+/// Passes `items` numbers from one thread to another through a `sync_channel` of `capacity`
+fn through_sync_channel(capacity: usize, items: u64) -> Vec<u64> {
+    let (sender, receiver) = mpsc::sync_channel(capacity);
+    thread::scope(|s| {
+        s.spawn(move || {
+            for item in 0..items {
+                sender.send(item).unwrap();
+            }
+        });
+        receiver.iter().collect()
+    })
+}
+
+/// Run with `cargo run --release -p part-45` to compare the two
+fn main() {
+    let args = Cli::new("Compares a ring buffer with a sync_channel, at several capacities")
+        .items(1_000_000)
+        .strategy()
+        .parse();
+    let items = args.items() as u64;
+    for capacity in [8, 64, 1024] {
+        println!("Passing {items} numbers through a capacity of {capacity}");
+        Comparison::new(5)
+            .bench("Ring buffer", || through_ring_buffer(capacity, items))
+            .bench("sync_channel", || through_sync_channel(capacity, items))
+            .print();
+    }
+}
+
+#[test]
+fn pushes_until_full_and_pops_until_empty() {
+    let (mut producer, mut consumer) = ring_buffer(3);
+    assert_eq!(consumer.pop(), None);
+    for item in 0..3 {
+        assert_eq!(producer.push(item), Ok(()));
+    }
+    assert_eq!(producer.push(3), Err(3));
+
+    assert_eq!(consumer.pop(), Some(0));
+    assert_eq!(producer.push(3), Ok(()));
+    assert_eq!(
+        [
+            consumer.pop(),
+            consumer.pop(),
+            consumer.pop(),
+            consumer.pop()
+        ],
+        [Some(1), Some(2), Some(3), None]
+    );
+}
+
+#[test]
+fn wraps_around_many_times() {
+    let (mut producer, mut consumer) = ring_buffer(2);
+    for item in 0..1000 {
+        producer.push(item).unwrap();
+        assert_eq!(consumer.pop(), Some(item));
+    }
+}
+
+#[test]
+fn keeps_the_order_across_threads() {
+    for capacity in [1, 2, 3, 16, 1024] {
+        assert_eq!(
+            through_ring_buffer(capacity, 100_000),
+            (0..100_000).collect::<Vec<_>>(),
+            "Wrong items with a capacity of {capacity}"
+        );
+    }
+}
+
+#[test]
+fn items_left_in_the_buffer_are_dropped() {
+    use std::sync::Arc;
+
+    let item = Arc::new(());
+    let (mut producer, mut consumer) = ring_buffer(4);
+    for _ in 0..3 {
+        producer.push(item.clone()).unwrap();
+    }
+    drop(consumer.pop());
+    assert_eq!(Arc::strong_count(&item), 3);
+
+    drop((producer, consumer));
+    assert_eq!(Arc::strong_count(&item), 1);
+}
@@ -0,0 +1,83 @@
+//! Part 45, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    cell::UnsafeCell,
+    mem::MaybeUninit,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use super::Shared;
+
+/// The pushing end of a ring buffer
+pub struct Producer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// The popping end of a ring buffer
+pub struct Consumer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// A lock-free queue with room for `capacity` items, for one thread to push to and another to
+/// pop from. Only having one of each is what lets it get away without locks.
+pub fn ring_buffer<T>(capacity: usize) -> (Producer<T>, Consumer<T>) {
+    assert!(
+        capacity > 0,
+        "A ring buffer needs room for at least one item"
+    );
+    let shared = Arc::new(Shared {
+        slots: (0..capacity)
+            .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+            .collect(),
+        head: AtomicUsize::new(0),
+        tail: AtomicUsize::new(0),
+    });
+    (
+        Producer {
+            shared: shared.clone(),
+        },
+        Consumer { shared },
+    )
+}
+
+impl<T> Producer<T> {
+    /// Adds `item` to the back of the buffer, or gives it back if the buffer is full
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        let shared = &*self.shared;
+        // Only this thread moves `tail`
+        let tail = shared.tail.load(Ordering::Relaxed);
+        // Acquire: pairs with the `Release` in `pop`, so the consumer is done with the slot
+        let head = shared.head.load(Ordering::Acquire);
+        if tail - head == shared.slots.len() {
+            return Err(item);
+        }
+        // SAFETY: The slot isn't between `head` and `tail`, so the consumer won't touch it
+        unsafe { (*shared.slot(tail)).write(item) };
+        // Release: the item is written before the consumer sees it
+        shared.tail.store(tail + 1, Ordering::Release);
+        Ok(())
+    }
+}
+
+impl<T> Consumer<T> {
+    /// Takes the item at the front of the buffer, if there is one
+    pub fn pop(&mut self) -> Option<T> {
+        let shared = &*self.shared;
+        // Only this thread moves `head`
+        let head = shared.head.load(Ordering::Relaxed);
+        // Acquire: pairs with the `Release` in `push`, so the item has been written
+        let tail = shared.tail.load(Ordering::Acquire);
+        if head == tail {
+            return None;
+        }
+        // SAFETY: The slot is between `head` and `tail`, so the producer is done with it,
+        // and moving `head` past it afterwards makes sure it's only read once
+        let item = unsafe { (*shared.slot(head)).assume_init_read() };
+        // Release: the item is read before the producer can reuse the slot
+        shared.head.store(head + 1, Ordering::Release);
+        Some(item)
+    }
+}
@@ -0,0 +1,61 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-45 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::thread;
+
+use part_45::{ring_buffer, solutions};
+
+#[test]
+fn buffers_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (mut producer, mut consumer) = ring_buffer(8);
+    let (mut expected_producer, mut expected_consumer) = solutions::ring_buffer(8);
+    for (i, op) in common::datagen::numbers_below(1000, 3, rng.seed())
+        .into_iter()
+        .enumerate()
+    {
+        if op == 0 {
+            assert_eq!(consumer.pop(), expected_consumer.pop());
+        } else {
+            assert_eq!(producer.push(i), expected_producer.push(i), "Pushing {i}");
+        }
+    }
+}
+
+#[test]
+fn hands_over_between_threads_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let items = common::datagen::numbers(10_000, rng.seed());
+    let (mut producer, mut consumer) = ring_buffer(16);
+    let pushing = {
+        let items = items.clone();
+        thread::spawn(move || {
+            for mut item in items {
+                while let Err(full) = producer.push(item) {
+                    item = full;
+                    thread::yield_now();
+                }
+            }
+        })
+    };
+    let popped: Vec<u64> = (0..items.len())
+        .map(|_| loop {
+            match consumer.pop() {
+                Some(item) => break item,
+                None => thread::yield_now(),
+            }
+        })
+        .collect();
+    pushing.join().unwrap();
+
+    let (mut expected_producer, mut expected_consumer) = solutions::ring_buffer(16);
+    let expected: Vec<u64> = items
+        .iter()
+        .map(|&item| {
+            expected_producer.push(item).unwrap();
+            expected_consumer.pop().unwrap()
+        })
+        .collect();
+    assert_eq!(popped, expected);
+}
@@ -0,0 +1,101 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use common::Semaphore;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Gives the other philosophers time to pick up their forks, making the deadlock happen every time
+pub fn pause() {
+    thread::sleep(Duration::from_millis(10));
+}
+
+/// A round table with a fork between every two philosophers
+#[derive(Debug)]
+pub struct Table {
+    forks: Vec<Mutex<()>>,
+    /// How many meals each philosopher has eaten
+    meals: Vec<AtomicUsize>,
+}
+
+impl Table {
+    pub fn new(philosophers: usize) -> Self {
+        assert!(philosophers >= 2, "It takes two forks to eat");
+        Self {
+            forks: (0..philosophers).map(|_| Mutex::new(())).collect(),
+            meals: (0..philosophers).map(|_| AtomicUsize::new(0)).collect(),
+        }
+    }
+
+    /// The index of the forks to the left and to the right of `philosopher`
+    pub fn forks_of(&self, philosopher: usize) -> (usize, usize) {
+        (philosopher, (philosopher + 1) % self.forks.len())
+    }
+
+    pub fn fork(&self, fork: usize) -> &Mutex<()> {
+        &self.forks[fork]
+    }
+
+    /// Lets `philosopher` eat a meal. Only call this while holding both of their forks!
+    pub fn eat(&self, philosopher: usize) {
+        thread::sleep(Duration::from_millis(1));
+        self.meals[philosopher].fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Seats `philosophers` philosophers at a table, and lets each of them `dine` until they've eaten
+/// `meals` meals. `dine` gets the table and which philosopher is eating, and must call
+/// [`Table::eat`] once. Returns how many meals each of the philosophers ate.
+fn dinner(philosophers: usize, meals: usize, dine: impl Fn(&Table, usize) + Sync) -> Vec<usize> {
+    let table = Table::new(philosophers);
+    thread::scope(|s| {
+        for philosopher in 0..philosophers {
+            let (table, dine) = (&table, &dine);
+            s.spawn(move || {
+                let _philosopher = common::span::worker(philosopher);
+                for _ in 0..meals {
+                    dine(table, philosopher);
+                }
+            });
+        }
+    });
+    table
+        .meals
+        .into_iter()
+        .map(AtomicUsize::into_inner)
+        .collect()
+}
+
+/// Every philosopher picks up the fork to their left, and then the one to their right.
+///
+/// Deadlocks every time: once everyone holds their left fork,
+/// every right fork is someone else's left fork, and no one will ever put theirs down.
+pub fn naive(philosophers: usize, meals: usize) -> Vec<usize> {
+    dinner(philosophers, meals, |table, philosopher| {
+        let (left, right) = table.forks_of(philosopher);
+        let _left = table.fork(left).lock().unwrap();
+        pause();
+        let _right = table.fork(right).lock().unwrap();
+        table.eat(philosopher);
+    })
+}
+
+/// Lets the philosophers dine without deadlocking, by picking up the forks in a different order.
+/// Keep the `pause()` between picking up the two forks, so you know the fix isn't just luck.
+pub fn ordered(philosophers: usize, meals: usize) -> Vec<usize> {
+    todo!()
+}
+
+/// Lets the philosophers dine without deadlocking, by having a waiter that only lets some of
+/// them try to eat at the same time. Every philosopher picks up their left fork first,
+/// and there's still a `pause()` before they pick up the right one.
+pub fn with_waiter(philosophers: usize, meals: usize) -> Vec<usize> {
+    todo!()
+}
@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use common::with_timeout;
+use part_46::{naive, ordered, with_waiter};
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+fn main() {
+    common::init_tracing();
+    report("Naive", with_timeout(TIMEOUT, || naive(5, 10)));
+    report("Ordered", with_timeout(TIMEOUT, || ordered(5, 10)));
+    report(
+        "With a waiter",
+        with_timeout(TIMEOUT, || with_waiter(5, 10)),
+    );
+}
+
+fn report(name: &str, meals: Option<Vec<usize>>) {
+    match meals {
+        Some(meals) => println!("{name}: the philosophers ate {meals:?} meals"),
+        None => println!("{name}: the philosophers deadlocked!"),
+    }
+}
+
+#[test]
+fn naive_deadlocks() {
+    assert_eq!(with_timeout(Duration::from_secs(1), || naive(5, 10)), None);
+}
+
+#[test]
+fn ordered_lets_everyone_eat() {
+    for philosophers in [2, 3, 5, 8] {
+        assert_eq!(
+            with_timeout(TIMEOUT, move || ordered(philosophers, 10)),
+            Some(vec![10; philosophers]),
+            "{philosophers} philosophers didn't all get to eat in time"
+        );
+    }
+}
+
+#[test]
+fn with_waiter_lets_everyone_eat() {
+    for philosophers in [2, 3, 5, 8] {
+        assert_eq!(
+            with_timeout(TIMEOUT, move || with_waiter(philosophers, 10)),
+            Some(vec![10; philosophers]),
+            "{philosophers} philosophers didn't all get to eat in time"
+        );
+    }
+}
@@ -0,0 +1,36 @@
+//! Part 46, with every exercise implemented. Only built with `--features solutions`.
+
+use common::Semaphore;
+
+use super::{dinner, pause};
+
+/// Lets the philosophers dine without deadlocking, by picking up the forks in a different order.
+/// Keep the `pause()` between picking up the two forks, so you know the fix isn't just luck.
+pub fn ordered(philosophers: usize, meals: usize) -> Vec<usize> {
+    dinner(philosophers, meals, |table, philosopher| {
+        let (left, right) = table.forks_of(philosopher);
+        // Everyone picks up the lower numbered fork first, so the last philosopher
+        // reaches for the same fork as the first, and one of them has to wait with empty hands
+        let (first, second) = (left.min(right), left.max(right));
+        let _first = table.fork(first).lock().unwrap();
+        pause();
+        let _second = table.fork(second).lock().unwrap();
+        table.eat(philosopher);
+    })
+}
+
+/// Lets the philosophers dine without deadlocking, by having a waiter that only lets some of
+/// them try to eat at the same time. Every philosopher picks up their left fork first,
+/// and there's still a `pause()` before they pick up the right one.
+pub fn with_waiter(philosophers: usize, meals: usize) -> Vec<usize> {
+    // With one seat fewer than there are philosophers, someone always gets two forks
+    let waiter = Semaphore::new(philosophers - 1);
+    dinner(philosophers, meals, |table, philosopher| {
+        let _seat = waiter.acquire();
+        let (left, right) = table.forks_of(philosopher);
+        let _left = table.fork(left).lock().unwrap();
+        pause();
+        let _right = table.fork(right).lock().unwrap();
+        table.eat(philosopher);
+    })
+}
@@ -0,0 +1,28 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-46 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::time::Duration;
+
+use common::with_timeout;
+use part_46::{ordered, solutions, with_waiter};
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+#[test]
+fn dines_like_the_solution() {
+    for (philosophers, meals) in [(2, 3), (5, 3)] {
+        let expected = solutions::ordered(philosophers, meals);
+        assert_eq!(
+            with_timeout(TIMEOUT, move || ordered(philosophers, meals)),
+            Some(expected.clone()),
+            "Ordered philosophers deadlocked, or ate the wrong number of meals"
+        );
+        assert_eq!(
+            with_timeout(TIMEOUT, move || with_waiter(philosophers, meals)),
+            Some(expected.clone()),
+            "Waited-on philosophers deadlocked, or ate the wrong number of meals"
+        );
+        assert_eq!(solutions::with_waiter(philosophers, meals), expected);
+    }
+}
@@ -0,0 +1,106 @@
+use std::{
+    cell::UnsafeCell,
+    ops::{Deref, DerefMut},
+    sync::{Condvar, Mutex},
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Who holds the lock, and who is waiting for it
+#[derive(Debug, Default)]
+struct State {
+    /// How many readers hold the lock
+    readers: usize,
+    /// Whether a writer holds the lock
+    writer: bool,
+    /// How many writers are waiting for the lock
+    waiting_writers: usize,
+}
+
+/// A lock that lets either any number of readers, or a single writer, access the value.
+/// Writers go first: once a writer is waiting, new readers have to wait for it.
+#[derive(Debug)]
+pub struct RwLock<T> {
+    state: Mutex<State>,
+    /// Notified when readers may be able to take the lock
+    readers_can_enter: Condvar,
+    /// Notified when a writer may be able to take the lock
+    writer_can_enter: Condvar,
+    value: UnsafeCell<T>,
+}
+
+// SAFETY: `state` makes sure the value is only shared by readers, or changed by a single writer.
+// Readers on many threads share it, so it must be `Sync`, and writers may change it, or even
+// replace it, from any thread, so it must be `Send` too.
+unsafe impl<T: Send + Sync> Sync for RwLock<T> {}
+
+/// Gives shared access to the value of a [`RwLock`], until it's dropped
+#[derive(Debug)]
+pub struct ReadGuard<'a, T> {
+    lock: &'a RwLock<T>,
+}
+
+/// Gives exclusive access to the value of a [`RwLock`], until it's dropped
+#[derive(Debug)]
+pub struct WriteGuard<'a, T> {
+    lock: &'a RwLock<T>,
+}
+
+impl<T> RwLock<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            state: Mutex::default(),
+            readers_can_enter: Condvar::new(),
+            writer_can_enter: Condvar::new(),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Waits until there are no writers holding or waiting for the lock, and takes it for reading
+    pub fn read(&self) -> ReadGuard<'_, T> {
+        todo!()
+    }
+
+    /// Waits until no one else holds the lock, and takes it for writing
+    pub fn write(&self) -> WriteGuard<'_, T> {
+        todo!()
+    }
+}
+
+impl<T> Drop for ReadGuard<'_, T> {
+    fn drop(&mut self) {
+        todo!()
+    }
+}
+
+impl<T> Drop for WriteGuard<'_, T> {
+    fn drop(&mut self) {
+        todo!()
+    }
+}
+
+impl<T> Deref for ReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: Only readers hold the lock while this guard exists
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> Deref for WriteGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: No one else holds the lock while this guard exists
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> DerefMut for WriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: No one else holds the lock while this guard exists
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
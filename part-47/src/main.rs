@@ -0,0 +1,123 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+use part_47::RwLock;
+
+/// Lets `readers` threads hold the lock for reading as much as they can, overlapping each other,
+/// while another thread takes it for writing `writes` times.
+/// Returns how long the longest wait for the write lock was.
+fn longest_write_wait(readers: usize, writes: usize) -> Duration {
+    let lock = RwLock::new(0);
+    let done = AtomicBool::new(false);
+    thread::scope(|s| {
+        for _ in 0..readers {
+            s.spawn(|| {
+                while !done.load(Ordering::Relaxed) {
+                    let _value = lock.read();
+                    thread::sleep(Duration::from_millis(2));
+                }
+            });
+        }
+
+        // Give the readers time to get going
+        thread::sleep(Duration::from_millis(20));
+        let longest = (0..writes)
+            .map(|_| {
+                let start = Instant::now();
+                *lock.write() += 1;
+                let waited = start.elapsed();
+                thread::sleep(Duration::from_millis(5));
+                waited
+            })
+            .max()
+            .unwrap_or_default();
+        done.store(true, Ordering::Relaxed);
+        longest
+    })
+}
+
+fn main() {
+    for readers in [1, 4, 16] {
+        println!(
+            "With {readers} readers, the longest wait for a write was {:?}",
+            longest_write_wait(readers, 20)
+        );
+    }
+}
+
+#[test]
+fn readers_share_the_lock() {
+    use std::sync::Barrier;
+
+    let finished = common::with_timeout(Duration::from_secs(1), || {
+        let lock = RwLock::new(42);
+        let barrier = Barrier::new(4);
+        thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    let value = lock.read();
+                    // Every reader waits here while holding the lock
+                    barrier.wait();
+                    assert_eq!(*value, 42);
+                });
+            }
+        });
+    });
+    assert!(
+        finished.is_some(),
+        "The readers didn't get the lock at once"
+    );
+}
+
+#[test]
+fn writers_have_the_lock_to_themselves() {
+    let lock = RwLock::new(0);
+    thread::scope(|s| {
+        for _ in 0..8 {
+            s.spawn(|| {
+                for _ in 0..1000 {
+                    *lock.write() += 1;
+                }
+            });
+        }
+    });
+    assert_eq!(*lock.read(), 8000);
+}
+
+#[test]
+fn readers_never_see_half_a_write() {
+    let lock = RwLock::new((0, 0));
+    thread::scope(|s| {
+        s.spawn(|| {
+            for i in 1..=100 {
+                let mut pair = lock.write();
+                pair.0 = i;
+                thread::yield_now();
+                pair.1 = i;
+            }
+        });
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..1000 {
+                    let pair = lock.read();
+                    assert_eq!(pair.0, pair.1);
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn writers_are_not_starved_by_readers() {
+    // The readers always overlap, so a writer would wait forever if it had to wait for no one
+    // to be reading
+    let longest = common::with_timeout(Duration::from_secs(5), || longest_write_wait(8, 10))
+        .expect("The writer was starved");
+    assert!(
+        longest < Duration::from_millis(100),
+        "A writer waited {longest:?}"
+    );
+}
@@ -0,0 +1,122 @@
+//! Part 47, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    cell::UnsafeCell,
+    ops::{Deref, DerefMut},
+    sync::{Condvar, Mutex},
+};
+
+use super::State;
+
+/// A lock that lets either any number of readers, or a single writer, access the value.
+/// Writers go first: once a writer is waiting, new readers have to wait for it.
+#[derive(Debug)]
+pub struct RwLock<T> {
+    state: Mutex<State>,
+    /// Notified when readers may be able to take the lock
+    readers_can_enter: Condvar,
+    /// Notified when a writer may be able to take the lock
+    writer_can_enter: Condvar,
+    value: UnsafeCell<T>,
+}
+
+// SAFETY: `state` makes sure the value is only shared by readers, or changed by a single writer.
+// Readers on many threads share it, so it must be `Sync`, and writers may change it, or even
+// replace it, from any thread, so it must be `Send` too.
+unsafe impl<T: Send + Sync> Sync for RwLock<T> {}
+
+/// Gives shared access to the value of a [`RwLock`], until it's dropped
+#[derive(Debug)]
+pub struct ReadGuard<'a, T> {
+    lock: &'a RwLock<T>,
+}
+
+/// Gives exclusive access to the value of a [`RwLock`], until it's dropped
+#[derive(Debug)]
+pub struct WriteGuard<'a, T> {
+    lock: &'a RwLock<T>,
+}
+
+impl<T> RwLock<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            state: Mutex::default(),
+            readers_can_enter: Condvar::new(),
+            writer_can_enter: Condvar::new(),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Waits until there are no writers holding or waiting for the lock, and takes it for reading
+    pub fn read(&self) -> ReadGuard<'_, T> {
+        let mut state = self
+            .readers_can_enter
+            .wait_while(self.state.lock().unwrap(), |state| {
+                state.writer || state.waiting_writers > 0
+            })
+            .unwrap();
+        state.readers += 1;
+        ReadGuard { lock: self }
+    }
+
+    /// Waits until no one else holds the lock, and takes it for writing
+    pub fn write(&self) -> WriteGuard<'_, T> {
+        let mut state = self.state.lock().unwrap();
+        // Announcing that we're waiting keeps new readers out, so the current ones are soon done
+        state.waiting_writers += 1;
+        let mut state = self
+            .writer_can_enter
+            .wait_while(state, |state| state.writer || state.readers > 0)
+            .unwrap();
+        state.waiting_writers -= 1;
+        state.writer = true;
+        WriteGuard { lock: self }
+    }
+}
+
+impl<T> Drop for ReadGuard<'_, T> {
+    fn drop(&mut self) {
+        let mut state = self.lock.state.lock().unwrap();
+        state.readers -= 1;
+        if state.readers == 0 {
+            self.lock.writer_can_enter.notify_one();
+        }
+    }
+}
+
+impl<T> Drop for WriteGuard<'_, T> {
+    fn drop(&mut self) {
+        let mut state = self.lock.state.lock().unwrap();
+        state.writer = false;
+        // Another writer goes first if there is one, but the readers check that themselves
+        if state.waiting_writers > 0 {
+            self.lock.writer_can_enter.notify_one();
+        }
+        self.lock.readers_can_enter.notify_all();
+    }
+}
+
+impl<T> Deref for ReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: Only readers hold the lock while this guard exists
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> Deref for WriteGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: No one else holds the lock while this guard exists
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> DerefMut for WriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: No one else holds the lock while this guard exists
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
@@ -0,0 +1,48 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-47 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::thread;
+
+use part_47::{solutions, RwLock};
+
+#[test]
+fn reads_and_writes_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (lock, expected) = (RwLock::new(Vec::new()), solutions::RwLock::new(Vec::new()));
+    for (i, op) in common::datagen::numbers_below(1000, 4, rng.seed())
+        .into_iter()
+        .enumerate()
+    {
+        if op == 0 {
+            lock.write().push(i);
+            expected.write().push(i);
+        } else {
+            // Any number of readers at once
+            let (first, second) = (lock.read(), lock.read());
+            let expected = expected.read();
+            assert_eq!(*first, *expected);
+            assert_eq!(*second, *expected);
+        }
+    }
+}
+
+#[test]
+fn writes_across_threads_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let values = common::datagen::numbers_below(4000, 100, rng.seed());
+    let (lock, expected) = (RwLock::new(0), solutions::RwLock::new(0));
+    thread::scope(|s| {
+        for values in values.chunks(1000) {
+            let (lock, expected) = (&lock, &expected);
+            s.spawn(move || {
+                for &value in values {
+                    *lock.write() += value;
+                    *expected.write() += value;
+                    assert!(*lock.read() > 0);
+                }
+            });
+        }
+    });
+    assert_eq!(*lock.read(), *expected.read());
+}
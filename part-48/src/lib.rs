@@ -0,0 +1,49 @@
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Merges the sorted slices `left` and `right` into `out`, which must be exactly as long as both
+pub fn merge<T: Ord + Copy>(left: &[T], right: &[T], out: &mut [T]) {
+    assert_eq!(left.len() + right.len(), out.len());
+    let (mut l, mut r) = (0, 0);
+    for slot in out {
+        // Taking from the left on ties keeps the sort stable
+        if r == right.len() || (l < left.len() && left[l] <= right[r]) {
+            *slot = left[l];
+            l += 1;
+        } else {
+            *slot = right[r];
+            r += 1;
+        }
+    }
+}
+
+/// Sorts `items` on this thread, merging into `scratch`, which must be exactly as long
+pub fn sort_with_scratch<T: Ord + Copy>(items: &mut [T], scratch: &mut [T]) {
+    if items.len() <= 1 {
+        return;
+    }
+    let mid = items.len() / 2;
+    let (left, right) = items.split_at_mut(mid);
+    let (left_scratch, right_scratch) = scratch.split_at_mut(mid);
+    sort_with_scratch(left, left_scratch);
+    sort_with_scratch(right, right_scratch);
+    merge(left, right, scratch);
+    items.copy_from_slice(scratch);
+}
+
+/// Sorts `items` with a merge sort on this thread
+pub fn merge_sort<T: Ord + Copy>(items: &mut [T]) {
+    let mut scratch = items.to_vec();
+    sort_with_scratch(items, &mut scratch);
+}
+
+/// Sorts `items` with a merge sort, sorting the two halves on separate scoped threads
+/// until the halves are no longer than `cutoff`, which are sorted on the current thread
+pub fn threaded_merge_sort<T: Ord + Copy + Send>(items: &mut [T], cutoff: usize) {
+    todo!()
+}
+
+/// Sorts `items` like `threaded_merge_sort`, but lets rayon decide which thread sorts which half
+pub fn rayon_merge_sort<T: Ord + Copy + Send>(items: &mut [T], cutoff: usize) {
+    todo!()
+}
@@ -0,0 +1,84 @@
+use common::{bench::Comparison, cli::Cli, datagen};
+use part_48::{merge_sort, rayon_merge_sort, threaded_merge_sort};
+
+const LEN: usize = 4_000_000;
+
+/// Run with `cargo run --release -p part-48` to compare the sorts and cutoffs
+fn main() {
+    // `workshop bench` tries other sizes too
+    let args = Cli::new("Compares merge sorts: serial, on threads and with Rayon")
+        .items(LEN)
+        .strategy()
+        .parse();
+    let len = args.items();
+    let numbers = datagen::numbers(len, 42);
+    println!("Sorting {len} numbers");
+
+    let mut comparison = Comparison::new(5);
+    comparison
+        .bench("sort_unstable", || numbers.clone().sort_unstable())
+        .bench("Serial", || merge_sort(&mut numbers.clone()));
+    for cutoff in [1_000, 10_000, 100_000, len / 4, len / 2] {
+        comparison.bench(&format!("Threaded, cutoff {cutoff}"), || {
+            threaded_merge_sort(&mut numbers.clone(), cutoff)
+        });
+    }
+    for cutoff in [100, 1_000, 10_000, 100_000, len / 4] {
+        comparison.bench(&format!("Rayon, cutoff {cutoff}"), || {
+            rayon_merge_sort(&mut numbers.clone(), cutoff)
+        });
+    }
+    comparison.print();
+}
+
+#[cfg(test)]
+fn check(sort: impl Fn(&mut [u64], usize)) {
+    for len in [0, 1, 2, 3, 10, 1000, 12_345] {
+        let numbers = datagen::numbers(len, len as u64);
+        let mut expected = numbers.clone();
+        expected.sort();
+
+        for cutoff in [0, 1, 2, 100, 5000, usize::MAX] {
+            let mut sorted = numbers.clone();
+            sort(&mut sorted, cutoff);
+            assert_eq!(
+                sorted, expected,
+                "Wrong result for {len} numbers with a cutoff of {cutoff}"
+            );
+        }
+    }
+}
+
+#[test]
+fn serial_sorts() {
+    check(|numbers, _| merge_sort(numbers));
+}
+
+#[test]
+fn threaded_sorts() {
+    check(threaded_merge_sort);
+}
+
+#[test]
+fn rayon_sorts() {
+    check(rayon_merge_sort);
+}
+
+#[test]
+fn sorts_duplicates_and_sorted_input() {
+    for sort in [threaded_merge_sort, rayon_merge_sort] {
+        let mut duplicates: Vec<u64> = (0..1000).map(|i| i % 7).collect();
+        let mut expected = duplicates.clone();
+        expected.sort();
+        sort(&mut duplicates, 10);
+        assert_eq!(duplicates, expected);
+
+        let mut sorted: Vec<u64> = (0..1000).collect();
+        sort(&mut sorted, 10);
+        assert_eq!(sorted, (0..1000).collect::<Vec<_>>());
+
+        let mut reversed: Vec<u64> = (0..1000).rev().collect();
+        sort(&mut reversed, 10);
+        assert_eq!(reversed, (0..1000).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,55 @@
+//! Part 48, with every exercise implemented. Only built with `--features solutions`.
+
+use super::{merge, sort_with_scratch};
+
+/// Sorts `items` with a merge sort, sorting the two halves on separate scoped threads
+/// until the halves are no longer than `cutoff`, which are sorted on the current thread
+pub fn threaded_merge_sort<T: Ord + Copy + Send>(items: &mut [T], cutoff: usize) {
+    let mut scratch = items.to_vec();
+    threaded_sort_with_scratch(items, &mut scratch, cutoff);
+}
+
+fn threaded_sort_with_scratch<T: Ord + Copy + Send>(
+    items: &mut [T],
+    scratch: &mut [T],
+    cutoff: usize,
+) {
+    if items.len() <= cutoff.max(1) {
+        return sort_with_scratch(items, scratch);
+    }
+    let mid = items.len() / 2;
+    let (left, right) = items.split_at_mut(mid);
+    let (left_scratch, right_scratch) = scratch.split_at_mut(mid);
+    std::thread::scope(|s| {
+        // This thread sorts one half itself, rather than waiting idly for two new threads
+        s.spawn(|| threaded_sort_with_scratch(left, left_scratch, cutoff));
+        threaded_sort_with_scratch(right, right_scratch, cutoff);
+    });
+    merge(left, right, scratch);
+    items.copy_from_slice(scratch);
+}
+
+/// Sorts `items` like `threaded_merge_sort`, but lets rayon decide which thread sorts which half
+pub fn rayon_merge_sort<T: Ord + Copy + Send>(items: &mut [T], cutoff: usize) {
+    let mut scratch = items.to_vec();
+    rayon_sort_with_scratch(items, &mut scratch, cutoff);
+}
+
+fn rayon_sort_with_scratch<T: Ord + Copy + Send>(
+    items: &mut [T],
+    scratch: &mut [T],
+    cutoff: usize,
+) {
+    if items.len() <= cutoff.max(1) {
+        return sort_with_scratch(items, scratch);
+    }
+    let mid = items.len() / 2;
+    let (left, right) = items.split_at_mut(mid);
+    let (left_scratch, right_scratch) = scratch.split_at_mut(mid);
+    rayon::join(
+        || rayon_sort_with_scratch(left, left_scratch, cutoff),
+        || rayon_sort_with_scratch(right, right_scratch, cutoff),
+    );
+    merge(left, right, scratch);
+    items.copy_from_slice(scratch);
+}
@@ -0,0 +1,63 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-48 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_48::{rayon_merge_sort, solutions, threaded_merge_sort};
+
+#[test]
+fn sorts_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for len in [0, 1, 2, 100, 10_000] {
+        let numbers = common::datagen::numbers(len, rng.seed());
+        for cutoff in [1, 16, 1000] {
+            let mut expected = numbers.clone();
+            solutions::threaded_merge_sort(&mut expected, cutoff);
+
+            let mut threaded = numbers.clone();
+            threaded_merge_sort(&mut threaded, cutoff);
+            assert_eq!(threaded, expected, "{len} numbers, cut off at {cutoff}");
+            let mut rayon = numbers.clone();
+            rayon_merge_sort(&mut rayon, cutoff);
+            assert_eq!(rayon, expected, "{len} numbers, cut off at {cutoff}");
+        }
+    }
+}
+
+#[test]
+fn sorts_stably_like_the_solution() {
+    // Only the first number is compared, so the sort has to keep the order of equal ones
+    #[derive(Clone, Copy, Debug)]
+    struct Keyed(u64, usize);
+    impl PartialEq for Keyed {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+    impl Eq for Keyed {}
+    impl PartialOrd for Keyed {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    impl Ord for Keyed {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    let mut rng = common::rng::for_test();
+    let keyed: Vec<Keyed> = common::datagen::numbers(5000, rng.seed())
+        .into_iter()
+        .enumerate()
+        .map(|(i, n)| Keyed(n % 10, i))
+        .collect();
+    let mut expected = keyed.clone();
+    solutions::rayon_merge_sort(&mut expected, 64);
+    let mut sorted = keyed.clone();
+    rayon_merge_sort(&mut sorted, 64);
+    let positions = |sorted: &[Keyed]| sorted.iter().map(|k| (k.0, k.1)).collect::<Vec<_>>();
+    assert_eq!(positions(&sorted), positions(&expected));
+    let mut threaded = keyed;
+    threaded_merge_sort(&mut threaded, 64);
+    assert_eq!(positions(&threaded), positions(&expected));
+}
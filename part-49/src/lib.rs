@@ -0,0 +1,44 @@
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Partitions `items`, which must hold at least two numbers, around the number in the middle.
+/// Returns an index `mid` where no number in `items[..mid]` is larger than any in `items[mid..]`.
+/// Both sides are always non-empty.
+pub fn partition(items: &mut [u64]) -> usize {
+    assert!(items.len() >= 2, "Can't partition fewer than two numbers");
+    // Picking the pivot from the middle avoids the worst case for already sorted input
+    let pivot = items[(items.len() - 1) / 2];
+    let (mut i, mut j) = (0, items.len() - 1);
+    loop {
+        while items[i] < pivot {
+            i += 1;
+        }
+        while items[j] > pivot {
+            j -= 1;
+        }
+        if i >= j {
+            return j + 1;
+        }
+        // Swapping numbers equal to the pivot too keeps duplicates spread over both sides
+        items.swap(i, j);
+        i += 1;
+        j -= 1;
+    }
+}
+
+/// Sorts `items` in place with a quicksort on this thread
+pub fn quicksort(items: &mut [u64]) {
+    if items.len() <= 1 {
+        return;
+    }
+    let mid = partition(items);
+    let (left, right) = items.split_at_mut(mid);
+    quicksort(left);
+    quicksort(right);
+}
+
+/// Sorts `items` in place with a quicksort, letting rayon sort the two sides of each partition
+/// in parallel until they are no longer than `cutoff`, which are sorted with `quicksort`
+pub fn parallel_quicksort(items: &mut [u64], cutoff: usize) {
+    todo!()
+}
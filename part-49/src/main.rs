@@ -0,0 +1,102 @@
+use common::{bench::Comparison, cli::Cli, datagen};
+use part_49::{parallel_quicksort, quicksort};
+
+const LEN: usize = 4_000_000;
+
+/// Run with `cargo run --release -p part-49` to compare the sorts and cutoffs
+fn main() {
+    // `workshop bench` tries other sizes too
+    let args = Cli::new("Compares quicksorts: serial, and in parallel at several cutoffs")
+        .items(LEN)
+        .strategy()
+        .parse();
+    let len = args.items();
+    let numbers = datagen::numbers(len, 42);
+    println!("Sorting {len} numbers");
+
+    let mut comparison = Comparison::new(5);
+    comparison
+        .bench("sort_unstable", || numbers.clone().sort_unstable())
+        .bench("Serial", || quicksort(&mut numbers.clone()));
+    for cutoff in [100, 1_000, 10_000, 100_000, len / 4] {
+        comparison.bench(&format!("Parallel, cutoff {cutoff}"), || {
+            parallel_quicksort(&mut numbers.clone(), cutoff)
+        });
+    }
+    comparison.print();
+}
+
+#[cfg(test)]
+fn check(numbers: Vec<u64>) {
+    let mut expected = numbers.clone();
+    expected.sort();
+
+    for cutoff in [0, 1, 2, 100, 5000, usize::MAX] {
+        let mut sorted = numbers.clone();
+        parallel_quicksort(&mut sorted, cutoff);
+        assert_eq!(
+            sorted,
+            expected,
+            "Wrong result for {} numbers with a cutoff of {cutoff}",
+            numbers.len()
+        );
+    }
+}
+
+#[test]
+fn partitions() {
+    use part_49::partition;
+
+    for len in [2, 3, 10, 1000] {
+        let mut numbers = datagen::numbers(len, len as u64);
+        let mid = partition(&mut numbers);
+        assert!(0 < mid && mid < len, "{mid} leaves one side empty");
+        let largest_left = numbers[..mid].iter().max().unwrap();
+        let smallest_right = numbers[mid..].iter().min().unwrap();
+        assert!(largest_left <= smallest_right);
+    }
+}
+
+#[test]
+fn serial_sorts() {
+    for len in [0, 1, 2, 3, 10, 1000, 12_345] {
+        let mut numbers = datagen::numbers(len, len as u64);
+        let mut expected = numbers.clone();
+        expected.sort();
+        quicksort(&mut numbers);
+        assert_eq!(numbers, expected);
+    }
+}
+
+#[test]
+fn sorts_random_numbers() {
+    let mut rng = common::rng::for_test();
+    for len in [0, 1, 2, 3, 10, 1000, 12_345] {
+        check(datagen::numbers(len, rng.seed()));
+    }
+}
+
+#[test]
+fn sorts_large_random_arrays() {
+    let mut rng = common::rng::for_test();
+    check(datagen::numbers(1_000_000, rng.seed()));
+}
+
+#[test]
+fn sorts_duplicates() {
+    let mut rng = common::rng::for_test();
+    check(vec![3; 10_000]);
+    check((0..10_000).map(|i| i % 7).collect());
+    check(
+        datagen::numbers(10_000, rng.seed())
+            .iter()
+            .map(|n| n % 100)
+            .collect(),
+    );
+}
+
+#[test]
+fn sorts_sorted_input() {
+    check((0..10_000).collect());
+    check((0..10_000).rev().collect());
+}
@@ -0,0 +1,18 @@
+//! Part 49, with every exercise implemented. Only built with `--features solutions`.
+
+use super::{partition, quicksort};
+
+/// Sorts `items` in place with a quicksort, letting rayon sort the two sides of each partition
+/// in parallel until they are no longer than `cutoff`, which are sorted with `quicksort`
+pub fn parallel_quicksort(items: &mut [u64], cutoff: usize) {
+    if items.len() <= cutoff.max(1) {
+        return quicksort(items);
+    }
+    let mid = partition(items);
+    // Two non-overlapping mutable borrows, which can be handed to one closure each
+    let (left, right) = items.split_at_mut(mid);
+    rayon::join(
+        || parallel_quicksort(left, cutoff),
+        || parallel_quicksort(right, cutoff),
+    );
+}
@@ -0,0 +1,34 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-49 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_49::{parallel_quicksort, solutions};
+
+#[test]
+fn sorts_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for len in [0, 1, 2, 100, 10_000] {
+        let numbers = common::datagen::numbers(len, rng.seed());
+        for cutoff in [1, 16, 1000] {
+            let mut expected = numbers.clone();
+            solutions::parallel_quicksort(&mut expected, cutoff);
+            let mut sorted = numbers.clone();
+            parallel_quicksort(&mut sorted, cutoff);
+            assert_eq!(sorted, expected, "{len} numbers, cut off at {cutoff}");
+        }
+    }
+}
+
+#[test]
+fn sorts_duplicates_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let numbers: Vec<u64> = common::datagen::numbers(10_000, rng.seed())
+        .into_iter()
+        .map(|n| n % 4)
+        .collect();
+    let mut expected = numbers.clone();
+    solutions::parallel_quicksort(&mut expected, 8);
+    let mut sorted = numbers;
+    parallel_quicksort(&mut sorted, 8);
+    assert_eq!(sorted, expected);
+}
@@ -0,0 +1,12 @@
+// Both closures borrow all of `items` mutably to index into it, even though they only
+// touch their own half, so the borrow checker has no way of knowing they don't overlap
+use part_49::{partition, quicksort};
+
+fn main() {
+    let mut items = vec![3, 1, 2];
+    let mid = partition(&mut items);
+    rayon::join(
+        || quicksort(&mut items[..mid]),
+        || quicksort(&mut items[mid..]),
+    );
+}
@@ -2,17 +2,33 @@ use std::time::Duration;
 
 use cfg_if::cfg_if;
 
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Data(pub u64);
+
+/// So that the datasets in `fixtures` can be made of `Data`
+impl From<u64> for Data {
+    fn from(value: u64) -> Self {
+        Data(value)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ComputationResult(pub u64);
 
 pub fn calculate(datum: Data) -> ComputationResult {
+    // Under `common::stress`, only wait a random moment, so that hundreds of runs fit in a test
+    if common::stress::jitter() {
+        return ComputationResult(datum.0 * 2);
+    }
     // Make calculations faster in test :p
     cfg_if! { if #[cfg(test)] { fn x() -> u64 { 100 } } else { fn x() -> u64 { 500 } } };
-    let compute_time = x();
-    // Simulate heavy workload
-    std::thread::sleep(Duration::from_millis(compute_time));
+    // `--compute-ms` can make it faster or slower, see `common::cli`
+    let compute_time = common::cli::compute_ms(x());
+    // Simulate heavy workload, which shows up in `common::viz` timelines
+    common::viz::busy(|| std::thread::sleep(Duration::from_millis(compute_time)));
     ComputationResult(datum.0 * 2)
 }
 
@@ -24,3 +40,52 @@ pub fn serial_calculate(data: Vec<Data>) -> Vec<ComputationResult> {
 pub fn parallel_calculate(data: Vec<Data>) -> Vec<ComputationResult> {
     todo!()
 }
+
+/// Advanced: how many times the sums below go over their numbers, so that reading them takes
+/// longer than making them
+#[cfg(feature = "advanced")]
+pub const PASSES: usize = 8;
+
+/// Advanced: sums `chunk`, `PASSES` times over, so that it's read from memory every time, and
+/// gives the sum
+#[cfg(feature = "advanced")]
+pub fn sum_passes(chunk: &[u64]) -> u64 {
+    let mut sum = 0;
+    for _ in 0..PASSES {
+        sum = std::hint::black_box(chunk).iter().sum();
+    }
+    sum
+}
+
+/// Advanced: the sum of `0..len`, split between `threads` threads, each of them pinned to a core of
+/// its own with `common::affinity::pin_spread`. The main thread makes all of the numbers, so their
+/// memory is first touched on its core, and on a machine with several NUMA nodes the operating
+/// system puts all of it on that core's node, where the threads on the other nodes read theirs
+/// from.
+#[cfg(feature = "advanced")]
+pub fn main_touch_sum(len: usize, threads: usize) -> u64 {
+    let numbers: Vec<u64> = (0..len as u64).collect();
+    let chunk_len = len.div_ceil(threads).max(1);
+    std::thread::scope(|s| {
+        let handles: Vec<_> = numbers
+            .chunks(chunk_len)
+            .enumerate()
+            .map(|(worker, chunk)| {
+                s.spawn(move || {
+                    // Without pinning, it still works, just without showing much
+                    let _ = common::affinity::pin_spread(worker, threads);
+                    sum_passes(chunk)
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    })
+}
+
+/// Advanced: the same sum as `main_touch_sum`, but every thread makes its own chunk of the
+/// numbers, after it's pinned, so that their memory is first touched on the core which reads it,
+/// and ends up on its NUMA node.
+#[cfg(feature = "advanced")]
+pub fn first_touch_sum(len: usize, threads: usize) -> u64 {
+    todo!()
+}
@@ -1,29 +1,105 @@
-use common::timed;
+use common::{cli::Cli, timed, viz};
+use fixtures::Dataset;
 use part_5::{parallel_calculate, serial_calculate, Data};
+#[cfg(test)]
+use serial_test::serial;
 
 fn main() {
-    let data = vec![Data(1), Data(2), Data(3)];
+    common::init_tracing();
+    let args = Cli::new("Calculates serially and in parallel, and shows when each thread was busy")
+        .items(fixtures::data_len(Dataset::Small))
+        .compute_ms(500)
+        .parse();
+    // The same as `fixtures::data(Dataset::Small)`, unless run with another `--items`
+    let data: Vec<Data> = (1..=args.items() as u64).map(Data).collect();
 
-    let serial_results = timed("Serial calculate", || serial_calculate(data.clone()));
-    let parallel_results = timed("Parallel calculate", || parallel_calculate(data));
+    let (serial_results, serial) =
+        viz::record(|| timed("Serial calculate", || serial_calculate(data.clone())));
+    print!("{serial}");
+    let (parallel_results, parallel) =
+        viz::record(|| timed("Parallel calculate", || parallel_calculate(data)));
+    print!("{parallel}");
 
     assert_eq!(serial_results, parallel_results);
 }
 
 #[test]
+// Not while a stress check makes every calculation take a random moment
+#[serial]
 fn parallel_is_faster() {
-    use common::{ensure_can_run_parallel_test, time_elapsed};
+    use common::{
+        bench::{assert_faster, measure},
+        ensure_can_run_parallel_test,
+    };
     ensure_can_run_parallel_test();
 
     // This test kind of assumes threads are executed on a system with more than one core
     // Hehe if your system is a single-core machine or the OS doesn't parallelize
     // threads this may fail...
-    let data = vec![Data(1), Data(2), Data(3)];
+    let data: Vec<Data> = fixtures::data(Dataset::Small);
 
-    let (serial, serial_elapsed) = time_elapsed("serial", || serial_calculate(data.clone()));
-    let (parallel, parallel_elapsed) =
-        time_elapsed("parallel", || parallel_calculate(data.clone()));
+    assert_eq!(
+        serial_calculate(data.clone()),
+        parallel_calculate(data.clone())
+    );
+    // Every calculation takes half a second, so only a few runs
+    let serial = measure("serial", 3, || serial_calculate(data.clone()));
+    let parallel = measure("parallel", 3, || parallel_calculate(data.clone()));
 
-    assert_eq!(serial, parallel);
-    assert!(serial_elapsed > parallel_elapsed);
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    assert_faster(&parallel, &serial);
+}
+
+#[test]
+#[serial]
+fn parallel_calculate_is_deterministic() {
+    let mut rng = common::rng::for_test();
+    let data: Vec<Data> = common::datagen::numbers_below(16, 1000, rng.seed())
+        .into_iter()
+        .map(Data)
+        .collect();
+    common::stress::Stress::new(rng.seed()).check(&data, serial_calculate, parallel_calculate);
+}
+
+#[cfg(feature = "solutions")]
+#[test]
+fn calculates_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let data: Vec<Data> = common::datagen::numbers_below(4, 1000, rng.seed())
+        .into_iter()
+        .map(Data)
+        .collect();
+    let expected = part_5::solutions::parallel_calculate(data.clone());
+    assert_eq!(parallel_calculate(data), expected);
+}
+
+#[cfg(feature = "advanced")]
+#[test]
+fn first_touch_sums_like_main_touch() {
+    for (len, threads) in [(1000, 1), (1000, 3), (10, 16), (0, 2)] {
+        let expected: u64 = (0..len as u64).sum();
+        assert_eq!(part_5::main_touch_sum(len, threads), expected);
+        assert_eq!(
+            part_5::first_touch_sum(len, threads),
+            expected,
+            "{len} numbers on {threads} threads"
+        );
+    }
+}
+
+#[cfg(feature = "advanced")]
+#[test]
+fn first_touch_is_faster_across_numa_nodes() {
+    use common::{affinity, bench};
+
+    // With a single node all of the memory is as close to every core, so there's nothing to see
+    if affinity::numa_nodes() < 2 || common::sanitize::skip_timing() {
+        return;
+    }
+    let (len, threads) = (1 << 24, affinity::cores().map_or(1, |cores| cores.len()));
+    let main_touch = bench::measure("main touch", 3, || part_5::main_touch_sum(len, threads));
+    let first_touch = bench::measure("first touch", 3, || part_5::first_touch_sum(len, threads));
+    bench::assert_faster(&first_touch, &main_touch);
 }
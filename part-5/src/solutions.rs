@@ -0,0 +1,47 @@
+//! Part 5, with every exercise implemented. Only built with `--features solutions`.
+
+#[cfg(feature = "advanced")]
+use super::sum_passes;
+use super::{calculate, ComputationResult, Data};
+
+pub fn parallel_calculate(data: Vec<Data>) -> Vec<ComputationResult> {
+    // One thread per datum, joined in the same order they were spawned to keep the results in order
+    let handles: Vec<_> = data
+        .into_iter()
+        .enumerate()
+        .map(|(index, datum)| {
+            std::thread::spawn(move || {
+                let _item = common::span::item(index);
+                calculate(datum)
+            })
+        })
+        .collect();
+    handles
+        .into_iter()
+        .map(|handle| handle.join().expect("Couldn't join thread"))
+        .collect()
+}
+
+/// Advanced: the same sum as `main_touch_sum`, but every thread makes its own chunk of the
+/// numbers, after it's pinned, so that their memory is first touched on the core which reads it,
+/// and ends up on its NUMA node.
+#[cfg(feature = "advanced")]
+pub fn first_touch_sum(len: usize, threads: usize) -> u64 {
+    let chunk_len = len.div_ceil(threads).max(1);
+    std::thread::scope(|s| {
+        let handles: Vec<_> = (0..threads)
+            .map(|worker| {
+                s.spawn(move || {
+                    // Pinned first, so that the thread doesn't move to another node afterwards
+                    let _ = common::affinity::pin_spread(worker, threads);
+                    let start = (worker * chunk_len).min(len) as u64;
+                    let end = ((worker + 1) * chunk_len).min(len) as u64;
+                    // Allocated, and written to, by the thread which reads it
+                    let chunk: Vec<u64> = (start..end).collect();
+                    sum_passes(&chunk)
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    })
+}
@@ -0,0 +1,33 @@
+use rayon::prelude::*;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// The prefix sums of `items`, where each number is the sum of itself and every number before it
+pub fn serial_scan(items: &[u64]) -> Vec<u64> {
+    let mut sum = 0;
+    items
+        .iter()
+        .map(|&item| {
+            sum += item;
+            sum
+        })
+        .collect()
+}
+
+/// Sums each `chunk_len`-long chunk of `items` in parallel. The last chunk may be shorter.
+/// Panics if `chunk_len` is 0.
+pub fn chunk_totals(items: &[u64], chunk_len: usize) -> Vec<u64> {
+    todo!()
+}
+
+/// The number each chunk starts counting from, which is the sum of the `totals` of every chunk
+/// before it
+pub fn chunk_offsets(totals: &[u64]) -> Vec<u64> {
+    todo!()
+}
+
+/// The same as `serial_scan`, but scanning `chunk_len`-long chunks of `items` in parallel
+pub fn parallel_scan(items: &[u64], chunk_len: usize) -> Vec<u64> {
+    todo!()
+}
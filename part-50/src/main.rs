@@ -0,0 +1,72 @@
+use common::{bench::Comparison, cli::Cli, datagen};
+use part_50::{parallel_scan, serial_scan};
+
+const LEN: usize = 10_000_000;
+
+/// Run with `cargo run --release -p part-50` to compare the scans and chunk lengths
+fn main() {
+    // `workshop bench` tries other sizes too
+    let args = Cli::new("Compares prefix sums: serial, and in parallel with several chunk lengths")
+        .items(LEN)
+        .strategy()
+        .parse();
+    let len = args.items();
+    let numbers = datagen::numbers_below(len, 1024, 42);
+    println!("Scanning {len} numbers");
+
+    let mut comparison = Comparison::new(10);
+    comparison.bench("Serial", || serial_scan(&numbers));
+    for chunk_len in [1_000, 10_000, 100_000, len / 16, len] {
+        comparison.bench(&format!("Parallel, chunks of {chunk_len}"), || {
+            parallel_scan(&numbers, chunk_len)
+        });
+    }
+    comparison.print();
+}
+
+#[test]
+fn scans_serially() {
+    assert_eq!(serial_scan(&[]), vec![]);
+    assert_eq!(serial_scan(&[3, 1, 4, 1, 5]), vec![3, 4, 8, 9, 14]);
+}
+
+#[test]
+fn sums_chunks() {
+    use part_50::chunk_totals;
+
+    assert_eq!(chunk_totals(&[], 2), vec![]);
+    assert_eq!(chunk_totals(&[3, 1, 4, 1, 5], 2), vec![4, 5, 5]);
+    assert_eq!(chunk_totals(&[3, 1, 4, 1, 5], 5), vec![14]);
+    assert_eq!(chunk_totals(&[3, 1, 4, 1, 5], 100), vec![14]);
+}
+
+#[test]
+fn offsets_chunks() {
+    use part_50::chunk_offsets;
+
+    assert_eq!(chunk_offsets(&[]), vec![]);
+    assert_eq!(chunk_offsets(&[4, 5, 5]), vec![0, 4, 9]);
+}
+
+#[test]
+fn scans_in_parallel() {
+    let mut rng = common::rng::for_test();
+    for len in [0, 1, 2, 3, 10, 1000, 12_345] {
+        let numbers = datagen::numbers_below(len, 1024, rng.seed());
+        let expected = serial_scan(&numbers);
+        for chunk_len in [1, 2, 7, 100, 5000, usize::MAX] {
+            assert_eq!(
+                parallel_scan(&numbers, chunk_len),
+                expected,
+                "Wrong result for {len} numbers in chunks of {chunk_len}"
+            );
+        }
+    }
+}
+
+#[test]
+fn scans_large_arrays() {
+    let mut rng = common::rng::for_test();
+    let numbers = datagen::numbers_below(1_000_000, 1024, rng.seed());
+    assert_eq!(parallel_scan(&numbers, 10_000), serial_scan(&numbers));
+}
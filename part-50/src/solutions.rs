@@ -0,0 +1,46 @@
+//! Part 50, with every exercise implemented. Only built with `--features solutions`.
+
+use rayon::prelude::*;
+
+/// Sums each `chunk_len`-long chunk of `items` in parallel. The last chunk may be shorter.
+/// Panics if `chunk_len` is 0.
+pub fn chunk_totals(items: &[u64], chunk_len: usize) -> Vec<u64> {
+    items
+        .par_chunks(chunk_len)
+        .map(|chunk| chunk.iter().sum())
+        .collect()
+}
+
+/// The number each chunk starts counting from, which is the sum of the `totals` of every chunk
+/// before it
+pub fn chunk_offsets(totals: &[u64]) -> Vec<u64> {
+    totals
+        .iter()
+        .scan(0, |sum, &total| {
+            let offset = *sum;
+            *sum += total;
+            Some(offset)
+        })
+        .collect()
+}
+
+/// The same as `serial_scan`, but scanning `chunk_len`-long chunks of `items` in parallel
+pub fn parallel_scan(items: &[u64], chunk_len: usize) -> Vec<u64> {
+    // First pass: sum every chunk, which can be done in parallel
+    let offsets = chunk_offsets(&chunk_totals(items, chunk_len));
+
+    // Second pass: scan every chunk, starting from the sum of the chunks before it
+    let mut scanned = vec![0; items.len()];
+    scanned
+        .par_chunks_mut(chunk_len)
+        .zip(items.par_chunks(chunk_len))
+        .zip(offsets)
+        .for_each(|((scanned, chunk), offset)| {
+            let mut sum = offset;
+            for (scanned, &item) in scanned.iter_mut().zip(chunk) {
+                sum += item;
+                *scanned = sum;
+            }
+        });
+    scanned
+}
@@ -0,0 +1,30 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-50 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_50::{chunk_offsets, chunk_totals, parallel_scan, solutions};
+
+#[test]
+fn scans_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for len in [0, 1, 7, 1000] {
+        let numbers: Vec<u64> = common::datagen::numbers_below(len, 1024, rng.seed())
+            .into_iter()
+            .map(|n| n % 1000)
+            .collect();
+        for chunk_len in [1, 3, 64, 2000] {
+            let totals = solutions::chunk_totals(&numbers, chunk_len);
+            assert_eq!(
+                chunk_totals(&numbers, chunk_len),
+                totals,
+                "{len} numbers in chunks of {chunk_len}"
+            );
+            assert_eq!(chunk_offsets(&totals), solutions::chunk_offsets(&totals));
+            assert_eq!(
+                parallel_scan(&numbers, chunk_len),
+                solutions::parallel_scan(&numbers, chunk_len),
+                "{len} numbers in chunks of {chunk_len}"
+            );
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::ops::{Index, IndexMut};
+
+use common::datagen;
+use rayon::prelude::*;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// A `rows` × `cols` matrix, stored row by row
+#[derive(Clone, Debug, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![0.0; rows * cols],
+        }
+    }
+
+    /// A matrix of pseudo-random whole numbers below 16. The same `seed` always gives the same
+    /// matrix. Whole numbers this small are added and multiplied exactly, so the result of a
+    /// multiplication doesn't depend on the order the numbers are added in.
+    pub fn random(rows: usize, cols: usize, seed: u64) -> Self {
+        let data = datagen::numbers_below(rows * cols, 16, seed)
+            .into_iter()
+            .map(|number| number as f64)
+            .collect();
+        Self { rows, cols, data }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, row: usize) -> &[f64] {
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Multiplies the matrices the straightforward way, one element of the result at a time
+    pub fn multiply(&self, other: &Matrix) -> Matrix {
+        self.check_dimensions(other);
+        let mut product = Matrix::zeros(self.rows, other.cols);
+        for row in 0..self.rows {
+            for col in 0..other.cols {
+                product[(row, col)] = (0..self.cols)
+                    .map(|i| self[(row, i)] * other[(i, col)])
+                    .sum();
+            }
+        }
+        product
+    }
+
+    /// Multiplies the matrices like `multiply`, splitting the rows of the result between
+    /// `threads` scoped threads
+    pub fn multiply_threaded(&self, other: &Matrix, threads: usize) -> Matrix {
+        self.check_dimensions(other);
+        todo!()
+    }
+
+    /// Multiplies the matrices like `multiply`, letting rayon compute the rows of the result
+    /// in parallel
+    pub fn multiply_rayon(&self, other: &Matrix) -> Matrix {
+        self.check_dimensions(other);
+        todo!()
+    }
+
+    /// Multiplies the matrices in parallel like `multiply_rayon`, but splits them into
+    /// `tile` × `tile` tiles which are multiplied one at a time, to make better use of the cache
+    pub fn multiply_tiled(&self, other: &Matrix, tile: usize) -> Matrix {
+        self.check_dimensions(other);
+        todo!()
+    }
+
+    fn check_dimensions(&self, other: &Matrix) {
+        assert_eq!(
+            self.cols, other.rows,
+            "Can't multiply a {}×{} matrix with a {}×{} matrix",
+            self.rows, self.cols, other.rows, other.cols
+        );
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    fn index(&self, (row, col): (usize, usize)) -> &f64 {
+        assert!(row < self.rows && col < self.cols);
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut f64 {
+        assert!(row < self.rows && col < self.cols);
+        &mut self.data[row * self.cols + col]
+    }
+}
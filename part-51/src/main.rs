@@ -0,0 +1,114 @@
+use common::{bench::Comparison, cli::Cli};
+use part_51::Matrix;
+
+const SIZE: usize = 512;
+
+/// Run with `cargo run --release -p part-51` to compare the multiplications
+fn main() {
+    let args = Cli::new("Compares matrix multiplications, with --items rows and columns")
+        .items(SIZE)
+        .strategy()
+        .parse();
+    let size = args.items();
+    let a = Matrix::random(size, size, 1);
+    let b = Matrix::random(size, size, 2);
+    println!("Multiplying two {size}×{size} matrices");
+
+    let mut comparison = Comparison::new(3);
+    comparison.bench("Naive", || a.multiply(&b));
+    for threads in [2, 4, 8] {
+        comparison.bench(&format!("Threaded, {threads} threads"), || {
+            a.multiply_threaded(&b, threads)
+        });
+    }
+    comparison.bench("Rayon", || a.multiply_rayon(&b));
+    for tile in [8, 32, 128] {
+        comparison.bench(&format!("Tiled, {tile}×{tile} tiles"), || {
+            a.multiply_tiled(&b, tile)
+        });
+    }
+    comparison.print();
+}
+
+/// Pairs of matrices of different shapes that can be multiplied, and their product
+#[cfg(test)]
+fn cases() -> Vec<(Matrix, Matrix, Matrix)> {
+    [(1, 1, 1), (3, 5, 2), (2, 5, 3), (17, 13, 19), (64, 64, 64)]
+        .into_iter()
+        .map(|(rows, shared, cols)| {
+            let a = Matrix::random(rows, shared, rows as u64);
+            let b = Matrix::random(shared, cols, cols as u64);
+            let product = a.multiply(&b);
+            (a, b, product)
+        })
+        .collect()
+}
+
+#[test]
+fn multiplies() {
+    let mut a = Matrix::zeros(2, 3);
+    let mut b = Matrix::zeros(3, 2);
+    for (i, value) in [1.0, 2.0, 3.0, 4.0, 5.0, 6.0].into_iter().enumerate() {
+        a[(i / 3, i % 3)] = value;
+        b[(i / 2, i % 2)] = value;
+    }
+
+    let product = a.multiply(&b);
+    assert_eq!(product.row(0), [22.0, 28.0]);
+    assert_eq!(product.row(1), [49.0, 64.0]);
+}
+
+#[test]
+fn multiplies_threaded() {
+    for (a, b, product) in cases() {
+        for threads in [1, 2, 3, 8, 100] {
+            assert_eq!(
+                a.multiply_threaded(&b, threads),
+                product,
+                "Wrong product of {}×{} and {}×{} with {threads} threads",
+                a.rows(),
+                a.cols(),
+                b.rows(),
+                b.cols()
+            );
+        }
+    }
+}
+
+#[test]
+fn multiplies_with_rayon() {
+    for (a, b, product) in cases() {
+        assert_eq!(
+            a.multiply_rayon(&b),
+            product,
+            "Wrong product of {}×{} and {}×{}",
+            a.rows(),
+            a.cols(),
+            b.rows(),
+            b.cols()
+        );
+    }
+}
+
+#[test]
+fn multiplies_tiled() {
+    for (a, b, product) in cases() {
+        for tile in [1, 2, 7, 16, 64, 1000] {
+            assert_eq!(
+                a.multiply_tiled(&b, tile),
+                product,
+                "Wrong product of {}×{} and {}×{} with {tile}×{tile} tiles",
+                a.rows(),
+                a.cols(),
+                b.rows(),
+                b.cols()
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Can't multiply a 2×3 matrix with a 2×3 matrix")]
+fn rejects_mismatched_dimensions() {
+    Matrix::zeros(2, 3).multiply_rayon(&Matrix::zeros(2, 3));
+}
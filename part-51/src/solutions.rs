@@ -0,0 +1,173 @@
+//! Part 51, with every exercise implemented. Only built with `--features solutions`.
+
+use std::ops::{Index, IndexMut};
+
+use common::datagen;
+use rayon::prelude::*;
+
+/// A `rows` × `cols` matrix, stored row by row
+#[derive(Clone, Debug, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![0.0; rows * cols],
+        }
+    }
+
+    /// A matrix of pseudo-random whole numbers below 16. The same `seed` always gives the same
+    /// matrix. Whole numbers this small are added and multiplied exactly, so the result of a
+    /// multiplication doesn't depend on the order the numbers are added in.
+    pub fn random(rows: usize, cols: usize, seed: u64) -> Self {
+        let data = datagen::numbers_below(rows * cols, 16, seed)
+            .into_iter()
+            .map(|number| number as f64)
+            .collect();
+        Self { rows, cols, data }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, row: usize) -> &[f64] {
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Multiplies the matrices the straightforward way, one element of the result at a time
+    pub fn multiply(&self, other: &Matrix) -> Matrix {
+        self.check_dimensions(other);
+        let mut product = Matrix::zeros(self.rows, other.cols);
+        for row in 0..self.rows {
+            for col in 0..other.cols {
+                product[(row, col)] = (0..self.cols)
+                    .map(|i| self[(row, i)] * other[(i, col)])
+                    .sum();
+            }
+        }
+        product
+    }
+
+    /// Multiplies the matrices like `multiply`, splitting the rows of the result between
+    /// `threads` scoped threads
+    pub fn multiply_threaded(&self, other: &Matrix, threads: usize) -> Matrix {
+        self.check_dimensions(other);
+        let mut product = Matrix::zeros(self.rows, other.cols);
+        if product.data.is_empty() {
+            return product;
+        }
+        let rows_per_thread = self.rows.div_ceil(threads.max(1));
+        std::thread::scope(|s| {
+            // Every thread gets its own rows of the result to write to
+            for (chunk, rows) in product
+                .data
+                .chunks_mut(rows_per_thread * other.cols)
+                .enumerate()
+            {
+                s.spawn(move || {
+                    for (i, product_row) in rows.chunks_mut(other.cols).enumerate() {
+                        self.multiply_row(other, chunk * rows_per_thread + i, product_row);
+                    }
+                });
+            }
+        });
+        product
+    }
+
+    /// Multiplies the matrices like `multiply`, letting rayon compute the rows of the result
+    /// in parallel
+    pub fn multiply_rayon(&self, other: &Matrix) -> Matrix {
+        self.check_dimensions(other);
+        let mut product = Matrix::zeros(self.rows, other.cols);
+        if product.data.is_empty() {
+            return product;
+        }
+        product
+            .data
+            .par_chunks_mut(other.cols)
+            .enumerate()
+            .for_each(|(row, product_row)| self.multiply_row(other, row, product_row));
+        product
+    }
+
+    /// Multiplies the matrices in parallel like `multiply_rayon`, but splits them into
+    /// `tile` × `tile` tiles which are multiplied one at a time, to make better use of the cache
+    pub fn multiply_tiled(&self, other: &Matrix, tile: usize) -> Matrix {
+        self.check_dimensions(other);
+        let mut product = Matrix::zeros(self.rows, other.cols);
+        if product.data.is_empty() {
+            return product;
+        }
+        let tile = tile.max(1);
+        product
+            .data
+            .par_chunks_mut(tile * other.cols)
+            .enumerate()
+            .for_each(|(band, product_rows)| {
+                // Multiplies one tile of `self` with one tile of `other` at a time, so the
+                // numbers of both tiles stay in the cache while they're used
+                for shared in (0..self.cols).step_by(tile) {
+                    for col in (0..other.cols).step_by(tile) {
+                        let cols = col..(col + tile).min(other.cols);
+                        for (i, product_row) in product_rows.chunks_mut(other.cols).enumerate() {
+                            let row = band * tile + i;
+                            for k in shared..(shared + tile).min(self.cols) {
+                                let value = self[(row, k)];
+                                let other_row = &other.row(k)[cols.clone()];
+                                for (product, &other) in
+                                    product_row[cols.clone()].iter_mut().zip(other_row)
+                                {
+                                    *product += value * other;
+                                }
+                            }
+                        }
+                    }
+                }
+            });
+        product
+    }
+
+    /// Computes row `row` of the product of `self` and `other` into `product_row`
+    fn multiply_row(&self, other: &Matrix, row: usize, product_row: &mut [f64]) {
+        for (col, product) in product_row.iter_mut().enumerate() {
+            *product = (0..self.cols)
+                .map(|i| self[(row, i)] * other[(i, col)])
+                .sum();
+        }
+    }
+
+    fn check_dimensions(&self, other: &Matrix) {
+        assert_eq!(
+            self.cols, other.rows,
+            "Can't multiply a {}×{} matrix with a {}×{} matrix",
+            self.rows, self.cols, other.rows, other.cols
+        );
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    fn index(&self, (row, col): (usize, usize)) -> &f64 {
+        assert!(row < self.rows && col < self.cols);
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut f64 {
+        assert!(row < self.rows && col < self.cols);
+        &mut self.data[row * self.cols + col]
+    }
+}
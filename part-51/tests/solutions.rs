@@ -0,0 +1,45 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-51 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_51::{solutions, Matrix};
+
+/// The matrices' entries, row by row, to compare them with the solution's
+fn entries_of(rows: usize, row: impl Fn(usize) -> Vec<f64>) -> Vec<Vec<f64>> {
+    (0..rows).map(row).collect()
+}
+
+#[test]
+fn multiplies_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for (n, m, p) in [(1, 1, 1), (3, 5, 2), (17, 9, 23), (64, 64, 64)] {
+        let (seed_a, seed_b) = (rng.seed(), rng.seed());
+        let (a, b) = (Matrix::random(n, m, seed_a), Matrix::random(m, p, seed_b));
+        let (expected_a, expected_b) = (
+            solutions::Matrix::random(n, m, seed_a),
+            solutions::Matrix::random(m, p, seed_b),
+        );
+        let entries = |product: &Matrix| entries_of(n, |row| product.row(row).to_vec());
+        let expected = |product: &solutions::Matrix| entries_of(n, |row| product.row(row).to_vec());
+
+        for threads in [1, 3, 8] {
+            assert_eq!(
+                entries(&a.multiply_threaded(&b, threads)),
+                expected(&expected_a.multiply_threaded(&expected_b, threads)),
+                "{n}×{m} by {m}×{p} on {threads} threads"
+            );
+        }
+        assert_eq!(
+            entries(&a.multiply_rayon(&b)),
+            expected(&expected_a.multiply_rayon(&expected_b)),
+            "{n}×{m} by {m}×{p} with rayon"
+        );
+        for tile in [1, 7, 16] {
+            assert_eq!(
+                entries(&a.multiply_tiled(&b, tile)),
+                expected(&expected_a.multiply_tiled(&expected_b, tile)),
+                "{n}×{m} by {m}×{p} with {tile}×{tile} tiles"
+            );
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// A small, fast pseudo-random number generator (xorshift64*).
+/// Not suitable for anything where the numbers must be unpredictable.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a generator for thread number `thread`. Every thread gets its own sequence of
+    /// numbers, and the same `seed` and `thread` always give the same one.
+    pub fn for_thread(seed: u64, thread: usize) -> Self {
+        // Scrambles the seed and thread (splitmix64), since similar states give similar numbers
+        let mut z = seed ^ (thread as u64).wrapping_mul(0x9E3779B97F4A7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        // The state must never be 0, or every number after it will be 0 too
+        Self {
+            state: (z ^ (z >> 31)).max(1),
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545F4914F6CDD1D)
+    }
+
+    /// A number in `0.0..1.0`
+    pub fn next_f64(&mut self) -> f64 {
+        // The 53 highest bits fill an `f64`'s mantissa exactly
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Picks a random point in the unit square, and checks whether it's inside the unit circle
+pub fn sample(rng: &mut Rng) -> bool {
+    let (x, y) = (rng.next_f64(), rng.next_f64());
+    x * x + y * y <= 1.0
+}
+
+/// The quarter of the unit circle inside the unit square covers π/4 of its area,
+/// so about π/4 of the samples should hit it
+pub fn estimate(hits: u64, samples: u64) -> f64 {
+    4.0 * hits as f64 / samples as f64
+}
+
+/// How many of `samples` samples hit the circle, using the generator for thread 0
+pub fn serial_hits(samples: u64, seed: u64) -> u64 {
+    let mut rng = Rng::for_thread(seed, 0);
+    let mut hits = 0;
+    for _ in 0..samples {
+        if sample(&mut rng) {
+            hits += 1;
+        }
+    }
+    hits
+}
+
+/// How many samples `thread` of `threads` threads should take, so they take `samples` in total
+pub fn samples_for_thread(samples: u64, threads: usize, thread: usize) -> u64 {
+    let (threads, thread) = (threads as u64, thread as u64);
+    samples / threads + u64::from(thread < samples % threads)
+}
+
+/// Splits `samples` samples between `threads` threads, each with its own generator,
+/// which add every hit to a shared atomic counter at once
+pub fn atomic_hits(samples: u64, threads: usize, seed: u64) -> u64 {
+    todo!()
+}
+
+/// Splits `samples` samples between `threads` threads, each with its own generator,
+/// which count their hits on their own and return them, to be summed up once they're done
+pub fn local_hits(samples: u64, threads: usize, seed: u64) -> u64 {
+    todo!()
+}
@@ -0,0 +1,108 @@
+use std::f64::consts::PI;
+
+use common::{bench::Comparison, cli::Cli};
+use part_52::{atomic_hits, estimate, local_hits, serial_hits};
+
+const SAMPLES: u64 = 20_000_000;
+const SEED: u64 = 42;
+
+/// Run with `cargo run --release -p part-52` to compare the ways of counting hits
+fn main() {
+    let args = Cli::new("Estimates π by sampling, on one thread and on several")
+        .items(SAMPLES as usize)
+        .strategy()
+        .parse();
+    let samples = args.items() as u64;
+    println!("Estimating π with {samples} samples");
+    for threads in [1, 4] {
+        let hits = local_hits(samples, threads, SEED);
+        let pi = estimate(hits, samples);
+        println!(
+            "{threads} thread(s) estimated {pi:.6}, which is off by {:.6}",
+            (pi - PI).abs()
+        );
+    }
+
+    let mut comparison = Comparison::new(5);
+    comparison.bench("Serial", || serial_hits(samples, SEED));
+    for threads in [2, 4, 8] {
+        comparison
+            .bench(&format!("Atomic, {threads} threads"), || {
+                atomic_hits(samples, threads, SEED)
+            })
+            .bench(&format!("Local, {threads} threads"), || {
+                local_hits(samples, threads, SEED)
+            });
+    }
+    comparison.print();
+}
+
+#[test]
+fn samples_add_up() {
+    use part_52::samples_for_thread;
+
+    for (samples, threads) in [(0, 1), (10, 1), (10, 3), (10, 10), (3, 10)] {
+        let total: u64 = (0..threads)
+            .map(|thread| samples_for_thread(samples, threads, thread))
+            .sum();
+        assert_eq!(total, samples);
+    }
+}
+
+#[test]
+fn serial_estimate_is_accurate() {
+    let mut rng = common::rng::for_test();
+    let seed = rng.seed();
+    // The estimate's standard deviation is about 0.0016 with a million samples
+    let pi = estimate(serial_hits(1_000_000, seed), 1_000_000);
+    assert!((pi - PI).abs() < 0.01, "{pi} is too far from π");
+}
+
+#[test]
+fn parallel_estimates_are_accurate() {
+    let mut rng = common::rng::for_test();
+    let seed = rng.seed();
+    for threads in [1, 2, 3, 8] {
+        for hits in [atomic_hits, local_hits] {
+            let pi = estimate(hits(1_000_000, threads, seed), 1_000_000);
+            assert!(
+                (pi - PI).abs() < 0.01,
+                "{pi} is too far from π with {threads} threads"
+            );
+        }
+    }
+}
+
+#[test]
+fn one_thread_matches_serial() {
+    let mut rng = common::rng::for_test();
+    let seed = rng.seed();
+    let expected = serial_hits(100_000, seed);
+    assert_eq!(atomic_hits(100_000, 1, seed), expected);
+    assert_eq!(local_hits(100_000, 1, seed), expected);
+}
+
+#[test]
+fn counting_does_not_change_the_result() {
+    let mut rng = common::rng::for_test();
+    let seed = rng.seed();
+    // Every thread's generator is decided by the seed, so the hits are too
+    for threads in [2, 3, 8] {
+        assert_eq!(
+            atomic_hits(100_000, threads, seed),
+            local_hits(100_000, threads, seed)
+        );
+    }
+}
+
+#[test]
+fn threads_take_different_samples() {
+    use part_52::{sample, Rng};
+
+    let mut rng = common::rng::for_test();
+    let seed = rng.seed();
+    // If every thread used the same generator, they would all sample the same points
+    let mut same_rng = Rng::for_thread(seed, 0);
+    let same_hits = (0..25_000).filter(|_| sample(&mut same_rng)).count() as u64 * 4;
+    assert_ne!(local_hits(100_000, 4, seed), same_hits);
+}
@@ -0,0 +1,54 @@
+//! Part 52, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+};
+
+use super::{sample, samples_for_thread, Rng};
+
+/// Splits `samples` samples between `threads` threads, each with its own generator,
+/// which add every hit to a shared atomic counter at once
+pub fn atomic_hits(samples: u64, threads: usize, seed: u64) -> u64 {
+    let hits = AtomicU64::new(0);
+    thread::scope(|s| {
+        for thread in 0..threads {
+            let hits = &hits;
+            s.spawn(move || {
+                let mut rng = Rng::for_thread(seed, thread);
+                for _ in 0..samples_for_thread(samples, threads, thread) {
+                    if sample(&mut rng) {
+                        // Every thread updates the same cache line, over and over again
+                        hits.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            });
+        }
+    });
+    hits.into_inner()
+}
+
+/// Splits `samples` samples between `threads` threads, each with its own generator,
+/// which count their hits on their own and return them, to be summed up once they're done
+pub fn local_hits(samples: u64, threads: usize, seed: u64) -> u64 {
+    thread::scope(|s| {
+        let handles: Vec<_> = (0..threads)
+            .map(|thread| {
+                s.spawn(move || {
+                    let mut rng = Rng::for_thread(seed, thread);
+                    let mut hits = 0;
+                    for _ in 0..samples_for_thread(samples, threads, thread) {
+                        if sample(&mut rng) {
+                            hits += 1;
+                        }
+                    }
+                    hits
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("Couldn't join thread"))
+            .sum()
+    })
+}
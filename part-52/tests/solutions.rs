@@ -0,0 +1,26 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-52 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_52::{atomic_hits, local_hits, solutions};
+
+#[test]
+fn hits_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    // Every thread has its own generator, so the same seed hits as often however the threads interleave
+    for (samples, threads) in [(0, 1), (1, 1), (10_000, 1), (10_000, 3), (100_000, 8)] {
+        let seed = rng.seed();
+        let expected = solutions::local_hits(samples, threads, seed);
+        assert_eq!(solutions::atomic_hits(samples, threads, seed), expected);
+        assert_eq!(
+            atomic_hits(samples, threads, seed),
+            expected,
+            "{samples} samples on {threads} threads"
+        );
+        assert_eq!(
+            local_hits(samples, threads, seed),
+            expected,
+            "{samples} samples on {threads} threads"
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use std::{fs::File, io, io::Write, path::Path};
+
+use rayon::prelude::*;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How many iterations a point gets to escape before it's assumed to be in the set
+pub const LIMIT: u8 = 255;
+
+/// The part of the complex plane that is rendered, as (real, imaginary) corners
+const TOP_LEFT: (f64, f64) = (-2.2, 1.2);
+const BOTTOM_RIGHT: (f64, f64) = (1.0, -1.2);
+
+/// How many iterations of `z = z² + c` it takes for `z` to get further than 2 from the origin,
+/// or `LIMIT` if it never does. Points that never escape are in the Mandelbrot set.
+pub fn escape_time(re: f64, im: f64) -> u8 {
+    let (mut z_re, mut z_im) = (0.0, 0.0);
+    for i in 0..LIMIT {
+        if z_re * z_re + z_im * z_im > 4.0 {
+            return i;
+        }
+        (z_re, z_im) = (z_re * z_re - z_im * z_im + re, 2.0 * z_re * z_im + im);
+    }
+    LIMIT
+}
+
+/// Renders row `row` of a `width` × `height` image into `pixels`, which must be `width` long.
+/// Points in the set are black, and the rest get brighter the faster they escape.
+pub fn render_row(row: usize, width: usize, height: usize, pixels: &mut [u8]) {
+    assert_eq!(pixels.len(), width);
+    let im = TOP_LEFT.1 + (BOTTOM_RIGHT.1 - TOP_LEFT.1) * row as f64 / height as f64;
+    for (col, pixel) in pixels.iter_mut().enumerate() {
+        let re = TOP_LEFT.0 + (BOTTOM_RIGHT.0 - TOP_LEFT.0) * col as f64 / width as f64;
+        *pixel = LIMIT - escape_time(re, im);
+    }
+}
+
+/// Renders a `width` × `height` image of the Mandelbrot set on this thread, one row after another
+pub fn render(width: usize, height: usize) -> Vec<u8> {
+    let mut pixels = vec![0; width * height];
+    if width == 0 {
+        return pixels;
+    }
+    for (row, row_pixels) in pixels.chunks_mut(width).enumerate() {
+        render_row(row, width, height, row_pixels);
+    }
+    pixels
+}
+
+/// Renders the same image as `render`, splitting the rows between `threads` scoped threads,
+/// each rendering a band of consecutive rows
+pub fn render_threaded(width: usize, height: usize, threads: usize) -> Vec<u8> {
+    todo!()
+}
+
+/// Renders the same image as `render`, letting rayon render the rows in parallel
+pub fn render_rayon(width: usize, height: usize) -> Vec<u8> {
+    todo!()
+}
+
+/// Writes the image to `path` as a grayscale [PGM](https://netpbm.sourceforge.net/doc/pgm.html)
+/// file, which most image viewers can open
+pub fn write_pgm(path: &Path, width: usize, height: usize, pixels: &[u8]) -> io::Result<()> {
+    let mut file = io::BufWriter::new(File::create(path)?);
+    write!(file, "P5\n{width} {height}\n255\n")?;
+    file.write_all(pixels)?;
+    file.flush()
+}
@@ -0,0 +1,97 @@
+use std::path::Path;
+
+use common::{bench::Comparison, cli::Cli};
+use part_53::{render, render_rayon, render_threaded, write_pgm};
+
+const WIDTH: usize = 1920;
+const HEIGHT: usize = 1440;
+
+/// Run with `cargo run --release -p part-53` to compare the renderers,
+/// or `cargo run --release -p part-53 -- mandelbrot.pgm` to save the image too
+fn main() {
+    let args = Cli::new("Renders the Mandelbrot set: serially, on threads and with Rayon")
+        .strategy()
+        .positional("path", "Where to write the image, as a PGM file")
+        .parse();
+    println!("Rendering {WIDTH}×{HEIGHT} pixels");
+
+    let mut comparison = Comparison::new(3);
+    comparison.bench("Serial", || render(WIDTH, HEIGHT));
+    for threads in [2, 4, 8, 32] {
+        comparison.bench(&format!("Threaded, {threads} threads"), || {
+            render_threaded(WIDTH, HEIGHT, threads)
+        });
+    }
+    comparison.bench("Rayon", || render_rayon(WIDTH, HEIGHT));
+    comparison.print();
+
+    if let Some(path) = args.positional("path") {
+        write_pgm(Path::new(path), WIDTH, HEIGHT, &render_rayon(WIDTH, HEIGHT))
+            .expect("Couldn't write image");
+        println!("Wrote the image to {path}");
+    }
+}
+
+/// A hash of the pixels (FNV-1a), which unlike the standard library's hashers is guaranteed to
+/// stay the same between Rust versions
+#[cfg(test)]
+fn hash(pixels: &[u8]) -> u64 {
+    pixels.iter().fold(0xcbf29ce484222325, |hash, &pixel| {
+        (hash ^ u64::from(pixel)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// The sizes the tests render, with the hash of the image they should get
+#[cfg(test)]
+const IMAGES: [(usize, usize, u64); 4] = [
+    (0, 0, 0xcbf29ce484222325),
+    (1, 1, 0xaf64734c8602ed21),
+    (64, 48, 0x2a5df84352e7e3fc),
+    (301, 199, 0x2b648851fbea1a7e),
+];
+
+#[test]
+fn renders_the_same_image() {
+    for (width, height, expected) in IMAGES {
+        let pixels = render(width, height);
+        assert_eq!(pixels.len(), width * height);
+        assert_eq!(
+            hash(&pixels),
+            expected,
+            "Wrong image with {width}×{height} pixels"
+        );
+    }
+}
+
+#[test]
+fn renders_threaded() {
+    for (width, height, expected) in IMAGES {
+        for threads in [1, 2, 3, 8, 500] {
+            assert_eq!(
+                hash(&render_threaded(width, height, threads)),
+                expected,
+                "Wrong image with {width}×{height} pixels and {threads} threads"
+            );
+        }
+    }
+}
+
+#[test]
+fn renders_with_rayon() {
+    for (width, height, expected) in IMAGES {
+        assert_eq!(
+            hash(&render_rayon(width, height)),
+            expected,
+            "Wrong image with {width}×{height} pixels"
+        );
+    }
+}
+
+#[test]
+fn renders_the_set_in_black() {
+    // The origin is in the set, while the corners are far outside it
+    let pixels = render(101, 101);
+    assert_eq!(pixels[50 * 101 + 69], 0);
+    assert!(pixels[0] > 250);
+    assert!(pixels[101 * 101 - 1] > 250);
+}
@@ -0,0 +1,39 @@
+//! Part 53, with every exercise implemented. Only built with `--features solutions`.
+
+use rayon::prelude::*;
+
+use super::render_row;
+
+/// Renders the same image as `render`, splitting the rows between `threads` scoped threads,
+/// each rendering a band of consecutive rows
+pub fn render_threaded(width: usize, height: usize, threads: usize) -> Vec<u8> {
+    let mut pixels = vec![0; width * height];
+    if pixels.is_empty() {
+        return pixels;
+    }
+    let rows_per_thread = height.div_ceil(threads.max(1));
+    std::thread::scope(|s| {
+        // Every band of rows is a separate mutable slice, so every thread can have its own
+        for (band, band_pixels) in pixels.chunks_mut(rows_per_thread * width).enumerate() {
+            s.spawn(move || {
+                for (i, row_pixels) in band_pixels.chunks_mut(width).enumerate() {
+                    render_row(band * rows_per_thread + i, width, height, row_pixels);
+                }
+            });
+        }
+    });
+    pixels
+}
+
+/// Renders the same image as `render`, letting rayon render the rows in parallel
+pub fn render_rayon(width: usize, height: usize) -> Vec<u8> {
+    let mut pixels = vec![0; width * height];
+    if pixels.is_empty() {
+        return pixels;
+    }
+    pixels
+        .par_chunks_mut(width)
+        .enumerate()
+        .for_each(|(row, row_pixels)| render_row(row, width, height, row_pixels));
+    pixels
+}
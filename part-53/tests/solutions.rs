@@ -0,0 +1,20 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-53 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_53::{render_rayon, render_threaded, solutions};
+
+#[test]
+fn renders_like_the_solution() {
+    for (width, height) in [(1, 1), (17, 5), (120, 80)] {
+        let expected = solutions::render_rayon(width, height);
+        assert_eq!(render_rayon(width, height), expected, "{width}×{height}");
+        for threads in [1, 3, 8, 200] {
+            assert_eq!(
+                render_threaded(width, height, threads),
+                solutions::render_threaded(width, height, threads),
+                "{width}×{height} on {threads} threads"
+            );
+        }
+    }
+}
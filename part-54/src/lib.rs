@@ -0,0 +1,100 @@
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash, RandomState},
+    sync::{Mutex, RwLock},
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// A hash map which can be used from many threads at once
+pub trait ConcurrentMap<K, V>: Sync {
+    /// Inserts `value` for `key`, returning the value it replaced, if any
+    fn insert(&self, key: K, value: V) -> Option<V>;
+    /// A copy of the value for `key`, if any
+    fn get(&self, key: &K) -> Option<V>;
+    /// Removes the value for `key`, returning it if there was one
+    fn remove(&self, key: &K) -> Option<V>;
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A single `HashMap` behind a single lock, which every operation has to take
+#[derive(Debug)]
+pub struct LockedMap<K, V>(Mutex<HashMap<K, V>>);
+
+impl<K, V> LockedMap<K, V> {
+    pub fn new() -> Self {
+        Self(Mutex::new(HashMap::new()))
+    }
+}
+
+impl<K, V> Default for LockedMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq + Send, V: Clone + Send> ConcurrentMap<K, V> for LockedMap<K, V> {
+    fn insert(&self, key: K, value: V) -> Option<V> {
+        self.0.lock().unwrap().insert(key, value)
+    }
+
+    fn get(&self, key: &K) -> Option<V> {
+        self.0.lock().unwrap().get(key).cloned()
+    }
+
+    fn remove(&self, key: &K) -> Option<V> {
+        self.0.lock().unwrap().remove(key)
+    }
+
+    fn len(&self) -> usize {
+        self.0.lock().unwrap().len()
+    }
+}
+
+/// A hash map split into shards, each a `HashMap` behind its own lock.
+/// Every key belongs to exactly one shard, decided by its hash,
+/// so operations on keys in different shards don't have to wait for each other.
+#[derive(Debug)]
+pub struct ShardedMap<K, V> {
+    shards: Vec<RwLock<HashMap<K, V>>>,
+    hasher: RandomState,
+}
+
+impl<K: Hash + Eq, V> ShardedMap<K, V> {
+    /// Creates an empty map with `shards` shards, which must be at least 1
+    pub fn with_shards(shards: usize) -> Self {
+        assert!(shards > 0, "A map needs at least one shard");
+        Self {
+            shards: (0..shards).map(|_| RwLock::new(HashMap::new())).collect(),
+            hasher: RandomState::new(),
+        }
+    }
+
+    /// The shard `key` belongs to
+    fn shard(&self, key: &K) -> &RwLock<HashMap<K, V>> {
+        todo!()
+    }
+}
+
+impl<K: Hash + Eq + Send + Sync, V: Clone + Send + Sync> ConcurrentMap<K, V> for ShardedMap<K, V> {
+    fn insert(&self, key: K, value: V) -> Option<V> {
+        todo!()
+    }
+
+    fn get(&self, key: &K) -> Option<V> {
+        todo!()
+    }
+
+    fn remove(&self, key: &K) -> Option<V> {
+        todo!()
+    }
+
+    fn len(&self) -> usize {
+        todo!()
+    }
+}
@@ -0,0 +1,161 @@
+use std::thread;
+
+use common::{bench::Comparison, cli::Cli, rng::Rng};
+use part_54::{ConcurrentMap, LockedMap, ShardedMap};
+
+const KEYS: u64 = 10_000;
+const OPERATIONS: u64 = 200_000;
+
+/// Run with `cargo run --release -p part-54` to compare the maps
+fn main() {
+    let args = Cli::new("Compares a map behind one lock with sharded maps, under a mixed workload")
+        .threads(
+            thread::available_parallelism()
+                .map_or(4, |n| n.get())
+                .max(4),
+        )
+        .strategy()
+        .parse();
+    let threads = args.threads();
+    println!("Running {OPERATIONS} operations on {threads} threads each");
+
+    let mut comparison = Comparison::new(5);
+    comparison.bench("Mutex<HashMap>", || {
+        mixed_workload(&LockedMap::new(), threads)
+    });
+    for shards in [1, 4, 16, 64] {
+        comparison.bench(&format!("Sharded, {shards} shard(s)"), || {
+            mixed_workload(&ShardedMap::with_shards(shards), threads)
+        });
+    }
+    comparison.print();
+}
+
+/// Lets `threads` threads do `OPERATIONS` random operations each on `map`:
+/// 80% reads, 10% inserts and 10% removals
+fn mixed_workload(map: &impl ConcurrentMap<u64, u64>, threads: usize) {
+    thread::scope(|s| {
+        for thread in 0..threads {
+            s.spawn(move || {
+                let mut rng = Rng::new(thread as u64);
+                for _ in 0..OPERATIONS {
+                    let key = rng.below(KEYS);
+                    match rng.below(10) {
+                        0 => {
+                            map.insert(key, rng.next_u64());
+                        }
+                        1 => {
+                            map.remove(&key);
+                        }
+                        _ => {
+                            common::bench::consume(map.get(&key));
+                        }
+                    }
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn inserts_gets_and_removes() {
+    for map in [ShardedMap::with_shards(1), ShardedMap::with_shards(8)] {
+        assert_eq!(map.get(&1), None);
+        assert_eq!(map.insert(1, "one"), None);
+        assert_eq!(map.insert(2, "two"), None);
+        assert_eq!(map.get(&1), Some("one"));
+        assert_eq!(map.insert(1, "uno"), Some("one"));
+        assert_eq!(map.get(&1), Some("uno"));
+        assert_eq!(map.len(), 2);
+
+        assert_eq!(map.remove(&1), Some("uno"));
+        assert_eq!(map.remove(&1), None);
+        assert_eq!(map.get(&1), None);
+        assert_eq!(map.len(), 1);
+        assert!(!map.is_empty());
+    }
+}
+
+#[test]
+fn counts_keys_in_every_shard() {
+    let map = ShardedMap::with_shards(16);
+    for key in 0..1000 {
+        map.insert(key, key);
+    }
+    assert_eq!(map.len(), 1000);
+    for key in 0..1000 {
+        assert_eq!(map.get(&key), Some(key));
+    }
+}
+
+#[test]
+fn threads_see_their_own_writes() {
+    let map = ShardedMap::with_shards(4);
+    thread::scope(|s| {
+        for thread in 0..8u64 {
+            let map = &map;
+            s.spawn(move || {
+                // Every thread has its own keys, so nothing else ever changes them
+                let keys = (0..500).map(|i| i * 8 + thread);
+                for key in keys.clone() {
+                    assert_eq!(map.insert(key, key), None);
+                    assert_eq!(map.get(&key), Some(key));
+                    assert_eq!(map.insert(key, key + 1), Some(key));
+                }
+                for key in keys {
+                    assert_eq!(map.remove(&key), Some(key + 1));
+                    assert_eq!(map.get(&key), None);
+                }
+            });
+        }
+    });
+    assert!(map.is_empty());
+}
+
+#[test]
+fn every_value_is_replaced_exactly_once() {
+    let map = ShardedMap::with_shards(4);
+    let mut values: Vec<u64> = thread::scope(|s| {
+        let handles: Vec<_> = (0..8u64)
+            .map(|thread| {
+                let map = &map;
+                s.spawn(move || {
+                    // Every insert replaces the value that was there right before it,
+                    // so no replaced value can be seen twice, or go missing
+                    (0..1000)
+                        .filter_map(|i| map.insert("key", thread * 1000 + i))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+    values.push(map.get(&"key").unwrap());
+    values.sort();
+    assert_eq!(values, (0..8000).collect::<Vec<_>>());
+}
+
+#[test]
+fn only_one_thread_removes_a_value() {
+    let map = ShardedMap::with_shards(4);
+    for key in 0..100 {
+        map.insert(key, key);
+    }
+    let removed: usize = thread::scope(|s| {
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let map = &map;
+                s.spawn(move || (0..100).filter(|key| map.remove(key).is_some()).count())
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .sum()
+    });
+    assert_eq!(removed, 100);
+    assert!(map.is_empty());
+}
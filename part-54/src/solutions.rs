@@ -0,0 +1,57 @@
+//! Part 54, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash, RandomState},
+    sync::RwLock,
+};
+
+use super::ConcurrentMap;
+
+/// A hash map split into shards, each a `HashMap` behind its own lock.
+/// Every key belongs to exactly one shard, decided by its hash,
+/// so operations on keys in different shards don't have to wait for each other.
+#[derive(Debug)]
+pub struct ShardedMap<K, V> {
+    shards: Vec<RwLock<HashMap<K, V>>>,
+    hasher: RandomState,
+}
+
+impl<K: Hash + Eq, V> ShardedMap<K, V> {
+    /// Creates an empty map with `shards` shards, which must be at least 1
+    pub fn with_shards(shards: usize) -> Self {
+        assert!(shards > 0, "A map needs at least one shard");
+        Self {
+            shards: (0..shards).map(|_| RwLock::new(HashMap::new())).collect(),
+            hasher: RandomState::new(),
+        }
+    }
+
+    /// The shard `key` belongs to
+    fn shard(&self, key: &K) -> &RwLock<HashMap<K, V>> {
+        let hash = self.hasher.hash_one(key);
+        &self.shards[(hash % self.shards.len() as u64) as usize]
+    }
+}
+
+impl<K: Hash + Eq + Send + Sync, V: Clone + Send + Sync> ConcurrentMap<K, V> for ShardedMap<K, V> {
+    fn insert(&self, key: K, value: V) -> Option<V> {
+        self.shard(&key).write().unwrap().insert(key, value)
+    }
+
+    fn get(&self, key: &K) -> Option<V> {
+        // Reads only need a read lock, so they don't wait for other reads in the same shard
+        self.shard(key).read().unwrap().get(key).cloned()
+    }
+
+    fn remove(&self, key: &K) -> Option<V> {
+        self.shard(key).write().unwrap().remove(key)
+    }
+
+    fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.read().unwrap().len())
+            .sum()
+    }
+}
@@ -0,0 +1,47 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-54 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::thread;
+
+use part_54::{solutions, ConcurrentMap, ShardedMap};
+
+#[test]
+fn maps_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let map = ShardedMap::with_shards(8);
+    let expected = solutions::ShardedMap::with_shards(8);
+    let ops = common::datagen::numbers_below(2000, 3, rng.seed());
+    let keys = common::datagen::numbers_below(2000, 100, rng.seed());
+    for (i, (op, key)) in ops.into_iter().zip(keys).enumerate() {
+        match op {
+            0 => assert_eq!(map.insert(key, i), ConcurrentMap::insert(&expected, key, i)),
+            1 => assert_eq!(map.get(&key), ConcurrentMap::get(&expected, &key)),
+            _ => assert_eq!(map.remove(&key), ConcurrentMap::remove(&expected, &key)),
+        }
+        assert_eq!(map.len(), ConcurrentMap::len(&expected));
+    }
+}
+
+#[test]
+fn maps_across_threads_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let map = ShardedMap::with_shards(4);
+    let expected = solutions::ShardedMap::with_shards(4);
+    thread::scope(|s| {
+        for _ in 0..4 {
+            let (map, expected) = (&map, &expected);
+            let seed = rng.seed();
+            s.spawn(move || {
+                for key in common::datagen::numbers_below(500, 1000, seed) {
+                    map.insert(key, key * 2);
+                    ConcurrentMap::insert(expected, key, key * 2);
+                }
+            });
+        }
+    });
+    assert_eq!(map.len(), ConcurrentMap::len(&expected));
+    for key in 0..1000 {
+        assert_eq!(map.get(&key), ConcurrentMap::get(&expected, &key));
+    }
+}
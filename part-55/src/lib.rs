@@ -0,0 +1,87 @@
+use std::sync::Mutex;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// What [`Bank::transfer`] gives back when the account doesn't have enough money
+#[derive(Debug, PartialEq, Eq)]
+pub struct InsufficientFunds;
+
+/// A bank with a fixed number of accounts, numbered from 0, which money can be moved between
+pub trait Bank: Sync {
+    /// Moves `amount` from account `from` to account `to`, if `from` has at least that much.
+    /// Transfers to the same account don't change anything.
+    fn transfer(&self, from: usize, to: usize, amount: u64) -> Result<(), InsufficientFunds>;
+    fn balance(&self, account: usize) -> u64;
+    /// The sum of every account's balance at a single moment
+    fn total(&self) -> u64;
+}
+
+/// Every account behind one lock, which every transfer has to take
+#[derive(Debug)]
+pub struct GlobalBank {
+    accounts: Mutex<Vec<u64>>,
+}
+
+impl GlobalBank {
+    /// Opens `accounts` accounts, with `balance` in each of them
+    pub fn new(accounts: usize, balance: u64) -> Self {
+        Self {
+            accounts: Mutex::new(vec![balance; accounts]),
+        }
+    }
+}
+
+impl Bank for GlobalBank {
+    fn transfer(&self, from: usize, to: usize, amount: u64) -> Result<(), InsufficientFunds> {
+        let mut accounts = self.accounts.lock().unwrap();
+        if accounts[from] < amount {
+            return Err(InsufficientFunds);
+        }
+        accounts[from] -= amount;
+        accounts[to] += amount;
+        Ok(())
+    }
+
+    fn balance(&self, account: usize) -> u64 {
+        self.accounts.lock().unwrap()[account]
+    }
+
+    fn total(&self) -> u64 {
+        self.accounts.lock().unwrap().iter().sum()
+    }
+}
+
+/// Every account behind its own lock, so transfers between different accounts
+/// don't have to wait for each other
+#[derive(Debug)]
+pub struct StripedBank {
+    accounts: Vec<Mutex<u64>>,
+}
+
+impl StripedBank {
+    /// Opens `accounts` accounts, with `balance` in each of them
+    pub fn new(accounts: usize, balance: u64) -> Self {
+        Self {
+            accounts: (0..accounts).map(|_| Mutex::new(balance)).collect(),
+        }
+    }
+}
+
+impl Bank for StripedBank {
+    /// Must never deadlock, even when other threads transfer between the same accounts
+    /// in the opposite direction
+    fn transfer(&self, from: usize, to: usize, amount: u64) -> Result<(), InsufficientFunds> {
+        todo!()
+    }
+
+    fn balance(&self, account: usize) -> u64 {
+        *self.accounts[account].lock().unwrap()
+    }
+
+    /// Must not count money in the middle of a transfer, which has left one account
+    /// but not yet arrived in the other
+    fn total(&self) -> u64 {
+        todo!()
+    }
+}
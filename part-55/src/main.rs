@@ -0,0 +1,156 @@
+use std::thread;
+
+use common::{bench::Comparison, cli::Cli};
+use fixtures::{
+    accounts::{accounts, Transfer},
+    Dataset,
+};
+use part_55::{Bank, GlobalBank, StripedBank};
+
+/// Run with `cargo run --release -p part-55` to compare the banks
+fn main() {
+    let args = Cli::new("Compares banks with a global lock and with a lock per account")
+        .threads(
+            thread::available_parallelism()
+                .map_or(4, |n| n.get())
+                .max(4),
+        )
+        .strategy()
+        .parse();
+    let threads = args.threads();
+    let accounts = accounts(Dataset::Large);
+    println!(
+        "Making {} transfers on {threads} threads each",
+        accounts.transfers.len()
+    );
+
+    let mut comparison = Comparison::new(5);
+    comparison
+        .bench("Global lock", || {
+            let bank = GlobalBank::new(accounts.count, accounts.balance);
+            make_transfers(&bank, threads, &accounts.transfers)
+        })
+        .bench("Lock per account", || {
+            let bank = StripedBank::new(accounts.count, accounts.balance);
+            make_transfers(&bank, threads, &accounts.transfers)
+        });
+    comparison.print();
+}
+
+/// Lets `threads` threads each make all of `transfers`, each starting at a different one.
+/// Returns how many of them were successful.
+fn make_transfers(bank: &impl Bank, threads: usize, transfers: &[Transfer]) -> u64 {
+    thread::scope(|s| {
+        let handles: Vec<_> = (0..threads)
+            .map(|thread| {
+                s.spawn(move || {
+                    let start = thread * transfers.len() / threads;
+                    transfers[start..]
+                        .iter()
+                        .chain(&transfers[..start])
+                        .filter(|transfer| {
+                            bank.transfer(transfer.from, transfer.to, transfer.amount)
+                                .is_ok()
+                        })
+                        .count() as u64
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("Couldn't join thread"))
+            .sum()
+    })
+}
+
+#[cfg(test)]
+const TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+#[test]
+fn transfers_money() {
+    use part_55::InsufficientFunds;
+
+    let bank = StripedBank::new(3, 100);
+    assert_eq!(bank.transfer(0, 1, 30), Ok(()));
+    assert_eq!(bank.transfer(1, 2, 130), Ok(()));
+    assert_eq!(bank.transfer(0, 2, 71), Err(InsufficientFunds));
+    assert_eq!(bank.transfer(0, 0, 70), Ok(()));
+    assert_eq!([0, 1, 2].map(|account| bank.balance(account)), [70, 0, 230]);
+    assert_eq!(bank.total(), 300);
+}
+
+#[test]
+fn opposite_transfers_do_not_deadlock() {
+    let finished = common::with_timeout(TIMEOUT, || {
+        let bank = StripedBank::new(2, 1000);
+        thread::scope(|s| {
+            s.spawn(|| (0..100_000).for_each(|_| _ = bank.transfer(0, 1, 1)));
+            s.spawn(|| (0..100_000).for_each(|_| _ = bank.transfer(1, 0, 1)));
+        });
+        bank.total()
+    });
+    assert_eq!(finished, Some(2000));
+}
+
+/// Makes lots of transfers from `dataset` while checking the total over and over again
+#[cfg(test)]
+fn check_total_is_conserved<B: Bank + Send + 'static>(dataset: Dataset, new: fn(usize, u64) -> B) {
+    let accounts = accounts(dataset);
+    let expected = accounts.total();
+    let bank = new(accounts.count, accounts.balance);
+    let result = common::with_timeout(TIMEOUT, move || {
+        thread::scope(|s| {
+            let transfers = s.spawn(|| make_transfers(&bank, 8, &accounts.transfers));
+            let mut totals = Vec::new();
+            while !transfers.is_finished() {
+                totals.push(bank.total());
+            }
+            (transfers.join().unwrap(), totals, bank.total())
+        })
+    });
+
+    let (successful, totals, total) = result.expect("The transfers deadlocked");
+    assert!(successful > 0, "No transfers were successful");
+    assert!(
+        totals.iter().all(|&total| total == expected),
+        "The total changed during the transfers"
+    );
+    assert_eq!(total, expected);
+}
+
+#[test]
+fn global_bank_conserves_money() {
+    check_total_is_conserved(Dataset::Medium, GlobalBank::new);
+}
+
+#[test]
+fn striped_bank_conserves_money() {
+    check_total_is_conserved(Dataset::Medium, StripedBank::new);
+}
+
+#[test]
+fn striped_bank_conserves_money_when_most_transfers_share_accounts() {
+    check_total_is_conserved(Dataset::Skewed, StripedBank::new);
+}
+
+#[test]
+fn striped_bank_conserves_money_between_few_accounts() {
+    // With fewer accounts, more transfers touch the same ones at the same time
+    let bank = StripedBank::new(4, 1000);
+    let result = common::with_timeout(TIMEOUT, move || {
+        thread::scope(|s| {
+            for thread in 0..8 {
+                let bank = &bank;
+                s.spawn(move || {
+                    for i in 0..20_000 {
+                        let from = (thread + i) % 4;
+                        let to = (thread * 3 + i * 7) % 4;
+                        _ = bank.transfer(from, to, (i % 300) as u64);
+                    }
+                });
+            }
+        });
+        bank.total()
+    });
+    assert_eq!(result, Some(4 * 1000));
+}
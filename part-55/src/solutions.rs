@@ -0,0 +1,68 @@
+//! Part 55, with every exercise implemented. Only built with `--features solutions`.
+
+use std::sync::Mutex;
+
+use super::{Bank, InsufficientFunds};
+
+/// Every account behind its own lock, so transfers between different accounts
+/// don't have to wait for each other
+#[derive(Debug)]
+pub struct StripedBank {
+    accounts: Vec<Mutex<u64>>,
+}
+
+impl StripedBank {
+    /// Opens `accounts` accounts, with `balance` in each of them
+    pub fn new(accounts: usize, balance: u64) -> Self {
+        Self {
+            accounts: (0..accounts).map(|_| Mutex::new(balance)).collect(),
+        }
+    }
+}
+
+impl Bank for StripedBank {
+    /// Must never deadlock, even when other threads transfer between the same accounts
+    /// in the opposite direction
+    fn transfer(&self, from: usize, to: usize, amount: u64) -> Result<(), InsufficientFunds> {
+        if from == to {
+            // Locking the same mutex twice would deadlock
+            return if self.balance(from) >= amount {
+                Ok(())
+            } else {
+                Err(InsufficientFunds)
+            };
+        }
+        // Every transfer locks the lower-numbered account first, so no two transfers can each
+        // hold a lock the other is waiting for
+        let (low, high) = (from.min(to), from.max(to));
+        let low_balance = self.accounts[low].lock().unwrap();
+        let high_balance = self.accounts[high].lock().unwrap();
+        let (mut from_balance, mut to_balance) = if from == low {
+            (low_balance, high_balance)
+        } else {
+            (high_balance, low_balance)
+        };
+        if *from_balance < amount {
+            return Err(InsufficientFunds);
+        }
+        *from_balance -= amount;
+        *to_balance += amount;
+        Ok(())
+    }
+
+    fn balance(&self, account: usize) -> u64 {
+        *self.accounts[account].lock().unwrap()
+    }
+
+    /// Must not count money in the middle of a transfer, which has left one account
+    /// but not yet arrived in the other
+    fn total(&self) -> u64 {
+        // Holding every lock at once, taken in the same order as the transfers take them
+        let balances: Vec<_> = self
+            .accounts
+            .iter()
+            .map(|account| account.lock().unwrap())
+            .collect();
+        balances.iter().map(|balance| **balance).sum()
+    }
+}
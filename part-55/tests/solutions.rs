@@ -0,0 +1,54 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-55 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::thread;
+
+use part_55::{solutions, Bank, StripedBank};
+
+#[test]
+fn transfers_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (bank, expected) = (
+        StripedBank::new(10, 100),
+        solutions::StripedBank::new(10, 100),
+    );
+    let accounts = common::datagen::numbers_below(2000, 10, rng.seed());
+    let amounts = common::datagen::numbers_below(1000, 150, rng.seed());
+    for (pair, amount) in accounts.chunks(2).zip(amounts) {
+        let (from, to) = (pair[0] as usize, pair[1] as usize);
+        assert_eq!(
+            bank.transfer(from, to, amount).is_ok(),
+            Bank::transfer(&expected, from, to, amount).is_ok(),
+            "Moving {amount} from {from} to {to}"
+        );
+        for account in 0..10 {
+            assert_eq!(bank.balance(account), Bank::balance(&expected, account));
+        }
+    }
+    assert_eq!(bank.total(), Bank::total(&expected));
+}
+
+#[test]
+fn keeps_the_total_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (bank, expected) = (
+        StripedBank::new(8, 1000),
+        solutions::StripedBank::new(8, 1000),
+    );
+    thread::scope(|s| {
+        for _ in 0..4 {
+            let (bank, expected) = (&bank, &expected);
+            let seed = rng.seed();
+            s.spawn(move || {
+                let accounts = common::datagen::numbers_below(1000, 8, seed);
+                for pair in accounts.chunks(2) {
+                    let (from, to) = (pair[0] as usize, pair[1] as usize);
+                    let _ = bank.transfer(from, to, 10);
+                    let _ = Bank::transfer(expected, from, to, 10);
+                }
+            });
+        }
+    });
+    assert_eq!(bank.total(), Bank::total(&expected));
+}
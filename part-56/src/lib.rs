@@ -0,0 +1,113 @@
+use std::sync::{Arc, Mutex, RwLock};
+
+use arc_swap::ArcSwap;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// A routing table, which says which backends requests should be sent to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Routes {
+    pub version: u64,
+    pub backends: Vec<String>,
+}
+
+impl Routes {
+    /// A table with `backends` backends, whose names all contain the version
+    pub fn new(version: u64, backends: usize) -> Self {
+        Self {
+            version,
+            backends: (0..backends)
+                .map(|i| format!("backend-{i}.v{version}"))
+                .collect(),
+        }
+    }
+
+    /// Picks the backend for a request, by its id
+    pub fn route(&self, request: u64) -> &str {
+        &self.backends[request as usize % self.backends.len()]
+    }
+
+    /// Whether every backend belongs to this version of the table,
+    /// which it wouldn't if it had been changed bit by bit
+    pub fn is_consistent(&self) -> bool {
+        let suffix = format!(".v{}", self.version);
+        self.backends
+            .iter()
+            .all(|backend| backend.ends_with(&suffix))
+    }
+}
+
+/// Holds the current routing table, which many threads read, and is occasionally replaced
+pub trait SharedRoutes: Sync {
+    fn new(routes: Routes) -> Self;
+    /// A snapshot of the current table, which stays the same even if the table is replaced
+    fn load(&self) -> Arc<Routes>;
+    /// Calls `f` with the current table, without making a snapshot of it
+    fn read<R>(&self, f: impl FnOnce(&Routes) -> R) -> R;
+    /// Replaces the whole table. Snapshots loaded before keep the old one.
+    fn store(&self, routes: Routes);
+}
+
+#[derive(Debug)]
+pub struct MutexRoutes(Mutex<Arc<Routes>>);
+
+#[derive(Debug)]
+pub struct RwLockRoutes(RwLock<Arc<Routes>>);
+
+#[derive(Debug)]
+pub struct ArcSwapRoutes(ArcSwap<Routes>);
+
+impl SharedRoutes for MutexRoutes {
+    fn new(routes: Routes) -> Self {
+        todo!()
+    }
+
+    fn load(&self) -> Arc<Routes> {
+        todo!()
+    }
+
+    fn read<R>(&self, f: impl FnOnce(&Routes) -> R) -> R {
+        todo!()
+    }
+
+    fn store(&self, routes: Routes) {
+        todo!()
+    }
+}
+
+impl SharedRoutes for RwLockRoutes {
+    fn new(routes: Routes) -> Self {
+        todo!()
+    }
+
+    fn load(&self) -> Arc<Routes> {
+        todo!()
+    }
+
+    fn read<R>(&self, f: impl FnOnce(&Routes) -> R) -> R {
+        todo!()
+    }
+
+    fn store(&self, routes: Routes) {
+        todo!()
+    }
+}
+
+impl SharedRoutes for ArcSwapRoutes {
+    fn new(routes: Routes) -> Self {
+        todo!()
+    }
+
+    fn load(&self) -> Arc<Routes> {
+        todo!()
+    }
+
+    fn read<R>(&self, f: impl FnOnce(&Routes) -> R) -> R {
+        todo!()
+    }
+
+    fn store(&self, routes: Routes) {
+        todo!()
+    }
+}
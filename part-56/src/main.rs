@@ -0,0 +1,141 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    thread,
+    time::Duration,
+};
+
+use common::{
+    bench::{self, Comparison},
+    cli::Cli,
+};
+use part_56::{ArcSwapRoutes, MutexRoutes, Routes, RwLockRoutes, SharedRoutes};
+
+const BACKENDS: usize = 16;
+const READS: u64 = 200_000;
+
+/// Run with `cargo run --release -p part-56` to compare how fast the tables can be read
+fn main() {
+    let args = Cli::new("Compares routing tables behind a Mutex, an RwLock and an ArcSwap")
+        .threads(
+            thread::available_parallelism()
+                .map_or(4, |n| n.get())
+                .max(4),
+        )
+        .strategy()
+        .parse();
+    let readers = args.threads();
+    println!("Routing {READS} requests on {readers} threads each, while the table is replaced");
+
+    let mut comparison = Comparison::new(5);
+    comparison
+        .bench("Mutex", || read_while_replacing::<MutexRoutes>(readers))
+        .bench("RwLock", || read_while_replacing::<RwLockRoutes>(readers))
+        .bench("ArcSwap", || read_while_replacing::<ArcSwapRoutes>(readers));
+    comparison.print();
+}
+
+/// Lets `readers` threads route `READS` requests each, while another thread replaces the table
+/// every 100 microseconds. Returns how many times the table was replaced.
+fn read_while_replacing<S: SharedRoutes>(readers: usize) -> u64 {
+    let routes = S::new(Routes::new(0, BACKENDS));
+    let done = AtomicBool::new(false);
+    thread::scope(|s| {
+        let writer = s.spawn(|| {
+            let mut version = 0;
+            while !done.load(Ordering::Relaxed) {
+                version += 1;
+                routes.store(Routes::new(version, BACKENDS));
+                thread::sleep(Duration::from_micros(100));
+            }
+            version
+        });
+
+        let handles: Vec<_> = (0..readers)
+            .map(|_| {
+                s.spawn(|| {
+                    for request in 0..READS {
+                        routes.read(|routes| bench::consume(routes.route(request).len()));
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().expect("Couldn't join reader");
+        }
+        done.store(true, Ordering::Relaxed);
+        writer.join().expect("Couldn't join writer")
+    })
+}
+
+#[cfg(test)]
+fn check_loads_what_was_stored<S: SharedRoutes>() {
+    let routes = S::new(Routes::new(0, BACKENDS));
+    let first = routes.load();
+    assert_eq!(*first, Routes::new(0, BACKENDS));
+
+    routes.store(Routes::new(1, BACKENDS));
+    assert_eq!(*routes.load(), Routes::new(1, BACKENDS));
+    assert_eq!(routes.read(|routes| routes.version), 1);
+    // Snapshots aren't changed by later stores
+    assert_eq!(*first, Routes::new(0, BACKENDS));
+}
+
+/// Lets several threads load snapshots while the table is replaced over and over again, checking
+/// that every snapshot is a whole table, and that no thread ever sees an older table than before
+#[cfg(test)]
+fn check_snapshots_are_consistent<S: SharedRoutes>() {
+    const VERSIONS: u64 = 500;
+
+    let routes = S::new(Routes::new(0, BACKENDS));
+    let done = AtomicBool::new(false);
+    thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                let mut latest = 0;
+                while !done.load(Ordering::Relaxed) {
+                    let snapshot = routes.load();
+                    assert!(snapshot.is_consistent(), "Saw a mix of tables");
+                    assert!(snapshot.version >= latest, "Saw an older table");
+                    latest = snapshot.version;
+                    assert!(routes.read(|routes| routes.is_consistent()));
+                }
+            });
+        }
+
+        for version in 1..=VERSIONS {
+            routes.store(Routes::new(version, BACKENDS));
+        }
+        done.store(true, Ordering::Relaxed);
+    });
+    assert_eq!(routes.load().version, VERSIONS);
+}
+
+#[test]
+fn mutex_loads_what_was_stored() {
+    check_loads_what_was_stored::<MutexRoutes>();
+}
+
+#[test]
+fn mutex_snapshots_are_consistent() {
+    check_snapshots_are_consistent::<MutexRoutes>();
+}
+
+#[test]
+fn rwlock_loads_what_was_stored() {
+    check_loads_what_was_stored::<RwLockRoutes>();
+}
+
+#[test]
+fn rwlock_snapshots_are_consistent() {
+    check_snapshots_are_consistent::<RwLockRoutes>();
+}
+
+#[test]
+fn arc_swap_loads_what_was_stored() {
+    check_loads_what_was_stored::<ArcSwapRoutes>();
+}
+
+#[test]
+fn arc_swap_snapshots_are_consistent() {
+    check_snapshots_are_consistent::<ArcSwapRoutes>();
+}
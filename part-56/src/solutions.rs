@@ -0,0 +1,71 @@
+//! Part 56, with every exercise implemented. Only built with `--features solutions`.
+
+use std::sync::{Arc, Mutex, RwLock};
+
+use arc_swap::ArcSwap;
+
+use super::{Routes, SharedRoutes};
+
+#[derive(Debug)]
+pub struct MutexRoutes(Mutex<Arc<Routes>>);
+
+#[derive(Debug)]
+pub struct RwLockRoutes(RwLock<Arc<Routes>>);
+
+#[derive(Debug)]
+pub struct ArcSwapRoutes(ArcSwap<Routes>);
+
+impl SharedRoutes for MutexRoutes {
+    fn new(routes: Routes) -> Self {
+        Self(Mutex::new(Arc::new(routes)))
+    }
+
+    fn load(&self) -> Arc<Routes> {
+        self.0.lock().unwrap().clone()
+    }
+
+    fn read<R>(&self, f: impl FnOnce(&Routes) -> R) -> R {
+        f(&self.0.lock().unwrap())
+    }
+
+    fn store(&self, routes: Routes) {
+        *self.0.lock().unwrap() = Arc::new(routes);
+    }
+}
+
+impl SharedRoutes for RwLockRoutes {
+    fn new(routes: Routes) -> Self {
+        Self(RwLock::new(Arc::new(routes)))
+    }
+
+    fn load(&self) -> Arc<Routes> {
+        self.0.read().unwrap().clone()
+    }
+
+    fn read<R>(&self, f: impl FnOnce(&Routes) -> R) -> R {
+        f(&self.0.read().unwrap())
+    }
+
+    fn store(&self, routes: Routes) {
+        *self.0.write().unwrap() = Arc::new(routes);
+    }
+}
+
+impl SharedRoutes for ArcSwapRoutes {
+    fn new(routes: Routes) -> Self {
+        Self(ArcSwap::from_pointee(routes))
+    }
+
+    fn load(&self) -> Arc<Routes> {
+        self.0.load_full()
+    }
+
+    fn read<R>(&self, f: impl FnOnce(&Routes) -> R) -> R {
+        // A guard, which doesn't touch the reference count like a snapshot would
+        f(&self.0.load())
+    }
+
+    fn store(&self, routes: Routes) {
+        self.0.store(Arc::new(routes));
+    }
+}
@@ -0,0 +1,78 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-56 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::thread;
+
+use part_56::{solutions, ArcSwapRoutes, MutexRoutes, Routes, RwLockRoutes, SharedRoutes};
+
+/// Which backend each request went to, with which version of the table, while the table was replaced
+fn routed<S: SharedRoutes>() -> Vec<(u64, String)> {
+    let shared = S::new(Routes::new(0, 3));
+    let mut routed = Vec::new();
+    for version in 1..20 {
+        routed.push((
+            shared.load().version,
+            shared.read(|routes| routes.route(version).to_string()),
+        ));
+        shared.store(Routes::new(version, version as usize % 4 + 1));
+    }
+    routed
+}
+
+/// Like `routed`, for the solution's tables
+fn expected<S: SharedRoutes>() -> Vec<(u64, String)> {
+    let shared = S::new(Routes::new(0, 3));
+    let mut routed = Vec::new();
+    for version in 1..20 {
+        routed.push((
+            shared.load().version,
+            shared.read(|routes| routes.route(version).to_string()),
+        ));
+        shared.store(Routes::new(version, version as usize % 4 + 1));
+    }
+    routed
+}
+
+#[test]
+fn routes_like_the_solution() {
+    assert_eq!(
+        routed::<MutexRoutes>(),
+        expected::<solutions::MutexRoutes>()
+    );
+    assert_eq!(
+        routed::<RwLockRoutes>(),
+        expected::<solutions::RwLockRoutes>()
+    );
+    assert_eq!(
+        routed::<ArcSwapRoutes>(),
+        expected::<solutions::ArcSwapRoutes>()
+    );
+}
+
+/// Whether every table the readers saw, while a writer replaced it, was a whole one
+fn consistent<S: SharedRoutes>() -> bool {
+    let shared = S::new(Routes::new(0, 4));
+    thread::scope(|s| {
+        let readers: Vec<_> = (0..3)
+            .map(|_| {
+                s.spawn(|| {
+                    (0..1000).all(|_| {
+                        shared.read(Routes::is_consistent) && shared.load().is_consistent()
+                    })
+                })
+            })
+            .collect();
+        for version in 1..200 {
+            shared.store(Routes::new(version, 4));
+        }
+        readers.into_iter().all(|reader| reader.join().unwrap())
+    })
+}
+
+#[test]
+fn stays_consistent_like_the_solution() {
+    assert!(consistent::<MutexRoutes>());
+    assert!(consistent::<RwLockRoutes>());
+    assert!(consistent::<ArcSwapRoutes>());
+}
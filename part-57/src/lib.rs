@@ -0,0 +1,87 @@
+use std::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Barrier, Mutex, OnceLock,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Computes the value the first time it's needed
+pub type Init = Box<dyn Fn() -> u64 + Send + Sync>;
+
+/// A value which is computed the first time it's asked for, and then kept
+pub trait Lazy: Sync {
+    fn new(init: Init) -> Self;
+    /// The value, computing it first if nobody has yet
+    fn get(&self) -> u64;
+}
+
+/// Checks whether the value is there, and if not, computes it.
+///
+/// Never gives a wrong value, but several threads can see that it's missing at the same time,
+/// and every one of them computes it.
+pub struct RacyLazy {
+    ready: AtomicBool,
+    value: AtomicU64,
+    init: Init,
+}
+
+impl Lazy for RacyLazy {
+    fn new(init: Init) -> Self {
+        Self {
+            ready: AtomicBool::new(false),
+            value: AtomicU64::new(0),
+            init,
+        }
+    }
+
+    fn get(&self) -> u64 {
+        if self.ready.load(Ordering::Acquire) {
+            return self.value.load(Ordering::Relaxed);
+        }
+        let value = (self.init)();
+        self.value.store(value, Ordering::Relaxed);
+        self.ready.store(true, Ordering::Release);
+        value
+    }
+}
+
+/// Lets `threads` threads call `lazy.get()` at the same time, returning what each of them got
+pub fn stampede(lazy: &impl Lazy, threads: usize) -> Vec<u64> {
+    todo!()
+}
+
+/// Computes the value exactly once, with the standard library's `OnceLock`
+pub struct OnceLockLazy {
+    value: OnceLock<u64>,
+    init: Init,
+}
+
+impl Lazy for OnceLockLazy {
+    fn new(init: Init) -> Self {
+        todo!()
+    }
+
+    fn get(&self) -> u64 {
+        todo!()
+    }
+}
+
+/// Computes the value exactly once. Once it's been computed, getting it only takes an atomic load.
+pub struct DoubleCheckedLazy {
+    ready: AtomicBool,
+    value: AtomicU64,
+    /// Held by the thread computing the value
+    lock: Mutex<()>,
+    init: Init,
+}
+
+impl Lazy for DoubleCheckedLazy {
+    fn new(init: Init) -> Self {
+        todo!()
+    }
+
+    fn get(&self) -> u64 {
+        todo!()
+    }
+}
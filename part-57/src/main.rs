@@ -0,0 +1,84 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use part_57::{stampede, DoubleCheckedLazy, Init, Lazy, OnceLockLazy, RacyLazy};
+
+const THREADS: usize = 8;
+
+fn main() {
+    let args = common::cli::Cli::new("Initializes lazy values from many threads at once")
+        .threads(THREADS)
+        .parse();
+    report::<RacyLazy>("RacyLazy", args.threads());
+    report::<OnceLockLazy>("OnceLockLazy", args.threads());
+    report::<DoubleCheckedLazy>("DoubleCheckedLazy", args.threads());
+}
+
+fn report<L: Lazy>(name: &str, threads: usize) {
+    let (init, inits) = counted_init();
+    let values = stampede(&L::new(init), threads);
+    println!(
+        "{name} was initialized {} time(s) by {threads} threads, which got {values:?}",
+        inits.load(Ordering::Relaxed)
+    );
+}
+
+/// A slow initializer, which counts how many times it's been called
+fn counted_init() -> (Init, Arc<AtomicUsize>) {
+    let inits = Arc::new(AtomicUsize::new(0));
+    let init = {
+        let inits = inits.clone();
+        Box::new(move || {
+            inits.fetch_add(1, Ordering::Relaxed);
+            // Slow enough that the other threads get to check before the value is ready
+            thread::sleep(Duration::from_millis(10));
+            42
+        })
+    };
+    (init, inits)
+}
+
+/// Lets many threads get the value at once, and returns how many times it was initialized
+#[cfg(test)]
+fn inits_in_stampede<L: Lazy>() -> usize {
+    let (init, inits) = counted_init();
+    let lazy = L::new(init);
+    assert_eq!(stampede(&lazy, THREADS), vec![42; THREADS]);
+    // Once the value is there, it's never computed again
+    let before = inits.load(Ordering::Relaxed);
+    assert_eq!(lazy.get(), 42);
+    assert_eq!(inits.load(Ordering::Relaxed), before);
+    before
+}
+
+#[test]
+fn racy_lazy_initializes_more_than_once() {
+    let inits = inits_in_stampede::<RacyLazy>();
+    assert!(inits > 1, "Only initialized {inits} time(s)");
+}
+
+#[test]
+fn once_lock_lazy_initializes_once() {
+    assert_eq!(inits_in_stampede::<OnceLockLazy>(), 1);
+}
+
+#[test]
+fn double_checked_lazy_initializes_once() {
+    assert_eq!(inits_in_stampede::<DoubleCheckedLazy>(), 1);
+}
+
+#[test]
+fn double_checked_lazy_stays_initialized() {
+    let (init, inits) = counted_init();
+    let lazy = DoubleCheckedLazy::new(init);
+    for _ in 0..10 {
+        assert_eq!(stampede(&lazy, 4), vec![42; 4]);
+    }
+    assert_eq!(inits.load(Ordering::Relaxed), 1);
+}
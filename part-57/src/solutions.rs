@@ -0,0 +1,84 @@
+//! Part 57, with every exercise implemented. Only built with `--features solutions`.
+
+use std::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Barrier, Mutex, OnceLock,
+};
+
+use super::{Init, Lazy};
+
+/// Lets `threads` threads call `lazy.get()` at the same time, returning what each of them got
+pub fn stampede(lazy: &impl Lazy, threads: usize) -> Vec<u64> {
+    let barrier = Barrier::new(threads);
+    std::thread::scope(|s| {
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                s.spawn(|| {
+                    // Makes every thread wait until all of them are ready to go
+                    barrier.wait();
+                    lazy.get()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("Couldn't join thread"))
+            .collect()
+    })
+}
+
+/// Computes the value exactly once, with the standard library's `OnceLock`
+pub struct OnceLockLazy {
+    value: OnceLock<u64>,
+    init: Init,
+}
+
+impl Lazy for OnceLockLazy {
+    fn new(init: Init) -> Self {
+        Self {
+            value: OnceLock::new(),
+            init,
+        }
+    }
+
+    fn get(&self) -> u64 {
+        *self.value.get_or_init(&self.init)
+    }
+}
+
+/// Computes the value exactly once. Once it's been computed, getting it only takes an atomic load.
+pub struct DoubleCheckedLazy {
+    ready: AtomicBool,
+    value: AtomicU64,
+    /// Held by the thread computing the value
+    lock: Mutex<()>,
+    init: Init,
+}
+
+impl Lazy for DoubleCheckedLazy {
+    fn new(init: Init) -> Self {
+        Self {
+            ready: AtomicBool::new(false),
+            value: AtomicU64::new(0),
+            lock: Mutex::new(()),
+            init,
+        }
+    }
+
+    fn get(&self) -> u64 {
+        // The first check: the value is usually there, and then no lock is needed
+        if self.ready.load(Ordering::Acquire) {
+            return self.value.load(Ordering::Relaxed);
+        }
+        let _guard = self.lock.lock().unwrap();
+        // The second check: another thread may have computed it while we waited for the lock
+        if self.ready.load(Ordering::Acquire) {
+            return self.value.load(Ordering::Relaxed);
+        }
+        let value = (self.init)();
+        self.value.store(value, Ordering::Relaxed);
+        // Release makes sure that whoever sees `ready` also sees the value
+        self.ready.store(true, Ordering::Release);
+        value
+    }
+}
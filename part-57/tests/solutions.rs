@@ -0,0 +1,51 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-57 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+use part_57::{solutions, stampede, DoubleCheckedLazy, Init, Lazy, OnceLockLazy};
+
+/// Computes 57, counting how many times it did
+fn counted() -> (Init, Arc<AtomicU64>) {
+    let calls = Arc::new(AtomicU64::new(0));
+    let counter = Arc::clone(&calls);
+    let init: Init = Box::new(move || {
+        counter.fetch_add(1, Ordering::Relaxed);
+        57
+    });
+    (init, calls)
+}
+
+/// What the threads got, and how many times the value was computed
+fn stampeded<L: Lazy>(threads: usize) -> (Vec<u64>, u64) {
+    let (init, calls) = counted();
+    let got = stampede(&L::new(init), threads);
+    (got, calls.load(Ordering::Relaxed))
+}
+
+/// Like `stampeded`, with the solution's stampede
+fn expected<L: Lazy>(threads: usize) -> (Vec<u64>, u64) {
+    let (init, calls) = counted();
+    let got = solutions::stampede(&L::new(init), threads);
+    (got, calls.load(Ordering::Relaxed))
+}
+
+#[test]
+fn computes_once_like_the_solution() {
+    for threads in [1, 4, 16] {
+        assert_eq!(
+            stampeded::<OnceLockLazy>(threads),
+            expected::<solutions::OnceLockLazy>(threads),
+            "{threads} threads"
+        );
+        assert_eq!(
+            stampeded::<DoubleCheckedLazy>(threads),
+            expected::<solutions::DoubleCheckedLazy>(threads),
+            "{threads} threads"
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use std::time::Duration;
+
+use tokio::{
+    sync::{mpsc, watch},
+    task::JoinSet,
+};
+
+#[cfg(all(test, feature = "solutions"))]
+mod solutions;
+
+#[tokio::main]
+async fn main() {
+    let (config, receiver) = watch::channel(Config::new(Duration::from_millis(300)));
+    let (jobs, mut done) = mpsc::unbounded_channel();
+    let mut workers = spawn_workers(3, &receiver, jobs);
+    drop(receiver);
+
+    let start = tokio::time::Instant::now();
+    let printer = tokio::spawn(async move {
+        while let Some(job) = done.recv().await {
+            println!(
+                "{:>5} ms: worker {} ran a job with config version {}",
+                start.elapsed().as_millis(),
+                job.worker,
+                job.version
+            );
+        }
+    });
+
+    tokio::time::sleep(Duration::from_millis(1000)).await;
+    println!("Speeding up");
+    update(&config, Duration::from_millis(100));
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    println!("Slowing down, a few times in a row");
+    for millis in [200, 300, 400, 500] {
+        update(&config, Duration::from_millis(millis));
+    }
+    tokio::time::sleep(Duration::from_millis(1200)).await;
+
+    // The workers stop once every sender of the config is gone
+    drop(config);
+    while workers.join_next().await.is_some() {}
+    printer.await.unwrap();
+}
+
+/// The configuration of the workers, which can change while they run
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Config {
+    /// Incremented on every update
+    version: u64,
+    /// How long a worker waits between jobs
+    period: Duration,
+}
+
+impl Config {
+    fn new(period: Duration) -> Self {
+        Self { version: 0, period }
+    }
+}
+
+/// A job done by a worker, with the config it had when it did it
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Job {
+    worker: usize,
+    version: u64,
+}
+
+/// Does a job every `period`, reporting it to `jobs`, with whatever config is current.
+/// Starts waiting for the next job over with the new period as soon as the config changes.
+/// Returns once the sender of the config has been dropped.
+async fn worker(id: usize, mut config: watch::Receiver<Config>, jobs: mpsc::UnboundedSender<Job>) {
+    todo!()
+}
+
+/// Spawns `count` workers numbered from 0, which all follow the same `config`
+fn spawn_workers(
+    count: usize,
+    config: &watch::Receiver<Config>,
+    jobs: mpsc::UnboundedSender<Job>,
+) -> JoinSet<()> {
+    todo!()
+}
+
+/// Changes the period of every worker, and increments the version of the config
+fn update(config: &watch::Sender<Config>, period: Duration) {
+    todo!()
+}
+
+/// Runs `workers` workers doing a job every `period`, and returns the sender of their config,
+/// the receiver of their jobs, and the workers
+#[cfg(test)]
+fn start(
+    workers: usize,
+    period: Duration,
+) -> (
+    watch::Sender<Config>,
+    mpsc::UnboundedReceiver<Job>,
+    JoinSet<()>,
+) {
+    let (config, receiver) = watch::channel(Config::new(period));
+    let (jobs, done) = mpsc::unbounded_channel();
+    let workers = spawn_workers(workers, &receiver, jobs);
+    (config, done, workers)
+}
+
+/// Stops the workers, and returns every job they did, in order
+#[cfg(test)]
+async fn stop(
+    config: watch::Sender<Config>,
+    mut jobs: mpsc::UnboundedReceiver<Job>,
+    mut workers: JoinSet<()>,
+) -> Vec<Job> {
+    drop(config);
+    while workers.join_next().await.is_some() {}
+    let mut done = Vec::new();
+    while let Some(job) = jobs.recv().await {
+        done.push(job);
+    }
+    done
+}
+
+/// The versions of the config `worker` used, in order
+#[cfg(test)]
+fn versions_of(jobs: &[Job], worker: usize) -> Vec<u64> {
+    jobs.iter()
+        .filter(|job| job.worker == worker)
+        .map(|job| job.version)
+        .collect()
+}
+
+#[tokio::test(start_paused = true)]
+async fn workers_use_the_initial_config() {
+    let (config, jobs, workers) = start(3, Duration::from_millis(100));
+    tokio::time::sleep(Duration::from_millis(350)).await;
+
+    let jobs = stop(config, jobs, workers).await;
+    for worker in 0..3 {
+        assert_eq!(versions_of(&jobs, worker), [0, 0, 0]);
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn workers_pick_up_updates() {
+    let (config, jobs, workers) = start(3, Duration::from_millis(100));
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    update(&config, Duration::from_millis(20));
+    // Waiting for the next job starts over with the new period
+    tokio::time::sleep(Duration::from_millis(110)).await;
+
+    let jobs = stop(config, jobs, workers).await;
+    for worker in 0..3 {
+        assert_eq!(versions_of(&jobs, worker), [0, 0, 1, 1, 1, 1, 1]);
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn workers_only_see_the_latest_of_a_burst_of_updates() {
+    let (config, jobs, workers) = start(4, Duration::from_millis(100));
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    for millis in 1..=10 {
+        update(&config, Duration::from_millis(millis * 10));
+    }
+    tokio::time::sleep(Duration::from_millis(350)).await;
+
+    let jobs = stop(config, jobs, workers).await;
+    for worker in 0..4 {
+        // The last update has a period of 100 ms, like the first config
+        assert_eq!(versions_of(&jobs, worker), [0, 10, 10, 10]);
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn every_worker_converges_on_the_latest_config() {
+    let (config, jobs, workers) = start(10, Duration::from_millis(30));
+    for update_number in 1..=5 {
+        tokio::time::sleep(Duration::from_millis(45)).await;
+        update(&config, Duration::from_millis(30 + update_number));
+    }
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert_eq!(
+        *config.borrow(),
+        Config {
+            version: 5,
+            period: Duration::from_millis(35),
+        }
+    );
+    let jobs = stop(config, jobs, workers).await;
+    for worker in 0..10 {
+        let versions = versions_of(&jobs, worker);
+        assert!(versions.is_sorted(), "Went back to an old config");
+        assert_eq!(versions.last(), Some(&5));
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn workers_stop_when_the_config_is_dropped() {
+    let (config, _jobs, mut workers) = start(3, Duration::from_millis(100));
+    drop(config);
+    let stopped = tokio::time::timeout(Duration::from_millis(1), async {
+        while workers.join_next().await.is_some() {}
+    });
+    assert!(stopped.await.is_ok(), "Workers are still running");
+}
+
+#[cfg(feature = "solutions")]
+#[tokio::test(start_paused = true)]
+async fn follows_updates_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let period = Duration::from_millis(50);
+    let (config, jobs, workers) = start(4, period);
+    let (expected_config, receiver) = watch::channel(Config::new(period));
+    let (expected_jobs, mut expected_done) = mpsc::unbounded_channel();
+    let mut expected_workers = solutions::spawn_workers(4, &receiver, expected_jobs);
+    drop(receiver);
+
+    let millis = common::datagen::numbers_below(10, 80, rng.seed());
+    for &millis in &millis {
+        tokio::time::sleep(Duration::from_millis(millis + 1)).await;
+        update(&config, Duration::from_millis(millis + 10));
+        solutions::update(&expected_config, Duration::from_millis(millis + 10));
+    }
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    drop(expected_config);
+    while expected_workers.join_next().await.is_some() {}
+    let mut expected = Vec::new();
+    while let Some(job) = expected_done.recv().await {
+        expected.push((job.worker, job.version));
+    }
+    let jobs = stop(config, jobs, workers).await;
+    for worker in 0..4 {
+        let versions: Vec<u64> = expected
+            .iter()
+            .filter(|&&(id, _)| id == worker)
+            .map(|&(_, version)| version)
+            .collect();
+        assert_eq!(versions_of(&jobs, worker), versions, "Worker {worker}");
+    }
+}
@@ -0,0 +1,66 @@
+//! Part 58, with every exercise implemented. Only built with `--features solutions`.
+
+use std::time::Duration;
+
+use tokio::{
+    sync::{mpsc, watch},
+    task::JoinSet,
+};
+
+use super::Config;
+
+/// A job done by a worker, with the config it had when it did it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Job {
+    pub worker: usize,
+    pub version: u64,
+}
+
+/// Does a job every `period`, reporting it to `jobs`, with whatever config is current.
+/// Starts waiting for the next job over with the new period as soon as the config changes.
+/// Returns once the sender of the config has been dropped.
+pub async fn worker(
+    id: usize,
+    mut config: watch::Receiver<Config>,
+    jobs: mpsc::UnboundedSender<Job>,
+) {
+    loop {
+        // Marks the current config as seen, so `changed` waits for the next one
+        let current = config.borrow_and_update().clone();
+        tokio::select! {
+            _ = tokio::time::sleep(current.period) => {
+                let job = Job { worker: id, version: current.version };
+                if jobs.send(job).is_err() {
+                    return;
+                }
+            }
+            changed = config.changed() => {
+                if changed.is_err() {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Spawns `count` workers numbered from 0, which all follow the same `config`
+pub fn spawn_workers(
+    count: usize,
+    config: &watch::Receiver<Config>,
+    jobs: mpsc::UnboundedSender<Job>,
+) -> JoinSet<()> {
+    let mut workers = JoinSet::new();
+    for id in 0..count {
+        workers.spawn(worker(id, config.clone(), jobs.clone()));
+    }
+    workers
+}
+
+/// Changes the period of every worker, and increments the version of the config
+pub fn update(config: &watch::Sender<Config>, period: Duration) {
+    // Changes the config in place, even if every receiver is gone
+    config.send_modify(|config| {
+        config.version += 1;
+        config.period = period;
+    });
+}
@@ -0,0 +1,199 @@
+use std::time::Duration;
+
+use tokio::sync::broadcast::{self, error::RecvError};
+
+#[cfg(all(test, feature = "solutions"))]
+mod solutions;
+
+/// How many messages the channel keeps for subscribers that haven't received them yet
+const CAPACITY: usize = 4;
+const MESSAGES: u64 = 30;
+
+#[tokio::main]
+async fn main() {
+    for (name, policy) in [
+        ("Skipping", Policy::Skip),
+        ("Resubscribing", Policy::Resubscribe),
+    ] {
+        let (sender, receiver) = broadcast::channel(CAPACITY);
+        let subscriber = tokio::spawn(policy.subscribe(receiver, Duration::from_millis(25)));
+        publish(sender, MESSAGES, Duration::from_millis(10)).await;
+        let received = subscriber.await.unwrap();
+        println!(
+            "{name} subscriber received {:?}, and missed {}",
+            received.messages, received.missed
+        );
+    }
+}
+
+/// Sends `0..count` on `sender`, one every `period`, and then closes the channel
+async fn publish(sender: broadcast::Sender<u64>, count: u64, period: Duration) {
+    for message in 0..count {
+        // Only fails if there are no subscribers, and there may be new ones later
+        _ = sender.send(message);
+        tokio::time::sleep(period).await;
+    }
+}
+
+/// What a subscriber got out of a broadcast channel by the time it closed
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Received {
+    messages: Vec<u64>,
+    /// How many messages it never received, because it was too slow
+    missed: u64,
+}
+
+/// What a subscriber does when it falls so far behind that the channel drops messages before
+/// the subscriber receives them
+#[derive(Debug, Clone, Copy)]
+enum Policy {
+    /// Skips the dropped messages and carries on with the oldest one the channel still has
+    Skip,
+    /// Skips every message the channel has, and carries on with the next one sent
+    Resubscribe,
+}
+
+impl Policy {
+    /// Receives messages until the channel closes, spending `work` on every one of them
+    async fn subscribe(self, receiver: broadcast::Receiver<u64>, work: Duration) -> Received {
+        match self {
+            Policy::Skip => skip_lagged(receiver, work).await,
+            Policy::Resubscribe => resubscribe_on_lag(receiver, work).await,
+        }
+    }
+}
+
+/// Receives messages until the channel closes, spending `work` on every one of them.
+/// When it falls behind, it carries on with the oldest message the channel still has.
+async fn skip_lagged(mut receiver: broadcast::Receiver<u64>, work: Duration) -> Received {
+    todo!()
+}
+
+/// Receives messages until the channel closes, spending `work` on every one of them.
+/// When it falls behind, it skips every message the channel still has by resubscribing,
+/// and carries on with the next one sent.
+async fn resubscribe_on_lag(mut receiver: broadcast::Receiver<u64>, work: Duration) -> Received {
+    todo!()
+}
+
+/// Lets a subscriber following `policy` receive `MESSAGES` messages, where it takes `work` to
+/// handle each one while a new one is sent every 10 ms
+#[cfg(test)]
+async fn slow_subscriber(policy: Policy, work: Duration) -> Received {
+    let (sender, receiver) = broadcast::channel(CAPACITY);
+    let subscriber = tokio::spawn(policy.subscribe(receiver, work));
+    publish(sender, MESSAGES, Duration::from_millis(10)).await;
+    subscriber.await.unwrap()
+}
+
+#[cfg(test)]
+fn assert_accounts_for_every_message(received: &Received) {
+    assert!(
+        received.messages.windows(2).all(|pair| pair[0] < pair[1]),
+        "Received {:?}, which is out of order or has duplicates",
+        received.messages
+    );
+    assert_eq!(
+        received.messages.len() as u64 + received.missed,
+        MESSAGES,
+        "Received {:?} and missed {}",
+        received.messages,
+        received.missed
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn fast_subscribers_miss_nothing() {
+    for policy in [Policy::Skip, Policy::Resubscribe] {
+        let received = slow_subscriber(policy, Duration::from_millis(5)).await;
+        assert_eq!(received.messages, (0..MESSAGES).collect::<Vec<_>>());
+        assert_eq!(received.missed, 0);
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn skipping_catches_up_with_the_oldest_messages() {
+    let received = slow_subscriber(Policy::Skip, Duration::from_millis(25)).await;
+    assert_accounts_for_every_message(&received);
+    assert!(received.missed > 0, "Didn't miss anything");
+
+    // The last messages are still in the channel when it closes
+    assert_eq!(received.messages.last(), Some(&(MESSAGES - 1)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn resubscribing_skips_to_the_newest_messages() {
+    let received = slow_subscriber(Policy::Resubscribe, Duration::from_millis(25)).await;
+    assert_accounts_for_every_message(&received);
+
+    let skipping = slow_subscriber(Policy::Skip, Duration::from_millis(25)).await;
+    // Resubscribing throws away what the channel had, on top of what it already dropped
+    assert!(received.missed > skipping.missed);
+}
+
+#[tokio::test(start_paused = true)]
+async fn resubscribing_drops_the_backlog() {
+    let (sender, receiver) = broadcast::channel(CAPACITY);
+    for message in 0..10 {
+        sender.send(message).unwrap();
+    }
+    let subscriber = tokio::spawn(resubscribe_on_lag(receiver, Duration::ZERO));
+    tokio::time::sleep(Duration::from_millis(1)).await;
+    for message in 10..12 {
+        sender.send(message).unwrap();
+    }
+    drop(sender);
+
+    assert_eq!(
+        subscriber.await.unwrap(),
+        Received {
+            messages: vec![10, 11],
+            missed: 10,
+        }
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn skipping_keeps_the_backlog() {
+    let (sender, receiver) = broadcast::channel(CAPACITY);
+    for message in 0..10 {
+        sender.send(message).unwrap();
+    }
+    let subscriber = tokio::spawn(skip_lagged(receiver, Duration::ZERO));
+    tokio::time::sleep(Duration::from_millis(1)).await;
+    for message in 10..12 {
+        sender.send(message).unwrap();
+    }
+    drop(sender);
+
+    assert_eq!(
+        subscriber.await.unwrap(),
+        Received {
+            messages: vec![6, 7, 8, 9, 10, 11],
+            missed: 6,
+        }
+    );
+}
+
+#[cfg(feature = "solutions")]
+#[tokio::test(start_paused = true)]
+async fn receives_like_the_solution() {
+    for millis in [1, 15, 25, 60] {
+        let work = Duration::from_millis(millis);
+        for (policy, expected) in [
+            (Policy::Skip, solutions::Policy::Skip),
+            (Policy::Resubscribe, solutions::Policy::Resubscribe),
+        ] {
+            let received = slow_subscriber(policy, work).await;
+            let (sender, receiver) = broadcast::channel(CAPACITY);
+            let subscriber = tokio::spawn(expected.subscribe(receiver, work));
+            publish(sender, MESSAGES, Duration::from_millis(10)).await;
+            let expected = subscriber.await.unwrap();
+            assert_eq!(
+                (received.messages, received.missed),
+                (expected.messages, expected.missed),
+                "{policy:?} with {millis} ms of work"
+            );
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Part 59, with every exercise implemented. Only built with `--features solutions`.
+
+use std::time::Duration;
+
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use super::Received;
+
+/// What a subscriber does when it falls so far behind that the channel drops messages before
+/// the subscriber receives them
+#[derive(Debug, Clone, Copy)]
+pub enum Policy {
+    /// Skips the dropped messages and carries on with the oldest one the channel still has
+    Skip,
+    /// Skips every message the channel has, and carries on with the next one sent
+    Resubscribe,
+}
+
+impl Policy {
+    /// Receives messages until the channel closes, spending `work` on every one of them
+    pub async fn subscribe(self, receiver: broadcast::Receiver<u64>, work: Duration) -> Received {
+        match self {
+            Policy::Skip => skip_lagged(receiver, work).await,
+            Policy::Resubscribe => resubscribe_on_lag(receiver, work).await,
+        }
+    }
+}
+
+/// Receives messages until the channel closes, spending `work` on every one of them.
+/// When it falls behind, it carries on with the oldest message the channel still has.
+pub async fn skip_lagged(mut receiver: broadcast::Receiver<u64>, work: Duration) -> Received {
+    let mut received = Received::default();
+    loop {
+        match receiver.recv().await {
+            Ok(message) => {
+                tokio::time::sleep(work).await;
+                received.messages.push(message);
+            }
+            // The next `recv` gives the oldest message the channel still has
+            Err(RecvError::Lagged(missed)) => received.missed += missed,
+            Err(RecvError::Closed) => return received,
+        }
+    }
+}
+
+/// Receives messages until the channel closes, spending `work` on every one of them.
+/// When it falls behind, it skips every message the channel still has by resubscribing,
+/// and carries on with the next one sent.
+pub async fn resubscribe_on_lag(
+    mut receiver: broadcast::Receiver<u64>,
+    work: Duration,
+) -> Received {
+    let mut received = Received::default();
+    loop {
+        match receiver.recv().await {
+            Ok(message) => {
+                tokio::time::sleep(work).await;
+                received.messages.push(message);
+            }
+            Err(RecvError::Lagged(missed)) => {
+                // Resubscribing skips what's left in the channel too
+                received.missed += missed + receiver.len() as u64;
+                receiver = receiver.resubscribe();
+            }
+            Err(RecvError::Closed) => return received,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::{
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::Duration,
+};
+
+use part_5::{ComputationResult, Data};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+pub fn rayon_parallel_calculate(data: Vec<Data>) -> Vec<ComputationResult> {
+    // Use the rayon prelude and use the same calculate-method as before
+    use part_5::calculate;
+    use rayon::prelude::*;
+    todo!()
+}
+
+/// Sends `data` from `producers` threads, like the producers in part 3, which each wait `interval`
+/// before every datum they send, as if it came in over the network. The channel closes once
+/// they're all done, so how much data there is isn't known until then.
+pub fn produce(data: Vec<Data>, producers: usize, interval: Duration) -> Receiver<Data> {
+    let (sender, receiver) = mpsc::channel();
+    let chunk_len = data.len().div_ceil(producers).max(1);
+    for chunk in data.chunks(chunk_len) {
+        let (sender, chunk) = (sender.clone(), chunk.to_vec());
+        thread::spawn(move || {
+            for datum in chunk {
+                thread::sleep(interval);
+                // Nobody's receiving anymore, so there's no point in sending the rest
+                if sender.send(datum).is_err() {
+                    return;
+                }
+            }
+        });
+    }
+    receiver
+}
+
+/// Runs `f` on everything `receiver` gets, in parallel with rayon, while it's still coming in,
+/// until the channel closes. The results are in no particular order.
+pub fn par_bridge_process<T: Send, R: Send>(
+    receiver: Receiver<T>,
+    f: impl Fn(T) -> R + Sync + Send,
+) -> Vec<R> {
+    use rayon::prelude::*;
+    todo!()
+}
+
+/// Part 5's `calculate` on a stream of data, see `par_bridge_process`
+pub fn rayon_stream_calculate(receiver: Receiver<Data>) -> Vec<ComputationResult> {
+    par_bridge_process(receiver, part_5::calculate)
+}
@@ -1,42 +1,148 @@
-use part_5::{ComputationResult, Data};
+use std::time::Duration;
 #[cfg(test)]
-use serial_test::serial;
+use std::{sync::mpsc, thread};
 
-fn main() {}
+use part_5::Data;
+#[cfg(test)]
+use part_6::{par_bridge_process, rayon_parallel_calculate};
+use part_6::{produce, rayon_stream_calculate};
+#[cfg(test)]
+use serial_test::serial;
 
-fn rayon_parallel_calculate(data: Vec<Data>) -> Vec<ComputationResult> {
-    // Use the rayon prelude and use the same calculate-method as before
-    use part_5::calculate;
-    use rayon::prelude::*;
-    todo!()
+/// Run with `cargo run -p part-6` to calculate data while it's still coming in
+fn main() {
+    let data: Vec<Data> = fixtures::data(fixtures::Dataset::Small);
+    let results = common::timed("Stream calculate", || {
+        rayon_stream_calculate(produce(data, 2, Duration::from_millis(200)))
+    });
+    println!("Calculated {} results", results.len());
 }
 
 #[cfg(test)]
 fn run_test(data_set: Vec<Data>) {
-    use common::time_elapsed;
+    use common::bench::{assert_faster, measure};
 
-    let (parallel_part_5, parallel_part_5_elapsed) = time_elapsed("Hand-implemented", || {
+    assert_eq!(
+        part_5::parallel_calculate(data_set.clone()),
+        rayon_parallel_calculate(data_set.clone())
+    );
+    // Every calculation takes half a second, so only a few runs
+    let part_5 = measure("Hand-implemented", 3, || {
         part_5::parallel_calculate(data_set.clone())
     });
-
-    let (parallel_rayon, parallel_rayon_elapsed) = time_elapsed("Rayon-implementation", || {
-        rayon_parallel_calculate(data_set)
+    let rayon = measure("Rayon-implementation", 3, || {
+        rayon_parallel_calculate(data_set.clone())
     });
 
-    assert_eq!(parallel_part_5, parallel_rayon);
-    assert!(parallel_part_5_elapsed > parallel_rayon_elapsed);
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    assert_faster(&rayon, &part_5);
 }
 
 #[test]
 #[serial]
 fn small_dataset() {
-    let data = vec![Data(1), Data(2), Data(3)];
-    run_test(data);
+    run_test(fixtures::data(fixtures::Dataset::Small));
 }
 
 #[test]
 #[serial]
 fn large_dataset() {
-    let data = (0..100).map(Data).collect();
-    run_test(data);
+    run_test(fixtures::data(fixtures::Dataset::Large));
+}
+
+#[test]
+#[serial]
+fn rayon_parallel_calculate_is_deterministic() {
+    let mut rng = common::rng::for_test();
+    let data: Vec<Data> = common::datagen::numbers_below(16, 1000, rng.seed())
+        .into_iter()
+        .map(Data)
+        .collect();
+    common::stress::Stress::new(rng.seed()).check(
+        &data,
+        part_5::serial_calculate,
+        rayon_parallel_calculate,
+    );
+}
+
+#[test]
+#[serial]
+fn stream_is_processed_completely() {
+    let data: Vec<Data> = (0..1000).map(Data).collect();
+    let mut results = par_bridge_process(produce(data, 4, Duration::ZERO), |datum| datum.0 * 2);
+    // Whichever thread was done first went first
+    results.sort();
+    assert_eq!(results, (0..1000).map(|x| x * 2).collect::<Vec<_>>());
+
+    let data: Vec<Data> = fixtures::data(fixtures::Dataset::Small);
+    let mut expected: Vec<u64> = data.iter().map(|datum| datum.0 * 2).collect();
+    let mut results: Vec<u64> = rayon_stream_calculate(produce(data, 3, Duration::from_millis(10)))
+        .into_iter()
+        .map(|result| result.0)
+        .collect();
+    results.sort();
+    expected.sort();
+    assert_eq!(results, expected);
+}
+
+#[test]
+#[serial]
+fn processing_overlaps_with_production() {
+    use std::{sync::Mutex, time::Instant};
+
+    let (sender, receiver) = mpsc::channel();
+    let producer = thread::spawn(move || {
+        for i in 0..4 {
+            thread::sleep(Duration::from_millis(50));
+            sender.send(Data(i)).unwrap();
+        }
+        Instant::now()
+    });
+    let started = Mutex::new(Vec::new());
+    let results = par_bridge_process(receiver, |datum| {
+        started.lock().unwrap().push(Instant::now());
+        thread::sleep(Duration::from_millis(20));
+        datum.0
+    });
+    let produced = producer.join().unwrap();
+
+    assert_eq!(results.len(), 4);
+    let first = started.into_inner().unwrap().into_iter().min().unwrap();
+    assert!(
+        first < produced,
+        "Nothing was processed until everything had been produced"
+    );
+}
+
+#[cfg(feature = "solutions")]
+#[test]
+fn calculates_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let data: Vec<Data> = common::datagen::numbers_below(4, 1000, rng.seed())
+        .into_iter()
+        .map(Data)
+        .collect();
+    assert_eq!(
+        rayon_parallel_calculate(data.clone()),
+        part_6::solutions::rayon_parallel_calculate(data)
+    );
+}
+
+#[cfg(feature = "solutions")]
+#[test]
+fn streams_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let data: Vec<u64> = common::datagen::numbers_below(100, 1000, rng.seed());
+    let stream = |data: &[u64]| {
+        let (sender, receiver) = mpsc::channel();
+        data.iter().for_each(|&x| sender.send(x).unwrap());
+        receiver
+    };
+    let mut results = par_bridge_process(stream(&data), |x| x + 1);
+    let mut expected = part_6::solutions::par_bridge_process(stream(&data), |x| x + 1);
+    results.sort();
+    expected.sort();
+    assert_eq!(results, expected);
 }
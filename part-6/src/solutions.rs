@@ -0,0 +1,22 @@
+//! Part 6, with every exercise implemented. Only built with `--features solutions`.
+
+use part_5::{ComputationResult, Data};
+
+pub fn rayon_parallel_calculate(data: Vec<Data>) -> Vec<ComputationResult> {
+    // Use the rayon prelude and use the same calculate-method as before
+    use part_5::calculate;
+    use rayon::prelude::*;
+    data.into_par_iter().map(calculate).collect()
+}
+
+/// Runs `f` on everything `receiver` gets, in parallel with rayon, while it's still coming in,
+/// until the channel closes. The results are in no particular order.
+pub fn par_bridge_process<T: Send, R: Send>(
+    receiver: std::sync::mpsc::Receiver<T>,
+    f: impl Fn(T) -> R + Sync + Send,
+) -> Vec<R> {
+    use rayon::prelude::*;
+    // Rayon's workers take turns taking the next item from the iterator, so processing starts
+    // with the first one, and none of them has to know how many there are
+    receiver.into_iter().par_bridge().map(f).collect()
+}
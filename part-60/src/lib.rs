@@ -0,0 +1,108 @@
+use std::{sync::mpsc as std_mpsc, thread, time::Duration};
+
+use tokio::sync::{mpsc, oneshot};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Does the work for a job. Returning `None` simulates the worker failing before it could reply.
+pub type Work = fn(u64) -> Option<u64>;
+
+/// Squares the job, but fails for 0
+pub fn square_unless_zero(job: u64) -> Option<u64> {
+    (job != 0).then_some(job * job)
+}
+
+/// Why a call didn't get a reply
+#[derive(Debug, PartialEq, Eq)]
+pub enum CallError {
+    /// The worker stopped, or dropped the request without replying
+    WorkerGone,
+    /// No reply came in time
+    TimedOut,
+}
+
+/// A job for a worker task, and where to send the reply
+#[derive(Debug)]
+pub struct Request {
+    pub job: u64,
+    pub reply: oneshot::Sender<u64>,
+}
+
+/// Handles requests one at a time, taking `delay` for each, until every client is gone
+pub async fn serve(mut requests: mpsc::Receiver<Request>, delay: Duration, work: Work) {
+    while let Some(request) = requests.recv().await {
+        tokio::time::sleep(delay).await;
+        if let Some(result) = work(request.job) {
+            // The caller may have stopped waiting, which is fine
+            _ = request.reply.send(result);
+        }
+    }
+}
+
+/// Sends requests to a worker task, and waits for the replies
+#[derive(Debug, Clone)]
+pub struct Client {
+    requests: mpsc::Sender<Request>,
+}
+
+impl Client {
+    /// Spawns a worker task running `serve`, and returns a client for it
+    pub fn spawn(delay: Duration, work: Work) -> Self {
+        let (requests, receiver) = mpsc::channel(16);
+        tokio::spawn(serve(receiver, delay, work));
+        Self { requests }
+    }
+
+    /// Sends `job` to the worker, and waits for its reply
+    pub async fn call(&self, job: u64) -> Result<u64, CallError> {
+        todo!()
+    }
+
+    /// Like `call`, but gives up if there's no reply within `timeout`
+    pub async fn call_timeout(&self, job: u64, timeout: Duration) -> Result<u64, CallError> {
+        todo!()
+    }
+}
+
+/// A job for a worker thread, and where to send the reply
+#[derive(Debug)]
+pub struct BlockingRequest {
+    pub job: u64,
+    pub reply: std_mpsc::SyncSender<u64>,
+}
+
+/// Handles requests one at a time, taking `delay` for each, until every client is gone
+pub fn serve_blocking(requests: std_mpsc::Receiver<BlockingRequest>, delay: Duration, work: Work) {
+    for request in requests {
+        thread::sleep(delay);
+        if let Some(result) = work(request.job) {
+            _ = request.reply.send(result);
+        }
+    }
+}
+
+/// Sends requests to a worker thread, and waits for the replies
+#[derive(Debug, Clone)]
+pub struct BlockingClient {
+    requests: std_mpsc::Sender<BlockingRequest>,
+}
+
+impl BlockingClient {
+    /// Spawns a worker thread running `serve_blocking`, and returns a client for it
+    pub fn spawn(delay: Duration, work: Work) -> Self {
+        let (requests, receiver) = std_mpsc::channel();
+        thread::spawn(move || serve_blocking(receiver, delay, work));
+        Self { requests }
+    }
+
+    /// Sends `job` to the worker, and waits for its reply
+    pub fn call(&self, job: u64) -> Result<u64, CallError> {
+        todo!()
+    }
+
+    /// Like `call`, but gives up if there's no reply within `timeout`
+    pub fn call_timeout(&self, job: u64, timeout: Duration) -> Result<u64, CallError> {
+        todo!()
+    }
+}
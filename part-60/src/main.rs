@@ -0,0 +1,136 @@
+use std::time::Duration;
+
+use part_60::{square_unless_zero, BlockingClient, Client};
+
+#[tokio::main]
+async fn main() {
+    let client = Client::spawn(Duration::from_millis(100), square_unless_zero);
+    println!("Async calls:");
+    for job in [3, 0, 7] {
+        println!("{job} gave {:?}", client.call(job).await);
+    }
+    println!(
+        "4 with a timeout gave {:?}",
+        client.call_timeout(4, Duration::from_millis(50)).await
+    );
+
+    // Blocking calls would keep the runtime's thread from doing anything else
+    tokio::task::spawn_blocking(|| {
+        let client = BlockingClient::spawn(Duration::from_millis(100), square_unless_zero);
+        println!("Blocking calls:");
+        for job in [3, 0, 7] {
+            println!("{job} gave {:?}", client.call(job));
+        }
+        println!(
+            "4 with a timeout gave {:?}",
+            client.call_timeout(4, Duration::from_millis(50))
+        );
+    })
+    .await
+    .unwrap();
+}
+
+#[cfg(test)]
+const DELAY: Duration = Duration::from_millis(100);
+
+#[tokio::test(start_paused = true)]
+async fn replies_to_calls() {
+    let client = Client::spawn(DELAY, square_unless_zero);
+    assert_eq!(client.call(3).await, Ok(9));
+    assert_eq!(client.call(12).await, Ok(144));
+}
+
+#[tokio::test(start_paused = true)]
+async fn replies_to_concurrent_callers() {
+    let client = Client::spawn(DELAY, square_unless_zero);
+    let calls: Vec<_> = (1..=10)
+        .map(|job| {
+            let client = client.clone();
+            tokio::spawn(async move { client.call(job).await })
+        })
+        .collect();
+    for (job, call) in (1..=10).zip(calls) {
+        // Every caller gets the reply to its own job
+        assert_eq!(call.await.unwrap(), Ok(job * job));
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn fails_when_the_worker_drops_the_request() {
+    use part_60::CallError;
+
+    let client = Client::spawn(DELAY, square_unless_zero);
+    assert_eq!(client.call(0).await, Err(CallError::WorkerGone));
+    // The worker is still there for the next call
+    assert_eq!(client.call(2).await, Ok(4));
+}
+
+#[tokio::test(start_paused = true)]
+async fn fails_when_the_worker_is_gone() {
+    use part_60::CallError;
+
+    let client = Client::spawn(DELAY, |_| panic!("Oh no!"));
+    assert_eq!(client.call(1).await, Err(CallError::WorkerGone));
+    assert_eq!(client.call(2).await, Err(CallError::WorkerGone));
+}
+
+#[tokio::test(start_paused = true)]
+async fn times_out() {
+    use part_60::CallError;
+
+    let client = Client::spawn(DELAY, square_unless_zero);
+    let start = tokio::time::Instant::now();
+    assert_eq!(
+        client.call_timeout(5, DELAY / 2).await,
+        Err(CallError::TimedOut)
+    );
+    assert_eq!(start.elapsed(), DELAY / 2);
+    assert_eq!(client.call_timeout(5, DELAY * 2).await, Ok(25));
+}
+
+#[test]
+fn blocking_replies_to_calls() {
+    let client = BlockingClient::spawn(Duration::ZERO, square_unless_zero);
+    assert_eq!(client.call(3), Ok(9));
+    assert_eq!(client.call(12), Ok(144));
+}
+
+#[test]
+fn blocking_replies_to_concurrent_callers() {
+    let client = BlockingClient::spawn(Duration::ZERO, square_unless_zero);
+    std::thread::scope(|s| {
+        for job in 1..=10 {
+            let client = client.clone();
+            s.spawn(move || assert_eq!(client.call(job), Ok(job * job)));
+        }
+    });
+}
+
+#[test]
+fn blocking_fails_when_the_worker_drops_the_request() {
+    use part_60::CallError;
+
+    let client = BlockingClient::spawn(Duration::ZERO, square_unless_zero);
+    assert_eq!(client.call(0), Err(CallError::WorkerGone));
+    assert_eq!(client.call(2), Ok(4));
+}
+
+#[test]
+fn blocking_fails_when_the_worker_is_gone() {
+    use part_60::CallError;
+
+    let client = BlockingClient::spawn(Duration::ZERO, |_| panic!("Oh no!"));
+    assert_eq!(client.call(1), Err(CallError::WorkerGone));
+    assert_eq!(client.call(2), Err(CallError::WorkerGone));
+}
+
+#[test]
+fn blocking_times_out() {
+    use part_60::CallError;
+
+    let client = BlockingClient::spawn(DELAY, square_unless_zero);
+    let start = std::time::Instant::now();
+    assert_eq!(client.call_timeout(5, DELAY / 2), Err(CallError::TimedOut));
+    assert!(start.elapsed() >= DELAY / 2);
+    assert_eq!(client.call_timeout(5, DELAY * 5), Ok(25));
+}
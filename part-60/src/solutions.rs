@@ -0,0 +1,80 @@
+//! Part 60, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{sync::mpsc as std_mpsc, thread, time::Duration};
+
+use tokio::sync::{mpsc, oneshot};
+
+use super::{serve, serve_blocking, BlockingRequest, CallError, Request, Work};
+
+/// Sends requests to a worker task, and waits for the replies
+#[derive(Debug, Clone)]
+pub struct Client {
+    requests: mpsc::Sender<Request>,
+}
+
+impl Client {
+    /// Spawns a worker task running `serve`, and returns a client for it
+    pub fn spawn(delay: Duration, work: Work) -> Self {
+        let (requests, receiver) = mpsc::channel(16);
+        tokio::spawn(serve(receiver, delay, work));
+        Self { requests }
+    }
+
+    /// Sends `job` to the worker, and waits for its reply
+    pub async fn call(&self, job: u64) -> Result<u64, CallError> {
+        let (reply, response) = oneshot::channel();
+        self.requests
+            .send(Request { job, reply })
+            .await
+            .map_err(|_| CallError::WorkerGone)?;
+        // Fails if the worker drops `reply` without sending anything
+        response.await.map_err(|_| CallError::WorkerGone)
+    }
+
+    /// Like `call`, but gives up if there's no reply within `timeout`
+    pub async fn call_timeout(&self, job: u64, timeout: Duration) -> Result<u64, CallError> {
+        tokio::time::timeout(timeout, self.call(job))
+            .await
+            .map_err(|_| CallError::TimedOut)?
+    }
+}
+
+/// Sends requests to a worker thread, and waits for the replies
+#[derive(Debug, Clone)]
+pub struct BlockingClient {
+    requests: std_mpsc::Sender<BlockingRequest>,
+}
+
+impl BlockingClient {
+    /// Spawns a worker thread running `serve_blocking`, and returns a client for it
+    pub fn spawn(delay: Duration, work: Work) -> Self {
+        let (requests, receiver) = std_mpsc::channel();
+        thread::spawn(move || serve_blocking(receiver, delay, work));
+        Self { requests }
+    }
+
+    /// Sends `job` to the worker, and waits for its reply
+    pub fn call(&self, job: u64) -> Result<u64, CallError> {
+        let response = self.send(job)?;
+        response.recv().map_err(|_| CallError::WorkerGone)
+    }
+
+    /// Like `call`, but gives up if there's no reply within `timeout`
+    pub fn call_timeout(&self, job: u64, timeout: Duration) -> Result<u64, CallError> {
+        let response = self.send(job)?;
+        response.recv_timeout(timeout).map_err(|error| match error {
+            std_mpsc::RecvTimeoutError::Timeout => CallError::TimedOut,
+            std_mpsc::RecvTimeoutError::Disconnected => CallError::WorkerGone,
+        })
+    }
+
+    /// Sends `job` to the worker, returning where the reply will arrive
+    fn send(&self, job: u64) -> Result<std_mpsc::Receiver<u64>, CallError> {
+        // Room for the one reply, so the worker never waits for the caller to receive it
+        let (reply, response) = std_mpsc::sync_channel(1);
+        self.requests
+            .send(BlockingRequest { job, reply })
+            .map_err(|_| CallError::WorkerGone)?;
+        Ok(response)
+    }
+}
@@ -0,0 +1,60 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-60 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{fmt::Debug, time::Duration};
+
+use part_60::{solutions, square_unless_zero, BlockingClient, Client};
+
+/// A call's reply, or its error by name, to compare errors with the solution's
+fn outcome<E: Debug>(result: Result<u64, E>) -> Result<u64, String> {
+    result.map_err(|error| format!("{error:?}"))
+}
+
+#[tokio::test(start_paused = true)]
+async fn calls_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let client = Client::spawn(Duration::from_millis(10), square_unless_zero);
+    let expected = solutions::Client::spawn(Duration::from_millis(10), square_unless_zero);
+    for job in common::datagen::numbers_below(20, 5, rng.seed()) {
+        assert_eq!(
+            outcome(client.call(job).await),
+            outcome(expected.call(job).await),
+            "Job {job}"
+        );
+        for millis in [5, 50] {
+            let timeout = Duration::from_millis(millis);
+            assert_eq!(
+                outcome(client.call_timeout(job, timeout).await),
+                outcome(expected.call_timeout(job, timeout).await),
+                "Job {job} with a timeout of {millis} ms"
+            );
+        }
+    }
+}
+
+#[test]
+fn calls_blocking_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let client = BlockingClient::spawn(Duration::from_millis(2), square_unless_zero);
+    let expected = solutions::BlockingClient::spawn(Duration::from_millis(2), square_unless_zero);
+    for job in common::datagen::numbers_below(10, 5, rng.seed()) {
+        assert_eq!(
+            outcome(client.call(job)),
+            outcome(expected.call(job)),
+            "Job {job}"
+        );
+        let timeout = Duration::from_secs(1);
+        assert_eq!(
+            outcome(client.call_timeout(job, timeout)),
+            outcome(expected.call_timeout(job, timeout)),
+            "Job {job}"
+        );
+    }
+    // Far too short for any reply
+    let timeout = Duration::from_micros(1);
+    assert_eq!(
+        outcome(client.call_timeout(1, timeout)),
+        outcome(expected.call_timeout(1, timeout))
+    );
+}
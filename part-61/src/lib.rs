@@ -0,0 +1,57 @@
+use std::{collections::HashMap, time::Duration};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How long it takes to write a line, or fetch a page
+pub const DELAY: Duration = Duration::from_millis(100);
+
+/// Pretends to write `line` somewhere slow, like a file or a socket
+pub async fn slow_write(line: &str) {
+    tokio::time::sleep(DELAY).await;
+    std::hint::black_box(line);
+}
+
+/// Adds `line` to `log`, and writes it out while holding the lock,
+/// so the lines are written in the same order as they're added.
+///
+/// Holds a `std::sync::MutexGuard` across an `.await`. If another task on the same thread tries to
+/// lock `log` in the meantime, it blocks the thread, and this task never gets to finish.
+/// The returned future isn't `Send` either, so it can't be spawned on a multi-threaded runtime.
+// Clippy knows this is a bad idea too
+#[allow(clippy::await_holding_lock)]
+pub async fn broken_log(log: &std::sync::Mutex<Vec<String>>, line: String) {
+    let mut lines = log.lock().unwrap();
+    slow_write(&line).await;
+    lines.push(line);
+}
+
+/// Does the same as `broken_log`, using a lock that can be held across an `.await`
+pub async fn log(log: &tokio::sync::Mutex<Vec<String>>, line: String) {
+    todo!()
+}
+
+/// Pretends to fetch a page of text from somewhere slow
+pub async fn fetch(page: u64) -> String {
+    tokio::time::sleep(DELAY).await;
+    format!("page {page} of {}", page % 3)
+}
+
+/// Fetches `page`, and counts every word on it in `counts`.
+///
+/// Holds the lock while fetching, like `broken_log`, even though the counts aren't touched
+/// until the page has been fetched.
+#[allow(clippy::await_holding_lock)]
+pub async fn broken_count_words(counts: &std::sync::Mutex<HashMap<String, u64>>, page: u64) {
+    let mut counts = counts.lock().unwrap();
+    let text = fetch(page).await;
+    for word in text.split_whitespace() {
+        *counts.entry(word.to_string()).or_default() += 1;
+    }
+}
+
+/// Does the same as `broken_count_words`, but without holding the lock across an `.await`,
+/// so it doesn't need an async lock
+pub async fn count_words(counts: &std::sync::Mutex<HashMap<String, u64>>, page: u64) {
+    todo!()
+}
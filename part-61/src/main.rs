@@ -0,0 +1,166 @@
+use std::{collections::HashMap, sync::Arc};
+
+use common::{bench::Comparison, cli::Cli};
+use part_61::{count_words, log};
+
+const TASKS: u64 = 100;
+const INCREMENTS: u64 = 1000;
+
+/// Run with `cargo run --release -p part-61` to compare the locks
+fn main() {
+    // `--strategy` picks which of the mutexes `Comparison` measures
+    Cli::new("Holds std and tokio mutexes across awaits, and compares them")
+        .strategy()
+        .parse();
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    runtime.block_on(async {
+        let lines = tokio::sync::Mutex::new(Vec::new());
+        tokio::join!(
+            log(&lines, "first".to_string()),
+            log(&lines, "second".to_string())
+        );
+        println!("Logged {:?}", lines.into_inner());
+
+        let counts = std::sync::Mutex::new(HashMap::new());
+        tokio::join!(count_words(&counts, 1), count_words(&counts, 2));
+        println!("Counted {:?}", counts.into_inner().unwrap());
+    });
+
+    println!("{TASKS} tasks incrementing a counter {INCREMENTS} times each");
+    let mut comparison = Comparison::new(10);
+    comparison
+        .bench("std::sync::Mutex", || {
+            runtime.block_on(increment_std(Arc::default()))
+        })
+        .bench("tokio::sync::Mutex", || {
+            runtime.block_on(increment_tokio(Arc::default()))
+        });
+    comparison.print();
+}
+
+/// Lets `TASKS` tasks increment `counter` `INCREMENTS` times each, never awaiting while locked
+async fn increment_std(counter: Arc<std::sync::Mutex<u64>>) -> u64 {
+    let mut tasks = tokio::task::JoinSet::new();
+    for _ in 0..TASKS {
+        let counter = counter.clone();
+        tasks.spawn(async move {
+            for _ in 0..INCREMENTS {
+                *counter.lock().unwrap() += 1;
+                tokio::task::yield_now().await;
+            }
+        });
+    }
+    while tasks.join_next().await.is_some() {}
+    let count = *counter.lock().unwrap();
+    count
+}
+
+/// Does the same as `increment_std`, with a `tokio::sync::Mutex`
+async fn increment_tokio(counter: Arc<tokio::sync::Mutex<u64>>) -> u64 {
+    let mut tasks = tokio::task::JoinSet::new();
+    for _ in 0..TASKS {
+        let counter = counter.clone();
+        tasks.spawn(async move {
+            for _ in 0..INCREMENTS {
+                *counter.lock().await += 1;
+                tokio::task::yield_now().await;
+            }
+        });
+    }
+    while tasks.join_next().await.is_some() {}
+    let count = *counter.lock().await;
+    count
+}
+
+/// Runs two calls of `log` or `broken_log` at once on a single thread, giving up after a second
+#[cfg(test)]
+fn log_twice_on_one_thread<M, F>(log: impl Fn(Arc<M>, String) -> F + Send + 'static) -> Option<()>
+where
+    M: Default + Send + Sync + 'static,
+    F: std::future::Future<Output = ()>,
+{
+    common::with_timeout(std::time::Duration::from_secs(1), move || {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        let lines = Arc::new(M::default());
+        runtime.block_on(async {
+            tokio::join!(
+                log(lines.clone(), "first".to_string()),
+                log(lines.clone(), "second".to_string())
+            )
+        });
+    })
+}
+
+#[test]
+fn broken_log_deadlocks() {
+    use part_61::broken_log;
+
+    let result = log_twice_on_one_thread(
+        |lines: Arc<std::sync::Mutex<Vec<String>>>, line| async move { broken_log(&lines, line).await },
+    );
+    assert_eq!(result, None);
+}
+
+#[test]
+fn log_does_not_deadlock() {
+    let result = log_twice_on_one_thread(
+        |lines: Arc<tokio::sync::Mutex<Vec<String>>>, line| async move { log(&lines, line).await },
+    );
+    assert_eq!(result, Some(()));
+}
+
+#[tokio::test(start_paused = true)]
+async fn log_keeps_the_lines_in_order() {
+    let lines = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+    let mut tasks = Vec::new();
+    for i in 0..5 {
+        let lines = lines.clone();
+        // Spawning makes sure the future is `Send`
+        tasks.push(tokio::spawn(async move {
+            log(&lines, format!("line {i}")).await
+        }));
+        // Lets the task lock the log before the next one is spawned
+        tokio::task::yield_now().await;
+    }
+    for task in tasks {
+        task.await.unwrap();
+    }
+
+    let expected: Vec<_> = (0..5).map(|i| format!("line {i}")).collect();
+    assert_eq!(*lines.lock().await, expected);
+}
+
+#[tokio::test(start_paused = true)]
+async fn counts_words() {
+    let counts = std::sync::Mutex::new(HashMap::new());
+    count_words(&counts, 4).await;
+    count_words(&counts, 7).await;
+
+    let counts = counts.into_inner().unwrap();
+    let expected: HashMap<_, _> = [("page", 2), ("4", 1), ("7", 1), ("of", 2), ("1", 2)]
+        .map(|(word, count)| (word.to_string(), count))
+        .into();
+    assert_eq!(counts, expected);
+}
+
+#[tokio::test(start_paused = true)]
+async fn counts_words_while_others_fetch() {
+    let counts = Arc::new(std::sync::Mutex::new(HashMap::new()));
+    let start = tokio::time::Instant::now();
+    let tasks: Vec<_> = (0..10)
+        .map(|page| {
+            let counts = counts.clone();
+            tokio::spawn(async move { count_words(&counts, page).await })
+        })
+        .collect();
+    for task in tasks {
+        task.await.unwrap();
+    }
+
+    // The pages were fetched at the same time
+    assert_eq!(start.elapsed(), part_61::DELAY);
+    assert_eq!(counts.lock().unwrap()["page"], 10);
+}
@@ -0,0 +1,38 @@
+//! Part 61, with every exercise implemented. Only built with `--features solutions`.
+
+use std::collections::HashMap;
+
+use super::{fetch, slow_write};
+
+/// Adds `line` to `log`, and writes it out while holding the lock,
+/// so the lines are written in the same order as they're added.
+///
+/// Holds a `std::sync::MutexGuard` across an `.await`. If another task on the same thread tries to
+/// lock `log` in the meantime, it blocks the thread, and this task never gets to finish.
+/// The returned future isn't `Send` either, so it can't be spawned on a multi-threaded runtime.
+// Clippy knows this is a bad idea too
+#[allow(clippy::await_holding_lock)]
+pub async fn broken_log(log: &std::sync::Mutex<Vec<String>>, line: String) {
+    let mut lines = log.lock().unwrap();
+    slow_write(&line).await;
+    lines.push(line);
+}
+
+/// Does the same as `broken_log`, using a lock that can be held across an `.await`
+pub async fn log(log: &tokio::sync::Mutex<Vec<String>>, line: String) {
+    // Waiting for the lock yields to other tasks, instead of blocking the thread
+    let mut lines = log.lock().await;
+    slow_write(&line).await;
+    lines.push(line);
+}
+
+/// Does the same as `broken_count_words`, but without holding the lock across an `.await`,
+/// so it doesn't need an async lock
+pub async fn count_words(counts: &std::sync::Mutex<HashMap<String, u64>>, page: u64) {
+    let text = fetch(page).await;
+    // Only locked once there's nothing left to wait for, and unlocked before returning
+    let mut counts = counts.lock().unwrap();
+    for word in text.split_whitespace() {
+        *counts.entry(word.to_string()).or_default() += 1;
+    }
+}
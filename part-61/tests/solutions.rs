@@ -0,0 +1,51 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-61 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{collections::HashMap, sync::Arc};
+
+use part_61::{count_words, log, solutions};
+use tokio::task::JoinSet;
+
+#[tokio::test(start_paused = true)]
+async fn logs_like_the_solution() {
+    let (lines, expected) = (
+        Arc::new(tokio::sync::Mutex::new(Vec::new())),
+        Arc::new(tokio::sync::Mutex::new(Vec::new())),
+    );
+    let mut tasks = JoinSet::new();
+    for task in 0..5 {
+        let (lines, expected) = (Arc::clone(&lines), Arc::clone(&expected));
+        tasks.spawn(async move {
+            for line in 0..3 {
+                log(&lines, format!("task {task}, line {line}")).await;
+                solutions::log(&expected, format!("task {task}, line {line}")).await;
+            }
+        });
+    }
+    while tasks.join_next().await.is_some() {}
+    let (mut lines, mut expected) = (lines.lock().await.clone(), expected.lock().await.clone());
+    // The tasks take turns with the lock in whatever order they get to it
+    lines.sort();
+    expected.sort();
+    assert_eq!(lines, expected);
+}
+
+#[tokio::test(start_paused = true)]
+async fn counts_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (counts, expected) = (
+        Arc::new(std::sync::Mutex::new(HashMap::new())),
+        Arc::new(std::sync::Mutex::new(HashMap::new())),
+    );
+    let mut tasks = JoinSet::new();
+    for page in common::datagen::numbers_below(20, 10, rng.seed()) {
+        let (counts, expected) = (Arc::clone(&counts), Arc::clone(&expected));
+        tasks.spawn(async move {
+            count_words(&counts, page).await;
+            solutions::count_words(&expected, page).await;
+        });
+    }
+    while tasks.join_next().await.is_some() {}
+    assert_eq!(*counts.lock().unwrap(), *expected.lock().unwrap());
+}
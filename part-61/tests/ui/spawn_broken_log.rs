@@ -0,0 +1,12 @@
+// A `std::sync::MutexGuard` must be unlocked on the thread that locked it, so it isn't `Send`.
+// A future holding one across an `.await` isn't `Send` either, since a multi-threaded runtime may
+// resume it on another thread.
+use std::sync::{Arc, Mutex};
+
+use part_61::broken_log;
+
+#[tokio::main]
+async fn main() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    tokio::spawn(async move { broken_log(&log, "hello".to_string()).await });
+}
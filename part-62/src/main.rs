@@ -0,0 +1,134 @@
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use part_5::{calculate, ComputationResult, Data};
+
+#[cfg(all(test, feature = "solutions"))]
+mod solutions;
+
+/// A single worker thread makes it easy to see when it's blocked
+#[tokio::main(flavor = "multi_thread", worker_threads = 1)]
+async fn main() {
+    common::cli::Cli::new("Calculates on the runtime, with spawn_blocking and with block_in_place")
+        .compute_ms(500)
+        .parse();
+    let (result, ticks) = ticks_during(blocking_handler(Data(1))).await;
+    println!("Calling calculate directly gave {result:?}, while the ticker ticked {ticks} times");
+
+    let (result, ticks) = ticks_during(spawn_blocking_handler(Data(2))).await;
+    println!("spawn_blocking gave {result:?}, while the ticker ticked {ticks} times");
+
+    let (result, ticks) = ticks_during(block_in_place_handler(Data(3))).await;
+    println!("block_in_place gave {result:?}, while the ticker ticked {ticks} times");
+}
+
+/// Spawns a task running `work`, while another task ticks every 10 ms. Returns the result,
+/// and how many times the other task ticked.
+async fn ticks_during<F>(work: F) -> (F::Output, u64)
+where
+    F: Future + Send + 'static,
+    F::Output: Send,
+{
+    let ticks = Arc::new(AtomicU64::new(0));
+    let ticker = tokio::spawn({
+        let ticks = ticks.clone();
+        async move {
+            loop {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                ticks.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    });
+    // Lets the ticker get going
+    tokio::task::yield_now().await;
+
+    let result = tokio::spawn(work).await.expect("The work panicked");
+    ticker.abort();
+    (result, ticks.load(Ordering::Relaxed))
+}
+
+/// Handles a request by calling `calculate`, which blocks the thread the task is running on
+/// for the whole calculation, so no other task can run on it in the meantime
+async fn blocking_handler(datum: Data) -> ComputationResult {
+    calculate(datum)
+}
+
+/// Handles a request by running `calculate` on tokio's pool of threads for blocking work,
+/// and awaiting the result
+async fn spawn_blocking_handler(datum: Data) -> ComputationResult {
+    todo!()
+}
+
+/// Handles a request by running `calculate` on the current thread,
+/// after telling tokio to move the other tasks on it to another thread.
+/// Only works on a multi-threaded runtime.
+async fn block_in_place_handler(datum: Data) -> ComputationResult {
+    todo!()
+}
+
+// `calculate` takes 500 ms when used from another crate. Ticking every 10 ms,
+// the ticker should tick about 50 times while it runs, unless it's kept from running.
+
+#[tokio::test]
+async fn blocking_handler_stalls_the_runtime() {
+    let (result, ticks) = ticks_during(blocking_handler(Data(1))).await;
+    assert_eq!(result, ComputationResult(2));
+    assert_eq!(ticks, 0);
+}
+
+#[tokio::test]
+async fn spawn_blocking_keeps_other_tasks_going() {
+    let (result, ticks) = ticks_during(spawn_blocking_handler(Data(2))).await;
+    assert_eq!(result, ComputationResult(4));
+    assert!(ticks > 20, "Only ticked {ticks} times");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn block_in_place_keeps_other_tasks_going() {
+    let (result, ticks) = ticks_during(block_in_place_handler(Data(3))).await;
+    assert_eq!(result, ComputationResult(6));
+    assert!(ticks > 20, "Only ticked {ticks} times");
+}
+
+#[tokio::test]
+#[should_panic]
+async fn block_in_place_needs_a_multi_threaded_runtime() {
+    block_in_place_handler(Data(4)).await;
+}
+
+#[tokio::test]
+async fn spawn_blocking_handles_requests_at_once() {
+    let start = tokio::time::Instant::now();
+    let handlers: Vec<_> = (1..=5)
+        .map(|i| tokio::spawn(spawn_blocking_handler(Data(i))))
+        .collect();
+    for (i, handler) in (1..=5).zip(handlers) {
+        assert_eq!(handler.await.unwrap(), ComputationResult(i * 2));
+    }
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    // Every calculation ran on its own blocking thread
+    assert!(start.elapsed() < Duration::from_millis(1000));
+}
+
+#[cfg(feature = "solutions")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn handles_like_the_solution() {
+    // Every calculation takes half a second, so a couple of them are enough
+    let (spawned, expected_spawned) = tokio::join!(
+        spawn_blocking_handler(Data(62)),
+        solutions::spawn_blocking_handler(Data(62))
+    );
+    assert_eq!(spawned, expected_spawned);
+    assert_eq!(
+        block_in_place_handler(Data(63)).await,
+        solutions::block_in_place_handler(Data(63)).await
+    );
+}
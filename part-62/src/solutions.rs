@@ -0,0 +1,21 @@
+//! Part 62, with every exercise implemented. Only built with `--features solutions`.
+
+use part_5::{calculate, ComputationResult, Data};
+
+/// Handles a request by running `calculate` on tokio's pool of threads for blocking work,
+/// and awaiting the result
+pub async fn spawn_blocking_handler(datum: Data) -> ComputationResult {
+    tokio::task::spawn_blocking(move || calculate(datum))
+        .await
+        .expect("The calculation panicked")
+}
+
+/// Handles a request by running `calculate` on the current thread,
+/// after telling tokio to move the other tasks on it to another thread.
+/// Only works on a multi-threaded runtime.
+pub async fn block_in_place_handler(datum: Data) -> ComputationResult {
+    tokio::task::block_in_place(|| calculate(datum))
+}
+
+// `calculate` takes 500 ms when used from another crate. Ticking every 10 ms,
+// the ticker should tick about 50 times while it runs, unless it's kept from running.
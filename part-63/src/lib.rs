@@ -0,0 +1,99 @@
+use std::{sync::Mutex, time::Duration};
+
+use common::chaos::{Chaos, Fault};
+use tokio::time::Instant;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How long the service takes to answer when all is well
+pub const LATENCY: Duration = Duration::from_millis(10);
+
+/// Why a call to the service failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceError {
+    /// The service answered with an error
+    Unavailable,
+    /// The service didn't answer in time
+    TimedOut,
+}
+
+/// A service which misbehaves whenever its `Chaos` says so
+#[derive(Debug)]
+pub struct FlakyService {
+    chaos: Chaos,
+    calls: Mutex<Vec<Instant>>,
+}
+
+impl FlakyService {
+    pub fn new(chaos: Chaos) -> Self {
+        Self {
+            chaos,
+            calls: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Doubles `request` after `LATENCY`.
+    /// Faults make it answer with an error, take longer, or panic.
+    pub async fn call(&self, request: u64) -> Result<u64, ServiceError> {
+        self.calls.lock().unwrap().push(Instant::now());
+        let fault = self.chaos.next_fault();
+        match fault {
+            Some(Fault::Delay(delay)) => tokio::time::sleep(LATENCY + delay).await,
+            Some(Fault::Panic) => panic!("The service crashed"),
+            _ => tokio::time::sleep(LATENCY).await,
+        }
+
+        if fault == Some(Fault::Error) {
+            Err(ServiceError::Unavailable)
+        } else {
+            Ok(request * 2)
+        }
+    }
+
+    /// When each call started
+    pub fn calls(&self) -> Vec<Instant> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+/// How hard to try before giving up
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// How many calls to make at most, including the first one
+    pub attempts: u32,
+    /// How long to wait for each call
+    pub timeout: Duration,
+    /// How long to wait before the first retry
+    pub base_delay: Duration,
+    /// The longest to wait between two calls
+    pub max_delay: Duration,
+}
+
+/// Calls `service`, but gives up with `ServiceError::TimedOut` if it doesn't answer within `timeout`
+pub async fn call_with_timeout(
+    service: &FlakyService,
+    request: u64,
+    timeout: Duration,
+) -> Result<u64, ServiceError> {
+    todo!()
+}
+
+/// How long to wait before retry number `retry`, counting from 0.
+/// Starts at `base_delay` and doubles for every retry, but never goes beyond `max_delay`.
+/// That is then scaled by `jitter`, which is between 0.0 and 1.0.
+pub fn backoff(policy: &RetryPolicy, retry: u32, jitter: f64) -> Duration {
+    todo!()
+}
+
+/// Calls `service` until it answers, making at most `policy.attempts` calls which each time out after `policy.timeout`.
+/// Waits with `backoff` before each retry, taking a new `jitter` every time.
+/// Returns the last error if every call failed.
+pub async fn call_with_retries(
+    service: &FlakyService,
+    request: u64,
+    policy: &RetryPolicy,
+    mut jitter: impl FnMut() -> f64,
+) -> Result<u64, ServiceError> {
+    todo!()
+}
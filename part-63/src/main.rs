@@ -0,0 +1,207 @@
+use std::time::Duration;
+
+use common::{chaos::Chaos, rng::Rng};
+use part_63::{call_with_retries, call_with_timeout, FlakyService, RetryPolicy};
+
+const POLICY: RetryPolicy = RetryPolicy {
+    attempts: 5,
+    timeout: Duration::from_millis(50),
+    base_delay: Duration::from_millis(100),
+    max_delay: Duration::from_millis(300),
+};
+
+#[tokio::main]
+async fn main() {
+    let service = FlakyService::new(
+        Chaos::new(63)
+            .errors(0.3)
+            .delays(0.2, Duration::from_secs(1)),
+    );
+
+    for request in 0..5 {
+        let result = call_with_timeout(&service, request, POLICY.timeout).await;
+        println!("Calling once with {request}: {result:?}");
+    }
+
+    // A number in 0..1 for spreading out retries
+    let mut rng = Rng::new(63);
+    let mut jitter = move || (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+    for request in 5..10 {
+        let calls = service.calls().len();
+        let start = tokio::time::Instant::now();
+        let result = call_with_retries(&service, request, &POLICY, &mut jitter).await;
+        println!(
+            "Calling with retries with {request}: {result:?} after {} calls and {} ms",
+            service.calls().len() - calls,
+            start.elapsed().as_millis()
+        );
+    }
+}
+
+/// When each call to `service` started, relative to `start`
+#[cfg(test)]
+fn call_offsets(service: &FlakyService, start: tokio::time::Instant) -> Vec<Duration> {
+    service
+        .calls()
+        .into_iter()
+        .map(|call| call - start)
+        .collect()
+}
+
+#[tokio::test(start_paused = true)]
+async fn call_with_timeout_returns_answers() {
+    let service = FlakyService::new(Chaos::scripted([]));
+    assert_eq!(
+        call_with_timeout(&service, 21, POLICY.timeout).await,
+        Ok(42)
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn call_with_timeout_returns_errors() {
+    use common::chaos::Fault;
+    use part_63::{ServiceError, LATENCY};
+
+    let service = FlakyService::new(Chaos::scripted([Some(Fault::Error)]));
+    let start = tokio::time::Instant::now();
+    assert_eq!(
+        call_with_timeout(&service, 21, POLICY.timeout).await,
+        Err(ServiceError::Unavailable)
+    );
+    assert_eq!(start.elapsed(), LATENCY);
+}
+
+#[tokio::test(start_paused = true)]
+async fn call_with_timeout_gives_up() {
+    use common::chaos::Fault;
+    use part_63::ServiceError;
+
+    let service = FlakyService::new(Chaos::scripted([Some(Fault::Delay(Duration::from_secs(
+        60,
+    )))]));
+    let start = tokio::time::Instant::now();
+    assert_eq!(
+        call_with_timeout(&service, 21, POLICY.timeout).await,
+        Err(ServiceError::TimedOut)
+    );
+    assert_eq!(start.elapsed(), POLICY.timeout);
+}
+
+#[test]
+fn backoff_doubles_up_to_the_max() {
+    use part_63::backoff;
+
+    let delays: Vec<_> = (0..5).map(|retry| backoff(&POLICY, retry, 1.0)).collect();
+    assert_eq!(delays, [100, 200, 300, 300, 300].map(Duration::from_millis));
+    // Doesn't overflow after many retries
+    assert_eq!(backoff(&POLICY, 1000, 1.0), POLICY.max_delay);
+}
+
+#[test]
+fn backoff_is_scaled_by_jitter() {
+    use part_63::backoff;
+
+    assert_eq!(backoff(&POLICY, 0, 0.0), Duration::ZERO);
+    assert_eq!(backoff(&POLICY, 1, 0.5), Duration::from_millis(100));
+    assert_eq!(backoff(&POLICY, 4, 0.25), Duration::from_millis(75));
+}
+
+#[tokio::test(start_paused = true)]
+async fn retries_with_backoff() {
+    use common::chaos::Fault;
+    use part_63::LATENCY;
+
+    let service = FlakyService::new(Chaos::scripted([
+        Some(Fault::Error),
+        Some(Fault::Error),
+        Some(Fault::Error),
+        None,
+    ]));
+    let start = tokio::time::Instant::now();
+    let result = call_with_retries(&service, 21, &POLICY, || 1.0).await;
+
+    assert_eq!(result, Ok(42));
+    // Each failed call takes `LATENCY`, followed by 100, 200 and then 300 ms of backoff
+    assert_eq!(
+        call_offsets(&service, start),
+        [0, 110, 320, 630].map(Duration::from_millis)
+    );
+    assert_eq!(start.elapsed(), Duration::from_millis(630) + LATENCY);
+}
+
+#[tokio::test(start_paused = true)]
+async fn retries_after_timeouts() {
+    use common::chaos::Fault;
+
+    let service = FlakyService::new(Chaos::scripted([
+        Some(Fault::Delay(Duration::from_secs(60))),
+        Some(Fault::Delay(Duration::from_secs(60))),
+    ]));
+    let start = tokio::time::Instant::now();
+    let result = call_with_retries(&service, 21, &POLICY, || 1.0).await;
+
+    assert_eq!(result, Ok(42));
+    // Each timed out call takes 50 ms, followed by 100 and then 200 ms of backoff
+    assert_eq!(
+        call_offsets(&service, start),
+        [0, 150, 400].map(Duration::from_millis)
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn takes_new_jitter_for_every_retry() {
+    use common::chaos::Fault;
+
+    let service = FlakyService::new(Chaos::scripted([Some(Fault::Error); 3]));
+    let mut jitters = [0.5, 0.25, 0.0].into_iter();
+    let start = tokio::time::Instant::now();
+    let result = call_with_retries(&service, 21, &POLICY, || jitters.next().unwrap()).await;
+
+    assert_eq!(result, Ok(42));
+    // Backoff of 50, 50 and then 0 ms
+    assert_eq!(
+        call_offsets(&service, start),
+        [0, 60, 120, 130].map(Duration::from_millis)
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn gives_up_after_the_last_attempt() {
+    use common::chaos::Fault;
+    use part_63::ServiceError;
+
+    let service = FlakyService::new(Chaos::scripted(
+        [Some(Fault::Error); 4]
+            .into_iter()
+            .chain([Some(Fault::Delay(Duration::from_secs(60)))]),
+    ));
+    let start = tokio::time::Instant::now();
+    let result = call_with_retries(&service, 21, &POLICY, || 1.0).await;
+
+    // The error from the last call is returned, without waiting after it
+    assert_eq!(result, Err(ServiceError::TimedOut));
+    assert_eq!(
+        call_offsets(&service, start),
+        [0, 110, 320, 630, 940].map(Duration::from_millis)
+    );
+    assert_eq!(start.elapsed(), Duration::from_millis(940) + POLICY.timeout);
+}
+
+#[tokio::test(start_paused = true)]
+async fn survives_random_faults() {
+    let mut rng = common::rng::for_test();
+    let service = FlakyService::new(
+        Chaos::new(rng.seed())
+            .errors(0.3)
+            .delays(0.2, Duration::from_secs(60)),
+    );
+    let policy = RetryPolicy {
+        attempts: 20,
+        ..POLICY
+    };
+    for request in 0..100 {
+        let result = call_with_retries(&service, request, &policy, || 0.5).await;
+        assert_eq!(result, Ok(request * 2));
+    }
+    assert!(service.calls().len() > 100);
+}
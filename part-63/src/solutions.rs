@@ -0,0 +1,47 @@
+//! Part 63, with every exercise implemented. Only built with `--features solutions`.
+
+use std::time::Duration;
+
+use super::{FlakyService, RetryPolicy, ServiceError};
+
+/// Calls `service`, but gives up with `ServiceError::TimedOut` if it doesn't answer within `timeout`
+pub async fn call_with_timeout(
+    service: &FlakyService,
+    request: u64,
+    timeout: Duration,
+) -> Result<u64, ServiceError> {
+    match tokio::time::timeout(timeout, service.call(request)).await {
+        Ok(result) => result,
+        Err(_) => Err(ServiceError::TimedOut),
+    }
+}
+
+/// How long to wait before retry number `retry`, counting from 0.
+/// Starts at `base_delay` and doubles for every retry, but never goes beyond `max_delay`.
+/// That is then scaled by `jitter`, which is between 0.0 and 1.0.
+pub fn backoff(policy: &RetryPolicy, retry: u32, jitter: f64) -> Duration {
+    policy
+        .base_delay
+        .saturating_mul(2u32.saturating_pow(retry))
+        .min(policy.max_delay)
+        .mul_f64(jitter)
+}
+
+/// Calls `service` until it answers, making at most `policy.attempts` calls which each time out after `policy.timeout`.
+/// Waits with `backoff` before each retry, taking a new `jitter` every time.
+/// Returns the last error if every call failed.
+pub async fn call_with_retries(
+    service: &FlakyService,
+    request: u64,
+    policy: &RetryPolicy,
+    mut jitter: impl FnMut() -> f64,
+) -> Result<u64, ServiceError> {
+    for retry in 0.. {
+        match call_with_timeout(service, request, policy.timeout).await {
+            Ok(answer) => return Ok(answer),
+            Err(error) if retry + 1 >= policy.attempts => return Err(error),
+            Err(_) => tokio::time::sleep(backoff(policy, retry, jitter())).await,
+        }
+    }
+    unreachable!("Ran out of retries")
+}
@@ -0,0 +1,103 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-63 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{fmt::Debug, time::Duration};
+
+use common::chaos::Chaos;
+use part_63::{
+    backoff, call_with_retries, call_with_timeout, solutions, FlakyService, RetryPolicy,
+};
+use tokio::time::Instant;
+
+const POLICY: RetryPolicy = RetryPolicy {
+    attempts: 4,
+    timeout: Duration::from_millis(50),
+    base_delay: Duration::from_millis(20),
+    max_delay: Duration::from_millis(100),
+};
+
+/// A service which fails in the same ways every time it's made with the same seed
+fn chaos(seed: u64) -> Chaos {
+    Chaos::new(seed)
+        .errors(0.3)
+        .delays(0.2, Duration::from_millis(100))
+}
+
+/// When each call started, from the first one
+fn times(calls: Vec<Instant>) -> Vec<Duration> {
+    calls.iter().map(|call| *call - calls[0]).collect()
+}
+
+/// A call's answer, or its error by name, to compare errors with the solution's
+fn outcome<E: Debug>(result: Result<u64, E>) -> Result<u64, String> {
+    result.map_err(|error| format!("{error:?}"))
+}
+
+#[test]
+fn backs_off_like_the_solution() {
+    let expected_policy = RetryPolicy {
+        attempts: POLICY.attempts,
+        timeout: POLICY.timeout,
+        base_delay: POLICY.base_delay,
+        max_delay: POLICY.max_delay,
+    };
+    for retry in 0..10 {
+        for jitter in [0.0, 0.25, 1.0] {
+            assert_eq!(
+                backoff(&POLICY, retry, jitter),
+                solutions::backoff(&expected_policy, retry, jitter),
+                "Retry {retry} with a jitter of {jitter}"
+            );
+        }
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn times_out_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let seed = rng.seed();
+    let service = FlakyService::new(chaos(seed));
+    let expected = FlakyService::new(chaos(seed));
+    for request in 0..20 {
+        assert_eq!(
+            outcome(call_with_timeout(&service, request, POLICY.timeout).await),
+            outcome(solutions::call_with_timeout(&expected, request, POLICY.timeout).await),
+            "Request {request}"
+        );
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn retries_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let seed = rng.seed();
+    let service = FlakyService::new(chaos(seed));
+    let expected = FlakyService::new(chaos(seed));
+    let expected_policy = RetryPolicy {
+        attempts: POLICY.attempts,
+        timeout: POLICY.timeout,
+        base_delay: POLICY.base_delay,
+        max_delay: POLICY.max_delay,
+    };
+    for request in 0..10 {
+        let jitters = common::datagen::numbers_below(POLICY.attempts as usize, 100, rng.seed());
+        let (mut ours, mut theirs) = (jitters.iter(), jitters.iter());
+        assert_eq!(
+            outcome(
+                call_with_retries(&service, request, &POLICY, || {
+                    *ours.next().unwrap() as f64 / 100.0
+                })
+                .await
+            ),
+            outcome(
+                solutions::call_with_retries(&expected, request, &expected_policy, || {
+                    *theirs.next().unwrap() as f64 / 100.0
+                })
+                .await
+            ),
+            "Request {request}"
+        );
+    }
+    assert_eq!(times(service.calls()), times(expected.calls()));
+}
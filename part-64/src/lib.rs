@@ -0,0 +1,54 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Barrier,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use common::bench::resident_memory;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How many threads or tasks to run at once
+pub const COUNT: usize = 10_000;
+
+/// The work each thread or task does: sleeping `SLEEPS` times for `PERIOD`
+pub const SLEEPS: u32 = 10;
+pub const PERIOD: Duration = Duration::from_millis(1);
+
+/// What running a number of threads or tasks cost
+#[derive(Debug, Clone, Copy)]
+pub struct Usage {
+    /// How many finished their work
+    pub finished: usize,
+    /// From spawning the first one, until the last one finished
+    pub elapsed: Duration,
+    /// How much more memory the process used once all of them had started
+    pub memory: usize,
+}
+
+impl Usage {
+    pub fn memory_per_unit(&self) -> usize {
+        self.memory / self.finished.max(1)
+    }
+}
+
+/// Memory used by the process, or 0 if that can't be measured here
+pub fn memory() -> usize {
+    resident_memory().unwrap_or(0)
+}
+
+/// Spawns `count` threads which each sleep `sleeps` times for `period`, and waits for all of them.
+/// Measures the memory used once every thread has started, before any of them starts sleeping.
+pub fn run_threads(count: usize, sleeps: u32, period: Duration) -> Usage {
+    todo!()
+}
+
+/// Spawns `count` tasks which each sleep `sleeps` times for `period`, and waits for all of them.
+/// Measures the memory used once every task has started, before any of them starts sleeping.
+pub async fn run_tasks(count: usize, sleeps: u32, period: Duration) -> Usage {
+    todo!()
+}
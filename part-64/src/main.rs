@@ -0,0 +1,67 @@
+use common::{bench::Comparison, cli::Cli};
+use part_64::{run_tasks, run_threads, Usage, COUNT, PERIOD, SLEEPS};
+
+/// Run with `cargo run --release -p part-64` to compare threads and tasks
+fn main() {
+    // `--strategy` picks which of them `Comparison` measures
+    Cli::new("Compares the memory and time threads and tasks take")
+        .strategy()
+        .parse();
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    // Tasks go first, since memory freed by the threads would be reused for them, hiding what they cost
+    let tasks = runtime.block_on(run_tasks(COUNT, SLEEPS, PERIOD));
+    let threads = run_threads(COUNT, SLEEPS, PERIOD);
+    print_usage("threads", threads);
+    print_usage("tasks", tasks);
+    println!();
+
+    Comparison::new(5)
+        .bench("threads", || run_threads(COUNT, SLEEPS, PERIOD))
+        .bench("tasks", || {
+            runtime.block_on(run_tasks(COUNT, SLEEPS, PERIOD))
+        })
+        .print();
+}
+
+fn print_usage(name: &str, usage: Usage) {
+    println!(
+        "{COUNT} {name}: {} finished in {:.2?}, using {} kB more memory, {} bytes each",
+        usage.finished,
+        usage.elapsed,
+        usage.memory / 1024,
+        usage.memory_per_unit()
+    );
+}
+
+#[test]
+fn threads_do_their_work() {
+    let usage = run_threads(1000, 2, PERIOD);
+    assert_eq!(usage.finished, 1000);
+    assert!(usage.elapsed >= 2 * PERIOD);
+}
+
+#[tokio::test]
+async fn tasks_do_their_work() {
+    let usage = run_tasks(COUNT, 2, PERIOD).await;
+    assert_eq!(usage.finished, COUNT);
+    assert!(usage.elapsed >= 2 * PERIOD);
+}
+
+#[tokio::test]
+async fn tasks_stay_within_budget() {
+    let usage = run_tasks(COUNT, SLEEPS, PERIOD).await;
+    assert_eq!(usage.finished, COUNT);
+    // Every task sleeps at the same time, so this is far less than sleeping for each of them in turn
+    assert!(
+        usage.elapsed < std::time::Duration::from_secs(1),
+        "Took {:?}",
+        usage.elapsed
+    );
+    // Much less than even a single page of a thread's stack
+    assert!(
+        usage.memory_per_unit() < 4096,
+        "Used {} bytes per task",
+        usage.memory_per_unit()
+    );
+}
@@ -0,0 +1,81 @@
+//! Part 64, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Barrier,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use super::{memory, Usage};
+
+/// Spawns `count` threads which each sleep `sleeps` times for `period`, and waits for all of them.
+/// Measures the memory used once every thread has started, before any of them starts sleeping.
+pub fn run_threads(count: usize, sleeps: u32, period: Duration) -> Usage {
+    let before = memory();
+    let start = Instant::now();
+    let finished = Arc::new(AtomicUsize::new(0));
+    // Every thread waits here once it has started, and so does the measuring thread
+    let barrier = Arc::new(Barrier::new(count + 1));
+
+    let handles: Vec<_> = (0..count)
+        .map(|_| {
+            let finished = Arc::clone(&finished);
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                barrier.wait();
+                for _ in 0..sleeps {
+                    thread::sleep(period);
+                }
+                finished.fetch_add(1, Ordering::Relaxed);
+            })
+        })
+        .collect();
+
+    // All threads exist at this point, but only start sleeping once we've joined the barrier
+    let memory = memory().saturating_sub(before);
+    barrier.wait();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    Usage {
+        finished: finished.load(Ordering::Relaxed),
+        elapsed: start.elapsed(),
+        memory,
+    }
+}
+
+/// Spawns `count` tasks which each sleep `sleeps` times for `period`, and waits for all of them.
+/// Measures the memory used once every task has started, before any of them starts sleeping.
+pub async fn run_tasks(count: usize, sleeps: u32, period: Duration) -> Usage {
+    let before = memory();
+    let start = Instant::now();
+    let finished = Arc::new(AtomicUsize::new(0));
+    let barrier = Arc::new(tokio::sync::Barrier::new(count + 1));
+
+    let mut tasks = tokio::task::JoinSet::new();
+    for _ in 0..count {
+        let finished = Arc::clone(&finished);
+        let barrier = Arc::clone(&barrier);
+        tasks.spawn(async move {
+            barrier.wait().await;
+            for _ in 0..sleeps {
+                tokio::time::sleep(period).await;
+            }
+            finished.fetch_add(1, Ordering::Relaxed);
+        });
+    }
+
+    let memory = memory().saturating_sub(before);
+    barrier.wait().await;
+    while tasks.join_next().await.is_some() {}
+
+    Usage {
+        finished: finished.load(Ordering::Relaxed),
+        elapsed: start.elapsed(),
+        memory,
+    }
+}
@@ -0,0 +1,33 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-64 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::time::Duration;
+
+use part_64::{run_tasks, run_threads, solutions};
+
+// Memory and time depend on the machine, so only how many finished can be compared
+
+#[test]
+fn runs_threads_like_the_solution() {
+    for count in [1, 10, 100] {
+        assert_eq!(
+            run_threads(count, 2, Duration::from_millis(1)).finished,
+            solutions::run_threads(count, 2, Duration::from_millis(1)).finished,
+            "{count} threads"
+        );
+    }
+}
+
+#[tokio::test]
+async fn runs_tasks_like_the_solution() {
+    for count in [1, 10, 1000] {
+        assert_eq!(
+            run_tasks(count, 2, Duration::from_millis(1)).await.finished,
+            solutions::run_tasks(count, 2, Duration::from_millis(1))
+                .await
+                .finished,
+            "{count} tasks"
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use std::time::Duration;
+
+use part_5::{ComputationResult, Data};
+use tokio::time::Instant;
+
+#[cfg(all(test, feature = "solutions"))]
+mod solutions;
+
+/// How long `fetch` takes for each unit of its datum
+const DELAY: Duration = Duration::from_millis(100);
+
+#[tokio::main]
+async fn main() {
+    let args = common::cli::Cli::new("Runs futures in turn, concurrently and a few at a time")
+        .items(fixtures::data_len(fixtures::Dataset::Small))
+        .parse();
+    // The same as `fixtures::data(Dataset::Small)`, unless run with another `--items`
+    let data = || (1..=args.items() as u64).map(Data).collect::<Vec<_>>();
+
+    let start = Instant::now();
+    let results = sequential(data()).await;
+    println!("Sequential: {results:?} in {:?}", start.elapsed());
+
+    let start = Instant::now();
+    let results = concurrent(data()).await;
+    println!("Concurrent: {results:?} in {:?}", start.elapsed());
+
+    let start = Instant::now();
+    let results = limited(data(), 2).await;
+    println!("At most 2 at a time: {results:?} in {:?}", start.elapsed());
+
+    let start = Instant::now();
+    let results = pair(Data(1), Data(3)).await;
+    println!("A pair: {results:?} in {:?}", start.elapsed());
+
+    let start = Instant::now();
+    let results = try_pair(Data(3), Data(0)).await;
+    println!("A pair which fails: {results:?} in {:?}", start.elapsed());
+}
+
+/// Pretends to fetch the result for `datum` from somewhere else, which takes `DELAY` for each unit of it
+async fn fetch(datum: Data) -> ComputationResult {
+    tokio::time::sleep(DELAY * datum.0 as u32).await;
+    ComputationResult(datum.0 * 2)
+}
+
+/// Returned by `try_fetch` for data it can't handle
+#[derive(Debug, PartialEq, Eq)]
+struct InvalidData(u64);
+
+/// Like `fetch`, but fails for 0 after `DELAY`
+async fn try_fetch(datum: Data) -> Result<ComputationResult, InvalidData> {
+    if datum.0 == 0 {
+        tokio::time::sleep(DELAY).await;
+        Err(InvalidData(datum.0))
+    } else {
+        Ok(fetch(datum).await)
+    }
+}
+
+/// Fetches the results one at a time, so it takes as long as all of them together
+async fn sequential(data: Vec<Data>) -> Vec<ComputationResult> {
+    let mut results = Vec::with_capacity(data.len());
+    for datum in data {
+        results.push(fetch(datum).await);
+    }
+    results
+}
+
+/// Fetches both at the same time, using `tokio::join!`
+async fn pair(a: Data, b: Data) -> (ComputationResult, ComputationResult) {
+    todo!()
+}
+
+/// Fetches both at the same time, using `tokio::try_join!`.
+/// Returns as soon as one of them fails, without waiting for the other.
+async fn try_pair(a: Data, b: Data) -> Result<(ComputationResult, ComputationResult), InvalidData> {
+    todo!()
+}
+
+/// Fetches all the results at the same time, using `futures::future::join_all`.
+/// The results are in the same order as the data.
+async fn concurrent(data: Vec<Data>) -> Vec<ComputationResult> {
+    todo!()
+}
+
+/// Fetches the results with at most `limit` fetches going on at once, using `FuturesUnordered`.
+/// A new fetch starts as soon as one finishes. The results are in the same order as the data.
+async fn limited(data: Vec<Data>, limit: usize) -> Vec<ComputationResult> {
+    todo!()
+}
+
+#[cfg(test)]
+fn results(values: &[u64]) -> Vec<ComputationResult> {
+    values
+        .iter()
+        .map(|&value| ComputationResult(value))
+        .collect()
+}
+
+#[tokio::test(start_paused = true)]
+async fn sequential_takes_the_sum() {
+    let start = Instant::now();
+    let results = sequential(vec![Data(1), Data(2), Data(3), Data(4)]).await;
+    assert_eq!(results, self::results(&[2, 4, 6, 8]));
+    assert_eq!(start.elapsed(), DELAY * 10);
+}
+
+#[tokio::test(start_paused = true)]
+async fn pair_takes_the_max() {
+    let start = Instant::now();
+    let results = pair(Data(3), Data(1)).await;
+    assert_eq!(results, (ComputationResult(6), ComputationResult(2)));
+    assert_eq!(start.elapsed(), DELAY * 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn try_pair_takes_the_max() {
+    let start = Instant::now();
+    let results = try_pair(Data(2), Data(4)).await;
+    assert_eq!(results, Ok((ComputationResult(4), ComputationResult(8))));
+    assert_eq!(start.elapsed(), DELAY * 4);
+}
+
+#[tokio::test(start_paused = true)]
+async fn try_pair_fails_fast() {
+    let start = Instant::now();
+    assert_eq!(try_pair(Data(5), Data(0)).await, Err(InvalidData(0)));
+    assert_eq!(start.elapsed(), DELAY);
+    assert_eq!(try_pair(Data(0), Data(5)).await, Err(InvalidData(0)));
+    assert_eq!(start.elapsed(), DELAY * 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn concurrent_takes_the_max() {
+    let start = Instant::now();
+    let results = concurrent(vec![Data(2), Data(4), Data(1), Data(3)]).await;
+    assert_eq!(results, self::results(&[4, 8, 2, 6]));
+    assert_eq!(start.elapsed(), DELAY * 4);
+}
+
+#[tokio::test(start_paused = true)]
+async fn limited_keeps_the_order() {
+    let results = limited(vec![Data(4), Data(3), Data(2), Data(1)], 2).await;
+    assert_eq!(results, self::results(&[8, 6, 4, 2]));
+}
+
+#[tokio::test(start_paused = true)]
+async fn limited_starts_the_next_as_soon_as_one_finishes() {
+    let start = Instant::now();
+    // 1 and 2 start straight away. 3 starts once 1 is done at 100 ms, and 4 once 2 is done at 200 ms.
+    let results = limited(vec![Data(1), Data(2), Data(3), Data(4)], 2).await;
+    assert_eq!(results, self::results(&[2, 4, 6, 8]));
+    assert_eq!(start.elapsed(), DELAY * 6);
+}
+
+#[tokio::test(start_paused = true)]
+async fn limited_by_one_is_sequential() {
+    let start = Instant::now();
+    limited(vec![Data(1), Data(2), Data(3), Data(4)], 1).await;
+    assert_eq!(start.elapsed(), DELAY * 10);
+}
+
+#[tokio::test(start_paused = true)]
+async fn limited_by_many_is_concurrent() {
+    let start = Instant::now();
+    limited(vec![Data(1), Data(2), Data(3), Data(4)], 100).await;
+    assert_eq!(start.elapsed(), DELAY * 4);
+}
+
+#[cfg(feature = "solutions")]
+#[tokio::test(start_paused = true)]
+async fn fetches_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let numbers = common::datagen::numbers_below(12, 5, rng.seed());
+    let data = || numbers.iter().map(|&n| Data(n)).collect::<Vec<_>>();
+
+    /// What `future` returned, and how long it took
+    async fn timed<T>(future: impl std::future::Future<Output = T>) -> (T, Duration) {
+        let start = Instant::now();
+        let output = future.await;
+        (output, start.elapsed())
+    }
+
+    assert_eq!(
+        timed(concurrent(data())).await,
+        timed(solutions::concurrent(data())).await
+    );
+    for limit in [1, 3, 20] {
+        assert_eq!(
+            timed(limited(data(), limit)).await,
+            timed(solutions::limited(data(), limit)).await,
+            "At most {limit} at a time"
+        );
+    }
+    for pairs in numbers.chunks(2) {
+        let (a, b) = (pairs[0], pairs[1]);
+        assert_eq!(
+            timed(pair(Data(a), Data(b))).await,
+            timed(solutions::pair(Data(a), Data(b))).await
+        );
+        let (ours, took) = timed(try_pair(Data(a), Data(b))).await;
+        let (theirs, expected) = timed(solutions::try_pair(Data(a), Data(b))).await;
+        assert_eq!(
+            (ours.map_err(|error| error.0), took),
+            (theirs.map_err(|error| error.0), expected),
+            "Data({a}) and Data({b})"
+        );
+    }
+}
@@ -0,0 +1,49 @@
+//! Part 65, with every exercise implemented. Only built with `--features solutions`.
+
+use futures::{stream::FuturesUnordered, StreamExt};
+use part_5::{ComputationResult, Data};
+
+use super::{fetch, try_fetch, InvalidData};
+
+/// Fetches both at the same time, using `tokio::join!`
+pub async fn pair(a: Data, b: Data) -> (ComputationResult, ComputationResult) {
+    tokio::join!(fetch(a), fetch(b))
+}
+
+/// Fetches both at the same time, using `tokio::try_join!`.
+/// Returns as soon as one of them fails, without waiting for the other.
+pub async fn try_pair(
+    a: Data,
+    b: Data,
+) -> Result<(ComputationResult, ComputationResult), InvalidData> {
+    tokio::try_join!(try_fetch(a), try_fetch(b))
+}
+
+/// Fetches all the results at the same time, using `futures::future::join_all`.
+/// The results are in the same order as the data.
+pub async fn concurrent(data: Vec<Data>) -> Vec<ComputationResult> {
+    futures::future::join_all(data.into_iter().map(fetch)).await
+}
+
+/// Fetches the results with at most `limit` fetches going on at once, using `FuturesUnordered`.
+/// A new fetch starts as soon as one finishes. The results are in the same order as the data.
+pub async fn limited(data: Vec<Data>, limit: usize) -> Vec<ComputationResult> {
+    assert!(
+        limit > 0,
+        "Can't fetch anything without fetching at least one at a time"
+    );
+    let mut results = vec![None; data.len()];
+    let mut data = data.into_iter().enumerate();
+    // Every future in a `FuturesUnordered` must have the same type, so they're all made here
+    let indexed_fetch = |(index, datum)| async move { (index, fetch(datum).await) };
+    let mut in_flight: FuturesUnordered<_> = data.by_ref().take(limit).map(indexed_fetch).collect();
+
+    while let Some((index, result)) = in_flight.next().await {
+        results[index] = Some(result);
+        if let Some(next) = data.next() {
+            in_flight.push(indexed_fetch(next));
+        }
+    }
+
+    results.into_iter().map(Option::unwrap).collect()
+}
@@ -0,0 +1,106 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use tokio::sync::{mpsc, Semaphore};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How long handling a request takes
+pub const WORK: Duration = Duration::from_millis(10);
+
+/// How many bytes each request carries
+pub const PAYLOAD: usize = 1024;
+
+#[derive(Debug)]
+pub struct Request {
+    pub id: u64,
+    pub payload: Vec<u8>,
+}
+
+/// Keeps track of how many requests have been made but not handled yet, and the most there ever were
+#[derive(Debug, Default)]
+pub struct Gauge {
+    current: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl Gauge {
+    /// Makes a new request, counting it as pending until it's handled
+    pub fn request(&self, id: u64) -> Request {
+        let current = self.current.fetch_add(1, Ordering::Relaxed) + 1;
+        self.peak.fetch_max(current, Ordering::Relaxed);
+        Request {
+            id,
+            payload: vec![0; PAYLOAD],
+        }
+    }
+
+    fn handled(&self) {
+        self.current.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    /// The most requests that were ever pending at once
+    pub fn peak(&self) -> usize {
+        self.peak.load(Ordering::Relaxed)
+    }
+}
+
+/// Handles `request`, and returns its id
+pub async fn handle(request: Request, gauge: &Gauge) -> u64 {
+    tokio::time::sleep(WORK).await;
+    gauge.handled();
+    request.id
+}
+
+/// Which requests were handled, in the order they were handled
+#[derive(Debug)]
+pub struct Report {
+    pub handled: Vec<u64>,
+    pub peak: usize,
+}
+
+/// Makes `count` requests as fast as possible, and hands them to a single worker through an unbounded channel.
+/// The requests pile up in the channel, since they're made far faster than they're handled.
+pub async fn unbounded(count: u64) -> Report {
+    let gauge = Arc::new(Gauge::default());
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let worker = tokio::spawn({
+        let gauge = gauge.clone();
+        async move {
+            let mut handled = Vec::new();
+            while let Some(request) = receiver.recv().await {
+                handled.push(handle(request, &gauge).await);
+            }
+            handled
+        }
+    });
+
+    for id in 0..count {
+        sender.send(gauge.request(id)).unwrap();
+    }
+    drop(sender);
+
+    let handled = worker.await.unwrap();
+    Report {
+        handled,
+        peak: gauge.peak(),
+    }
+}
+
+/// Like `unbounded`, but with a channel which holds at most `capacity` requests,
+/// so making requests has to wait while the channel is full
+pub async fn bounded(count: u64, capacity: usize) -> Report {
+    todo!()
+}
+
+/// Makes `count` requests, and handles each of them on its own task.
+/// Waits for a permit from a `Semaphore` before making each request, so no more than `permits` are ever pending.
+pub async fn admission_control(count: u64, permits: usize) -> Report {
+    todo!()
+}
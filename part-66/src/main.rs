@@ -0,0 +1,84 @@
+use part_66::{admission_control, bounded, unbounded, PAYLOAD};
+use tokio::time::Instant;
+
+const COUNT: u64 = 500;
+
+#[tokio::main]
+async fn main() {
+    let args = common::cli::Cli::new("Handles bursts of requests, with and without limits")
+        .items(COUNT as usize)
+        .parse();
+    let count = args.items() as u64;
+    let start = Instant::now();
+    let report = unbounded(count).await;
+    println!(
+        "Unbounded: handled {} requests in {:?}, with up to {} pending, taking up {} kB",
+        report.handled.len(),
+        start.elapsed(),
+        report.peak,
+        report.peak * PAYLOAD / 1024
+    );
+
+    let start = Instant::now();
+    let report = bounded(count, 16).await;
+    println!(
+        "Bounded: handled {} requests in {:?}, with up to {} pending, taking up {} kB",
+        report.handled.len(),
+        start.elapsed(),
+        report.peak,
+        report.peak * PAYLOAD / 1024
+    );
+
+    let start = Instant::now();
+    let report = admission_control(count, 16).await;
+    println!(
+        "Admission control: handled {} requests in {:?}, with up to {} pending, taking up {} kB",
+        report.handled.len(),
+        start.elapsed(),
+        report.peak,
+        report.peak * PAYLOAD / 1024
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn unbounded_lets_requests_pile_up() {
+    let report = unbounded(1000).await;
+    assert_eq!(report.handled, (0..1000).collect::<Vec<_>>());
+    // Every request was made before the first one was handled
+    assert_eq!(report.peak, 1000);
+}
+
+#[tokio::test(start_paused = true)]
+async fn bounded_limits_pending_requests() {
+    let report = bounded(1000, 4).await;
+    assert_eq!(report.handled, (0..1000).collect::<Vec<_>>());
+    // The ones in the channel, the one being handled, and the one waiting to be sent
+    assert!(report.peak <= 4 + 2, "Up to {} were pending", report.peak);
+}
+
+#[tokio::test(start_paused = true)]
+async fn bounded_handles_one_at_a_time() {
+    use part_66::WORK;
+
+    let start = Instant::now();
+    bounded(100, 4).await;
+    assert_eq!(start.elapsed(), WORK * 100);
+}
+
+#[tokio::test(start_paused = true)]
+async fn admission_control_limits_pending_requests() {
+    let mut report = admission_control(1000, 8).await;
+    report.handled.sort();
+    assert_eq!(report.handled, (0..1000).collect::<Vec<_>>());
+    assert_eq!(report.peak, 8);
+}
+
+#[tokio::test(start_paused = true)]
+async fn admission_control_handles_requests_concurrently() {
+    use part_66::WORK;
+
+    let start = Instant::now();
+    admission_control(100, 8).await;
+    // 13 rounds of 8 requests, with the last one only handling 4
+    assert_eq!(start.elapsed(), WORK * 13);
+}
@@ -0,0 +1,66 @@
+//! Part 66, with every exercise implemented. Only built with `--features solutions`.
+
+use std::sync::Arc;
+
+use tokio::sync::{mpsc, Semaphore};
+
+use super::{handle, Gauge, Report};
+
+/// Like `unbounded`, but with a channel which holds at most `capacity` requests,
+/// so making requests has to wait while the channel is full
+pub async fn bounded(count: u64, capacity: usize) -> Report {
+    let gauge = Arc::new(Gauge::default());
+    let (sender, mut receiver) = mpsc::channel(capacity);
+    let worker = tokio::spawn({
+        let gauge = gauge.clone();
+        async move {
+            let mut handled = Vec::new();
+            while let Some(request) = receiver.recv().await {
+                handled.push(handle(request, &gauge).await);
+            }
+            handled
+        }
+    });
+
+    for id in 0..count {
+        // Waits here while the channel is full
+        sender.send(gauge.request(id)).await.unwrap();
+    }
+    drop(sender);
+
+    let handled = worker.await.unwrap();
+    Report {
+        handled,
+        peak: gauge.peak(),
+    }
+}
+
+/// Makes `count` requests, and handles each of them on its own task.
+/// Waits for a permit from a `Semaphore` before making each request, so no more than `permits` are ever pending.
+pub async fn admission_control(count: u64, permits: usize) -> Report {
+    let gauge = Arc::new(Gauge::default());
+    let semaphore = Arc::new(Semaphore::new(permits));
+    let mut handlers = tokio::task::JoinSet::new();
+
+    for id in 0..count {
+        // Waits here while `permits` requests are pending
+        let permit = semaphore.clone().acquire_owned().await.unwrap();
+        let request = gauge.request(id);
+        let gauge = gauge.clone();
+        handlers.spawn(async move {
+            let id = handle(request, &gauge).await;
+            // Lets the next request in
+            drop(permit);
+            id
+        });
+    }
+
+    let mut handled = Vec::new();
+    while let Some(id) = handlers.join_next().await {
+        handled.push(id.unwrap());
+    }
+    Report {
+        handled,
+        peak: gauge.peak(),
+    }
+}
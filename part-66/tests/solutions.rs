@@ -0,0 +1,36 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-66 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_66::{admission_control, bounded, solutions};
+
+#[tokio::test(start_paused = true)]
+async fn bounds_like_the_solution() {
+    for (count, capacity) in [(1, 1), (50, 1), (50, 8), (20, 100)] {
+        let report = bounded(count, capacity).await;
+        let expected = solutions::bounded(count, capacity).await;
+        assert_eq!(
+            (report.handled, report.peak),
+            (expected.handled, expected.peak),
+            "{count} requests through a channel of {capacity}"
+        );
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn admits_like_the_solution() {
+    for (count, permits) in [(1, 1), (50, 1), (50, 8), (20, 100)] {
+        let (mut report, mut expected) = (
+            admission_control(count, permits).await,
+            solutions::admission_control(count, permits).await,
+        );
+        // Requests handled at the same time finish in any order
+        report.handled.sort();
+        expected.handled.sort();
+        assert_eq!(
+            (report.handled, report.peak),
+            (expected.handled, expected.peak),
+            "{count} requests with {permits} permits"
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use std::{cmp::Reverse, thread};
+
+use common::datagen;
+use rayon::prelude::*;
+use tokio::sync::mpsc;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// `len` lines to feed through the pipeline, each holding a number below a million
+pub fn random_lines(len: usize, seed: u64) -> Vec<String> {
+    datagen::numbers_below(len, 1_000_000, seed)
+        .into_iter()
+        .map(|number| (number + 1).to_string())
+        .collect()
+}
+
+/// Stage 1: turns a line into a number
+pub fn parse(line: &str) -> u64 {
+    line.trim().parse().expect("Lines should hold numbers")
+}
+
+/// Stage 2: how many steps it takes the Collatz sequence starting at `n` to reach 1,
+/// which takes a while to compute
+pub fn collatz_steps(mut n: u64) -> u64 {
+    let mut steps = 0;
+    while n > 1 {
+        n = if n.is_multiple_of(2) {
+            n / 2
+        } else {
+            3 * n + 1
+        };
+        steps += 1;
+    }
+    steps
+}
+
+/// Stage 3: what came out of the pipeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Summary {
+    /// How many numbers went through
+    pub count: u64,
+    /// The steps of all of them added together
+    pub total_steps: u64,
+    /// The number with the most steps, and its steps. Ties go to the smaller number.
+    pub longest: Option<(u64, u64)>,
+}
+
+impl Summary {
+    pub fn add(&mut self, n: u64, steps: u64) {
+        *self = self.merge(Summary {
+            count: 1,
+            total_steps: steps,
+            longest: Some((n, steps)),
+        });
+    }
+
+    /// Combines the summaries of two parts of the input
+    pub fn merge(self, other: Summary) -> Summary {
+        Summary {
+            count: self.count + other.count,
+            total_steps: self.total_steps + other.total_steps,
+            longest: self
+                .longest
+                .into_iter()
+                .chain(other.longest)
+                .max_by_key(|&(n, steps)| (steps, Reverse(n))),
+        }
+    }
+}
+
+/// Runs every stage on one thread
+pub fn serial(lines: Vec<String>) -> Summary {
+    let mut summary = Summary::default();
+    for line in lines {
+        let n = parse(&line);
+        summary.add(n, collatz_steps(n));
+    }
+    summary
+}
+
+/// Runs each stage on its own threads, connected by channels:
+/// one thread parsing, `workers` threads computing steps, and the calling thread summarizing
+pub fn threads_and_channels(lines: Vec<String>, workers: usize) -> Summary {
+    todo!()
+}
+
+/// Runs the stages with rayon's parallel iterators
+pub fn rayon_iterators(lines: Vec<String>) -> Summary {
+    todo!()
+}
+
+/// Runs each stage on its own tasks, connected by tokio's channels:
+/// one task parsing, `workers` tasks computing steps, and the calling task summarizing
+pub async fn tokio_tasks(lines: Vec<String>, workers: usize) -> Summary {
+    todo!()
+}
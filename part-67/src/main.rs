@@ -0,0 +1,107 @@
+use common::{bench::Comparison, cli::Cli};
+use part_67::{random_lines, rayon_iterators, serial, threads_and_channels, tokio_tasks};
+
+const LEN: usize = 200_000;
+
+/// Run with `cargo run --release -p part-67` to compare the pipelines
+fn main() {
+    // `workshop bench` tries other sizes too
+    let args = Cli::new("Runs lines through pipelines of threads, Rayon and tokio")
+        .items(LEN)
+        .threads(std::thread::available_parallelism().map_or(4, |n| n.get()))
+        .strategy()
+        .parse();
+    let (len, workers) = (args.items(), args.threads());
+    let lines = random_lines(len, 42);
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    println!("Running {len} lines through each pipeline, with {workers} workers");
+
+    Comparison::new(5)
+        .bench("Serial", || serial(lines.clone()))
+        .bench("Threads and channels", || {
+            threads_and_channels(lines.clone(), workers)
+        })
+        .bench("Rayon iterators", || rayon_iterators(lines.clone()))
+        .bench("Tokio tasks", || {
+            runtime.block_on(tokio_tasks(lines.clone(), workers))
+        })
+        .print();
+}
+
+#[test]
+fn summary_picks_the_longest() {
+    use part_67::{collatz_steps, Summary};
+
+    let mut summary = Summary::default();
+    assert_eq!(summary.longest, None);
+    for n in [6, 1, 7, 3] {
+        summary.add(n, collatz_steps(n));
+    }
+    assert_eq!(
+        summary,
+        Summary {
+            count: 4,
+            total_steps: 8 + 16 + 7,
+            longest: Some((7, 16)),
+        }
+    );
+}
+
+#[test]
+fn summary_breaks_ties_with_the_smaller_number() {
+    use part_67::Summary;
+
+    let mut first = Summary::default();
+    first.add(13, 9);
+    let mut second = Summary::default();
+    second.add(12, 9);
+    assert_eq!(first.merge(second).longest, Some((12, 9)));
+    assert_eq!(second.merge(first).longest, Some((12, 9)));
+    assert_eq!(Summary::default().merge(first), first);
+}
+
+#[test]
+fn threads_and_channels_match_serial() {
+    for len in [0, 1, 10, 10_000] {
+        let lines = random_lines(len, len as u64);
+        for workers in [1, 2, 7] {
+            assert_eq!(
+                threads_and_channels(lines.clone(), workers),
+                serial(lines.clone()),
+                "Wrong summary for {len} lines with {workers} workers"
+            );
+        }
+    }
+}
+
+#[test]
+fn rayon_iterators_match_serial() {
+    for len in [0, 1, 10, 10_000] {
+        let lines = random_lines(len, len as u64);
+        assert_eq!(
+            rayon_iterators(lines.clone()),
+            serial(lines),
+            "Wrong summary for {len} lines"
+        );
+    }
+}
+
+#[tokio::test]
+async fn tokio_tasks_match_serial() {
+    for len in [0, 1, 10, 10_000] {
+        let lines = random_lines(len, len as u64);
+        for workers in [1, 2, 7] {
+            assert_eq!(
+                tokio_tasks(lines.clone(), workers).await,
+                serial(lines.clone()),
+                "Wrong summary for {len} lines with {workers} workers"
+            );
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn tokio_tasks_match_serial_on_many_threads() {
+    let lines = random_lines(10_000, 4);
+    assert_eq!(tokio_tasks(lines.clone(), 4).await, serial(lines));
+}
@@ -0,0 +1,89 @@
+//! Part 67, with every exercise implemented. Only built with `--features solutions`.
+
+use std::thread;
+
+use rayon::prelude::*;
+use tokio::sync::mpsc;
+
+use super::{collatz_steps, parse, Summary};
+
+/// Runs each stage on its own threads, connected by channels:
+/// one thread parsing, `workers` threads computing steps, and the calling thread summarizing
+pub fn threads_and_channels(lines: Vec<String>, workers: usize) -> Summary {
+    assert!(workers > 0, "Can't compute anything without workers");
+    let (number_sender, number_receiver) = crossbeam_channel::bounded(1024);
+    let (result_sender, result_receiver) = crossbeam_channel::bounded(1024);
+
+    thread::scope(|s| {
+        s.spawn(move || {
+            for line in lines {
+                number_sender.send(parse(&line)).unwrap();
+            }
+        });
+        for _ in 0..workers {
+            let numbers = number_receiver.clone();
+            let results = result_sender.clone();
+            s.spawn(move || {
+                for n in numbers {
+                    results.send((n, collatz_steps(n))).unwrap();
+                }
+            });
+        }
+        // The workers have their own, and the channels only close once every one of them is gone
+        drop(number_receiver);
+        drop(result_sender);
+
+        let mut summary = Summary::default();
+        for (n, steps) in result_receiver {
+            summary.add(n, steps);
+        }
+        summary
+    })
+}
+
+/// Runs the stages with rayon's parallel iterators
+pub fn rayon_iterators(lines: Vec<String>) -> Summary {
+    lines
+        .par_iter()
+        .map(|line| parse(line))
+        .map(|n| (n, collatz_steps(n)))
+        .fold(Summary::default, |mut summary, (n, steps)| {
+            summary.add(n, steps);
+            summary
+        })
+        .reduce(Summary::default, Summary::merge)
+}
+
+/// Runs each stage on its own tasks, connected by tokio's channels:
+/// one task parsing, `workers` tasks computing steps, and the calling task summarizing
+pub async fn tokio_tasks(lines: Vec<String>, workers: usize) -> Summary {
+    assert!(workers > 0, "Can't compute anything without workers");
+    let (result_sender, mut results) = mpsc::channel(1024);
+
+    // Tokio's receivers can't be shared, so every worker gets a channel of its own
+    let workers: Vec<_> = (0..workers)
+        .map(|_| {
+            let (sender, mut numbers) = mpsc::channel(1024);
+            let results = result_sender.clone();
+            tokio::spawn(async move {
+                while let Some(n) = numbers.recv().await {
+                    results.send((n, collatz_steps(n))).await.unwrap();
+                }
+            });
+            sender
+        })
+        .collect();
+    drop(result_sender);
+
+    tokio::spawn(async move {
+        for (line, worker) in lines.iter().zip(workers.iter().cycle()) {
+            worker.send(parse(line)).await.unwrap();
+        }
+    });
+
+    let mut summary = Summary::default();
+    while let Some((n, steps)) = results.recv().await {
+        summary.add(n, steps);
+    }
+    summary
+}
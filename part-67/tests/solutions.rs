@@ -0,0 +1,40 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-67 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_67::{
+    random_lines, rayon_iterators, solutions, threads_and_channels, tokio_tasks, Summary,
+};
+
+/// The summary's fields, to compare it with the solution's
+fn fields(summary: Summary) -> (u64, u64, Option<(u64, u64)>) {
+    (summary.count, summary.total_steps, summary.longest)
+}
+
+#[tokio::test]
+async fn pipes_like_the_solution() {
+    for len in [0, 1, 500] {
+        let lines = random_lines(len, 67);
+        let expected = solutions::rayon_iterators(lines.clone());
+        let expected = (expected.count, expected.total_steps, expected.longest);
+        assert_eq!(
+            fields(rayon_iterators(lines.clone())),
+            expected,
+            "{len} lines with rayon"
+        );
+        for workers in [1, 4] {
+            assert_eq!(
+                fields(threads_and_channels(lines.clone(), workers)),
+                expected,
+                "{len} lines on {workers} threads"
+            );
+            assert_eq!(
+                fields(tokio_tasks(lines.clone(), workers).await),
+                expected,
+                "{len} lines on {workers} tasks"
+            );
+            let solved = solutions::threads_and_channels(lines.clone(), workers);
+            assert_eq!((solved.count, solved.total_steps, solved.longest), expected);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use std::{
+    hint::black_box,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use common::priority::{self, PriorityError};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// A small piece of CPU-bound work
+pub fn work_unit() -> u64 {
+    (0..1000u64).fold(0, |hash, i| {
+        black_box(hash.wrapping_mul(31).wrapping_add(i))
+    })
+}
+
+/// Spawns a thread which sets its niceness to `niceness` before running `f`.
+/// Returns the error instead of running `f` if the niceness couldn't be set.
+pub fn spawn_with_niceness<F, T>(niceness: i32, f: F) -> JoinHandle<Result<T, PriorityError>>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    todo!()
+}
+
+/// Spawns a thread with each of the `niceness` values, which all do `work_unit` as many times as they can
+/// until `duration` has passed. Returns how many units each of them did, or the first error if a thread couldn't
+/// set its niceness.
+pub fn contend(duration: Duration, niceness: &[i32]) -> Result<Vec<u64>, PriorityError> {
+    todo!()
+}
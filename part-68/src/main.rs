@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use common::priority::{LOWEST, NORMAL};
+use part_68::contend;
+
+const DURATION: Duration = Duration::from_secs(1);
+
+fn main() {
+    // Twice as many busy threads as there are cores, so they have to compete for them
+    let cores = std::thread::available_parallelism().map_or(4, |n| n.get());
+    for niceness in [NORMAL, 5, 10, LOWEST] {
+        let threads: Vec<_> = [NORMAL, niceness]
+            .into_iter()
+            .flat_map(|niceness| std::iter::repeat_n(niceness, cores))
+            .collect();
+        match contend(DURATION, &threads) {
+            Ok(units) => {
+                let (normal, other) = units.split_at(cores);
+                println!(
+                    "Niceness {NORMAL} did {} units, while niceness {niceness} did {}",
+                    normal.iter().sum::<u64>(),
+                    other.iter().sum::<u64>()
+                );
+            }
+            Err(error) => println!("Couldn't set the niceness to {niceness}: {error:?}"),
+        }
+    }
+}
+
+#[test]
+fn spawned_threads_get_their_niceness() {
+    use common::priority::{self, PriorityError};
+    use part_68::spawn_with_niceness;
+
+    let before = match priority::niceness() {
+        Ok(niceness) => niceness,
+        Err(error) => {
+            assert_eq!(error, PriorityError::Unsupported);
+            return;
+        }
+    };
+    let result = spawn_with_niceness(10, priority::niceness).join().unwrap();
+    assert_eq!(result, Ok(Ok(10)));
+    // Only the new thread was affected
+    assert_eq!(priority::niceness(), Ok(before));
+}
+
+#[test]
+fn raising_the_priority_may_be_refused() {
+    use common::priority::PriorityError;
+    use part_68::spawn_with_niceness;
+
+    let ran = spawn_with_niceness(-5, || ()).join().unwrap();
+    assert!(
+        matches!(
+            ran,
+            Ok(()) | Err(PriorityError::NotPermitted | PriorityError::Unsupported)
+        ),
+        "Unexpected result: {ran:?}"
+    );
+}
+
+#[test]
+fn every_thread_gets_to_work() {
+    use common::priority::PriorityError;
+
+    match contend(Duration::from_millis(100), &[NORMAL, NORMAL, 5]) {
+        Ok(units) => {
+            assert_eq!(units.len(), 3);
+            assert!(units.iter().all(|&units| units > 0), "{units:?}");
+        }
+        Err(error) => assert_eq!(error, PriorityError::Unsupported),
+    }
+}
+
+#[test]
+fn lower_priority_does_less_work() {
+    let cores = std::thread::available_parallelism().map_or(4, |n| n.get());
+    let threads: Vec<_> = [NORMAL, LOWEST]
+        .into_iter()
+        .flat_map(|niceness| std::iter::repeat_n(niceness, cores))
+        .collect();
+    let units = match contend(Duration::from_millis(500), &threads) {
+        Ok(units) => units,
+        Err(error) => {
+            println!("Can't change priorities here, skipping: {error:?}");
+            return;
+        }
+    };
+
+    let (normal, lowest) = units.split_at(cores);
+    let normal: u64 = normal.iter().sum();
+    let lowest: u64 = lowest.iter().sum();
+    assert!(
+        lowest < normal / 2,
+        "The lowest priority did {lowest} units, and the normal priority {normal}"
+    );
+}
@@ -0,0 +1,58 @@
+//! Part 68, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    hint::black_box,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use common::priority::{self, PriorityError};
+
+use super::work_unit;
+
+/// Spawns a thread which sets its niceness to `niceness` before running `f`.
+/// Returns the error instead of running `f` if the niceness couldn't be set.
+pub fn spawn_with_niceness<F, T>(niceness: i32, f: F) -> JoinHandle<Result<T, PriorityError>>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    thread::spawn(move || {
+        priority::set_niceness(niceness)?;
+        Ok(f())
+    })
+}
+
+/// Spawns a thread with each of the `niceness` values, which all do `work_unit` as many times as they can
+/// until `duration` has passed. Returns how many units each of them did, or the first error if a thread couldn't
+/// set its niceness.
+pub fn contend(duration: Duration, niceness: &[i32]) -> Result<Vec<u64>, PriorityError> {
+    let stop = Arc::new(AtomicBool::new(false));
+    let handles: Vec<_> = niceness
+        .iter()
+        .map(|&niceness| {
+            let stop = stop.clone();
+            spawn_with_niceness(niceness, move || {
+                let mut units = 0;
+                while !stop.load(Ordering::Relaxed) {
+                    black_box(work_unit());
+                    units += 1;
+                }
+                units
+            })
+        })
+        .collect();
+
+    thread::sleep(duration);
+    stop.store(true, Ordering::Relaxed);
+    // Joins every thread, even if one of them failed
+    let units: Vec<_> = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+    units.into_iter().collect()
+}
@@ -0,0 +1,45 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-68 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::time::Duration;
+
+use part_68::{contend, solutions, spawn_with_niceness};
+
+#[test]
+fn spawns_like_the_solution() {
+    // Lowering a thread's priority is always allowed, where priorities can be set at all
+    for niceness in [0, 5, 19] {
+        let ours = spawn_with_niceness(niceness, move || niceness * 2)
+            .join()
+            .unwrap();
+        let theirs = solutions::spawn_with_niceness(niceness, move || niceness * 2)
+            .join()
+            .unwrap();
+        assert_eq!(
+            format!("{ours:?}"),
+            format!("{theirs:?}"),
+            "With a niceness of {niceness}"
+        );
+    }
+}
+
+#[test]
+fn contends_like_the_solution() {
+    // How much work each thread gets done depends on the machine, but not how many threads there are
+    let niceness = [0, 10, 19];
+    let ours = contend(Duration::from_millis(50), &niceness);
+    let theirs = solutions::contend(Duration::from_millis(50), &niceness);
+    assert_eq!(
+        ours.as_ref()
+            .map(Vec::len)
+            .map_err(|error| format!("{error:?}")),
+        theirs
+            .as_ref()
+            .map(Vec::len)
+            .map_err(|error| format!("{error:?}"))
+    );
+    if let Ok(units) = ours {
+        assert!(units.iter().all(|&units| units > 0), "{units:?}");
+    }
+}
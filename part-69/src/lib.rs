@@ -0,0 +1,84 @@
+use std::{
+    thread::{self, yield_now, Scope, ScopedJoinHandle},
+    time::{Duration, Instant},
+};
+
+use common::affinity::{self, AffinityError};
+use part_45::{ring_buffer, Consumer, Producer};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How many items the ring buffer between the producer and the consumer holds
+pub const CAPACITY: usize = 1024;
+
+/// Which cores the producer and the consumer run on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placement {
+    pub producer: usize,
+    pub consumer: usize,
+}
+
+impl Placement {
+    /// Both on the first core this process may run on
+    pub fn same_core() -> Result<Placement, AffinityError> {
+        let first = affinity::cores()?[0];
+        Ok(Placement {
+            producer: first,
+            consumer: first,
+        })
+    }
+
+    /// On the first two cores this process may run on, or `None` if it may only run on one
+    pub fn different_cores() -> Result<Option<Placement>, AffinityError> {
+        Ok(match affinity::cores()?[..] {
+            [producer, consumer, ..] => Some(Placement { producer, consumer }),
+            _ => None,
+        })
+    }
+}
+
+/// Pushes `item`, waiting for room whenever the buffer is full
+fn push(producer: &mut Producer<u64>, mut item: u64) {
+    while let Err(rejected) = producer.push(item) {
+        item = rejected;
+        yield_now();
+    }
+}
+
+/// Pops an item, waiting for one whenever the buffer is empty
+fn pop(consumer: &mut Consumer<u64>) -> u64 {
+    loop {
+        match consumer.pop() {
+            Some(item) => return item,
+            None => yield_now(),
+        }
+    }
+}
+
+/// Spawns a scoped thread which pins itself to `core` before running `f`.
+/// Runs `f` even if the thread couldn't be pinned, so whoever it works with isn't left waiting,
+/// but then returns the error instead of what `f` returned.
+pub fn spawn_pinned<'scope, F, T>(
+    s: &'scope Scope<'scope, '_>,
+    core: usize,
+    f: F,
+) -> ScopedJoinHandle<'scope, Result<T, AffinityError>>
+where
+    F: FnOnce() -> T + Send + 'scope,
+    T: Send + 'scope,
+{
+    todo!()
+}
+
+/// Sends `items` numbers from a producer to a consumer through a ring buffer of `CAPACITY`,
+/// with both pinned according to `placement`. Returns how long it took.
+pub fn transfer(placement: Placement, items: u64) -> Result<Duration, AffinityError> {
+    todo!()
+}
+
+/// Bounces a number back and forth between two threads `rounds` times, through a ring buffer in each direction,
+/// with both pinned according to `placement`. Returns how long each round trip took on average.
+pub fn ping_pong(placement: Placement, rounds: u32) -> Result<Duration, AffinityError> {
+    todo!()
+}
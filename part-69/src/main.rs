@@ -0,0 +1,126 @@
+use common::{bench::Comparison, cli::Cli};
+use part_69::{ping_pong, transfer, Placement};
+
+const ITEMS: u64 = 1_000_000;
+const ROUNDS: u32 = 10_000;
+
+/// Run with `cargo run --release -p part-69` to compare the placements
+fn main() {
+    let args = Cli::new("Passes data between threads on the same core and on others")
+        .items(ITEMS as usize)
+        .strategy()
+        .parse();
+    let items = args.items() as u64;
+    let same = match Placement::same_core() {
+        Ok(placement) => placement,
+        Err(error) => return println!("Can't pin threads here: {error:?}"),
+    };
+    let mut placements = vec![("Same core", same)];
+    match Placement::different_cores().unwrap() {
+        Some(different) => placements.push(("Different cores", different)),
+        None => println!("Only one core to run on, so there's nothing to compare with"),
+    }
+
+    for (name, placement) in &placements {
+        let round_trip = ping_pong(*placement, ROUNDS).unwrap();
+        println!("{name} ({placement:?}): {round_trip:?} per round trip");
+    }
+
+    println!("Transferring {items} items");
+    let mut comparison = Comparison::new(5);
+    for (name, placement) in &placements {
+        comparison.bench(name, || transfer(*placement, items).unwrap());
+    }
+    comparison.print();
+}
+
+/// Gives up on the test if threads can't be pinned on this platform
+#[cfg(test)]
+fn same_core_or_skip() -> Option<Placement> {
+    use common::affinity::AffinityError;
+
+    match Placement::same_core() {
+        Ok(placement) => Some(placement),
+        Err(AffinityError::Unsupported) => None,
+        Err(error) => panic!("Couldn't find a core: {error:?}"),
+    }
+}
+
+#[test]
+fn spawn_pinned_runs_on_the_core() {
+    use common::affinity;
+    use part_69::spawn_pinned;
+
+    if same_core_or_skip().is_none() {
+        return;
+    }
+    for core in affinity::cores().unwrap() {
+        let running_on = std::thread::scope(|s| {
+            spawn_pinned(s, core, affinity::current_core)
+                .join()
+                .unwrap()
+        });
+        assert_eq!(running_on, Ok(Ok(core)));
+    }
+}
+
+#[test]
+fn spawn_pinned_runs_even_without_the_core() {
+    use common::affinity::AffinityError;
+    use part_69::spawn_pinned;
+
+    let mut ran = false;
+    let result = std::thread::scope(|s| spawn_pinned(s, 100_000, || ran = true).join().unwrap());
+    assert!(ran);
+    if same_core_or_skip().is_some() {
+        assert_eq!(result, Err(AffinityError::NoSuchCore(100_000)));
+    }
+}
+
+#[test]
+fn transfers_on_the_same_core() {
+    let Some(placement) = same_core_or_skip() else {
+        return;
+    };
+    assert!(transfer(placement, 100_000).is_ok());
+    assert!(ping_pong(placement, 1000).is_ok());
+}
+
+#[test]
+fn transfers_on_different_cores() {
+    if same_core_or_skip().is_none() {
+        return;
+    }
+    let Some(placement) = Placement::different_cores().unwrap() else {
+        println!("Only one core to run on, skipping");
+        return;
+    };
+    assert!(transfer(placement, 100_000).is_ok());
+    assert!(ping_pong(placement, 1000).is_ok());
+}
+
+#[test]
+fn fails_without_hanging_when_a_core_is_missing() {
+    use common::affinity::AffinityError;
+
+    let Some(same) = same_core_or_skip() else {
+        return;
+    };
+    let placement = Placement {
+        consumer: 100_000,
+        ..same
+    };
+    let transferred = common::with_timeout(std::time::Duration::from_secs(10), move || {
+        transfer(placement, 1000)
+    });
+    assert_eq!(transferred, Some(Err(AffinityError::NoSuchCore(100_000))));
+
+    let placement = Placement {
+        producer: 100_000,
+        ..same
+    };
+    let bounced = common::with_timeout(std::time::Duration::from_secs(10), move || {
+        ping_pong(placement, 1000)
+    });
+    assert_eq!(bounced, Some(Err(AffinityError::NoSuchCore(100_000))));
+}
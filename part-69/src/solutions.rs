@@ -0,0 +1,99 @@
+//! Part 69, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    thread::{self, yield_now, Scope, ScopedJoinHandle},
+    time::{Duration, Instant},
+};
+
+use common::affinity::{self, AffinityError};
+use part_45::solutions::{ring_buffer, Consumer, Producer};
+
+use super::{Placement, CAPACITY};
+
+/// Pushes `item`, waiting for room whenever the buffer is full
+fn push(producer: &mut Producer<u64>, mut item: u64) {
+    while let Err(rejected) = producer.push(item) {
+        item = rejected;
+        yield_now();
+    }
+}
+
+/// Pops an item, waiting for one whenever the buffer is empty
+fn pop(consumer: &mut Consumer<u64>) -> u64 {
+    loop {
+        match consumer.pop() {
+            Some(item) => return item,
+            None => yield_now(),
+        }
+    }
+}
+
+/// Spawns a scoped thread which pins itself to `core` before running `f`.
+/// Runs `f` even if the thread couldn't be pinned, so whoever it works with isn't left waiting,
+/// but then returns the error instead of what `f` returned.
+pub fn spawn_pinned<'scope, F, T>(
+    s: &'scope Scope<'scope, '_>,
+    core: usize,
+    f: F,
+) -> ScopedJoinHandle<'scope, Result<T, AffinityError>>
+where
+    F: FnOnce() -> T + Send + 'scope,
+    T: Send + 'scope,
+{
+    s.spawn(move || {
+        let pinned = affinity::pin_to_core(core);
+        let result = f();
+        pinned.map(|()| result)
+    })
+}
+
+/// Sends `items` numbers from a producer to a consumer through a ring buffer of `CAPACITY`,
+/// with both pinned according to `placement`. Returns how long it took.
+pub fn transfer(placement: Placement, items: u64) -> Result<Duration, AffinityError> {
+    let (mut producer, mut consumer) = ring_buffer(CAPACITY);
+    thread::scope(|s| {
+        let start = Instant::now();
+        let sending = spawn_pinned(s, placement.producer, move || {
+            for item in 0..items {
+                push(&mut producer, item);
+            }
+        });
+        let receiving = spawn_pinned(s, placement.consumer, move || {
+            for expected in 0..items {
+                assert_eq!(pop(&mut consumer), expected, "Items arrived out of order");
+            }
+        });
+        sending.join().unwrap()?;
+        receiving.join().unwrap()?;
+        Ok(start.elapsed())
+    })
+}
+
+/// Bounces a number back and forth between two threads `rounds` times, through a ring buffer in each direction,
+/// with both pinned according to `placement`. Returns how long each round trip took on average.
+pub fn ping_pong(placement: Placement, rounds: u32) -> Result<Duration, AffinityError> {
+    let (mut ping, mut ping_receiver) = ring_buffer(1);
+    let (mut pong, mut pong_receiver) = ring_buffer(1);
+    thread::scope(|s| {
+        let start = Instant::now();
+        let pinging = spawn_pinned(s, placement.producer, move || {
+            for round in 0..rounds {
+                push(&mut ping, round.into());
+                assert_eq!(
+                    pop(&mut pong_receiver),
+                    u64::from(round),
+                    "Got the wrong pong back"
+                );
+            }
+        });
+        let ponging = spawn_pinned(s, placement.consumer, move || {
+            for _ in 0..rounds {
+                let round = pop(&mut ping_receiver);
+                push(&mut pong, round);
+            }
+        });
+        pinging.join().unwrap()?;
+        ponging.join().unwrap()?;
+        Ok(start.elapsed() / rounds.max(1))
+    })
+}
@@ -0,0 +1,47 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-69 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::thread;
+
+use part_69::{ping_pong, solutions, spawn_pinned, transfer, Placement};
+
+// How long things take depends on the machine, so only what comes out of them can be compared
+
+#[test]
+fn pins_like_the_solution() {
+    for core in [0, 1, usize::MAX] {
+        let (ours, theirs) = thread::scope(|s| {
+            let ours = spawn_pinned(s, core, || core / 2).join().unwrap();
+            let theirs = solutions::spawn_pinned(s, core, || core / 2)
+                .join()
+                .unwrap();
+            (ours, theirs)
+        });
+        assert_eq!(
+            ours.map_err(|error| format!("{error:?}")),
+            theirs.map_err(|error| format!("{error:?}")),
+            "On core {core}"
+        );
+    }
+}
+
+#[test]
+fn transfers_like_the_solution() {
+    let Ok(placement) = Placement::same_core() else {
+        // Threads can't be pinned here
+        return;
+    };
+    let expected = Placement {
+        producer: placement.producer,
+        consumer: placement.consumer,
+    };
+    assert_eq!(
+        transfer(placement, 10_000).is_ok(),
+        solutions::transfer(expected, 10_000).is_ok()
+    );
+    assert_eq!(
+        ping_pong(placement, 100).is_ok(),
+        solutions::ping_pong(expected, 100).is_ok()
+    );
+}
@@ -0,0 +1,65 @@
+use tokio::task::JoinHandle;
+
+#[cfg(all(test, feature = "solutions"))]
+mod solutions;
+
+#[tokio::main]
+async fn main() {
+    let greeting = spawn_greeting().await.expect("Task panicked");
+    println!("{greeting}");
+
+    let numbers = spawn_many(5).await;
+    println!("Tasks returned: {numbers:?}");
+}
+
+/// Spawns a task that returns the message `Hello from task!`
+fn spawn_greeting() -> JoinHandle<String> {
+    todo!()
+}
+
+/// Spawns `n` tasks, where task number `i` returns `i`.
+/// Waits for all of them and returns their results in the order they were spawned.
+async fn spawn_many(n: usize) -> Vec<usize> {
+    todo!()
+}
+
+/// Does the same as `main`'s first line, but without `#[tokio::main]`:
+/// creates a runtime by hand and uses it to run `spawn_greeting` to completion.
+fn greet_without_macro() -> String {
+    todo!()
+}
+
+#[tokio::test]
+async fn task_greets() {
+    let greeting = spawn_greeting().await.expect("Task panicked");
+    assert_eq!(greeting, "Hello from task!");
+}
+
+#[tokio::test]
+async fn awaits_every_task() {
+    assert_eq!(spawn_many(10).await, (0..10).collect::<Vec<_>>());
+}
+
+// Not a `#[tokio::test]`, you can't create a runtime from within a runtime!
+#[test]
+fn runs_without_macro() {
+    assert_eq!(greet_without_macro(), "Hello from task!");
+}
+
+#[cfg(feature = "solutions")]
+#[tokio::test]
+async fn spawns_like_the_solution() {
+    assert_eq!(
+        spawn_greeting().await.expect("Task panicked"),
+        solutions::spawn_greeting().await.expect("Task panicked")
+    );
+    for n in [0, 1, 100] {
+        assert_eq!(spawn_many(n).await, solutions::spawn_many(n).await);
+    }
+}
+
+#[cfg(feature = "solutions")]
+#[test]
+fn greets_like_the_solution() {
+    assert_eq!(greet_without_macro(), solutions::greet_without_macro());
+}
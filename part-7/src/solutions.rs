@@ -0,0 +1,31 @@
+//! Part 7, with every exercise implemented. Only built with `--features solutions`.
+
+use tokio::task::JoinHandle;
+
+/// Spawns a task that returns the message `Hello from task!`
+pub fn spawn_greeting() -> JoinHandle<String> {
+    tokio::spawn(async { String::from("Hello from task!") })
+}
+
+/// Spawns `n` tasks, where task number `i` returns `i`.
+/// Waits for all of them and returns their results in the order they were spawned.
+pub async fn spawn_many(n: usize) -> Vec<usize> {
+    // Spawn every task first, so they all get to run at the same time
+    let handles: Vec<_> = (0..n).map(|i| tokio::spawn(async move { i })).collect();
+
+    let mut results = Vec::with_capacity(n);
+    for handle in handles {
+        results.push(handle.await.expect("Task panicked"));
+    }
+    results
+}
+
+/// Does the same as `main`'s first line, but without `#[tokio::main]`:
+/// creates a runtime by hand and uses it to run `spawn_greeting` to completion.
+pub fn greet_without_macro() -> String {
+    let runtime = tokio::runtime::Runtime::new().expect("Couldn't create runtime");
+    // `spawn_greeting` has to be called inside the runtime, or there's nowhere to spawn the task
+    runtime
+        .block_on(async { spawn_greeting().await })
+        .expect("Task panicked")
+}
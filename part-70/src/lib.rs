@@ -0,0 +1,98 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    thread::{self, yield_now},
+};
+
+use part_54::ConcurrentMap;
+use proptest::{collection::vec, prelude::*};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How many different keys the operations use. Few keys means the threads often work on the same ones.
+pub const KEYS: u8 = 8;
+
+/// An operation on a map. Inserts don't say which value, since that's picked when they're run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Insert(u8),
+    Get(u8),
+    Remove(u8),
+}
+
+/// What an operation returned
+pub type Outcome = Option<u64>;
+
+/// Any single operation
+pub fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        (0..KEYS).prop_map(Op::Insert),
+        (0..KEYS).prop_map(Op::Get),
+        (0..KEYS).prop_map(Op::Remove),
+    ]
+}
+
+/// A list of operations for each of 1 to 4 threads
+pub fn threads() -> impl Strategy<Value = Vec<Vec<Op>>> {
+    vec(vec(op(), 0..50), 1..=4)
+}
+
+/// The value inserted by the operation at `index` in the list of `thread`, which makes every inserted value unique
+pub fn value(thread: usize, index: usize) -> u64 {
+    (thread as u64) << 32 | index as u64
+}
+
+/// What's left in `map`, for every key
+pub fn remaining(map: &impl ConcurrentMap<u8, u64>) -> Vec<(u8, u64)> {
+    (0..KEYS)
+        .filter_map(|key| map.get(&key).map(|value| (key, value)))
+        .collect()
+}
+
+/// Looks up the value an insert replaces and inserts the new one under separate locks,
+/// so two inserts at once can both replace the same value
+#[derive(Debug, Default)]
+pub struct RacyMap(Mutex<HashMap<u8, u64>>);
+
+impl ConcurrentMap<u8, u64> for RacyMap {
+    fn insert(&self, key: u8, value: u64) -> Option<u64> {
+        let old = self.0.lock().unwrap().get(&key).copied();
+        // Gives other threads a chance to get in between
+        yield_now();
+        self.0.lock().unwrap().insert(key, value);
+        old
+    }
+
+    fn get(&self, key: &u8) -> Option<u64> {
+        self.0.lock().unwrap().get(key).copied()
+    }
+
+    fn remove(&self, key: &u8) -> Option<u64> {
+        self.0.lock().unwrap().remove(key)
+    }
+
+    fn len(&self) -> usize {
+        self.0.lock().unwrap().len()
+    }
+}
+
+/// Runs every list of operations in `threads` on its own thread, all at the same time.
+/// Inserts insert `value(thread, index)`. Returns what every operation returned, in the same shape as `threads`.
+pub fn run_concurrently(
+    map: &impl ConcurrentMap<u8, u64>,
+    threads: &[Vec<Op>],
+) -> Vec<Vec<Outcome>> {
+    todo!()
+}
+
+/// Checks that no value was lost, duplicated or made up. Every inserted value must have come out exactly once:
+/// by being replaced by another insert, by being removed, or by being left in the map at the end, in `remaining`.
+/// Gets, inserts and removes may only return values which were inserted for the same key.
+pub fn check_conservation(
+    threads: &[Vec<Op>],
+    outcomes: &[Vec<Outcome>],
+    remaining: &[(u8, u64)],
+) -> Result<(), String> {
+    todo!()
+}
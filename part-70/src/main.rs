@@ -0,0 +1,209 @@
+use part_54::{ConcurrentMap, ShardedMap};
+use part_70::{check_conservation, remaining, run_concurrently, threads, Op, RacyMap};
+use proptest::test_runner::{Config, TestCaseError, TestError, TestRunner};
+
+/// Runs the operations of `threads` concurrently on the map made by `new_map`, and checks the result
+fn conserves_values<M: ConcurrentMap<u8, u64>>(
+    new_map: impl Fn() -> M,
+    threads: &[Vec<Op>],
+) -> Result<(), TestCaseError> {
+    let map = new_map();
+    let outcomes = run_concurrently(&map, threads);
+    let remaining = remaining(&map);
+    check_conservation(threads, &outcomes, &remaining).map_err(TestCaseError::fail)?;
+    proptest::prop_assert_eq!(map.len(), remaining.len());
+    Ok(())
+}
+
+/// Property tests `new_map` with many random operations, and shows the smallest failing case if it finds one
+fn search<M: ConcurrentMap<u8, u64>>(name: &str, new_map: impl Fn() -> M) {
+    let mut runner = TestRunner::new(Config {
+        cases: 1000,
+        // Failures found here are expected, so there's no need to save them for the next run
+        failure_persistence: None,
+        ..Config::default()
+    });
+    match runner.run(&threads(), |threads| conserves_values(&new_map, &threads)) {
+        Ok(()) => println!("{name}: no problems found"),
+        Err(TestError::Fail(reason, threads)) => {
+            println!("{name}: {reason}, with these operations on each thread:");
+            for ops in threads {
+                println!("    {ops:?}");
+            }
+        }
+        Err(TestError::Abort(reason)) => println!("{name}: gave up, {reason}"),
+    }
+}
+
+fn main() {
+    search("Sharded map", || ShardedMap::with_shards(4));
+    search("Racy map", RacyMap::default);
+}
+
+#[test]
+fn accepts_sequential_operations() {
+    use part_70::value;
+
+    let threads = [vec![
+        Op::Insert(1),
+        Op::Insert(1),
+        Op::Get(1),
+        Op::Remove(1),
+    ]];
+    let outcomes = [vec![
+        None,
+        Some(value(0, 0)),
+        Some(value(0, 1)),
+        Some(value(0, 1)),
+    ]];
+    assert_eq!(check_conservation(&threads, &outcomes, &[]), Ok(()));
+}
+
+#[test]
+fn accepts_values_left_in_the_map() {
+    use part_70::value;
+
+    let threads = [vec![Op::Insert(1)], vec![Op::Insert(2), Op::Get(1)]];
+    let outcomes = [vec![None], vec![None, Some(value(0, 0))]];
+    let remaining = [(1, value(0, 0)), (2, value(1, 0))];
+    assert_eq!(check_conservation(&threads, &outcomes, &remaining), Ok(()));
+}
+
+#[test]
+fn finds_lost_values() {
+    use part_70::value;
+
+    // The second insert should have replaced the first value
+    let threads = [vec![Op::Insert(1), Op::Insert(1)]];
+    let outcomes = [vec![None, None]];
+    let remaining = [(1, value(0, 1))];
+    assert!(check_conservation(&threads, &outcomes, &remaining).is_err());
+}
+
+#[test]
+fn finds_duplicated_values() {
+    use part_70::value;
+
+    // Both inserts replaced the first value
+    let threads = [
+        vec![Op::Insert(1)],
+        vec![Op::Insert(1)],
+        vec![Op::Insert(1)],
+    ];
+    let outcomes = [vec![None], vec![Some(value(0, 0))], vec![Some(value(0, 0))]];
+    let remaining = [(1, value(2, 0))];
+    assert!(check_conservation(&threads, &outcomes, &remaining).is_err());
+
+    // Removed, but still there
+    let threads = [vec![Op::Insert(1), Op::Remove(1)]];
+    let outcomes = [vec![None, Some(value(0, 0))]];
+    let remaining = [(1, value(0, 0))];
+    assert!(check_conservation(&threads, &outcomes, &remaining).is_err());
+}
+
+#[test]
+fn finds_made_up_values() {
+    let threads = [vec![Op::Get(1)]];
+    assert!(check_conservation(&threads, &[vec![Some(42)]], &[]).is_err());
+    assert!(check_conservation(&[vec![]], &[vec![]], &[(1, 42)]).is_err());
+}
+
+#[test]
+fn finds_values_under_the_wrong_key() {
+    use part_70::value;
+
+    let threads = [vec![Op::Insert(1), Op::Get(2)]];
+    let outcomes = [vec![None, Some(value(0, 0))]];
+    let remaining = [(1, value(0, 0))];
+    assert!(check_conservation(&threads, &outcomes, &remaining).is_err());
+    assert!(check_conservation(&threads, &[vec![None, None]], &[(2, value(0, 0))]).is_err());
+}
+
+#[test]
+fn runs_every_operation() {
+    use part_54::LockedMap;
+    use part_70::value;
+
+    let threads = [vec![Op::Insert(1), Op::Get(1), Op::Remove(1), Op::Get(1)]];
+    let outcomes = run_concurrently(&LockedMap::new(), &threads);
+    assert_eq!(
+        outcomes,
+        [vec![None, Some(value(0, 0)), Some(value(0, 0)), None]]
+    );
+}
+
+/// Counts how many of them are alive, to check that none are leaked or dropped twice
+#[cfg(test)]
+struct Tracked(std::sync::Arc<std::sync::atomic::AtomicUsize>);
+
+#[cfg(test)]
+impl Tracked {
+    fn new(alive: &std::sync::Arc<std::sync::atomic::AtomicUsize>) -> Self {
+        alive.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        Self(alive.clone())
+    }
+}
+
+#[cfg(test)]
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, std::sync::atomic::Ordering::Relaxed);
+    }
+}
+
+proptest::proptest! {
+    #[test]
+    fn locked_map_conserves_values(threads in threads()) {
+        conserves_values(part_54::LockedMap::new, &threads)?;
+    }
+
+    #[test]
+    fn sharded_map_conserves_values(threads in threads(), shards in 1usize..8) {
+        conserves_values(|| ShardedMap::with_shards(shards), &threads)?;
+    }
+
+    #[test]
+    fn ring_buffer_delivers_everything_in_order(
+        capacity in 1usize..16,
+        items in proptest::collection::vec(proptest::prelude::any::<u64>(), 0..500),
+    ) {
+        let (mut producer, mut consumer) = part_45::ring_buffer(capacity);
+        let received = std::thread::scope(|s| {
+            s.spawn(|| {
+                for &item in &items {
+                    while producer.push(item).is_err() {
+                        std::thread::yield_now();
+                    }
+                }
+            });
+            let mut received = Vec::with_capacity(items.len());
+            while received.len() < items.len() {
+                match consumer.pop() {
+                    Some(item) => received.push(item),
+                    None => std::thread::yield_now(),
+                }
+            }
+            received
+        });
+        proptest::prop_assert_eq!(received, items);
+    }
+
+    #[test]
+    fn ring_buffer_drops_what_is_left(capacity in 1usize..16, pushes in 0usize..32, pops in 0usize..32) {
+        let alive = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let (mut producer, mut consumer) = part_45::ring_buffer(capacity);
+        for _ in 0..pushes {
+            // Whatever doesn't fit is handed back, and dropped here
+            _ = producer.push(Tracked::new(&alive));
+        }
+        for _ in 0..pops {
+            drop(consumer.pop());
+        }
+        proptest::prop_assert_eq!(
+            alive.load(std::sync::atomic::Ordering::Relaxed),
+            pushes.min(capacity).saturating_sub(pops)
+        );
+        drop((producer, consumer));
+        proptest::prop_assert_eq!(alive.load(std::sync::atomic::Ordering::Relaxed), 0);
+    }
+}
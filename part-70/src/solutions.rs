@@ -0,0 +1,108 @@
+//! Part 70, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    collections::HashMap,
+    thread::{self},
+};
+
+use part_54::ConcurrentMap;
+
+use super::{value, Op, Outcome};
+
+/// Runs every list of operations in `threads` on its own thread, all at the same time.
+/// Inserts insert `value(thread, index)`. Returns what every operation returned, in the same shape as `threads`.
+pub fn run_concurrently(
+    map: &impl ConcurrentMap<u8, u64>,
+    threads: &[Vec<Op>],
+) -> Vec<Vec<Outcome>> {
+    thread::scope(|s| {
+        let handles: Vec<_> = threads
+            .iter()
+            .enumerate()
+            .map(|(thread, ops)| {
+                s.spawn(move || {
+                    ops.iter()
+                        .enumerate()
+                        .map(|(index, &op)| match op {
+                            Op::Insert(key) => map.insert(key, value(thread, index)),
+                            Op::Get(key) => map.get(&key),
+                            Op::Remove(key) => map.remove(&key),
+                        })
+                        .collect()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    })
+}
+
+/// Checks that no value was lost, duplicated or made up. Every inserted value must have come out exactly once:
+/// by being replaced by another insert, by being removed, or by being left in the map at the end, in `remaining`.
+/// Gets, inserts and removes may only return values which were inserted for the same key.
+pub fn check_conservation(
+    threads: &[Vec<Op>],
+    outcomes: &[Vec<Outcome>],
+    remaining: &[(u8, u64)],
+) -> Result<(), String> {
+    // Which key every value was inserted for
+    let inserted: HashMap<u64, u8> = threads
+        .iter()
+        .enumerate()
+        .flat_map(|(thread, ops)| {
+            ops.iter()
+                .enumerate()
+                .filter_map(move |(index, &op)| match op {
+                    Op::Insert(key) => Some((value(thread, index), key)),
+                    _ => None,
+                })
+        })
+        .collect();
+    let check_key = |value: u64, key: u8| match inserted.get(&value) {
+        Some(&inserted_key) if inserted_key == key => Ok(()),
+        Some(inserted_key) => Err(format!(
+            "{value:#x} was inserted for key {inserted_key}, but came out of key {key}"
+        )),
+        None => Err(format!(
+            "{value:#x} came out of key {key}, but was never inserted"
+        )),
+    };
+
+    // How many times every value came out, not counting gets, which leave it in the map
+    let mut came_out: HashMap<u64, usize> = HashMap::new();
+    for (ops, outcomes) in threads.iter().zip(outcomes) {
+        if ops.len() != outcomes.len() {
+            return Err(format!(
+                "{} operations had {} outcomes",
+                ops.len(),
+                outcomes.len()
+            ));
+        }
+        for (&op, &outcome) in ops.iter().zip(outcomes) {
+            let (Op::Insert(key) | Op::Get(key) | Op::Remove(key)) = op;
+            if let Some(value) = outcome {
+                check_key(value, key)?;
+                if !matches!(op, Op::Get(_)) {
+                    *came_out.entry(value).or_default() += 1;
+                }
+            }
+        }
+    }
+    for &(key, value) in remaining {
+        check_key(value, key)?;
+        *came_out.entry(value).or_default() += 1;
+    }
+
+    let mut values: Vec<_> = inserted.keys().copied().collect();
+    values.sort();
+    for value in values {
+        match came_out.get(&value).copied().unwrap_or(0) {
+            1 => {}
+            0 => return Err(format!("{value:#x} was lost")),
+            times => return Err(format!("{value:#x} came out {times} times")),
+        }
+    }
+    Ok(())
+}
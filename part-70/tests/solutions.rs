@@ -0,0 +1,83 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-70 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_54::LockedMap;
+use part_70::{check_conservation, remaining, run_concurrently, solutions, Op, KEYS};
+
+/// Lists of operations for `threads` threads, as both the exercise's and the solution's operations
+fn scripts(threads: usize, seed: u64) -> (Vec<Vec<Op>>, Vec<Vec<Op>>) {
+    let kinds = common::datagen::numbers_below(threads * 40, 3, seed);
+    let keys = common::datagen::numbers_below(threads * 40, KEYS.into(), seed.wrapping_add(1));
+    let ops: Vec<(u64, u8)> = kinds
+        .into_iter()
+        .zip(keys.into_iter().map(|key| key as u8))
+        .collect();
+    let ours = ops
+        .chunks(40)
+        .map(|ops| {
+            ops.iter()
+                .map(|&(kind, key)| match kind {
+                    0 => Op::Insert(key),
+                    1 => Op::Get(key),
+                    _ => Op::Remove(key),
+                })
+                .collect()
+        })
+        .collect();
+    let theirs = ops
+        .chunks(40)
+        .map(|ops| {
+            ops.iter()
+                .map(|&(kind, key)| match kind {
+                    0 => Op::Insert(key),
+                    1 => Op::Get(key),
+                    _ => Op::Remove(key),
+                })
+                .collect()
+        })
+        .collect();
+    (ours, theirs)
+}
+
+#[test]
+fn runs_like_the_solution() {
+    // On a single thread, the operations can only go one way
+    let mut rng = common::rng::for_test();
+    for _ in 0..5 {
+        let (ours, theirs) = scripts(1, rng.seed());
+        let (map, expected) = (LockedMap::new(), LockedMap::new());
+        assert_eq!(
+            run_concurrently(&map, &ours),
+            solutions::run_concurrently(&expected, &theirs)
+        );
+        assert_eq!(remaining(&map), remaining(&expected));
+    }
+}
+
+#[test]
+fn checks_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for threads in [1, 2, 4] {
+        let (ours, theirs) = scripts(threads, rng.seed());
+        let map = LockedMap::new();
+        let outcomes = solutions::run_concurrently(&map, &theirs);
+        let left = remaining(&map);
+        assert_eq!(
+            check_conservation(&ours, &outcomes, &left).is_ok(),
+            solutions::check_conservation(&theirs, &outcomes, &left).is_ok(),
+            "What {threads} threads did with a map that conserves values"
+        );
+
+        // Losing what was left in the map, or making up a value
+        assert_eq!(
+            check_conservation(&ours, &outcomes, &[]).is_ok(),
+            solutions::check_conservation(&theirs, &outcomes, &[]).is_ok()
+        );
+        let made_up = [(0, u64::MAX)];
+        assert_eq!(
+            check_conservation(&ours, &outcomes, &made_up).is_ok(),
+            solutions::check_conservation(&theirs, &outcomes, &made_up).is_ok()
+        );
+    }
+}
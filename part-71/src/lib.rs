@@ -0,0 +1,116 @@
+use std::{
+    cell::UnsafeCell,
+    hint::spin_loop,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    thread,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How many times each thread counts. Miri is very slow, so it gets much less to do.
+pub const INCREMENTS: u64 = if cfg!(miri) { 10 } else { 100_000 };
+
+static mut COUNTER: u64 = 0;
+
+/// Lets `threads` threads each increment a `static mut` `increments` times.
+/// They all write to it at the same time without any synchronization, which is a data race,
+/// and so undefined behavior.
+pub fn static_mut_count(threads: usize, increments: u64) -> u64 {
+    // SAFETY: none, this is the bug
+    unsafe { COUNTER = 0 };
+    thread::scope(|s| {
+        for id in 0..threads {
+            s.spawn(move || {
+                let _worker = common::span::worker(id);
+                for _ in 0..increments {
+                    unsafe { COUNTER += 1 };
+                }
+            });
+        }
+    });
+    unsafe { COUNTER }
+}
+
+/// Like `static_mut_count`, but without `unsafe`
+pub fn atomic_count(threads: usize, increments: u64) -> u64 {
+    todo!()
+}
+
+/// A raw pointer which claims to be safe to send to other threads
+struct SendPtr(*mut u64);
+
+// SAFETY: none, this is what lets the bug through
+unsafe impl Send for SendPtr {}
+
+impl SendPtr {
+    /// Going through a method makes closures capture the whole `SendPtr`, not just the pointer inside
+    fn get(&self) -> *mut u64 {
+        self.0
+    }
+}
+
+/// Sums `numbers` by letting `threads` threads each add their chunk to the same total, through a raw pointer.
+/// They write to it at the same time, which is a data race.
+pub fn raw_pointer_sum(numbers: &[u64], threads: usize) -> u64 {
+    let mut total = 0;
+    let pointer = &mut total as *mut u64;
+    thread::scope(|s| {
+        for (id, chunk) in numbers
+            .chunks(numbers.len().div_ceil(threads).max(1))
+            .enumerate()
+        {
+            let total = SendPtr(pointer);
+            s.spawn(move || {
+                let _worker = common::span::worker(id);
+                for number in chunk {
+                    // SAFETY: none, every thread writes here at the same time
+                    unsafe { *total.get() += number };
+                }
+            });
+        }
+    });
+    total
+}
+
+/// Like `raw_pointer_sum`, but without `unsafe`
+pub fn chunked_sum(numbers: &[u64], threads: usize) -> u64 {
+    todo!()
+}
+
+/// Passes a single message from one thread to another, with a flag that says when it's there
+#[derive(Debug, Default)]
+pub struct Mailbox {
+    ready: AtomicBool,
+    message: UnsafeCell<u64>,
+}
+
+// SAFETY: the message is only written before `ready` is set, and only read after,
+// as long as setting and checking `ready` synchronizes the two
+unsafe impl Sync for Mailbox {}
+
+impl Mailbox {
+    /// Sets the flag with `Relaxed`, which doesn't make the message visible to whoever sees the flag.
+    /// Reading the message after seeing the flag is a data race.
+    pub fn send_relaxed(&self, message: u64) {
+        unsafe { *self.message.get() = message };
+        self.ready.store(true, Ordering::Relaxed);
+    }
+
+    pub fn receive_relaxed(&self) -> u64 {
+        while !self.ready.load(Ordering::Relaxed) {
+            spin_loop();
+        }
+        unsafe { *self.message.get() }
+    }
+
+    /// Like `send_relaxed`, but correct. May only be called once.
+    pub fn send(&self, message: u64) {
+        todo!()
+    }
+
+    /// Waits for the message, like `receive_relaxed`, but correct
+    pub fn receive(&self) -> u64 {
+        todo!()
+    }
+}
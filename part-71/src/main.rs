@@ -0,0 +1,85 @@
+use std::thread;
+
+use part_71::{raw_pointer_sum, static_mut_count, Mailbox, INCREMENTS};
+
+const THREADS: usize = 4;
+
+/// Runs the broken versions. Run with `cargo +nightly miri run -p part-71` to see Miri catch them.
+fn main() {
+    common::init_tracing();
+    let args = common::cli::Cli::new("Counts and sums with unsafe code, racing and not")
+        .threads(THREADS)
+        .parse();
+    let threads = args.threads();
+    println!(
+        "static mut counter: {} out of {}",
+        static_mut_count(threads, INCREMENTS),
+        threads as u64 * INCREMENTS
+    );
+
+    let numbers: Vec<u64> = (1..=INCREMENTS).collect();
+    println!(
+        "Raw pointer sum: {} out of {}",
+        raw_pointer_sum(&numbers, threads),
+        numbers.iter().sum::<u64>()
+    );
+
+    let mailbox = Mailbox::default();
+    let received = thread::scope(|s| {
+        s.spawn(|| mailbox.send_relaxed(42));
+        s.spawn(|| mailbox.receive_relaxed()).join().unwrap()
+    });
+    println!("Relaxed mailbox: received {received}");
+}
+
+#[test]
+fn atomic_count_counts_everything() {
+    use part_71::atomic_count;
+
+    assert_eq!(
+        atomic_count(THREADS, INCREMENTS),
+        THREADS as u64 * INCREMENTS
+    );
+}
+
+#[test]
+fn atomic_count_with_one_thread() {
+    use part_71::atomic_count;
+
+    assert_eq!(atomic_count(1, INCREMENTS), INCREMENTS);
+    assert_eq!(atomic_count(THREADS, 0), 0);
+}
+
+#[test]
+fn chunked_sum_sums_everything() {
+    use part_71::chunked_sum;
+
+    let numbers: Vec<u64> = (1..=INCREMENTS).collect();
+    assert_eq!(
+        chunked_sum(&numbers, THREADS),
+        INCREMENTS * (INCREMENTS + 1) / 2
+    );
+}
+
+#[test]
+fn chunked_sum_handles_uneven_and_empty_input() {
+    use part_71::chunked_sum;
+
+    assert_eq!(chunked_sum(&[], THREADS), 0);
+    assert_eq!(chunked_sum(&[1, 2, 3], THREADS), 6);
+    assert_eq!(chunked_sum(&[1, 2, 3, 4, 5], 2), 15);
+}
+
+#[test]
+fn mailbox_delivers_the_message() {
+    // Miri explores different interleavings on every run, so give it a few
+    for message in 0..if cfg!(miri) { 5 } else { 1000 } {
+        let mailbox = Mailbox::default();
+        let received = thread::scope(|s| {
+            let receiver = s.spawn(|| mailbox.receive());
+            s.spawn(|| mailbox.send(message));
+            receiver.join().unwrap()
+        });
+        assert_eq!(received, message);
+    }
+}
@@ -0,0 +1,81 @@
+//! Part 71, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    cell::UnsafeCell,
+    hint::spin_loop,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    thread,
+};
+
+/// Like `static_mut_count`, but without `unsafe`
+pub fn atomic_count(threads: usize, increments: u64) -> u64 {
+    let counter = AtomicU64::new(0);
+    thread::scope(|s| {
+        for _ in 0..threads {
+            s.spawn(|| {
+                for _ in 0..increments {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+    counter.into_inner()
+}
+
+/// Like `raw_pointer_sum`, but without `unsafe`
+pub fn chunked_sum(numbers: &[u64], threads: usize) -> u64 {
+    thread::scope(|s| {
+        let handles: Vec<_> = numbers
+            .chunks(numbers.len().div_ceil(threads).max(1))
+            .map(|chunk| s.spawn(move || chunk.iter().sum::<u64>()))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .sum()
+    })
+}
+
+/// Passes a single message from one thread to another, with a flag that says when it's there
+#[derive(Debug, Default)]
+pub struct Mailbox {
+    ready: AtomicBool,
+    message: UnsafeCell<u64>,
+}
+
+// SAFETY: the message is only written before `ready` is set, and only read after,
+// as long as setting and checking `ready` synchronizes the two
+unsafe impl Sync for Mailbox {}
+
+impl Mailbox {
+    /// Sets the flag with `Relaxed`, which doesn't make the message visible to whoever sees the flag.
+    /// Reading the message after seeing the flag is a data race.
+    pub fn send_relaxed(&self, message: u64) {
+        unsafe { *self.message.get() = message };
+        self.ready.store(true, Ordering::Relaxed);
+    }
+
+    pub fn receive_relaxed(&self) -> u64 {
+        while !self.ready.load(Ordering::Relaxed) {
+            spin_loop();
+        }
+        unsafe { *self.message.get() }
+    }
+
+    /// Like `send_relaxed`, but correct. May only be called once.
+    pub fn send(&self, message: u64) {
+        // SAFETY: only the sender writes the message, and only before setting `ready`
+        unsafe { *self.message.get() = message };
+        // Makes the write above visible to whoever sees `ready` with `Acquire`
+        self.ready.store(true, Ordering::Release);
+    }
+
+    /// Waits for the message, like `receive_relaxed`, but correct
+    pub fn receive(&self) -> u64 {
+        while !self.ready.load(Ordering::Acquire) {
+            spin_loop();
+        }
+        // SAFETY: seeing `ready` with `Acquire` means the message has been written, and won't be again
+        unsafe { *self.message.get() }
+    }
+}
@@ -0,0 +1,50 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-71 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::thread;
+
+use part_71::{atomic_count, chunked_sum, solutions, Mailbox, INCREMENTS};
+
+#[test]
+fn counts_like_the_solution() {
+    for threads in [1, 2, 8] {
+        assert_eq!(
+            atomic_count(threads, INCREMENTS),
+            solutions::atomic_count(threads, INCREMENTS),
+            "{threads} threads"
+        );
+    }
+}
+
+#[test]
+fn sums_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let numbers =
+        common::datagen::numbers_below(if cfg!(miri) { 100 } else { 10_000 }, 1000, rng.seed());
+    for threads in [1, 3, 8] {
+        assert_eq!(
+            chunked_sum(&numbers, threads),
+            solutions::chunked_sum(&numbers, threads),
+            "{threads} threads"
+        );
+    }
+    assert_eq!(chunked_sum(&[], 4), solutions::chunked_sum(&[], 4));
+}
+
+#[test]
+fn passes_messages_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for message in common::datagen::numbers(if cfg!(miri) { 2 } else { 50 }, rng.seed()) {
+        let (mailbox, expected) = (Mailbox::default(), solutions::Mailbox::default());
+        let (received, expected) = thread::scope(|s| {
+            let receiver = s.spawn(|| mailbox.receive());
+            let expected_receiver = s.spawn(|| expected.receive());
+            // The solution's receiver goes first, so it isn't left waiting if `send` panics
+            expected.send(message);
+            mailbox.send(message);
+            (receiver.join().unwrap(), expected_receiver.join().unwrap())
+        });
+        assert_eq!(received, expected);
+    }
+}
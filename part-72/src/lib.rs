@@ -0,0 +1,157 @@
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    future::poll_fn,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc::{self, RecvError, SendError, TryRecvError},
+        Mutex, OnceLock,
+    },
+    task::{Poll, Waker},
+    thread,
+};
+
+#[cfg(target_arch = "wasm32")]
+pub mod web;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Tries out some of the concurrency primitives from `std`, and reports which of them work here.
+/// Some others, like `thread::sleep` and `Instant::now`, panic in the browser, and a panic there can't be caught.
+pub fn probe() -> Vec<(&'static str, Result<(), String>)> {
+    vec![
+        (
+            "thread::Builder::spawn",
+            thread::Builder::new()
+                .spawn(|| {})
+                .map_err(|error| error.to_string())
+                .and_then(|handle| handle.join().map_err(|_| "panicked".to_string())),
+        ),
+        (
+            "thread::available_parallelism",
+            thread::available_parallelism()
+                .map(|_| ())
+                .map_err(|error| error.to_string()),
+        ),
+        ("Mutex", {
+            let mutex = Mutex::new(0);
+            *mutex.lock().unwrap() += 1;
+            Ok(())
+        }),
+        ("AtomicU64", {
+            let atomic = AtomicU64::new(0);
+            atomic.fetch_add(1, Ordering::Relaxed);
+            Ok(())
+        }),
+        ("OnceLock", {
+            let once = OnceLock::new();
+            once.get_or_init(|| 42);
+            Ok(())
+        }),
+        ("mpsc::channel on one thread", {
+            let (sender, receiver) = mpsc::channel();
+            sender.send(42).unwrap();
+            receiver
+                .recv()
+                .map(|_| ())
+                .map_err(|error| error.to_string())
+        }),
+    ]
+}
+
+/// What the senders and the receiver share
+#[derive(Debug)]
+struct Shared<T> {
+    messages: VecDeque<T>,
+    senders: usize,
+    receiver: bool,
+    /// Wakes the receiver when it's waiting for a message
+    waker: Option<Waker>,
+}
+
+impl<T> Shared<T> {
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Sends messages to the `Receiver`, like `mpsc::Sender`, but only from the same thread.
+/// In the browser, this is what the callbacks for messages from web workers use.
+#[derive(Debug)]
+pub struct Sender<T>(Rc<RefCell<Shared<T>>>);
+
+/// Receives messages from the `Sender`s. Has the same methods as `mpsc::Receiver`,
+/// except that `recv` can't block, since blocking the main thread of the browser isn't allowed, so it's async instead.
+#[derive(Debug)]
+pub struct Receiver<T>(Rc<RefCell<Shared<T>>>);
+
+/// A channel for a single thread, which has no way of blocking
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let shared = Rc::new(RefCell::new(Shared {
+        messages: VecDeque::new(),
+        senders: 1,
+        receiver: true,
+        waker: None,
+    }));
+    (Sender(shared.clone()), Receiver(shared))
+}
+
+impl<T> Sender<T> {
+    /// Fails if the receiver has been dropped
+    pub fn send(&self, message: T) -> Result<(), SendError<T>> {
+        let mut shared = self.0.borrow_mut();
+        if !shared.receiver {
+            return Err(SendError(message));
+        }
+        shared.messages.push_back(message);
+        shared.wake();
+        Ok(())
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.0.borrow_mut().senders += 1;
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut shared = self.0.borrow_mut();
+        shared.senders -= 1;
+        if shared.senders == 0 {
+            // The receiver might be waiting for a message which will never come
+            shared.wake();
+        }
+    }
+}
+
+impl<T> Receiver<T> {
+    /// Returns the next message if there is one, without waiting.
+    /// Fails with `Disconnected` once there are no messages left and every sender has been dropped.
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        todo!()
+    }
+
+    /// Waits for the next message.
+    /// Fails once there are no messages left and every sender has been dropped.
+    pub async fn recv(&self) -> Result<T, RecvError> {
+        todo!()
+    }
+
+    /// Every message that's already there, without waiting
+    pub fn try_iter(&self) -> impl Iterator<Item = T> + '_ {
+        std::iter::from_fn(|| self.try_recv().ok())
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.0.borrow_mut().receiver = false;
+    }
+}
@@ -0,0 +1,109 @@
+use part_72::probe;
+
+/// Run with `cargo run -p part-72` to see what works natively, and see the README for running it in the browser
+fn main() {
+    for (primitive, result) in probe() {
+        match result {
+            Ok(()) => println!("{primitive}: works"),
+            Err(error) => println!("{primitive}: {error}"),
+        }
+    }
+}
+
+#[test]
+fn try_recv_reports_empty_and_disconnected() {
+    use part_72::channel;
+    use std::sync::mpsc::TryRecvError;
+
+    let (sender, receiver) = channel();
+    assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+    sender.send(1).unwrap();
+    drop(sender);
+    assert_eq!(receiver.try_recv(), Ok(1));
+    assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+}
+
+#[test]
+fn messages_arrive_in_order_from_every_sender() {
+    use part_72::channel;
+
+    let (sender, receiver) = channel();
+    let other = sender.clone();
+    sender.send(1).unwrap();
+    other.send(2).unwrap();
+    sender.send(3).unwrap();
+    assert_eq!(receiver.try_iter().collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn stays_connected_while_any_sender_is_left() {
+    use part_72::channel;
+    use std::sync::mpsc::TryRecvError;
+
+    let (sender, receiver) = channel::<u64>();
+    let other = sender.clone();
+    drop(sender);
+    assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+    drop(other);
+    assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+}
+
+#[test]
+fn recv_waits_for_messages_from_other_tasks() {
+    use futures::{executor::LocalPool, task::LocalSpawnExt};
+    use part_72::channel;
+    use std::sync::mpsc::RecvError;
+
+    let mut pool = LocalPool::new();
+    let (sender, receiver) = channel();
+    let spawner = pool.spawner();
+    // The pool polls the receiver first, so it's already waiting when these are sent
+    spawner
+        .spawn_local(async move {
+            for message in 0..3 {
+                sender.send(message).unwrap();
+            }
+        })
+        .unwrap();
+
+    let received = pool.run_until(async move {
+        let mut received = Vec::new();
+        loop {
+            match receiver.recv().await {
+                Ok(message) => received.push(message),
+                Err(RecvError) => break received,
+            }
+        }
+    });
+    assert_eq!(received, [0, 1, 2]);
+}
+
+#[test]
+fn recv_wakes_up_when_the_last_sender_is_dropped() {
+    use futures::{executor::LocalPool, task::LocalSpawnExt};
+    use part_72::channel;
+    use std::sync::mpsc::RecvError;
+
+    let mut pool = LocalPool::new();
+    let (sender, receiver) = channel::<u64>();
+    pool.spawner()
+        .spawn_local(async move { drop(sender) })
+        .unwrap();
+    assert_eq!(pool.run_until(receiver.recv()), Err(RecvError));
+}
+
+#[test]
+fn send_fails_without_a_receiver() {
+    use part_72::channel;
+
+    let (sender, receiver) = channel();
+    drop(receiver);
+    assert_eq!(sender.send(42).unwrap_err().0, 42);
+}
+
+#[test]
+fn everything_works_natively() {
+    for (primitive, result) in probe() {
+        assert_eq!(result, Ok(()), "{primitive} should work");
+    }
+}
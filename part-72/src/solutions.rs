@@ -0,0 +1,115 @@
+//! Part 72, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    future::poll_fn,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc::{self, RecvError, TryRecvError},
+        Mutex, OnceLock,
+    },
+    task::Poll,
+    thread,
+};
+
+use super::{Sender, Shared};
+
+/// Tries out some of the concurrency primitives from `std`, and reports which of them work here.
+/// Some others, like `thread::sleep` and `Instant::now`, panic in the browser, and a panic there can't be caught.
+pub fn probe() -> Vec<(&'static str, Result<(), String>)> {
+    vec![
+        (
+            "thread::Builder::spawn",
+            thread::Builder::new()
+                .spawn(|| {})
+                .map_err(|error| error.to_string())
+                .and_then(|handle| handle.join().map_err(|_| "panicked".to_string())),
+        ),
+        (
+            "thread::available_parallelism",
+            thread::available_parallelism()
+                .map(|_| ())
+                .map_err(|error| error.to_string()),
+        ),
+        ("Mutex", {
+            let mutex = Mutex::new(0);
+            *mutex.lock().unwrap() += 1;
+            Ok(())
+        }),
+        ("AtomicU64", {
+            let atomic = AtomicU64::new(0);
+            atomic.fetch_add(1, Ordering::Relaxed);
+            Ok(())
+        }),
+        ("OnceLock", {
+            let once = OnceLock::new();
+            once.get_or_init(|| 42);
+            Ok(())
+        }),
+        ("mpsc::channel on one thread", {
+            let (sender, receiver) = mpsc::channel();
+            sender.send(42).unwrap();
+            receiver
+                .recv()
+                .map(|_| ())
+                .map_err(|error| error.to_string())
+        }),
+    ]
+}
+
+/// Receives messages from the `Sender`s. Has the same methods as `mpsc::Receiver`,
+/// except that `recv` can't block, since blocking the main thread of the browser isn't allowed, so it's async instead.
+#[derive(Debug)]
+pub struct Receiver<T>(Rc<RefCell<Shared<T>>>);
+
+/// A channel for a single thread, which has no way of blocking
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let shared = Rc::new(RefCell::new(Shared {
+        messages: VecDeque::new(),
+        senders: 1,
+        receiver: true,
+        waker: None,
+    }));
+    (Sender(shared.clone()), Receiver(shared))
+}
+
+impl<T> Receiver<T> {
+    /// Returns the next message if there is one, without waiting.
+    /// Fails with `Disconnected` once there are no messages left and every sender has been dropped.
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        let mut shared = self.0.borrow_mut();
+        match shared.messages.pop_front() {
+            Some(message) => Ok(message),
+            None if shared.senders == 0 => Err(TryRecvError::Disconnected),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// Waits for the next message.
+    /// Fails once there are no messages left and every sender has been dropped.
+    pub async fn recv(&self) -> Result<T, RecvError> {
+        poll_fn(|cx| match self.try_recv() {
+            Ok(message) => Poll::Ready(Ok(message)),
+            Err(TryRecvError::Disconnected) => Poll::Ready(Err(RecvError)),
+            Err(TryRecvError::Empty) => {
+                // Nothing can send in between, since everything runs on this thread
+                self.0.borrow_mut().waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    /// Every message that's already there, without waiting
+    pub fn try_iter(&self) -> impl Iterator<Item = T> + '_ {
+        std::iter::from_fn(|| self.try_recv().ok())
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.0.borrow_mut().receiver = false;
+    }
+}
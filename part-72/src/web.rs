@@ -0,0 +1,90 @@
+//! The browser side: offloads the computation from part 5 to web workers.
+//!
+//! Every worker runs its own instance of this module, starting from `www/worker.js`, and shares no memory with the page.
+//! All they can do is pass messages, which is why the results come back through a `Receiver`.
+
+use js_sys::{Array, Date};
+use part_5::{ComputationResult, Data};
+use wasm_bindgen::prelude::*;
+use web_sys::{DedicatedWorkerGlobalScope, MessageEvent, Worker, WorkerOptions, WorkerType};
+
+use crate::{channel, probe, Receiver};
+
+/// The script every worker starts with, relative to the page
+const WORKER_SCRIPT: &str = "./worker.js";
+
+/// Like `part_5::calculate`, which can't run here, since `thread::sleep` isn't supported.
+/// Busy waits for the same time instead.
+fn calculate(datum: Data) -> ComputationResult {
+    let start = Date::now();
+    while Date::now() - start < 500.0 {}
+    ComputationResult(datum.0 * 2)
+}
+
+/// Packs a number with the index of the datum it belongs to, for sending as a message
+fn encode(index: usize, number: u64) -> JsValue {
+    Array::of2(&JsValue::from(index), &JsValue::from(number)).into()
+}
+
+/// Unpacks a message made with `encode`
+fn decode(message: &JsValue) -> (usize, u64) {
+    let message = Array::from(message);
+    let index = message.get(0).as_f64().expect("Index should be a number") as usize;
+    let number = u64::try_from(message.get(1)).expect("Number should be a BigInt");
+    (index, number)
+}
+
+/// Runs in every worker. Calculates the result for every `encode`d datum it gets, and sends it back `encode`d the same way.
+/// Sends `null` once it's ready, since messages which arrive before then would be lost.
+#[wasm_bindgen]
+pub fn worker_main() {
+    let scope: DedicatedWorkerGlobalScope = js_sys::global().unchecked_into();
+    let reply = scope.clone();
+    let onmessage = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+        let (index, datum) = decode(&event.data());
+        let ComputationResult(result) = calculate(Data(datum));
+        reply
+            .post_message(&encode(index, result))
+            .expect("Page should accept results");
+    });
+    scope.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+    // The handler is needed for as long as the worker lives
+    onmessage.forget();
+    scope
+        .post_message(&JsValue::NULL)
+        .expect("Page should accept messages");
+}
+
+/// Spreads `data` over `workers` new web workers, which must be at least one.
+/// Returns the results along with the index of their datum, in whatever order they finish.
+/// The receiver is disconnected once all of them have arrived, and every worker is terminated when it's done.
+pub fn offload(
+    data: Vec<Data>,
+    workers: usize,
+) -> Result<Receiver<(usize, ComputationResult)>, JsValue> {
+    todo!()
+}
+
+/// What `probe` finds in the browser, one primitive per line
+#[wasm_bindgen]
+pub fn probe_report() -> String {
+    probe()
+        .into_iter()
+        .map(|(primitive, result)| match result {
+            Ok(()) => format!("{primitive}: works"),
+            Err(error) => format!("{primitive}: {error}"),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Calculates the results for the data `0..count` on `workers` web workers, in order
+#[wasm_bindgen]
+pub async fn run(count: usize, workers: usize) -> Result<Vec<u64>, JsValue> {
+    let receiver = offload((0..count as u64).map(Data).collect(), workers)?;
+    let mut results = vec![0; count];
+    while let Ok((index, ComputationResult(result))) = receiver.recv().await {
+        results[index] = result;
+    }
+    Ok(results)
+}
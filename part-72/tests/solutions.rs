@@ -0,0 +1,48 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-72 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use futures::{executor::LocalPool, task::LocalSpawnExt};
+use part_72::{channel, solutions};
+
+#[test]
+fn receives_like_the_solution() {
+    let (sender, receiver) = channel();
+    let (expected_sender, expected_receiver) = solutions::channel();
+    for message in 0..5 {
+        assert_eq!(receiver.try_recv(), expected_receiver.try_recv());
+        if message % 2 == 0 {
+            sender.send(message).unwrap();
+            expected_sender.send(message).unwrap();
+        }
+    }
+    drop((sender, expected_sender));
+    for _ in 0..4 {
+        assert_eq!(receiver.try_recv(), expected_receiver.try_recv());
+    }
+}
+
+#[test]
+fn waits_like_the_solution() {
+    let mut pool = LocalPool::new();
+    let (sender, receiver) = channel();
+    let (expected_sender, expected_receiver) = solutions::channel();
+    pool.spawner()
+        .spawn_local(async move {
+            for message in 0..3 {
+                sender.send(message).unwrap();
+                expected_sender.send(message).unwrap();
+            }
+        })
+        .unwrap();
+
+    let (received, expected) = pool.run_until(async move {
+        let (mut received, mut expected) = (Vec::new(), Vec::new());
+        for _ in 0..4 {
+            received.push(receiver.recv().await);
+            expected.push(expected_receiver.recv().await);
+        }
+        (received, expected)
+    });
+    assert_eq!(received, expected);
+}
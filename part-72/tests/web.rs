@@ -0,0 +1,39 @@
+//! Run these with `cargo test --target wasm32-unknown-unknown -p part-72 --test web`, see the README for setting it up
+#![cfg(target_arch = "wasm32")]
+
+use part_72::{channel, probe};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn threads_cannot_be_spawned() {
+    let results = probe();
+    let (_, spawn) = results
+        .iter()
+        .find(|(primitive, _)| *primitive == "thread::Builder::spawn")
+        .unwrap();
+    assert!(spawn.is_err());
+}
+
+#[wasm_bindgen_test]
+fn single_threaded_primitives_still_work() {
+    for (primitive, result) in probe() {
+        if !primitive.starts_with("thread::") {
+            assert_eq!(result, Ok(()), "{primitive} should work");
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+async fn recv_waits_for_messages_from_other_tasks() {
+    let (sender, receiver) = channel();
+    wasm_bindgen_futures::spawn_local(async move {
+        for message in 0..3 {
+            sender.send(message).unwrap();
+        }
+    });
+    let mut received = Vec::new();
+    while let Ok(message) = receiver.recv().await {
+        received.push(message);
+    }
+    assert_eq!(received, [0, 1, 2]);
+}
@@ -0,0 +1,22 @@
+import init, { probe_report, run } from "./pkg/part_72.js";
+
+await init();
+document.getElementById("probe").textContent = probe_report();
+
+const button = document.getElementById("run");
+const output = document.getElementById("output");
+button.addEventListener("click", async () => {
+  const count = Number(document.getElementById("count").value);
+  const workers = Number(document.getElementById("workers").value);
+  button.disabled = true;
+  output.textContent = "Calculating...";
+  const start = performance.now();
+  try {
+    const results = await run(count, workers);
+    const elapsed = Math.round(performance.now() - start);
+    output.textContent = `Got ${results.join(", ")} in ${elapsed} ms`;
+  } catch (error) {
+    output.textContent = `Failed: ${error}`;
+  }
+  button.disabled = false;
+});
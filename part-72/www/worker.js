@@ -0,0 +1,5 @@
+// Every web worker starts here, with its own instance of the module
+import init, { worker_main } from "./pkg/part_72.js";
+
+await init();
+worker_main();
@@ -0,0 +1,43 @@
+use rayon::prelude::*;
+
+#[cfg(feature = "gpu")]
+mod gpu;
+
+#[cfg(feature = "gpu")]
+pub use gpu::{workgroups, Gpu, GpuError, WORKGROUP_SIZE};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// The work done for every element: `rounds` times multiplying by `b` and adding `a`, wrapping around on overflow.
+/// More rounds means more work for the same amount of data. `shader.wgsl` does exactly the same on the GPU.
+pub fn kernel(a: u32, b: u32, rounds: u32) -> u32 {
+    let mut x = a;
+    for _ in 0..rounds {
+        x = x.wrapping_mul(b).wrapping_add(a);
+    }
+    x
+}
+
+/// `len` random numbers. The same `seed` always gives the same numbers.
+pub fn random_vector(len: usize, seed: u64) -> Vec<u32> {
+    common::datagen::numbers(len, seed)
+        .into_iter()
+        .map(|number| number as u32)
+        .collect()
+}
+
+/// Runs `kernel` for every pair of elements of `a` and `b`, one after another.
+/// Panics if they aren't the same length.
+pub fn serial_multiply(a: &[u32], b: &[u32], rounds: u32) -> Vec<u32> {
+    assert_eq!(a.len(), b.len(), "Vectors must be the same length");
+    a.iter()
+        .zip(b)
+        .map(|(&a, &b)| kernel(a, b, rounds))
+        .collect()
+}
+
+/// Does the same as `serial_multiply`, with rayon
+pub fn rayon_multiply(a: &[u32], b: &[u32], rounds: u32) -> Vec<u32> {
+    todo!()
+}
@@ -0,0 +1,101 @@
+use common::{bench::Comparison, cli::Cli};
+use part_73::{random_vector, rayon_multiply, serial_multiply};
+
+const LEN: usize = 1 << 20;
+
+/// Run with `cargo run --release -p part-73 --features gpu` to compare the CPU with the GPU
+fn main() {
+    let args = Cli::new("Multiplies vectors serially, with Rayon, and on the GPU")
+        .items(LEN)
+        .strategy()
+        .parse();
+    let len = args.items();
+    let (a, b) = (random_vector(len, 1), random_vector(len, 2));
+    #[cfg(feature = "gpu")]
+    let gpu = match part_73::Gpu::new() {
+        Ok(gpu) => {
+            println!("Using {}", gpu.name());
+            Some(gpu)
+        }
+        Err(error) => {
+            println!("Can't use the GPU: {error:?}");
+            None
+        }
+    };
+    #[cfg(not(feature = "gpu"))]
+    println!("Built without the gpu feature, so there's only the CPU");
+
+    // Little work per element, where moving the data dominates, and more of it
+    for rounds in [1, 256] {
+        println!("{len} elements, {rounds} rounds each");
+        let mut comparison = Comparison::new(5);
+        comparison
+            .bench("Serial", || serial_multiply(&a, &b, rounds))
+            .bench("Rayon", || rayon_multiply(&a, &b, rounds));
+        #[cfg(feature = "gpu")]
+        if let Some(gpu) = &gpu {
+            comparison.bench("GPU", || gpu.multiply(&a, &b, rounds).unwrap());
+        }
+        comparison.print();
+    }
+}
+
+/// Checks that `multiply` gives the same results as `serial_multiply`, including for lengths which don't split evenly
+#[cfg(test)]
+fn verify(multiply: impl Fn(&[u32], &[u32], u32) -> Vec<u32>) {
+    for len in [0, 1, 63, 64, 65, 10_000] {
+        let (a, b) = (random_vector(len, 1), random_vector(len, 2));
+        for rounds in [0, 1, 100] {
+            assert_eq!(
+                multiply(&a, &b, rounds),
+                serial_multiply(&a, &b, rounds),
+                "Wrong results for {len} elements with {rounds} rounds"
+            );
+        }
+    }
+}
+
+#[test]
+fn kernel_wraps_around() {
+    use part_73::kernel;
+
+    assert_eq!(kernel(3, 5, 0), 3);
+    assert_eq!(kernel(3, 5, 2), (3 * 5 + 3) * 5 + 3);
+    assert_eq!(kernel(u32::MAX, 2, 1), u32::MAX - 2);
+}
+
+#[test]
+fn rayon_matches_serial() {
+    verify(rayon_multiply);
+}
+
+#[test]
+#[should_panic(expected = "same length")]
+fn rayon_rejects_different_lengths() {
+    rayon_multiply(&[1, 2], &[3], 1);
+}
+
+#[cfg(feature = "gpu")]
+#[test]
+fn workgroups_cover_every_element() {
+    use part_73::{workgroups, WORKGROUP_SIZE};
+
+    assert_eq!(workgroups(0), 0);
+    assert_eq!(workgroups(1), 1);
+    assert_eq!(workgroups(WORKGROUP_SIZE as usize), 1);
+    assert_eq!(workgroups(WORKGROUP_SIZE as usize + 1), 2);
+    assert_eq!(workgroups(usize::MAX), u32::MAX);
+}
+
+#[cfg(feature = "gpu")]
+#[test]
+fn gpu_matches_serial() {
+    use part_73::{Gpu, GpuError};
+
+    let gpu = match Gpu::new() {
+        Ok(gpu) => gpu,
+        Err(GpuError::NoAdapter) => return println!("There's no GPU to test on"),
+        Err(error) => panic!("Couldn't use the GPU: {error:?}"),
+    };
+    verify(|a, b, rounds| gpu.multiply(a, b, rounds).unwrap());
+}
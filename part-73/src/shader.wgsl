@@ -0,0 +1,13 @@
+// The same as `kernel` in lib.rs. Every invocation of `main` computes one element of `result`.
+
+@group(0) @binding(0) var<storage, read> a: array<u32>;
+@group(0) @binding(1) var<storage, read> b: array<u32>;
+@group(0) @binding(2) var<storage, read_write> result: array<u32>;
+// Only the first number is used, the rest is padding
+@group(0) @binding(3) var<uniform> rounds: vec4<u32>;
+
+// Must be the same as `WORKGROUP_SIZE` in gpu.rs
+@compute @workgroup_size(64)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    // TODO: compute the element of `result` this invocation is for, which is `id.x`
+}
@@ -0,0 +1,20 @@
+//! Part 73, with every exercise implemented. Only built with `--features solutions`.
+
+use rayon::prelude::*;
+
+#[cfg(feature = "gpu")]
+pub use gpu::{workgroups, Gpu, GpuError, WORKGROUP_SIZE};
+
+use super::kernel;
+
+#[cfg(feature = "gpu")]
+mod gpu;
+
+/// Does the same as `serial_multiply`, with rayon
+pub fn rayon_multiply(a: &[u32], b: &[u32], rounds: u32) -> Vec<u32> {
+    assert_eq!(a.len(), b.len(), "Vectors must be the same length");
+    a.par_iter()
+        .zip(b)
+        .map(|(&a, &b)| kernel(a, b, rounds))
+        .collect()
+}
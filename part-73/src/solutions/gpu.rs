@@ -0,0 +1,165 @@
+use std::sync::mpsc;
+
+use wgpu::util::DeviceExt;
+
+/// How many invocations of the shader run together, which must be the same as `@workgroup_size` in `shader.wgsl`
+pub const WORKGROUP_SIZE: u32 = 64;
+
+/// Why the GPU couldn't be used
+#[derive(Debug)]
+pub enum GpuError {
+    /// There's no GPU, or none that wgpu can use
+    NoAdapter,
+    /// The GPU was found, but couldn't be opened
+    Device(wgpu::RequestDeviceError),
+    /// The vectors are longer than the GPU can handle at once
+    TooLong(usize),
+    /// The results couldn't be read back
+    Map(wgpu::BufferAsyncError),
+}
+
+/// How many workgroups of `WORKGROUP_SIZE` invocations it takes to have one invocation for each of `len` elements,
+/// or `u32::MAX` if that's more than fits
+pub fn workgroups(len: usize) -> u32 {
+    u32::try_from(len.div_ceil(WORKGROUP_SIZE as usize)).unwrap_or(u32::MAX)
+}
+
+/// A GPU which is ready to run the shader
+#[derive(Debug)]
+pub struct Gpu {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+    name: String,
+}
+
+impl Gpu {
+    /// Finds a GPU and compiles the shader for it
+    pub fn new() -> Result<Self, GpuError> {
+        // Most of wgpu is async, since the GPU works on its own, but there's nothing else to do in the meantime here
+        pollster::block_on(Self::connect())
+    }
+
+    async fn connect() -> Result<Self, GpuError> {
+        let instance = wgpu::Instance::default();
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                ..Default::default()
+            })
+            .await
+            .ok_or(GpuError::NoAdapter)?;
+        let (device, queue) = adapter
+            .request_device(
+                &wgpu::DeviceDescriptor {
+                    label: None,
+                    required_features: wgpu::Features::empty(),
+                    required_limits: adapter.limits(),
+                    memory_hints: wgpu::MemoryHints::Performance,
+                },
+                None,
+            )
+            .await
+            .map_err(GpuError::Device)?;
+        let module = device.create_shader_module(wgpu::include_wgsl!("shader.wgsl"));
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("kernel"),
+            layout: None,
+            module: &module,
+            entry_point: "main",
+            compilation_options: Default::default(),
+            cache: None,
+        });
+        Ok(Self {
+            device,
+            queue,
+            pipeline,
+            name: adapter.get_info().name,
+        })
+    }
+
+    /// What the GPU calls itself
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Does the same as `serial_multiply`, on the GPU.
+    /// This includes copying the vectors to the GPU, and the results back again.
+    pub fn multiply(&self, a: &[u32], b: &[u32], rounds: u32) -> Result<Vec<u32>, GpuError> {
+        assert_eq!(a.len(), b.len(), "Vectors must be the same length");
+        // Empty buffers can't be given to a shader
+        if a.is_empty() {
+            return Ok(Vec::new());
+        }
+        let (len, size) = (a.len(), std::mem::size_of_val(a) as u64);
+        let limits = self.device.limits();
+        if workgroups(len) > limits.max_compute_workgroups_per_dimension
+            || size > limits.max_storage_buffer_binding_size.into()
+        {
+            return Err(GpuError::TooLong(len));
+        }
+
+        let input = |label, contents: &[u32], usage| {
+            self.device
+                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some(label),
+                    contents: bytemuck::cast_slice(contents),
+                    usage,
+                })
+        };
+        let a = input("a", a, wgpu::BufferUsages::STORAGE);
+        let b = input("b", b, wgpu::BufferUsages::STORAGE);
+        let rounds = input("rounds", &[rounds, 0, 0, 0], wgpu::BufferUsages::UNIFORM);
+        let result = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("result"),
+            size,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        // The shader can't write to memory the CPU can read, so the result is copied over to this afterwards
+        let readback = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("readback"),
+            size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &self.pipeline.get_bind_group_layout(0),
+            // In the same order as the bindings in the shader
+            entries: &[&a, &b, &result, &rounds]
+                .into_iter()
+                .enumerate()
+                .map(|(binding, buffer)| wgpu::BindGroupEntry {
+                    binding: binding as u32,
+                    resource: buffer.as_entire_binding(),
+                })
+                .collect::<Vec<_>>(),
+        });
+        let mut encoder = self.device.create_command_encoder(&Default::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&Default::default());
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(workgroups(len), 1, 1);
+        }
+        encoder.copy_buffer_to_buffer(&result, 0, &readback, 0, size);
+        self.queue.submit([encoder.finish()]);
+
+        // The GPU says when the results are ready through a callback, which hands them over through a channel
+        let slice = readback.slice(..);
+        let (sender, receiver) = mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |mapped| {
+            let _ = sender.send(mapped);
+        });
+        self.device.poll(wgpu::Maintain::Wait);
+        receiver
+            .recv()
+            .expect("Mapping should finish after waiting for the GPU")
+            .map_err(GpuError::Map)?;
+        let results = bytemuck::cast_slice(&slice.get_mapped_range()).to_vec();
+        readback.unmap();
+        Ok(results)
+    }
+}
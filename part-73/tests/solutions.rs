@@ -0,0 +1,32 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-73 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_73::{random_vector, rayon_multiply, solutions};
+
+#[test]
+fn multiplies_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for len in [0, 1, 1000] {
+        let (a, b) = (random_vector(len, rng.seed()), random_vector(len, 74));
+        for rounds in [0, 1, 50] {
+            assert_eq!(
+                rayon_multiply(&a, &b, rounds),
+                solutions::rayon_multiply(&a, &b, rounds),
+                "{len} elements, {rounds} rounds"
+            );
+        }
+    }
+}
+
+#[cfg(feature = "gpu")]
+#[test]
+fn groups_like_the_solution() {
+    for len in [0, 1, 63, 64, 65, 100_000] {
+        assert_eq!(
+            part_73::workgroups(len),
+            solutions::workgroups(len),
+            "{len} elements"
+        );
+    }
+}
@@ -0,0 +1,18 @@
+// Swapping the `Rc` for an `Arc` takes care of the reference count, but not of the `RefCell`.
+// It keeps track of borrows without any synchronization, so two threads could both borrow it mutably.
+use std::{cell::RefCell, sync::Arc, thread};
+
+use part_74::{winner, Scores};
+
+fn main() {
+    let scores = Arc::new(RefCell::new(Scores::new()));
+    let handles: Vec<_> = (0..4)
+        .map(|game| {
+            let scores = scores.clone();
+            thread::spawn(move || *scores.borrow_mut().entry(winner(game)).or_default() += 1)
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
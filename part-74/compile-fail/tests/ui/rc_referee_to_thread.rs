@@ -0,0 +1,18 @@
+// A `Referee` holds an `Rc`, so it can't be sent to another thread either.
+// Two threads with clones of the same `Rc` could update its reference count at the same time.
+use std::{cell::RefCell, rc::Rc, thread};
+
+use part_74::{Referee, Scores};
+
+fn main() {
+    let scores = Rc::new(RefCell::new(Scores::new()));
+    let handles: Vec<_> = (0..4)
+        .map(|game| {
+            let referee = Referee::new(&scores);
+            thread::spawn(move || referee.record(game))
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
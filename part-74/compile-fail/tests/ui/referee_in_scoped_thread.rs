@@ -0,0 +1,16 @@
+// Scoped threads may borrow, but only what can be shared between threads.
+// Every thread with a `&Referee` could clone the `Rc` inside it.
+use std::{cell::RefCell, rc::Rc, thread};
+
+use part_74::{Referee, Scores};
+
+fn main() {
+    let scores = Rc::new(RefCell::new(Scores::new()));
+    let referee = Referee::new(&scores);
+    thread::scope(|s| {
+        for game in 0..4 {
+            let referee = &referee;
+            s.spawn(move || referee.record(game));
+        }
+    });
+}
@@ -0,0 +1,99 @@
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    rc::Rc,
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How many games every player has won
+pub type Scores = BTreeMap<&'static str, u64>;
+
+const PLAYERS: [&str; 4] = ["Ada", "Brendan", "Grace", "Linus"];
+
+/// How long it takes to judge a game
+pub const JUDGING: Duration = Duration::from_millis(2);
+
+/// Who won `game`. Takes a while to decide.
+pub fn winner(game: u64) -> &'static str {
+    thread::sleep(JUDGING);
+    PLAYERS[(game * 7 + game / 3) as usize % PLAYERS.len()]
+}
+
+/// Judges games and records the winners on a scoreboard shared with the other referees
+#[derive(Debug, Clone)]
+pub struct Referee {
+    scores: Rc<RefCell<Scores>>,
+}
+
+impl Referee {
+    pub fn new(scores: &Rc<RefCell<Scores>>) -> Self {
+        Self {
+            scores: scores.clone(),
+        }
+    }
+
+    pub fn record(&self, game: u64) {
+        *self.scores.borrow_mut().entry(winner(game)).or_default() += 1;
+    }
+}
+
+/// Lets `referees` referees take turns judging `games` games, on this thread. There must be at least one referee.
+pub fn rc_scoreboard(games: u64, referees: usize) -> Scores {
+    let scores = Rc::new(RefCell::new(Scores::new()));
+    let referees: Vec<_> = (0..referees).map(|_| Referee::new(&scores)).collect();
+    for game in 0..games {
+        referees[game as usize % referees.len()].record(game);
+    }
+    scores.take()
+}
+
+/// Like `Referee`, but for a scoreboard shared between threads
+#[derive(Debug, Clone)]
+pub struct SharedReferee {
+    scores: Arc<Mutex<Scores>>,
+}
+
+impl SharedReferee {
+    pub fn new(scores: &Arc<Mutex<Scores>>) -> Self {
+        Self {
+            scores: scores.clone(),
+        }
+    }
+
+    pub fn record(&self, game: u64) {
+        todo!()
+    }
+}
+
+/// Like `rc_scoreboard`, but every referee judges its games on its own thread, with a `SharedReferee`
+pub fn arc_scoreboard(games: u64, referees: usize) -> Scores {
+    todo!()
+}
+
+/// Judges games and sends the winners to whoever keeps the scores, so there's nothing to share
+#[derive(Debug, Clone)]
+pub struct MessagingReferee {
+    winners: mpsc::Sender<&'static str>,
+}
+
+impl MessagingReferee {
+    pub fn new(winners: &mpsc::Sender<&'static str>) -> Self {
+        Self {
+            winners: winners.clone(),
+        }
+    }
+
+    pub fn record(&self, game: u64) {
+        todo!()
+    }
+}
+
+/// Like `arc_scoreboard`, but with a `MessagingReferee` on every thread, while this thread keeps the scores
+pub fn channel_scoreboard(games: u64, referees: usize) -> Scores {
+    todo!()
+}
@@ -0,0 +1,80 @@
+use common::{cli::Cli, time_elapsed};
+use part_74::{arc_scoreboard, channel_scoreboard, rc_scoreboard};
+
+const GAMES: u64 = 200;
+const REFEREES: usize = 4;
+
+fn main() {
+    let args = Cli::new("Keeps score on one thread, in a shared mutex, and through a channel")
+        .items(GAMES as usize)
+        .threads(REFEREES)
+        .parse();
+    let (games, referees) = (args.items() as u64, args.threads());
+    let (scores, _) = time_elapsed("Rc<RefCell<_>> on one thread", || {
+        rc_scoreboard(games, referees)
+    });
+    println!("{scores:?}");
+    let (scores, _) = time_elapsed("Arc<Mutex<_>> on a thread per referee", || {
+        arc_scoreboard(games, referees)
+    });
+    println!("{scores:?}");
+    let (scores, _) = time_elapsed("Channel from a thread per referee", || {
+        channel_scoreboard(games, referees)
+    });
+    println!("{scores:?}");
+}
+
+/// The scores counted without any referees
+#[cfg(test)]
+fn expected(games: u64) -> part_74::Scores {
+    let mut scores = part_74::Scores::new();
+    for game in 0..games {
+        *scores.entry(part_74::winner(game)).or_default() += 1;
+    }
+    scores
+}
+
+#[test]
+fn rc_scoreboard_counts_every_game() {
+    assert_eq!(rc_scoreboard(GAMES, REFEREES), expected(GAMES));
+    assert_eq!(rc_scoreboard(GAMES, 1), expected(GAMES));
+}
+
+#[test]
+fn arc_scoreboard_counts_every_game() {
+    assert_eq!(arc_scoreboard(GAMES, REFEREES), expected(GAMES));
+    assert_eq!(arc_scoreboard(GAMES, 1), expected(GAMES));
+}
+
+#[test]
+fn arc_scoreboard_with_more_referees_than_games() {
+    assert_eq!(arc_scoreboard(3, 8), expected(3));
+    assert_eq!(arc_scoreboard(0, REFEREES), expected(0));
+}
+
+#[test]
+fn channel_scoreboard_counts_every_game() {
+    assert_eq!(channel_scoreboard(GAMES, REFEREES), expected(GAMES));
+    assert_eq!(channel_scoreboard(GAMES, 1), expected(GAMES));
+}
+
+#[test]
+fn channel_scoreboard_with_more_referees_than_games() {
+    assert_eq!(channel_scoreboard(3, 8), expected(3));
+    assert_eq!(channel_scoreboard(0, REFEREES), expected(0));
+}
+
+#[test]
+fn referees_judge_in_parallel() {
+    let (_, serial) = time_elapsed("serial", || rc_scoreboard(GAMES, REFEREES));
+    let (_, shared) = time_elapsed("shared", || arc_scoreboard(GAMES, REFEREES));
+    let (_, messages) = time_elapsed("messages", || channel_scoreboard(GAMES, REFEREES));
+    assert!(
+        shared * 2 < serial,
+        "Referees should judge at the same time"
+    );
+    assert!(
+        messages * 2 < serial,
+        "Referees should judge at the same time"
+    );
+}
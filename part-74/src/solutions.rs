@@ -0,0 +1,90 @@
+//! Part 74, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    sync::{mpsc, Arc, Mutex},
+    thread,
+};
+
+use super::{winner, Scores};
+
+/// Like `Referee`, but for a scoreboard shared between threads
+#[derive(Debug, Clone)]
+pub struct SharedReferee {
+    scores: Arc<Mutex<Scores>>,
+}
+
+impl SharedReferee {
+    pub fn new(scores: &Arc<Mutex<Scores>>) -> Self {
+        Self {
+            scores: scores.clone(),
+        }
+    }
+
+    pub fn record(&self, game: u64) {
+        // Judge before locking, or the referees would wait for each other to finish judging
+        let winner = winner(game);
+        *self.scores.lock().unwrap().entry(winner).or_default() += 1;
+    }
+}
+
+/// Like `rc_scoreboard`, but every referee judges its games on its own thread, with a `SharedReferee`
+pub fn arc_scoreboard(games: u64, referees: usize) -> Scores {
+    let scores = Arc::new(Mutex::new(Scores::new()));
+    let handles: Vec<_> = (0..referees)
+        .map(|first| {
+            let referee = SharedReferee::new(&scores);
+            thread::spawn(move || {
+                for game in (first as u64..games).step_by(referees) {
+                    referee.record(game);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    // Every referee is gone with its thread, so this is the only reference left
+    Arc::into_inner(scores).unwrap().into_inner().unwrap()
+}
+
+/// Judges games and sends the winners to whoever keeps the scores, so there's nothing to share
+#[derive(Debug, Clone)]
+pub struct MessagingReferee {
+    winners: mpsc::Sender<&'static str>,
+}
+
+impl MessagingReferee {
+    pub fn new(winners: &mpsc::Sender<&'static str>) -> Self {
+        Self {
+            winners: winners.clone(),
+        }
+    }
+
+    pub fn record(&self, game: u64) {
+        self.winners
+            .send(winner(game))
+            .expect("Scores should be kept until every referee is done");
+    }
+}
+
+/// Like `arc_scoreboard`, but with a `MessagingReferee` on every thread, while this thread keeps the scores
+pub fn channel_scoreboard(games: u64, referees: usize) -> Scores {
+    let (sender, receiver) = mpsc::channel();
+    for first in 0..referees {
+        let referee = MessagingReferee::new(&sender);
+        thread::spawn(move || {
+            for game in (first as u64..games).step_by(referees) {
+                referee.record(game);
+            }
+        });
+    }
+    // Otherwise the receiver would wait for this one forever
+    drop(sender);
+
+    // The channel is disconnected once every referee is done, and gone with its thread
+    let mut scores = Scores::new();
+    for winner in receiver {
+        *scores.entry(winner).or_default() += 1;
+    }
+    scores
+}
@@ -0,0 +1,57 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-74 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::sync::{mpsc, Arc, Mutex};
+
+use part_74::{
+    arc_scoreboard, channel_scoreboard, solutions, MessagingReferee, Scores, SharedReferee,
+};
+
+#[test]
+fn keeps_score_like_the_solution() {
+    for (games, referees) in [(0, 1), (1, 1), (100, 3), (1000, 8)] {
+        let expected = solutions::arc_scoreboard(games, referees);
+        assert_eq!(
+            arc_scoreboard(games, referees),
+            expected,
+            "{games} games, {referees} referees"
+        );
+        assert_eq!(
+            channel_scoreboard(games, referees),
+            expected,
+            "{games} games, {referees} referees"
+        );
+        assert_eq!(solutions::channel_scoreboard(games, referees), expected);
+    }
+}
+
+#[test]
+fn records_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let games = common::datagen::numbers(50, rng.seed());
+    let (scores, expected) = (
+        Arc::new(Mutex::new(Scores::new())),
+        Arc::new(Mutex::new(Scores::new())),
+    );
+    let (referee, expected_referee) = (
+        SharedReferee::new(&scores),
+        solutions::SharedReferee::new(&expected),
+    );
+    let (winners, received) = mpsc::channel();
+    let (expected_winners, expected_received) = mpsc::channel();
+    let messaging = MessagingReferee::new(&winners);
+    let expected_messaging = solutions::MessagingReferee::new(&expected_winners);
+    for &game in &games {
+        referee.record(game);
+        expected_referee.record(game);
+        messaging.record(game);
+        expected_messaging.record(game);
+    }
+    assert_eq!(*scores.lock().unwrap(), *expected.lock().unwrap());
+    drop((winners, expected_winners, messaging, expected_messaging));
+    assert_eq!(
+        received.iter().collect::<Vec<_>>(),
+        expected_received.iter().collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,118 @@
+use std::{
+    fmt,
+    ops::Range,
+    sync::{Barrier, Mutex},
+    thread,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// A grid of cells which are either alive or dead. Everything outside of the grid is dead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    rows: Vec<Vec<bool>>,
+}
+
+impl Grid {
+    /// A `width` × `height` grid where about a third of the cells are alive.
+    /// The same `seed` always gives the same grid.
+    pub fn random(width: usize, height: usize, seed: u64) -> Self {
+        let cells = common::datagen::numbers_below(width * height, 3, seed);
+        Self {
+            rows: cells
+                .chunks(width.max(1))
+                .map(|row| row.iter().map(|&cell| cell == 0).collect())
+                .collect(),
+        }
+    }
+
+    /// Parses one line per row, with `#` for a live cell and anything else for a dead one
+    pub fn parse(text: &str) -> Self {
+        Self {
+            rows: text
+                .lines()
+                .map(|line| line.chars().map(|cell| cell == '#').collect())
+                .collect(),
+        }
+    }
+
+    pub fn rows(&self) -> &[Vec<bool>] {
+        &self.rows
+    }
+
+    /// How many cells are alive
+    pub fn alive(&self) -> usize {
+        self.rows.iter().flatten().filter(|&&cell| cell).count()
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.rows {
+            let line: String = row
+                .iter()
+                .map(|&cell| if cell { '#' } else { '.' })
+                .collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether the cell at `col` of `row` is alive in the next generation. A live cell stays alive with two or three
+/// live neighbours, and a dead one comes alive with exactly three. `above` and `below` are `None` at the edges.
+fn next_cell(above: Option<&[bool]>, row: &[bool], below: Option<&[bool]>, col: usize) -> bool {
+    let neighbours = [above, Some(row), below]
+        .into_iter()
+        .flatten()
+        .flat_map(|row| &row[col.saturating_sub(1)..(col + 2).min(row.len())])
+        .filter(|&&cell| cell)
+        .count()
+        - row[col] as usize;
+    matches!((row[col], neighbours), (true, 2 | 3) | (false, 3))
+}
+
+/// The next generation of `band`, which is some consecutive rows of a grid.
+/// `above` and `below` are the rows just outside of it, its halo, or `None` at the edges of the grid.
+pub fn step_band(
+    band: &[Vec<bool>],
+    above: Option<&[bool]>,
+    below: Option<&[bool]>,
+) -> Vec<Vec<bool>> {
+    (0..band.len())
+        .map(|i| {
+            let above = if i == 0 {
+                above
+            } else {
+                Some(&band[i - 1][..])
+            };
+            let below = band.get(i + 1).map(|row| &row[..]).or(below);
+            (0..band[i].len())
+                .map(|col| next_cell(above, &band[i], below, col))
+                .collect()
+        })
+        .collect()
+}
+
+/// Calculates `generations` generations of `grid`, one after another
+pub fn serial_generations(grid: &Grid, generations: usize) -> Grid {
+    let mut rows = grid.rows.clone();
+    for _ in 0..generations {
+        rows = step_band(&rows, None, None);
+    }
+    Grid { rows }
+}
+
+/// Splits `height` rows into bands of consecutive rows for `threads` threads, as evenly as possible.
+/// No band is empty, so there are fewer bands than threads when there are fewer rows. Panics if `threads` is 0.
+pub fn bands(height: usize, threads: usize) -> Vec<Range<usize>> {
+    todo!()
+}
+
+/// Calculates the same generations as `serial_generations`, with a thread for each of the `bands`.
+/// Every thread keeps its own rows, and only shares the rows at the edges of its band with its neighbours,
+/// once every generation.
+pub fn parallel_generations(grid: &Grid, generations: usize, threads: usize) -> Grid {
+    todo!()
+}
@@ -0,0 +1,118 @@
+use common::{bench::Comparison, cli::Cli};
+use part_75::{parallel_generations, serial_generations, Grid};
+
+const GLIDER: &str = "\
+.#......
+..#.....
+###.....
+........
+........
+........";
+
+/// Run with `cargo run --release -p part-75` to compare with the serial version
+fn main() {
+    // `--strategy` picks which of them `Comparison` measures
+    Cli::new("Runs the game of life serially, and on several threads")
+        .strategy()
+        .parse();
+    let glider = Grid::parse(GLIDER);
+    for generation in 0..4 {
+        println!("Generation {generation}:");
+        println!("{}", parallel_generations(&glider, generation, 3));
+    }
+
+    let grid = Grid::random(512, 512, 75);
+    let mut comparison = Comparison::new(3);
+    comparison.bench("Serial", || serial_generations(&grid, 50));
+    for threads in [2, 4, 8] {
+        comparison.bench(&format!("{threads} threads"), || {
+            parallel_generations(&grid, 50, threads)
+        });
+    }
+    comparison.print();
+}
+
+#[test]
+fn blinker_blinks() {
+    let vertical = Grid::parse(".#.\n.#.\n.#.");
+    let horizontal = Grid::parse("...\n###\n...");
+    assert_eq!(serial_generations(&vertical, 1), horizontal);
+    for threads in [1, 2, 3] {
+        assert_eq!(parallel_generations(&vertical, 1, threads), horizontal);
+        assert_eq!(parallel_generations(&vertical, 2, threads), vertical);
+    }
+}
+
+#[test]
+fn glider_crosses_bands() {
+    // A glider moves one cell diagonally every four generations, so it moves from one band into another
+    let glider = Grid::parse(GLIDER);
+    let moved = Grid::parse(
+        "\
+........
+..#.....
+...#....
+.###....
+........
+........",
+    );
+    assert_eq!(serial_generations(&glider, 4), moved);
+    for threads in [2, 3, 6] {
+        assert_eq!(
+            parallel_generations(&glider, 4, threads),
+            moved,
+            "Wrong result with {threads} threads"
+        );
+    }
+}
+
+#[test]
+fn same_as_serial_over_many_generations() {
+    let mut rng = common::rng::for_test();
+    let grid = Grid::random(40, 30, rng.seed());
+    let expected = serial_generations(&grid, 100);
+    assert!(expected.alive() > 0, "Everything died, nothing was tested");
+    for threads in [1, 2, 3, 4, 7] {
+        assert_eq!(
+            parallel_generations(&grid, 100, threads),
+            expected,
+            "Wrong result with {threads} threads"
+        );
+    }
+}
+
+#[test]
+fn more_threads_than_rows() {
+    let mut rng = common::rng::for_test();
+    let grid = Grid::random(20, 5, rng.seed());
+    assert_eq!(
+        parallel_generations(&grid, 20, 16),
+        serial_generations(&grid, 20)
+    );
+}
+
+#[test]
+fn empty_grids_stay_empty() {
+    let mut rng = common::rng::for_test();
+    let empty = Grid::parse("");
+    assert_eq!(parallel_generations(&empty, 10, 4), empty);
+    let grid = Grid::random(10, 10, rng.seed());
+    assert_eq!(parallel_generations(&grid, 0, 4), grid);
+}
+
+#[test]
+fn bands_are_even_and_cover_every_row() {
+    use part_75::bands;
+
+    assert_eq!(bands(10, 3), [0..3, 3..6, 6..10]);
+    assert_eq!(bands(8, 4), [0..2, 2..4, 4..6, 6..8]);
+    assert_eq!(bands(3, 8), [0..1, 1..2, 2..3]);
+    assert_eq!(bands(0, 4), []);
+    for (height, threads) in [(100, 7), (1, 1), (64, 64)] {
+        let bands = bands(height, threads);
+        let sizes: Vec<_> = bands.iter().map(|band| band.len()).collect();
+        assert!(sizes.iter().max().unwrap() - sizes.iter().min().unwrap() <= 1);
+        assert_eq!(sizes.iter().sum::<usize>(), height);
+        assert!(bands.windows(2).all(|pair| pair[0].end == pair[1].start));
+    }
+}
@@ -0,0 +1,63 @@
+//! Part 75, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    ops::Range,
+    sync::{Barrier, Mutex},
+    thread,
+};
+
+use super::{step_band, Grid};
+
+/// Splits `height` rows into bands of consecutive rows for `threads` threads, as evenly as possible.
+/// No band is empty, so there are fewer bands than threads when there are fewer rows. Panics if `threads` is 0.
+pub fn bands(height: usize, threads: usize) -> Vec<Range<usize>> {
+    assert!(threads > 0, "There must be at least one thread");
+    let count = threads.min(height);
+    (0..count)
+        .map(|band| band * height / count..(band + 1) * height / count)
+        .collect()
+}
+
+/// Calculates the same generations as `serial_generations`, with a thread for each of the `bands`.
+/// Every thread keeps its own rows, and only shares the rows at the edges of its band with its neighbours,
+/// once every generation.
+pub fn parallel_generations(grid: &Grid, generations: usize, threads: usize) -> Grid {
+    let bands = bands(grid.rows.len(), threads);
+    // The top and bottom row of every band, for its neighbours to read
+    let edges: Vec<Mutex<(Vec<bool>, Vec<bool>)>> =
+        bands.iter().map(|_| Mutex::default()).collect();
+    let barrier = Barrier::new(bands.len());
+
+    let rows = thread::scope(|s| {
+        let handles: Vec<_> = bands
+            .iter()
+            .enumerate()
+            .map(|(i, range)| {
+                let (edges, barrier) = (&edges, &barrier);
+                let mut band = grid.rows[range.clone()].to_vec();
+                s.spawn(move || {
+                    for _ in 0..generations {
+                        *edges[i].lock().unwrap() = (band[0].clone(), band[band.len() - 1].clone());
+                        // Every band has to share its edges before anyone can read them
+                        barrier.wait();
+                        let above = i
+                            .checked_sub(1)
+                            .map(|above| edges[above].lock().unwrap().1.clone());
+                        let below = edges
+                            .get(i + 1)
+                            .map(|below| below.lock().unwrap().0.clone());
+                        // And everyone has to read them before anyone shares the next generation's
+                        barrier.wait();
+                        band = step_band(&band, above.as_deref(), below.as_deref());
+                    }
+                    band
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+    Grid { rows }
+}
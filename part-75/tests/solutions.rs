@@ -0,0 +1,35 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-75 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_75::{bands, parallel_generations, solutions, Grid};
+
+#[test]
+fn bands_like_the_solution() {
+    for height in [0, 1, 7, 100] {
+        for threads in [1, 3, 8, 200] {
+            assert_eq!(
+                bands(height, threads),
+                solutions::bands(height, threads),
+                "{height} rows on {threads} threads"
+            );
+        }
+    }
+}
+
+#[test]
+fn lives_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for (width, height) in [(1, 1), (10, 3), (40, 30)] {
+        let seed = rng.seed();
+        let grid = Grid::random(width, height, seed);
+        let expected = Grid::random(width, height, seed);
+        for threads in [1, 4, 50] {
+            assert_eq!(
+                parallel_generations(&grid, 12, threads).rows(),
+                solutions::parallel_generations(&expected, 12, threads).rows(),
+                "{width}×{height} on {threads} threads"
+            );
+        }
+    }
+}
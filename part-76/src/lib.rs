@@ -0,0 +1,126 @@
+use std::{
+    cmp::Ordering,
+    collections::BinaryHeap,
+    panic::{self, AssertUnwindSafe},
+    sync::{Arc, Condvar, Mutex},
+    time::Instant,
+};
+
+use part_16::ThreadPool;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How urgent a job is. Higher priorities go first.
+pub type Priority = u8;
+
+/// Something to do, how urgent it is, and when it should be done by
+pub struct Job {
+    pub priority: Priority,
+    pub deadline: Instant,
+    work: Box<dyn FnOnce() + Send + 'static>,
+}
+
+impl Job {
+    pub fn new(
+        priority: Priority,
+        deadline: Instant,
+        work: impl FnOnce() + Send + 'static,
+    ) -> Self {
+        Self {
+            priority,
+            deadline,
+            work: Box::new(work),
+        }
+    }
+
+    /// Does the work. A panicking job is reported, but doesn't take its worker down with it.
+    pub fn run(self) {
+        if panic::catch_unwind(AssertUnwindSafe(self.work)).is_err() {
+            eprintln!("A job with priority {} panicked", self.priority);
+        }
+    }
+}
+
+/// A job in the queue, with the order it arrived in
+struct Entry {
+    job: Job,
+    arrival: u64,
+}
+
+impl Ord for Entry {
+    /// The entry that should go first is the greatest, since `BinaryHeap` pops the greatest first:
+    /// the one with the highest priority, then the earliest deadline, and then the one which arrived first.
+    fn cmp(&self, other: &Self) -> Ordering {
+        todo!()
+    }
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Entry {}
+
+#[derive(Default)]
+struct State {
+    heap: BinaryHeap<Entry>,
+    /// How many jobs have been pushed, which numbers them in the order they arrived
+    arrivals: u64,
+    closed: bool,
+}
+
+/// Jobs waiting to be done, which come out most urgent first. Can be shared between threads.
+#[derive(Default)]
+pub struct PriorityQueue {
+    state: Mutex<State>,
+    /// Signalled when a job is pushed, or the queue is closed
+    changed: Condvar,
+}
+
+impl PriorityQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `job` to the queue, and wakes up someone waiting for one.
+    /// Panics if the queue has been closed.
+    pub fn push(&self, job: Job) {
+        todo!()
+    }
+
+    /// Takes the most urgent job, waiting for one if the queue is empty.
+    /// Returns `None` once the queue is closed and empty.
+    pub fn pop(&self) -> Option<Job> {
+        todo!()
+    }
+
+    /// Lets everyone waiting in `pop` know that no more jobs are coming.
+    /// Jobs which are already in the queue can still be popped.
+    pub fn close(&self) {
+        todo!()
+    }
+
+    /// How many jobs are waiting
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Makes every worker of `pool` run jobs from `queue`, most urgent first, until it's closed and empty.
+/// Close the queue before dropping the pool, or the pool will wait for the workers forever.
+pub fn serve(pool: &ThreadPool, queue: &Arc<PriorityQueue>) {
+    todo!()
+}
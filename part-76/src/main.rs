@@ -0,0 +1,201 @@
+use std::{
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use part_16::ThreadPool;
+use part_76::{serve, Job, PriorityQueue};
+
+fn main() {
+    // A single worker, so the jobs are done strictly one after another
+    let pool = ThreadPool::new(1);
+    let queue = Arc::new(PriorityQueue::new());
+    let start = Instant::now();
+    // Queue everything up before the worker starts, so it gets to pick
+    for (name, priority, millis) in [
+        ("backup", 0, 1000),
+        ("report", 1, 300),
+        ("alert", 9, 100),
+        ("email", 1, 80),
+    ] {
+        let deadline = start + Duration::from_millis(millis);
+        queue.push(Job::new(priority, deadline, move || {
+            thread::sleep(Duration::from_millis(50));
+            let late = if Instant::now() <= deadline {
+                "in time"
+            } else {
+                "too late"
+            };
+            println!(
+                "{name} (priority {priority}) done after {:?}, {late}",
+                start.elapsed()
+            );
+        }));
+    }
+    serve(&pool, &queue);
+    queue.close();
+}
+
+/// Pushes a job which keeps the only worker of the pool busy until the returned sender is dropped,
+/// and waits until that worker has started on it
+#[cfg(test)]
+fn occupy(queue: &PriorityQueue) -> std::sync::mpsc::Sender<()> {
+    use std::sync::mpsc;
+
+    let (started_sender, started) = mpsc::channel();
+    let (release, released) = mpsc::channel::<()>();
+    queue.push(Job::new(0, Instant::now(), move || {
+        started_sender.send(()).unwrap();
+        let _ = released.recv();
+    }));
+    started.recv().unwrap();
+    release
+}
+
+#[test]
+fn pops_the_highest_priority_first() {
+    let queue = PriorityQueue::new();
+    let deadline = Instant::now();
+    for priority in [3, 1, 4, 1, 5, 9, 2, 6] {
+        queue.push(Job::new(priority, deadline, || ()));
+    }
+    queue.close();
+    let priorities: Vec<_> = std::iter::from_fn(|| queue.pop())
+        .map(|job| job.priority)
+        .collect();
+    assert_eq!(priorities, [9, 6, 5, 4, 3, 2, 1, 1]);
+}
+
+#[test]
+fn pops_the_earliest_deadline_first_within_a_priority() {
+    let queue = PriorityQueue::new();
+    let now = Instant::now();
+    let deadlines: Vec<_> = [30, 10, 20]
+        .into_iter()
+        .map(|millis| now + Duration::from_millis(millis))
+        .collect();
+    for &deadline in &deadlines {
+        queue.push(Job::new(1, deadline, || ()));
+    }
+    queue.push(Job::new(2, now + Duration::from_secs(60), || ()));
+    queue.close();
+    let popped: Vec<_> = std::iter::from_fn(|| queue.pop())
+        .map(|job| (job.priority, job.deadline))
+        .collect();
+    assert_eq!(
+        popped,
+        [
+            (2, now + Duration::from_secs(60)),
+            (1, deadlines[1]),
+            (1, deadlines[2]),
+            (1, deadlines[0])
+        ]
+    );
+}
+
+#[test]
+fn pops_in_arrival_order_when_everything_else_is_equal() {
+    use std::sync::Mutex;
+
+    let queue = PriorityQueue::new();
+    let deadline = Instant::now();
+    let order = Arc::new(Mutex::new(Vec::new()));
+    for id in 0..5 {
+        let order = order.clone();
+        queue.push(Job::new(1, deadline, move || {
+            order.lock().unwrap().push(id)
+        }));
+    }
+    queue.close();
+    while let Some(job) = queue.pop() {
+        job.run();
+    }
+    assert_eq!(*order.lock().unwrap(), [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn pop_waits_for_a_job() {
+    let queue = Arc::new(PriorityQueue::new());
+    let popper = {
+        let queue = queue.clone();
+        thread::spawn(move || queue.pop().map(|job| job.priority))
+    };
+    thread::sleep(Duration::from_millis(50));
+    assert!(!popper.is_finished(), "Pop should wait for a job");
+    queue.push(Job::new(7, Instant::now(), || ()));
+    assert_eq!(popper.join().unwrap(), Some(7));
+}
+
+#[test]
+fn close_wakes_everyone_up() {
+    let queue = Arc::new(PriorityQueue::new());
+    let poppers: Vec<_> = (0..4)
+        .map(|_| {
+            let queue = queue.clone();
+            thread::spawn(move || queue.pop().is_none())
+        })
+        .collect();
+    thread::sleep(Duration::from_millis(50));
+    queue.close();
+    for popper in poppers {
+        assert!(popper.join().unwrap());
+    }
+}
+
+#[test]
+#[should_panic(expected = "closed")]
+fn cannot_push_after_closing() {
+    let queue = PriorityQueue::new();
+    queue.close();
+    queue.push(Job::new(1, Instant::now(), || ()));
+}
+
+#[test]
+fn pool_runs_urgent_jobs_before_earlier_ones() {
+    use std::sync::Mutex;
+
+    let pool = ThreadPool::new(1);
+    let queue = Arc::new(PriorityQueue::new());
+    serve(&pool, &queue);
+    let release = occupy(&queue);
+
+    // All of these arrive while the only worker is busy, the low priority ones first
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let deadline = Instant::now() + Duration::from_secs(1);
+    for (id, priority) in [(0, 1), (1, 1), (2, 5), (3, 9), (4, 5)] {
+        let order = order.clone();
+        queue.push(Job::new(priority, deadline, move || {
+            order.lock().unwrap().push(id)
+        }));
+    }
+    drop(release);
+    queue.close();
+    drop(pool);
+    assert_eq!(*order.lock().unwrap(), [3, 2, 4, 0, 1]);
+}
+
+#[test]
+fn every_worker_serves_until_closed() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let pool = ThreadPool::new(4);
+    let queue = Arc::new(PriorityQueue::new());
+    serve(&pool, &queue);
+    let done = Arc::new(AtomicUsize::new(0));
+    let threads = Arc::new(std::sync::Mutex::new(std::collections::HashSet::new()));
+    for priority in 0..100 {
+        let (done, threads) = (done.clone(), threads.clone());
+        queue.push(Job::new(priority, Instant::now(), move || {
+            threads.lock().unwrap().insert(thread::current().id());
+            thread::sleep(Duration::from_millis(1));
+            done.fetch_add(1, Ordering::SeqCst);
+        }));
+    }
+    // A panicking job mustn't stop its worker from serving
+    queue.push(Job::new(50, Instant::now(), || panic!("Oops")));
+    queue.close();
+    drop(pool);
+    assert_eq!(done.load(Ordering::SeqCst), 100);
+    assert!(threads.lock().unwrap().len() > 1, "Only one worker served");
+}
@@ -0,0 +1,119 @@
+//! Part 76, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    cmp::Ordering,
+    collections::BinaryHeap,
+    sync::{Arc, Condvar, Mutex},
+};
+
+use part_16::solutions::ThreadPool;
+
+use super::Job;
+
+/// A job in the queue, with the order it arrived in
+struct Entry {
+    job: Job,
+    arrival: u64,
+}
+
+impl Ord for Entry {
+    /// The entry that should go first is the greatest, since `BinaryHeap` pops the greatest first:
+    /// the one with the highest priority, then the earliest deadline, and then the one which arrived first.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.job
+            .priority
+            .cmp(&other.job.priority)
+            .then_with(|| other.job.deadline.cmp(&self.job.deadline))
+            .then_with(|| other.arrival.cmp(&self.arrival))
+    }
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Entry {}
+
+#[derive(Default)]
+struct State {
+    heap: BinaryHeap<Entry>,
+    /// How many jobs have been pushed, which numbers them in the order they arrived
+    arrivals: u64,
+    closed: bool,
+}
+
+/// Jobs waiting to be done, which come out most urgent first. Can be shared between threads.
+#[derive(Default)]
+pub struct PriorityQueue {
+    state: Mutex<State>,
+    /// Signalled when a job is pushed, or the queue is closed
+    changed: Condvar,
+}
+
+impl PriorityQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `job` to the queue, and wakes up someone waiting for one.
+    /// Panics if the queue has been closed.
+    pub fn push(&self, job: Job) {
+        let mut state = self.state.lock().unwrap();
+        assert!(!state.closed, "Can't push to a closed queue");
+        let arrival = state.arrivals;
+        state.arrivals += 1;
+        state.heap.push(Entry { job, arrival });
+        // One job is enough for one worker
+        self.changed.notify_one();
+    }
+
+    /// Takes the most urgent job, waiting for one if the queue is empty.
+    /// Returns `None` once the queue is closed and empty.
+    pub fn pop(&self) -> Option<Job> {
+        let mut state = self
+            .changed
+            .wait_while(self.state.lock().unwrap(), |state| {
+                state.heap.is_empty() && !state.closed
+            })
+            .unwrap();
+        state.heap.pop().map(|entry| entry.job)
+    }
+
+    /// Lets everyone waiting in `pop` know that no more jobs are coming.
+    /// Jobs which are already in the queue can still be popped.
+    pub fn close(&self) {
+        self.state.lock().unwrap().closed = true;
+        // Nobody gets a job out of this, so everyone has to find out
+        self.changed.notify_all();
+    }
+
+    /// How many jobs are waiting
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Makes every worker of `pool` run jobs from `queue`, most urgent first, until it's closed and empty.
+/// Close the queue before dropping the pool, or the pool will wait for the workers forever.
+pub fn serve(pool: &ThreadPool, queue: &Arc<PriorityQueue>) {
+    for _ in 0..pool.size() {
+        let queue = queue.clone();
+        pool.execute(move || {
+            while let Some(job) = queue.pop() {
+                job.run();
+            }
+        });
+    }
+}
@@ -0,0 +1,85 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-76 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use part_16::ThreadPool;
+use part_76::{serve, solutions, Job, PriorityQueue};
+
+/// Seeded priorities and deadlines, with some ties in both
+fn jobs(seed: u64) -> Vec<(u8, Duration)> {
+    let priorities = common::datagen::numbers_below(40, 4, seed);
+    let deadlines = common::datagen::numbers_below(40, 5, seed.wrapping_add(1));
+    priorities
+        .into_iter()
+        .zip(deadlines)
+        .map(|(priority, deadline)| (priority as u8, Duration::from_secs(deadline)))
+        .collect()
+}
+
+#[test]
+fn pops_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let start = Instant::now();
+    let (queue, expected) = (PriorityQueue::new(), solutions::PriorityQueue::new());
+    for (priority, deadline) in jobs(rng.seed()) {
+        queue.push(Job::new(priority, start + deadline, || ()));
+        expected.push(Job::new(priority, start + deadline, || ()));
+    }
+    queue.close();
+    expected.close();
+    assert_eq!(queue.len(), expected.len());
+    loop {
+        let (job, expected_job) = (queue.pop(), expected.pop());
+        assert_eq!(
+            job.as_ref().map(|job| (job.priority, job.deadline)),
+            expected_job
+                .as_ref()
+                .map(|job| (job.priority, job.deadline))
+        );
+        if job.is_none() {
+            break;
+        }
+    }
+}
+
+#[test]
+fn serves_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let start = Instant::now();
+    let (ran, expected_ran) = (
+        Arc::new(Mutex::new(Vec::new())),
+        Arc::new(Mutex::new(Vec::new())),
+    );
+    let (queue, expected) = (
+        Arc::new(PriorityQueue::new()),
+        Arc::new(solutions::PriorityQueue::new()),
+    );
+    let jobs = jobs(rng.seed());
+    for (i, &(priority, deadline)) in jobs.iter().enumerate() {
+        let (ran, expected_ran) = (Arc::clone(&ran), Arc::clone(&expected_ran));
+        queue.push(Job::new(priority, start + deadline, move || {
+            ran.lock().unwrap().push(i)
+        }));
+        expected.push(Job::new(priority, start + deadline, move || {
+            expected_ran.lock().unwrap().push(i)
+        }));
+    }
+    queue.close();
+    expected.close();
+    // A single worker runs the jobs in the order they come out of the queue
+    {
+        let pool = ThreadPool::new(1);
+        serve(&pool, &queue);
+    }
+    {
+        let pool = part_16::solutions::ThreadPool::new(1);
+        solutions::serve(&pool, &expected);
+    }
+    assert_eq!(*ran.lock().unwrap(), *expected_ran.lock().unwrap());
+    assert_eq!(ran.lock().unwrap().len(), jobs.len());
+}
@@ -0,0 +1,175 @@
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crossbeam_channel::{Receiver, RecvTimeoutError, Sender};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How often a worker beats, both while working and while waiting for a job
+pub const BEAT: Duration = Duration::from_millis(10);
+
+/// How long a worker may go without beating before it's considered stalled
+pub const TIMEOUT: Duration = Duration::from_millis(100);
+
+/// How often the monitor checks the heartbeats
+pub const CHECK: Duration = Duration::from_millis(10);
+
+/// How long a stalled worker hangs before it comes to again
+pub const STALL: Duration = Duration::from_millis(500);
+
+/// Something to do, which takes `duration`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Job {
+    pub id: usize,
+    pub duration: Duration,
+}
+
+/// A job which was done, and which worker did it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Completion {
+    pub job: usize,
+    pub worker: usize,
+}
+
+/// A worker which was replaced by a new one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replacement {
+    pub slot: usize,
+    pub stalled: usize,
+    pub replacement: usize,
+    /// The job the stalled worker was working on, which was put back in the queue
+    pub job: Option<usize>,
+    /// How long it had been since the stalled worker's last beat when it was found out
+    pub silent_for: Duration,
+}
+
+/// Everything that happened in `run_supervised`, in order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    pub completed: Vec<Completion>,
+    pub replaced: Vec<Replacement>,
+}
+
+/// What the monitor knows about the worker in a slot
+#[derive(Debug)]
+struct Heartbeat {
+    /// The worker in this slot, which is the only one allowed to beat here
+    worker: usize,
+    last_beat: Instant,
+    /// What the worker is working on
+    job: Option<Job>,
+}
+
+/// Everything the workers and the monitor share
+#[derive(Debug)]
+struct Shared {
+    slots: Vec<Mutex<Heartbeat>>,
+    /// `jobs.1` is for putting jobs back in the queue
+    jobs: (Sender<Job>, Receiver<Job>),
+    completed: Sender<Completion>,
+    /// Ids of jobs which make the first worker to take them stall
+    stalls: Mutex<HashSet<usize>>,
+    next_worker: AtomicUsize,
+}
+
+impl Shared {
+    /// Spawns a new worker into `slot`, which `heartbeat` is the heartbeat of, and returns its id
+    fn spawn_worker(self: &Arc<Self>, slot: usize, heartbeat: &mut Heartbeat) -> usize {
+        let id = self.next_worker.fetch_add(1, Ordering::Relaxed);
+        *heartbeat = Heartbeat {
+            worker: id,
+            last_beat: Instant::now(),
+            job: None,
+        };
+        let shared = self.clone();
+        thread::spawn(move || {
+            let _worker = common::span::worker(id);
+            worker(&shared, slot, id)
+        });
+        id
+    }
+
+    /// Records a beat from `worker` in `slot`, and sets what it's working on.
+    /// Returns `false` if the worker has been replaced, in which case it must stop.
+    fn beat(&self, slot: usize, worker: usize, job: Option<Job>) -> bool {
+        let mut heartbeat = self.slots[slot].lock().unwrap();
+        if heartbeat.worker != worker {
+            return false;
+        }
+        heartbeat.last_beat = Instant::now();
+        heartbeat.job = job;
+        true
+    }
+
+    /// Whether the first worker to take `job` should stall on it
+    fn should_stall(&self, job: &Job) -> bool {
+        self.stalls.lock().unwrap().remove(&job.id)
+    }
+}
+
+/// The worker `id` in `slot`. Takes jobs until it's been replaced, and beats every `BEAT` while working on
+/// them or waiting for one. Only reports a job as completed if it hasn't been replaced in the meantime,
+/// since then the job has been given to someone else. Hangs for `STALL` on a job which it `should_stall` on.
+fn worker(shared: &Arc<Shared>, slot: usize, id: usize) {
+    todo!()
+}
+
+/// Makes one round of checks. Replaces every worker which hasn't beaten for `TIMEOUT`,
+/// and puts the job it was working on back in the queue.
+fn check(shared: &Arc<Shared>) -> Vec<Replacement> {
+    todo!()
+}
+
+/// Runs every job in `jobs` on `workers` workers, while a monitor replaces workers which stall.
+/// The first worker to take any of the jobs in `stalls` stalls. Returns once every job has been completed.
+pub fn run_supervised(jobs: Vec<Job>, workers: usize, stalls: &[usize]) -> Report {
+    let (completed, completions) = crossbeam_channel::unbounded();
+    let shared = Arc::new(Shared {
+        slots: (0..workers)
+            .map(|_| {
+                Mutex::new(Heartbeat {
+                    worker: usize::MAX,
+                    last_beat: Instant::now(),
+                    job: None,
+                })
+            })
+            .collect(),
+        jobs: crossbeam_channel::unbounded(),
+        completed,
+        stalls: Mutex::new(stalls.iter().copied().collect()),
+        next_worker: AtomicUsize::new(0),
+    });
+    for slot in 0..workers {
+        shared.spawn_worker(slot, &mut shared.slots[slot].lock().unwrap());
+    }
+    let count = jobs.len();
+    for job in jobs {
+        shared.jobs.0.send(job).unwrap();
+    }
+
+    let mut report = Report::default();
+    let mut next_check = Instant::now() + CHECK;
+    while report.completed.len() < count {
+        // Checks on time even when jobs are completed more often than every `CHECK`
+        match completions.recv_deadline(next_check) {
+            Ok(completion) => report.completed.push(completion),
+            Err(_) => {
+                report.replaced.extend(check(&shared));
+                next_check = Instant::now() + CHECK;
+            }
+        }
+    }
+    // Stops the workers which are still running, since none of them are in a slot anymore
+    for slot in &shared.slots {
+        slot.lock().unwrap().worker = usize::MAX;
+    }
+    report
+}
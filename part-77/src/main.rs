@@ -0,0 +1,109 @@
+use std::time::{Duration, Instant};
+
+use part_77::{run_supervised, Job};
+
+/// `count` jobs which take `millis` milliseconds each
+fn jobs(count: usize, millis: u64) -> Vec<Job> {
+    (0..count)
+        .map(|id| Job {
+            id,
+            duration: Duration::from_millis(millis),
+        })
+        .collect()
+}
+
+fn main() {
+    common::init_tracing();
+    let args = common::cli::Cli::new("Runs jobs on supervised workers, replacing one which stalls")
+        .items(12)
+        .threads(3)
+        .parse();
+    let start = Instant::now();
+    let report = run_supervised(jobs(args.items(), 30), args.threads(), &[4]);
+    for replacement in &report.replaced {
+        println!(
+            "Worker {} in slot {} went silent for {:?} on job {:?}, and was replaced by worker {}",
+            replacement.stalled,
+            replacement.slot,
+            replacement.silent_for,
+            replacement.job,
+            replacement.replacement
+        );
+    }
+    for completion in &report.completed {
+        println!(
+            "Job {} done by worker {}",
+            completion.job, completion.worker
+        );
+    }
+    println!("All done after {:?}", start.elapsed());
+}
+
+/// The ids of the completed jobs, sorted
+#[cfg(test)]
+fn completed_ids(report: &part_77::Report) -> Vec<usize> {
+    let mut ids: Vec<_> = report.completed.iter().map(|c| c.job).collect();
+    ids.sort_unstable();
+    ids
+}
+
+#[test]
+fn completes_every_job_exactly_once() {
+    let report = run_supervised(jobs(20, 5), 4, &[]);
+    assert_eq!(completed_ids(&report), (0..20).collect::<Vec<_>>());
+    assert_eq!(report.replaced, []);
+}
+
+#[test]
+fn doesnt_replace_idle_workers() {
+    use part_77::TIMEOUT;
+
+    // Three of the workers have nothing to do for much longer than the timeout
+    let report = run_supervised(jobs(1, 3 * TIMEOUT.as_millis() as u64), 4, &[]);
+    assert_eq!(completed_ids(&report), [0]);
+    assert_eq!(report.replaced, []);
+}
+
+#[test]
+fn detects_a_stalled_worker_soon_after_the_timeout() {
+    use part_77::{STALL, TIMEOUT};
+
+    let report = run_supervised(jobs(10, 10), 2, &[3]);
+    assert_eq!(report.replaced.len(), 1, "{:?}", report.replaced);
+    let silent_for = report.replaced[0].silent_for;
+    assert!(silent_for >= TIMEOUT, "Replaced after only {silent_for:?}");
+    assert!(silent_for < 2 * TIMEOUT, "Took {silent_for:?} to notice");
+    assert!(silent_for < STALL);
+}
+
+#[test]
+fn reassigns_the_job_of_a_stalled_worker() {
+    let report = run_supervised(jobs(10, 10), 2, &[3]);
+    assert_eq!(completed_ids(&report), (0..10).collect::<Vec<_>>());
+    let replacement = &report.replaced[0];
+    assert_eq!(replacement.job, Some(3));
+    assert_ne!(replacement.replacement, replacement.stalled);
+    let redone = report.completed.iter().find(|c| c.job == 3).unwrap();
+    assert_ne!(redone.worker, replacement.stalled);
+}
+
+#[test]
+fn the_stalled_worker_doesnt_complete_its_job_when_it_comes_to() {
+    use part_77::{STALL, TIMEOUT};
+
+    // Enough work to keep going for a good while after the stalled worker wakes up again
+    let start = Instant::now();
+    let report = run_supervised(jobs(80, 20), 2, &[0]);
+    assert!(start.elapsed() > STALL + TIMEOUT);
+    assert_eq!(completed_ids(&report), (0..80).collect::<Vec<_>>());
+    assert_eq!(report.replaced.len(), 1, "{:?}", report.replaced);
+}
+
+#[test]
+fn replaces_every_stalled_worker() {
+    let report = run_supervised(jobs(6, 10), 2, &[0, 1]);
+    assert_eq!(completed_ids(&report), (0..6).collect::<Vec<_>>());
+    let mut jobs: Vec<_> = report.replaced.iter().map(|r| r.job.unwrap()).collect();
+    jobs.sort_unstable();
+    assert_eq!(jobs, [0, 1]);
+}
@@ -0,0 +1,208 @@
+//! Part 77, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::Instant,
+};
+
+use crossbeam_channel::{Receiver, RecvTimeoutError, Sender};
+
+use super::{Job, Replacement, BEAT, CHECK, STALL, TIMEOUT};
+
+/// A job which was done, and which worker did it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Completion {
+    pub job: usize,
+    pub worker: usize,
+}
+
+/// Everything that happened in `run_supervised`, in order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    pub completed: Vec<Completion>,
+    pub replaced: Vec<Replacement>,
+}
+
+/// What the monitor knows about the worker in a slot
+#[derive(Debug)]
+struct Heartbeat {
+    /// The worker in this slot, which is the only one allowed to beat here
+    worker: usize,
+    last_beat: Instant,
+    /// What the worker is working on
+    job: Option<Job>,
+}
+
+/// Everything the workers and the monitor share
+#[derive(Debug)]
+struct Shared {
+    slots: Vec<Mutex<Heartbeat>>,
+    /// `jobs.1` is for putting jobs back in the queue
+    jobs: (Sender<Job>, Receiver<Job>),
+    completed: Sender<Completion>,
+    /// Ids of jobs which make the first worker to take them stall
+    stalls: Mutex<HashSet<usize>>,
+    next_worker: AtomicUsize,
+}
+
+impl Shared {
+    /// Spawns a new worker into `slot`, which `heartbeat` is the heartbeat of, and returns its id
+    fn spawn_worker(self: &Arc<Self>, slot: usize, heartbeat: &mut Heartbeat) -> usize {
+        let id = self.next_worker.fetch_add(1, Ordering::Relaxed);
+        *heartbeat = Heartbeat {
+            worker: id,
+            last_beat: Instant::now(),
+            job: None,
+        };
+        let shared = self.clone();
+        thread::spawn(move || {
+            let _worker = common::span::worker(id);
+            worker(&shared, slot, id)
+        });
+        id
+    }
+
+    /// Records a beat from `worker` in `slot`, and sets what it's working on.
+    /// Returns `false` if the worker has been replaced, in which case it must stop.
+    fn beat(&self, slot: usize, worker: usize, job: Option<Job>) -> bool {
+        let mut heartbeat = self.slots[slot].lock().unwrap();
+        if heartbeat.worker != worker {
+            return false;
+        }
+        heartbeat.last_beat = Instant::now();
+        heartbeat.job = job;
+        true
+    }
+
+    /// Whether the first worker to take `job` should stall on it
+    fn should_stall(&self, job: &Job) -> bool {
+        self.stalls.lock().unwrap().remove(&job.id)
+    }
+}
+
+/// The worker `id` in `slot`. Takes jobs until it's been replaced, and beats every `BEAT` while working on
+/// them or waiting for one. Only reports a job as completed if it hasn't been replaced in the meantime,
+/// since then the job has been given to someone else. Hangs for `STALL` on a job which it `should_stall` on.
+fn worker(shared: &Arc<Shared>, slot: usize, id: usize) {
+    loop {
+        if !shared.beat(slot, id, None) {
+            return;
+        }
+        // Waits at most a beat, so it can beat while there's nothing to do
+        let job = match shared.jobs.1.recv_timeout(BEAT) {
+            Ok(job) => job,
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => return,
+        };
+        if !shared.beat(slot, id, Some(job.clone())) {
+            // Replaced while waiting, so the job would be lost if this worker didn't put it back
+            shared.jobs.0.send(job).unwrap();
+            return;
+        }
+
+        if shared.should_stall(&job) {
+            thread::sleep(STALL);
+        }
+        let started = Instant::now();
+        while started.elapsed() < job.duration {
+            thread::sleep((job.duration - started.elapsed()).min(BEAT));
+            if !shared.beat(slot, id, Some(job.clone())) {
+                return;
+            }
+        }
+
+        // Reports while holding the lock, so the monitor can't replace this worker in between
+        let mut heartbeat = shared.slots[slot].lock().unwrap();
+        if heartbeat.worker != id {
+            return;
+        }
+        heartbeat.job = None;
+        heartbeat.last_beat = Instant::now();
+        shared
+            .completed
+            .send(Completion {
+                job: job.id,
+                worker: id,
+            })
+            .unwrap();
+    }
+}
+
+/// Makes one round of checks. Replaces every worker which hasn't beaten for `TIMEOUT`,
+/// and puts the job it was working on back in the queue.
+fn check(shared: &Arc<Shared>) -> Vec<Replacement> {
+    let mut replaced = Vec::new();
+    for (slot, heartbeat) in shared.slots.iter().enumerate() {
+        // Holding the lock keeps the stalled worker from finishing its job while it's being replaced
+        let mut heartbeat = heartbeat.lock().unwrap();
+        let silent_for = heartbeat.last_beat.elapsed();
+        if silent_for < TIMEOUT {
+            continue;
+        }
+        let job = heartbeat.job.take();
+        if let Some(job) = &job {
+            shared.jobs.0.send(job.clone()).unwrap();
+        }
+        let stalled = heartbeat.worker;
+        let replacement = shared.spawn_worker(slot, &mut heartbeat);
+        replaced.push(Replacement {
+            slot,
+            stalled,
+            replacement,
+            job: job.map(|job| job.id),
+            silent_for,
+        });
+    }
+    replaced
+}
+
+/// Runs every job in `jobs` on `workers` workers, while a monitor replaces workers which stall.
+/// The first worker to take any of the jobs in `stalls` stalls. Returns once every job has been completed.
+pub fn run_supervised(jobs: Vec<Job>, workers: usize, stalls: &[usize]) -> Report {
+    let (completed, completions) = crossbeam_channel::unbounded();
+    let shared = Arc::new(Shared {
+        slots: (0..workers)
+            .map(|_| {
+                Mutex::new(Heartbeat {
+                    worker: usize::MAX,
+                    last_beat: Instant::now(),
+                    job: None,
+                })
+            })
+            .collect(),
+        jobs: crossbeam_channel::unbounded(),
+        completed,
+        stalls: Mutex::new(stalls.iter().copied().collect()),
+        next_worker: AtomicUsize::new(0),
+    });
+    for slot in 0..workers {
+        shared.spawn_worker(slot, &mut shared.slots[slot].lock().unwrap());
+    }
+    let count = jobs.len();
+    for job in jobs {
+        shared.jobs.0.send(job).unwrap();
+    }
+
+    let mut report = Report::default();
+    let mut next_check = Instant::now() + CHECK;
+    while report.completed.len() < count {
+        // Checks on time even when jobs are completed more often than every `CHECK`
+        match completions.recv_deadline(next_check) {
+            Ok(completion) => report.completed.push(completion),
+            Err(_) => {
+                report.replaced.extend(check(&shared));
+                next_check = Instant::now() + CHECK;
+            }
+        }
+    }
+    // Stops the workers which are still running, since none of them are in a slot anymore
+    for slot in &shared.slots {
+        slot.lock().unwrap().worker = usize::MAX;
+    }
+    report
+}
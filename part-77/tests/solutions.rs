@@ -0,0 +1,70 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-77 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::time::Duration;
+
+use part_77::{run_supervised, solutions, Job};
+
+#[test]
+fn supervises_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let durations = common::datagen::numbers_below(12, 20, rng.seed());
+    let jobs: Vec<Job> = durations
+        .iter()
+        .enumerate()
+        .map(|(id, &millis)| Job {
+            id,
+            duration: Duration::from_millis(millis),
+        })
+        .collect();
+    let expected_jobs: Vec<Job> = jobs
+        .iter()
+        .map(|job| Job {
+            id: job.id,
+            duration: job.duration,
+        })
+        .collect();
+    let stalls = [2, 7];
+
+    // Which worker did what depends on the timing, but not which jobs were done and which workers were replaced
+    let report = run_supervised(jobs, 3, &stalls);
+    let expected = solutions::run_supervised(expected_jobs, 3, &stalls);
+    let done = |jobs: Vec<usize>| {
+        let mut jobs = jobs;
+        jobs.sort();
+        jobs
+    };
+    assert_eq!(
+        done(
+            report
+                .completed
+                .iter()
+                .map(|completion| completion.job)
+                .collect()
+        ),
+        done(
+            expected
+                .completed
+                .iter()
+                .map(|completion| completion.job)
+                .collect()
+        )
+    );
+    assert_eq!(
+        done(
+            report
+                .replaced
+                .iter()
+                .filter_map(|replacement| replacement.job)
+                .collect()
+        ),
+        done(
+            expected
+                .replaced
+                .iter()
+                .filter_map(|replacement| replacement.job)
+                .collect()
+        )
+    );
+}
@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use common::{bench::Comparison, cli::Cli};
+use part_78::{fixed_updates, hammer, Strategy};
+
+const THREADS: usize = 8;
+
+/// Run with `cargo run --release -p part-78` to compare the strategies
+fn main() {
+    common::init_tracing();
+    let args = Cli::new("Updates one counter from many threads, spinning and backing off")
+        .items(100_000)
+        .threads(THREADS)
+        .strategies(["spin", "backoff"])
+        .parse();
+    let threads = args.threads();
+    println!("{threads} threads updating one counter");
+    let mut comparison = Comparison::new(10);
+    for strategy in [Strategy::Spin, Strategy::Backoff] {
+        comparison.bench(&format!("{strategy:?}"), || {
+            fixed_updates(threads, args.items() as u64, strategy)
+        });
+    }
+    comparison.print();
+
+    for strategy in [Strategy::Spin, Strategy::Backoff] {
+        if !args.runs(&format!("{strategy:?}")) {
+            continue;
+        }
+        let contention = hammer(threads, Duration::from_millis(500), strategy);
+        println!(
+            "{strategy:?}: {:.0} updates per second, fairness {:.2}, per thread {:?}",
+            contention.throughput(),
+            contention.fairness(),
+            contention.per_thread
+        );
+    }
+}
+
+#[test]
+fn backs_off_further_every_time() {
+    use part_78::{Backoff, SPIN_LIMIT};
+
+    let mut backoff = Backoff::new(78);
+    for step in 0..=SPIN_LIMIT {
+        assert_eq!(backoff.step(), step);
+        assert!(!backoff.is_yielding());
+        let spins = backoff.snooze();
+        assert!(
+            (1..=1 << step).contains(&spins),
+            "{spins} spins at step {step}"
+        );
+    }
+    assert!(backoff.is_yielding());
+    assert_eq!(backoff.snooze(), 0);
+    assert_eq!(backoff.snooze(), 0);
+    assert_eq!(backoff.step(), SPIN_LIMIT + 1);
+
+    backoff.reset();
+    assert_eq!(backoff.step(), 0);
+    assert!(!backoff.is_yielding());
+}
+
+#[test]
+fn spins_for_random_amounts() {
+    use part_78::{Backoff, SPIN_LIMIT};
+
+    let mut spins = std::collections::HashSet::new();
+    let mut backoff = Backoff::new(78);
+    for _ in 0..100 {
+        backoff.reset();
+        for _ in 0..SPIN_LIMIT {
+            backoff.snooze();
+        }
+        spins.insert(backoff.snooze());
+    }
+    assert!(spins.len() > 10, "Only ever spun {spins:?} times");
+}
+
+#[test]
+fn backoff_updates_once() {
+    use part_78::{backoff_update, Backoff};
+    use std::sync::atomic::AtomicU64;
+
+    let counter = AtomicU64::new(41);
+    assert_eq!(backoff_update(&counter, &mut Backoff::new(1)), 42);
+    assert_eq!(counter.into_inner(), 42);
+}
+
+#[test]
+fn counts_exactly() {
+    for strategy in [Strategy::Spin, Strategy::Backoff] {
+        assert_eq!(
+            fixed_updates(THREADS, 10_000, strategy),
+            80_000,
+            "{strategy:?}"
+        );
+    }
+}
+
+#[test]
+fn counts_every_update_while_hammering() {
+    let contention = hammer(THREADS, Duration::from_millis(100), Strategy::Backoff);
+    assert_eq!(contention.counter, contention.total());
+    assert!(contention.total() > 0);
+}
+
+#[test]
+fn no_thread_starves_with_backoff() {
+    let contention = hammer(THREADS, Duration::from_millis(300), Strategy::Backoff);
+    // Every thread gets at least a fifth of its fair share
+    let fair_share = contention.total() / THREADS as u64;
+    for (thread, &updates) in contention.per_thread.iter().enumerate() {
+        assert!(
+            updates >= fair_share / 5,
+            "Thread {thread} only got {updates} of {:?}",
+            contention.per_thread
+        );
+    }
+}
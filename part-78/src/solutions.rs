@@ -0,0 +1,157 @@
+//! Part 78, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    hint,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+use super::{spin_update, work, Contention, SPIN_LIMIT};
+
+/// Waits a bit longer every time it's asked to, starting over when it's `reset`.
+/// How long it spins is random, so that threads which failed at the same time don't all try again at the same time.
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    step: u32,
+    /// The state of a xorshift generator, which is never zero
+    random: u64,
+}
+
+impl Backoff {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            step: 0,
+            random: seed | 1,
+        }
+    }
+
+    /// How many times it has waited since it was created or reset, up to one more than `SPIN_LIMIT`
+    pub fn step(&self) -> u32 {
+        self.step
+    }
+
+    /// Whether it has given up on spinning, and yields to other threads instead
+    pub fn is_yielding(&self) -> bool {
+        self.step > SPIN_LIMIT
+    }
+
+    pub fn reset(&mut self) {
+        self.step = 0;
+    }
+
+    /// A random number
+    fn next_random(&mut self) -> u64 {
+        self.random ^= self.random << 13;
+        self.random ^= self.random >> 7;
+        self.random ^= self.random << 17;
+        self.random
+    }
+
+    /// Waits, and moves on to the next step, unless it's yielding already.
+    /// Up to `SPIN_LIMIT`, it spins a random number of times between 1 and 2^step, and returns how many.
+    /// After that, it yields to other threads instead, and returns 0.
+    pub fn snooze(&mut self) -> u32 {
+        let spins = if self.is_yielding() {
+            thread::yield_now();
+            0
+        } else {
+            let spins = 1 + (self.next_random() % (1 << self.step)) as u32;
+            for _ in 0..spins {
+                hint::spin_loop();
+            }
+            spins
+        };
+        self.step = (self.step + 1).min(SPIN_LIMIT + 1);
+        spins
+    }
+}
+
+/// Like `spin_update`, but waits for a while with `backoff` after every failed attempt
+pub fn backoff_update(counter: &AtomicU64, backoff: &mut Backoff) -> u64 {
+    // Every update starts out hopeful
+    backoff.reset();
+    let mut current = counter.load(Ordering::Relaxed);
+    loop {
+        let new = work(current);
+        match counter.compare_exchange_weak(current, new, Ordering::Relaxed, Ordering::Relaxed) {
+            Ok(_) => return new,
+            Err(_) => {
+                backoff.snooze();
+                // What the counter was when it failed is likely out of date by now
+                current = counter.load(Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+/// How to retry when another thread updated the counter first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    Spin,
+    Backoff,
+}
+
+impl Strategy {
+    /// Updates `counter` once with this strategy. `backoff` is only used by `Strategy::Backoff`.
+    fn update(self, counter: &AtomicU64, backoff: &mut Backoff) -> u64 {
+        match self {
+            Strategy::Spin => spin_update(counter),
+            Strategy::Backoff => backoff_update(counter, backoff),
+        }
+    }
+}
+
+/// Lets `threads` threads each update one shared counter `updates` times, with `strategy`.
+/// Returns the counter, which should be `threads * updates`.
+pub fn fixed_updates(threads: usize, updates: u64, strategy: Strategy) -> u64 {
+    let counter = AtomicU64::new(0);
+    thread::scope(|s| {
+        for thread in 0..threads {
+            let counter = &counter;
+            s.spawn(move || {
+                let _worker = common::span::worker(thread);
+                let mut backoff = Backoff::new(thread as u64 + 1);
+                for _ in 0..updates {
+                    strategy.update(counter, &mut backoff);
+                }
+            });
+        }
+    });
+    counter.into_inner()
+}
+
+/// Lets `threads` threads update one shared counter as often as they can for `duration`, with `strategy`
+pub fn hammer(threads: usize, duration: Duration, strategy: Strategy) -> Contention {
+    let counter = AtomicU64::new(0);
+    let stop = AtomicBool::new(false);
+    let start = Instant::now();
+    let per_thread = thread::scope(|s| {
+        let handles: Vec<_> = (0..threads)
+            .map(|thread| {
+                let (counter, stop) = (&counter, &stop);
+                s.spawn(move || {
+                    let _worker = common::span::worker(thread);
+                    let mut backoff = Backoff::new(thread as u64 + 1);
+                    let mut updates = 0;
+                    while !stop.load(Ordering::Relaxed) {
+                        strategy.update(counter, &mut backoff);
+                        updates += 1;
+                    }
+                    updates
+                })
+            })
+            .collect();
+        thread::sleep(duration);
+        stop.store(true, Ordering::Relaxed);
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    });
+    Contention {
+        per_thread,
+        counter: counter.into_inner(),
+        elapsed: start.elapsed(),
+    }
+}
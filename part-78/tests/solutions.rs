@@ -0,0 +1,41 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-78 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::sync::atomic::AtomicU64;
+
+use part_78::{backoff_update, fixed_updates, solutions, Backoff, Strategy, SPIN_LIMIT};
+
+#[test]
+fn snoozes_like_the_solution() {
+    for seed in [1, 78, u64::MAX] {
+        let (mut backoff, mut expected) = (Backoff::new(seed), solutions::Backoff::new(seed));
+        for _ in 0..=SPIN_LIMIT + 2 {
+            assert_eq!(backoff.snooze(), expected.snooze(), "Seeded with {seed}");
+            assert_eq!(backoff.step(), expected.step());
+            assert_eq!(backoff.is_yielding(), expected.is_yielding());
+        }
+        backoff.reset();
+        expected.reset();
+        assert_eq!(backoff.snooze(), expected.snooze());
+    }
+}
+
+#[test]
+fn updates_like_the_solution() {
+    let (counter, expected) = (AtomicU64::new(78), AtomicU64::new(78));
+    let (mut backoff, mut expected_backoff) = (Backoff::new(78), solutions::Backoff::new(78));
+    for _ in 0..10 {
+        assert_eq!(
+            backoff_update(&counter, &mut backoff),
+            solutions::backoff_update(&expected, &mut expected_backoff)
+        );
+    }
+    for threads in [1, 4] {
+        assert_eq!(
+            fixed_updates(threads, 1000, Strategy::Backoff),
+            solutions::fixed_updates(threads, 1000, solutions::Strategy::Backoff),
+            "{threads} threads"
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use std::{
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+    thread,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// A counter which many threads can add to at once
+pub trait Counter: Sync {
+    fn add(&self, n: u64);
+
+    /// Everything which has been added so far
+    fn sum(&self) -> u64;
+}
+
+/// Just one atomic, which every thread adds to
+#[derive(Debug, Default)]
+pub struct AtomicCounter(AtomicU64);
+
+impl Counter for AtomicCounter {
+    fn add(&self, n: u64) {
+        self.0.fetch_add(n, Ordering::Relaxed);
+    }
+
+    fn sum(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// One part of a `ShardedCounter`, on a cache line of its own so that adding to it doesn't slow down the other shards.
+/// Some CPUs fetch cache lines two at a time, so 128 bytes is safer than 64.
+#[derive(Debug, Default)]
+#[repr(align(128))]
+struct Shard(AtomicU64);
+
+/// Gives every thread which asks for one a different number, counting up from 0
+static NEXT_THREAD: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// The number of the current thread, which decides which shard it adds to
+    static THREAD: usize = NEXT_THREAD.fetch_add(1, Ordering::Relaxed);
+}
+
+/// The number of the current thread. Every thread gets a different one, the first time it asks.
+pub fn thread_number() -> usize {
+    THREAD.with(|thread| *thread)
+}
+
+/// A counter split up into shards, where every thread adds to its own shard, and reading it sums them all.
+/// Threads only share a shard if there are more threads than shards.
+#[derive(Debug)]
+pub struct ShardedCounter {
+    shards: Box<[Shard]>,
+}
+
+impl ShardedCounter {
+    pub fn new(shards: usize) -> Self {
+        assert!(shards > 0, "A counter needs at least one shard");
+        Self {
+            shards: (0..shards).map(|_| Shard::default()).collect(),
+        }
+    }
+
+    /// One shard for every thread the machine can run at once
+    pub fn per_core() -> Self {
+        Self::new(thread::available_parallelism().map_or(4, |n| n.get()))
+    }
+
+    pub fn shards(&self) -> usize {
+        self.shards.len()
+    }
+}
+
+impl Counter for ShardedCounter {
+    /// Adds `n` to the shard of the current thread
+    fn add(&self, n: u64) {
+        todo!()
+    }
+
+    /// Sums up all the shards. While other threads are adding, that's not the count at any one moment,
+    /// but it's never less than what had been added before it started, and never more than what had been added when it finished.
+    fn sum(&self) -> u64 {
+        todo!()
+    }
+}
+
+/// Lets `threads` threads add 1 to `counter` `increments` times each, and returns the sum once they're done
+pub fn count(counter: &impl Counter, threads: usize, increments: u64) -> u64 {
+    thread::scope(|s| {
+        for id in 0..threads {
+            s.spawn(move || {
+                let _worker = common::span::worker(id);
+                for _ in 0..increments {
+                    counter.add(1);
+                }
+            });
+        }
+    });
+    counter.sum()
+}
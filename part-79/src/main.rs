@@ -0,0 +1,84 @@
+use common::{bench::Comparison, cli::Cli};
+use part_79::{count, AtomicCounter, ShardedCounter};
+
+const INCREMENTS: u64 = 1_000_000;
+
+/// Run with `cargo run --release -p part-79` to see the difference
+fn main() {
+    common::init_tracing();
+    let args = Cli::new("Counts on one atomic, and on a counter sharded per core")
+        .items(INCREMENTS as usize)
+        .threads(std::thread::available_parallelism().map_or(4, |n| n.get()))
+        .strategy()
+        .parse();
+    let (increments, threads) = (args.items() as u64, args.threads());
+    println!("Counting to {increments} on each of {threads} threads");
+    let expected = threads as u64 * increments;
+    let mut comparison = Comparison::new(10);
+    comparison
+        .bench("One atomic", || {
+            assert_eq!(
+                count(&AtomicCounter::default(), threads, increments),
+                expected
+            )
+        })
+        .bench("Sharded", || {
+            assert_eq!(
+                count(&ShardedCounter::per_core(), threads, increments),
+                expected
+            )
+        });
+    comparison.print();
+}
+
+#[test]
+fn atomic_counts_exactly() {
+    assert_eq!(count(&AtomicCounter::default(), 8, 10_000), 80_000);
+}
+
+#[test]
+fn sharded_counts_exactly() {
+    assert_eq!(count(&ShardedCounter::new(8), 8, 10_000), 80_000);
+}
+
+#[test]
+fn more_threads_than_shards() {
+    assert_eq!(count(&ShardedCounter::new(3), 8, 10_000), 80_000);
+}
+
+#[test]
+fn a_single_shard_is_just_an_atomic() {
+    assert_eq!(count(&ShardedCounter::new(1), 4, 10_000), 40_000);
+}
+
+#[test]
+fn adds_more_than_one() {
+    use part_79::Counter;
+
+    let counter = ShardedCounter::new(4);
+    counter.add(40);
+    counter.add(2);
+    assert_eq!(counter.sum(), 42);
+}
+
+#[test]
+fn sums_never_go_down_while_counting() {
+    use part_79::Counter;
+
+    let counter = ShardedCounter::new(4);
+    let last = std::thread::scope(|s| {
+        let reader = s.spawn(|| {
+            let mut last = 0;
+            while last < 40_000 {
+                let sum = counter.sum();
+                assert!(sum >= last, "Went down from {last} to {sum}");
+                assert!(sum <= 40_000);
+                last = sum;
+            }
+            last
+        });
+        count(&counter, 4, 10_000);
+        reader.join().unwrap()
+    });
+    assert_eq!(last, 40_000);
+}
@@ -0,0 +1,47 @@
+//! Part 79, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{sync::atomic::Ordering, thread};
+
+use super::{thread_number, Counter, Shard};
+
+/// A counter split up into shards, where every thread adds to its own shard, and reading it sums them all.
+/// Threads only share a shard if there are more threads than shards.
+#[derive(Debug)]
+pub struct ShardedCounter {
+    shards: Box<[Shard]>,
+}
+
+impl ShardedCounter {
+    pub fn new(shards: usize) -> Self {
+        assert!(shards > 0, "A counter needs at least one shard");
+        Self {
+            shards: (0..shards).map(|_| Shard::default()).collect(),
+        }
+    }
+
+    /// One shard for every thread the machine can run at once
+    pub fn per_core() -> Self {
+        Self::new(thread::available_parallelism().map_or(4, |n| n.get()))
+    }
+
+    pub fn shards(&self) -> usize {
+        self.shards.len()
+    }
+}
+
+impl Counter for ShardedCounter {
+    /// Adds `n` to the shard of the current thread
+    fn add(&self, n: u64) {
+        let shard = &self.shards[thread_number() % self.shards.len()];
+        shard.0.fetch_add(n, Ordering::Relaxed);
+    }
+
+    /// Sums up all the shards. While other threads are adding, that's not the count at any one moment,
+    /// but it's never less than what had been added before it started, and never more than what had been added when it finished.
+    fn sum(&self) -> u64 {
+        self.shards
+            .iter()
+            .map(|shard| shard.0.load(Ordering::Relaxed))
+            .sum()
+    }
+}
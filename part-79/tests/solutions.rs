@@ -0,0 +1,27 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-79 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_79::{count, solutions, Counter, ShardedCounter};
+
+#[test]
+fn adds_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (counter, expected) = (ShardedCounter::new(4), solutions::ShardedCounter::new(4));
+    for n in common::datagen::numbers_below(100, 1000, rng.seed()) {
+        counter.add(n);
+        Counter::add(&expected, n);
+        assert_eq!(counter.sum(), Counter::sum(&expected));
+    }
+}
+
+#[test]
+fn counts_like_the_solution() {
+    for (shards, threads) in [(1, 1), (1, 4), (4, 4), (2, 8)] {
+        assert_eq!(
+            count(&ShardedCounter::new(shards), threads, 10_000),
+            count(&solutions::ShardedCounter::new(shards), threads, 10_000),
+            "{shards} shards, {threads} threads"
+        );
+    }
+}
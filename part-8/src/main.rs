@@ -0,0 +1,87 @@
+use tokio::sync::mpsc::Receiver;
+
+#[cfg(all(test, feature = "solutions"))]
+mod solutions;
+
+/// How many messages the channels can hold before senders have to wait
+const CAPACITY: usize = 4;
+
+#[tokio::main]
+async fn main() {
+    let mut receiver = across_the_border();
+    while let Some(x) = receiver.recv().await {
+        println!("Got: {x}");
+    }
+
+    let mut receiver = producers();
+    while let Some(x) = receiver.recv().await {
+        println!("Got from producer: {x}");
+    }
+}
+
+/// Returns a receiver that will receive the numbers `0, 1, ..., 9` in that order,
+/// sent from a spawned task over a channel holding at most `CAPACITY` messages.
+fn across_the_border() -> Receiver<i32> {
+    todo!()
+}
+
+/// Spawns ten tasks, each sending their own, single value (one of `0, 1, ..., 9`)
+/// over a channel holding at most `CAPACITY` messages.
+fn producers() -> Receiver<i32> {
+    todo!()
+}
+
+#[tokio::test]
+async fn sends_data_correctly() {
+    let mut receiver = across_the_border();
+    let mut result = Vec::new();
+    while let Some(x) = receiver.recv().await {
+        result.push(x);
+    }
+
+    assert_eq!(result, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9])
+}
+
+#[tokio::test]
+async fn channel_is_bounded() {
+    let receiver = across_the_border();
+    assert_eq!(receiver.max_capacity(), CAPACITY);
+}
+
+#[tokio::test]
+async fn sends_messages_correctly() {
+    use std::collections::HashSet;
+
+    let mut receiver = producers();
+    assert_eq!(receiver.max_capacity(), CAPACITY);
+
+    let mut results = HashSet::new();
+    while let Some(x) = receiver.recv().await {
+        results.insert(x);
+    }
+
+    assert_eq!(results, HashSet::from_iter(0..10))
+}
+
+#[cfg(feature = "solutions")]
+#[tokio::test]
+async fn sends_like_the_solution() {
+    async fn received(mut receiver: Receiver<i32>) -> Vec<i32> {
+        let mut result = Vec::new();
+        while let Some(x) = receiver.recv().await {
+            result.push(x);
+        }
+        result
+    }
+
+    assert_eq!(
+        received(across_the_border()).await,
+        received(solutions::across_the_border()).await
+    );
+    // The tasks can send in any order
+    let mut results = received(producers()).await;
+    let mut expected = received(solutions::producers()).await;
+    results.sort();
+    expected.sort();
+    assert_eq!(results, expected);
+}
@@ -0,0 +1,33 @@
+//! Part 8, with every exercise implemented. Only built with `--features solutions`.
+
+use tokio::sync::mpsc::Receiver;
+
+use super::CAPACITY;
+
+/// Returns a receiver that will receive the numbers `0, 1, ..., 9` in that order,
+/// sent from a spawned task over a channel holding at most `CAPACITY` messages.
+pub fn across_the_border() -> Receiver<i32> {
+    let (tx, rx) = tokio::sync::mpsc::channel(CAPACITY);
+    tokio::spawn(async move {
+        for x in 0..10 {
+            // Waits for room in the channel whenever it is full
+            tx.send(x)
+                .await
+                .expect("Couldn't send value. Receiver dropped");
+        }
+    });
+    rx
+}
+
+/// Spawns ten tasks, each sending their own, single value (one of `0, 1, ..., 9`)
+/// over a channel holding at most `CAPACITY` messages.
+pub fn producers() -> Receiver<i32> {
+    let (sender, receiver) = tokio::sync::mpsc::channel(CAPACITY);
+    for x in 0..10 {
+        let sender = sender.clone();
+        tokio::spawn(async move {
+            sender.send(x).await.expect("Couldn't send message");
+        });
+    }
+    receiver
+}
@@ -0,0 +1,106 @@
+use std::{
+    sync::mpsc::{self, SendError},
+    thread,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How many items a `Batcher` collects before sending them, unless told otherwise
+pub const BATCH_SIZE: usize = 256;
+
+/// One tiny message: which producer sent it, and how many that producer had sent before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Message {
+    pub producer: usize,
+    pub seq: u64,
+}
+
+/// Collects items and sends them through a channel a whole batch at a time, instead of one by one.
+/// Whatever is left over is sent when it's dropped.
+#[derive(Debug)]
+pub struct Batcher<T> {
+    sender: mpsc::Sender<Vec<T>>,
+    batch: Vec<T>,
+    capacity: usize,
+}
+
+impl<T> Batcher<T> {
+    /// Sends batches of `capacity` items through `sender`
+    pub fn new(sender: mpsc::Sender<Vec<T>>, capacity: usize) -> Self {
+        assert!(capacity > 0, "A batch needs room for at least one item");
+        Self {
+            sender,
+            batch: Vec::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Adds `item` to the batch, and sends the batch if it's full.
+    /// Fails if the receiver is gone, in which case the batch is lost.
+    pub fn push(&mut self, item: T) -> Result<(), SendError<Vec<T>>> {
+        todo!()
+    }
+
+    /// Sends what's in the batch right away, if there's anything in it
+    pub fn flush(&mut self) -> Result<(), SendError<Vec<T>>> {
+        todo!()
+    }
+}
+
+impl<T> Drop for Batcher<T> {
+    fn drop(&mut self) {
+        // Flushing while already panicking could panic again, which aborts instead of unwinding
+        if !self.batch.is_empty() && !thread::panicking() {
+            // Nobody is listening anymore if this fails, so there's no one to tell
+            let _ = self.flush();
+        }
+    }
+}
+
+/// Lets `producers` threads send `items` messages each through one channel, one message at a time.
+/// Returns the messages in the order they were received.
+pub fn per_item(producers: usize, items: u64) -> Vec<Message> {
+    let (sender, receiver) = mpsc::channel();
+    for producer in 0..producers {
+        let sender = sender.clone();
+        thread::spawn(move || {
+            let _producer = common::span::worker(producer);
+            for seq in 0..items {
+                sender.send(Message { producer, seq }).unwrap();
+            }
+        });
+    }
+    drop(sender);
+    receiver.iter().collect()
+}
+
+/// Like `per_item`, but every producer sends its messages in batches of `batch_size` with a `Batcher`
+pub fn batched(producers: usize, items: u64, batch_size: usize) -> Vec<Message> {
+    todo!()
+}
+
+/// Checks that `received` has exactly `items` messages from each of `producers` producers,
+/// and that every producer's messages arrived in the order they were sent
+pub fn check_stream(received: &[Message], producers: usize, items: u64) -> Result<(), String> {
+    let mut next = vec![0; producers];
+    for message in received {
+        let expected = next
+            .get_mut(message.producer)
+            .ok_or_else(|| format!("{message:?} is from a producer which doesn't exist"))?;
+        if message.seq != *expected {
+            return Err(format!(
+                "Expected message {expected} from producer {}, but got {}",
+                message.producer, message.seq
+            ));
+        }
+        *expected += 1;
+    }
+    match next.iter().position(|&count| count != items) {
+        Some(producer) => Err(format!(
+            "Got {} of the {items} messages from producer {producer}",
+            next[producer]
+        )),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,93 @@
+use common::{bench::Comparison, cli::Cli};
+use part_80::{batched, check_stream, per_item, BATCH_SIZE};
+
+const PRODUCERS: usize = 4;
+const ITEMS: u64 = 1_000_000;
+
+/// Run with `cargo run --release -p part-80` to see the difference
+fn main() {
+    common::init_tracing();
+    let args = Cli::new("Sends messages from several producers one at a time, and in batches")
+        .items(ITEMS as usize)
+        .threads(PRODUCERS)
+        .strategy()
+        .parse();
+    let (producers, items) = (args.threads(), args.items() as u64);
+    println!("{producers} producers sending {items} messages each");
+    let mut comparison = Comparison::new(5);
+    comparison.bench("One at a time", || {
+        let received = per_item(producers, items);
+        check_stream(&received, producers, items).unwrap();
+    });
+    for batch_size in [16, BATCH_SIZE, 4096] {
+        comparison.bench(&format!("Batches of {batch_size}"), || {
+            let received = batched(producers, items, batch_size);
+            check_stream(&received, producers, items).unwrap();
+        });
+    }
+    comparison.print();
+}
+
+#[test]
+fn batches_of_one_are_still_batches() {
+    let received = batched(3, 1000, 1);
+    assert_eq!(check_stream(&received, 3, 1000), Ok(()));
+}
+
+#[test]
+fn loses_and_reorders_nothing() {
+    let received = batched(PRODUCERS, 100_000, BATCH_SIZE);
+    assert_eq!(check_stream(&received, PRODUCERS, 100_000), Ok(()));
+}
+
+#[test]
+fn sends_the_last_partial_batch() {
+    // 1000 isn't a multiple of 256, so the last batch of every producer isn't full
+    let received = batched(2, 1000, BATCH_SIZE);
+    assert_eq!(received.len(), 2000);
+    assert_eq!(check_stream(&received, 2, 1000), Ok(()));
+}
+
+#[test]
+fn sends_when_the_batch_is_full() {
+    use part_80::Batcher;
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut batcher = Batcher::new(sender, 3);
+    for item in 0..7 {
+        batcher.push(item).unwrap();
+    }
+    assert_eq!(
+        receiver.try_iter().collect::<Vec<_>>(),
+        [vec![0, 1, 2], vec![3, 4, 5]]
+    );
+    batcher.flush().unwrap();
+    assert_eq!(receiver.try_recv(), Ok(vec![6]));
+    // Nothing to flush, so nothing is sent
+    batcher.flush().unwrap();
+    drop(batcher);
+    assert_eq!(receiver.recv(), Err(std::sync::mpsc::RecvError));
+}
+
+#[test]
+fn fails_when_the_receiver_is_gone() {
+    use part_80::Batcher;
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut batcher = Batcher::new(sender, 2);
+    batcher.push(1).unwrap();
+    drop(receiver);
+    assert!(batcher.push(2).is_err());
+}
+
+#[test]
+fn check_stream_catches_mistakes() {
+    use part_80::Message;
+
+    let message = |producer, seq| Message { producer, seq };
+    assert_eq!(check_stream(&[message(0, 0), message(0, 1)], 1, 2), Ok(()));
+    assert!(check_stream(&[message(0, 1), message(0, 0)], 1, 2).is_err());
+    assert!(check_stream(&[message(0, 0)], 1, 2).is_err());
+    assert!(check_stream(&[message(0, 0), message(0, 0)], 1, 1).is_err());
+    assert!(check_stream(&[message(1, 0)], 1, 1).is_err());
+}
@@ -0,0 +1,75 @@
+//! Part 80, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    sync::mpsc::{self, SendError},
+    thread,
+};
+
+use super::Message;
+
+/// Collects items and sends them through a channel a whole batch at a time, instead of one by one.
+/// Whatever is left over is sent when it's dropped.
+#[derive(Debug)]
+pub struct Batcher<T> {
+    sender: mpsc::Sender<Vec<T>>,
+    batch: Vec<T>,
+    capacity: usize,
+}
+
+impl<T> Batcher<T> {
+    /// Sends batches of `capacity` items through `sender`
+    pub fn new(sender: mpsc::Sender<Vec<T>>, capacity: usize) -> Self {
+        assert!(capacity > 0, "A batch needs room for at least one item");
+        Self {
+            sender,
+            batch: Vec::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Adds `item` to the batch, and sends the batch if it's full.
+    /// Fails if the receiver is gone, in which case the batch is lost.
+    pub fn push(&mut self, item: T) -> Result<(), SendError<Vec<T>>> {
+        self.batch.push(item);
+        if self.batch.len() >= self.capacity {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Sends what's in the batch right away, if there's anything in it
+    pub fn flush(&mut self) -> Result<(), SendError<Vec<T>>> {
+        if self.batch.is_empty() {
+            return Ok(());
+        }
+        // The next batch gets a fresh buffer, since this one is sent off with the items
+        let batch = std::mem::replace(&mut self.batch, Vec::with_capacity(self.capacity));
+        self.sender.send(batch)
+    }
+}
+
+impl<T> Drop for Batcher<T> {
+    fn drop(&mut self) {
+        // Flushing while already panicking could panic again, which aborts instead of unwinding
+        if !self.batch.is_empty() && !thread::panicking() {
+            // Nobody is listening anymore if this fails, so there's no one to tell
+            let _ = self.flush();
+        }
+    }
+}
+
+/// Like `per_item`, but every producer sends its messages in batches of `batch_size` with a `Batcher`
+pub fn batched(producers: usize, items: u64, batch_size: usize) -> Vec<Message> {
+    let (sender, receiver) = mpsc::channel();
+    for producer in 0..producers {
+        let mut batcher = Batcher::new(sender.clone(), batch_size);
+        thread::spawn(move || {
+            for seq in 0..items {
+                batcher.push(Message { producer, seq }).unwrap();
+            }
+            // Dropping the batcher at the end of the thread sends the last, partial batch
+        });
+    }
+    drop(sender);
+    receiver.iter().flatten().collect()
+}
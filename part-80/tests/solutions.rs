@@ -0,0 +1,86 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-80 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::sync::mpsc;
+
+use part_80::{batched, solutions, Batcher, Message};
+
+/// The messages, sorted, to compare them with the solution's whichever order the producers got to send them in
+fn sorted(messages: impl IntoIterator<Item = (usize, u64)>) -> Vec<(usize, u64)> {
+    let mut messages: Vec<_> = messages.into_iter().collect();
+    messages.sort();
+    messages
+}
+
+#[test]
+fn batches_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let items = common::datagen::numbers(100, rng.seed());
+    let (sender, receiver) = mpsc::channel();
+    let (expected_sender, expected_receiver) = mpsc::channel();
+    let (mut batcher, mut expected) = (
+        Batcher::new(sender, 7),
+        solutions::Batcher::new(expected_sender, 7),
+    );
+    for (i, &item) in items.iter().enumerate() {
+        batcher.push(item).unwrap();
+        expected.push(item).unwrap();
+        if i % 30 == 0 {
+            batcher.flush().unwrap();
+            expected.flush().unwrap();
+        }
+        assert_eq!(
+            receiver.try_iter().collect::<Vec<_>>(),
+            expected_receiver.try_iter().collect::<Vec<_>>()
+        );
+    }
+    // And the rest when they're dropped
+    drop((batcher, expected));
+    assert_eq!(
+        receiver.iter().collect::<Vec<_>>(),
+        expected_receiver.iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn sends_like_the_solution() {
+    for (producers, items, batch_size) in [(1, 0, 1), (1, 10, 3), (4, 1000, 64), (3, 100, 1000)] {
+        let received = batched(producers, items, batch_size);
+        let expected = solutions::batched(producers, items, batch_size);
+        assert_eq!(
+            sorted(
+                received
+                    .iter()
+                    .map(|&Message { producer, seq }| (producer, seq))
+            ),
+            sorted(
+                expected
+                    .iter()
+                    .map(|message| (message.producer, message.seq))
+            ),
+            "{producers} producers, {items} items each, in batches of {batch_size}"
+        );
+    }
+}
+
+#[test]
+fn fails_like_the_solution() {
+    let (sender, receiver) = mpsc::channel();
+    let (expected_sender, expected_receiver) = mpsc::channel();
+    drop((receiver, expected_receiver));
+    let (mut batcher, mut expected) = (
+        Batcher::new(sender, 2),
+        solutions::Batcher::new(expected_sender, 2),
+    );
+    for item in 0..3 {
+        assert_eq!(
+            batcher.push(item).map_err(|error| error.0),
+            expected.push(item).map_err(|error| error.0)
+        );
+    }
+    assert_eq!(
+        batcher.flush().map_err(|error| error.0),
+        expected.flush().map_err(|error| error.0)
+    );
+}
@@ -0,0 +1,55 @@
+use std::{ops::Range, sync::Arc, thread};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How much of the data a worker scans at a time
+pub const CHUNK_SIZE: usize = 64 * 1024;
+
+/// `len` bytes of random text, with a line break every 40 bytes or so
+pub fn random_text(len: usize, seed: u64) -> Vec<u8> {
+    common::datagen::numbers_below(len, 40, seed)
+        .into_iter()
+        .map(|n| if n == 0 { b'\n' } else { b'a' + (n % 26) as u8 })
+        .collect()
+}
+
+/// How many lines `bytes` has, or rather how many line breaks
+pub fn count_lines(bytes: &[u8]) -> usize {
+    bytes.iter().filter(|&&byte| byte == b'\n').count()
+}
+
+/// The ranges of the chunks `len` bytes are split into, all of them `chunk_size` long except maybe the last one
+pub fn chunks(len: usize, chunk_size: usize) -> impl Iterator<Item = Range<usize>> {
+    (0..len)
+        .step_by(chunk_size)
+        .map(move |start| start..(start + chunk_size).min(len))
+}
+
+/// Lets `workers` threads count the lines in `data` a chunk at a time.
+/// Every chunk is copied into a `Vec` of its own, which is sent to whichever worker is free.
+pub fn copied_scan(data: &[u8], workers: usize, chunk_size: usize) -> usize {
+    let (jobs, work) = crossbeam_channel::unbounded::<Vec<u8>>();
+    let (counts, results) = crossbeam_channel::unbounded();
+    for id in 0..workers {
+        let (work, counts) = (work.clone(), counts.clone());
+        thread::spawn(move || {
+            let _worker = common::span::worker(id);
+            for chunk in work {
+                counts.send(count_lines(&chunk)).unwrap();
+            }
+        });
+    }
+    drop(counts);
+    for range in chunks(data.len(), chunk_size) {
+        jobs.send(data[range].to_vec()).unwrap();
+    }
+    // Lets the workers stop once they're done with the last chunk
+    drop(jobs);
+    results.iter().sum()
+}
+
+/// Like `copied_scan`, but without copying anything. Every worker gets the whole of `data` and the range of the chunk to scan.
+pub fn shared_scan(data: Arc<[u8]>, workers: usize, chunk_size: usize) -> usize {
+    todo!()
+}
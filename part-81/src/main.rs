@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use common::{
+    alloc::{count_allocations, CountingAllocator},
+    bench::Comparison,
+    cli::Cli,
+};
+use part_81::{copied_scan, count_lines, random_text, shared_scan, CHUNK_SIZE};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const LEN: usize = 64 * 1024 * 1024;
+const WORKERS: usize = 4;
+
+/// Run with `cargo run --release -p part-81` to see the difference
+fn main() {
+    common::init_tracing();
+    let args = Cli::new("Counts lines in copied chunks, and in chunks of shared data")
+        .items(LEN)
+        .threads(WORKERS)
+        .strategy()
+        .parse();
+    let (len, workers) = (args.items(), args.threads());
+    let text = random_text(len, 81);
+    let expected = count_lines(&text);
+    let shared: Arc<[u8]> = text.clone().into();
+    println!("Counting {expected} lines in {} MiB", len / 1024 / 1024);
+
+    let (_, copied) = count_allocations(|| copied_scan(&text, workers, CHUNK_SIZE));
+    let (_, zero_copy) = count_allocations(|| shared_scan(shared.clone(), workers, CHUNK_SIZE));
+    println!("Copied chunks: {copied:?}");
+    println!("Shared data:   {zero_copy:?}");
+
+    let mut comparison = Comparison::new(10);
+    comparison
+        .bench("Copied chunks", || {
+            assert_eq!(copied_scan(&text, workers, CHUNK_SIZE), expected)
+        })
+        .bench("Shared data", || {
+            assert_eq!(shared_scan(shared.clone(), workers, CHUNK_SIZE), expected)
+        });
+    comparison.print();
+}
+
+/// Runs `f` while no other test is counting allocations, since they'd count what `f` allocates too
+#[cfg(test)]
+fn without_counting(f: impl FnOnce()) {
+    count_allocations(f);
+}
+
+#[test]
+fn copied_scan_counts_every_line() {
+    let mut rng = common::rng::for_test();
+    without_counting(|| {
+        let text = random_text(1_000_000, rng.seed());
+        assert_eq!(copied_scan(&text, WORKERS, CHUNK_SIZE), count_lines(&text));
+    });
+}
+
+#[test]
+fn shared_scan_counts_every_line() {
+    let mut rng = common::rng::for_test();
+    without_counting(|| {
+        let text = random_text(1_000_000, rng.seed());
+        let expected = count_lines(&text);
+        assert_eq!(shared_scan(text.into(), WORKERS, CHUNK_SIZE), expected);
+    });
+}
+
+#[test]
+fn handles_a_short_last_chunk() {
+    let mut rng = common::rng::for_test();
+    without_counting(|| {
+        let text = random_text(10_007, rng.seed());
+        let expected = count_lines(&text);
+        assert_eq!(shared_scan(text.into(), 3, 1000), expected);
+    });
+}
+
+#[test]
+fn handles_no_data() {
+    without_counting(|| {
+        assert_eq!(shared_scan(Arc::new([]), WORKERS, CHUNK_SIZE), 0);
+    });
+}
+
+#[test]
+fn copying_allocates_the_whole_payload() {
+    let mut rng = common::rng::for_test();
+    let text = random_text(4 * 1024 * 1024, rng.seed());
+    let (_, allocated) = count_allocations(|| copied_scan(&text, WORKERS, CHUNK_SIZE));
+    assert!(allocated.bytes >= text.len() as u64, "{allocated:?}");
+}
+
+#[test]
+fn sharing_doesnt_copy_the_payload() {
+    let mut rng = common::rng::for_test();
+    let text: Arc<[u8]> = random_text(4 * 1024 * 1024, rng.seed()).into();
+    let (_, allocated) = count_allocations(|| shared_scan(text.clone(), WORKERS, CHUNK_SIZE));
+    // Just the channels and the threads, which is a tiny fraction of the payload
+    assert!(allocated.bytes < text.len() as u64 / 20, "{allocated:?}");
+}
@@ -0,0 +1,22 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-81 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::sync::Arc;
+
+use part_81::{random_text, shared_scan, solutions};
+
+#[test]
+fn scans_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    for len in [0, 1, 100_000] {
+        let data: Arc<[u8]> = random_text(len, rng.seed()).into();
+        for (workers, chunk_size) in [(1, 1000), (4, 1000), (3, 7), (8, 1_000_000)] {
+            assert_eq!(
+                shared_scan(Arc::clone(&data), workers, chunk_size),
+                solutions::shared_scan(Arc::clone(&data), workers, chunk_size),
+                "{len} bytes, {workers} workers, chunks of {chunk_size}"
+            );
+        }
+    }
+}
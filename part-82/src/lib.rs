@@ -0,0 +1,187 @@
+use std::{
+    fs,
+    io::{self, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    sync::Semaphore,
+    task::JoinSet,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Writes `files` files of `size` bytes each to `dir`, returning their paths
+pub fn generate_files(dir: &Path, files: usize, size: usize) -> io::Result<Vec<PathBuf>> {
+    (0..files)
+        .map(|file| {
+            let path = dir.join(format!("file-{file}.bin"));
+            let contents: Vec<u8> = (0..size).map(|i| (file * 31 + i * 7) as u8).collect();
+            fs::write(&path, contents)?;
+            Ok(path)
+        })
+        .collect()
+}
+
+/// Something to check that every byte was read, which adds up the same in any order
+pub fn checksum(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .enumerate()
+        .map(|(i, &byte)| (i as u64 + 1) * byte as u64)
+        .sum()
+}
+
+/// Reads the files one after the other, and adds up their checksums
+pub fn serial_read(paths: &[PathBuf]) -> io::Result<u64> {
+    paths
+        .iter()
+        .map(|path| Ok(checksum(&fs::read(path)?)))
+        .sum()
+}
+
+/// Reads the files on `threads` threads, where each thread takes the next file nobody has taken yet
+pub fn blocking_read(paths: &[PathBuf], threads: usize) -> io::Result<u64> {
+    let next = AtomicUsize::new(0);
+    thread::scope(|s| {
+        let handles: Vec<_> = (0..threads)
+            .map(|id| {
+                let next = &next;
+                s.spawn(move || {
+                    let _worker = common::span::worker(id);
+                    let mut total = 0;
+                    while let Some(path) = paths.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        total += checksum(&fs::read(path)?);
+                    }
+                    Ok(total)
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .sum()
+    })
+}
+
+/// Reads every file in a task of its own with `tokio::fs`
+pub async fn async_read(paths: Vec<PathBuf>) -> io::Result<u64> {
+    todo!()
+}
+
+/// Reads the files with one `spawn_blocking` for every `batch` files, which reads them with `std::fs`
+pub async fn spawn_blocking_read(paths: Vec<PathBuf>, batch: usize) -> io::Result<u64> {
+    todo!()
+}
+
+/// What the server answers to `request`
+pub fn response(request: u64) -> u64 {
+    request * request
+}
+
+/// The sum of the responses to all the requests from 0 up to `requests`
+pub fn expected_responses(requests: u64) -> u64 {
+    (0..requests).map(response).sum()
+}
+
+/// A server which takes a while to answer, like one that has to ask a database first.
+/// Every connection sends one number, and gets its `response` back after `delay`.
+/// It keeps running in the background until the program ends.
+#[derive(Debug)]
+pub struct SlowServer {
+    addr: SocketAddr,
+    served: Arc<AtomicU64>,
+}
+
+impl SlowServer {
+    pub fn start(delay: Duration) -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let served = Arc::new(AtomicU64::new(0));
+        let counter = served.clone();
+        thread::spawn(move || {
+            let _server = common::span::thread("server");
+            for stream in listener.incoming().flatten() {
+                let counter = counter.clone();
+                // A thread for every connection, so the server is never what the clients wait for
+                thread::spawn(move || serve(stream, delay, &counter));
+            }
+        });
+        Ok(Self { addr, served })
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// How many requests it has answered
+    pub fn served(&self) -> u64 {
+        self.served.load(Ordering::Relaxed)
+    }
+}
+
+fn serve(mut stream: TcpStream, delay: Duration, served: &AtomicU64) -> io::Result<()> {
+    let mut request = [0; 8];
+    stream.read_exact(&mut request)?;
+    thread::sleep(delay);
+    // Counted before answering, so a client which got its response knows it's been counted
+    served.fetch_add(1, Ordering::Relaxed);
+    stream.write_all(&response(u64::from_le_bytes(request)).to_le_bytes())
+}
+
+/// Sends `request` to the server at `addr` over a connection of its own, and waits for the response
+pub fn blocking_request(addr: SocketAddr, request: u64) -> io::Result<u64> {
+    let mut stream = TcpStream::connect(addr)?;
+    stream.write_all(&request.to_le_bytes())?;
+    let mut response = [0; 8];
+    stream.read_exact(&mut response)?;
+    Ok(u64::from_le_bytes(response))
+}
+
+/// Sends every request from 0 up to `requests` to the server at `addr` from `threads` threads,
+/// and adds up the responses
+pub fn blocking_fetch(addr: SocketAddr, requests: u64, threads: usize) -> io::Result<u64> {
+    let next = AtomicU64::new(0);
+    thread::scope(|s| {
+        let handles: Vec<_> = (0..threads)
+            .map(|id| {
+                let next = &next;
+                s.spawn(move || {
+                    let _worker = common::span::worker(id);
+                    let mut total = 0;
+                    loop {
+                        let request = next.fetch_add(1, Ordering::Relaxed);
+                        if request >= requests {
+                            return Ok(total);
+                        }
+                        let _item = common::span::item(request as usize);
+                        total += blocking_request(addr, request)?;
+                    }
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .sum()
+    })
+}
+
+/// Like `blocking_request`, but with `tokio::net`
+pub async fn async_request(addr: SocketAddr, request: u64) -> io::Result<u64> {
+    todo!()
+}
+
+/// Like `blocking_fetch`, but with a task for every request, and at most `concurrency` of them connected at once
+pub async fn async_fetch(addr: SocketAddr, requests: u64, concurrency: usize) -> io::Result<u64> {
+    todo!()
+}
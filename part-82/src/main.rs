@@ -0,0 +1,166 @@
+use std::time::Duration;
+
+use common::{bench::Comparison, cli::Cli};
+use part_82::{
+    async_fetch, async_read, blocking_fetch, blocking_read, expected_responses, generate_files,
+    serial_read, spawn_blocking_read, SlowServer,
+};
+
+/// Run with `cargo run --release -p part-82` to see where async pays off
+fn main() -> std::io::Result<()> {
+    common::init_tracing();
+    // `--strategy` picks which of them `Comparison` measures
+    Cli::new("Compares blocking and async I/O, on files and over the network")
+        .strategy()
+        .parse();
+    let runtime = tokio::runtime::Runtime::new()?;
+
+    let dir = tempfile::tempdir()?;
+    let paths = generate_files(dir.path(), 2000, 4096)?;
+    let expected = serial_read(&paths)?;
+    println!("Reading {} files of 4 KiB", paths.len());
+    let mut comparison = Comparison::new(10);
+    comparison.bench("Serial", || serial_read(&paths).unwrap());
+    for threads in [4, 16] {
+        comparison.bench(&format!("{threads} threads"), || {
+            assert_eq!(blocking_read(&paths, threads).unwrap(), expected)
+        });
+    }
+    comparison
+        .bench("tokio::fs", || {
+            assert_eq!(
+                runtime.block_on(async_read(paths.clone())).unwrap(),
+                expected
+            )
+        })
+        .bench("spawn_blocking", || {
+            assert_eq!(
+                runtime
+                    .block_on(spawn_blocking_read(paths.clone(), 100))
+                    .unwrap(),
+                expected
+            )
+        });
+    comparison.print();
+
+    let server = SlowServer::start(Duration::from_millis(10))?;
+    let requests = 500;
+    let expected = expected_responses(requests);
+    println!("Sending {requests} requests which take 10 ms each to answer");
+    let mut comparison = Comparison::new(3);
+    for threads in [4, 16, 64] {
+        comparison.bench(&format!("{threads} threads"), || {
+            assert_eq!(
+                blocking_fetch(server.addr(), requests, threads).unwrap(),
+                expected
+            )
+        });
+    }
+    comparison.bench("Tasks, 256 at once", || {
+        assert_eq!(
+            runtime
+                .block_on(async_fetch(server.addr(), requests, 256))
+                .unwrap(),
+            expected
+        )
+    });
+    comparison.print();
+    Ok(())
+}
+
+#[cfg(test)]
+fn fixture(files: usize) -> (tempfile::TempDir, Vec<std::path::PathBuf>) {
+    let dir = tempfile::tempdir().expect("Couldn't create a temporary directory");
+    let paths = generate_files(dir.path(), files, 1000).expect("Couldn't write files");
+    // Returning the directory keeps it alive, it's deleted when dropped
+    (dir, paths)
+}
+
+#[test]
+fn blocking_read_matches_serial() {
+    let (_dir, paths) = fixture(50);
+    let expected = serial_read(&paths).unwrap();
+    for threads in [1, 4, 64] {
+        assert_eq!(blocking_read(&paths, threads).unwrap(), expected);
+    }
+}
+
+#[tokio::test]
+async fn async_read_matches_serial() {
+    let (_dir, paths) = fixture(50);
+    let expected = serial_read(&paths).unwrap();
+    assert_eq!(async_read(paths).await.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn spawn_blocking_read_matches_serial() {
+    let (_dir, paths) = fixture(50);
+    let expected = serial_read(&paths).unwrap();
+    for batch in [1, 7, 100] {
+        assert_eq!(
+            spawn_blocking_read(paths.clone(), batch).await.unwrap(),
+            expected,
+            "Batches of {batch}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn no_files() {
+    assert_eq!(async_read(Vec::new()).await.unwrap(), 0);
+    assert_eq!(spawn_blocking_read(Vec::new(), 10).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn missing_files_are_errors() {
+    let (dir, mut paths) = fixture(10);
+    paths.insert(5, dir.path().join("does-not-exist.bin"));
+    assert!(async_read(paths.clone()).await.is_err());
+    assert!(spawn_blocking_read(paths, 3).await.is_err());
+}
+
+#[tokio::test]
+async fn async_fetch_gets_every_response() {
+    let server = SlowServer::start(Duration::from_millis(1)).unwrap();
+    assert_eq!(
+        async_fetch(server.addr(), 100, 10).await.unwrap(),
+        expected_responses(100)
+    );
+    assert_eq!(server.served(), 100);
+}
+
+#[tokio::test]
+async fn async_fetch_waits_for_the_server_at_the_same_time() {
+    let delay = Duration::from_millis(50);
+    let server = SlowServer::start(delay).unwrap();
+    let start = std::time::Instant::now();
+    assert_eq!(
+        async_fetch(server.addr(), 40, 40).await.unwrap(),
+        expected_responses(40)
+    );
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    // One after the other, that would take 2 seconds
+    assert!(start.elapsed() < 10 * delay, "Took {:?}", start.elapsed());
+}
+
+#[tokio::test]
+async fn async_fetch_limits_how_many_are_connected() {
+    let delay = Duration::from_millis(20);
+    let server = SlowServer::start(delay).unwrap();
+    let start = std::time::Instant::now();
+    async_fetch(server.addr(), 10, 2).await.unwrap();
+    // Five rounds of two at once
+    assert!(start.elapsed() >= 5 * delay, "Took {:?}", start.elapsed());
+}
+
+#[tokio::test]
+async fn async_fetch_fails_without_a_server() {
+    // Nothing listens on the port of a listener which is gone
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    assert!(async_fetch(addr, 5, 5).await.is_err());
+}
@@ -0,0 +1,66 @@
+//! Part 82, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{io, net::SocketAddr, path::PathBuf, sync::Arc};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    sync::Semaphore,
+    task::JoinSet,
+};
+
+use super::{checksum, serial_read};
+
+/// Reads every file in a task of its own with `tokio::fs`
+pub async fn async_read(paths: Vec<PathBuf>) -> io::Result<u64> {
+    let mut tasks = JoinSet::new();
+    for path in paths {
+        tasks.spawn(async move { Ok::<_, io::Error>(checksum(&tokio::fs::read(path).await?)) });
+    }
+    let mut total = 0;
+    while let Some(result) = tasks.join_next().await {
+        total += result.unwrap()?;
+    }
+    Ok(total)
+}
+
+/// Reads the files with one `spawn_blocking` for every `batch` files, which reads them with `std::fs`
+pub async fn spawn_blocking_read(paths: Vec<PathBuf>, batch: usize) -> io::Result<u64> {
+    let mut tasks = JoinSet::new();
+    for chunk in paths.chunks(batch) {
+        let chunk = chunk.to_vec();
+        tasks.spawn_blocking(move || serial_read(&chunk));
+    }
+    let mut total = 0;
+    while let Some(result) = tasks.join_next().await {
+        total += result.unwrap()?;
+    }
+    Ok(total)
+}
+
+/// Like `blocking_request`, but with `tokio::net`
+pub async fn async_request(addr: SocketAddr, request: u64) -> io::Result<u64> {
+    let mut stream = tokio::net::TcpStream::connect(addr).await?;
+    stream.write_all(&request.to_le_bytes()).await?;
+    let mut response = [0; 8];
+    stream.read_exact(&mut response).await?;
+    Ok(u64::from_le_bytes(response))
+}
+
+/// Like `blocking_fetch`, but with a task for every request, and at most `concurrency` of them connected at once
+pub async fn async_fetch(addr: SocketAddr, requests: u64, concurrency: usize) -> io::Result<u64> {
+    let permits = Arc::new(Semaphore::new(concurrency));
+    let mut tasks = JoinSet::new();
+    for request in 0..requests {
+        let permits = permits.clone();
+        tasks.spawn(async move {
+            // Waiting for a permit costs a task nothing but a little memory
+            let _permit = permits.acquire().await.unwrap();
+            async_request(addr, request).await
+        });
+    }
+    let mut total = 0;
+    while let Some(result) = tasks.join_next().await {
+        total += result.unwrap()?;
+    }
+    Ok(total)
+}
@@ -0,0 +1,60 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-82 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{io, time::Duration};
+
+use part_82::{
+    async_fetch, async_read, generate_files, solutions, spawn_blocking_read, SlowServer,
+};
+
+/// What came out, or the kind of error, to compare it with the solution's
+fn outcome(result: io::Result<u64>) -> Result<u64, io::ErrorKind> {
+    result.map_err(|error| error.kind())
+}
+
+#[tokio::test]
+async fn reads_like_the_solution() {
+    let dir = tempfile::tempdir().expect("Couldn't create a temporary directory");
+    let paths = generate_files(dir.path(), 50, 1000).expect("Couldn't write files");
+    assert_eq!(
+        outcome(async_read(paths.clone()).await),
+        outcome(solutions::async_read(paths.clone()).await)
+    );
+    for batch in [1, 8, 100] {
+        assert_eq!(
+            outcome(spawn_blocking_read(paths.clone(), batch).await),
+            outcome(solutions::spawn_blocking_read(paths.clone(), batch).await),
+            "In batches of {batch}"
+        );
+    }
+
+    let missing = vec![dir.path().join("missing")];
+    assert_eq!(
+        outcome(async_read(missing.clone()).await),
+        outcome(solutions::async_read(missing.clone()).await)
+    );
+    assert_eq!(
+        outcome(spawn_blocking_read(missing.clone(), 1).await),
+        outcome(solutions::spawn_blocking_read(missing, 1).await)
+    );
+}
+
+#[tokio::test]
+async fn fetches_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let server = SlowServer::start(Duration::from_millis(1)).unwrap();
+    for request in common::datagen::numbers(5, rng.seed()) {
+        assert_eq!(
+            outcome(part_82::async_request(server.addr(), request).await),
+            outcome(solutions::async_request(server.addr(), request).await)
+        );
+    }
+    for (requests, concurrency) in [(1, 1), (20, 4), (10, 50)] {
+        assert_eq!(
+            outcome(async_fetch(server.addr(), requests, concurrency).await),
+            outcome(solutions::async_fetch(server.addr(), requests, concurrency).await),
+            "{requests} requests, {concurrency} at a time"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use std::{ops::ControlFlow, time::Duration};
+
+use crossbeam_channel::{never, select, tick};
+use tokio::{sync::mpsc, time};
+use tokio_stream::{
+    wrappers::{IntervalStream, ReceiverStream},
+    StreamExt,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Something a user asked for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    /// Skip data until `Resume`
+    Pause,
+    Resume,
+    /// Stop right away, without waiting for the other sources
+    Shutdown,
+}
+
+/// Which of the sources a channel carries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    Commands,
+    Data,
+}
+
+/// Anything the handler loop has to handle, from any of the sources
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    Tick,
+    Command(Command),
+    Data(u64),
+    /// Every sender of that source is gone, so nothing more will come from it
+    Closed(Source),
+}
+
+/// Keeps track of what it has handled. Knows nothing about where the events come from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Handler {
+    pub ticks: u64,
+    /// Every command except `Shutdown`, in the order they were handled
+    pub commands: Vec<Command>,
+    /// Every piece of data which was handled while not paused, in the order it was handled
+    pub data: Vec<u64>,
+    /// How many pieces of data were skipped while paused
+    pub skipped: u64,
+    /// The sources which have closed
+    pub closed: Vec<Source>,
+    paused: bool,
+}
+
+impl Handler {
+    /// Handles `event`. Breaks once the loop should stop: on `Shutdown`, or once both the commands and the data have closed.
+    /// Ticks never close, so they don't have to.
+    pub fn handle(&mut self, event: Event) -> ControlFlow<()> {
+        match event {
+            Event::Tick => self.ticks += 1,
+            Event::Command(Command::Shutdown) => return ControlFlow::Break(()),
+            Event::Command(command) => {
+                self.paused = command == Command::Pause;
+                self.commands.push(command);
+            }
+            Event::Data(_) if self.paused => self.skipped += 1,
+            Event::Data(data) => self.data.push(data),
+            Event::Closed(source) => {
+                self.closed.push(source);
+                if self.closed.len() == 2 {
+                    return ControlFlow::Break(());
+                }
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+/// Handles a tick every `period`, and whatever arrives on `commands` and `data`, with crossbeam's `select!`.
+/// Returns the handler once it says to stop.
+pub fn run_sync(
+    period: Duration,
+    commands: crossbeam_channel::Receiver<Command>,
+    data: crossbeam_channel::Receiver<u64>,
+) -> Handler {
+    todo!()
+}
+
+/// Like `run_sync`, but with tokio's channels and `select!`, and a `tokio::time::interval` for the ticks
+pub async fn run_select(
+    period: Duration,
+    commands: mpsc::Receiver<Command>,
+    data: mpsc::Receiver<u64>,
+) -> Handler {
+    todo!()
+}
+
+/// Like `run_select`, but turns every source into a stream of `Event`s, and merges them into one stream
+pub async fn run_merged(
+    period: Duration,
+    commands: mpsc::Receiver<Command>,
+    data: mpsc::Receiver<u64>,
+) -> Handler {
+    todo!()
+}
@@ -0,0 +1,258 @@
+use std::{thread, time::Duration};
+
+use part_83::{run_merged, run_select, run_sync, Command};
+use tokio::sync::mpsc;
+
+const PERIOD: Duration = Duration::from_millis(10);
+
+/// Sends data every few milliseconds, pausing it for a while in between, and then hangs up
+async fn produce(commands: mpsc::Sender<Command>, data: mpsc::Sender<u64>) {
+    for item in 0..30 {
+        match item {
+            10 => commands.send(Command::Pause).await.unwrap(),
+            20 => commands.send(Command::Resume).await.unwrap(),
+            _ => (),
+        }
+        data.send(item).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(3)).await;
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let (command_sender, commands) = crossbeam_channel::unbounded();
+    let (data_sender, data) = crossbeam_channel::unbounded();
+    let producer = thread::spawn(move || {
+        for item in 0..30 {
+            match item {
+                10 => command_sender.send(Command::Pause).unwrap(),
+                20 => command_sender.send(Command::Resume).unwrap(),
+                _ => (),
+            }
+            data_sender.send(item).unwrap();
+            thread::sleep(Duration::from_millis(3));
+        }
+    });
+    let handler = tokio::task::spawn_blocking(move || run_sync(PERIOD, commands, data))
+        .await
+        .unwrap();
+    producer.join().unwrap();
+    println!("crossbeam select!: {handler:?}");
+
+    let (command_sender, commands) = mpsc::channel(16);
+    let (data_sender, data) = mpsc::channel(16);
+    tokio::spawn(produce(command_sender, data_sender));
+    println!(
+        "tokio select!: {:?}",
+        run_select(PERIOD, commands, data).await
+    );
+
+    let (command_sender, commands) = mpsc::channel(16);
+    let (data_sender, data) = mpsc::channel(16);
+    tokio::spawn(produce(command_sender, data_sender));
+    println!(
+        "Merged streams: {:?}",
+        run_merged(PERIOD, commands, data).await
+    );
+}
+
+/// Checks that `handler` got both commands, and every piece of data in order, either handled or skipped
+#[cfg(test)]
+fn assert_serviced(handler: &part_83::Handler, items: u64) {
+    use part_83::Source;
+
+    assert_eq!(handler.commands, [Command::Pause, Command::Resume]);
+    assert_eq!(handler.data.len() as u64 + handler.skipped, items);
+    assert!(handler.data.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(handler.ticks > 0);
+    assert!(handler.closed.contains(&Source::Commands));
+    assert!(handler.closed.contains(&Source::Data));
+}
+
+#[test]
+fn sync_services_every_source() {
+    let (command_sender, commands) = crossbeam_channel::unbounded();
+    let (data_sender, data) = crossbeam_channel::unbounded();
+    let producer = thread::spawn(move || {
+        command_sender.send(Command::Pause).unwrap();
+        command_sender.send(Command::Resume).unwrap();
+        for item in 0..100 {
+            data_sender.send(item).unwrap();
+        }
+        // Long enough for a few ticks before hanging up
+        thread::sleep(5 * PERIOD);
+    });
+    let handler = run_sync(PERIOD, commands, data);
+    producer.join().unwrap();
+    assert_serviced(&handler, 100);
+}
+
+#[test]
+fn sync_handles_everything_sent_before_hanging_up() {
+    let (command_sender, commands) = crossbeam_channel::unbounded();
+    let (data_sender, data) = crossbeam_channel::unbounded();
+    for item in 0..1000 {
+        data_sender.send(item).unwrap();
+    }
+    drop((command_sender, data_sender));
+    let handler = run_sync(PERIOD, commands, data);
+    assert_eq!(handler.data, (0..1000).collect::<Vec<_>>());
+}
+
+#[test]
+fn sync_stops_on_shutdown() {
+    let handler = common::with_timeout(Duration::from_secs(1), || {
+        let (command_sender, commands) = crossbeam_channel::unbounded();
+        let (_data_sender, data) = crossbeam_channel::unbounded();
+        command_sender.send(Command::Shutdown).unwrap();
+        // Both senders are still around, so only the shutdown can stop it
+        run_sync(PERIOD, commands, data)
+    })
+    .expect("Didn't stop on shutdown");
+    assert_eq!(handler.closed, []);
+}
+
+#[test]
+fn sync_keeps_ticking_while_data_pours_in() {
+    let (command_sender, commands) = crossbeam_channel::unbounded::<Command>();
+    let (data_sender, data) = crossbeam_channel::bounded(16);
+    let producer = thread::spawn(move || {
+        let start = std::time::Instant::now();
+        let mut item = 0;
+        while start.elapsed() < 10 * PERIOD {
+            data_sender.send(item).unwrap();
+            item += 1;
+        }
+        drop(command_sender);
+    });
+    let handler = run_sync(PERIOD, commands, data);
+    producer.join().unwrap();
+    assert!(handler.ticks >= 3, "Only {} ticks", handler.ticks);
+}
+
+/// Sends both commands and `items` pieces of data, and hangs up a few ticks later
+#[cfg(test)]
+async fn send_everything(commands: mpsc::Sender<Command>, data: mpsc::Sender<u64>, items: u64) {
+    commands.send(Command::Pause).await.unwrap();
+    commands.send(Command::Resume).await.unwrap();
+    for item in 0..items {
+        data.send(item).await.unwrap();
+    }
+    tokio::time::sleep(5 * PERIOD).await;
+}
+
+/// Sends data as fast as the channel takes it for ten ticks, and hangs up
+#[cfg(test)]
+async fn pour(commands: mpsc::Sender<Command>, data: mpsc::Sender<u64>) {
+    let start = tokio::time::Instant::now();
+    let mut item = 0;
+    while start.elapsed() < 10 * PERIOD {
+        data.send(item).await.unwrap();
+        item += 1;
+    }
+    drop(commands);
+}
+
+#[tokio::test]
+async fn select_services_every_source() {
+    let (command_sender, commands) = mpsc::channel(16);
+    let (data_sender, data) = mpsc::channel(16);
+    tokio::spawn(send_everything(command_sender, data_sender, 100));
+    let handler = run_select(PERIOD, commands, data).await;
+    assert_serviced(&handler, 100);
+}
+
+#[tokio::test]
+async fn select_handles_everything_sent_before_hanging_up() {
+    let (command_sender, commands) = mpsc::channel(16);
+    let (data_sender, data) = mpsc::channel(1000);
+    for item in 0..1000 {
+        data_sender.send(item).await.unwrap();
+    }
+    drop((command_sender, data_sender));
+    let handler = run_select(PERIOD, commands, data).await;
+    assert_eq!(handler.data, (0..1000).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+async fn select_stops_on_shutdown() {
+    let (command_sender, commands) = mpsc::channel(16);
+    let (_data_sender, data) = mpsc::channel(16);
+    command_sender.send(Command::Shutdown).await.unwrap();
+    let handler = tokio::time::timeout(Duration::from_secs(1), run_select(PERIOD, commands, data))
+        .await
+        .expect("Didn't stop on shutdown");
+    assert_eq!(handler.closed, []);
+}
+
+#[tokio::test]
+async fn select_keeps_ticking_while_data_pours_in() {
+    let (command_sender, commands) = mpsc::channel(16);
+    let (data_sender, data) = mpsc::channel(16);
+    tokio::spawn(pour(command_sender, data_sender));
+    let handler = run_select(PERIOD, commands, data).await;
+    assert!(handler.ticks >= 3, "Only {} ticks", handler.ticks);
+}
+
+#[tokio::test]
+async fn merged_services_every_source() {
+    let (command_sender, commands) = mpsc::channel(16);
+    let (data_sender, data) = mpsc::channel(16);
+    tokio::spawn(send_everything(command_sender, data_sender, 100));
+    let handler = run_merged(PERIOD, commands, data).await;
+    assert_serviced(&handler, 100);
+}
+
+#[tokio::test]
+async fn merged_handles_everything_sent_before_hanging_up() {
+    let (command_sender, commands) = mpsc::channel(16);
+    let (data_sender, data) = mpsc::channel(1000);
+    for item in 0..1000 {
+        data_sender.send(item).await.unwrap();
+    }
+    drop((command_sender, data_sender));
+    let handler = run_merged(PERIOD, commands, data).await;
+    assert_eq!(handler.data, (0..1000).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+async fn merged_stops_on_shutdown() {
+    let (command_sender, commands) = mpsc::channel(16);
+    let (_data_sender, data) = mpsc::channel(16);
+    command_sender.send(Command::Shutdown).await.unwrap();
+    let handler = tokio::time::timeout(Duration::from_secs(1), run_merged(PERIOD, commands, data))
+        .await
+        .expect("Didn't stop on shutdown");
+    assert_eq!(handler.closed, []);
+}
+
+#[tokio::test]
+async fn merged_keeps_ticking_while_data_pours_in() {
+    let (command_sender, commands) = mpsc::channel(16);
+    let (data_sender, data) = mpsc::channel(16);
+    tokio::spawn(pour(command_sender, data_sender));
+    let handler = run_merged(PERIOD, commands, data).await;
+    assert!(handler.ticks >= 3, "Only {} ticks", handler.ticks);
+}
+
+#[test]
+fn handler_skips_data_while_paused() {
+    use part_83::{Event, Handler, Source};
+
+    let mut handler = Handler::default();
+    for event in [
+        Event::Data(1),
+        Event::Command(Command::Pause),
+        Event::Data(2),
+        Event::Tick,
+        Event::Command(Command::Resume),
+        Event::Data(3),
+        Event::Closed(Source::Data),
+    ] {
+        assert!(handler.handle(event).is_continue());
+    }
+    assert_eq!(handler.data, [1, 3]);
+    assert_eq!(handler.skipped, 1);
+    assert_eq!(handler.ticks, 1);
+    assert!(handler.handle(Event::Closed(Source::Commands)).is_break());
+}
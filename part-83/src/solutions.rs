@@ -0,0 +1,107 @@
+//! Part 83, with every exercise implemented. Only built with `--features solutions`.
+
+use std::time::Duration;
+
+use crossbeam_channel::{never, select, tick};
+use tokio::{sync::mpsc, time};
+use tokio_stream::{
+    wrappers::{IntervalStream, ReceiverStream},
+    StreamExt,
+};
+
+use super::{Command, Event, Handler, Source};
+
+/// Handles a tick every `period`, and whatever arrives on `commands` and `data`, with crossbeam's `select!`.
+/// Returns the handler once it says to stop.
+pub fn run_sync(
+    period: Duration,
+    commands: crossbeam_channel::Receiver<Command>,
+    data: crossbeam_channel::Receiver<u64>,
+) -> Handler {
+    let mut handler = Handler::default();
+    let ticks = tick(period);
+    // Closed channels are swapped for ones which never receive anything, or `select!` would keep picking them
+    let (mut commands, mut data) = (commands, data);
+    loop {
+        let event = select! {
+            recv(ticks) -> _ => Event::Tick,
+            recv(commands) -> command => match command {
+                Ok(command) => Event::Command(command),
+                Err(_) => {
+                    commands = never();
+                    Event::Closed(Source::Commands)
+                }
+            },
+            recv(data) -> item => match item {
+                Ok(item) => Event::Data(item),
+                Err(_) => {
+                    data = never();
+                    Event::Closed(Source::Data)
+                }
+            },
+        };
+        if handler.handle(event).is_break() {
+            return handler;
+        }
+    }
+}
+
+/// Like `run_sync`, but with tokio's channels and `select!`, and a `tokio::time::interval` for the ticks
+pub async fn run_select(
+    period: Duration,
+    commands: mpsc::Receiver<Command>,
+    data: mpsc::Receiver<u64>,
+) -> Handler {
+    let mut handler = Handler::default();
+    let mut ticks = time::interval(period);
+    let (mut commands, mut data) = (commands, data);
+    let (mut commands_open, mut data_open) = (true, true);
+    loop {
+        // A closed channel keeps returning `None` right away, so its branch is turned off
+        let event = tokio::select! {
+            _ = ticks.tick() => Event::Tick,
+            command = commands.recv(), if commands_open => match command {
+                Some(command) => Event::Command(command),
+                None => {
+                    commands_open = false;
+                    Event::Closed(Source::Commands)
+                }
+            },
+            item = data.recv(), if data_open => match item {
+                Some(item) => Event::Data(item),
+                None => {
+                    data_open = false;
+                    Event::Closed(Source::Data)
+                }
+            },
+        };
+        if handler.handle(event).is_break() {
+            return handler;
+        }
+    }
+}
+
+/// Like `run_select`, but turns every source into a stream of `Event`s, and merges them into one stream
+pub async fn run_merged(
+    period: Duration,
+    commands: mpsc::Receiver<Command>,
+    data: mpsc::Receiver<u64>,
+) -> Handler {
+    let ticks = IntervalStream::new(time::interval(period)).map(|_| Event::Tick);
+    // A stream which ends is just left out of the merge, so the end is turned into an event of its own
+    let commands = ReceiverStream::new(commands)
+        .map(Event::Command)
+        .chain(tokio_stream::once(Event::Closed(Source::Commands)));
+    let data = ReceiverStream::new(data)
+        .map(Event::Data)
+        .chain(tokio_stream::once(Event::Closed(Source::Data)));
+    let mut events = ticks.merge(commands).merge(data);
+
+    let mut handler = Handler::default();
+    while let Some(event) = events.next().await {
+        if handler.handle(event).is_break() {
+            break;
+        }
+    }
+    handler
+}
@@ -0,0 +1,89 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-83 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{fmt::Debug, time::Duration};
+
+use part_83::{run_merged, run_select, run_sync, solutions, Command, Handler};
+use tokio::sync::mpsc;
+
+/// Long enough that it never ticks while the data is handled
+const PERIOD: Duration = Duration::from_secs(3600);
+
+/// What the handler handled, with the sources by name, to compare it with the solution's
+fn handled<S: Debug>(data: Vec<u64>, skipped: u64, closed: &[S]) -> (Vec<u64>, u64, Vec<String>) {
+    let mut closed: Vec<String> = closed.iter().map(|source| format!("{source:?}")).collect();
+    // The sources can close in either order
+    closed.sort();
+    (data, skipped, closed)
+}
+
+fn ours(handler: Handler) -> (Vec<u64>, u64, Vec<String>) {
+    handled(handler.data, handler.skipped, &handler.closed)
+}
+
+fn theirs(handler: Handler) -> (Vec<u64>, u64, Vec<String>) {
+    handled(handler.data, handler.skipped, &handler.closed)
+}
+
+#[test]
+fn runs_sync_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let numbers = common::datagen::numbers(50, rng.seed());
+    let run = |numbers: &[u64]| {
+        let (data, receiver) = crossbeam_channel::unbounded();
+        for &number in numbers {
+            data.send(number).unwrap();
+        }
+        receiver
+    };
+    let (data, expected_data) = (run(&numbers), run(&numbers));
+    let (_, commands) = crossbeam_channel::unbounded();
+    let (_, expected_commands) = crossbeam_channel::unbounded();
+    assert_eq!(
+        ours(run_sync(PERIOD, commands, data)),
+        theirs(solutions::run_sync(
+            PERIOD,
+            expected_commands,
+            expected_data
+        ))
+    );
+}
+
+/// The receivers of a closed commands channel, and a closed data channel with `numbers` in it
+fn channels(numbers: &[u64]) -> (mpsc::Receiver<part_83::Command>, mpsc::Receiver<u64>) {
+    let (data, receiver) = mpsc::channel(numbers.len().max(1));
+    let (_, commands) = mpsc::channel(1);
+    for &number in numbers {
+        data.try_send(number).unwrap();
+    }
+    (commands, receiver)
+}
+
+/// Like `channels`, for the solution's commands
+fn expected_channels(numbers: &[u64]) -> (mpsc::Receiver<Command>, mpsc::Receiver<u64>) {
+    let (data, receiver) = mpsc::channel(numbers.len().max(1));
+    let (_, commands) = mpsc::channel(1);
+    for &number in numbers {
+        data.try_send(number).unwrap();
+    }
+    (commands, receiver)
+}
+
+#[tokio::test(start_paused = true)]
+async fn runs_async_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let numbers = common::datagen::numbers(50, rng.seed());
+    let (commands, data) = channels(&numbers);
+    let (expected_commands, expected_data) = expected_channels(&numbers);
+    assert_eq!(
+        ours(run_select(PERIOD, commands, data).await),
+        theirs(solutions::run_select(PERIOD, expected_commands, expected_data).await)
+    );
+    let (commands, data) = channels(&numbers);
+    let (expected_commands, expected_data) = expected_channels(&numbers);
+    assert_eq!(
+        ours(run_merged(PERIOD, commands, data).await),
+        theirs(solutions::run_merged(PERIOD, expected_commands, expected_data).await)
+    );
+}
@@ -0,0 +1,174 @@
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use tokio::{
+    sync::{Mutex, Semaphore},
+    task::JoinSet,
+    time::{self, Instant},
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// What the server sends back
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Response {
+    pub id: u64,
+}
+
+/// Why the server refused a request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServerError {
+    /// Too many requests at once
+    Overloaded,
+    /// Too many requests in too short a time
+    RateLimited,
+}
+
+/// A pretend HTTP server, which takes `latency` to answer a request.
+/// It refuses requests when `max_in_flight` are being answered already, or when `max_per_window` were started
+/// within the last `window`, like an API with a rate limit.
+#[derive(Debug)]
+pub struct MockServer {
+    latency: Duration,
+    max_in_flight: usize,
+    max_per_window: usize,
+    window: Duration,
+    in_flight: AtomicUsize,
+    peak_in_flight: AtomicUsize,
+    /// When the requests within the last `window` were started
+    started: std::sync::Mutex<VecDeque<Instant>>,
+    served: AtomicU64,
+    overloaded: AtomicU64,
+    rate_limited: AtomicU64,
+}
+
+impl MockServer {
+    pub fn new(
+        latency: Duration,
+        max_in_flight: usize,
+        max_per_window: usize,
+        window: Duration,
+    ) -> Self {
+        Self {
+            latency,
+            max_in_flight,
+            max_per_window,
+            window,
+            in_flight: AtomicUsize::new(0),
+            peak_in_flight: AtomicUsize::new(0),
+            started: Default::default(),
+            served: AtomicU64::new(0),
+            overloaded: AtomicU64::new(0),
+            rate_limited: AtomicU64::new(0),
+        }
+    }
+
+    /// Answers request `id` after `latency`, unless it's refused, which it is right away
+    pub async fn get(&self, id: u64) -> Result<Response, ServerError> {
+        {
+            let now = Instant::now();
+            let mut started = self.started.lock().unwrap();
+            while started
+                .front()
+                .is_some_and(|&start| now.duration_since(start) >= self.window)
+            {
+                started.pop_front();
+            }
+            if started.len() >= self.max_per_window {
+                self.rate_limited.fetch_add(1, Ordering::Relaxed);
+                return Err(ServerError::RateLimited);
+            }
+            started.push_back(now);
+        }
+
+        let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        if in_flight > self.max_in_flight {
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            self.overloaded.fetch_add(1, Ordering::Relaxed);
+            return Err(ServerError::Overloaded);
+        }
+        self.peak_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+        time::sleep(self.latency).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        self.served.fetch_add(1, Ordering::Relaxed);
+        Ok(Response { id })
+    }
+
+    pub fn served(&self) -> u64 {
+        self.served.load(Ordering::Relaxed)
+    }
+
+    pub fn overloaded(&self) -> u64 {
+        self.overloaded.load(Ordering::Relaxed)
+    }
+
+    pub fn rate_limited(&self) -> u64 {
+        self.rate_limited.load(Ordering::Relaxed)
+    }
+
+    /// The most requests it has answered at once
+    pub fn peak_in_flight(&self) -> usize {
+        self.peak_in_flight.load(Ordering::SeqCst)
+    }
+}
+
+/// How many tokens a `TokenBucket` has, and when it was last refilled
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled: Instant,
+}
+
+/// Lets through `rate` requests a second on average, and bursts of up to `burst` at once.
+/// It starts out full, and every request takes a token out of it. Tokens trickle back in at `rate` a second.
+#[derive(Debug)]
+pub struct TokenBucket {
+    rate: f64,
+    burst: f64,
+    bucket: Mutex<Bucket>,
+}
+
+impl TokenBucket {
+    pub fn new(rate: f64, burst: u32) -> Self {
+        assert!(
+            rate > 0.0 && burst > 0,
+            "A bucket which never lets anything through is no use"
+        );
+        Self {
+            rate,
+            burst: burst as f64,
+            bucket: Mutex::new(Bucket {
+                tokens: burst as f64,
+                refilled: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until there's a token, and takes it. Whoever asked first gets the next token.
+    pub async fn acquire(&self) {
+        todo!()
+    }
+}
+
+/// Adds the tokens `bucket` has earned since it was last refilled, at `rate` a second, but never more than `burst`
+fn refill(bucket: &mut Bucket, rate: f64, burst: f64, now: Instant) {
+    todo!()
+}
+
+/// Sends requests `0..requests` to `server`, with at most `concurrency` at once, and no faster than `limiter` lets them through.
+/// Returns what the server answered to each of them, in the order of their ids.
+pub async fn fetch_all(
+    server: Arc<MockServer>,
+    requests: u64,
+    concurrency: usize,
+    limiter: Arc<TokenBucket>,
+) -> Vec<Result<Response, ServerError>> {
+    todo!()
+}
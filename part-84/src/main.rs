@@ -0,0 +1,174 @@
+use std::{sync::Arc, time::Duration};
+
+use part_84::{fetch_all, MockServer, TokenBucket};
+use tokio::time::Instant;
+
+/// A server which answers in 20 ms, at most 10 requests at once and 100 a second
+fn server() -> Arc<MockServer> {
+    Arc::new(MockServer::new(
+        Duration::from_millis(20),
+        10,
+        100,
+        Duration::from_secs(1),
+    ))
+}
+
+#[tokio::main]
+async fn main() {
+    let args = common::cli::Cli::new("Fetches from a server without limits, and with some")
+        .items(300)
+        .parse();
+    let requests = args.items() as u64;
+    for (name, concurrency, limiter) in [
+        ("No limits", 300, TokenBucket::new(1e9, 300)),
+        ("Only concurrency", 10, TokenBucket::new(1e9, 300)),
+        ("Concurrency and rate", 10, TokenBucket::new(80.0, 10)),
+    ] {
+        let server = server();
+        let start = Instant::now();
+        let responses = fetch_all(server.clone(), requests, concurrency, Arc::new(limiter)).await;
+        let ok = responses.iter().filter(|response| response.is_ok()).count();
+        println!(
+            "{name}: {ok} of {requests} answered in {:?}, {} overloaded, {} rate limited",
+            start.elapsed(),
+            server.overloaded(),
+            server.rate_limited()
+        );
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn bucket_lets_a_burst_through_at_once() {
+    let bucket = TokenBucket::new(10.0, 5);
+    let start = Instant::now();
+    for _ in 0..5 {
+        bucket.acquire().await;
+    }
+    assert_eq!(start.elapsed(), Duration::ZERO);
+}
+
+#[tokio::test(start_paused = true)]
+async fn bucket_lets_the_rest_through_at_the_rate() {
+    let bucket = TokenBucket::new(10.0, 5);
+    for _ in 0..5 {
+        bucket.acquire().await;
+    }
+    let start = Instant::now();
+    for _ in 0..10 {
+        bucket.acquire().await;
+    }
+    let elapsed = start.elapsed();
+    assert!(
+        (Duration::from_millis(999)..Duration::from_millis(1005)).contains(&elapsed),
+        "10 tokens at 10 a second took {elapsed:?}"
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn bucket_fills_up_while_idle_but_only_to_the_burst() {
+    let bucket = TokenBucket::new(10.0, 5);
+    for _ in 0..5 {
+        bucket.acquire().await;
+    }
+    tokio::time::sleep(Duration::from_secs(10)).await;
+    let start = Instant::now();
+    for _ in 0..5 {
+        bucket.acquire().await;
+    }
+    assert_eq!(start.elapsed(), Duration::ZERO);
+    bucket.acquire().await;
+    assert!(start.elapsed() >= Duration::from_millis(100));
+}
+
+#[tokio::test(start_paused = true)]
+async fn bucket_serves_waiters_in_order() {
+    let bucket = Arc::new(TokenBucket::new(10.0, 1));
+    bucket.acquire().await;
+    let (sender, mut order) = tokio::sync::mpsc::unbounded_channel();
+    for waiter in 0..5 {
+        let (bucket, sender) = (bucket.clone(), sender.clone());
+        tokio::spawn(async move {
+            bucket.acquire().await;
+            sender.send(waiter).unwrap();
+        });
+        // Makes sure they start waiting in order
+        tokio::task::yield_now().await;
+    }
+    drop(sender);
+    let mut received = Vec::new();
+    while let Some(waiter) = order.recv().await {
+        received.push(waiter);
+    }
+    assert_eq!(received, [0, 1, 2, 3, 4]);
+}
+
+/// A server which answers in 50 ms, at most 10 requests at once and 100 a second, and a client which stays below that
+#[cfg(test)]
+async fn fetch_a_thousand() -> (
+    Arc<MockServer>,
+    Vec<Result<part_84::Response, part_84::ServerError>>,
+) {
+    let server = Arc::new(MockServer::new(
+        Duration::from_millis(50),
+        10,
+        100,
+        Duration::from_secs(1),
+    ));
+    let limiter = Arc::new(TokenBucket::new(80.0, 10));
+    let responses = fetch_all(server.clone(), 1000, 10, limiter).await;
+    (server, responses)
+}
+
+#[tokio::test(start_paused = true)]
+async fn fetches_everything_without_overloading_the_server() {
+    let (server, responses) = fetch_a_thousand().await;
+    let ids: Vec<_> = responses
+        .into_iter()
+        .map(|response| response.unwrap().id)
+        .collect();
+    assert_eq!(ids, (0..1000).collect::<Vec<_>>());
+    assert_eq!(server.served(), 1000);
+    assert_eq!(server.overloaded(), 0);
+    assert_eq!(server.rate_limited(), 0);
+    assert!(server.peak_in_flight() <= 10);
+}
+
+#[tokio::test(start_paused = true)]
+async fn takes_as_long_as_the_rate_says() {
+    let start = Instant::now();
+    fetch_a_thousand().await;
+    // A burst of 10, then the other 990 at 80 a second, and the last one takes 50 ms to answer
+    let expected = Duration::from_secs_f64(990.0 / 80.0) + Duration::from_millis(50);
+    let elapsed = start.elapsed();
+    assert!(
+        elapsed >= expected && elapsed < expected + Duration::from_millis(10),
+        "Expected {expected:?}, took {elapsed:?}"
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn takes_as_long_as_the_concurrency_says() {
+    let server = Arc::new(MockServer::new(
+        Duration::from_millis(200),
+        5,
+        10_000,
+        Duration::from_secs(1),
+    ));
+    let limiter = Arc::new(TokenBucket::new(1e9, 1000));
+    let start = Instant::now();
+    let responses = fetch_all(server.clone(), 1000, 5, limiter).await;
+    assert!(responses.iter().all(Result::is_ok));
+    // 200 rounds of 5 at once
+    assert_eq!(start.elapsed(), Duration::from_secs(40));
+    assert_eq!(server.peak_in_flight(), 5);
+}
+
+#[tokio::test(start_paused = true)]
+async fn overloads_the_server_without_limits() {
+    let server = server();
+    let limiter = Arc::new(TokenBucket::new(1e9, 1000));
+    let responses = fetch_all(server.clone(), 1000, 1000, limiter).await;
+    assert!(responses.iter().any(Result::is_err));
+    assert!(server.overloaded() > 0);
+    assert!(server.rate_limited() > 0);
+}
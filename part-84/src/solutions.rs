@@ -0,0 +1,89 @@
+//! Part 84, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{sync::Arc, time::Duration};
+
+use tokio::{
+    sync::{Mutex, Semaphore},
+    task::JoinSet,
+    time::{self, Instant},
+};
+
+use super::{Bucket, MockServer, Response, ServerError};
+
+/// Lets through `rate` requests a second on average, and bursts of up to `burst` at once.
+/// It starts out full, and every request takes a token out of it. Tokens trickle back in at `rate` a second.
+#[derive(Debug)]
+pub struct TokenBucket {
+    rate: f64,
+    burst: f64,
+    bucket: Mutex<Bucket>,
+}
+
+impl TokenBucket {
+    pub fn new(rate: f64, burst: u32) -> Self {
+        assert!(
+            rate > 0.0 && burst > 0,
+            "A bucket which never lets anything through is no use"
+        );
+        Self {
+            rate,
+            burst: burst as f64,
+            bucket: Mutex::new(Bucket {
+                tokens: burst as f64,
+                refilled: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until there's a token, and takes it. Whoever asked first gets the next token.
+    pub async fn acquire(&self) {
+        // tokio's mutex is fair, so holding it while waiting for a token makes everyone else wait their turn
+        let mut bucket = self.bucket.lock().await;
+        refill(&mut bucket, self.rate, self.burst, Instant::now());
+        if bucket.tokens < 1.0 {
+            let missing = 1.0 - bucket.tokens;
+            time::sleep(Duration::from_secs_f64(missing / self.rate)).await;
+            refill(&mut bucket, self.rate, self.burst, Instant::now());
+        }
+        // Rounding may leave it a hair short of a whole token
+        bucket.tokens = (bucket.tokens - 1.0).max(0.0);
+    }
+}
+
+/// Adds the tokens `bucket` has earned since it was last refilled, at `rate` a second, but never more than `burst`
+fn refill(bucket: &mut Bucket, rate: f64, burst: f64, now: Instant) {
+    let earned = now.duration_since(bucket.refilled).as_secs_f64() * rate;
+    bucket.tokens = (bucket.tokens + earned).min(burst);
+    bucket.refilled = now;
+}
+
+/// Sends requests `0..requests` to `server`, with at most `concurrency` at once, and no faster than `limiter` lets them through.
+/// Returns what the server answered to each of them, in the order of their ids.
+pub async fn fetch_all(
+    server: Arc<MockServer>,
+    requests: u64,
+    concurrency: usize,
+    limiter: Arc<TokenBucket>,
+) -> Vec<Result<Response, ServerError>> {
+    let permits = Arc::new(Semaphore::new(concurrency));
+    let mut tasks = JoinSet::new();
+    for id in 0..requests {
+        let (server, permits, limiter) = (server.clone(), permits.clone(), limiter.clone());
+        tasks.spawn(async move {
+            // A permit first, so a request which got its token doesn't sit on it waiting for a permit
+            let _permit = permits.acquire().await.unwrap();
+            limiter.acquire().await;
+            (id, server.get(id).await)
+        });
+    }
+    let mut responses = Vec::with_capacity(requests as usize);
+    while let Some(result) = tasks.join_next().await {
+        responses.push(result.unwrap());
+    }
+    // They finish in whatever order the server answers them
+    responses.sort_by_key(|&(id, _)| id);
+    responses
+        .into_iter()
+        .map(|(_, response)| response)
+        .collect()
+}
@@ -0,0 +1,74 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-84 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{fmt::Debug, sync::Arc, time::Duration};
+
+use part_84::{fetch_all, solutions, MockServer, TokenBucket};
+use tokio::time::Instant;
+
+/// When each acquire got its token, from the start
+async fn acquired(bucket: &TokenBucket, times: usize) -> Vec<Duration> {
+    let start = Instant::now();
+    let mut acquired = Vec::new();
+    for _ in 0..times {
+        bucket.acquire().await;
+        acquired.push(start.elapsed());
+    }
+    acquired
+}
+
+#[tokio::test(start_paused = true)]
+async fn acquires_like_the_solution() {
+    for (rate, burst) in [(10.0, 1), (100.0, 5), (3.5, 2)] {
+        let (bucket, expected) = (
+            TokenBucket::new(rate, burst),
+            solutions::TokenBucket::new(rate, burst),
+        );
+        let start = Instant::now();
+        let mut expected_acquired = Vec::new();
+        for _ in 0..12 {
+            expected.acquire().await;
+            expected_acquired.push(start.elapsed());
+        }
+        assert_eq!(
+            acquired(&bucket, 12).await,
+            expected_acquired,
+            "{rate} a second, bursts of {burst}"
+        );
+    }
+}
+
+/// Which requests were answered, and the kind of error for the others
+fn answers<R: Debug, E: Debug>(answers: Vec<Result<R, E>>) -> Vec<String> {
+    answers.iter().map(|answer| format!("{answer:?}")).collect()
+}
+
+#[tokio::test(start_paused = true)]
+async fn fetches_like_the_solution() {
+    for (concurrency, rate) in [(1, 1000.0), (4, 20.0), (16, 50.0)] {
+        let (latency, window) = (Duration::from_millis(10), Duration::from_secs(1));
+        let server = Arc::new(MockServer::new(latency, 8, 40, window));
+        let expected_server = Arc::new(MockServer::new(latency, 8, 40, window));
+        let ours = fetch_all(
+            server.clone(),
+            60,
+            concurrency,
+            Arc::new(TokenBucket::new(rate, 4)),
+        )
+        .await;
+        let theirs = solutions::fetch_all(
+            expected_server.clone(),
+            60,
+            concurrency,
+            Arc::new(solutions::TokenBucket::new(rate, 4)),
+        )
+        .await;
+        assert_eq!(
+            answers(ours),
+            answers(theirs),
+            "{concurrency} at once, {rate} a second"
+        );
+        assert_eq!(server.peak_in_flight(), expected_server.peak_in_flight());
+    }
+}
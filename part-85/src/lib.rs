@@ -0,0 +1,231 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::{BuildHasher, Hash, RandomState},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// A cache which can be used from many threads at once, and forgets the least recently used entries when it's full
+pub trait Cache<K, V>: Sync {
+    /// A copy of the value for `key`, if it's cached. Counts as using it.
+    fn get(&self, key: &K) -> Option<V>;
+    /// Caches `value` for `key`, which counts as using it, and forgets the least recently used entry if that makes it too big
+    fn put(&self, key: K, value: V);
+    fn len(&self) -> usize;
+    /// How many of the gets found what they were looking for
+    fn stats(&self) -> &Stats;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Counts the hits and misses of a cache
+#[derive(Debug, Default)]
+pub struct Stats {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl Stats {
+    pub fn record(&self, hit: bool) {
+        let counter = if hit { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// The share of the gets which were hits, or 0 if there haven't been any
+    pub fn hit_rate(&self) -> f64 {
+        let (hits, misses) = (self.hits(), self.misses());
+        if hits + misses == 0 {
+            0.0
+        } else {
+            hits as f64 / (hits + misses) as f64
+        }
+    }
+}
+
+/// An LRU cache for a single thread. Keeps track of when every entry was last used with a counter,
+/// and the entries in the order they were used, so the least recently used one is always first.
+#[derive(Debug)]
+pub struct LruInner<K, V> {
+    capacity: usize,
+    /// The value of every entry, with when it was last used
+    entries: HashMap<K, (V, u64)>,
+    /// The key of every entry, by when it was last used
+    by_use: BTreeMap<u64, K>,
+    clock: u64,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> LruInner<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "A cache needs room for at least one entry");
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            by_use: BTreeMap::new(),
+            clock: 0,
+        }
+    }
+
+    /// Moves `key` to the back of the line, if it's there
+    fn touch(&mut self, key: &K) -> Option<&V> {
+        let (value, used) = self.entries.get_mut(key)?;
+        let key = self.by_use.remove(used).unwrap();
+        self.clock += 1;
+        *used = self.clock;
+        self.by_use.insert(self.clock, key);
+        Some(value)
+    }
+
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        self.touch(key).cloned()
+    }
+
+    pub fn put(&mut self, key: K, value: V) {
+        if self.touch(&key).is_some() {
+            self.entries.get_mut(&key).unwrap().0 = value;
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            let (_, oldest) = self.by_use.pop_first().unwrap();
+            self.entries.remove(&oldest);
+        }
+        self.clock += 1;
+        self.by_use.insert(self.clock, key.clone());
+        self.entries.insert(key, (value, self.clock));
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The keys from the least to the most recently used
+    pub fn keys(&self) -> Vec<K> {
+        self.by_use.values().cloned().collect()
+    }
+}
+
+/// One `LruInner` behind one lock
+#[derive(Debug)]
+pub struct MutexLru<K, V> {
+    inner: Mutex<LruInner<K, V>>,
+    stats: Stats,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> MutexLru<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Mutex::new(LruInner::new(capacity)),
+            stats: Stats::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone + Send, V: Clone + Send> Cache<K, V> for MutexLru<K, V> {
+    fn get(&self, key: &K) -> Option<V> {
+        todo!()
+    }
+
+    fn put(&self, key: K, value: V) {
+        todo!()
+    }
+
+    fn len(&self) -> usize {
+        todo!()
+    }
+
+    fn stats(&self) -> &Stats {
+        &self.stats
+    }
+}
+
+/// An LRU cache split into shards, each an `LruInner` behind its own lock, with a share of the capacity.
+/// Every key belongs to one shard, decided by its hash, and is only ever evicted to make room in that shard.
+#[derive(Debug)]
+pub struct ShardedLru<K, V> {
+    shards: Vec<Mutex<LruInner<K, V>>>,
+    hasher: RandomState,
+    stats: Stats,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> ShardedLru<K, V> {
+    /// A cache of `shards` shards which can hold `capacity` entries in all, rounded up to fill every shard equally
+    pub fn new(capacity: usize, shards: usize) -> Self {
+        assert!(shards > 0, "A cache needs at least one shard");
+        let per_shard = capacity.div_ceil(shards);
+        Self {
+            shards: (0..shards)
+                .map(|_| Mutex::new(LruInner::new(per_shard)))
+                .collect(),
+            hasher: RandomState::new(),
+            stats: Stats::default(),
+        }
+    }
+
+    /// Which shard `key` belongs to
+    pub fn shard_of(&self, key: &K) -> usize {
+        todo!()
+    }
+
+    /// The keys in shard `shard`, from the least to the most recently used
+    pub fn keys_in_shard(&self, shard: usize) -> Vec<K> {
+        self.shards[shard].lock().unwrap().keys()
+    }
+}
+
+impl<K: Hash + Eq + Clone + Send, V: Clone + Send> Cache<K, V> for ShardedLru<K, V> {
+    fn get(&self, key: &K) -> Option<V> {
+        todo!()
+    }
+
+    fn put(&self, key: K, value: V) {
+        todo!()
+    }
+
+    fn len(&self) -> usize {
+        todo!()
+    }
+
+    fn stats(&self) -> &Stats {
+        &self.stats
+    }
+}
+
+/// What a cached lookup would otherwise have to compute
+pub fn expensive(key: u64) -> u64 {
+    key.wrapping_mul(0x9e3779b97f4a7c15).rotate_left(17)
+}
+
+/// Lets `threads` threads look up the `keys` a chunk each, putting what they miss in the cache like a real cache would
+pub fn mixed_load(cache: &(impl Cache<u64, u64> + ?Sized), keys: &[u64], threads: usize) {
+    thread::scope(|s| {
+        for (id, chunk) in keys.chunks(keys.len().div_ceil(threads).max(1)).enumerate() {
+            s.spawn(move || {
+                let _worker = common::span::worker(id);
+                for &key in chunk {
+                    if cache.get(&key).is_none() {
+                        cache.put(key, expensive(key));
+                    }
+                }
+            });
+        }
+    });
+}
@@ -0,0 +1,168 @@
+use common::{bench::Comparison, cli::Cli};
+use part_85::{mixed_load, Cache, MutexLru, ShardedLru};
+
+const THREADS: usize = 8;
+const CAPACITY: usize = 1000;
+
+/// Run with `cargo run --release -p part-85` to compare them
+fn main() {
+    common::init_tracing();
+    let args = Cli::new("Compares an LRU cache behind one lock with sharded ones")
+        .threads(THREADS)
+        .strategy()
+        .parse();
+    let threads = args.threads();
+    // Twice as many keys as fit, so about half the lookups hit
+    let keys = common::datagen::numbers_below(threads * 100_000, 2 * CAPACITY as u64, 85);
+    println!("{} lookups on {threads} threads", keys.len());
+
+    let mut comparison = Comparison::new(10);
+    comparison.bench("One lock", || {
+        let cache = MutexLru::new(CAPACITY);
+        mixed_load(&cache, &keys, threads);
+    });
+    for shards in [4, 16, 64] {
+        comparison.bench(&format!("{shards} shards"), || {
+            let cache = ShardedLru::new(CAPACITY, shards);
+            mixed_load(&cache, &keys, threads);
+        });
+    }
+    comparison.print();
+
+    let one_lock = MutexLru::new(CAPACITY);
+    mixed_load(&one_lock, &keys, threads);
+    let sharded = ShardedLru::new(CAPACITY, 64);
+    mixed_load(&sharded, &keys, threads);
+    println!(
+        "Hit rates: {:.3} with one lock, {:.3} with 64 shards",
+        one_lock.stats().hit_rate(),
+        sharded.stats().hit_rate()
+    );
+}
+
+/// Checks that `cache` forgets the least recently used entry, and that getting or putting an entry counts as using it
+#[cfg(test)]
+fn check_eviction(cache: &impl Cache<u64, &'static str>) {
+    cache.put(1, "one");
+    cache.put(2, "two");
+    assert_eq!(cache.get(&1), Some("one"));
+    // 2 is the least recently used now
+    cache.put(3, "three");
+    assert_eq!(cache.get(&2), None);
+    // Replacing a value doesn't make room for anything
+    cache.put(1, "uno");
+    assert_eq!(cache.len(), 2);
+    cache.put(4, "four");
+    assert_eq!(cache.get(&3), None);
+    assert_eq!(cache.get(&1), Some("uno"));
+    assert_eq!(cache.get(&4), Some("four"));
+}
+
+#[test]
+fn inner_keeps_keys_in_order_of_use() {
+    use part_85::LruInner;
+
+    let mut inner = LruInner::new(3);
+    for key in [1, 2, 3] {
+        inner.put(key, key * 10);
+    }
+    inner.get(&1);
+    inner.put(4, 40);
+    assert_eq!(inner.keys(), [3, 1, 4]);
+}
+
+#[test]
+fn mutex_lru_evicts_the_least_recently_used() {
+    check_eviction(&MutexLru::new(2));
+}
+
+#[test]
+fn sharded_lru_with_one_shard_evicts_the_least_recently_used() {
+    check_eviction(&ShardedLru::new(2, 1));
+}
+
+#[test]
+fn sharded_lru_evicts_within_a_shard() {
+    let cache = ShardedLru::new(8, 4);
+    // Three keys in the same shard, which fits two of them
+    let keys: Vec<u64> = (0..)
+        .filter(|key| cache.shard_of(key) == 0)
+        .take(3)
+        .collect();
+    let (a, b, c) = (keys[0], keys[1], keys[2]);
+    cache.put(a, a);
+    cache.put(b, b);
+    cache.get(&a);
+    cache.put(c, c);
+    assert_eq!(cache.keys_in_shard(0), [a, c]);
+    assert_eq!(cache.get(&b), None);
+}
+
+#[test]
+fn sharded_lru_spreads_the_keys() {
+    let cache = ShardedLru::new(1000, 4);
+    for key in 0..1000 {
+        cache.put(key, key);
+    }
+    for shard in 0..4 {
+        assert!(
+            !cache.keys_in_shard(shard).is_empty(),
+            "Shard {shard} is empty"
+        );
+    }
+}
+
+#[test]
+fn never_grows_past_the_capacity() {
+    let mut rng = common::rng::for_test();
+    let keys = common::datagen::numbers_below(40_000, 5000, rng.seed());
+    let one_lock = MutexLru::new(CAPACITY);
+    mixed_load(&one_lock, &keys, THREADS);
+    assert_eq!(one_lock.len(), CAPACITY);
+    let sharded = ShardedLru::new(CAPACITY, 16);
+    mixed_load(&sharded, &keys, THREADS);
+    // The shards can each be full, and share the capacity rounded up
+    assert!(sharded.len() <= CAPACITY.div_ceil(16) * 16);
+}
+
+#[test]
+fn caches_the_right_values_from_many_threads() {
+    use part_85::expensive;
+
+    let mut rng = common::rng::for_test();
+    let keys = common::datagen::numbers_below(40_000, 2000, rng.seed());
+    let cache = ShardedLru::new(CAPACITY, 16);
+    mixed_load(&cache, &keys, THREADS);
+    for key in 0..2000 {
+        if let Some(value) = cache.get(&key) {
+            assert_eq!(value, expensive(key));
+        }
+    }
+}
+
+#[test]
+fn counts_hits_and_misses() {
+    let cache = MutexLru::new(10);
+    cache.put(1, 1);
+    cache.get(&1);
+    cache.get(&1);
+    cache.get(&2);
+    cache.get(&1);
+    let stats = cache.stats();
+    assert_eq!((stats.hits(), stats.misses()), (3, 1));
+    assert_eq!(stats.hit_rate(), 0.75);
+}
+
+#[test]
+fn hits_when_everything_fits() {
+    let mut rng = common::rng::for_test();
+    let keys = common::datagen::numbers_below(40_000, 100, rng.seed());
+    for cache in [
+        &MutexLru::new(CAPACITY) as &dyn Cache<u64, u64>,
+        &ShardedLru::new(CAPACITY, 16),
+    ] {
+        mixed_load(cache, &keys, THREADS);
+        // Every key misses once, or a few times if threads look for it at the same time
+        assert!(cache.stats().hit_rate() > 0.95, "{:?}", cache.stats());
+    }
+}
@@ -0,0 +1,102 @@
+//! Part 85, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    hash::{BuildHasher, Hash, RandomState},
+    sync::Mutex,
+};
+
+use super::{Cache, LruInner, Stats};
+
+/// One `LruInner` behind one lock
+#[derive(Debug)]
+pub struct MutexLru<K, V> {
+    inner: Mutex<LruInner<K, V>>,
+    stats: Stats,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> MutexLru<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Mutex::new(LruInner::new(capacity)),
+            stats: Stats::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone + Send, V: Clone + Send> Cache<K, V> for MutexLru<K, V> {
+    fn get(&self, key: &K) -> Option<V> {
+        let value = self.inner.lock().unwrap().get(key);
+        self.stats.record(value.is_some());
+        value
+    }
+
+    fn put(&self, key: K, value: V) {
+        self.inner.lock().unwrap().put(key, value);
+    }
+
+    fn len(&self) -> usize {
+        self.inner.lock().unwrap().len()
+    }
+
+    fn stats(&self) -> &Stats {
+        &self.stats
+    }
+}
+
+/// An LRU cache split into shards, each an `LruInner` behind its own lock, with a share of the capacity.
+/// Every key belongs to one shard, decided by its hash, and is only ever evicted to make room in that shard.
+#[derive(Debug)]
+pub struct ShardedLru<K, V> {
+    shards: Vec<Mutex<LruInner<K, V>>>,
+    hasher: RandomState,
+    stats: Stats,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> ShardedLru<K, V> {
+    /// A cache of `shards` shards which can hold `capacity` entries in all, rounded up to fill every shard equally
+    pub fn new(capacity: usize, shards: usize) -> Self {
+        assert!(shards > 0, "A cache needs at least one shard");
+        let per_shard = capacity.div_ceil(shards);
+        Self {
+            shards: (0..shards)
+                .map(|_| Mutex::new(LruInner::new(per_shard)))
+                .collect(),
+            hasher: RandomState::new(),
+            stats: Stats::default(),
+        }
+    }
+
+    /// Which shard `key` belongs to
+    pub fn shard_of(&self, key: &K) -> usize {
+        self.hasher.hash_one(key) as usize % self.shards.len()
+    }
+
+    /// The keys in shard `shard`, from the least to the most recently used
+    pub fn keys_in_shard(&self, shard: usize) -> Vec<K> {
+        self.shards[shard].lock().unwrap().keys()
+    }
+}
+
+impl<K: Hash + Eq + Clone + Send, V: Clone + Send> Cache<K, V> for ShardedLru<K, V> {
+    fn get(&self, key: &K) -> Option<V> {
+        let value = self.shards[self.shard_of(key)].lock().unwrap().get(key);
+        self.stats.record(value.is_some());
+        value
+    }
+
+    fn put(&self, key: K, value: V) {
+        let shard = self.shard_of(&key);
+        self.shards[shard].lock().unwrap().put(key, value);
+    }
+
+    fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.lock().unwrap().len())
+            .sum()
+    }
+
+    fn stats(&self) -> &Stats {
+        &self.stats
+    }
+}
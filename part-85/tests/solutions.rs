@@ -0,0 +1,78 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-85 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_85::{expensive, solutions, Cache, MutexLru, ShardedLru};
+
+/// Gets the `keys` from the cache, putting what's missing, and returns what the gets found
+fn looked_up(cache: &impl Cache<u64, u64>, keys: &[u64]) -> Vec<Option<u64>> {
+    keys.iter()
+        .map(|&key| {
+            let found = cache.get(&key);
+            if found.is_none() {
+                cache.put(key, expensive(key));
+            }
+            found
+        })
+        .collect()
+}
+
+/// Like `looked_up`, with the solution's caches
+fn expected(cache: &impl Cache<u64, u64>, keys: &[u64]) -> Vec<Option<u64>> {
+    keys.iter()
+        .map(|&key| {
+            let found = cache.get(&key);
+            if found.is_none() {
+                cache.put(key, expensive(key));
+            }
+            found
+        })
+        .collect()
+}
+
+#[test]
+fn caches_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let keys = common::datagen::numbers_below(2000, 50, rng.seed());
+    for capacity in [1, 10, 100] {
+        let (cache, solution) = (MutexLru::new(capacity), solutions::MutexLru::new(capacity));
+        assert_eq!(
+            looked_up(&cache, &keys),
+            expected(&solution, &keys),
+            "A capacity of {capacity}"
+        );
+        assert_eq!(cache.len(), Cache::len(&solution));
+        let stats = Cache::stats(&solution);
+        assert_eq!(
+            (cache.stats().hits(), cache.stats().misses()),
+            (stats.hits(), stats.misses())
+        );
+    }
+}
+
+#[test]
+fn shards_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    // Which shard a key goes in is random, so the shards have to be large enough that nothing is evicted
+    let keys = common::datagen::numbers_below(2000, 50, rng.seed());
+    for shards in [1, 4, 16] {
+        let (cache, solution) = (
+            ShardedLru::new(50 * shards, shards),
+            solutions::ShardedLru::new(50 * shards, shards),
+        );
+        assert_eq!(
+            looked_up(&cache, &keys),
+            expected(&solution, &keys),
+            "{shards} shards"
+        );
+        assert_eq!(cache.len(), Cache::len(&solution));
+        for key in 0..50 {
+            assert!(cache.shard_of(&key) < shards);
+            assert_eq!(cache.shard_of(&key), cache.shard_of(&key));
+        }
+    }
+    // With a single shard, it's exactly one LRU cache
+    let (cache, solution) = (ShardedLru::new(10, 1), solutions::ShardedLru::new(10, 1));
+    assert_eq!(looked_up(&cache, &keys), expected(&solution, &keys));
+    assert_eq!(cache.keys_in_shard(0), solution.keys_in_shard(0));
+}
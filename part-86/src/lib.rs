@@ -0,0 +1,190 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        mpsc::{self, RecvError, RecvTimeoutError, TryRecvError, TrySendError},
+        Arc, Mutex, Weak,
+    },
+    time::Duration,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// What publishing does when a subscriber's queue is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlowPolicy {
+    /// Waits until the subscriber has made room, which slows down the publisher
+    Block,
+    /// Drops the event for that subscriber, and counts it as dropped
+    DropNewest,
+    /// Unsubscribes the subscriber, which gets the events already in its queue and then finds it disconnected
+    Disconnect,
+}
+
+/// The bus's end of a subscription
+#[derive(Debug)]
+struct Subscriber<E> {
+    id: usize,
+    sender: mpsc::SyncSender<E>,
+    policy: SlowPolicy,
+    dropped: Arc<AtomicU64>,
+}
+
+// Not derived, since that would require `E: Clone`, which the sender doesn't need
+impl<E> Clone for Subscriber<E> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            sender: self.sender.clone(),
+            policy: self.policy,
+            dropped: self.dropped.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Inner<E> {
+    /// The subscribers of every topic, in the order they subscribed
+    topics: Mutex<HashMap<String, Vec<Subscriber<E>>>>,
+    next_id: AtomicUsize,
+}
+
+impl<E> Inner<E> {
+    /// Removes the subscriber `id` from `topic`, if it's still there
+    fn remove(&self, topic: &str, id: usize) {
+        let mut topics = self.topics.lock().unwrap();
+        if let Some(subscribers) = topics.get_mut(topic) {
+            subscribers.retain(|subscriber| subscriber.id != id);
+            if subscribers.is_empty() {
+                topics.remove(topic);
+            }
+        }
+    }
+}
+
+/// Sends every event published on a topic to everyone who subscribed to it.
+/// Clones share the same subscribers.
+#[derive(Debug)]
+pub struct EventBus<E> {
+    inner: Arc<Inner<E>>,
+}
+
+impl<E> Clone for EventBus<E> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<E> Default for EventBus<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> EventBus<E> {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                topics: Mutex::new(HashMap::new()),
+                next_id: AtomicUsize::new(0),
+            }),
+        }
+    }
+
+    /// Subscribes to `topic`, with a queue of `capacity` events. Gets every event published on the topic
+    /// after this returns, unless `policy` says otherwise, until the subscription is dropped.
+    pub fn subscribe(&self, topic: &str, capacity: usize, policy: SlowPolicy) -> Subscription<E> {
+        let (sender, receiver) = mpsc::sync_channel(capacity);
+        let id = self.inner.next_id.fetch_add(1, Ordering::Relaxed);
+        let dropped = Arc::new(AtomicU64::new(0));
+        self.inner
+            .topics
+            .lock()
+            .unwrap()
+            .entry(topic.to_string())
+            .or_default()
+            .push(Subscriber {
+                id,
+                sender,
+                policy,
+                dropped: dropped.clone(),
+            });
+        Subscription {
+            id,
+            topic: topic.to_string(),
+            receiver,
+            dropped,
+            bus: Arc::downgrade(&self.inner),
+        }
+    }
+
+    /// How many subscribers `topic` has
+    pub fn subscribers(&self, topic: &str) -> usize {
+        self.inner
+            .topics
+            .lock()
+            .unwrap()
+            .get(topic)
+            .map_or(0, Vec::len)
+    }
+}
+
+impl<E: Clone> EventBus<E> {
+    /// Sends `event` to every subscriber of `topic`, following the `SlowPolicy` of each of them.
+    /// Returns how many got it. Subscribers which are gone, or which were too slow and asked to be disconnected, are unsubscribed.
+    pub fn publish(&self, topic: &str, event: E) -> usize {
+        todo!()
+    }
+}
+
+/// A subscriber's end of a subscription. Dropping it unsubscribes.
+#[derive(Debug)]
+pub struct Subscription<E> {
+    id: usize,
+    topic: String,
+    receiver: mpsc::Receiver<E>,
+    dropped: Arc<AtomicU64>,
+    /// Weak, so that the subscription doesn't keep the bus alive. The queue disconnects once every bus is gone.
+    bus: Weak<Inner<E>>,
+}
+
+impl<E> Subscription<E> {
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    /// Waits for the next event. Fails once the subscriber has been disconnected and every event in the queue has been received.
+    pub fn recv(&self) -> Result<E, RecvError> {
+        self.receiver.recv()
+    }
+
+    pub fn try_recv(&self) -> Result<E, TryRecvError> {
+        self.receiver.try_recv()
+    }
+
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<E, RecvTimeoutError> {
+        self.receiver.recv_timeout(timeout)
+    }
+
+    /// Every event which is in the queue right now, without waiting for more
+    pub fn try_iter(&self) -> mpsc::TryIter<'_, E> {
+        self.receiver.try_iter()
+    }
+
+    /// How many events were dropped because the queue was full
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl<E> Drop for Subscription<E> {
+    fn drop(&mut self) {
+        // Panicking again while a test is already panicking would abort every test
+        if !std::thread::panicking() {
+            todo!()
+        }
+    }
+}
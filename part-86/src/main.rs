@@ -0,0 +1,228 @@
+use std::thread;
+
+use part_86::{EventBus, SlowPolicy, Subscription};
+
+/// Every event `subscription` gets until it's disconnected
+fn everything(subscription: &Subscription<String>) -> Vec<String> {
+    std::iter::from_fn(|| subscription.recv().ok()).collect()
+}
+
+fn main() {
+    let bus = EventBus::new();
+    let logger = bus.subscribe("orders", 100, SlowPolicy::Block);
+    let dashboard = bus.subscribe("orders", 2, SlowPolicy::DropNewest);
+    let flaky = bus.subscribe("orders", 2, SlowPolicy::Disconnect);
+    let shipping = bus.subscribe("shipping", 10, SlowPolicy::Block);
+
+    let publisher = {
+        let bus = bus.clone();
+        thread::spawn(move || {
+            for order in 1..=5 {
+                let delivered = bus.publish("orders", format!("order {order}"));
+                println!("Order {order} went to {delivered} subscribers");
+            }
+            bus.publish("shipping", "everything shipped".to_string());
+        })
+    };
+    publisher.join().unwrap();
+    // Once the bus is gone, every subscription disconnects after it has received what's in its queue
+    drop(bus);
+
+    println!("The logger got {:?}", everything(&logger));
+    println!(
+        "The dashboard got {:?}, and missed {}",
+        everything(&dashboard),
+        dashboard.dropped()
+    );
+    println!("The flaky one got {:?}", everything(&flaky));
+    println!("Shipping got {:?}", everything(&shipping));
+}
+
+/// How long the tests wait for an event before giving up on it
+#[cfg(test)]
+const PATIENCE: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// The next event `subscription` gets, or `None` once it's disconnected.
+/// Fails the test instead of waiting forever for an event which is never published.
+#[cfg(test)]
+fn next_event<E>(subscription: &Subscription<E>) -> Option<E> {
+    use std::sync::mpsc::RecvTimeoutError;
+
+    match subscription.recv_timeout(PATIENCE) {
+        Ok(event) => Some(event),
+        Err(RecvTimeoutError::Disconnected) => None,
+        Err(RecvTimeoutError::Timeout) => panic!("Waited {PATIENCE:?} for an event"),
+    }
+}
+
+#[test]
+fn delivers_to_every_subscriber_of_the_topic() {
+    let bus = EventBus::new();
+    let first = bus.subscribe("news", 10, SlowPolicy::Block);
+    let second = bus.subscribe("news", 10, SlowPolicy::Block);
+    let other = bus.subscribe("weather", 10, SlowPolicy::Block);
+    assert_eq!(bus.publish("news", 1), 2);
+    assert_eq!(first.try_recv(), Ok(1));
+    assert_eq!(second.try_recv(), Ok(1));
+    assert!(other.try_recv().is_err());
+    assert_eq!(bus.publish("sports", 2), 0);
+}
+
+#[test]
+fn keeps_the_order_of_the_events() {
+    let bus = EventBus::new();
+    let subscription = bus.subscribe("numbers", 1000, SlowPolicy::Block);
+    for n in 0..1000 {
+        bus.publish("numbers", n);
+    }
+    let received: Vec<_> = subscription.try_iter().collect();
+    assert_eq!(received, (0..1000).collect::<Vec<_>>());
+}
+
+#[test]
+fn dropping_a_subscription_unsubscribes() {
+    let bus = EventBus::new();
+    let staying = bus.subscribe("news", 10, SlowPolicy::Block);
+    let leaving = bus.subscribe("news", 10, SlowPolicy::Block);
+    assert_eq!(bus.subscribers("news"), 2);
+    drop(leaving);
+    assert_eq!(bus.subscribers("news"), 1);
+    assert_eq!(bus.publish("news", 1), 1);
+    assert_eq!(staying.try_recv(), Ok(1));
+}
+
+#[test]
+fn drops_events_for_a_slow_subscriber_which_asked_for_it() {
+    let bus = EventBus::new();
+    let slow = bus.subscribe("news", 2, SlowPolicy::DropNewest);
+    let delivered: Vec<_> = (0..5).map(|n| bus.publish("news", n)).collect();
+    assert_eq!(delivered, [1, 1, 0, 0, 0]);
+    assert_eq!(slow.try_iter().collect::<Vec<_>>(), [0, 1]);
+    assert_eq!(slow.dropped(), 3);
+    // It's still subscribed, and gets new events once it has made room
+    assert_eq!(bus.publish("news", 5), 1);
+    assert_eq!(slow.try_recv(), Ok(5));
+}
+
+#[test]
+fn disconnects_a_slow_subscriber_which_asked_for_it() {
+    let bus = EventBus::new();
+    let slow = bus.subscribe("news", 2, SlowPolicy::Disconnect);
+    let fine = bus.subscribe("news", 10, SlowPolicy::Block);
+    for n in 0..3 {
+        bus.publish("news", n);
+    }
+    assert_eq!(bus.subscribers("news"), 1);
+    assert_eq!(next_event(&slow), Some(0));
+    assert_eq!(next_event(&slow), Some(1));
+    assert_eq!(next_event(&slow), None);
+    assert_eq!(fine.try_iter().collect::<Vec<_>>(), [0, 1, 2]);
+}
+
+#[test]
+fn blocks_the_publisher_until_a_slow_subscriber_catches_up() {
+    let bus = EventBus::new();
+    let slow = bus.subscribe("news", 1, SlowPolicy::Block);
+    let publisher = {
+        let bus = bus.clone();
+        thread::spawn(move || (0..100).map(|n| bus.publish("news", n)).sum::<usize>())
+    };
+    let received: Vec<_> = (0..100).map(|_| next_event(&slow).unwrap()).collect();
+    assert_eq!(received, (0..100).collect::<Vec<_>>());
+    assert_eq!(publisher.join().unwrap(), 100);
+}
+
+#[test]
+fn unsubscribing_unblocks_a_waiting_publisher() {
+    use std::time::Duration;
+
+    let bus = EventBus::new();
+    let slow = bus.subscribe("news", 1, SlowPolicy::Block);
+    let delivered = common::with_timeout(Duration::from_secs(5), move || {
+        let publisher = {
+            let bus = bus.clone();
+            thread::spawn(move || (0..10).map(|n| bus.publish("news", n)).collect::<Vec<_>>())
+        };
+        // Gives the publisher time to fill the queue and start waiting
+        thread::sleep(Duration::from_millis(50));
+        drop(slow);
+        let delivered = publisher.join().unwrap();
+        assert_eq!(bus.subscribers("news"), 0);
+        delivered
+    })
+    .expect("The publisher is still waiting for a subscriber which is gone");
+    assert_eq!(delivered[0], 1);
+    assert_eq!(*delivered.last().unwrap(), 0);
+}
+
+#[test]
+fn gets_every_event_published_after_subscribing() {
+    let bus = EventBus::new();
+    let subscribers: Vec<_> = (0..8)
+        .map(|_| {
+            let bus = bus.clone();
+            let (subscribed, ready) = std::sync::mpsc::channel();
+            let handle = thread::spawn(move || {
+                let subscription = bus.subscribe("news", 100, SlowPolicy::Block);
+                subscribed.send(()).unwrap();
+                (0..100)
+                    .map(|_| next_event(&subscription).unwrap())
+                    .collect::<Vec<_>>()
+            });
+            ready
+                .recv_timeout(PATIENCE)
+                .expect("The subscriber didn't subscribe");
+            handle
+        })
+        .collect();
+    for n in 0..100 {
+        assert_eq!(bus.publish("news", n), 8);
+    }
+    for subscriber in subscribers {
+        assert_eq!(subscriber.join().unwrap(), (0..100).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn survives_subscribing_and_unsubscribing_while_publishing() {
+    use std::time::Duration;
+
+    let bus = EventBus::new();
+    let finished = common::with_timeout(Duration::from_secs(10), move || {
+        let bus = &bus;
+        thread::scope(|s| {
+            for policy in [
+                SlowPolicy::Block,
+                SlowPolicy::DropNewest,
+                SlowPolicy::Disconnect,
+            ] {
+                for _ in 0..3 {
+                    s.spawn(move || {
+                        for _ in 0..200 {
+                            let subscription = bus.subscribe("news", 1, policy);
+                            // Sometimes reads, sometimes leaves with events in the queue
+                            let _ = subscription.try_recv();
+                        }
+                    });
+                }
+            }
+            s.spawn(|| {
+                for n in 0..2000 {
+                    bus.publish("news", n);
+                }
+            });
+        });
+        bus.subscribers("news")
+    });
+    assert_eq!(finished, Some(0), "Deadlocked, or left subscribers behind");
+}
+
+#[test]
+fn dropping_the_bus_disconnects_the_subscribers() {
+    let bus = EventBus::new();
+    let subscription = bus.subscribe("news", 10, SlowPolicy::Block);
+    bus.publish("news", 1);
+    drop(bus);
+    assert_eq!(next_event(&subscription), Some(1));
+    assert_eq!(next_event(&subscription), None);
+}
@@ -0,0 +1,42 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-86 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_86::{solutions, EventBus, SlowPolicy};
+
+#[test]
+fn publishes_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (bus, expected) = (EventBus::new(), solutions::EventBus::new());
+    let dropping = bus.subscribe("news", 3, SlowPolicy::DropNewest);
+    let expected_dropping = expected.subscribe("news", 3, SlowPolicy::DropNewest);
+    let disconnecting = bus.subscribe("news", 2, SlowPolicy::Disconnect);
+    let expected_disconnecting = expected.subscribe("news", 2, SlowPolicy::Disconnect);
+    let other = bus.subscribe("other", 10, SlowPolicy::Block);
+    let expected_other = expected.subscribe("other", 10, SlowPolicy::Block);
+
+    for event in common::datagen::numbers(6, rng.seed()) {
+        assert_eq!(bus.publish("news", event), expected.publish("news", event));
+        assert_eq!(bus.subscribers("news"), expected.subscribers("news"));
+    }
+    assert_eq!(bus.publish("nobody", 1), expected.publish("nobody", 1));
+    assert_eq!(bus.publish("other", 2), expected.publish("other", 2));
+
+    assert_eq!(
+        dropping.try_iter().collect::<Vec<_>>(),
+        expected_dropping.try_iter().collect::<Vec<_>>()
+    );
+    assert_eq!(dropping.dropped(), expected_dropping.dropped());
+    assert_eq!(
+        disconnecting.try_iter().collect::<Vec<_>>(),
+        expected_disconnecting.try_iter().collect::<Vec<_>>()
+    );
+    assert_eq!(disconnecting.try_recv(), expected_disconnecting.try_recv());
+    assert_eq!(other.try_recv(), expected_other.try_recv());
+
+    // Dropping a subscription unsubscribes it
+    drop((dropping, expected_dropping));
+    assert_eq!(bus.subscribers("news"), expected.subscribers("news"));
+    drop((other, expected_other));
+    assert_eq!(bus.subscribers("other"), expected.subscribers("other"));
+}
@@ -0,0 +1,55 @@
+//! Four small pieces of concurrent code, each with a bug in it.
+//! They all work most of the time, which is what makes these bugs so nasty.
+
+pub mod counter;
+pub mod lazy;
+pub mod signal;
+pub mod tickets;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Under `--cfg loom` the synchronization primitives are swapped for loom's, which lets loom
+/// explore every way the threads in a test can interleave, and catch data races.
+mod sync {
+    // Not all of the code needs all of these, at least not once it's fixed
+    #[cfg(loom)]
+    #[allow(unused_imports)]
+    pub use loom::{
+        cell::UnsafeCell,
+        sync::{
+            atomic::{AtomicBool, AtomicU64, Ordering},
+            Condvar, Mutex,
+        },
+        thread,
+    };
+    #[cfg(not(loom))]
+    #[allow(unused_imports)]
+    pub use std::{
+        sync::{
+            atomic::{AtomicBool, AtomicU64, Ordering},
+            Condvar, Mutex,
+        },
+        thread,
+    };
+
+    /// The standard library's `UnsafeCell`, with the same API as loom's
+    #[cfg(not(loom))]
+    #[derive(Debug)]
+    pub struct UnsafeCell<T>(std::cell::UnsafeCell<T>);
+
+    #[cfg(not(loom))]
+    impl<T> UnsafeCell<T> {
+        pub fn new(value: T) -> Self {
+            Self(std::cell::UnsafeCell::new(value))
+        }
+
+        pub fn with<R>(&self, f: impl FnOnce(*const T) -> R) -> R {
+            f(self.0.get())
+        }
+
+        pub fn with_mut<R>(&self, f: impl FnOnce(*mut T) -> R) -> R {
+            f(self.0.get())
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Barrier,
+    },
+    thread,
+    time::Duration,
+};
+
+use part_87::{counter::ViewCounter, lazy::Lazy, signal::Signal, tickets::BoxOffice};
+
+/// The threads every stress test runs
+const THREADS: usize = 4;
+
+/// How many times `expensive_greeting` has been called
+static GREETINGS: AtomicUsize = AtomicUsize::new(0);
+
+fn expensive_greeting() -> String {
+    GREETINGS.fetch_add(1, Ordering::Relaxed);
+    thread::sleep(Duration::from_millis(10));
+    "Hello, world!".to_string()
+}
+
+/// Counts `views` views on each of `THREADS` threads at once
+fn count_views(views: u64) -> u64 {
+    let counter = ViewCounter::new();
+    thread::scope(|s| {
+        for _ in 0..THREADS {
+            s.spawn(|| {
+                for _ in 0..views {
+                    counter.view();
+                }
+            });
+        }
+    });
+    counter.views()
+}
+
+/// Lets `THREADS` threads at once try to buy `attempts` tickets each, from a box office with `capacity` tickets.
+/// Returns the box office, and the ticket numbers the buyers were given.
+fn buy_tickets(capacity: usize, attempts: usize) -> (BoxOffice, Vec<usize>) {
+    let office = BoxOffice::new(capacity);
+    let start = Barrier::new(THREADS);
+    let tickets = thread::scope(|s| {
+        let buyers: Vec<_> = (0..THREADS)
+            .map(|buyer| {
+                let (office, start) = (&office, &start);
+                s.spawn(move || {
+                    start.wait();
+                    (0..attempts)
+                        .filter_map(|_| office.buy(&format!("Buyer {buyer}")).ok())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        buyers
+            .into_iter()
+            .flat_map(|buyer| buyer.join().unwrap())
+            .collect()
+    });
+    (office, tickets)
+}
+
+/// Sets a signal while `THREADS` threads wait for it, `rounds` times.
+/// Returns `false` if some thread was still waiting after a few seconds, having missed it.
+fn signal_rounds(rounds: usize) -> bool {
+    common::with_timeout(Duration::from_secs(5), move || {
+        for _ in 0..rounds {
+            let signal = Arc::new(Signal::new());
+            let waiters: Vec<_> = (0..THREADS)
+                .map(|_| {
+                    let signal = signal.clone();
+                    thread::spawn(move || signal.wait())
+                })
+                .collect();
+            signal.set();
+            for waiter in waiters {
+                waiter.join().unwrap();
+            }
+        }
+    })
+    .is_some()
+}
+
+fn main() {
+    println!(
+        "Counted {} of {} views",
+        count_views(1_000_000),
+        THREADS * 1_000_000
+    );
+
+    let (office, tickets) = buy_tickets(100, 50);
+    println!(
+        "Sold {} of 100 tickets, and handed out {} of them",
+        office.sold(),
+        tickets.len()
+    );
+
+    if signal_rounds(1000) {
+        println!("Everyone woke up when the signal was set");
+    } else {
+        println!("Someone missed the signal, and is still waiting");
+    }
+
+    let greeting = Lazy::new(expensive_greeting);
+    thread::scope(|s| {
+        for _ in 0..THREADS {
+            s.spawn(|| greeting.get().len());
+        }
+    });
+    println!(
+        "Computed {:?} {} time(s)",
+        greeting.get(),
+        GREETINGS.load(Ordering::Relaxed)
+    );
+}
+
+#[test]
+fn counts_every_view() {
+    assert_eq!(count_views(1_000_000), THREADS as u64 * 1_000_000);
+}
+
+#[test]
+fn never_sells_more_tickets_than_there_are() {
+    for _ in 0..20 {
+        let (office, mut tickets) = buy_tickets(100, 50);
+        assert_eq!(office.sold(), 100);
+        tickets.sort_unstable();
+        assert_eq!(tickets, (0..100).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn sells_out_exactly_when_everyone_wants_a_ticket() {
+    let (office, tickets) = buy_tickets(1000, 1000);
+    let buyers = office.buyers();
+    assert_eq!(buyers.len(), 1000);
+    assert_eq!(tickets.len(), 1000);
+    assert!(buyers.iter().all(|buyer| buyer.starts_with("Buyer ")));
+}
+
+#[test]
+fn sets_the_signal_once_and_for_all() {
+    let signal = Signal::new();
+    assert!(!signal.is_set());
+    signal.set();
+    assert!(signal.is_set());
+    // Returns right away, since it's already set
+    signal.wait();
+    signal.set();
+    assert!(signal.is_set());
+}
+
+#[test]
+fn wakes_up_everyone_waiting_for_the_signal() {
+    assert!(signal_rounds(1000), "Some thread missed the signal");
+}
+
+#[test]
+fn computes_the_value_once() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    fn answer() -> u64 {
+        CALLS.fetch_add(1, Ordering::Relaxed);
+        thread::sleep(Duration::from_millis(10));
+        42
+    }
+
+    let lazy = Lazy::new(answer);
+    let start = Barrier::new(THREADS);
+    let answers: Vec<u64> = thread::scope(|s| {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                s.spawn(|| {
+                    start.wait();
+                    *lazy.get()
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    assert_eq!(answers, [42; THREADS]);
+    assert_eq!(*lazy.get(), 42);
+    assert_eq!(CALLS.load(Ordering::Relaxed), 1);
+}
@@ -0,0 +1,38 @@
+//! Lets threads wait for something to happen, such as the end of a startup phase.
+
+use crate::sync::{thread, AtomicBool, Condvar, Mutex, Ordering};
+
+/// Starts out unset, and is set once. Everyone waiting for it is woken up when it's set.
+#[derive(Debug, Default)]
+pub struct Signal {
+    set: AtomicBool,
+    lock: Mutex<()>,
+    changed: Condvar,
+}
+
+impl Signal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the signal, and wakes up everyone waiting for it
+    pub fn set(&self) {
+        self.set.store(true, Ordering::SeqCst);
+        self.changed.notify_all();
+    }
+
+    /// Whether the signal has been set
+    pub fn is_set(&self) -> bool {
+        self.set.load(Ordering::SeqCst)
+    }
+
+    /// Waits until the signal is set, or returns right away if it already is
+    pub fn wait(&self) {
+        while !self.is_set() {
+            // Other threads get a chance to run here, like they would if this one was interrupted
+            thread::yield_now();
+            let guard = self.lock.lock().unwrap();
+            drop(self.changed.wait(guard).unwrap());
+        }
+    }
+}
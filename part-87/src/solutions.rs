@@ -0,0 +1,6 @@
+//! Part 87, with every exercise implemented. Only built with `--features solutions`.
+
+pub mod counter;
+pub mod lazy;
+pub mod signal;
+pub mod tickets;
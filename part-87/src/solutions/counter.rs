@@ -0,0 +1,32 @@
+use crate::sync::{AtomicU64, Ordering};
+
+/// How many times a page has been viewed
+#[derive(Debug)]
+pub struct ViewCounter {
+    views: AtomicU64,
+}
+
+impl ViewCounter {
+    pub fn new() -> Self {
+        Self {
+            views: AtomicU64::new(0),
+        }
+    }
+
+    /// Counts one more view
+    pub fn view(&self) {
+        // Nothing else is read or written based on the count, so it doesn't need to be ordered with anything
+        self.views.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// How many views have been counted so far
+    pub fn views(&self) -> u64 {
+        self.views.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for ViewCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,45 @@
+use crate::sync::{AtomicBool, Mutex, Ordering, UnsafeCell};
+
+/// A value which is only computed by `init` when it's first asked for, by whichever thread gets there first.
+/// Uses double-checked locking, so that threads only lock while the value is being computed.
+pub struct Lazy<T> {
+    init: fn() -> T,
+    ready: AtomicBool,
+    lock: Mutex<()>,
+    value: UnsafeCell<Option<T>>,
+}
+
+// SAFETY: `value` is only written once, by the thread holding the lock, before `ready` is set.
+// After that it's only read, by any thread, so the value must be `Sync` too.
+unsafe impl<T: Send + Sync> Sync for Lazy<T> {}
+
+impl<T> Lazy<T> {
+    pub fn new(init: fn() -> T) -> Self {
+        Self {
+            init,
+            ready: AtomicBool::new(false),
+            lock: Mutex::new(()),
+            value: UnsafeCell::new(None),
+        }
+    }
+
+    /// The value, which is computed first if no one has asked for it before
+    pub fn get(&self) -> &T {
+        // Acquire, so that a thread which sees `ready` also sees the value
+        if !self.ready.load(Ordering::Acquire) {
+            let _guard = self.lock.lock().unwrap();
+            // Someone else may have computed it while this thread waited for the lock,
+            // and the lock makes sure that's seen, together with the value
+            if !self.ready.load(Ordering::Relaxed) {
+                let value = (self.init)();
+                // SAFETY: No one reads the value before `ready` is set, and no one else writes it while this thread holds the lock
+                self.value.with_mut(|slot| unsafe { *slot = Some(value) });
+                self.ready.store(true, Ordering::Release);
+            }
+        }
+        // SAFETY: The value is set by now, and is never written again
+        self.value
+            .with(|slot| unsafe { (*slot).as_ref() })
+            .expect("The value is set before `ready` is")
+    }
+}
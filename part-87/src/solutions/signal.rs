@@ -0,0 +1,35 @@
+use crate::sync::{Condvar, Mutex};
+
+/// Starts out unset, and is set once. Everyone waiting for it is woken up when it's set.
+#[derive(Debug, Default)]
+pub struct Signal {
+    /// Whether it's set. Only changed while holding the lock, so no one can miss the change.
+    set: Mutex<bool>,
+    changed: Condvar,
+}
+
+impl Signal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the signal, and wakes up everyone waiting for it
+    pub fn set(&self) {
+        *self.set.lock().unwrap() = true;
+        self.changed.notify_all();
+    }
+
+    /// Whether the signal has been set
+    pub fn is_set(&self) -> bool {
+        *self.set.lock().unwrap()
+    }
+
+    /// Waits until the signal is set, or returns right away if it already is
+    pub fn wait(&self) {
+        // Waiting releases the lock, and there's no way to set the signal in between checking and waiting
+        let mut set = self.set.lock().unwrap();
+        while !*set {
+            set = self.changed.wait(set).unwrap();
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::sync::{thread, Mutex};
+use crate::tickets::SoldOut;
+
+/// Sells up to `capacity` tickets, numbered from 0
+#[derive(Debug)]
+pub struct BoxOffice {
+    capacity: usize,
+    buyers: Mutex<Vec<String>>,
+}
+
+impl BoxOffice {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            buyers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// How many tickets have been sold
+    pub fn sold(&self) -> usize {
+        self.buyers.lock().unwrap().len()
+    }
+
+    /// Who bought every ticket, by ticket number
+    pub fn buyers(&self) -> Vec<String> {
+        self.buyers.lock().unwrap().clone()
+    }
+
+    /// Sells a ticket to `buyer`, and returns its number
+    pub fn buy(&self, buyer: &str) -> Result<usize, SoldOut> {
+        // Checking and buying under the same lock, so no one can buy the last ticket in between
+        let mut buyers = self.buyers.lock().unwrap();
+        if buyers.len() >= self.capacity {
+            return Err(SoldOut);
+        }
+        // Printing the ticket takes a moment, which gives the other buyers a chance to get in line
+        thread::yield_now();
+        buyers.push(buyer.to_string());
+        Ok(buyers.len() - 1)
+    }
+}
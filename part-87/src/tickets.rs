@@ -0,0 +1,45 @@
+//! Sells a limited number of tickets to buyers who all show up at once.
+
+use crate::sync::{thread, Mutex};
+
+/// There were no tickets left
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SoldOut;
+
+/// Sells up to `capacity` tickets, numbered from 0
+#[derive(Debug)]
+pub struct BoxOffice {
+    capacity: usize,
+    buyers: Mutex<Vec<String>>,
+}
+
+impl BoxOffice {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            buyers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// How many tickets have been sold
+    pub fn sold(&self) -> usize {
+        self.buyers.lock().unwrap().len()
+    }
+
+    /// Who bought every ticket, by ticket number
+    pub fn buyers(&self) -> Vec<String> {
+        self.buyers.lock().unwrap().clone()
+    }
+
+    /// Sells a ticket to `buyer`, and returns its number
+    pub fn buy(&self, buyer: &str) -> Result<usize, SoldOut> {
+        if self.sold() >= self.capacity {
+            return Err(SoldOut);
+        }
+        // Printing the ticket takes a moment, which gives the other buyers a chance to get in line
+        thread::yield_now();
+        let mut buyers = self.buyers.lock().unwrap();
+        buyers.push(buyer.to_string());
+        Ok(buyers.len() - 1)
+    }
+}
@@ -0,0 +1,61 @@
+//! Run these with `RUSTFLAGS="--cfg loom" cargo test --release -p part-87 --test loom`
+#![cfg(loom)]
+
+use loom::{sync::Arc, thread};
+use part_87::{counter::ViewCounter, lazy::Lazy, signal::Signal, tickets::BoxOffice};
+
+#[test]
+fn counts_every_view() {
+    loom::model(|| {
+        let counter = Arc::new(ViewCounter::new());
+        let viewer = {
+            let counter = counter.clone();
+            thread::spawn(move || counter.view())
+        };
+        counter.view();
+        viewer.join().unwrap();
+        assert_eq!(counter.views(), 2);
+    });
+}
+
+#[test]
+fn sells_the_last_ticket_once() {
+    loom::model(|| {
+        let office = Arc::new(BoxOffice::new(1));
+        let other = {
+            let office = office.clone();
+            thread::spawn(move || office.buy("Other").is_ok())
+        };
+        let bought = office.buy("Me").is_ok();
+        let other_bought = other.join().unwrap();
+        assert!(bought != other_bought, "Exactly one buyer gets the ticket");
+        assert_eq!(office.sold(), 1);
+    });
+}
+
+#[test]
+fn wakes_up_the_waiter() {
+    loom::model(|| {
+        let signal = Arc::new(Signal::new());
+        let waiter = {
+            let signal = signal.clone();
+            thread::spawn(move || signal.wait())
+        };
+        signal.set();
+        // Loom reports a deadlock if the waiter can wait forever
+        waiter.join().unwrap();
+    });
+}
+
+#[test]
+fn everyone_sees_the_computed_value() {
+    loom::model(|| {
+        let lazy = Arc::new(Lazy::new(|| String::from("computed")));
+        let other = {
+            let lazy = lazy.clone();
+            thread::spawn(move || lazy.get().clone())
+        };
+        assert_eq!(lazy.get(), "computed");
+        assert_eq!(other.join().unwrap(), "computed");
+    });
+}
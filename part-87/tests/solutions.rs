@@ -0,0 +1,66 @@
+//! The exercises against their fixes, on the same inputs.
+//! Run with `cargo test -p part-87 --features solutions`.
+#![cfg(all(feature = "solutions", not(loom)))]
+
+use std::thread;
+
+use part_87::{counter::ViewCounter, lazy::Lazy, signal::Signal, solutions, tickets::BoxOffice};
+
+#[test]
+fn counts_like_the_fix() {
+    let (counter, expected) = (ViewCounter::new(), solutions::counter::ViewCounter::new());
+    thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..1000 {
+                    counter.view();
+                    expected.view();
+                }
+            });
+        }
+    });
+    assert_eq!(counter.views(), expected.views());
+}
+
+#[test]
+fn initializes_like_the_fix() {
+    let (lazy, expected) = (Lazy::new(|| 87), solutions::lazy::Lazy::new(|| 87));
+    thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| assert_eq!(lazy.get(), expected.get()));
+        }
+    });
+}
+
+#[test]
+fn signals_like_the_fix() {
+    let (signal, expected) = (Signal::new(), solutions::signal::Signal::new());
+    assert_eq!(signal.is_set(), expected.is_set());
+    thread::scope(|s| {
+        s.spawn(|| signal.wait());
+        s.spawn(|| expected.wait());
+        signal.set();
+        expected.set();
+    });
+    assert_eq!(signal.is_set(), expected.is_set());
+}
+
+#[test]
+fn sells_like_the_fix() {
+    let (office, expected) = (BoxOffice::new(10), solutions::tickets::BoxOffice::new(10));
+    thread::scope(|s| {
+        for buyer in 0..4 {
+            let (office, expected) = (&office, &expected);
+            s.spawn(move || {
+                for ticket in 0..5 {
+                    let name = format!("buyer {buyer}, ticket {ticket}");
+                    _ = office.buy(&name);
+                    _ = expected.buy(&name);
+                }
+            });
+        }
+    });
+    assert_eq!(office.sold(), expected.sold());
+    assert_eq!(office.buyers().len(), expected.buyers().len());
+    assert_eq!(office.buy("late").is_err(), expected.buy("late").is_err());
+}
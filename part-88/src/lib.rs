@@ -0,0 +1,179 @@
+// Under `--cfg loom` the atomics are swapped for loom's, which lets loom
+// explore every way the threads in a test can interleave.
+#[cfg(loom)]
+use loom::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+#[cfg(not(loom))]
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Marks the end of a list
+pub const EMPTY: u32 = u32::MAX;
+
+/// Hands out slots numbered `0..capacity`, such as the entries of a preallocated buffer, to one owner at a time.
+/// The free slots are kept in a lock-free linked list, like the stack in part 22,
+/// where the `next` of every free slot is the free slot after it.
+pub trait FreeList: Sync {
+    /// A list where every slot is free, in order
+    fn with_capacity(capacity: u32) -> Self;
+
+    /// Takes a free slot, or returns `None` if there are none left.
+    /// Calls `pause` once, after reading the head of the list and its `next`, but before swapping the head.
+    fn allocate_pausing(&self, pause: impl FnOnce()) -> Option<u32>;
+
+    /// Gives back a slot which was taken by `allocate`
+    fn release(&self, slot: u32);
+
+    /// Takes a free slot, or returns `None` if there are none left
+    fn allocate(&self) -> Option<u32> {
+        self.allocate_pausing(|| {})
+    }
+}
+
+/// The `next` of every slot in a list with `capacity` slots, when every slot is free
+fn linked(capacity: u32) -> Box<[AtomicU32]> {
+    assert!(capacity < EMPTY, "There must be fewer than {EMPTY} slots");
+    (0..capacity)
+        .map(|slot| AtomicU32::new(if slot + 1 < capacity { slot + 1 } else { EMPTY }))
+        .collect()
+}
+
+/// Swaps the head with a compare-and-swap on its slot number, which is subject to the ABA problem
+#[derive(Debug)]
+pub struct NaiveFreeList {
+    head: AtomicU32,
+    next: Box<[AtomicU32]>,
+}
+
+impl FreeList for NaiveFreeList {
+    fn with_capacity(capacity: u32) -> Self {
+        Self {
+            head: AtomicU32::new(if capacity > 0 { 0 } else { EMPTY }),
+            next: linked(capacity),
+        }
+    }
+
+    fn allocate_pausing(&self, pause: impl FnOnce()) -> Option<u32> {
+        let mut pause = Some(pause);
+        let mut head = self.head.load(Ordering::Acquire);
+        loop {
+            if head == EMPTY {
+                return None;
+            }
+            let next = self.next[head as usize].load(Ordering::Relaxed);
+            if let Some(pause) = pause.take() {
+                pause();
+            }
+            // Not `compare_exchange_weak`, since failing for no reason would make `replay_aba` miss the problem
+            match self
+                .head
+                .compare_exchange(head, next, Ordering::Acquire, Ordering::Acquire)
+            {
+                Ok(_) => return Some(head),
+                Err(current) => head = current,
+            }
+        }
+    }
+
+    fn release(&self, slot: u32) {
+        let mut head = self.head.load(Ordering::Relaxed);
+        loop {
+            self.next[slot as usize].store(head, Ordering::Relaxed);
+            // Release, so whoever allocates the slot next sees its `next`
+            match self
+                .head
+                .compare_exchange_weak(head, slot, Ordering::Release, Ordering::Relaxed)
+            {
+                Ok(_) => return,
+                Err(current) => head = current,
+            }
+        }
+    }
+}
+
+/// Like `NaiveFreeList`, but the head is a slot number and a version, packed together by `pack`.
+/// Every change to the head gives it a new version, so a compare-and-swap can tell that the head has changed,
+/// even if it's back to the same slot.
+#[derive(Debug)]
+pub struct TaggedFreeList {
+    head: AtomicU64,
+    next: Box<[AtomicU32]>,
+}
+
+/// A slot number and a version, in one value which fits in an atomic
+pub fn pack(slot: u32, version: u32) -> u64 {
+    (version as u64) << 32 | slot as u64
+}
+
+/// The slot number and version put together by `pack`
+pub fn unpack(head: u64) -> (u32, u32) {
+    (head as u32, (head >> 32) as u32)
+}
+
+impl FreeList for TaggedFreeList {
+    fn with_capacity(capacity: u32) -> Self {
+        Self {
+            head: AtomicU64::new(pack(if capacity > 0 { 0 } else { EMPTY }, 0)),
+            next: linked(capacity),
+        }
+    }
+
+    fn allocate_pausing(&self, pause: impl FnOnce()) -> Option<u32> {
+        todo!()
+    }
+
+    fn release(&self, slot: u32) {
+        todo!()
+    }
+}
+
+/// Replays the interleaving which causes the ABA problem, on a list with three slots, `0 -> 1 -> 2`:
+///
+/// 1. One thread starts allocating. It reads that the head is 0 and its next is 1, and then it's paused.
+/// 2. Another thread allocates 0 and 1, and releases 0 again, so the list is `0 -> 2`.
+/// 3. The first thread swaps the head. It's 0, like it was before, so the naive list swaps in 1 as the new head,
+///    even though 1 is taken.
+///
+/// Returns every slot which is taken at the end: the one the first thread got, the one the other thread kept,
+/// and the ones which can still be allocated after that. In a correct list, every slot is in there once.
+pub fn replay_aba<L: FreeList>() -> Vec<u32> {
+    let list = L::with_capacity(3);
+    let (interrupted, kept) = std::thread::scope(|s| {
+        // Channels rather than barriers, since a thread which panics drops its end of a channel,
+        // which wakes up the other thread instead of leaving it waiting forever
+        let (paused, pause) = std::sync::mpsc::channel();
+        let (resume, resumed) = std::sync::mpsc::channel::<()>();
+        let list = &list;
+        let interrupted = s.spawn(move || {
+            list.allocate_pausing(move || {
+                paused.send(()).unwrap();
+                // Also carries on if the other thread panicked
+                let _ = resumed.recv();
+            })
+        });
+
+        if pause.recv().is_err() {
+            match interrupted.join() {
+                Err(panic) => std::panic::resume_unwind(panic),
+                Ok(_) => panic!("`allocate_pausing` returned without calling `pause`"),
+            }
+        }
+        let first = list.allocate().unwrap();
+        let kept = list.allocate().unwrap();
+        list.release(first);
+        drop(resume);
+
+        (interrupted.join().unwrap().unwrap(), kept)
+    });
+
+    let mut taken = vec![interrupted, kept];
+    // A broken list may hand out the same slots forever, so stop once it has handed out more slots than it has
+    while taken.len() <= 3 {
+        match list.allocate() {
+            Some(slot) => taken.push(slot),
+            None => break,
+        }
+    }
+    taken
+}
@@ -0,0 +1,117 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    thread,
+};
+
+use part_88::{replay_aba, FreeList, NaiveFreeList, TaggedFreeList};
+
+/// Lets `threads` threads allocate and release slots `rounds` times each, holding up to `held` slots at a time.
+/// Returns how many times a slot was handed out while someone else had it.
+fn hammer<L: FreeList>(threads: usize, rounds: usize, held: usize) -> usize {
+    let capacity = (threads * held) as u32;
+    let list = L::with_capacity(capacity);
+    let in_use: Vec<_> = (0..capacity).map(|_| AtomicBool::new(false)).collect();
+    let collisions: usize = thread::scope(|s| {
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                s.spawn(|| {
+                    let mut collisions = 0;
+                    let mut mine = Vec::new();
+                    for round in 0..rounds {
+                        if mine.len() < held && round % 3 != 2 {
+                            let slot = list.allocate().expect("There's a slot for every one held");
+                            if in_use[slot as usize].swap(true, Ordering::Relaxed) {
+                                collisions += 1;
+                            }
+                            mine.push(slot);
+                        } else if let Some(slot) = mine.pop() {
+                            in_use[slot as usize].store(false, Ordering::Relaxed);
+                            list.release(slot);
+                        }
+                    }
+                    for slot in mine {
+                        in_use[slot as usize].store(false, Ordering::Relaxed);
+                        list.release(slot);
+                    }
+                    collisions
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    });
+    collisions
+}
+
+fn main() {
+    let args = common::cli::Cli::new("Replays the ABA problem, and hammers two free lists")
+        .items(100_000)
+        .threads(4)
+        .parse();
+    let (rounds, threads) = (args.items(), args.threads());
+    println!(
+        "After the ABA interleaving, the naive list has handed out {:?}",
+        replay_aba::<NaiveFreeList>()
+    );
+    println!(
+        "After the ABA interleaving, the tagged list has handed out {:?}",
+        replay_aba::<TaggedFreeList>()
+    );
+    println!(
+        "Under stress, the naive list handed out a taken slot {} times, and the tagged list {} times",
+        hammer::<NaiveFreeList>(threads, rounds, 4),
+        hammer::<TaggedFreeList>(threads, rounds, 4)
+    );
+}
+
+#[test]
+fn the_naive_list_hands_out_a_taken_slot() {
+    let mut taken = replay_aba::<NaiveFreeList>();
+    taken.sort_unstable();
+    assert_eq!(taken, [0, 1, 1, 2]);
+}
+
+#[test]
+fn the_tagged_list_survives_the_aba_interleaving() {
+    let mut taken = replay_aba::<TaggedFreeList>();
+    taken.sort_unstable();
+    assert_eq!(taken, [0, 1, 2]);
+}
+
+#[test]
+fn hands_out_every_slot_once() {
+    let list = TaggedFreeList::with_capacity(5);
+    let taken: Vec<_> = std::iter::from_fn(|| list.allocate()).collect();
+    assert_eq!(taken, [0, 1, 2, 3, 4]);
+    assert_eq!(list.allocate(), None);
+}
+
+#[test]
+fn hands_out_the_last_released_slot_first() {
+    let list = TaggedFreeList::with_capacity(3);
+    let (a, b) = (list.allocate().unwrap(), list.allocate().unwrap());
+    list.release(a);
+    list.release(b);
+    assert_eq!(list.allocate(), Some(b));
+    assert_eq!(list.allocate(), Some(a));
+    assert_eq!(list.allocate(), Some(2));
+    assert_eq!(list.allocate(), None);
+}
+
+#[test]
+fn has_nothing_to_hand_out_without_slots() {
+    let list = TaggedFreeList::with_capacity(0);
+    assert_eq!(list.allocate(), None);
+}
+
+#[test]
+fn pauses_once_before_swapping_the_head() {
+    let list = TaggedFreeList::with_capacity(2);
+    let mut pauses = 0;
+    assert_eq!(list.allocate_pausing(|| pauses += 1), Some(0));
+    assert_eq!(pauses, 1);
+}
+
+#[test]
+fn never_hands_out_a_taken_slot() {
+    assert_eq!(hammer::<TaggedFreeList>(4, 100_000, 4), 0);
+}
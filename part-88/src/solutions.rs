@@ -0,0 +1,69 @@
+//! Part 88, with every exercise implemented. Only built with `--features solutions`.
+
+// Under `--cfg loom` the atomics are swapped for loom's, which lets loom
+// explore every way the threads in a test can interleave.
+#[cfg(loom)]
+use loom::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+#[cfg(not(loom))]
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+
+use super::{linked, pack, unpack, FreeList, EMPTY};
+
+/// Like `NaiveFreeList`, but the head is a slot number and a version, packed together by `pack`.
+/// Every change to the head gives it a new version, so a compare-and-swap can tell that the head has changed,
+/// even if it's back to the same slot.
+#[derive(Debug)]
+pub struct TaggedFreeList {
+    head: AtomicU64,
+    next: Box<[AtomicU32]>,
+}
+
+impl FreeList for TaggedFreeList {
+    fn with_capacity(capacity: u32) -> Self {
+        Self {
+            head: AtomicU64::new(pack(if capacity > 0 { 0 } else { EMPTY }, 0)),
+            next: linked(capacity),
+        }
+    }
+
+    fn allocate_pausing(&self, pause: impl FnOnce()) -> Option<u32> {
+        let mut pause = Some(pause);
+        let mut head = self.head.load(Ordering::Acquire);
+        loop {
+            let (slot, version) = unpack(head);
+            if slot == EMPTY {
+                return None;
+            }
+            let next = self.next[slot as usize].load(Ordering::Relaxed);
+            if let Some(pause) = pause.take() {
+                pause();
+            }
+            // Fails if anyone changed the head since it was read, even if it's the same slot again
+            let new = pack(next, version.wrapping_add(1));
+            match self
+                .head
+                .compare_exchange(head, new, Ordering::Acquire, Ordering::Acquire)
+            {
+                Ok(_) => return Some(slot),
+                Err(current) => head = current,
+            }
+        }
+    }
+
+    fn release(&self, slot: u32) {
+        let mut head = self.head.load(Ordering::Relaxed);
+        loop {
+            let (first, version) = unpack(head);
+            self.next[slot as usize].store(first, Ordering::Relaxed);
+            // Release, so whoever allocates the slot next sees its `next`
+            let new = pack(slot, version.wrapping_add(1));
+            match self
+                .head
+                .compare_exchange_weak(head, new, Ordering::Release, Ordering::Relaxed)
+            {
+                Ok(_) => return,
+                Err(current) => head = current,
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! Run these with `RUSTFLAGS="--cfg loom" cargo test --release -p part-88 --test loom`
+#![cfg(loom)]
+
+use loom::{sync::Arc, thread};
+use part_88::{FreeList, NaiveFreeList, TaggedFreeList};
+
+/// While one thread allocates a slot, another allocates two and releases the first one again.
+/// Checks that no slot is handed out twice, including the ones which are still free at the end.
+fn allocate_while_the_head_changes<L: FreeList + Send + 'static>() {
+    loom::model(|| {
+        let list = Arc::new(L::with_capacity(3));
+        let other = {
+            let list = list.clone();
+            thread::spawn(move || list.allocate().unwrap())
+        };
+
+        let first = list.allocate().unwrap();
+        let kept = list.allocate();
+        list.release(first);
+
+        let mut taken: Vec<_> = [Some(other.join().unwrap()), kept]
+            .into_iter()
+            .flatten()
+            .collect();
+        while taken.len() <= 3 {
+            match list.allocate() {
+                Some(slot) => taken.push(slot),
+                None => break,
+            }
+        }
+        taken.sort_unstable();
+        assert_eq!(taken, [0, 1, 2], "Every slot is handed out once");
+    });
+}
+
+#[test]
+#[should_panic(expected = "Every slot is handed out once")]
+fn the_naive_list_has_the_aba_problem() {
+    allocate_while_the_head_changes::<NaiveFreeList>();
+}
+
+#[test]
+fn the_tagged_list_does_not() {
+    allocate_while_the_head_changes::<TaggedFreeList>();
+}
+
+#[test]
+fn concurrent_releases_are_all_kept() {
+    loom::model(|| {
+        let list = Arc::new(TaggedFreeList::with_capacity(2));
+        let (a, b) = (list.allocate().unwrap(), list.allocate().unwrap());
+        let releaser = {
+            let list = list.clone();
+            thread::spawn(move || list.release(a))
+        };
+        list.release(b);
+        releaser.join().unwrap();
+
+        let mut taken = vec![list.allocate().unwrap(), list.allocate().unwrap()];
+        taken.sort_unstable();
+        assert_eq!(taken, [0, 1]);
+        assert_eq!(list.allocate(), None);
+    });
+}
@@ -0,0 +1,72 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-88 --features solutions`.
+#![cfg(all(feature = "solutions", not(loom)))]
+
+use std::thread;
+
+use part_88::{replay_aba, solutions, FreeList, TaggedFreeList};
+
+#[test]
+fn allocates_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (list, expected) = (
+        TaggedFreeList::with_capacity(8),
+        <solutions::TaggedFreeList as FreeList>::with_capacity(8),
+    );
+    let mut taken = Vec::new();
+    for op in common::datagen::numbers_below(200, 3, rng.seed()) {
+        if op == 0 && !taken.is_empty() {
+            let slot = taken.remove(0);
+            list.release(slot);
+            FreeList::release(&expected, slot);
+        } else {
+            let slot = list.allocate();
+            assert_eq!(slot, FreeList::allocate(&expected));
+            taken.extend(slot);
+        }
+    }
+}
+
+#[test]
+fn replays_like_the_solution() {
+    assert_eq!(
+        replay_aba::<TaggedFreeList>(),
+        replay_aba::<solutions::TaggedFreeList>()
+    );
+}
+
+#[test]
+fn hands_out_slots_like_the_solution() {
+    /// Which slots were free at the end, after threads took and gave back slots at the same time
+    fn free_at_the_end(
+        allocate: impl Fn() -> Option<u32> + Sync,
+        release: impl Fn(u32) + Sync,
+    ) -> Vec<u32> {
+        thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    for _ in 0..1000 {
+                        if let Some(slot) = allocate() {
+                            release(slot);
+                        }
+                    }
+                });
+            }
+        });
+        let mut free: Vec<u32> = std::iter::from_fn(&allocate).collect();
+        free.sort();
+        free
+    }
+
+    let (list, expected) = (
+        TaggedFreeList::with_capacity(16),
+        <solutions::TaggedFreeList as FreeList>::with_capacity(16),
+    );
+    assert_eq!(
+        free_at_the_end(|| list.allocate(), |slot| list.release(slot)),
+        free_at_the_end(
+            || FreeList::allocate(&expected),
+            |slot| FreeList::release(&expected, slot)
+        )
+    );
+}
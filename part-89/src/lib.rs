@@ -0,0 +1,125 @@
+use std::{mem::ManuallyDrop, ptr, sync::atomic::Ordering};
+
+use crossbeam_epoch::{self as epoch, Atomic, Owned};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+struct Node<T> {
+    /// Moved out by whoever pops the node, so it's dropped manually
+    value: ManuallyDrop<T>,
+    /// The node below this one. Never changed after the node has been pushed.
+    next: Atomic<Node<T>>,
+}
+
+/// The lock-free stack from part 22, but popped nodes are freed once no thread can be looking at them anymore.
+///
+/// Every operation pins the current thread to the current _epoch_ with `epoch::pin`, which returns a `Guard`.
+/// A popped node is handed to `Guard::defer_destroy`, and crossbeam-epoch frees it once every thread
+/// which was pinned when it was popped has been unpinned, so none of them can still be reading it.
+pub struct Stack<T> {
+    head: Atomic<Node<T>>,
+}
+
+impl<T> Stack<T> {
+    pub fn new() -> Self {
+        Self {
+            head: Atomic::null(),
+        }
+    }
+
+    /// Puts `value` on top of the stack
+    pub fn push(&self, value: T) {
+        todo!()
+    }
+
+    /// Takes the value on top of the stack, if there is one.
+    /// The popped node is freed once no other thread can be reading it.
+    pub fn pop(&self) -> Option<T> {
+        todo!()
+    }
+
+    /// Whether the stack is empty right now
+    pub fn is_empty(&self) -> bool {
+        let guard = epoch::pin();
+        self.head.load(Ordering::Acquire, &guard).is_null()
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for Stack<T> {
+    fn drop(&mut self) {
+        // SAFETY: We have the only reference to the stack, so no other thread can be using the nodes
+        unsafe {
+            let guard = epoch::unprotected();
+            let mut node = self.head.load(Ordering::Relaxed, guard);
+            while !node.is_null() {
+                let mut owned = node.into_owned();
+                ManuallyDrop::drop(&mut owned.value);
+                node = owned.next.load(Ordering::Relaxed, guard);
+            }
+        }
+    }
+}
+
+// SAFETY: Values are moved into the stack by one thread, and out of it by another
+unsafe impl<T: Send> Send for Stack<T> {}
+unsafe impl<T: Send> Sync for Stack<T> {}
+
+/// The operations the stress tests and comparisons run, on both this stack and the one from part 22
+pub trait ConcurrentStack<T>: Sync {
+    fn push(&self, value: T);
+    fn pop(&self) -> Option<T>;
+}
+
+impl<T: Send> ConcurrentStack<T> for Stack<T> {
+    fn push(&self, value: T) {
+        Stack::push(self, value)
+    }
+
+    fn pop(&self) -> Option<T> {
+        Stack::pop(self)
+    }
+}
+
+impl<T: Send> ConcurrentStack<T> for part_22::Stack<T> {
+    fn push(&self, value: T) {
+        part_22::Stack::push(self, value)
+    }
+
+    fn pop(&self) -> Option<T> {
+        part_22::Stack::pop(self)
+    }
+}
+
+/// Lets `threads` threads push `rounds` values each onto `stack`, popping one after every push,
+/// and then pops what's left. Returns the sum of every value popped, which should be the sum of every value pushed.
+pub fn churn(stack: &impl ConcurrentStack<u64>, threads: u64, rounds: u64) -> u64 {
+    std::thread::scope(|s| {
+        let handles: Vec<_> = (0..threads)
+            .map(|thread| {
+                s.spawn(move || {
+                    let _worker = common::span::worker(thread as usize);
+                    let mut popped = 0;
+                    for round in 0..rounds {
+                        stack.push(thread * rounds + round);
+                        popped += stack.pop().expect("Every thread pushes before it pops");
+                    }
+                    popped
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum::<u64>()
+    }) + std::iter::from_fn(|| stack.pop()).sum::<u64>()
+}
+
+/// The sum of every value `churn` pushes
+pub fn churned(threads: u64, rounds: u64) -> u64 {
+    let values = threads * rounds;
+    values * values.saturating_sub(1) / 2
+}
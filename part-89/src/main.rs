@@ -0,0 +1,125 @@
+use std::time::{Duration, Instant};
+
+use common::alloc::{count_allocations, in_use, CountingAllocator};
+use part_89::{churn, churned, ConcurrentStack, Stack};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Fewer rounds under Miri, which is thorough but slow
+const ROUNDS: u64 = if cfg!(miri) { 100 } else { 100_000 };
+const THREADS: u64 = 4;
+
+/// Runs `churn` on `stack` while no one else is counting, and returns how long it took,
+/// how many more bytes were in use right after, and how many once crossbeam-epoch has had a chance to catch up
+fn measure(stack: &impl ConcurrentStack<u64>) -> (Duration, u64, u64) {
+    let (measured, _) = count_allocations(|| {
+        let before = in_use();
+        let start = Instant::now();
+        assert_eq!(churn(stack, THREADS, ROUNDS), churned(THREADS, ROUNDS));
+        let elapsed = start.elapsed();
+        let right_after = in_use().saturating_sub(before);
+        // Every pin frees a few batches of garbage, if no one could still be using them, so this frees what's left
+        for _ in 0..1000 {
+            crossbeam_epoch::pin().flush();
+        }
+        (elapsed, right_after, in_use().saturating_sub(before))
+    });
+    measured
+}
+
+/// Runs `f` while no other test is counting allocations, since they'd count what `f` allocates too
+#[cfg(test)]
+fn without_counting(f: impl FnOnce()) {
+    count_allocations(f);
+}
+
+fn main() {
+    common::init_tracing();
+    let leaky = part_22::Stack::new();
+    let (elapsed, _, grown) = measure(&leaky);
+    println!(
+        "The stack from part 22 took {elapsed:?}, and holds on to {grown} more bytes in {} retired nodes",
+        leaky.retired()
+    );
+    let (elapsed, right_after, grown) = measure(&Stack::new());
+    println!(
+        "The epoch-based stack took {elapsed:?}, and held on to {right_after} more bytes right after, and {grown} a little later"
+    );
+}
+
+#[test]
+fn pops_in_reverse_order() {
+    without_counting(|| {
+        let stack = Stack::new();
+        assert!(stack.is_empty());
+        assert_eq!(stack.pop(), None);
+        for value in 0..10 {
+            stack.push(value);
+        }
+        assert!(!stack.is_empty());
+        assert_eq!(
+            std::iter::from_fn(|| stack.pop()).collect::<Vec<_>>(),
+            (0..10).rev().collect::<Vec<_>>()
+        );
+        assert!(stack.is_empty());
+    });
+}
+
+#[test]
+fn pops_every_value_once_between_threads() {
+    without_counting(|| {
+        let stack = Stack::new();
+        assert_eq!(churn(&stack, THREADS, ROUNDS), churned(THREADS, ROUNDS));
+        assert!(stack.is_empty());
+    });
+}
+
+#[test]
+fn drops_popped_values_once() {
+    without_counting(|| {
+        let value = std::sync::Arc::new(());
+        let stack = Stack::new();
+        for _ in 0..100 {
+            stack.push(value.clone());
+        }
+        while let Some(popped) = stack.pop() {
+            drop(popped);
+        }
+        assert_eq!(std::sync::Arc::strong_count(&value), 1);
+    });
+}
+
+#[test]
+fn drops_the_values_left_when_dropped() {
+    without_counting(|| {
+        let value = std::sync::Arc::new(());
+        let stack = Stack::new();
+        for _ in 0..100 {
+            stack.push(value.clone());
+        }
+        stack.pop();
+        drop(stack);
+        assert_eq!(std::sync::Arc::strong_count(&value), 1);
+    });
+}
+
+#[test]
+fn frees_popped_nodes() {
+    let (_, _, grown) = measure(&Stack::new());
+    // Every node is at least as big as its value, so keeping them all would take this much
+    let kept = THREADS * ROUNDS * std::mem::size_of::<u64>() as u64;
+    assert!(
+        grown < kept / 4,
+        "{grown} more bytes are in use, keeping every node takes {kept}"
+    );
+}
+
+#[test]
+fn the_stack_from_part_22_keeps_every_popped_node() {
+    let stack = part_22::Stack::new();
+    // Right after, since catching up may free garbage other tests left behind
+    let (_, grown, _) = measure(&stack);
+    assert_eq!(stack.retired() as u64, THREADS * ROUNDS);
+    assert!(grown >= THREADS * ROUNDS * std::mem::size_of::<u64>() as u64);
+}
@@ -0,0 +1,133 @@
+//! Part 89, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{mem::ManuallyDrop, ptr, sync::atomic::Ordering};
+
+use crossbeam_epoch::{self as epoch, Atomic, Owned};
+
+use super::{ConcurrentStack, Node};
+
+/// The lock-free stack from part 22, but popped nodes are freed once no thread can be looking at them anymore.
+///
+/// Every operation pins the current thread to the current _epoch_ with `epoch::pin`, which returns a `Guard`.
+/// A popped node is handed to `Guard::defer_destroy`, and crossbeam-epoch frees it once every thread
+/// which was pinned when it was popped has been unpinned, so none of them can still be reading it.
+pub struct Stack<T> {
+    head: Atomic<Node<T>>,
+}
+
+impl<T> Stack<T> {
+    pub fn new() -> Self {
+        Self {
+            head: Atomic::null(),
+        }
+    }
+
+    /// Puts `value` on top of the stack
+    pub fn push(&self, value: T) {
+        let mut node = Owned::new(Node {
+            value: ManuallyDrop::new(value),
+            next: Atomic::null(),
+        });
+        let guard = epoch::pin();
+        let mut head = self.head.load(Ordering::Relaxed, &guard);
+        loop {
+            node.next.store(head, Ordering::Relaxed);
+            // Release, so whoever pops the node sees its value
+            match self.head.compare_exchange_weak(
+                head,
+                node,
+                Ordering::Release,
+                Ordering::Relaxed,
+                &guard,
+            ) {
+                Ok(_) => return,
+                // Someone else changed the head, so try again on top of theirs, with the node we got back
+                Err(error) => {
+                    head = error.current;
+                    node = error.new;
+                }
+            }
+        }
+    }
+
+    /// Takes the value on top of the stack, if there is one.
+    /// The popped node is freed once no other thread can be reading it.
+    pub fn pop(&self) -> Option<T> {
+        let guard = epoch::pin();
+        let mut head = self.head.load(Ordering::Acquire, &guard);
+        loop {
+            // SAFETY: The node can't be freed while this thread is pinned, since it was loaded while pinned
+            let node = unsafe { head.as_ref() }?;
+            let next = node.next.load(Ordering::Relaxed, &guard);
+            match self.head.compare_exchange_weak(
+                head,
+                next,
+                Ordering::Acquire,
+                Ordering::Acquire,
+                &guard,
+            ) {
+                Ok(_) => {
+                    // SAFETY: This thread popped the node, so no one else takes its value,
+                    // and no one else hands it to `defer_destroy`
+                    unsafe {
+                        let value = ptr::read(&node.value);
+                        guard.defer_destroy(head);
+                        return Some(ManuallyDrop::into_inner(value));
+                    }
+                }
+                Err(error) => head = error.current,
+            }
+        }
+    }
+
+    /// Whether the stack is empty right now
+    pub fn is_empty(&self) -> bool {
+        let guard = epoch::pin();
+        self.head.load(Ordering::Acquire, &guard).is_null()
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for Stack<T> {
+    fn drop(&mut self) {
+        // SAFETY: We have the only reference to the stack, so no other thread can be using the nodes
+        unsafe {
+            let guard = epoch::unprotected();
+            let mut node = self.head.load(Ordering::Relaxed, guard);
+            while !node.is_null() {
+                let mut owned = node.into_owned();
+                ManuallyDrop::drop(&mut owned.value);
+                node = owned.next.load(Ordering::Relaxed, guard);
+            }
+        }
+    }
+}
+
+// SAFETY: Values are moved into the stack by one thread, and out of it by another
+unsafe impl<T: Send> Send for Stack<T> {}
+unsafe impl<T: Send> Sync for Stack<T> {}
+
+impl<T: Send> ConcurrentStack<T> for Stack<T> {
+    fn push(&self, value: T) {
+        Stack::push(self, value)
+    }
+
+    fn pop(&self) -> Option<T> {
+        Stack::pop(self)
+    }
+}
+
+impl<T: Send> ConcurrentStack<T> for part_22::solutions::Stack<T> {
+    fn push(&self, value: T) {
+        part_22::solutions::Stack::push(self, value)
+    }
+
+    fn pop(&self) -> Option<T> {
+        part_22::solutions::Stack::pop(self)
+    }
+}
@@ -0,0 +1,34 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-89 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_89::{churn, solutions, Stack};
+
+#[test]
+fn stacks_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (stack, expected) = (Stack::new(), solutions::Stack::new());
+    for (value, op) in common::datagen::numbers_below(500, 3, rng.seed())
+        .into_iter()
+        .enumerate()
+    {
+        if op == 0 {
+            assert_eq!(stack.pop(), expected.pop());
+        } else {
+            stack.push(value);
+            expected.push(value);
+        }
+        assert_eq!(stack.is_empty(), expected.is_empty());
+    }
+}
+
+#[test]
+fn churns_like_the_solution() {
+    for threads in [1, 4] {
+        assert_eq!(
+            churn(&Stack::new(), threads, 1000),
+            churn(&solutions::Stack::new(), threads, 1000),
+            "{threads} threads"
+        );
+    }
+}
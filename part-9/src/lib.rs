@@ -0,0 +1,43 @@
+use std::{
+    sync::{atomic::AtomicBool, Arc, Mutex},
+    thread,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Lets `threads` threads increment a shared, mutex-protected counter `increments` times each
+pub fn mutex_count(threads: usize, increments: usize) -> usize {
+    let counter = Arc::new(Mutex::new(0));
+    let handles: Vec<_> = (0..threads)
+        .map(|id| {
+            let counter = counter.clone();
+            thread::spawn(move || {
+                let _worker = common::span::worker(id);
+                for _ in 0..increments {
+                    *counter.lock().unwrap() += 1;
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().expect("Couldn't join thread");
+    }
+    let count = *counter.lock().unwrap();
+    count
+}
+
+/// Same as `mutex_count`, but using an `AtomicUsize` as the counter instead
+pub fn atomic_count(threads: usize, increments: usize) -> usize {
+    todo!()
+}
+
+/// Blocks the current thread until `flag` is set to `true`, by spinning
+pub fn wait_for_flag(flag: &AtomicBool) {
+    todo!()
+}
+
+/// Sets `flag` to `true`, releasing anyone waiting for it in `wait_for_flag`
+pub fn set_flag(flag: &AtomicBool) {
+    todo!()
+}
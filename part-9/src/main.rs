@@ -0,0 +1,62 @@
+use common::{cli::Cli, timed};
+use part_9::{atomic_count, mutex_count};
+
+fn main() {
+    common::init_tracing();
+    let args = Cli::new("Counts on several threads, with a mutex and with an atomic")
+        .items(1_000_000)
+        .threads(4)
+        .parse();
+    let (threads, increments) = (args.threads(), args.items());
+
+    let mutex_result = timed("Mutex counter", || mutex_count(threads, increments));
+    let atomic_result = timed("Atomic counter", || atomic_count(threads, increments));
+
+    assert_eq!(mutex_result, atomic_result);
+}
+
+#[test]
+fn counts_correctly() {
+    assert_eq!(atomic_count(1, 1000), 1000);
+    assert_eq!(atomic_count(8, 10_000), 80_000);
+    assert_eq!(atomic_count(8, 10_000), mutex_count(8, 10_000));
+}
+
+#[test]
+fn atomic_is_faster() {
+    use common::bench::{assert_faster, measure};
+
+    assert_eq!(mutex_count(4, 250_000), atomic_count(4, 250_000));
+    let mutex = measure("mutex", 5, || mutex_count(4, 250_000));
+    let atomic = measure("atomic", 5, || atomic_count(4, 250_000));
+
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    assert_faster(&atomic, &mutex);
+}
+
+#[test]
+fn waits_for_flag() {
+    use part_9::{set_flag, wait_for_flag};
+    use std::{
+        sync::{atomic::AtomicBool, Arc},
+        time::Duration,
+    };
+
+    let flag = Arc::new(AtomicBool::new(false));
+    let waiter = {
+        let flag = flag.clone();
+        std::thread::spawn(move || wait_for_flag(&flag))
+    };
+
+    std::thread::sleep(Duration::from_millis(50));
+    assert!(
+        !waiter.is_finished(),
+        "Stopped waiting before the flag was set"
+    );
+
+    set_flag(&flag);
+    let finished = common::with_timeout(Duration::from_secs(1), move || waiter.join());
+    assert!(finished.is_some(), "Still waiting after the flag was set");
+}
@@ -0,0 +1,44 @@
+//! Part 9, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+};
+
+/// Same as `mutex_count`, but using an `AtomicUsize` as the counter instead
+pub fn atomic_count(threads: usize, increments: usize) -> usize {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let handles: Vec<_> = (0..threads)
+        .map(|id| {
+            let counter = counter.clone();
+            thread::spawn(move || {
+                let _worker = common::span::worker(id);
+                for _ in 0..increments {
+                    // Only the count itself matters, so no ordering with other memory is needed
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().expect("Couldn't join thread");
+    }
+    // Joining the threads makes all their increments visible here
+    counter.load(Ordering::Relaxed)
+}
+
+/// Blocks the current thread until `flag` is set to `true`, by spinning
+pub fn wait_for_flag(flag: &AtomicBool) {
+    while !flag.load(Ordering::Acquire) {
+        // Tells the CPU we're busy-waiting, so it can save power or let a sibling core run
+        std::hint::spin_loop();
+    }
+}
+
+/// Sets `flag` to `true`, releasing anyone waiting for it in `wait_for_flag`
+pub fn set_flag(flag: &AtomicBool) {
+    flag.store(true, Ordering::Release);
+}
@@ -0,0 +1,41 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-9 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{
+    sync::{atomic::AtomicBool, Arc},
+    thread,
+    time::Duration,
+};
+
+use part_9::{atomic_count, set_flag, solutions, wait_for_flag};
+
+#[test]
+fn counts_like_the_solution() {
+    for (threads, increments) in [(1, 0), (4, 1000), (8, 10_000)] {
+        assert_eq!(
+            atomic_count(threads, increments),
+            solutions::atomic_count(threads, increments)
+        );
+    }
+}
+
+/// Sets a flag with one `set_flag` while another thread waits for it with `wait`
+fn handshake(set: fn(&AtomicBool), wait: fn(&AtomicBool)) {
+    let flag = Arc::new(AtomicBool::new(false));
+    let waiter = thread::spawn({
+        let flag = flag.clone();
+        move || common::with_timeout(Duration::from_secs(1), move || wait(&flag))
+    });
+    set(&flag);
+    assert!(
+        waiter.join().unwrap().is_some(),
+        "The flag was set, but the waiting thread never saw it"
+    );
+}
+
+#[test]
+fn flags_work_with_the_solution() {
+    handshake(set_flag, solutions::wait_for_flag);
+    handshake(solutions::set_flag, wait_for_flag);
+}
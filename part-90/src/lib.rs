@@ -0,0 +1,175 @@
+use std::{
+    marker::PhantomData,
+    sync::RwLock,
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+// Under `--cfg loom` the atomics are swapped for loom's, which lets loom
+// explore every way the threads in a test can interleave and every value a load may see.
+#[cfg(loom)]
+use loom::sync::atomic::{fence, AtomicU64, Ordering};
+#[cfg(not(loom))]
+use std::sync::atomic::{fence, AtomicU64, Ordering};
+
+/// Waits a moment before trying again. Loom needs to be told that the thread is waiting for another one.
+fn spin() {
+    #[cfg(loom)]
+    loom::thread::yield_now();
+    #[cfg(not(loom))]
+    std::hint::spin_loop();
+}
+
+/// A value which can be stored as `N` words
+pub trait Packed<const N: usize>: Copy {
+    fn pack(self) -> [u64; N];
+    fn unpack(words: [u64; N]) -> Self;
+}
+
+/// A sequence lock: a lock for small values which are read far more often than they're written.
+///
+/// Readers never block the writer, and never write anything themselves, so they don't fight over a cache line
+/// like the readers of a `RwLock` do. Instead, a reader checks that no one wrote while it was reading,
+/// and tries again if someone did. The `sequence` is odd while the value is being written, and goes up by two for every write.
+///
+/// The value is stored as atomic words, since a reader can be reading while the writer is writing.
+/// That's a data race if the words are ordinary values, even if the torn value is thrown away afterwards.
+#[derive(Debug)]
+pub struct SeqLock<T, const N: usize> {
+    sequence: AtomicU64,
+    words: [AtomicU64; N],
+    value: PhantomData<T>,
+}
+
+impl<T: Packed<N>, const N: usize> SeqLock<T, N> {
+    pub fn new(value: T) -> Self {
+        Self {
+            sequence: AtomicU64::new(0),
+            words: value.pack().map(AtomicU64::new),
+            value: PhantomData,
+        }
+    }
+
+    /// Replaces the value. Writers take turns, by making the sequence odd while they write.
+    pub fn write(&self, value: T) {
+        todo!()
+    }
+
+    /// Reads the value, unless it was written while it was read.
+    /// Returns `None` if a write was in progress, or happened while reading.
+    pub fn try_read(&self) -> Option<T> {
+        todo!()
+    }
+
+    /// Reads the value, trying again until no one writes while it's read
+    pub fn read(&self) -> T {
+        loop {
+            if let Some(value) = self.try_read() {
+                return value;
+            }
+            spin();
+        }
+    }
+}
+
+/// Where something is. Always written with `y == 2 * x` and `z == 3 * x`, so a torn read is easy to spot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Position {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Position {
+    pub fn at(x: i64) -> Self {
+        Self {
+            x,
+            y: 2 * x,
+            z: 3 * x,
+        }
+    }
+
+    /// Whether all the coordinates come from the same write
+    pub fn is_whole(&self) -> bool {
+        self.y == 2 * self.x && self.z == 3 * self.x
+    }
+}
+
+impl Packed<3> for Position {
+    fn pack(self) -> [u64; 3] {
+        [self.x, self.y, self.z].map(|coordinate| coordinate as u64)
+    }
+
+    fn unpack([x, y, z]: [u64; 3]) -> Self {
+        Self {
+            x: x as i64,
+            y: y as i64,
+            z: z as i64,
+        }
+    }
+}
+
+/// Something which holds a `Position` that's shared between threads
+pub trait Shared: Sync {
+    fn load(&self) -> Position;
+    fn store(&self, position: Position);
+}
+
+impl Shared for SeqLock<Position, 3> {
+    fn load(&self) -> Position {
+        self.read()
+    }
+
+    fn store(&self, position: Position) {
+        self.write(position)
+    }
+}
+
+impl Shared for RwLock<Position> {
+    fn load(&self) -> Position {
+        *self.read().unwrap()
+    }
+
+    fn store(&self, position: Position) {
+        *self.write().unwrap() = position;
+    }
+}
+
+/// What `read_mostly` measured
+#[derive(Debug, Clone, Copy)]
+pub struct Workload {
+    pub elapsed: Duration,
+    /// How many times a reader saw a position which wasn't whole
+    pub torn: u64,
+    /// The last position every reader saw, which is the last one written
+    pub last: Position,
+}
+
+/// Lets `readers` threads read `shared` `reads` times each, while one thread writes it `writes` times
+pub fn read_mostly(shared: &impl Shared, readers: usize, reads: u64, writes: i64) -> Workload {
+    let start = Instant::now();
+    let torn = std::thread::scope(|s| {
+        s.spawn(|| {
+            let _writer = common::span::thread("writer");
+            for x in 1..=writes {
+                shared.store(Position::at(x));
+            }
+        });
+        let handles: Vec<_> = (0..readers)
+            .map(|id| {
+                s.spawn(move || {
+                    let _reader = common::span::worker(id);
+                    (0..reads).filter(|_| !shared.load().is_whole()).count() as u64
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    });
+    Workload {
+        elapsed: start.elapsed(),
+        torn,
+        last: shared.load(),
+    }
+}
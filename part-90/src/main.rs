@@ -0,0 +1,84 @@
+use std::sync::RwLock;
+
+use part_90::{read_mostly, Position, SeqLock};
+
+const READERS: usize = 3;
+const READS: u64 = 1_000_000;
+const WRITES: i64 = 10_000;
+
+fn main() {
+    common::init_tracing();
+    let args = common::cli::Cli::new("Reads a position under a SeqLock and an RwLock")
+        .items(READS as usize)
+        .threads(READERS)
+        .parse();
+    let (readers, reads) = (args.threads(), args.items() as u64);
+    let seqlock = read_mostly(&SeqLock::new(Position::default()), readers, reads, WRITES);
+    let rwlock = read_mostly(&RwLock::new(Position::default()), readers, reads, WRITES);
+    for (name, workload) in [("SeqLock", seqlock), ("RwLock", rwlock)] {
+        println!(
+            "{name}: {} reads and {WRITES} writes took {:?}, with {} torn reads",
+            readers as u64 * reads,
+            workload.elapsed,
+            workload.torn
+        );
+    }
+}
+
+#[test]
+fn reads_what_was_written() {
+    let lock = SeqLock::new(Position::at(1));
+    assert_eq!(lock.read(), Position::at(1));
+    assert_eq!(lock.try_read(), Some(Position::at(1)));
+    lock.write(Position::at(-7));
+    assert_eq!(lock.read(), Position::at(-7));
+    assert_eq!(lock.try_read(), Some(Position::at(-7)));
+}
+
+#[test]
+fn packs_negative_coordinates() {
+    use part_90::Packed;
+
+    let position = Position {
+        x: -1,
+        y: i64::MIN,
+        z: i64::MAX,
+    };
+    assert_eq!(Position::unpack(position.pack()), position);
+}
+
+#[test]
+fn readers_never_see_a_torn_position() {
+    let lock = SeqLock::new(Position::default());
+    let workload = read_mostly(&lock, READERS, 100_000, WRITES);
+    assert_eq!(workload.torn, 0);
+    assert_eq!(workload.last, Position::at(WRITES));
+}
+
+#[test]
+fn writers_take_turns() {
+    let lock = SeqLock::new(Position::default());
+    let torn = std::thread::scope(|s| {
+        for writer in 0..3 {
+            let lock = &lock;
+            s.spawn(move || {
+                for x in 0..WRITES {
+                    lock.write(Position::at(x * 3 + writer));
+                }
+            });
+        }
+        let reader = s.spawn(|| (0..100_000).filter(|_| !lock.read().is_whole()).count());
+        reader.join().unwrap()
+    });
+    assert_eq!(torn, 0);
+    let last = lock.read();
+    assert!(last.is_whole());
+    assert!(last.x >= (WRITES - 1) * 3, "One of the last writes wins");
+}
+
+#[test]
+fn a_rwlock_never_tears_either() {
+    let workload = read_mostly(&RwLock::new(Position::default()), READERS, 10_000, 1000);
+    assert_eq!(workload.torn, 0);
+    assert_eq!(workload.last, Position::at(1000));
+}
@@ -0,0 +1,103 @@
+//! Part 90, with every exercise implemented. Only built with `--features solutions`.
+
+use std::marker::PhantomData;
+
+// Under `--cfg loom` the atomics are swapped for loom's, which lets loom
+// explore every way the threads in a test can interleave and every value a load may see.
+#[cfg(loom)]
+use loom::sync::atomic::{fence, AtomicU64, Ordering};
+#[cfg(not(loom))]
+use std::sync::atomic::{fence, AtomicU64, Ordering};
+
+use super::{spin, Packed, Position, Shared};
+
+/// A sequence lock: a lock for small values which are read far more often than they're written.
+///
+/// Readers never block the writer, and never write anything themselves, so they don't fight over a cache line
+/// like the readers of a `RwLock` do. Instead, a reader checks that no one wrote while it was reading,
+/// and tries again if someone did. The `sequence` is odd while the value is being written, and goes up by two for every write.
+///
+/// The value is stored as atomic words, since a reader can be reading while the writer is writing.
+/// That's a data race if the words are ordinary values, even if the torn value is thrown away afterwards.
+#[derive(Debug)]
+pub struct SeqLock<T, const N: usize> {
+    sequence: AtomicU64,
+    words: [AtomicU64; N],
+    value: PhantomData<T>,
+}
+
+impl<T: Packed<N>, const N: usize> SeqLock<T, N> {
+    pub fn new(value: T) -> Self {
+        Self {
+            sequence: AtomicU64::new(0),
+            words: value.pack().map(AtomicU64::new),
+            value: PhantomData,
+        }
+    }
+
+    /// Replaces the value. Writers take turns, by making the sequence odd while they write.
+    pub fn write(&self, value: T) {
+        let mut sequence = self.sequence.load(Ordering::Relaxed);
+        loop {
+            if sequence % 2 == 1 {
+                // Someone else is writing
+                spin();
+                sequence = self.sequence.load(Ordering::Relaxed);
+                continue;
+            }
+            // Acquire, so this write comes after the previous writer's
+            match self.sequence.compare_exchange_weak(
+                sequence,
+                sequence + 1,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(current) => sequence = current,
+            }
+        }
+        // Keeps the words from being written before the sequence is odd, as seen by a reader's `Acquire` fence
+        fence(Ordering::Release);
+        for (word, value) in self.words.iter().zip(value.pack()) {
+            word.store(value, Ordering::Relaxed);
+        }
+        // Release, so a reader which sees the new sequence also sees every word
+        self.sequence.store(sequence + 2, Ordering::Release);
+    }
+
+    /// Reads the value, unless it was written while it was read.
+    /// Returns `None` if a write was in progress, or happened while reading.
+    pub fn try_read(&self) -> Option<T> {
+        // Acquire, so the words are at least as new as this sequence
+        let before = self.sequence.load(Ordering::Acquire);
+        if before % 2 == 1 {
+            return None;
+        }
+        let words = std::array::from_fn(|i| self.words[i].load(Ordering::Relaxed));
+        // Keeps the words from being read after the sequence is checked again.
+        // If any of them came from a newer write, so does the sequence.
+        fence(Ordering::Acquire);
+        let after = self.sequence.load(Ordering::Relaxed);
+        (before == after).then(|| T::unpack(words))
+    }
+
+    /// Reads the value, trying again until no one writes while it's read
+    pub fn read(&self) -> T {
+        loop {
+            if let Some(value) = self.try_read() {
+                return value;
+            }
+            spin();
+        }
+    }
+}
+
+impl Shared for SeqLock<Position, 3> {
+    fn load(&self) -> Position {
+        self.read()
+    }
+
+    fn store(&self, position: Position) {
+        self.write(position)
+    }
+}
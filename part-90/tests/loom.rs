@@ -0,0 +1,60 @@
+//! Run these with `RUSTFLAGS="--cfg loom" cargo test --release -p part-90 --test loom`
+#![cfg(loom)]
+
+use loom::{sync::Arc, thread};
+use part_90::{Position, SeqLock};
+
+#[test]
+fn a_reader_never_sees_a_torn_position() {
+    loom::model(|| {
+        let lock = Arc::new(SeqLock::new(Position::at(0)));
+        let writer = {
+            let lock = lock.clone();
+            thread::spawn(move || {
+                lock.write(Position::at(1));
+                lock.write(Position::at(2));
+            })
+        };
+
+        if let Some(position) = lock.try_read() {
+            assert!(position.is_whole(), "Saw {position:?}");
+        }
+        writer.join().unwrap();
+        assert_eq!(lock.read(), Position::at(2));
+    });
+}
+
+#[test]
+fn a_reader_sees_the_latest_write_after_it() {
+    loom::model(|| {
+        let lock = Arc::new(SeqLock::new(Position::at(0)));
+        let writer = {
+            let lock = lock.clone();
+            thread::spawn(move || lock.write(Position::at(1)))
+        };
+        let first = lock.read();
+        let second = lock.read();
+        assert!(first.is_whole() && second.is_whole());
+        // Reads don't go back in time
+        assert!(second.x >= first.x);
+        writer.join().unwrap();
+    });
+}
+
+#[test]
+fn writers_take_turns() {
+    loom::model(|| {
+        let lock = Arc::new(SeqLock::new(Position::at(0)));
+        let writer = {
+            let lock = lock.clone();
+            thread::spawn(move || lock.write(Position::at(1)))
+        };
+        lock.write(Position::at(2));
+        writer.join().unwrap();
+        let last = lock.read();
+        assert!(
+            last == Position::at(1) || last == Position::at(2),
+            "Saw {last:?}"
+        );
+    });
+}
@@ -0,0 +1,76 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-90 --features solutions`.
+#![cfg(all(feature = "solutions", not(loom)))]
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::atomic::{AtomicBool, Ordering},
+    thread,
+};
+
+use part_90::{solutions, Position, SeqLock};
+
+/// The position's coordinates, to compare it with the solution's
+fn coordinates(position: Position) -> (i64, i64, i64) {
+    (position.x, position.y, position.z)
+}
+
+#[test]
+fn reads_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (lock, expected) = (
+        SeqLock::new(Position::at(0)),
+        solutions::SeqLock::new(Position::at(0)),
+    );
+    for x in common::datagen::numbers_below(100, 1000, rng.seed()) {
+        let x = x as i64 - 500;
+        lock.write(Position::at(x));
+        expected.write(Position::at(x));
+        let expected = expected
+            .try_read()
+            .map(|position| (position.x, position.y, position.z));
+        assert_eq!(lock.try_read().map(coordinates), expected);
+    }
+}
+
+#[test]
+fn never_tears_like_the_solution() {
+    let (lock, expected) = (
+        SeqLock::new(Position::at(0)),
+        solutions::SeqLock::new(Position::at(0)),
+    );
+    let done = AtomicBool::new(false);
+    let (whole, expected_whole) = thread::scope(|s| {
+        let reader = s.spawn(|| {
+            let mut whole = true;
+            while !done.load(Ordering::Relaxed) {
+                whole &= lock.read().is_whole();
+            }
+            whole
+        });
+        let expected_reader = s.spawn(|| {
+            let mut whole = true;
+            while !done.load(Ordering::Relaxed) {
+                whole &= expected.read().is_whole();
+            }
+            whole
+        });
+        let writes = panic::catch_unwind(AssertUnwindSafe(|| {
+            for x in 0..10_000 {
+                lock.write(Position::at(x));
+                expected.write(Position::at(x));
+            }
+        }));
+        // The readers only stop once they're told to, even if writing panicked
+        done.store(true, Ordering::Relaxed);
+        if let Err(panic) = writes {
+            panic::resume_unwind(panic);
+        }
+        (reader.join().unwrap(), expected_reader.join().unwrap())
+    });
+    assert_eq!(whole, expected_whole);
+    assert_eq!(coordinates(lock.read()), {
+        let position = expected.read();
+        (position.x, position.y, position.z)
+    });
+}
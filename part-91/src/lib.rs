@@ -0,0 +1,134 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Condvar, Mutex,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// A barrier for `threads` threads, which can be used for any number of rounds
+pub trait RoundBarrier: Sync + Send {
+    fn new(threads: usize) -> Self;
+
+    /// Waits until all the threads have called `wait` in this round, and then lets all of them through.
+    /// Returns `true` for exactly one of them in every round, the leader.
+    fn wait(&self) -> bool;
+}
+
+impl RoundBarrier for std::sync::Barrier {
+    fn new(threads: usize) -> Self {
+        std::sync::Barrier::new(threads)
+    }
+
+    fn wait(&self) -> bool {
+        std::sync::Barrier::wait(self).is_leader()
+    }
+}
+
+/// Counts the threads which have arrived, and lets them through once the count is back to 0.
+/// Works for one round, and handles spurious wakeups, but gets stuck when it's reused.
+#[derive(Debug)]
+pub struct CountingBarrier {
+    threads: usize,
+    arrived: Mutex<usize>,
+    everyone_arrived: Condvar,
+}
+
+impl RoundBarrier for CountingBarrier {
+    fn new(threads: usize) -> Self {
+        Self {
+            threads: threads.max(1),
+            arrived: Mutex::new(0),
+            everyone_arrived: Condvar::new(),
+        }
+    }
+
+    fn wait(&self) -> bool {
+        let mut arrived = self.arrived.lock().unwrap();
+        *arrived += 1;
+        if *arrived == self.threads {
+            // Everyone is here, so reset the count for the next round
+            *arrived = 0;
+            self.everyone_arrived.notify_all();
+            return true;
+        }
+        // In a loop, since waiting on a condition variable can return without anyone notifying it
+        while *arrived != 0 {
+            arrived = self.everyone_arrived.wait(arrived).unwrap();
+        }
+        false
+    }
+}
+
+/// Who has arrived in the current round, and which round it is
+#[derive(Debug, Default)]
+struct State {
+    arrived: usize,
+    generation: u64,
+}
+
+/// A barrier which tells the rounds apart by counting them. A thread waits until the generation it arrived in is over,
+/// which is still true after a thread from the next one has arrived.
+#[derive(Debug)]
+pub struct GenerationalBarrier {
+    threads: usize,
+    state: Mutex<State>,
+    everyone_arrived: Condvar,
+}
+
+impl RoundBarrier for GenerationalBarrier {
+    fn new(threads: usize) -> Self {
+        Self {
+            threads: threads.max(1),
+            state: Mutex::new(State::default()),
+            everyone_arrived: Condvar::new(),
+        }
+    }
+
+    fn wait(&self) -> bool {
+        todo!()
+    }
+}
+
+/// Lets `threads` threads wait on the same barrier `rounds` times each.
+/// Checks that every thread had arrived in every round before any of them got through, and that every round had one leader.
+pub fn run_rounds<B: RoundBarrier + 'static>(threads: usize, rounds: usize) -> Result<(), String> {
+    let barrier = Arc::new(B::new(threads));
+    let arrived: Arc<Vec<_>> = Arc::new((0..rounds).map(|_| AtomicUsize::new(0)).collect());
+    let leaders: Arc<Vec<_>> = Arc::new((0..rounds).map(|_| AtomicUsize::new(0)).collect());
+
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let (barrier, arrived, leaders) = (barrier.clone(), arrived.clone(), leaders.clone());
+            std::thread::spawn(move || {
+                for round in 0..rounds {
+                    arrived[round].fetch_add(1, Ordering::Relaxed);
+                    if barrier.wait() {
+                        leaders[round].fetch_add(1, Ordering::Relaxed);
+                    }
+                    let seen = arrived[round].load(Ordering::Relaxed);
+                    if seen != threads {
+                        return Err(format!(
+                            "Got through round {round} when {seen} of {threads} threads had arrived"
+                        ));
+                    }
+                }
+                Ok(())
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap()?;
+    }
+
+    match leaders
+        .iter()
+        .position(|leaders| leaders.load(Ordering::Relaxed) != 1)
+    {
+        Some(round) => Err(format!(
+            "Round {round} had {} leaders",
+            leaders[round].load(Ordering::Relaxed)
+        )),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,101 @@
+use std::time::{Duration, Instant};
+
+use part_91::{run_rounds, CountingBarrier, GenerationalBarrier, RoundBarrier};
+
+const THREADS: usize = 4;
+const ROUNDS: usize = 10_000;
+
+/// Runs `run_rounds` with `B`, giving up after a few seconds, since a broken barrier can leave threads waiting forever
+fn rounds_within_timeout<B: RoundBarrier + 'static>(
+    threads: usize,
+    rounds: usize,
+) -> Option<Result<(), String>> {
+    common::with_timeout(Duration::from_secs(10), move || {
+        run_rounds::<B>(threads, rounds)
+    })
+}
+
+fn main() {
+    let args = common::cli::Cli::new("Runs rounds of threads meeting at three barriers")
+        .items(ROUNDS)
+        .threads(THREADS)
+        .parse();
+    let (rounds, threads) = (args.items(), args.threads());
+    for (name, run) in [
+        (
+            "std::sync::Barrier",
+            rounds_within_timeout::<std::sync::Barrier> as fn(usize, usize) -> _,
+        ),
+        (
+            "GenerationalBarrier",
+            rounds_within_timeout::<GenerationalBarrier>,
+        ),
+        ("CountingBarrier", rounds_within_timeout::<CountingBarrier>),
+    ] {
+        let start = Instant::now();
+        match run(threads, rounds) {
+            Some(Ok(())) => println!("{name}: {rounds} rounds took {:?}", start.elapsed()),
+            Some(Err(error)) => println!("{name}: {error}"),
+            None => println!("{name}: got stuck"),
+        }
+    }
+}
+
+#[test]
+fn lets_a_single_thread_through_as_the_leader() {
+    let barrier = GenerationalBarrier::new(1);
+    assert!(barrier.wait());
+    assert!(barrier.wait());
+}
+
+#[test]
+fn treats_no_threads_like_one() {
+    let barrier = GenerationalBarrier::new(0);
+    assert!(barrier.wait());
+}
+
+#[test]
+fn waits_for_every_thread_in_one_round() {
+    assert_eq!(
+        rounds_within_timeout::<GenerationalBarrier>(THREADS, 1),
+        Some(Ok(()))
+    );
+}
+
+#[test]
+fn waits_for_every_thread_in_every_round() {
+    assert_eq!(
+        rounds_within_timeout::<GenerationalBarrier>(THREADS, ROUNDS),
+        Some(Ok(()))
+    );
+}
+
+#[test]
+fn works_with_more_threads_than_cores() {
+    assert_eq!(
+        rounds_within_timeout::<GenerationalBarrier>(32, 1000),
+        Some(Ok(()))
+    );
+}
+
+#[test]
+fn the_standard_barrier_passes_the_same_test() {
+    assert_eq!(
+        rounds_within_timeout::<std::sync::Barrier>(THREADS, ROUNDS),
+        Some(Ok(()))
+    );
+}
+
+#[test]
+fn the_counting_barrier_works_once_but_not_when_reused() {
+    assert_eq!(
+        rounds_within_timeout::<CountingBarrier>(THREADS, 1),
+        Some(Ok(()))
+    );
+    assert_ne!(
+        common::with_timeout(Duration::from_secs(2), || run_rounds::<CountingBarrier>(
+            THREADS, ROUNDS
+        )),
+        Some(Ok(()))
+    );
+}
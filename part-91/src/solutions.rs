@@ -0,0 +1,45 @@
+//! Part 91, with every exercise implemented. Only built with `--features solutions`.
+
+use std::sync::{Condvar, Mutex};
+
+use super::{RoundBarrier, State};
+
+/// A barrier which tells the rounds apart by counting them. A thread waits until the generation it arrived in is over,
+/// which is still true after a thread from the next one has arrived.
+#[derive(Debug)]
+pub struct GenerationalBarrier {
+    threads: usize,
+    state: Mutex<State>,
+    everyone_arrived: Condvar,
+}
+
+impl RoundBarrier for GenerationalBarrier {
+    fn new(threads: usize) -> Self {
+        Self {
+            threads: threads.max(1),
+            state: Mutex::new(State::default()),
+            everyone_arrived: Condvar::new(),
+        }
+    }
+
+    fn wait(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let generation = state.generation;
+        state.arrived += 1;
+        if state.arrived == self.threads {
+            // Everyone is here, so start the next round
+            state.arrived = 0;
+            state.generation += 1;
+            self.everyone_arrived.notify_all();
+            return true;
+        }
+        // Once the generation has moved on this round is over, even if threads have already arrived for the next one.
+        // `wait_while` checks again after every wakeup, including the spurious ones.
+        drop(
+            self.everyone_arrived
+                .wait_while(state, |state| state.generation == generation)
+                .unwrap(),
+        );
+        false
+    }
+}
@@ -0,0 +1,16 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-91 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_91::{run_rounds, solutions, GenerationalBarrier};
+
+#[test]
+fn waits_like_the_solution() {
+    for (threads, rounds) in [(1, 1), (1, 10), (4, 1), (4, 100), (16, 20)] {
+        assert_eq!(
+            run_rounds::<GenerationalBarrier>(threads, rounds),
+            run_rounds::<solutions::GenerationalBarrier>(threads, rounds),
+            "{threads} threads, {rounds} rounds"
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use std::{
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Barrier, Condvar, Mutex, OnceLock,
+    },
+    thread::{self, Thread},
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// Something one thread can wait for until another one sets it
+pub trait Event: Send + Sync {
+    /// An unset event, which `waiter` is the one to wait for
+    fn for_waiter(waiter: Thread) -> Self;
+    fn set(&self);
+    fn wait(&self);
+}
+
+/// Nobody is waiting, and the latch isn't set
+const EMPTY: u8 = 0;
+/// The waiter is parked, or about to be, and has to be unparked when the latch is set
+const WAITING: u8 = 1;
+/// The latch is set, for good
+const SET: u8 = 2;
+
+/// A one-shot event for a single thread, built on parking.
+///
+/// Once set, it stays set. Only the thread it was made for may wait on it, since that's the thread `set` unparks.
+#[derive(Debug)]
+pub struct Latch {
+    state: AtomicU8,
+    waiter: Thread,
+}
+
+impl Latch {
+    /// A latch for the current thread to wait on
+    pub fn new() -> Self {
+        Self::for_thread(thread::current())
+    }
+
+    /// A latch for `waiter` to wait on
+    pub fn for_thread(waiter: Thread) -> Self {
+        Self {
+            state: AtomicU8::new(EMPTY),
+            waiter,
+        }
+    }
+
+    /// Whether the latch has been set
+    pub fn is_set(&self) -> bool {
+        self.state.load(Ordering::Acquire) == SET
+    }
+
+    /// Sets the latch, and wakes up the waiter if it's waiting. Everything done before setting it
+    /// is visible to the waiter once it's done waiting.
+    pub fn set(&self) {
+        todo!()
+    }
+
+    /// Waits until the latch is set, or returns right away if it already is.
+    /// Panics if called by another thread than the one the latch was made for.
+    pub fn wait(&self) {
+        todo!()
+    }
+
+    /// Like `wait`, but gives up after `timeout`. Returns whether the latch was set.
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        todo!()
+    }
+
+    fn check_waiter(&self) {
+        assert_eq!(
+            thread::current().id(),
+            self.waiter.id(),
+            "Only the thread the latch was made for can wait on it"
+        );
+    }
+}
+
+impl Default for Latch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Event for Latch {
+    fn for_waiter(waiter: Thread) -> Self {
+        Latch::for_thread(waiter)
+    }
+
+    fn set(&self) {
+        Latch::set(self)
+    }
+
+    fn wait(&self) {
+        Latch::wait(self)
+    }
+}
+
+/// The same one-shot event with a `Mutex` and a `Condvar`, like the `Signal` in part 87.
+/// Any number of threads may wait for it.
+#[derive(Debug, Default)]
+pub struct CondvarLatch {
+    set: Mutex<bool>,
+    changed: Condvar,
+}
+
+impl Event for CondvarLatch {
+    fn for_waiter(_: Thread) -> Self {
+        Self::default()
+    }
+
+    fn set(&self) {
+        *self.set.lock().unwrap() = true;
+        self.changed.notify_all();
+    }
+
+    fn wait(&self) {
+        let set = self.set.lock().unwrap();
+        drop(self.changed.wait_while(set, |set| !*set).unwrap());
+    }
+}
+
+/// Passes control back and forth between this thread and another one `rounds` times, with a new event every time.
+/// Returns how long it took.
+pub fn ping_pong<E: Event>(rounds: usize) -> Duration {
+    let main = thread::current();
+    let pongs: Vec<E> = (0..rounds).map(|_| E::for_waiter(main.clone())).collect();
+    // The pings are for the other thread, which has to exist before they can be made
+    let pings = OnceLock::<Vec<E>>::new();
+    let ready = Barrier::new(2);
+    thread::scope(|s| {
+        let other = s.spawn(|| {
+            ready.wait();
+            for (ping, pong) in pings.get().unwrap().iter().zip(&pongs) {
+                ping.wait();
+                pong.set();
+            }
+        });
+        let waiter = other.thread().clone();
+        let _ = pings.set((0..rounds).map(|_| E::for_waiter(waiter.clone())).collect());
+        ready.wait();
+
+        let start = Instant::now();
+        for (ping, pong) in pings.get().unwrap().iter().zip(&pongs) {
+            ping.set();
+            pong.wait();
+        }
+        start.elapsed()
+    })
+}
@@ -0,0 +1,151 @@
+use part_92::{ping_pong, CondvarLatch, Latch};
+
+const ROUNDS: usize = 10_000;
+
+fn main() {
+    let rounds = common::cli::Cli::new("Passes a latch back and forth, parking and with a Condvar")
+        .items(ROUNDS)
+        .parse()
+        .items();
+    let parked = ping_pong::<Latch>(rounds);
+    let condvar = ping_pong::<CondvarLatch>(rounds);
+    println!("{rounds} round trips took {parked:?} with parking, and {condvar:?} with a condition variable");
+}
+
+#[test]
+fn returns_right_away_when_set_before_waiting() {
+    use std::time::Duration;
+
+    let latch = Latch::new();
+    assert!(!latch.is_set());
+    latch.set();
+    assert!(latch.is_set());
+    latch.wait();
+    assert!(latch.wait_timeout(Duration::ZERO));
+}
+
+#[test]
+fn wakes_up_when_set_while_waiting() {
+    use std::{sync::Arc, thread, time::Duration};
+
+    let latch = Arc::new(Latch::new());
+    let setter = {
+        let latch = latch.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            latch.set();
+        })
+    };
+    latch.wait();
+    assert!(latch.is_set());
+    setter.join().unwrap();
+}
+
+#[test]
+fn keeps_waiting_when_unparked_by_someone_else() {
+    use std::{
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+        thread,
+        time::Duration,
+    };
+
+    let latch = Arc::new(Latch::new());
+    let done = Arc::new(AtomicBool::new(false));
+    let main = thread::current();
+    let setter = {
+        let (latch, done) = (latch.clone(), done.clone());
+        thread::spawn(move || {
+            for _ in 0..20 {
+                main.unpark();
+                thread::sleep(Duration::from_millis(1));
+            }
+            done.store(true, Ordering::Relaxed);
+            latch.set();
+        })
+    };
+    latch.wait();
+    // The latch orders this after the store, even though it's `Relaxed`
+    assert!(
+        done.load(Ordering::Relaxed),
+        "Woke up before the latch was set"
+    );
+    setter.join().unwrap();
+}
+
+#[test]
+fn can_be_set_more_than_once() {
+    use std::{sync::Arc, thread};
+
+    let latch = Arc::new(Latch::new());
+    let setters: Vec<_> = (0..8)
+        .map(|_| {
+            let latch = latch.clone();
+            thread::spawn(move || latch.set())
+        })
+        .collect();
+    latch.wait();
+    for setter in setters {
+        setter.join().unwrap();
+    }
+    latch.set();
+    assert!(latch.is_set());
+}
+
+#[test]
+fn gives_up_waiting_after_the_timeout() {
+    use std::time::Duration;
+
+    let latch = Latch::new();
+    let start = std::time::Instant::now();
+    assert!(!latch.wait_timeout(Duration::from_millis(50)));
+    assert!(start.elapsed() >= Duration::from_millis(50));
+    assert!(!latch.is_set());
+}
+
+#[test]
+fn can_wait_again_after_a_timeout() {
+    use std::{sync::Arc, thread, time::Duration};
+
+    let latch = Arc::new(Latch::new());
+    assert!(!latch.wait_timeout(Duration::from_millis(10)));
+    let setter = {
+        let latch = latch.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            latch.set();
+        })
+    };
+    assert!(latch.wait_timeout(Duration::from_secs(5)));
+    latch.wait();
+    setter.join().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Only the thread the latch was made for can wait on it")]
+fn only_its_own_thread_may_wait() {
+    use std::{sync::Arc, thread};
+
+    let latch = Arc::new(Latch::new());
+    let other = {
+        let latch = latch.clone();
+        thread::spawn(move || latch.wait())
+    };
+    latch.set();
+    if let Err(panic) = other.join() {
+        std::panic::resume_unwind(panic);
+    }
+}
+
+#[test]
+fn passes_control_back_and_forth_without_losing_a_wakeup() {
+    use std::time::Duration;
+
+    let finished = common::with_timeout(Duration::from_secs(10), || {
+        ping_pong::<Latch>(ROUNDS);
+        ping_pong::<CondvarLatch>(ROUNDS);
+    });
+    assert!(finished.is_some(), "Some thread is still waiting");
+}
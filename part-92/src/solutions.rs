@@ -0,0 +1,107 @@
+//! Part 92, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    sync::atomic::{AtomicU8, Ordering},
+    thread::{self, Thread},
+    time::{Duration, Instant},
+};
+
+use super::{Event, EMPTY, SET, WAITING};
+
+/// A one-shot event for a single thread, built on parking.
+///
+/// Once set, it stays set. Only the thread it was made for may wait on it, since that's the thread `set` unparks.
+#[derive(Debug)]
+pub struct Latch {
+    state: AtomicU8,
+    waiter: Thread,
+}
+
+impl Latch {
+    /// A latch for the current thread to wait on
+    pub fn new() -> Self {
+        Self::for_thread(thread::current())
+    }
+
+    /// A latch for `waiter` to wait on
+    pub fn for_thread(waiter: Thread) -> Self {
+        Self {
+            state: AtomicU8::new(EMPTY),
+            waiter,
+        }
+    }
+
+    /// Whether the latch has been set
+    pub fn is_set(&self) -> bool {
+        self.state.load(Ordering::Acquire) == SET
+    }
+
+    /// Sets the latch, and wakes up the waiter if it's waiting. Everything done before setting it
+    /// is visible to the waiter once it's done waiting.
+    pub fn set(&self) {
+        // Release, so the waiter sees everything done before this. Only unparks if there's someone to wake up.
+        if self.state.swap(SET, Ordering::Release) == WAITING {
+            self.waiter.unpark();
+        }
+    }
+
+    /// Waits until the latch is set, or returns right away if it already is.
+    /// Panics if called by another thread than the one the latch was made for.
+    pub fn wait(&self) {
+        self.check_waiter();
+        // Fails if the latch is set already, or if an earlier `wait_timeout` gave up while waiting
+        let _ = self
+            .state
+            .compare_exchange(EMPTY, WAITING, Ordering::Relaxed, Ordering::Relaxed);
+        // `park` can return without anyone unparking this thread, so check every time.
+        // If `set` unparks it before it parks, `park` returns right away, so the wakeup isn't lost.
+        while !self.is_set() {
+            thread::park();
+        }
+    }
+
+    /// Like `wait`, but gives up after `timeout`. Returns whether the latch was set.
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        self.check_waiter();
+        let deadline = Instant::now() + timeout;
+        let _ = self
+            .state
+            .compare_exchange(EMPTY, WAITING, Ordering::Relaxed, Ordering::Relaxed);
+        while !self.is_set() {
+            let left = deadline.saturating_duration_since(Instant::now());
+            if left.is_zero() {
+                return false;
+            }
+            thread::park_timeout(left);
+        }
+        true
+    }
+
+    fn check_waiter(&self) {
+        assert_eq!(
+            thread::current().id(),
+            self.waiter.id(),
+            "Only the thread the latch was made for can wait on it"
+        );
+    }
+}
+
+impl Default for Latch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Event for Latch {
+    fn for_waiter(waiter: Thread) -> Self {
+        Latch::for_thread(waiter)
+    }
+
+    fn set(&self) {
+        Latch::set(self)
+    }
+
+    fn wait(&self) {
+        Latch::wait(self)
+    }
+}
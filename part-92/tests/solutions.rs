@@ -0,0 +1,49 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-92 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{panic, thread, time::Duration};
+
+use part_92::{solutions, Latch};
+
+#[test]
+fn sets_like_the_solution() {
+    let (latch, expected) = (Latch::new(), solutions::Latch::new());
+    assert_eq!(latch.is_set(), expected.is_set());
+    assert_eq!(
+        latch.wait_timeout(Duration::from_millis(1)),
+        expected.wait_timeout(Duration::from_millis(1))
+    );
+    thread::scope(|s| {
+        s.spawn(|| {
+            latch.set();
+            expected.set();
+        });
+        latch.wait();
+        expected.wait();
+    });
+    assert_eq!(latch.is_set(), expected.is_set());
+    // Once set, it stays set
+    assert_eq!(
+        latch.wait_timeout(Duration::from_millis(1)),
+        expected.wait_timeout(Duration::from_millis(1))
+    );
+    latch.set();
+    expected.set();
+    latch.wait();
+    expected.wait();
+}
+
+#[test]
+fn only_lets_its_waiter_wait_like_the_solution() {
+    let (latch, expected) = (Latch::new(), solutions::Latch::new());
+    latch.set();
+    expected.set();
+    thread::scope(|s| {
+        let waited =
+            s.spawn(|| panic::catch_unwind(panic::AssertUnwindSafe(|| latch.wait())).is_ok());
+        let expected_waited =
+            s.spawn(|| panic::catch_unwind(panic::AssertUnwindSafe(|| expected.wait())).is_ok());
+        assert_eq!(waited.join().unwrap(), expected_waited.join().unwrap());
+    });
+}
@@ -0,0 +1,148 @@
+use std::time::Duration;
+
+use part_93::{latency, predict, throughput, Channel, Crossbeam, Flume, Scenario, Std};
+
+const MESSAGES: u64 = 1_000_000;
+const ROUND_TRIPS: u32 = 10_000;
+
+/// The name of the fastest of `results`
+fn winner(results: &[(&'static str, Duration)]) -> &'static str {
+    results
+        .iter()
+        .min_by_key(|(_, elapsed)| *elapsed)
+        .map(|(name, _)| *name)
+        .unwrap()
+}
+
+fn main() {
+    common::init_tracing();
+    let messages = common::cli::Cli::new("Compares the throughput and latency of three channels")
+        .items(MESSAGES as usize)
+        .parse()
+        .items() as u64;
+    println!(
+        "{:<22}{:>12}{:>12}{:>12}   winner     predicted",
+        "producers:consumers",
+        Std::NAME,
+        Crossbeam::NAME,
+        Flume::NAME
+    );
+    let mut correct = 0;
+    for scenario in Scenario::ALL {
+        let results = [
+            (Std::NAME, throughput::<Std>(scenario, messages)),
+            (Crossbeam::NAME, throughput::<Crossbeam>(scenario, messages)),
+            (Flume::NAME, throughput::<Flume>(scenario, messages)),
+        ];
+        let (winner, predicted) = (winner(&results), predict(scenario));
+        correct += usize::from(winner == predicted);
+        println!(
+            "{:<22}{:>12.1?}{:>12.1?}{:>12.1?}   {winner:<10} {predicted}",
+            scenario.to_string(),
+            results[0].1,
+            results[1].1,
+            results[2].1,
+        );
+    }
+    println!("You predicted {correct} of {} winners", Scenario::ALL.len());
+
+    println!(
+        "\nRound trip latency: {} {:?}, {} {:?}, {} {:?}",
+        Std::NAME,
+        latency::<Std>(ROUND_TRIPS),
+        Crossbeam::NAME,
+        latency::<Crossbeam>(ROUND_TRIPS),
+        Flume::NAME,
+        latency::<Flume>(ROUND_TRIPS),
+    );
+}
+
+/// Checks that a channel of kind `C` delivers in order, and reports disconnects both ways
+#[cfg(test)]
+fn check_delivery<C: Channel>() {
+    use part_93::{Receiver, Sender};
+
+    for (sender, receiver) in [C::unbounded(), C::bounded(10)] {
+        for value in 0..10 {
+            assert_eq!(sender.send(value), Ok(()));
+        }
+        drop(sender);
+        assert_eq!(
+            std::iter::from_fn(|| receiver.recv()).collect::<Vec<_>>(),
+            (0..10).collect::<Vec<_>>(),
+            "{} delivers in order, and then reports the disconnect",
+            C::NAME
+        );
+    }
+
+    let (sender, receiver) = C::unbounded();
+    let clone = receiver.clone();
+    drop((receiver, clone));
+    assert_eq!(
+        sender.send(1),
+        Err(1),
+        "{} gives back what can't be sent",
+        C::NAME
+    );
+}
+
+/// Checks that every number `throughput` sends arrives once, in every scenario
+#[cfg(test)]
+fn check_every_scenario<C: Channel>() {
+    for scenario in Scenario::ALL {
+        throughput::<C>(scenario, 10_000);
+    }
+    assert!(latency::<C>(100) > Duration::ZERO);
+}
+
+#[test]
+fn std_channels_deliver() {
+    check_delivery::<Std>();
+    check_every_scenario::<Std>();
+}
+
+#[test]
+fn crossbeam_channels_deliver() {
+    check_delivery::<Crossbeam>();
+    check_every_scenario::<Crossbeam>();
+}
+
+#[test]
+fn flume_channels_deliver() {
+    check_delivery::<Flume>();
+    check_every_scenario::<Flume>();
+}
+
+#[test]
+fn bounded_channels_make_senders_wait() {
+    fn check<C: Channel>()
+    where
+        C::Sender<i32>: 'static,
+    {
+        use part_93::Sender;
+
+        let (sender, receiver) = C::bounded(2);
+        let finished = common::with_timeout(Duration::from_millis(100), move || {
+            for value in 0..3 {
+                sender.send(value).unwrap();
+            }
+        });
+        assert_eq!(
+            finished,
+            None,
+            "{} has room for more than it should",
+            C::NAME
+        );
+        drop(receiver);
+    }
+    check::<Std>();
+    check::<Crossbeam>();
+    check::<Flume>();
+}
+
+#[test]
+fn predicts_a_channel_for_every_scenario() {
+    for scenario in Scenario::ALL {
+        assert!([Std::NAME, Crossbeam::NAME, Flume::NAME].contains(&predict(scenario)));
+    }
+}
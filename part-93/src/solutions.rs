@@ -0,0 +1,254 @@
+//! Part 93, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use super::Scenario;
+
+/// The sending half of a channel
+pub trait Sender<T>: Clone + Send {
+    /// Sends `value`, waiting for room if the channel is bounded and full.
+    /// Gives the value back if every receiver is gone.
+    fn send(&self, value: T) -> Result<(), T>;
+}
+
+/// The receiving half of a channel, which can be cloned to receive from several threads
+pub trait Receiver<T>: Clone + Send {
+    /// Waits for a value, or returns `None` once every sender is gone and the channel is empty
+    fn recv(&self) -> Option<T>;
+}
+
+/// A channel implementation
+pub trait Channel {
+    const NAME: &'static str;
+    type Sender<T: Send>: Sender<T>;
+    type Receiver<T: Send>: Receiver<T>;
+
+    /// A channel with room for `capacity` values
+    fn bounded<T: Send>(capacity: usize) -> (Self::Sender<T>, Self::Receiver<T>);
+
+    /// A channel with room for any number of values
+    fn unbounded<T: Send>() -> (Self::Sender<T>, Self::Receiver<T>);
+}
+
+/// `std::sync::mpsc`. Its receiver can't be cloned, so receivers share it behind a mutex.
+#[derive(Debug, Clone, Copy)]
+pub struct Std;
+
+/// Either kind of sender from `std::sync::mpsc`
+#[derive(Debug)]
+pub enum StdSender<T> {
+    Bounded(mpsc::SyncSender<T>),
+    Unbounded(mpsc::Sender<T>),
+}
+
+// Not derived, since that would only make it `Clone` if `T` is
+impl<T> Clone for StdSender<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Bounded(sender) => Self::Bounded(sender.clone()),
+            Self::Unbounded(sender) => Self::Unbounded(sender.clone()),
+        }
+    }
+}
+
+impl<T: Send> Sender<T> for StdSender<T> {
+    fn send(&self, value: T) -> Result<(), T> {
+        match self {
+            Self::Bounded(sender) => sender.send(value),
+            Self::Unbounded(sender) => sender.send(value),
+        }
+        .map_err(|error| error.0)
+    }
+}
+
+/// A receiver from `std::sync::mpsc`, shared by everyone who receives from it
+#[derive(Debug)]
+pub struct StdReceiver<T>(Arc<Mutex<mpsc::Receiver<T>>>);
+
+impl<T> Clone for StdReceiver<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Send> Receiver<T> for StdReceiver<T> {
+    fn recv(&self) -> Option<T> {
+        // Waits while holding the lock, so the other receivers wait for the lock instead
+        self.0.lock().unwrap().recv().ok()
+    }
+}
+
+impl Channel for Std {
+    const NAME: &'static str = "std";
+    type Sender<T: Send> = StdSender<T>;
+    type Receiver<T: Send> = StdReceiver<T>;
+
+    fn bounded<T: Send>(capacity: usize) -> (StdSender<T>, StdReceiver<T>) {
+        let (sender, receiver) = mpsc::sync_channel(capacity);
+        (
+            StdSender::Bounded(sender),
+            StdReceiver(Arc::new(Mutex::new(receiver))),
+        )
+    }
+
+    fn unbounded<T: Send>() -> (StdSender<T>, StdReceiver<T>) {
+        let (sender, receiver) = mpsc::channel();
+        (
+            StdSender::Unbounded(sender),
+            StdReceiver(Arc::new(Mutex::new(receiver))),
+        )
+    }
+}
+
+/// [crossbeam-channel](https://docs.rs/crossbeam-channel)
+#[derive(Debug, Clone, Copy)]
+pub struct Crossbeam;
+
+impl<T: Send> Sender<T> for crossbeam_channel::Sender<T> {
+    fn send(&self, value: T) -> Result<(), T> {
+        crossbeam_channel::Sender::send(self, value).map_err(|error| error.into_inner())
+    }
+}
+
+impl<T: Send> Receiver<T> for crossbeam_channel::Receiver<T> {
+    fn recv(&self) -> Option<T> {
+        crossbeam_channel::Receiver::recv(self).ok()
+    }
+}
+
+impl Channel for Crossbeam {
+    const NAME: &'static str = "crossbeam";
+    type Sender<T: Send> = crossbeam_channel::Sender<T>;
+    type Receiver<T: Send> = crossbeam_channel::Receiver<T>;
+
+    fn bounded<T: Send>(capacity: usize) -> (Self::Sender<T>, Self::Receiver<T>) {
+        crossbeam_channel::bounded(capacity)
+    }
+
+    fn unbounded<T: Send>() -> (Self::Sender<T>, Self::Receiver<T>) {
+        crossbeam_channel::unbounded()
+    }
+}
+
+/// [flume](https://docs.rs/flume)
+#[derive(Debug, Clone, Copy)]
+pub struct Flume;
+
+impl<T: Send> Sender<T> for flume::Sender<T> {
+    fn send(&self, value: T) -> Result<(), T> {
+        flume::Sender::send(self, value).map_err(|error| error.into_inner())
+    }
+}
+
+impl<T: Send> Receiver<T> for flume::Receiver<T> {
+    fn recv(&self) -> Option<T> {
+        flume::Receiver::recv(self).ok()
+    }
+}
+
+impl Channel for Flume {
+    const NAME: &'static str = "flume";
+    type Sender<T: Send> = flume::Sender<T>;
+    type Receiver<T: Send> = flume::Receiver<T>;
+
+    fn bounded<T: Send>(capacity: usize) -> (Self::Sender<T>, Self::Receiver<T>) {
+        flume::bounded(capacity)
+    }
+
+    fn unbounded<T: Send>() -> (Self::Sender<T>, Self::Receiver<T>) {
+        flume::unbounded()
+    }
+}
+
+/// Which channel you think is the fastest in `scenario`: `Std::NAME`, `Crossbeam::NAME` or `Flume::NAME`.
+/// There's no wrong answer, `cargo run --release -p part-93` shows how close you were.
+pub fn predict(scenario: Scenario) -> &'static str {
+    match scenario {
+        // std's receiver is behind a lock here, so several consumers take turns waiting for each value
+        Scenario { consumers: 2.., .. } => Crossbeam::NAME,
+        // Several producers filling up a bounded channel means a lot of waking each other up,
+        // which crossbeam spends a lot of effort on
+        Scenario {
+            producers: 2..,
+            capacity: Some(_),
+            ..
+        } => Crossbeam::NAME,
+        // With few threads, flume's simpler design has less to do for every message
+        Scenario { .. } => Flume::NAME,
+    }
+}
+
+/// Sends `messages` numbers, split between the producers of `scenario`, to its consumers, which add them up.
+/// Returns how long it took, and checks that every message arrived once.
+pub fn throughput<C: Channel>(scenario: Scenario, messages: u64) -> Duration {
+    let (sender, receiver) = match scenario.capacity {
+        Some(capacity) => C::bounded(capacity),
+        None => C::unbounded(),
+    };
+    let producers = scenario.producers as u64;
+    let start = Instant::now();
+    let total: u64 = thread::scope(|s| {
+        for producer in 0..producers {
+            let sender = sender.clone();
+            s.spawn(move || {
+                let _producer = common::span::thread("producer");
+                let _worker = common::span::worker(producer as usize);
+                for message in (producer..messages).step_by(producers as usize) {
+                    if sender.send(message).is_err() {
+                        panic!("The consumers stopped early");
+                    }
+                }
+            });
+        }
+        // Otherwise the consumers would wait for this one forever
+        drop(sender);
+
+        let consumers: Vec<_> = (0..scenario.consumers)
+            .map(|consumer| {
+                let receiver = receiver.clone();
+                s.spawn(move || {
+                    let _consumer = common::span::thread("consumer");
+                    let _worker = common::span::worker(consumer);
+                    std::iter::from_fn(|| receiver.recv()).sum::<u64>()
+                })
+            })
+            .collect();
+        consumers.into_iter().map(|c| c.join().unwrap()).sum()
+    });
+    let elapsed = start.elapsed();
+    assert_eq!(
+        total,
+        messages * messages.saturating_sub(1) / 2,
+        "Lost or duplicated messages"
+    );
+    elapsed
+}
+
+/// Sends a message back and forth between two threads `round_trips` times, over two channels with room for one.
+/// Returns the average time for a round trip.
+pub fn latency<C: Channel>(round_trips: u32) -> Duration {
+    let (ping, pinged) = C::bounded(1);
+    let (pong, ponged) = C::bounded(1);
+    thread::scope(|s| {
+        // Moved in here, so it's dropped at the end, which tells the other thread to stop
+        let ping = ping;
+        s.spawn(move || {
+            let _echo = common::span::thread("echo");
+            while let Some(message) = pinged.recv() {
+                if pong.send(message).is_err() {
+                    break;
+                }
+            }
+        });
+        let start = Instant::now();
+        for message in 0..round_trips {
+            assert!(ping.send(message).is_ok());
+            assert_eq!(ponged.recv(), Some(message));
+        }
+        start.elapsed() / round_trips.max(1)
+    })
+}
@@ -0,0 +1,103 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-93 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_93::{
+    predict, solutions, throughput, Channel, Crossbeam, Flume, Receiver, Scenario, Sender, Std,
+};
+
+/// What happens to `values` sent through a channel of `C`, before and after the receiver is gone
+fn sent<C: Channel>(
+    capacity: Option<usize>,
+    values: &[u64],
+) -> (Vec<Option<u64>>, Result<(), u64>) {
+    let (sender, receiver) = match capacity {
+        Some(capacity) => C::bounded(capacity),
+        None => C::unbounded(),
+    };
+    let received = values
+        .iter()
+        .map(|&value| {
+            sender.send(value).unwrap();
+            receiver.recv()
+        })
+        .collect();
+    drop(receiver);
+    (received, sender.send(93))
+}
+
+/// Like `sent`, with the solution's channels
+fn expected<C: solutions::Channel>(
+    capacity: Option<usize>,
+    values: &[u64],
+) -> (Vec<Option<u64>>, Result<(), u64>) {
+    use solutions::{Receiver, Sender};
+
+    let (sender, receiver) = match capacity {
+        Some(capacity) => C::bounded(capacity),
+        None => C::unbounded(),
+    };
+    let received = values
+        .iter()
+        .map(|&value| {
+            sender.send(value).unwrap();
+            receiver.recv()
+        })
+        .collect();
+    drop(receiver);
+    (received, sender.send(93))
+}
+
+#[test]
+fn sends_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let values = common::datagen::numbers(20, rng.seed());
+    for capacity in [Some(1), Some(16), None] {
+        assert_eq!(
+            sent::<Crossbeam>(capacity, &values),
+            expected::<solutions::Crossbeam>(capacity, &values)
+        );
+        assert_eq!(
+            sent::<Flume>(capacity, &values),
+            expected::<solutions::Flume>(capacity, &values)
+        );
+        assert_eq!(
+            sent::<Std>(capacity, &values),
+            expected::<solutions::Std>(capacity, &values)
+        );
+    }
+}
+
+#[test]
+fn disconnects_like_the_solution() {
+    let (sender, receiver) = <Crossbeam as Channel>::unbounded::<u64>();
+    let (expected_sender, expected_receiver) =
+        <solutions::Crossbeam as solutions::Channel>::unbounded::<u64>();
+    drop((sender, expected_sender));
+    assert_eq!(
+        Receiver::recv(&receiver),
+        solutions::Receiver::recv(&expected_receiver)
+    );
+    let (sender, receiver) = <Flume as Channel>::bounded::<u64>(1);
+    let (expected_sender, expected_receiver) =
+        <solutions::Flume as solutions::Channel>::bounded::<u64>(1);
+    drop((sender, expected_sender));
+    assert_eq!(
+        Receiver::recv(&receiver),
+        solutions::Receiver::recv(&expected_receiver)
+    );
+}
+
+#[test]
+fn runs_every_scenario_like_the_solution() {
+    // There's no wrong prediction, as long as it's one of the channels
+    for scenario in Scenario::ALL {
+        assert!(
+            [Std::NAME, Crossbeam::NAME, Flume::NAME].contains(&predict(scenario)),
+            "{scenario}"
+        );
+        // Checks that every message arrived once
+        throughput::<Crossbeam>(scenario, 10_000);
+        throughput::<Flume>(scenario, 10_000);
+    }
+}
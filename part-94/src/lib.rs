@@ -0,0 +1,41 @@
+use std::{sync::mpsc, thread, time::Duration};
+
+use tokio::runtime::Runtime;
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How many threads the runtime from `runtime` runs its tasks on
+pub const WORKERS: usize = 2;
+
+/// A multi-threaded runtime with `WORKERS` worker threads, for the tasks
+pub fn runtime() -> Runtime {
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(WORKERS)
+        .enable_all()
+        .build()
+        .unwrap()
+}
+
+/// Passes a number back and forth between this thread and another one, over two `std::sync::mpsc` channels,
+/// `round_trips` times. Both sides add one to the number before passing it on, starting from 0.
+/// Returns the number after the last round trip.
+pub fn thread_ping_pong(round_trips: u32) -> u32 {
+    todo!()
+}
+
+/// Like `thread_ping_pong`, but between this task and another tokio task, over two `tokio::sync::mpsc` channels
+pub async fn task_ping_pong(round_trips: u32) -> u32 {
+    todo!()
+}
+
+/// Starts `count` threads which each sleep for `duration`, and waits for all of them.
+/// Returns how many of them woke up.
+pub fn thread_sleepers(count: usize, duration: Duration) -> usize {
+    todo!()
+}
+
+/// Like `thread_sleepers`, but with `count` tokio tasks sleeping with `tokio::time::sleep`
+pub async fn task_sleepers(count: usize, duration: Duration) -> usize {
+    todo!()
+}
@@ -0,0 +1,142 @@
+use std::time::{Duration, Instant};
+
+use common::{bench::Comparison, budget, cli::Cli};
+use part_94::{runtime, task_ping_pong, task_sleepers, thread_ping_pong, thread_sleepers};
+
+const ROUND_TRIPS: u32 = 10_000;
+/// Far fewer than the tasks, since many systems won't let a process have 100 000 threads
+const SLEEPING_THREADS: usize = 10_000;
+const SLEEPING_TASKS: usize = 100_000;
+const SLEEP: Duration = Duration::from_millis(500);
+
+/// Run with `cargo run --release -p part-94` to compare threads and tasks
+fn main() {
+    let args = Cli::new("Compares switching and sleeping on OS threads and tokio tasks")
+        .items(ROUND_TRIPS as usize)
+        .strategy()
+        .parse();
+    let round_trips = args.items() as u32;
+    let runtime = runtime();
+    let one_thread = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    println!("Passing a number back and forth {round_trips} times");
+    let mut comparison = Comparison::new(10);
+    comparison
+        .bench("OS threads", || thread_ping_pong(round_trips))
+        .bench("tokio tasks", || {
+            runtime.block_on(task_ping_pong(round_trips))
+        })
+        .bench("tokio tasks on one thread", || {
+            one_thread.block_on(task_ping_pong(round_trips))
+        });
+    comparison.print();
+    for measurement in comparison.measurements() {
+        println!(
+            "{}: {:?} per switch",
+            measurement.name,
+            measurement.median() / (2 * round_trips)
+        );
+    }
+    drop(runtime);
+
+    println!();
+    report("OS threads", SLEEPING_THREADS, || {
+        thread_sleepers(SLEEPING_THREADS, SLEEP)
+    });
+    report("tokio tasks", SLEEPING_TASKS, || {
+        part_94::runtime().block_on(task_sleepers(SLEEPING_TASKS, SLEEP))
+    });
+}
+
+/// Measures `sleepers` running `count` sleepers, and prints how much every one of them needed
+fn report(name: &str, count: usize, sleepers: impl FnOnce() -> usize) {
+    let start = Instant::now();
+    let (woke, usage) = budget::measure(sleepers);
+    let elapsed = start.elapsed();
+    assert_eq!(woke, count);
+    print!("{count} sleeping {name} took {elapsed:?}");
+    match usage {
+        Some(usage) => println!(
+            ", {} extra threads and {} MiB of memory, {} bytes per sleeper",
+            usage.threads,
+            usage.memory / (1024 * 1024),
+            usage.memory / count
+        ),
+        None => println!(),
+    }
+}
+
+#[test]
+fn thread_ping_pong_adds_up() {
+    assert_eq!(thread_ping_pong(0), 0);
+    assert_eq!(thread_ping_pong(1000), 2000);
+}
+
+#[test]
+fn task_ping_pong_adds_up() {
+    let runtime = runtime();
+    assert_eq!(runtime.block_on(task_ping_pong(0)), 0);
+    assert_eq!(runtime.block_on(task_ping_pong(1000)), 2000);
+}
+
+#[test]
+fn task_ping_pong_doesnt_block_the_thread() {
+    // Only one thread to run both tasks on, so a task blocking it while waiting would wait forever
+    let number = common::with_timeout(Duration::from_secs(5), || {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        runtime.block_on(task_ping_pong(1000))
+    });
+    assert_eq!(number, Some(2000), "Ping-pong got stuck on one thread");
+}
+
+#[test]
+fn sleeping_threads_sleep_at_the_same_time() {
+    let start = Instant::now();
+    assert_eq!(thread_sleepers(100, Duration::from_millis(50)), 100);
+    assert!(
+        start.elapsed() < Duration::from_secs(2),
+        "Took {:?}, the threads didn't sleep at the same time",
+        start.elapsed()
+    );
+}
+
+#[test]
+fn sleeping_threads_need_a_thread_each() {
+    let (woke, usage) = budget::measure(|| thread_sleepers(1000, Duration::from_millis(500)));
+    assert_eq!(woke, 1000);
+    if let Some(usage) = usage {
+        assert!(
+            usage.threads >= 1000,
+            "Only {} extra threads",
+            usage.threads
+        );
+    }
+}
+
+#[test]
+fn sleeping_tasks_sleep_at_the_same_time() {
+    let start = Instant::now();
+    let woke = runtime().block_on(task_sleepers(1000, Duration::from_millis(50)));
+    assert_eq!(woke, 1000);
+    assert!(
+        start.elapsed() < Duration::from_secs(2),
+        "Took {:?}, the tasks didn't sleep at the same time",
+        start.elapsed()
+    );
+}
+
+#[test]
+fn hundred_thousand_sleeping_tasks_fit_the_budget() {
+    // The runtime's workers, with some room for threads started by other tests running at the same time
+    let budget = budget::Budget {
+        threads: part_94::WORKERS + 8,
+        memory: 256 * 1024 * 1024,
+    };
+    let woke =
+        budget.check(|| runtime().block_on(task_sleepers(100_000, Duration::from_millis(500))));
+    assert_eq!(woke, 100_000);
+}
@@ -0,0 +1,78 @@
+//! Part 94, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{sync::mpsc, thread, time::Duration};
+
+/// Passes a number back and forth between this thread and another one, over two `std::sync::mpsc` channels,
+/// `round_trips` times. Both sides add one to the number before passing it on, starting from 0.
+/// Returns the number after the last round trip.
+pub fn thread_ping_pong(round_trips: u32) -> u32 {
+    let (ping, pinged) = mpsc::channel();
+    let (pong, ponged) = mpsc::channel();
+    thread::scope(|s| {
+        s.spawn(move || {
+            // Stops once `ping` is dropped
+            for number in pinged {
+                if pong.send(number + 1).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut number = 0;
+        for _ in 0..round_trips {
+            ping.send(number + 1).unwrap();
+            number = ponged.recv().unwrap();
+        }
+        // Otherwise the other thread would wait for another number forever, and the scope for it
+        drop(ping);
+        number
+    })
+}
+
+/// Like `thread_ping_pong`, but between this task and another tokio task, over two `tokio::sync::mpsc` channels
+pub async fn task_ping_pong(round_trips: u32) -> u32 {
+    let (ping, mut pinged) = tokio::sync::mpsc::channel(1);
+    let (pong, mut ponged) = tokio::sync::mpsc::channel(1);
+    let ponger = tokio::spawn(async move {
+        while let Some(number) = pinged.recv().await {
+            if pong.send(number + 1).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut number = 0;
+    for _ in 0..round_trips {
+        ping.send(number + 1).await.unwrap();
+        number = ponged.recv().await.unwrap();
+    }
+    drop(ping);
+    ponger.await.unwrap();
+    number
+}
+
+/// Starts `count` threads which each sleep for `duration`, and waits for all of them.
+/// Returns how many of them woke up.
+pub fn thread_sleepers(count: usize, duration: Duration) -> usize {
+    let sleepers: Vec<_> = (0..count)
+        .map(|_| thread::spawn(move || thread::sleep(duration)))
+        .collect();
+    sleepers
+        .into_iter()
+        .map(|sleeper| sleeper.join().unwrap())
+        .count()
+}
+
+/// Like `thread_sleepers`, but with `count` tokio tasks sleeping with `tokio::time::sleep`
+pub async fn task_sleepers(count: usize, duration: Duration) -> usize {
+    let mut sleepers = tokio::task::JoinSet::new();
+    for _ in 0..count {
+        sleepers.spawn(tokio::time::sleep(duration));
+    }
+    let mut woke = 0;
+    while let Some(sleeper) = sleepers.join_next().await {
+        sleeper.unwrap();
+        woke += 1;
+    }
+    woke
+}
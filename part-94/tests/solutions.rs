@@ -0,0 +1,44 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-94 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::time::Duration;
+
+use part_94::{
+    runtime, solutions, task_ping_pong, task_sleepers, thread_ping_pong, thread_sleepers,
+};
+
+#[test]
+fn ping_pongs_like_the_solution() {
+    let runtime = runtime();
+    for round_trips in [0, 1, 100] {
+        assert_eq!(
+            thread_ping_pong(round_trips),
+            solutions::thread_ping_pong(round_trips),
+            "{round_trips} round trips"
+        );
+        assert_eq!(
+            runtime.block_on(task_ping_pong(round_trips)),
+            runtime.block_on(solutions::task_ping_pong(round_trips)),
+            "{round_trips} round trips"
+        );
+    }
+}
+
+#[test]
+fn sleeps_like_the_solution() {
+    let runtime = runtime();
+    for count in [0, 1, 50] {
+        let duration = Duration::from_millis(5);
+        assert_eq!(
+            thread_sleepers(count, duration),
+            solutions::thread_sleepers(count, duration),
+            "{count} threads"
+        );
+        assert_eq!(
+            runtime.block_on(task_sleepers(count, duration)),
+            runtime.block_on(solutions::task_sleepers(count, duration)),
+            "{count} tasks"
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use futures::future::{join_all, BoxFuture};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How long the simulated work for a single node takes
+pub const WORK: Duration = Duration::from_millis(10);
+
+/// A tree of numbers, where every node takes a while to visit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tree {
+    pub value: u64,
+    pub children: Vec<Tree>,
+}
+
+impl Tree {
+    /// A tree with `depth` levels, where every node but the leaves has `branching` children.
+    /// The nodes are numbered from 1, level by level.
+    pub fn new(depth: u32, branching: u64) -> Self {
+        Self::numbered(1, depth, branching)
+    }
+
+    fn numbered(value: u64, depth: u32, branching: u64) -> Self {
+        let children = if depth > 1 {
+            (0..branching)
+                .map(|child| {
+                    Self::numbered(branching * (value - 1) + 2 + child, depth - 1, branching)
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        Self { value, children }
+    }
+
+    pub fn leaf(value: u64) -> Self {
+        Self {
+            value,
+            children: Vec::new(),
+        }
+    }
+
+    /// How many nodes the tree has
+    pub fn nodes(&self) -> u32 {
+        1 + self.children.iter().map(Tree::nodes).sum::<u32>()
+    }
+
+    /// How many levels the tree has, counting the root, so a single node has depth 1
+    pub fn depth(&self) -> u32 {
+        1 + self.children.iter().map(Tree::depth).max().unwrap_or(0)
+    }
+
+    /// The sum of every value in the tree, without any of the work
+    pub fn sum(&self) -> u64 {
+        self.value + self.children.iter().map(Tree::sum).sum::<u64>()
+    }
+
+    /// The work of visiting this node, not counting its children
+    pub async fn visit(&self) -> u64 {
+        tokio::time::sleep(WORK).await;
+        self.value
+    }
+}
+
+/// Visits every node in `tree`, one at a time, and adds up what they returned
+pub fn walk(tree: &Tree) -> BoxFuture<'_, u64> {
+    todo!()
+}
+
+/// Like `walk`, but visits the children of every node at the same time
+pub fn walk_parallel(tree: &Tree) -> BoxFuture<'_, u64> {
+    todo!()
+}
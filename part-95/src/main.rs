@@ -0,0 +1,100 @@
+use std::time::Instant;
+
+use part_95::{walk, walk_parallel, Tree};
+
+/// Run with `cargo run --release -p part-95` to walk a tree both ways
+#[tokio::main]
+async fn main() {
+    let tree = Tree::new(6, 3);
+    println!(
+        "A tree with {} nodes on {} levels, adding up to {}",
+        tree.nodes(),
+        tree.depth(),
+        tree.sum()
+    );
+
+    let start = Instant::now();
+    let sum = walk(&tree).await;
+    println!("walk: {sum} in {:?}", start.elapsed());
+
+    let start = Instant::now();
+    let sum = walk_parallel(&tree).await;
+    println!("walk_parallel: {sum} in {:?}", start.elapsed());
+}
+
+/// A tree with branches of different depths
+#[cfg(test)]
+fn lopsided() -> Tree {
+    Tree {
+        value: 1,
+        children: vec![
+            Tree::leaf(2),
+            Tree {
+                value: 3,
+                children: vec![Tree {
+                    value: 4,
+                    children: vec![Tree::leaf(5), Tree::leaf(6)],
+                }],
+            },
+            Tree::new(2, 4),
+        ],
+    }
+}
+
+#[test]
+fn trees_are_numbered_level_by_level() {
+    let tree = Tree::new(4, 3);
+    assert_eq!(tree.nodes(), 1 + 3 + 9 + 27);
+    assert_eq!(tree.depth(), 4);
+    let nodes = u64::from(tree.nodes());
+    assert_eq!(tree.sum(), nodes * (nodes + 1) / 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn walk_adds_up_every_node() {
+    for tree in [Tree::leaf(7), Tree::new(4, 2), Tree::new(3, 5), lopsided()] {
+        assert_eq!(walk(&tree).await, tree.sum());
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn walk_parallel_adds_up_every_node() {
+    for tree in [Tree::leaf(7), Tree::new(4, 2), Tree::new(3, 5), lopsided()] {
+        assert_eq!(walk_parallel(&tree).await, tree.sum());
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn walk_visits_one_node_at_a_time() {
+    for tree in [Tree::new(5, 2), lopsided()] {
+        let start = tokio::time::Instant::now();
+        walk(&tree).await;
+        assert_eq!(start.elapsed(), part_95::WORK * tree.nodes());
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn walk_parallel_takes_as_long_as_the_deepest_branch() {
+    for tree in [Tree::new(5, 2), Tree::new(3, 10), lopsided()] {
+        let start = tokio::time::Instant::now();
+        walk_parallel(&tree).await;
+        assert_eq!(
+            start.elapsed(),
+            part_95::WORK * tree.depth(),
+            "Took as long as {} nodes, for a tree with {} levels",
+            start.elapsed().as_millis() / part_95::WORK.as_millis(),
+            tree.depth()
+        );
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn walks_can_be_spawned() {
+    // Spawned tasks may move between threads, so the boxed futures must be `Send`
+    let tree = std::sync::Arc::new(Tree::new(3, 3));
+    let sums = tokio::spawn({
+        let tree = tree.clone();
+        async move { (walk(&tree).await, walk_parallel(&tree).await) }
+    });
+    assert_eq!(sums.await.unwrap(), (tree.sum(), tree.sum()));
+}
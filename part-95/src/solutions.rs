@@ -0,0 +1,28 @@
+//! Part 95, with every exercise implemented. Only built with `--features solutions`.
+
+use futures::future::{join_all, BoxFuture};
+
+use super::Tree;
+
+/// Visits every node in `tree`, one at a time, and adds up what they returned
+pub fn walk(tree: &Tree) -> BoxFuture<'_, u64> {
+    // An async fn can't await itself directly: its future would have to contain a future of its own type.
+    // Boxing puts the inner future on the heap, so the outer one only holds a pointer to it.
+    Box::pin(async move {
+        let mut total = tree.visit().await;
+        for child in &tree.children {
+            total += walk(child).await;
+        }
+        total
+    })
+}
+
+/// Like `walk`, but visits the children of every node at the same time
+pub fn walk_parallel(tree: &Tree) -> BoxFuture<'_, u64> {
+    Box::pin(async move {
+        let value = tree.visit().await;
+        // The children run concurrently, so this takes as long as the slowest one, not all of them together
+        let children = join_all(tree.children.iter().map(walk_parallel)).await;
+        value + children.iter().sum::<u64>()
+    })
+}
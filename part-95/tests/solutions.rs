@@ -0,0 +1,33 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-95 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_95::{solutions, walk, walk_parallel, Tree};
+use tokio::time::Instant;
+
+#[tokio::test(start_paused = true)]
+async fn walks_like_the_solution() {
+    for (depth, branching) in [(1, 1), (3, 2), (4, 3)] {
+        let (tree, expected) = (Tree::new(depth, branching), Tree::new(depth, branching));
+
+        let start = Instant::now();
+        let sum = walk(&tree).await;
+        let took = start.elapsed();
+        let start = Instant::now();
+        assert_eq!(
+            (sum, took),
+            (solutions::walk(&expected).await, start.elapsed()),
+            "{depth} levels of {branching}"
+        );
+
+        let start = Instant::now();
+        let sum = walk_parallel(&tree).await;
+        let took = start.elapsed();
+        let start = Instant::now();
+        assert_eq!(
+            (sum, took),
+            (solutions::walk_parallel(&expected).await, start.elapsed()),
+            "{depth} levels of {branching}, in parallel"
+        );
+    }
+}
@@ -0,0 +1,16 @@
+// The future of an async fn holds the futures of everything it's awaiting, so a future awaiting
+// another call to the same function would have to contain itself, and be infinitely big.
+use part_95::Tree;
+
+async fn walk(tree: &Tree) -> u64 {
+    let mut total = tree.visit().await;
+    for child in &tree.children {
+        total += walk(child).await;
+    }
+    total
+}
+
+fn main() {
+    let tree = Tree::new(3, 2);
+    let _ = walk(&tree);
+}
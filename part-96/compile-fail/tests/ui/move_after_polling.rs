@@ -0,0 +1,12 @@
+// Once a future which isn't `Unpin` has been pinned, it can't be moved out of its `Pin` again,
+// since it might point into itself by then.
+use std::pin::pin;
+
+use part_25::block_on;
+use part_96::WordCounter;
+
+fn main() {
+    let mut counter = pin!(WordCounter::new("one two three"));
+    block_on(counter.as_mut());
+    let _moved: WordCounter = *counter;
+}
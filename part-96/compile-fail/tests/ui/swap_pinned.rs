@@ -0,0 +1,10 @@
+// Swapping moves both values, so a `Pin` of something which isn't `Unpin` doesn't hand out the `&mut` needed for it.
+use std::pin::pin;
+
+use part_96::WordCounter;
+
+fn main() {
+    let a = pin!(WordCounter::new("a"));
+    let b = pin!(WordCounter::new("b"));
+    std::mem::swap(&mut *a, &mut *b);
+}
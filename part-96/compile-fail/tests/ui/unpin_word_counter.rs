@@ -0,0 +1,10 @@
+// `Pin::new` is only for types which are `Unpin`, and don't care about being moved. `CountPolls` is only
+// `Unpin` if the future it wraps is, so pinning one needs `pin!` or `Box::pin`, like pinning a `WordCounter` does.
+use std::pin::Pin;
+
+use part_96::{CountPolls, WordCounter};
+
+fn main() {
+    let mut counter = CountPolls::new(WordCounter::new("a"));
+    let _ = Pin::new(&mut counter);
+}
@@ -0,0 +1,93 @@
+use std::{
+    future::Future,
+    marker::PhantomPinned,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How much text a `WordCounter` has room for
+pub const CAPACITY: usize = 256;
+
+/// Counts the words in a text, one word per poll, like an async fn which awaits something after every word.
+///
+/// The text is kept inside the counter itself, and what's left to count is a pointer into it,
+/// just like a reference to one of an async fn's own local variables held across an `.await`.
+/// So once it's been polled, the counter must never move again, which it promises by not being `Unpin`.
+pub struct WordCounter {
+    buffer: [u8; CAPACITY],
+    len: usize,
+    /// The part of `buffer` which hasn't been counted yet, or `None` until the first poll
+    rest: Option<*const str>,
+    words: usize,
+    _pinned: PhantomPinned,
+}
+
+impl WordCounter {
+    /// Panics if `text` is longer than `CAPACITY` bytes
+    pub fn new(text: &str) -> Self {
+        assert!(text.len() <= CAPACITY, "Text longer than {CAPACITY} bytes");
+        let mut buffer = [0; CAPACITY];
+        buffer[..text.len()].copy_from_slice(text.as_bytes());
+        Self {
+            buffer,
+            len: text.len(),
+            rest: None,
+            words: 0,
+            _pinned: PhantomPinned,
+        }
+    }
+
+    /// The text to count the words in
+    pub fn text(&self) -> &str {
+        // SAFETY: the first `len` bytes were copied from a `&str` in `new`, and are never changed
+        unsafe { std::str::from_utf8_unchecked(&self.buffer[..self.len]) }
+    }
+
+    /// Panics if `rest` has been set, but doesn't point into this counter's own buffer.
+    /// Only compares addresses, so it's safe to call even if the counter has been moved.
+    pub fn check_not_moved(&self) {
+        let Some(rest) = self.rest else {
+            return;
+        };
+        let buffer = self.buffer.as_ptr_range();
+        let rest = rest as *const u8;
+        assert!(
+            buffer.start <= rest && rest <= buffer.end,
+            "WordCounter was moved after it was first polled, `rest` points to where it used to be"
+        );
+    }
+}
+
+impl Future for WordCounter {
+    type Output = usize;
+
+    /// Counts one more word and returns `Poll::Pending`, after waking itself so it's polled again,
+    /// or returns how many words there were once there are no more
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<usize> {
+        todo!()
+    }
+}
+
+/// Runs `future`, and returns its output along with how many times it was polled.
+/// Unlike the `PollCounter` from part 25's tests, it works for futures which aren't `Unpin` too.
+pub struct CountPolls<F> {
+    future: F,
+    polls: u32,
+}
+
+impl<F> CountPolls<F> {
+    pub fn new(future: F) -> Self {
+        Self { future, polls: 0 }
+    }
+}
+
+impl<F: Future> Future for CountPolls<F> {
+    type Output = (F::Output, u32);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        todo!()
+    }
+}
@@ -0,0 +1,104 @@
+use std::pin::pin;
+
+use part_25::block_on;
+use part_96::{CountPolls, WordCounter};
+
+const TEXT: &str =
+    "A future which points into itself must stay where it is once it has been polled";
+
+/// Run with `cargo run -p part-96` to count some words on the executor from part 25
+fn main() {
+    // Moving it before the first poll is fine, since it doesn't point anywhere yet
+    let counter = WordCounter::new(TEXT);
+    let moved = counter;
+    let (words, polls) = block_on(CountPolls::new(moved));
+    println!("{words} words in {polls} polls");
+
+    // `pin!` pins it on the stack, so it can be polled by reference and can't be moved afterwards
+    let mut counter = pin!(WordCounter::new("Pinned to the stack"));
+    let words = block_on(counter.as_mut());
+    println!("{words} words in {:?}", counter.text());
+
+    // `Box::pin` pins it on the heap, where moving the box doesn't move the counter
+    let counter = Box::pin(WordCounter::new("Pinned to the heap"));
+    let boxed = counter;
+    println!("{} words", block_on(boxed));
+}
+
+#[test]
+fn counts_words() {
+    for (text, words) in [
+        ("", 0),
+        ("   ", 0),
+        ("one", 1),
+        ("  two words  ", 2),
+        ("tabs\tand\nnewlines  count too", 5),
+        (TEXT, 16),
+    ] {
+        assert_eq!(block_on(WordCounter::new(text)), words, "In {text:?}");
+    }
+}
+
+#[test]
+fn counts_words_in_the_longest_text() {
+    let text = "ab ".repeat(part_96::CAPACITY / 3);
+    assert_eq!(block_on(WordCounter::new(&text)), part_96::CAPACITY / 3);
+}
+
+#[test]
+fn counts_one_word_per_poll() {
+    let (words, polls) = block_on(CountPolls::new(WordCounter::new("one two three")));
+    assert_eq!(words, 3);
+    // Once for every word, and once more to find out there are no more
+    assert_eq!(polls, 4);
+}
+
+#[test]
+fn can_be_moved_before_the_first_poll() {
+    let counters: Vec<_> = ["a b", "c d e"].into_iter().map(WordCounter::new).collect();
+    let words: Vec<_> = counters.into_iter().map(block_on).collect();
+    assert_eq!(words, [2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "moved")]
+fn moving_after_the_first_poll_is_caught() {
+    use std::{
+        future::Future,
+        pin::Pin,
+        task::{Context, Waker},
+    };
+
+    let mut counter = WordCounter::new("one two three");
+    let mut cx = Context::from_waker(Waker::noop());
+    // SAFETY: none, this breaks the promise `Pin` makes on purpose, to show what the check is for.
+    // `check_not_moved` only compares addresses, so it catches the move before anything reads the old location.
+    let _ = unsafe { Pin::new_unchecked(&mut counter) }.poll(&mut cx);
+    let mut moved = Box::new(counter);
+    let _ = unsafe { Pin::new_unchecked(&mut *moved) }.poll(&mut cx);
+}
+
+#[test]
+fn count_polls_passes_on_the_output() {
+    assert_eq!(block_on(CountPolls::new(async { "done" })), ("done", 1));
+}
+
+#[test]
+fn count_polls_works_with_async_blocks_which_borrow_their_own_locals() {
+    let (length, polls) = block_on(CountPolls::new(async {
+        let text = String::from("borrowed across an await");
+        // A reference into the async block's own state, held across an `.await`, like `WordCounter::rest`
+        let words: Vec<&str> = text.split(' ').collect();
+        let more = WordCounter::new("and then some").await;
+        words.len() + more
+    }));
+    assert_eq!(length, 7);
+    assert_eq!(polls, 4);
+}
+
+#[test]
+fn count_polls_is_only_unpin_if_its_future_is() {
+    fn assert_unpin<T: Unpin>(_: &T) {}
+    assert_unpin(&CountPolls::new(std::future::ready(1)));
+    // `CountPolls::new(WordCounter::new(""))` wouldn't compile here, see `tests/ui/unpin_word_counter.rs`
+}
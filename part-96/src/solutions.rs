@@ -0,0 +1,117 @@
+//! Part 96, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    future::Future,
+    marker::PhantomPinned,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use super::CAPACITY;
+
+/// Counts the words in a text, one word per poll, like an async fn which awaits something after every word.
+///
+/// The text is kept inside the counter itself, and what's left to count is a pointer into it,
+/// just like a reference to one of an async fn's own local variables held across an `.await`.
+/// So once it's been polled, the counter must never move again, which it promises by not being `Unpin`.
+pub struct WordCounter {
+    buffer: [u8; CAPACITY],
+    len: usize,
+    /// The part of `buffer` which hasn't been counted yet, or `None` until the first poll
+    rest: Option<*const str>,
+    words: usize,
+    _pinned: PhantomPinned,
+}
+
+impl WordCounter {
+    /// Panics if `text` is longer than `CAPACITY` bytes
+    pub fn new(text: &str) -> Self {
+        assert!(text.len() <= CAPACITY, "Text longer than {CAPACITY} bytes");
+        let mut buffer = [0; CAPACITY];
+        buffer[..text.len()].copy_from_slice(text.as_bytes());
+        Self {
+            buffer,
+            len: text.len(),
+            rest: None,
+            words: 0,
+            _pinned: PhantomPinned,
+        }
+    }
+
+    /// The text to count the words in
+    pub fn text(&self) -> &str {
+        // SAFETY: the first `len` bytes were copied from a `&str` in `new`, and are never changed
+        unsafe { std::str::from_utf8_unchecked(&self.buffer[..self.len]) }
+    }
+
+    /// Panics if `rest` has been set, but doesn't point into this counter's own buffer.
+    /// Only compares addresses, so it's safe to call even if the counter has been moved.
+    pub fn check_not_moved(&self) {
+        let Some(rest) = self.rest else {
+            return;
+        };
+        let buffer = self.buffer.as_ptr_range();
+        let rest = rest as *const u8;
+        assert!(
+            buffer.start <= rest && rest <= buffer.end,
+            "WordCounter was moved after it was first polled, `rest` points to where it used to be"
+        );
+    }
+}
+
+impl Future for WordCounter {
+    type Output = usize;
+
+    /// Counts one more word and returns `Poll::Pending`, after waking itself so it's polled again,
+    /// or returns how many words there were once there are no more
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<usize> {
+        // SAFETY: nothing is moved out of `this`, and the counter stays where it is from now on,
+        // since it's pinned, so `rest` keeps pointing into its own buffer
+        let this = unsafe { self.get_unchecked_mut() };
+        if this.rest.is_none() {
+            // Only now that it's pinned can it point into itself
+            this.rest = Some(this.text());
+        }
+        this.check_not_moved();
+
+        // SAFETY: `rest` points into `buffer`, which hasn't moved, and always at the start of a word or whitespace
+        let rest = unsafe { &*this.rest.unwrap() }.trim_start();
+        if rest.is_empty() {
+            return Poll::Ready(this.words);
+        }
+        let word = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        this.words += 1;
+        this.rest = Some(&rest[word..]);
+
+        // There's more to do right away, so ask to be polled again, like `yield_now`
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// Runs `future`, and returns its output along with how many times it was polled.
+/// Unlike the `PollCounter` from part 25's tests, it works for futures which aren't `Unpin` too.
+pub struct CountPolls<F> {
+    future: F,
+    polls: u32,
+}
+
+impl<F> CountPolls<F> {
+    pub fn new(future: F) -> Self {
+        Self { future, polls: 0 }
+    }
+}
+
+impl<F: Future> Future for CountPolls<F> {
+    type Output = (F::Output, u32);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: nothing is moved out of `this`. `polls` is never pinned, so it may be changed through a plain `&mut`.
+        let this = unsafe { self.get_unchecked_mut() };
+        this.polls += 1;
+        // SAFETY: `future` is only ever reached through a pinned `CountPolls`, and never moved out of it,
+        // so it stays where it is just like the `CountPolls` does
+        let future = unsafe { Pin::new_unchecked(&mut this.future) };
+        future.poll(cx).map(|output| (output, this.polls))
+    }
+}
@@ -0,0 +1,29 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-96 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_25::block_on;
+use part_96::{solutions, CountPolls, WordCounter};
+
+#[test]
+fn counts_like_the_solution() {
+    for text in [
+        "",
+        "one",
+        "  two  words ",
+        "the quick brown fox jumps over the lazy dog",
+    ] {
+        let counted = block_on(CountPolls::new(WordCounter::new(text)));
+        let expected = block_on(solutions::CountPolls::new(solutions::WordCounter::new(
+            text,
+        )));
+        assert_eq!(counted, expected, "{text:?}");
+    }
+}
+
+#[test]
+fn counts_polls_like_the_solution() {
+    let counted = block_on(CountPolls::new(async { 96 }));
+    let expected = block_on(solutions::CountPolls::new(async { 96 }));
+    assert_eq!(counted, expected);
+}
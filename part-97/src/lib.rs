@@ -0,0 +1,192 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Condvar, Mutex,
+    },
+    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How often the timer thread checks for expired timers while there are any
+pub const TICK: Duration = Duration::from_millis(1);
+
+/// How many slots the timer thread's wheel has
+pub const SLOTS: usize = 64;
+
+/// A waker which only counts how many times it's woken, in `wakes`.
+/// Built by hand from a `RawWaker`, where the data pointer is an `Arc<AtomicUsize>` turned into a raw pointer.
+pub fn counting_waker(wakes: Arc<AtomicUsize>) -> Waker {
+    todo!()
+}
+
+/// A hashed timer wheel: a ring of slots, one for every tick, where a timer due at tick `t` goes in slot `t % slots`.
+/// Timers more than a round of the wheel away share a slot with earlier ones, and stay in it until they're due.
+#[derive(Debug)]
+pub struct TimerWheel<T> {
+    /// The timers in every slot, with the tick they're due at
+    slots: Vec<Vec<(u64, T)>>,
+    /// The tick the wheel has been advanced to. Every timer due at or before it has expired.
+    now: u64,
+    len: usize,
+}
+
+impl<T> TimerWheel<T> {
+    /// An empty wheel with `slots` slots, at tick 0
+    pub fn new(slots: usize) -> Self {
+        assert!(slots > 0, "A timer wheel needs at least one slot");
+        Self {
+            slots: (0..slots).map(|_| Vec::new()).collect(),
+            now: 0,
+            len: 0,
+        }
+    }
+
+    /// How many timers haven't expired yet
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds a timer for `item`, due at tick `deadline`.
+    /// One which is due already expires the next time the wheel moves forward.
+    pub fn insert(&mut self, deadline: u64, item: T) {
+        todo!()
+    }
+
+    /// Moves the wheel forward to tick `to`, and returns every timer which is due by then, in no particular order.
+    /// Moving it backwards does nothing.
+    pub fn advance(&mut self, to: u64) -> Vec<T> {
+        todo!()
+    }
+}
+
+/// Where a `Sleep` keeps the waker from its latest poll, shared with the timer thread
+type WakerSlot = Arc<Mutex<Waker>>;
+
+/// What the timer thread shares with the `Timer` and its `Sleep`s
+struct State {
+    wheel: TimerWheel<WakerSlot>,
+    /// Set when the `Timer` is dropped, to stop the timer thread
+    shutdown: bool,
+}
+
+struct Shared {
+    /// Tick 0 of the wheel
+    start: Instant,
+    state: Mutex<State>,
+    /// Notified when a timer is added, or the timer thread should shut down
+    changed: Condvar,
+}
+
+impl Shared {
+    /// The last tick which has fully passed
+    fn now_tick(&self) -> u64 {
+        (self.start.elapsed().as_nanos() / TICK.as_nanos()) as u64
+    }
+
+    /// The first tick at or after `deadline`, so a timer never expires early
+    fn deadline_tick(&self, deadline: Instant) -> u64 {
+        let since_start = deadline.saturating_duration_since(self.start);
+        since_start.as_nanos().div_ceil(TICK.as_nanos()) as u64
+    }
+
+    /// Adds a timer which wakes whatever waker is in `waker` at `deadline`, and lets the timer thread know
+    fn add(&self, deadline: Instant, waker: WakerSlot) {
+        let tick = self.deadline_tick(deadline);
+        self.state.lock().unwrap().wheel.insert(tick, waker);
+        self.changed.notify_one();
+    }
+}
+
+/// What the timer thread does: waits for timers while there are none, and otherwise advances the wheel
+/// every `TICK` and wakes the expired timers, until `shutdown` is set
+fn drive(shared: &Shared) {
+    todo!()
+}
+
+/// A timer thread, which wakes up tasks sleeping in a `Sleep` when their time is up.
+/// Dropping it stops the thread, and `Sleep`s which are still waiting are never woken.
+pub struct Timer {
+    shared: Arc<Shared>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Timer {
+    pub fn new() -> Self {
+        let shared = Arc::new(Shared {
+            start: Instant::now(),
+            state: Mutex::new(State {
+                wheel: TimerWheel::new(SLOTS),
+                shutdown: false,
+            }),
+            changed: Condvar::new(),
+        });
+        let thread = thread::Builder::new()
+            .name("timer".to_string())
+            .spawn({
+                let shared = shared.clone();
+                move || drive(&shared)
+            })
+            .unwrap();
+        Self {
+            shared,
+            thread: Some(thread),
+        }
+    }
+
+    /// A future which completes once `duration` has passed
+    pub fn sleep(&self, duration: Duration) -> Sleep {
+        Sleep {
+            deadline: Instant::now() + duration,
+            waker: None,
+            shared: self.shared.clone(),
+        }
+    }
+
+    /// How many timers are waiting to expire
+    pub fn pending(&self) -> usize {
+        self.shared.state.lock().unwrap().wheel.len()
+    }
+}
+
+impl Default for Timer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        self.shared.state.lock().unwrap().shutdown = true;
+        self.shared.changed.notify_one();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Completes at `deadline`. Like part 25's `Delay`, but without a thread of its own.
+pub struct Sleep {
+    deadline: Instant,
+    /// The waker from the latest poll, once the timer has been added
+    waker: Option<WakerSlot>,
+    shared: Arc<Shared>,
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    /// Adds a timer on the first poll, and only updates its waker on later ones
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        todo!()
+    }
+}
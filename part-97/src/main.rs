@@ -0,0 +1,227 @@
+use std::time::{Duration, Instant};
+
+use part_26::Executor;
+use part_97::Timer;
+
+const SLEEPERS: u64 = 10_000;
+
+/// Run with `cargo run --release -p part-97` to let many tasks sleep with a single timer thread
+fn main() {
+    let sleepers = common::cli::Cli::new("Lets many tasks sleep with a single timer thread")
+        .items(SLEEPERS as usize)
+        .parse()
+        .items() as u64;
+    let timer = Timer::new();
+    let mut executor = Executor::new();
+    let spawner = executor.spawner();
+    for i in 0..sleepers {
+        let sleep = timer.sleep(Duration::from_millis(i % 500));
+        spawner.spawn(sleep);
+    }
+
+    let start = Instant::now();
+    executor.run();
+    println!(
+        "{sleepers} tasks slept for up to 500 ms in {:?}, with one timer thread and {} polls",
+        start.elapsed(),
+        executor.polls()
+    );
+}
+
+#[test]
+fn counting_waker_counts_every_wake() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    let wakes = Arc::new(AtomicUsize::new(0));
+    let waker = part_97::counting_waker(wakes.clone());
+    waker.wake_by_ref();
+    let clone = waker.clone();
+    clone.wake();
+    waker.wake();
+    assert_eq!(wakes.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn counting_waker_clones_share_the_counter_and_let_go_of_it() {
+    use std::sync::{atomic::AtomicUsize, Arc};
+
+    let wakes = Arc::new(AtomicUsize::new(0));
+    let waker = part_97::counting_waker(wakes.clone());
+    let clones: Vec<_> = (0..10).map(|_| waker.clone()).collect();
+    assert_eq!(Arc::strong_count(&wakes), 12);
+    assert!(clones.iter().all(|clone| clone.will_wake(&waker)));
+
+    drop(clones);
+    drop(waker);
+    assert_eq!(Arc::strong_count(&wakes), 1, "A waker leaked its reference");
+}
+
+#[test]
+fn wheel_expires_timers_when_they_are_due() {
+    use part_97::TimerWheel;
+
+    let mut wheel = TimerWheel::new(8);
+    wheel.insert(5, 'a');
+    wheel.insert(2, 'b');
+    wheel.insert(5, 'c');
+    assert_eq!(wheel.len(), 3);
+
+    assert!(wheel.advance(1).is_empty());
+    assert_eq!(wheel.advance(2), ['b']);
+    let mut expired = wheel.advance(6);
+    expired.sort();
+    assert_eq!(expired, ['a', 'c']);
+    assert!(wheel.is_empty());
+}
+
+#[test]
+fn wheel_keeps_timers_for_later_rounds() {
+    use part_97::TimerWheel;
+
+    // 2, 6 and 10 all share a slot
+    let mut wheel = TimerWheel::new(4);
+    wheel.insert(10, 10);
+    wheel.insert(2, 2);
+    wheel.insert(6, 6);
+
+    assert_eq!(wheel.advance(2), [2]);
+    assert_eq!(wheel.len(), 2);
+    assert!(wheel.advance(5).is_empty());
+    assert_eq!(wheel.advance(6), [6]);
+    // Many rounds at once
+    assert_eq!(wheel.advance(1000), [10]);
+    assert!(wheel.is_empty());
+}
+
+#[test]
+fn wheel_expires_overdue_timers_on_the_next_tick() {
+    use part_97::TimerWheel;
+
+    let mut wheel = TimerWheel::new(4);
+    assert!(wheel.advance(10).is_empty());
+    wheel.insert(3, "overdue");
+    wheel.insert(10, "due now");
+    assert!(
+        wheel.advance(10).is_empty(),
+        "Moving to the same tick again"
+    );
+    assert!(wheel.advance(9).is_empty(), "Moving backwards");
+    let mut expired = wheel.advance(11);
+    expired.sort();
+    assert_eq!(expired, ["due now", "overdue"]);
+}
+
+#[test]
+fn sleep_is_woken_once_when_its_time_is_up() {
+    use std::{
+        future::Future,
+        pin::pin,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        task::{Context, Poll},
+    };
+
+    let timer = Timer::new();
+    let wakes = Arc::new(AtomicUsize::new(0));
+    let waker = part_97::counting_waker(wakes.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    let mut sleep = pin!(timer.sleep(Duration::from_millis(20)));
+    assert_eq!(sleep.as_mut().poll(&mut cx), Poll::Pending);
+    assert_eq!(sleep.as_mut().poll(&mut cx), Poll::Pending);
+    assert_eq!(timer.pending(), 1, "Polling again added another timer");
+    assert_eq!(wakes.load(Ordering::SeqCst), 0);
+
+    std::thread::sleep(Duration::from_millis(200));
+    assert_eq!(wakes.load(Ordering::SeqCst), 1);
+    assert_eq!(timer.pending(), 0);
+    assert_eq!(sleep.as_mut().poll(&mut cx), Poll::Ready(()));
+}
+
+#[test]
+fn sleep_wakes_the_latest_waker() {
+    use std::{
+        future::Future,
+        pin::pin,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        task::Context,
+    };
+
+    let timer = Timer::new();
+    let first = Arc::new(AtomicUsize::new(0));
+    let second = Arc::new(AtomicUsize::new(0));
+    let mut sleep = pin!(timer.sleep(Duration::from_millis(20)));
+
+    let _ = sleep
+        .as_mut()
+        .poll(&mut Context::from_waker(&part_97::counting_waker(
+            first.clone(),
+        )));
+    let _ = sleep
+        .as_mut()
+        .poll(&mut Context::from_waker(&part_97::counting_waker(
+            second.clone(),
+        )));
+    std::thread::sleep(Duration::from_millis(200));
+
+    assert_eq!(first.load(Ordering::SeqCst), 0);
+    assert_eq!(second.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn sleep_takes_as_long_as_it_should() {
+    let timer = Timer::new();
+    let mut executor = Executor::new();
+    let sleep = timer.sleep(Duration::from_millis(50));
+    let start = Instant::now();
+    executor.block_on(sleep);
+    assert!(start.elapsed() >= Duration::from_millis(50));
+    // Once to add the timer, and once when it's woken
+    assert_eq!(executor.polls(), 2);
+    if common::sanitize::skip_timing() {
+        return;
+    }
+    assert!(start.elapsed() < Duration::from_millis(500));
+}
+
+#[test]
+fn sleeping_tasks_are_only_polled_when_woken() {
+    const TASKS: usize = 1000;
+
+    let timer = Timer::new();
+    let mut executor = Executor::new();
+    let spawner = executor.spawner();
+    for i in 0..TASKS {
+        let sleep = timer.sleep(Duration::from_millis(i as u64 % 50));
+        spawner.spawn(sleep);
+    }
+    executor.run();
+    // At most once to add the timer and once when it's woken, with no polling in between
+    assert!(
+        executor.polls() <= 2 * TASKS,
+        "{} polls for {TASKS} tasks",
+        executor.polls()
+    );
+}
+
+#[test]
+fn dropping_the_timer_stops_its_thread() {
+    let stopped = common::with_timeout(Duration::from_secs(1), || {
+        let timer = Timer::new();
+        let mut sleep = Box::pin(timer.sleep(Duration::from_secs(3600)));
+        let _ = std::future::Future::poll(
+            sleep.as_mut(),
+            &mut std::task::Context::from_waker(std::task::Waker::noop()),
+        );
+        drop(timer);
+    });
+    assert!(stopped.is_some(), "The timer thread didn't stop");
+}
@@ -0,0 +1,90 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-97 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{
+    future::Future,
+    pin::pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::Context,
+    thread,
+    time::{Duration, Instant},
+};
+
+use part_97::{counting_waker, solutions, Timer, TimerWheel};
+
+#[test]
+fn wakes_like_the_solution() {
+    let (wakes, expected) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+    let (waker, expected_waker) = (
+        counting_waker(wakes.clone()),
+        solutions::counting_waker(expected.clone()),
+    );
+    for _ in 0..3 {
+        waker.wake_by_ref();
+        expected_waker.wake_by_ref();
+    }
+    let (clone, expected_clone) = (waker.clone(), expected_waker.clone());
+    clone.wake();
+    expected_clone.wake();
+    drop((waker, expected_waker));
+    assert_eq!(
+        wakes.load(Ordering::Relaxed),
+        expected.load(Ordering::Relaxed)
+    );
+    // Every waker has been dropped, so only the test's own handles are left
+    assert_eq!(Arc::strong_count(&wakes), Arc::strong_count(&expected));
+}
+
+#[test]
+fn expires_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (mut wheel, mut expected) = (TimerWheel::new(8), solutions::TimerWheel::new(8));
+    let deadlines = common::datagen::numbers_below(100, 50, rng.seed());
+    for (item, &deadline) in deadlines.iter().enumerate() {
+        wheel.insert(deadline, item);
+        expected.insert(deadline, item);
+    }
+    for to in [0, 3, 3, 2, 10, 17, 40, 100] {
+        let (mut expired, mut expected_expired) = (wheel.advance(to), expected.advance(to));
+        expired.sort();
+        expected_expired.sort();
+        assert_eq!(expired, expected_expired, "Advancing to {to}");
+        assert_eq!(wheel.len(), expected.len());
+    }
+}
+
+#[test]
+fn sleeps_like_the_solution() {
+    /// Polls the sleep whenever its waker is woken, until it's ready, and returns how long that took
+    fn slept(sleep: impl Future<Output = ()>) -> Duration {
+        let start = Instant::now();
+        let wakes = Arc::new(AtomicUsize::new(0));
+        let waker = solutions::counting_waker(wakes.clone());
+        let mut context = Context::from_waker(&waker);
+        let mut sleep = pin!(sleep);
+        let mut polls = 1;
+        while sleep.as_mut().poll(&mut context).is_pending() {
+            while wakes.load(Ordering::Relaxed) < polls {
+                thread::sleep(Duration::from_millis(1));
+            }
+            polls += 1;
+        }
+        start.elapsed()
+    }
+
+    let (timer, expected) = (Timer::new(), solutions::Timer::new());
+    for millis in [0, 5, 20] {
+        let duration = Duration::from_millis(millis);
+        assert!(slept(timer.sleep(duration)) >= duration);
+        assert!(slept(expected.sleep(duration)) >= duration);
+        assert_eq!(
+            timer.pending(),
+            expected.pending(),
+            "After sleeping {millis} ms"
+        );
+    }
+}
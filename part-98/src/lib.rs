@@ -0,0 +1,68 @@
+use std::{
+    collections::HashMap,
+    io::{self, Read, Write},
+    net,
+};
+
+use mio::{
+    net::{TcpListener, TcpStream},
+    Events, Interest, Poll, Token,
+};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// The token for events on the listener. Connections get the ones after it.
+const LISTENER: Token = Token(0);
+
+/// A client's connection, along with what it has sent which hasn't been echoed back yet
+#[derive(Debug)]
+pub struct Connection {
+    stream: TcpStream,
+    /// Received from the client, but not sent back yet
+    pending: Vec<u8>,
+    /// Set once the client has closed its side of the connection
+    closed: bool,
+}
+
+impl Connection {
+    pub fn new(stream: TcpStream) -> Self {
+        Self {
+            stream,
+            pending: Vec::new(),
+            closed: false,
+        }
+    }
+
+    /// Reads everything the client has sent so far into `pending`, without waiting for more.
+    /// Sets `closed` if the client has closed the connection, and does nothing once it has.
+    pub fn read(&mut self) -> io::Result<()> {
+        todo!()
+    }
+
+    /// Sends as much of `pending` back as the socket will take without waiting, and removes what was sent
+    pub fn write(&mut self) -> io::Result<()> {
+        todo!()
+    }
+
+    /// What to be told about next: more to read until the client is done, and room to write while there's something to send
+    pub fn interest(&self) -> Interest {
+        match (self.closed, self.pending.is_empty()) {
+            (false, true) => Interest::READABLE,
+            (false, false) => Interest::READABLE | Interest::WRITABLE,
+            (true, _) => Interest::WRITABLE,
+        }
+    }
+
+    /// Whether the client is gone, and everything it sent has been echoed back, so the connection can be closed
+    pub fn is_done(&self) -> bool {
+        self.closed && self.pending.is_empty()
+    }
+}
+
+/// Echoes everything every client sends back to it, with one thread serving every connection.
+/// Works through the connections which are ready whenever `mio` says some are, and runs until polling fails.
+/// A connection failing only closes that connection.
+pub fn serve(listener: net::TcpListener) -> io::Result<()> {
+    todo!()
+}
@@ -0,0 +1,75 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use common::budget;
+
+const CONNECTIONS: usize = 1000;
+
+/// Run with `cargo run --release -p part-98` to compare the servers with many connections open.
+/// Run with `cargo run -p part-98 -- serve` to only run the `mio` server, and connect with e.g. `nc localhost 7878`.
+fn main() -> std::io::Result<()> {
+    let args = common::cli::Cli::new("Compares the servers with many connections open")
+        .items(CONNECTIONS)
+        .positional("mode", "`serve` to only run the mio server")
+        .parse();
+    let connections = args.items();
+    if args.positional("mode") == Some("serve") {
+        let listener = TcpListener::bind("127.0.0.1:7878")?;
+        println!("Listening on {}", listener.local_addr()?);
+        return part_98::serve(listener);
+    }
+
+    println!("{connections} connections open at once");
+    report(
+        "Thread per connection",
+        start(|listener| part_36::serve_per_connection(listener, Arc::default())),
+        connections,
+    );
+    report(
+        "mio, one thread",
+        start(|listener| part_98::serve(listener).unwrap()),
+        connections,
+    );
+    Ok(())
+}
+
+/// Starts `serve` on a free port in the background, returning its address
+fn start(serve: impl FnOnce(TcpListener) + Send + 'static) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Couldn't bind");
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || serve(listener));
+    addr
+}
+
+/// Opens `connections` connections, has a line echoed on each while all are open, and prints what the server needed for it
+fn report(name: &str, addr: SocketAddr, connections: usize) {
+    let start = Instant::now();
+    let ((), usage) = budget::measure(|| {
+        let mut streams: Vec<_> = (0..connections)
+            .map(|_| TcpStream::connect(addr).unwrap())
+            .collect();
+        for stream in &mut streams {
+            writeln!(stream, "hello").unwrap();
+        }
+        for stream in &streams {
+            let mut echo = String::new();
+            BufReader::new(stream).read_line(&mut echo).unwrap();
+            assert_eq!(echo, "hello\n");
+        }
+        thread::sleep(Duration::from_millis(20));
+    });
+    print!("{name}: {:?}", start.elapsed());
+    match usage {
+        Some(usage) => println!(
+            ", {} extra threads and {} KiB of memory",
+            usage.threads,
+            usage.memory / 1024
+        ),
+        None => println!(),
+    }
+}
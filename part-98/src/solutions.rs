@@ -0,0 +1,151 @@
+//! Part 98, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    collections::HashMap,
+    io::{self, Read, Write},
+    net,
+};
+
+use mio::{
+    net::{TcpListener, TcpStream},
+    Events, Interest, Poll, Token,
+};
+
+use super::LISTENER;
+
+/// A client's connection, along with what it has sent which hasn't been echoed back yet
+#[derive(Debug)]
+pub struct Connection {
+    stream: TcpStream,
+    /// Received from the client, but not sent back yet
+    pending: Vec<u8>,
+    /// Set once the client has closed its side of the connection
+    closed: bool,
+}
+
+impl Connection {
+    pub fn new(stream: TcpStream) -> Self {
+        Self {
+            stream,
+            pending: Vec::new(),
+            closed: false,
+        }
+    }
+
+    /// Reads everything the client has sent so far into `pending`, without waiting for more.
+    /// Sets `closed` if the client has closed the connection, and does nothing once it has.
+    pub fn read(&mut self) -> io::Result<()> {
+        let mut buffer = [0; 4096];
+        while !self.closed {
+            match self.stream.read(&mut buffer) {
+                Ok(0) => self.closed = true,
+                Ok(read) => self.pending.extend_from_slice(&buffer[..read]),
+                // Everything there is has been read. `mio` only says when there's something new,
+                // so stopping any earlier could mean never hearing about this connection again.
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => break,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(())
+    }
+
+    /// Sends as much of `pending` back as the socket will take without waiting, and removes what was sent
+    pub fn write(&mut self) -> io::Result<()> {
+        while !self.pending.is_empty() {
+            match self.stream.write(&self.pending) {
+                Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+                Ok(written) => {
+                    self.pending.drain(..written);
+                }
+                // The socket's buffer is full, so wait until the client has read some of it
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => break,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(())
+    }
+
+    /// What to be told about next: more to read until the client is done, and room to write while there's something to send
+    pub fn interest(&self) -> Interest {
+        match (self.closed, self.pending.is_empty()) {
+            (false, true) => Interest::READABLE,
+            (false, false) => Interest::READABLE | Interest::WRITABLE,
+            (true, _) => Interest::WRITABLE,
+        }
+    }
+
+    /// Whether the client is gone, and everything it sent has been echoed back, so the connection can be closed
+    pub fn is_done(&self) -> bool {
+        self.closed && self.pending.is_empty()
+    }
+}
+
+/// Echoes everything every client sends back to it, with one thread serving every connection.
+/// Works through the connections which are ready whenever `mio` says some are, and runs until polling fails.
+/// A connection failing only closes that connection.
+pub fn serve(listener: net::TcpListener) -> io::Result<()> {
+    listener.set_nonblocking(true)?;
+    let mut listener = TcpListener::from_std(listener);
+    let mut poll = Poll::new()?;
+    let mut events = Events::with_capacity(1024);
+    poll.registry()
+        .register(&mut listener, LISTENER, Interest::READABLE)?;
+
+    let mut connections = HashMap::new();
+    let mut next_token = LISTENER.0 + 1;
+    loop {
+        // Waits until at least one of the sockets is ready
+        match poll.poll(&mut events, None) {
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            result => result?,
+        }
+
+        for event in &events {
+            if event.token() == LISTENER {
+                // Accepts every connection which is waiting, like reading until there's nothing more
+                loop {
+                    match listener.accept() {
+                        Ok((stream, _)) => {
+                            let token = Token(next_token);
+                            next_token += 1;
+                            let mut connection = Connection::new(stream);
+                            let interest = connection.interest();
+                            poll.registry()
+                                .register(&mut connection.stream, token, interest)?;
+                            connections.insert(token, connection);
+                        }
+                        Err(error) if error.kind() == io::ErrorKind::WouldBlock => break,
+                        Err(error) => {
+                            eprintln!("Couldn't accept connection: {error}");
+                            break;
+                        }
+                    }
+                }
+                continue;
+            }
+
+            let token = event.token();
+            // The connection may have been closed by an earlier event in the same batch
+            let Some(connection) = connections.get_mut(&token) else {
+                continue;
+            };
+            match connection.read().and_then(|()| connection.write()) {
+                Ok(()) if !connection.is_done() => {
+                    // What to wait for may have changed, with more or nothing left to send
+                    let interest = connection.interest();
+                    poll.registry()
+                        .reregister(&mut connection.stream, token, interest)?;
+                }
+                result => {
+                    if let Err(error) = result {
+                        eprintln!("Connection failed: {error}");
+                    }
+                    let mut connection = connections.remove(&token).unwrap();
+                    poll.registry().deregister(&mut connection.stream)?;
+                }
+            }
+        }
+    }
+}
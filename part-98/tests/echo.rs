@@ -0,0 +1,96 @@
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{Shutdown, SocketAddr, TcpListener, TcpStream},
+    thread,
+};
+
+use part_98::serve;
+
+const CLIENTS: usize = 50;
+
+/// Starts a server on a free port in the background, returning its address.
+/// The server runs until the tests are done.
+fn start() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Couldn't bind");
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || serve(listener).expect("The server failed"));
+    addr
+}
+
+/// Connects, sends a few lines and checks that each of them comes back
+fn client(addr: SocketAddr, id: usize) {
+    let mut stream = TcpStream::connect(addr).expect("Couldn't connect");
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    for message in 0..3 {
+        let line = format!("client {id} says {message}");
+        writeln!(stream, "{line}").unwrap();
+
+        let mut echo = String::new();
+        reader.read_line(&mut echo).unwrap();
+        assert_eq!(echo.trim_end(), line);
+    }
+}
+
+#[test]
+fn echoes_every_client() {
+    let addr = start();
+    let handles: Vec<_> = (0..CLIENTS)
+        .map(|id| thread::spawn(move || client(addr, id)))
+        .collect();
+    for handle in handles {
+        handle.join().expect("Client failed");
+    }
+}
+
+#[test]
+fn serves_every_connection_at_once() {
+    let addr = start();
+    // All from this thread, so the server has to switch between them
+    let mut streams: Vec<_> = (0..500)
+        .map(|_| TcpStream::connect(addr).unwrap())
+        .collect();
+    for (id, stream) in streams.iter_mut().enumerate() {
+        writeln!(stream, "hello from {id}").unwrap();
+    }
+    for (id, stream) in streams.into_iter().enumerate() {
+        let mut echo = String::new();
+        BufReader::new(stream).read_line(&mut echo).unwrap();
+        assert_eq!(echo, format!("hello from {id}\n"));
+    }
+}
+
+#[test]
+fn echoes_everything_before_closing() {
+    let addr = start();
+    let stream = TcpStream::connect(addr).unwrap();
+    // Far more than fits in the sockets' buffers, so the server can't send it all back at once
+    let sent: Vec<u8> = (0..4 * 1024 * 1024).map(|i| (i % 251) as u8).collect();
+
+    let writer = thread::spawn({
+        let mut stream = stream.try_clone().unwrap();
+        let sent = sent.clone();
+        move || {
+            stream.write_all(&sent).unwrap();
+            stream.shutdown(Shutdown::Write).unwrap();
+        }
+    });
+    let mut received = Vec::new();
+    (&stream).read_to_end(&mut received).unwrap();
+    writer.join().unwrap();
+
+    assert_eq!(received.len(), sent.len());
+    assert!(received == sent, "The echo differs from what was sent");
+}
+
+#[test]
+fn keeps_serving_after_clients_leave() {
+    let addr = start();
+    for _ in 0..10 {
+        drop(TcpStream::connect(addr).unwrap());
+    }
+    let mut rude = TcpStream::connect(addr).unwrap();
+    rude.write_all(b"leaving without reading this").unwrap();
+    drop(rude);
+
+    client(addr, 0);
+}
@@ -0,0 +1,43 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-98 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use std::{
+    io::{self, Read, Write},
+    net::{Shutdown, SocketAddr, TcpListener, TcpStream},
+    thread,
+};
+
+use part_98::{serve, solutions};
+
+/// Starts `serve` on a free port in the background, returning its address
+fn start(serve: fn(TcpListener) -> io::Result<()>) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Couldn't bind");
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || serve(listener).expect("The server failed"));
+    addr
+}
+
+/// Sends `messages`, closes its side of the connection and returns everything the server sent back
+fn echoed(addr: SocketAddr, messages: &[u64]) -> Vec<u8> {
+    let mut stream = TcpStream::connect(addr).expect("Couldn't connect");
+    for message in messages {
+        writeln!(stream, "{message}").unwrap();
+    }
+    stream.shutdown(Shutdown::Write).unwrap();
+    let mut echo = Vec::new();
+    stream.read_to_end(&mut echo).unwrap();
+    echo
+}
+
+#[test]
+fn echoes_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let (addr, expected) = (start(serve), start(solutions::serve));
+    let messages = common::datagen::numbers(10_000, rng.seed());
+    for chunk in messages.chunks(1000) {
+        assert_eq!(echoed(addr, chunk), echoed(expected, chunk));
+    }
+    // Closed without sending anything
+    assert_eq!(echoed(addr, &[]), echoed(expected, &[]));
+}
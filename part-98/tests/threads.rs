@@ -0,0 +1,59 @@
+//! How many threads the servers need, in a file of its own so no other tests start threads at the same time
+
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::Arc,
+    thread,
+};
+
+use common::budget;
+
+const CONNECTIONS: usize = 200;
+
+/// Starts `serve` on a free port in the background, returning its address
+fn start(serve: impl FnOnce(TcpListener) + Send + 'static) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Couldn't bind");
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || serve(listener));
+    addr
+}
+
+/// Opens `CONNECTIONS` connections from this thread, and checks that each of them is echoed while all are open
+fn connect_all(addr: SocketAddr) {
+    let mut streams: Vec<_> = (0..CONNECTIONS)
+        .map(|_| TcpStream::connect(addr).unwrap())
+        .collect();
+    for stream in &mut streams {
+        writeln!(stream, "hello").unwrap();
+    }
+    for stream in &streams {
+        let mut echo = String::new();
+        BufReader::new(stream).read_line(&mut echo).unwrap();
+        assert_eq!(echo, "hello\n");
+    }
+    // Long enough for the measuring thread to see every connection open at once
+    thread::sleep(std::time::Duration::from_millis(20));
+}
+
+#[test]
+fn one_thread_serves_every_connection() {
+    let addr = start(|listener| part_98::serve(listener).unwrap());
+    let ((), usage) = budget::measure(|| connect_all(addr));
+    if let Some(usage) = usage {
+        assert_eq!(usage.threads, 0, "Started threads to serve connections");
+    }
+}
+
+#[test]
+fn thread_per_connection_needs_a_thread_for_every_connection() {
+    let addr = start(|listener| part_36::serve_per_connection(listener, Arc::default()));
+    let ((), usage) = budget::measure(|| connect_all(addr));
+    if let Some(usage) = usage {
+        assert!(
+            usage.threads >= CONNECTIONS,
+            "Only {} threads for {CONNECTIONS} connections",
+            usage.threads
+        );
+    }
+}
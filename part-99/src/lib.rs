@@ -0,0 +1,180 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    thread,
+    time::{Duration, Instant},
+};
+
+use common::sync::{ContentionReport, InstrumentedMutex};
+
+#[cfg(feature = "solutions")]
+pub mod solutions;
+
+/// How many shards `ShardedCounter` has
+pub const SHARDS: usize = 16;
+
+const SECTIONS: [&str; 6] = [
+    "/Products",
+    "/products",
+    "/Blog",
+    "/blog",
+    "/cart",
+    "/About",
+];
+
+/// `count` lines from a web server's access log, the same ones for the same `seed`.
+/// They're about 128 different pages, written in a few different ways.
+pub fn log_lines(count: usize, seed: u64) -> Vec<String> {
+    common::datagen::numbers_below(count, 1 << 16, seed)
+        .into_iter()
+        .map(|number| {
+            let section = SECTIONS[number as usize % SECTIONS.len()];
+            let item = number / 8 % 32;
+            let slash = if number % 3 == 0 { "/" } else { "" };
+            format!("GET {section}/{item}{slash}?ref={number} HTTP/1.1")
+        })
+        .collect()
+}
+
+/// The page a log line is about: its path in lower case, without the query or a trailing slash.
+/// Takes a few microseconds, like looking the page up in a list of redirects would.
+pub fn normalize(line: &str) -> String {
+    let path = line.split(' ').nth(1).unwrap_or("/");
+    let path = path.split('?').next().unwrap_or(path);
+    let page = path.trim_end_matches('/').to_lowercase();
+
+    // The simulated lookup
+    let mut hasher = DefaultHasher::new();
+    for round in 0..200u32 {
+        (round, &page).hash(&mut hasher);
+    }
+    std::hint::black_box(hasher.finish());
+    page
+}
+
+/// Counts how many times every page has been viewed, from several threads at once
+pub trait ViewCounter: Sync {
+    /// Counts a view of the page in the log line `line`
+    fn record(&self, line: &str);
+    /// How many times every page has been viewed
+    fn counts(&self) -> HashMap<String, u64>;
+    /// How the counter's locks have been used, all added up
+    fn contention(&self) -> ContentionReport;
+}
+
+/// Normalizes the line and counts it while holding the only lock, so only one thread does anything at a time
+#[derive(Debug, Default)]
+pub struct CoarseCounter {
+    counts: InstrumentedMutex<HashMap<String, u64>>,
+}
+
+impl ViewCounter for CoarseCounter {
+    fn record(&self, line: &str) {
+        let mut counts = self.counts.lock();
+        *counts.entry(normalize(line)).or_default() += 1;
+    }
+
+    fn counts(&self) -> HashMap<String, u64> {
+        self.counts.lock().clone()
+    }
+
+    fn contention(&self) -> ContentionReport {
+        self.counts.report()
+    }
+}
+
+/// Like `CoarseCounter`, but only holds the lock for as long as it has to
+#[derive(Debug, Default)]
+pub struct ShortCounter {
+    counts: InstrumentedMutex<HashMap<String, u64>>,
+}
+
+impl ViewCounter for ShortCounter {
+    fn record(&self, line: &str) {
+        todo!()
+    }
+
+    fn counts(&self) -> HashMap<String, u64> {
+        self.counts.lock().clone()
+    }
+
+    fn contention(&self) -> ContentionReport {
+        self.counts.report()
+    }
+}
+
+/// Like `ShortCounter`, but splits the pages between `SHARDS` maps with a lock each,
+/// so threads counting different pages don't wait for each other
+#[derive(Debug)]
+pub struct ShardedCounter {
+    shards: Vec<InstrumentedMutex<HashMap<String, u64>>>,
+}
+
+impl Default for ShardedCounter {
+    fn default() -> Self {
+        Self {
+            shards: (0..SHARDS).map(|_| InstrumentedMutex::default()).collect(),
+        }
+    }
+}
+
+impl ShardedCounter {
+    /// The shard `page` is counted in
+    fn shard(&self, page: &str) -> &InstrumentedMutex<HashMap<String, u64>> {
+        let mut hasher = DefaultHasher::new();
+        page.hash(&mut hasher);
+        &self.shards[hasher.finish() as usize % self.shards.len()]
+    }
+}
+
+impl ViewCounter for ShardedCounter {
+    fn record(&self, line: &str) {
+        todo!()
+    }
+
+    fn counts(&self) -> HashMap<String, u64> {
+        todo!()
+    }
+
+    fn contention(&self) -> ContentionReport {
+        self.shards.iter().map(InstrumentedMutex::report).sum()
+    }
+}
+
+/// What a run of `count_views` took
+#[derive(Debug, Clone, Copy)]
+pub struct Run {
+    pub elapsed: Duration,
+    pub threads: usize,
+    pub contention: ContentionReport,
+}
+
+impl Run {
+    /// The share of all the threads' time they spent waiting for a lock, from 0 to 1
+    pub fn waiting_share(&self) -> f64 {
+        self.contention.waited.as_secs_f64() / (self.elapsed.as_secs_f64() * self.threads as f64)
+    }
+}
+
+/// Records every line in `lines` with `counter`, split between `threads` threads
+pub fn count_views(counter: &impl ViewCounter, lines: &[String], threads: usize) -> Run {
+    let start = Instant::now();
+    thread::scope(|s| {
+        for (id, chunk) in lines
+            .chunks(lines.len().div_ceil(threads).max(1))
+            .enumerate()
+        {
+            s.spawn(move || {
+                let _worker = common::span::worker(id);
+                for line in chunk {
+                    counter.record(line);
+                }
+            });
+        }
+    });
+    Run {
+        elapsed: start.elapsed(),
+        threads,
+        contention: counter.contention(),
+    }
+}
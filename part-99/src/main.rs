@@ -0,0 +1,125 @@
+use part_99::{count_views, log_lines, CoarseCounter, ShardedCounter, ShortCounter, ViewCounter};
+
+const LINES: usize = 200_000;
+const THREADS: usize = 8;
+
+fn measure(name: &str, counter: &impl ViewCounter, lines: &[String], threads: usize) {
+    let run = count_views(counter, lines, threads);
+    println!(
+        "{name}: {:?}, waiting {:.1}% of the time\n  {}",
+        run.elapsed,
+        run.waiting_share() * 100.0,
+        run.contention
+    );
+}
+
+/// Run with `cargo run --release -p part-99` to see how much each counter's threads wait for its locks
+fn main() {
+    common::init_tracing();
+    let args = common::cli::Cli::new("Counts page views behind one lock and sharded ones")
+        .items(LINES)
+        .threads(THREADS)
+        .parse();
+    let threads = args.threads();
+    let lines = log_lines(args.items(), 99);
+    println!("Counting {} views with {threads} threads", lines.len());
+    measure(
+        "One lock, held while normalizing",
+        &CoarseCounter::default(),
+        &lines,
+        threads,
+    );
+    measure(
+        "One lock, held only to count",
+        &ShortCounter::default(),
+        &lines,
+        threads,
+    );
+    measure("Sharded locks", &ShardedCounter::default(), &lines, threads);
+}
+
+#[cfg(test)]
+const TEST_LINES: usize = 20_000;
+
+/// The most of the threads' time which can be spent waiting for a lock, for a counter to pass
+#[cfg(test)]
+const MAX_WAITING_SHARE: f64 = 0.25;
+
+#[test]
+fn counters_agree_on_the_counts() {
+    let mut rng = common::rng::for_test();
+    let lines = log_lines(TEST_LINES, rng.seed());
+    let coarse = CoarseCounter::default();
+    count_views(&coarse, &lines, THREADS);
+    let counts = coarse.counts();
+    assert_eq!(counts.len(), 128);
+    assert_eq!(counts.values().sum::<u64>(), TEST_LINES as u64);
+    assert_eq!(counts["/products/7"], {
+        let products_7 = lines
+            .iter()
+            .filter(|line| part_99::normalize(line) == "/products/7");
+        products_7.count() as u64
+    });
+
+    let short = ShortCounter::default();
+    count_views(&short, &lines, THREADS);
+    assert_eq!(short.counts(), counts, "ShortCounter");
+    let sharded = ShardedCounter::default();
+    count_views(&sharded, &lines, THREADS);
+    assert_eq!(sharded.counts(), counts, "ShardedCounter");
+}
+
+#[test]
+fn normalize_finds_the_page() {
+    use part_99::normalize;
+
+    assert_eq!(normalize("GET /Products/7/?ref=1 HTTP/1.1"), "/products/7");
+    assert_eq!(normalize("GET /blog/12 HTTP/1.1"), "/blog/12");
+}
+
+#[test]
+fn coarse_counter_is_contended() {
+    let mut rng = common::rng::for_test();
+    let run = count_views(
+        &CoarseCounter::default(),
+        &log_lines(TEST_LINES, rng.seed()),
+        THREADS,
+    );
+    assert_eq!(run.contention.acquisitions, TEST_LINES as u64);
+    // Normalizing is nearly all the work, and it's done with the lock held
+    assert!(run.contention.held > run.elapsed / 2, "{}", run.contention);
+    assert!(
+        run.waiting_share() > MAX_WAITING_SHARE,
+        "{}",
+        run.contention
+    );
+}
+
+#[cfg(test)]
+fn assert_uncontended(name: &str, counter: &impl ViewCounter) {
+    let mut rng = common::rng::for_test();
+    let run = count_views(counter, &log_lines(TEST_LINES, rng.seed()), THREADS);
+    assert_eq!(run.contention.acquisitions, TEST_LINES as u64, "{name}");
+    assert!(
+        run.contention.held < run.elapsed / 4,
+        "{name} held its locks for {:?} of {:?}",
+        run.contention.held,
+        run.elapsed
+    );
+    assert!(
+        run.waiting_share() < MAX_WAITING_SHARE,
+        "{name}'s threads were waiting {:.1}% of the time: {}",
+        run.waiting_share() * 100.0,
+        run.contention
+    );
+}
+
+#[test]
+fn short_counter_is_uncontended() {
+    assert_uncontended("ShortCounter", &ShortCounter::default());
+}
+
+#[test]
+fn sharded_counter_is_uncontended() {
+    assert_uncontended("ShardedCounter", &ShardedCounter::default());
+}
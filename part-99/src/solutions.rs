@@ -0,0 +1,73 @@
+//! Part 99, with every exercise implemented. Only built with `--features solutions`.
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+};
+
+use common::sync::{ContentionReport, InstrumentedMutex};
+
+use super::{normalize, ViewCounter, SHARDS};
+
+/// Like `CoarseCounter`, but only holds the lock for as long as it has to
+#[derive(Debug, Default)]
+pub struct ShortCounter {
+    counts: InstrumentedMutex<HashMap<String, u64>>,
+}
+
+impl ViewCounter for ShortCounter {
+    fn record(&self, line: &str) {
+        let page = normalize(line);
+        *self.counts.lock().entry(page).or_default() += 1;
+    }
+
+    fn counts(&self) -> HashMap<String, u64> {
+        self.counts.lock().clone()
+    }
+
+    fn contention(&self) -> ContentionReport {
+        self.counts.report()
+    }
+}
+
+/// Like `ShortCounter`, but splits the pages between `SHARDS` maps with a lock each,
+/// so threads counting different pages don't wait for each other
+#[derive(Debug)]
+pub struct ShardedCounter {
+    shards: Vec<InstrumentedMutex<HashMap<String, u64>>>,
+}
+
+impl Default for ShardedCounter {
+    fn default() -> Self {
+        Self {
+            shards: (0..SHARDS).map(|_| InstrumentedMutex::default()).collect(),
+        }
+    }
+}
+
+impl ShardedCounter {
+    /// The shard `page` is counted in
+    fn shard(&self, page: &str) -> &InstrumentedMutex<HashMap<String, u64>> {
+        let mut hasher = DefaultHasher::new();
+        page.hash(&mut hasher);
+        &self.shards[hasher.finish() as usize % self.shards.len()]
+    }
+}
+
+impl ViewCounter for ShardedCounter {
+    fn record(&self, line: &str) {
+        let page = normalize(line);
+        *self.shard(&page).lock().entry(page).or_default() += 1;
+    }
+
+    fn counts(&self) -> HashMap<String, u64> {
+        self.shards
+            .iter()
+            .flat_map(|shard| shard.lock().clone())
+            .collect()
+    }
+
+    fn contention(&self) -> ContentionReport {
+        self.shards.iter().map(InstrumentedMutex::report).sum()
+    }
+}
@@ -0,0 +1,45 @@
+//! The exercises against their solutions, on the same inputs.
+//! Run with `cargo test -p part-99 --features solutions`.
+#![cfg(feature = "solutions")]
+
+use part_99::{
+    count_views, log_lines, solutions, CoarseCounter, ShardedCounter, ShortCounter, ViewCounter,
+};
+
+#[test]
+fn counts_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let lines = log_lines(2000, rng.seed());
+    let expected = CoarseCounter::default();
+    for line in &lines {
+        ViewCounter::record(&expected, line);
+    }
+    let expected = ViewCounter::counts(&expected);
+
+    let short = ShortCounter::default();
+    count_views(&short, &lines, 4);
+    assert_eq!(short.counts(), expected);
+
+    let sharded = ShardedCounter::default();
+    count_views(&sharded, &lines, 4);
+    assert_eq!(sharded.counts(), expected);
+}
+
+#[test]
+fn locks_like_the_solution() {
+    let mut rng = common::rng::for_test();
+    let lines = log_lines(200, rng.seed());
+    let (sharded, expected) = (
+        ShardedCounter::default(),
+        solutions::ShardedCounter::default(),
+    );
+    for line in &lines {
+        sharded.record(line);
+        ViewCounter::record(&expected, line);
+    }
+    // Taken once for every view, and once for every shard when counting
+    assert_eq!(
+        sharded.contention().acquisitions,
+        ViewCounter::contention(&expected).acquisitions
+    );
+}
@@ -0,0 +1,102 @@
+//! A few of the exercises, compiled to WebAssembly so they can be run from a page in the browser,
+//! either all on the page or spread over web workers, like in part 72.
+//!
+//! Everything in here runs the same natively and in the browser. Only `web` knows about the
+//! browser, and `main.rs` runs the same tasks natively, on threads.
+
+use fixtures::Dataset;
+
+#[cfg(target_arch = "wasm32")]
+pub mod web;
+
+/// How long the calculation from part 5 takes for every datum, in milliseconds
+pub const CALCULATION_MS: f64 = 500.0;
+
+/// The current time in milliseconds, since some fixed moment.
+/// `Instant` panics in the browser, so it's measured with `Date` there.
+pub type Clock = fn() -> f64;
+
+/// What the playground can run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Task {
+    /// The slow calculation from part 5, on `fixtures::data`
+    Calculate,
+    /// The merge sort from part 48, on `fixtures::numbers`
+    Sort,
+}
+
+impl Task {
+    pub const ALL: [Task; 2] = [Task::Calculate, Task::Sort];
+
+    /// The name the page uses for it
+    pub fn name(self) -> &'static str {
+        match self {
+            Task::Calculate => "calculate",
+            Task::Sort => "sort",
+        }
+    }
+
+    /// The task called `name`, if there is one
+    pub fn named(name: &str) -> Option<Task> {
+        Task::ALL.into_iter().find(|task| task.name() == name)
+    }
+
+    /// What the task runs on
+    pub fn input(self, dataset: Dataset) -> Vec<u64> {
+        match self {
+            Task::Calculate => fixtures::data(dataset),
+            Task::Sort => fixtures::numbers(dataset),
+        }
+    }
+
+    /// Runs the task on `items`, or on a piece of them, on this thread
+    pub fn run(self, items: &[u64], clock: Clock) -> Vec<u64> {
+        match self {
+            Task::Calculate => items.iter().map(|&item| calculate(item, clock)).collect(),
+            Task::Sort => {
+                let mut items = items.to_vec();
+                part_48::merge_sort(&mut items);
+                items
+            }
+        }
+    }
+
+    /// Puts together the results of running the task on the pieces `split` made, in order,
+    /// into what running it on all of the items would have given
+    pub fn combine(self, pieces: Vec<Vec<u64>>) -> Vec<u64> {
+        match self {
+            Task::Calculate => pieces.concat(),
+            Task::Sort => pieces
+                .into_iter()
+                .reduce(|left, right| {
+                    let mut merged = vec![0; left.len() + right.len()];
+                    part_48::merge(&left, &right, &mut merged);
+                    merged
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// The dataset called `name`, like `"small"` for `Dataset::Small`
+pub fn dataset(name: &str) -> Option<Dataset> {
+    Dataset::ALL
+        .into_iter()
+        .find(|dataset| format!("{dataset:?}").eq_ignore_ascii_case(name))
+}
+
+/// Like `part_5::calculate`, which can't run in the browser, since `thread::sleep` isn't supported
+/// there. Busy waits for the same time instead.
+pub fn calculate(datum: u64, clock: Clock) -> u64 {
+    let start = clock();
+    while clock() - start < CALCULATION_MS {}
+    datum * 2
+}
+
+/// Splits `items` into at most `pieces` pieces of about the same length, in order
+pub fn split(items: &[u64], pieces: usize) -> Vec<&[u64]> {
+    if items.is_empty() {
+        return Vec::new();
+    }
+    items.chunks(items.len().div_ceil(pieces.max(1))).collect()
+}
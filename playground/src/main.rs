@@ -0,0 +1,82 @@
+use std::{
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use fixtures::Dataset;
+use playground::{split, Task};
+
+/// The clock for running natively, which the browser doesn't have
+fn now() -> f64 {
+    let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    since_epoch.as_secs_f64() * 1000.0
+}
+
+/// `Task::run` split over `threads` threads, the same way the page splits it over web workers
+fn parallel(task: Task, items: &[u64], threads: usize) -> Vec<u64> {
+    let pieces = thread::scope(|s| {
+        let handles: Vec<_> = split(items, threads)
+            .into_iter()
+            .map(|piece| s.spawn(move || task.run(piece, now)))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    task.combine(pieces)
+}
+
+/// The same tasks as the page in `www`, natively, to compare with.
+/// Run with `cargo run --release -p playground`
+fn main() {
+    for task in Task::ALL {
+        let items = task.input(Dataset::Medium);
+        let name = task.name();
+        let serial = common::timed(&format!("{name} serially"), || task.run(&items, now));
+        let parallel = common::timed(&format!("{name} on 4 threads"), || {
+            parallel(task, &items, 4)
+        });
+        assert_eq!(serial, parallel);
+    }
+}
+
+#[test]
+fn finds_tasks_and_datasets_by_name() {
+    assert_eq!(Task::named("sort"), Some(Task::Sort));
+    assert_eq!(Task::named("calculate"), Some(Task::Calculate));
+    assert_eq!(Task::named("juggle"), None);
+    assert_eq!(playground::dataset("skewed"), Some(Dataset::Skewed));
+    assert_eq!(playground::dataset("Large"), Some(Dataset::Large));
+    assert_eq!(playground::dataset("huge"), None);
+}
+
+#[test]
+fn splits_into_pieces_in_order() {
+    let items: Vec<u64> = (0..10).collect();
+    for pieces in 1..=12 {
+        let split = split(&items, pieces);
+        assert!(split.len() <= pieces, "{pieces} pieces");
+        assert_eq!(split.concat(), items, "{pieces} pieces");
+    }
+    assert!(split(&[], 4).is_empty());
+}
+
+#[test]
+fn sorts_like_serially_in_pieces() {
+    let items = Task::Sort.input(Dataset::Skewed);
+    let serial = Task::Sort.run(&items, now);
+    assert!(serial.windows(2).all(|pair| pair[0] <= pair[1]));
+    for threads in [1, 3, 8] {
+        assert_eq!(
+            parallel(Task::Sort, &items, threads),
+            serial,
+            "{threads} threads"
+        );
+    }
+}
+
+#[test]
+fn calculates_like_part_5_in_pieces() {
+    let items = Task::Calculate.input(Dataset::Small);
+    let expected: Vec<u64> = items.iter().map(|item| item * 2).collect();
+    // One item per thread, so it takes as long as a single calculation
+    assert_eq!(parallel(Task::Calculate, &items, items.len()), expected);
+}
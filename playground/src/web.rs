@@ -0,0 +1,108 @@
+//! The browser side: runs a task either on the page, or spread over web workers.
+//!
+//! Every worker runs its own instance of this module, starting from `www/worker.js`, and shares no
+//! memory with the page, so the pieces of the input and their results are passed as messages.
+
+use js_sys::{Array, BigUint64Array, Date, Promise};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{DedicatedWorkerGlobalScope, MessageEvent, Worker, WorkerOptions, WorkerType};
+
+use crate::{dataset, split, Task};
+
+/// The script every worker starts with, relative to the page
+const WORKER_SCRIPT: &str = "./worker.js";
+
+/// The task and dataset called `task` and `dataset`, or an error for the page to show
+fn parse(task: &str, dataset_name: &str) -> Result<(Task, fixtures::Dataset), JsValue> {
+    let task = Task::named(task).ok_or_else(|| JsValue::from_str(&format!("No task {task}")))?;
+    let dataset = dataset(dataset_name)
+        .ok_or_else(|| JsValue::from_str(&format!("No dataset {dataset_name}")))?;
+    Ok((task, dataset))
+}
+
+/// Resolves to the data of the next message `worker` sends
+fn next_message(worker: &Worker) -> JsFuture {
+    let promise = Promise::new(&mut |resolve, _reject| {
+        let onmessage = Closure::once_into_js(move |event: MessageEvent| {
+            resolve
+                .call1(&JsValue::NULL, &event.data())
+                .expect("Resolving should work");
+        });
+        worker.set_onmessage(Some(onmessage.unchecked_ref()));
+    });
+    JsFuture::from(promise)
+}
+
+/// Runs in every worker. Runs the task it's sent on the items it's sent along with it, as an
+/// array of the task's name and a `BigUint64Array`, and sends back the results as another one.
+/// Sends `null` once it's ready, since messages which arrive before then would be lost.
+#[wasm_bindgen]
+pub fn worker_main() {
+    let scope: DedicatedWorkerGlobalScope = js_sys::global().unchecked_into();
+    let reply = scope.clone();
+    let onmessage = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+        let message = Array::from(&event.data());
+        let task = message.get(0).as_string().expect("Task should be a string");
+        let task = Task::named(&task).expect("Task should exist");
+        let items = BigUint64Array::from(message.get(1)).to_vec();
+        let results = task.run(&items, Date::now);
+        reply
+            .post_message(&BigUint64Array::from(&results[..]))
+            .expect("Page should accept results");
+    });
+    scope.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+    // The handler is needed for as long as the worker lives
+    onmessage.forget();
+    scope
+        .post_message(&JsValue::NULL)
+        .expect("Page should accept messages");
+}
+
+/// Runs `task` on `dataset` on the page, which doesn't respond until it's done
+#[wasm_bindgen]
+pub fn serial(task: &str, dataset: &str) -> Result<Vec<u64>, JsValue> {
+    let (task, dataset) = parse(task, dataset)?;
+    Ok(task.run(&task.input(dataset), Date::now))
+}
+
+/// Runs `task` on `dataset` split over `workers` new web workers, and puts their results together.
+/// Every worker is terminated once it's done.
+#[wasm_bindgen]
+pub async fn parallel(task: &str, dataset: &str, workers: usize) -> Result<Vec<u64>, JsValue> {
+    let (task, dataset) = parse(task, dataset)?;
+    let items = task.input(dataset);
+    let pieces = split(&items, workers);
+
+    let options = WorkerOptions::new();
+    // `worker.js` imports the module, which only module workers can do
+    options.set_type(WorkerType::Module);
+    let workers = pieces
+        .iter()
+        .map(|_| Worker::new_with_options(WORKER_SCRIPT, &options))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Waits for all of them to start, which they do at the same time
+    let ready: Vec<JsFuture> = workers.iter().map(next_message).collect();
+    for worker in ready {
+        worker.await?;
+    }
+
+    let mut results = Vec::with_capacity(workers.len());
+    for (worker, piece) in workers.iter().zip(&pieces) {
+        // Listens before sending, so the result can't arrive before anyone is listening
+        results.push(next_message(worker));
+        let message = Array::of2(
+            &JsValue::from_str(task.name()),
+            &BigUint64Array::from(*piece),
+        );
+        worker.post_message(&message)?;
+    }
+
+    let mut done = Vec::with_capacity(workers.len());
+    for (worker, result) in workers.iter().zip(results) {
+        done.push(BigUint64Array::from(result.await?).to_vec());
+        worker.terminate();
+    }
+    Ok(task.combine(done))
+}
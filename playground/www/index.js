@@ -0,0 +1,38 @@
+import init, { parallel, serial } from "./pkg/playground.js";
+
+await init();
+
+const buttons = [document.getElementById("serial"), document.getElementById("parallel")];
+const output = document.getElementById("output");
+// The last results of every task on every dataset, to check the other way against
+const previous = new Map();
+
+async function time(label, run) {
+  const task = document.getElementById("task").value;
+  const dataset = document.getElementById("dataset").value;
+  const workers = Number(document.getElementById("workers").value);
+  buttons.forEach((button) => (button.disabled = true));
+  output.textContent += `${label(workers)} on ${task}, ${dataset}... `;
+  // Lets the page show that before a serial run blocks it
+  await new Promise((resolve) => setTimeout(resolve));
+  const start = performance.now();
+  try {
+    const results = await run(task, dataset, workers);
+    const elapsed = Math.round(performance.now() - start);
+    const key = `${task} ${dataset}`;
+    const before = previous.get(key);
+    const same = before && before.join() === results.join() ? ", same results as before" : "";
+    previous.set(key, results);
+    output.textContent += `${elapsed} ms${same}\n`;
+  } catch (error) {
+    output.textContent += `failed: ${error}\n`;
+  }
+  buttons.forEach((button) => (button.disabled = false));
+}
+
+buttons[0].addEventListener("click", () =>
+  time(() => "Serial", (task, dataset) => serial(task, dataset)),
+);
+buttons[1].addEventListener("click", () =>
+  time((workers) => `Parallel on ${workers} workers`, parallel),
+);
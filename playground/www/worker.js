@@ -0,0 +1,5 @@
+// Every web worker starts here, with its own instance of the module
+import init, { worker_main } from "./pkg/playground.js";
+
+await init();
+worker_main();
@@ -0,0 +1,376 @@
+//! `workshop bench`: runs the parts which compare implementations with `common::bench`, on
+//! several sizes of data, and puts the results side by side, so that machines can be compared
+
+use std::{collections::BTreeMap, fmt, fs, io, time::Duration};
+
+use serde::Serialize;
+
+use crate::parts::{self, Part};
+
+/// A part whose program measures its implementations with `common::bench::Comparison`,
+/// taking the size of its data from `common::bench::size`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Benchmark {
+    pub part: u32,
+    /// How much data to try it with, like numbers to sort or tasks to run
+    pub sizes: &'static [usize],
+}
+
+/// Every part `workshop bench` runs. The sizes end with the part's own, which is the biggest.
+pub const BENCHMARKS: [Benchmark; 5] = [
+    Benchmark {
+        part: 16,
+        sizes: &[1_000, 10_000],
+    },
+    Benchmark {
+        part: 48,
+        sizes: &[100_000, 1_000_000, 4_000_000],
+    },
+    Benchmark {
+        part: 49,
+        sizes: &[100_000, 1_000_000, 4_000_000],
+    },
+    Benchmark {
+        part: 50,
+        sizes: &[1_000_000, 10_000_000],
+    },
+    Benchmark {
+        part: 67,
+        sizes: &[20_000, 200_000],
+    },
+];
+
+/// What kind of implementation a benchmark measures, from how its name starts,
+/// like `Serial`, `Threaded, cutoff 1000` or `Rayon`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Kind {
+    Serial,
+    Parallel,
+    Rayon,
+    Pool,
+    /// Like the standard library's `sort_unstable`, which is there for reference
+    Other,
+}
+
+impl Kind {
+    /// The kinds which get a column in the table
+    pub const COMPARED: [Kind; 4] = [Kind::Serial, Kind::Parallel, Kind::Rayon, Kind::Pool];
+
+    pub fn of(name: &str) -> Self {
+        let name = name.to_lowercase();
+        if name.contains("pool") {
+            Self::Pool
+        } else if name.starts_with("rayon") {
+            Self::Rayon
+        } else if name.starts_with("serial") || name.starts_with("naive") {
+            Self::Serial
+        } else if ["parallel", "thread"]
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+        {
+            Self::Parallel
+        } else {
+            Self::Other
+        }
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Serial => "Serial",
+            Self::Parallel => "Parallel",
+            Self::Rayon => "Rayon",
+            Self::Pool => "Pool",
+            Self::Other => "Other",
+        })
+    }
+}
+
+/// One measurement from a part's comparison
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Measured {
+    pub part: u32,
+    pub size: usize,
+    pub benchmark: String,
+    pub kind: Kind,
+    #[serde(rename = "median_ns", serialize_with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "fastest_ns", serialize_with = "nanos")]
+    pub fastest: Duration,
+}
+
+fn nanos<S: serde::Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+/// Reads what `Comparison::print` recorded: a median, a fastest run and a name on every line
+pub fn parse_record(part: u32, size: usize, record: &str) -> Vec<Measured> {
+    record
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\t');
+            let median = fields.next()?.parse().ok()?;
+            let fastest = fields.next()?.parse().ok()?;
+            let name = fields.next()?;
+            Some(Measured {
+                part,
+                size,
+                benchmark: name.to_string(),
+                kind: Kind::of(name),
+                median: Duration::from_nanos(median),
+                fastest: Duration::from_nanos(fastest),
+            })
+        })
+        .collect()
+}
+
+/// Runs `part`'s program with optimizations on `size` pieces of data, and gives what it measured.
+/// A part with exercises left to implement panics, so it has measured nothing.
+pub fn run(part: &Part, size: usize) -> io::Result<Vec<Measured>> {
+    let record = parts::workspace()
+        .join(".workshop")
+        .join(format!("bench-{}-{size}.tsv", part.package()));
+    if let Some(dir) = record.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let _ = fs::remove_file(&record);
+    let output = crate::cargo()
+        .args(["run", "--release", "--quiet", "-p", &part.package()])
+        .env(common::bench::SIZE_VARIABLE, size.to_string())
+        .env(common::bench::RECORD_VARIABLE, &record)
+        .env("RUST_BACKTRACE", "0")
+        .output()?;
+    let measured = match fs::read_to_string(&record) {
+        Ok(text) => parse_record(part.number, size, &text),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(error) => return Err(error),
+    };
+    let _ = fs::remove_file(&record);
+    if measured.is_empty() && !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = if stderr.contains("not yet implemented") {
+            "it has exercises left to implement".to_string()
+        } else {
+            stderr
+                .lines()
+                .find(|line| line.starts_with("error") || line.contains("panicked"))
+                .unwrap_or("it failed")
+                .trim()
+                .to_string()
+        };
+        return Err(io::Error::other(reason));
+    }
+    Ok(measured)
+}
+
+/// The machine the benchmarks ran on
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Machine {
+    pub os: &'static str,
+    pub arch: &'static str,
+    pub cores: usize,
+}
+
+impl Machine {
+    pub fn this() -> Self {
+        Machine {
+            os: std::env::consts::OS,
+            arch: std::env::consts::ARCH,
+            cores: std::thread::available_parallelism().map_or(1, |cores| cores.get()),
+        }
+    }
+}
+
+/// Everything that was measured, and where
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Results {
+    pub machine: Machine,
+    pub measured: Vec<Measured>,
+}
+
+impl Results {
+    pub fn json(&self) -> String {
+        let mut json = serde_json::to_string_pretty(self).expect("Results can always be JSON");
+        json.push('\n');
+        json
+    }
+
+    pub fn csv(&self) -> String {
+        let mut csv = String::from("part,size,benchmark,kind,median_ns,fastest_ns\n");
+        for measured in &self.measured {
+            csv.push_str(&format!(
+                "{},{},\"{}\",{},{},{}\n",
+                measured.part,
+                measured.size,
+                measured.benchmark.replace('"', "\"\""),
+                measured.kind.to_string().to_lowercase(),
+                measured.median.as_nanos(),
+                measured.fastest.as_nanos()
+            ));
+        }
+        csv
+    }
+}
+
+/// A table with a row for every part and size, and the fastest of each kind of implementation
+impl fmt::Display for Results {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "On {} {} with {} core{}, the fastest median of each kind:",
+            self.machine.os,
+            self.machine.arch,
+            self.machine.cores,
+            if self.machine.cores == 1 { "" } else { "s" }
+        )?;
+        let mut rows: BTreeMap<(u32, usize), BTreeMap<Kind, Duration>> = BTreeMap::new();
+        for measured in &self.measured {
+            let fastest = rows
+                .entry((measured.part, measured.size))
+                .or_default()
+                .entry(measured.kind)
+                .or_insert(measured.median);
+            *fastest = (*fastest).min(measured.median);
+        }
+
+        write!(f, "{:>5}  {:>10}", "Part", "Size")?;
+        for kind in Kind::COMPARED {
+            write!(f, "  {:>10}", kind.to_string())?;
+        }
+        writeln!(f, "  {:>8}", "Speedup")?;
+        for ((part, size), fastest) in rows {
+            write!(f, "{part:>5}  {size:>10}")?;
+            for kind in Kind::COMPARED {
+                let time = fastest
+                    .get(&kind)
+                    .map(|time| format!("{time:.2?}"))
+                    .unwrap_or_default();
+                write!(f, "  {time:>10}")?;
+            }
+            // How much faster the best of the others is than serial, or than a thread per task
+            let baseline = [Kind::Serial, Kind::Parallel]
+                .into_iter()
+                .find_map(|kind| Some((kind, *fastest.get(&kind)?)));
+            let best = fastest
+                .iter()
+                .filter(|(kind, _)| {
+                    Kind::COMPARED.contains(kind)
+                        && baseline.is_some_and(|(baseline, _)| **kind != baseline)
+                })
+                .map(|(_, time)| *time)
+                .min();
+            let speedup = match (baseline, best) {
+                (Some((_, baseline)), Some(best)) => format!(
+                    "{:.2}x",
+                    baseline.as_secs_f64() / best.as_secs_f64().max(f64::MIN_POSITIVE)
+                ),
+                _ => String::new(),
+            };
+            writeln!(f, "  {speedup:>8}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn results() -> Results {
+    let mut measured = parse_record(
+        48,
+        1000,
+        "400000\t380000\tsort_unstable\n\
+         2000000\t1900000\tSerial\n\
+         800000\t700000\tThreaded, cutoff 100\n\
+         600000\t590000\tThreaded, cutoff 500\n\
+         500000\t480000\tRayon, cutoff 100\n\
+         not a measurement\n",
+    );
+    measured.extend(parse_record(
+        16,
+        10,
+        "900000\t800000\tThread per task\n300000\t250000\tThread pool\n",
+    ));
+    Results {
+        machine: Machine {
+            os: "linux",
+            arch: "x86_64",
+            cores: 8,
+        },
+        measured,
+    }
+}
+
+#[test]
+fn tells_kinds_apart() {
+    let kinds: Vec<_> = [
+        "Serial",
+        "Naive",
+        "Threaded, 4 threads",
+        "Parallel, chunks of 1000",
+        "Threads and channels",
+        "Rayon iterators",
+        "Thread pool",
+        "sort_unstable",
+    ]
+    .into_iter()
+    .map(Kind::of)
+    .collect();
+    use Kind::*;
+    assert_eq!(
+        kinds,
+        [Serial, Serial, Parallel, Parallel, Parallel, Rayon, Pool, Other]
+    );
+}
+
+#[test]
+fn reads_what_comparisons_record() {
+    let results = results();
+    assert_eq!(results.measured.len(), 7);
+    assert_eq!(
+        results.measured[1],
+        Measured {
+            part: 48,
+            size: 1000,
+            benchmark: "Serial".to_string(),
+            kind: Kind::Serial,
+            median: Duration::from_millis(2),
+            fastest: Duration::from_micros(1900),
+        }
+    );
+}
+
+#[test]
+fn compares_the_fastest_of_each_kind() {
+    let table = results().to_string();
+    let lines: Vec<_> = table.lines().collect();
+    assert_eq!(
+        lines[0],
+        "On linux x86_64 with 8 cores, the fastest median of each kind:"
+    );
+    assert_eq!(
+        lines[1],
+        " Part        Size      Serial    Parallel       Rayon        Pool   Speedup"
+    );
+    assert_eq!(
+        lines[2],
+        "   16          10                900.00µs                300.00µs     3.00x"
+    );
+    assert_eq!(
+        lines[3],
+        "   48        1000      2.00ms    600.00µs    500.00µs                 4.00x"
+    );
+}
+
+#[test]
+fn saves_csv_and_json() {
+    let results = results();
+    let csv = results.csv();
+    assert!(csv.starts_with("part,size,benchmark,kind,median_ns,fastest_ns\n"));
+    assert!(csv.contains("48,1000,\"Threaded, cutoff 100\",parallel,800000,700000\n"));
+
+    let json: serde_json::Value = serde_json::from_str(&results.json()).unwrap();
+    assert_eq!(json["machine"]["cores"], 8);
+    assert_eq!(json["measured"][4]["kind"], "rayon");
+    assert_eq!(json["measured"][4]["median_ns"], 500000);
+}
@@ -0,0 +1,187 @@
+//! Which packages in the workspace use which, from `cargo metadata`, so that changing a part
+//! also tests the parts built on it, like part 6 on part 5, and so that checking a part can point
+//! at the ones it builds on
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Metadata {
+    packages: Vec<Package>,
+}
+
+#[derive(Deserialize)]
+struct Package {
+    name: String,
+    manifest_path: PathBuf,
+    dependencies: Vec<Dependency>,
+}
+
+#[derive(Deserialize)]
+struct Dependency {
+    name: String,
+    /// Only there for dependencies in the workspace, or elsewhere on disk
+    path: Option<PathBuf>,
+}
+
+/// The packages in the workspace, and which of them depend on which
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph {
+    /// Every package's name, by its directory
+    packages: BTreeMap<PathBuf, String>,
+    /// The packages which depend on a package, directly, by its name
+    dependents: BTreeMap<String, BTreeSet<String>>,
+    /// The packages a package depends on, directly, by its name
+    dependencies: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Graph {
+    /// Reads the graph of the packages in `workspace`
+    pub fn load(workspace: &Path) -> io::Result<Self> {
+        let output = crate::cargo()
+            .current_dir(workspace)
+            .args(["metadata", "--format-version", "1", "--no-deps"])
+            .output()?;
+        if !output.status.success() {
+            return Err(io::Error::other(format!(
+                "cargo metadata failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Self::parse(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    /// Reads the output of `cargo metadata --format-version 1`
+    pub fn parse(json: &str) -> io::Result<Self> {
+        let metadata: Metadata = serde_json::from_str(json)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        let mut graph = Self::default();
+        for package in metadata.packages {
+            for dependency in &package.dependencies {
+                if dependency.path.is_some() {
+                    graph
+                        .dependents
+                        .entry(dependency.name.clone())
+                        .or_default()
+                        .insert(package.name.clone());
+                    graph
+                        .dependencies
+                        .entry(package.name.clone())
+                        .or_default()
+                        .insert(dependency.name.clone());
+                }
+            }
+            if let Some(dir) = package.manifest_path.parent() {
+                graph.packages.insert(dir.to_path_buf(), package.name);
+            }
+        }
+        Ok(graph)
+    }
+
+    /// The package `path` is in, if it's in one
+    pub fn package_of(&self, path: &Path) -> Option<&str> {
+        // The innermost one, in case packages are inside each other
+        self.packages
+            .iter()
+            .filter(|(dir, _)| path.starts_with(dir))
+            .max_by_key(|(dir, _)| dir.components().count())
+            .map(|(_, name)| name.as_str())
+    }
+
+    /// `package`, and every package which depends on it, directly or through others
+    pub fn affected(&self, package: &str) -> BTreeSet<String> {
+        reachable(&self.dependents, package)
+    }
+
+    /// Every package `package` depends on, directly or through others, like part 5 for part 6, and
+    /// `common`
+    pub fn prerequisites(&self, package: &str) -> BTreeSet<String> {
+        let mut prerequisites = reachable(&self.dependencies, package);
+        prerequisites.remove(package);
+        prerequisites
+    }
+}
+
+/// `package`, and every package `edges` lead to from it
+fn reachable(edges: &BTreeMap<String, BTreeSet<String>>, package: &str) -> BTreeSet<String> {
+    let mut reached = BTreeSet::from([package.to_string()]);
+    let mut unvisited = vec![package];
+    while let Some(package) = unvisited.pop() {
+        for next in edges.get(package).into_iter().flatten() {
+            if reached.insert(next.clone()) {
+                unvisited.push(next);
+            }
+        }
+    }
+    reached
+}
+
+#[test]
+fn finds_everything_that_depends_on_a_package() {
+    let json = r#"{"packages": [
+        {"name": "common", "manifest_path": "/w/common/Cargo.toml", "dependencies": []},
+        {"name": "part-5", "manifest_path": "/w/part-5/Cargo.toml", "dependencies": [
+            {"name": "common", "path": "/w/common"},
+            {"name": "rayon", "source": "registry+https://github.com/rust-lang/crates.io-index"}
+        ]},
+        {"name": "part-6", "manifest_path": "/w/part-6/Cargo.toml", "dependencies": [
+            {"name": "common", "path": "/w/common"},
+            {"name": "part-5", "path": "/w/part-5"}
+        ]},
+        {"name": "part-7", "manifest_path": "/w/part-7/Cargo.toml", "dependencies": [
+            {"name": "part-6", "path": "/w/part-6"}
+        ]},
+        {"name": "rayon", "manifest_path": "/w/rayon/Cargo.toml", "dependencies": []}
+    ]}"#;
+    let graph = Graph::parse(json).unwrap();
+    assert_eq!(
+        graph.package_of(Path::new("/w/part-5/src/lib.rs")),
+        Some("part-5")
+    );
+    assert_eq!(graph.package_of(Path::new("/w/README.md")), None);
+    assert_eq!(
+        graph.affected("part-6"),
+        BTreeSet::from(["part-6".into(), "part-7".into()])
+    );
+    assert_eq!(
+        graph.affected("common"),
+        BTreeSet::from([
+            "common".into(),
+            "part-5".into(),
+            "part-6".into(),
+            "part-7".into()
+        ])
+    );
+    // Only dependencies on disk count, so a crate which happens to share a name isn't affected
+    assert_eq!(graph.affected("rayon"), BTreeSet::from(["rayon".into()]));
+
+    assert_eq!(
+        graph.prerequisites("part-7"),
+        BTreeSet::from(["common".into(), "part-5".into(), "part-6".into()])
+    );
+    assert_eq!(
+        graph.prerequisites("part-5"),
+        BTreeSet::from(["common".into()])
+    );
+    assert!(graph.prerequisites("common").is_empty());
+}
+
+#[test]
+fn reads_the_workspace() {
+    let graph = Graph::load(&crate::parts::workspace()).unwrap();
+    let affected = graph.affected("part-5");
+    for part in ["part-5", "part-6", "part-17", "part-29"] {
+        assert!(affected.contains(part), "{part}");
+    }
+    assert!(!affected.contains("part-4"));
+    // The part with the executor, which builds on the futures by hand
+    let prerequisites = graph.prerequisites("part-97");
+    for part in ["part-25", "part-26"] {
+        assert!(prerequisites.contains(part), "{part}");
+    }
+}
@@ -0,0 +1,315 @@
+//! `workshop events`: what happens while the parts are tested, as a JSON object on every line, for
+//! plugging the workshop into a university's or a company's own grading
+//!
+//! Every part gives a `started` event, a `test` event for every test, with how long it took, and a
+//! `finished` event, with an `error` event for every compiler error before it if it doesn't compile:
+//!
+//! ```json
+//! {"event":"started","part":4}
+//! {"event":"test","id":"part-4/src/lib.rs::sums","part":4,"test":"sums","status":"failed","seconds":0.002,"exercise":"sum","failure":"Not implemented yet, at part-4/src/lib.rs:9:5"}
+//! {"event":"finished","part":4,"outcome":"failing","passed":0,"tests":1,"seconds":3.1}
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{
+    grading::{Outcome, PartGrade, TIMEOUT},
+    parts::Part,
+    process,
+    report::{self, Report, Status},
+};
+
+/// Something that happened while testing a part
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "kebab-case")]
+pub enum Event {
+    Started {
+        part: u32,
+    },
+    /// A test which ran, or was ignored, or was still running when the part timed out
+    Test {
+        /// Which test it is, the same every time, like `part-4/src/lib.rs::sums`. Tests in
+        /// different test programs can have the same name, so it's in there too.
+        id: String,
+        part: u32,
+        test: String,
+        status: Status,
+        /// How long it ran, when that's known
+        #[serde(skip_serializing_if = "Option::is_none")]
+        seconds: Option<f64>,
+        /// The exercise the test failed on, when it failed on a `todo!()`
+        #[serde(skip_serializing_if = "Option::is_none")]
+        exercise: Option<String>,
+        /// The failing assertion or panic message
+        #[serde(skip_serializing_if = "Option::is_none")]
+        failure: Option<String>,
+    },
+    /// A compiler error, or why cargo couldn't be run
+    Error {
+        part: u32,
+        message: String,
+    },
+    Finished {
+        part: u32,
+        outcome: Outcome,
+        passed: usize,
+        /// How many tests ran, not counting ignored ones
+        tests: usize,
+        /// How long building and testing the part took
+        seconds: f64,
+    },
+}
+
+impl Event {
+    /// The event as a single line of JSON
+    pub fn json(&self) -> String {
+        serde_json::to_string(self).expect("Events can always be JSON")
+    }
+}
+
+/// How long every test took, from the test harness's output as it comes in. With a single test
+/// thread, it prints `test name ... ` when a test starts, and the rest of the line when it's done.
+#[derive(Debug, Default)]
+pub struct Timer {
+    /// The line being printed, up to what's been printed of it so far
+    line: Vec<u8>,
+    /// How many test programs have started, which print `running 5 tests` first
+    programs: usize,
+    started: Option<(String, Instant)>,
+    /// How long every test which finished took, by which test program it was in and name
+    times: BTreeMap<(usize, String), Duration>,
+}
+
+impl Timer {
+    /// Takes in more of the output, which arrived at `now`
+    pub fn read(&mut self, output: &[u8], now: Instant) {
+        for &byte in output {
+            if byte == b'\n' {
+                let line = String::from_utf8_lossy(&self.line).into_owned();
+                self.finished(&line, now);
+                self.line.clear();
+            } else {
+                self.line.push(byte);
+            }
+        }
+        let line = String::from_utf8_lossy(&self.line);
+        if let Some(name) = line
+            .strip_prefix("test ")
+            .and_then(|line| line.strip_suffix(" ... "))
+        {
+            if self.started.is_none() {
+                self.started = Some((test_name(name).to_string(), now));
+            }
+        }
+    }
+
+    fn finished(&mut self, line: &str, now: Instant) {
+        if line.starts_with("running ") && (line.ends_with(" tests") || line.ends_with(" test")) {
+            self.programs += 1;
+            return;
+        }
+        let Some((name, _)) = line
+            .strip_prefix("test ")
+            .and_then(|line| line.split_once(" ... "))
+        else {
+            return;
+        };
+        let name = test_name(name);
+        // Otherwise the whole line came at once, so it didn't take long enough to tell
+        let duration = match self.started.take() {
+            Some((started, at)) if started == name => now.duration_since(at),
+            _ => Duration::ZERO,
+        };
+        self.times.insert(
+            (self.programs.saturating_sub(1), name.to_string()),
+            duration,
+        );
+    }
+
+    /// How long every test which finished took, by the test program it was in and name, with
+    /// `targets` the programs in the order they ran, from `report::targets`
+    pub fn durations(&self, targets: &[String]) -> BTreeMap<(String, String), Duration> {
+        self.times
+            .iter()
+            .map(|((program, name), &duration)| {
+                let target = targets.get(*program).cloned().unwrap_or_default();
+                ((target, name.clone()), duration)
+            })
+            .collect()
+    }
+}
+
+/// The `id` of the test `test` of part `part`, in the test program `target`
+fn id(part: u32, target: &str, test: &str) -> String {
+    if target.is_empty() {
+        format!("part-{part}::{test}")
+    } else {
+        format!("part-{part}/{target}::{test}")
+    }
+}
+
+fn test_name(name: &str) -> &str {
+    name.trim_end_matches(" - should panic")
+}
+
+/// The events for `part`, after `Started`, from how it was graded and how long its tests took, by
+/// test program and name
+pub fn events(grade: &PartGrade, durations: &BTreeMap<(String, String), Duration>) -> Vec<Event> {
+    let mut events: Vec<Event> = grade
+        .errors
+        .iter()
+        .map(|error| Event::Error {
+            part: grade.part,
+            message: error.clone(),
+        })
+        .collect();
+    events.extend(grade.results.iter().map(|result| {
+        Event::Test {
+            id: id(grade.part, &result.target, &result.test),
+            part: grade.part,
+            test: result.test.clone(),
+            status: result.status,
+            seconds: durations
+                .get(&(result.target.clone(), result.test.clone()))
+                .filter(|_| result.status != Status::Ignored)
+                .map(Duration::as_secs_f64),
+            exercise: result.exercise.clone(),
+            failure: result.failure.clone(),
+        }
+    }));
+    events.push(Event::Finished {
+        part: grade.part,
+        outcome: grade.outcome,
+        passed: grade.passed,
+        tests: grade.tests,
+        seconds: grade.seconds,
+    });
+    events
+}
+
+/// Tests `part` one test at a time, so that every test can be timed, and gives the events for it
+/// to `emit`. Also gives the report, to keep track of progress.
+pub fn test(part: &Part, mut emit: impl FnMut(Event)) -> Option<Report> {
+    emit(Event::Started { part: part.number });
+    let mut command = report::command(part, None);
+    command.args(["--", "--test-threads=1"]);
+    let mut timer = Timer::default();
+    let start = Instant::now();
+    let output = process::run_observed(command, Some(TIMEOUT), |output| {
+        timer.read(output, Instant::now())
+    });
+    let targets = match &output {
+        Ok(output) => report::targets(&output.stderr),
+        Err(_) => Vec::new(),
+    };
+    let (grade, report) = PartGrade::graded(part, output, start.elapsed());
+    for event in events(&grade, &timer.durations(&targets)) {
+        emit(event);
+    }
+    report
+}
+
+#[test]
+fn times_tests_as_they_run() {
+    let start = Instant::now();
+    let at = |millis| start + Duration::from_millis(millis);
+    let mut timer = Timer::default();
+    timer.read(b"\nrunning 3 tests\ntest sums ", at(0));
+    timer.read(b"... ", at(1));
+    timer.read(b"ok\ntest panics - should panic ... ", at(11));
+    timer.read(b"FAILED\ntest quick ... ok\n", at(16));
+    timer.read(b"test slow ... ignored\n", at(20));
+    // Another test program, with a test of the same name
+    timer.read(b"\nrunning 1 test\ntest sums ... ", at(20));
+    timer.read(b"ok\n", at(23));
+    let targets = ["src/lib.rs".to_string(), "tests/solutions.rs".to_string()];
+    let time = |target: &str, name: &str, millis| {
+        (
+            (target.to_string(), name.to_string()),
+            Duration::from_millis(millis),
+        )
+    };
+    assert_eq!(
+        timer.durations(&targets),
+        BTreeMap::from([
+            time("src/lib.rs", "sums", 10),
+            time("src/lib.rs", "panics", 5),
+            time("src/lib.rs", "quick", 0),
+            time("src/lib.rs", "slow", 0),
+            time("tests/solutions.rs", "sums", 3),
+        ])
+    );
+}
+
+#[test]
+fn turns_grades_into_events() {
+    use crate::grading::TestGrade;
+
+    let grade = PartGrade {
+        part: 4,
+        title: Some("sums".to_string()),
+        outcome: Outcome::Failing,
+        seconds: 3.5,
+        passed: 2,
+        tests: 3,
+        errors: Vec::new(),
+        results: vec![
+            TestGrade {
+                target: "src/lib.rs".to_string(),
+                test: "sums".to_string(),
+                status: Status::Failed,
+                exercise: Some("sum".to_string()),
+                failure: Some("Not implemented yet, at part-4/src/lib.rs:9:5".to_string()),
+            },
+            TestGrade {
+                target: "src/lib.rs".to_string(),
+                test: "counts".to_string(),
+                status: Status::Passed,
+                exercise: None,
+                failure: None,
+            },
+            TestGrade {
+                target: "src/lib.rs".to_string(),
+                test: "slow".to_string(),
+                status: Status::Ignored,
+                exercise: None,
+                failure: None,
+            },
+            // The same name as the first, in another test program
+            TestGrade {
+                target: "tests/solutions.rs".to_string(),
+                test: "sums".to_string(),
+                status: Status::Passed,
+                exercise: None,
+                failure: None,
+            },
+        ],
+    };
+    let time = |target: &str, name: &str, millis| {
+        (
+            (target.to_string(), name.to_string()),
+            Duration::from_millis(millis),
+        )
+    };
+    let durations = BTreeMap::from([
+        time("src/lib.rs", "sums", 250),
+        time("src/lib.rs", "slow", 0),
+        time("tests/solutions.rs", "sums", 500),
+    ]);
+    let lines: Vec<String> = events(&grade, &durations).iter().map(Event::json).collect();
+    assert_eq!(
+        lines,
+        [
+            r#"{"event":"test","id":"part-4/src/lib.rs::sums","part":4,"test":"sums","status":"failed","seconds":0.25,"exercise":"sum","failure":"Not implemented yet, at part-4/src/lib.rs:9:5"}"#,
+            r#"{"event":"test","id":"part-4/src/lib.rs::counts","part":4,"test":"counts","status":"passed"}"#,
+            r#"{"event":"test","id":"part-4/src/lib.rs::slow","part":4,"test":"slow","status":"ignored"}"#,
+            r#"{"event":"test","id":"part-4/tests/solutions.rs::sums","part":4,"test":"sums","status":"passed","seconds":0.5}"#,
+            r#"{"event":"finished","part":4,"outcome":"failing","passed":2,"tests":3,"seconds":3.5}"#,
+        ]
+    );
+}
@@ -0,0 +1,404 @@
+//! Finding the exercises which are left to implement: the functions which still have a `todo!()`
+//! or an `unimplemented!()` in them. The sources are parsed with `syn`, so that commented-out code
+//! doesn't count, and it works on code which doesn't compile yet, before any test has run.
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use syn::{
+    spanned::Spanned,
+    visit::{self, Visit},
+    Attribute, Expr, Ident, Lit, Meta,
+};
+
+use crate::{parts::Part, tiers::Tier};
+
+/// An exercise which hasn't been implemented yet: a function with a `todo!()` in it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exercise {
+    pub file: PathBuf,
+    /// Where the `todo!()` is, counting from 1
+    pub line: usize,
+    pub function: String,
+    /// The function's doc comment, without the `///`
+    pub docs: Vec<String>,
+    /// Which tier the exercise is in, if it's a bonus or advanced one
+    pub tier: Option<Tier>,
+}
+
+/// The macros which are left in the exercises for the attendees to replace
+const PLACEHOLDERS: [&str; 2] = ["todo", "unimplemented"];
+
+/// The function the visitor is in
+struct Function {
+    name: String,
+    docs: Vec<String>,
+    tier: Option<Tier>,
+}
+
+struct Finder<'a> {
+    file: &'a Path,
+    /// Innermost last, since functions can be inside functions
+    functions: Vec<Function>,
+    /// The tiers of the modules, impls and traits the visitor is in, like a module behind
+    /// `#[cfg(feature = "advanced")]`
+    tiers: Vec<Option<Tier>>,
+    exercises: Vec<Exercise>,
+}
+
+impl Finder<'_> {
+    fn tier(&self, attrs: &[Attribute]) -> Option<Tier> {
+        tier(attrs).or_else(|| self.tiers.iter().rev().find_map(|tier| *tier))
+    }
+
+    fn function(&mut self, name: &Ident, attrs: &[Attribute], visit: impl FnOnce(&mut Self)) {
+        self.functions.push(Function {
+            name: name.to_string(),
+            docs: docs(attrs),
+            tier: self.tier(attrs),
+        });
+        visit(self);
+        self.functions.pop();
+    }
+
+    fn item(&mut self, attrs: &[Attribute], visit: impl FnOnce(&mut Self)) {
+        self.tiers.push(tier(attrs));
+        visit(self);
+        self.tiers.pop();
+    }
+}
+
+impl<'ast> Visit<'ast> for Finder<'_> {
+    fn visit_item_fn(&mut self, item: &'ast syn::ItemFn) {
+        self.function(&item.sig.ident, &item.attrs, |finder| {
+            visit::visit_item_fn(finder, item)
+        });
+    }
+
+    fn visit_impl_item_fn(&mut self, item: &'ast syn::ImplItemFn) {
+        self.function(&item.sig.ident, &item.attrs, |finder| {
+            visit::visit_impl_item_fn(finder, item)
+        });
+    }
+
+    fn visit_trait_item_fn(&mut self, item: &'ast syn::TraitItemFn) {
+        self.function(&item.sig.ident, &item.attrs, |finder| {
+            visit::visit_trait_item_fn(finder, item)
+        });
+    }
+
+    fn visit_item_mod(&mut self, item: &'ast syn::ItemMod) {
+        self.item(&item.attrs, |finder| visit::visit_item_mod(finder, item));
+    }
+
+    fn visit_item_impl(&mut self, item: &'ast syn::ItemImpl) {
+        self.item(&item.attrs, |finder| visit::visit_item_impl(finder, item));
+    }
+
+    fn visit_item_trait(&mut self, item: &'ast syn::ItemTrait) {
+        self.item(&item.attrs, |finder| visit::visit_item_trait(finder, item));
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        let placeholder = PLACEHOLDERS
+            .iter()
+            .any(|placeholder| mac.path.is_ident(placeholder));
+        if let (true, Some(function)) = (placeholder, self.functions.last()) {
+            self.exercises.push(Exercise {
+                file: self.file.to_path_buf(),
+                line: mac.path.span().start().line,
+                function: function.name.clone(),
+                docs: function.docs.clone(),
+                tier: function.tier,
+            });
+        }
+        visit::visit_macro(self, mac);
+    }
+}
+
+/// The lines of a doc comment, from its `#[doc = "..."]` attributes
+fn docs(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(doc) if doc.path.is_ident("doc") => match &doc.value {
+                Expr::Lit(expr) => match &expr.lit {
+                    Lit::Str(doc) => Some(doc.value().trim().to_string()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// The tier of an item behind a feature, like `#[cfg(feature = "advanced")]`
+fn tier(attrs: &[Attribute]) -> Option<Tier> {
+    let cfgs: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .filter_map(|attr| Some(attr.meta.require_list().ok()?.tokens.to_string()))
+        .collect();
+    Tier::ALL.into_iter().rev().find(|tier| {
+        let feature = format!("feature = \"{}\"", tier.feature());
+        cfgs.iter().any(|cfg| cfg.contains(&feature))
+    })
+}
+
+/// The exercises in `source` which are left to implement
+pub fn find_in(file: &Path, source: &str) -> Vec<Exercise> {
+    let Ok(syntax) = syn::parse_file(source) else {
+        // Halfway through writing something, so it's only looked at line by line
+        return find_in_lines(file, source);
+    };
+    let mut finder = Finder {
+        file,
+        functions: Vec::new(),
+        tiers: Vec::new(),
+        exercises: Vec::new(),
+    };
+    finder.visit_file(&syntax);
+    finder.exercises
+}
+
+/// `find_in` for code which doesn't parse, going by the lines which look like placeholders and
+/// function headers
+fn find_in_lines(file: &Path, source: &str) -> Vec<Exercise> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut exercises = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        let placeholder = PLACEHOLDERS
+            .iter()
+            .any(|placeholder| line.contains(&format!("{placeholder}!(")));
+        if !placeholder || line.trim_start().starts_with("//") {
+            continue;
+        }
+        let Some((header, function)) = (0..=index)
+            .rev()
+            .find_map(|i| Some((i, function_name(lines[i])?)))
+        else {
+            continue;
+        };
+        let mut docs: Vec<String> = lines[..header]
+            .iter()
+            .rev()
+            .map(|line| line.trim())
+            .skip_while(|line| line.starts_with("#["))
+            .map_while(|line| line.strip_prefix("///"))
+            .map(|doc| doc.trim().to_string())
+            .collect();
+        docs.reverse();
+        exercises.push(Exercise {
+            file: file.to_path_buf(),
+            line: index + 1,
+            function,
+            docs,
+            tier: None,
+        });
+    }
+    exercises
+}
+
+/// The name of the function `line` starts, if it does
+fn function_name(line: &str) -> Option<String> {
+    let (_, rest) = line.split_once("fn ")?;
+    let before = &line[..line.len() - rest.len() - 3];
+    if !before
+        .split_whitespace()
+        .all(|word| ["pub", "pub(crate)", "async", "unsafe", "const"].contains(&word))
+    {
+        return None;
+    }
+    let name: String = rest
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+    (!name.is_empty()).then_some(name)
+}
+
+/// The exercises in every source file of `part` which are left to implement
+pub fn find(part: &Part) -> io::Result<Vec<Exercise>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![part.dir.join("src")];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension().is_some_and(|extension| extension == "rs") {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+
+    let workspace = part.dir.parent().unwrap_or(&part.dir);
+    let mut exercises = Vec::new();
+    for path in files {
+        let source = fs::read_to_string(&path)?;
+        let file = path.strip_prefix(workspace).unwrap_or(&path);
+        // Everything in a program which is only built with a tier's feature is in that tier
+        let program = path
+            .strip_prefix(part.dir.join("src").join("bin"))
+            .ok()
+            .and_then(|path| path.components().next())
+            .and_then(|program| Path::new(program.as_os_str()).file_stem()?.to_str())
+            .and_then(|program| part.tiers.programs.get(program));
+        exercises.extend(find_in(file, &source).into_iter().map(|exercise| Exercise {
+            tier: exercise.tier.or(program.copied()),
+            ..exercise
+        }));
+    }
+    Ok(exercises)
+}
+
+/// What `workshop status <part>` shows: every exercise left in a part, and where it is
+pub struct Remaining<'a> {
+    pub part: &'a Part,
+    pub exercises: &'a [Exercise],
+}
+
+impl fmt::Display for Remaining<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.exercises.len() {
+            0 => return writeln!(f, "{}: every exercise has been implemented", self.part),
+            1 => writeln!(f, "{}: 1 exercise left", self.part)?,
+            left => writeln!(f, "{}: {left} exercises left", self.part)?,
+        }
+        for exercise in self.exercises {
+            write!(
+                f,
+                "  {}:{} `{}`",
+                exercise.file.display(),
+                exercise.line,
+                exercise.function
+            )?;
+            match exercise.tier {
+                Some(tier) => writeln!(f, ", one of the {tier} exercises")?,
+                None => writeln!(f)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn finds_unimplemented_exercises() {
+    let source = r#"
+/// The sum of `numbers`,
+/// on another thread
+#[must_use]
+pub fn sum(numbers: &[u64]) -> u64 {
+    todo!()
+}
+
+/// The same, but lock-free
+#[cfg(feature = "advanced")]
+pub fn lock_free_sum(numbers: &[u64]) -> u64 {
+    todo!()
+}
+
+impl Counter {
+    pub(crate) async fn count(&self) {
+        let _ = "todo!()";
+        unimplemented!()
+    }
+
+    // todo!() is done here
+    fn done(&self) {}
+}
+
+#[cfg(feature = "bonus")]
+mod bonus {
+    trait Steps {
+        fn step(&self) -> u32 {
+            let closure = || todo!("the step");
+            closure()
+        }
+    }
+}
+"#;
+    let exercises = find_in(Path::new("part-1/src/lib.rs"), source);
+    let found: Vec<_> = exercises
+        .iter()
+        .map(|exercise| (exercise.function.as_str(), exercise.line, exercise.tier))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            ("sum", 6, None),
+            ("lock_free_sum", 12, Some(Tier::Advanced)),
+            ("count", 18, None),
+            ("step", 29, Some(Tier::Bonus))
+        ]
+    );
+    assert_eq!(
+        exercises[0].docs,
+        ["The sum of `numbers`,", "on another thread"]
+    );
+    assert_eq!(exercises[1].docs, ["The same, but lock-free"]);
+    assert!(exercises[2].docs.is_empty());
+}
+
+#[test]
+fn finds_exercises_in_code_which_does_not_parse() {
+    let source = "
+/// Halfway there
+pub fn sum(numbers: &[u64]) -> u64 {
+    let total = numbers.iter().
+    todo!()
+}
+";
+    let exercises = find_in(Path::new("part-1/src/lib.rs"), source);
+    assert_eq!(exercises.len(), 1);
+    assert_eq!(
+        (exercises[0].function.as_str(), exercises[0].line),
+        ("sum", 5)
+    );
+    assert_eq!(exercises[0].docs, ["Halfway there"]);
+}
+
+#[test]
+fn finds_the_tiers_of_programs() {
+    let parts = crate::parts::discover(&crate::parts::workspace()).unwrap();
+    let exercises = find(&parts[0]).unwrap();
+    let scoped = exercises
+        .iter()
+        .find(|exercise| exercise.function == "sum_on_thread")
+        .unwrap();
+    assert_eq!(scoped.tier, Some(Tier::Bonus));
+    assert!(exercises
+        .iter()
+        .filter(|exercise| exercise.file.ends_with("src/main.rs"))
+        .all(|exercise| exercise.tier.is_none()));
+}
+
+#[test]
+fn lists_what_is_left() {
+    let parts = crate::parts::discover(&crate::parts::workspace()).unwrap();
+    let exercises = find_in(
+        Path::new("part-17/src/lib.rs"),
+        "pub fn steal() {\n    todo!()\n}\n#[cfg(feature = \"advanced\")]\nfn run_crossbeam() {\n    todo!()\n}\n",
+    );
+    let remaining = Remaining {
+        part: &parts[16],
+        exercises: &exercises,
+    };
+    assert_eq!(
+        remaining.to_string(),
+        "Part 17: work stealing: 2 exercises left\n  \
+         part-17/src/lib.rs:2 `steal`\n  \
+         part-17/src/lib.rs:6 `run_crossbeam`, one of the advanced exercises\n"
+    );
+    let done = Remaining {
+        part: &parts[16],
+        exercises: &[],
+    };
+    assert_eq!(
+        done.to_string(),
+        "Part 17: work stealing: every exercise has been implemented\n"
+    );
+}
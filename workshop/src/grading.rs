@@ -0,0 +1,457 @@
+//! `workshop report`: how every part went, as JSON or Markdown, for instructors to collect from
+//! attendees at the end of a session
+
+use std::{
+    fmt::{self, Write},
+    io,
+    str::FromStr,
+    time::{Duration, Instant, SystemTime},
+};
+
+use serde::Serialize;
+
+use crate::{
+    exercises::{self, Exercise},
+    parts::Part,
+    process,
+    report::{self, Report, Status, TestResult},
+};
+
+/// Long enough for a part's tests, unless one of them is stuck, like on an exercise that's half done
+pub const TIMEOUT: Duration = Duration::from_secs(180);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Markdown,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "json" => Ok(Self::Json),
+            "markdown" | "md" => Ok(Self::Markdown),
+            _ => Err(format!(
+                "There's no report format {format}, pick json or markdown"
+            )),
+        }
+    }
+}
+
+/// How a part went when it was graded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Outcome {
+    Passing,
+    Failing,
+    DoesNotCompile,
+    /// A test was still running after the timeout
+    TimedOut,
+    /// Cargo couldn't be run at all
+    Broken,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Passing => "✓ passing",
+            Self::Failing => "✗ failing",
+            Self::DoesNotCompile => "✗ doesn't compile",
+            Self::TimedOut => "✗ timed out",
+            Self::Broken => "✗ couldn't run",
+        })
+    }
+}
+
+/// How one test went
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TestGrade {
+    /// The test program it's in, see `TestResult::target`
+    pub target: String,
+    pub test: String,
+    pub status: Status,
+    /// The exercise the test failed on, when it failed on a `todo!()`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exercise: Option<String>,
+    /// The failing assertion or panic message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure: Option<String>,
+}
+
+/// How one part went
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PartGrade {
+    pub part: u32,
+    pub title: Option<String>,
+    pub outcome: Outcome,
+    /// How long building and testing the part took. The test harness doesn't time single tests.
+    pub seconds: f64,
+    pub passed: usize,
+    /// How many tests ran, not counting ignored ones
+    pub tests: usize,
+    /// The compiler's errors, or why cargo couldn't be run
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+    pub results: Vec<TestGrade>,
+}
+
+/// The exercise `result` failed on, from where its `todo!()` is
+fn exercise(result: &TestResult, exercises: &[Exercise]) -> Option<String> {
+    if !result.is_unimplemented() {
+        return None;
+    }
+    let (_, location) = result.reason.as_deref()?.rsplit_once(", at ")?;
+    let mut location = location.rsplitn(3, ':');
+    let (_column, line, file) = (location.next()?, location.next()?, location.next()?);
+    let line: usize = line.parse().ok()?;
+    exercises
+        .iter()
+        .find(|exercise| exercise.line == line && exercise.file.to_str() == Some(file))
+        .map(|exercise| exercise.function.clone())
+}
+
+impl PartGrade {
+    /// Grades `part` from what testing it found. `stuck` are the tests still running when it timed
+    /// out, by the test program they're in and name.
+    pub fn new(
+        part: &Part,
+        report: &Report,
+        stuck: Option<Vec<(String, String)>>,
+        elapsed: Duration,
+        exercises: &[Exercise],
+    ) -> Self {
+        let mut results: Vec<TestGrade> = report
+            .results()
+            .iter()
+            .map(|result| TestGrade {
+                target: result.target.clone(),
+                test: result.name.clone(),
+                status: result.status,
+                exercise: exercise(result, exercises),
+                failure: result.reason.clone(),
+            })
+            .collect();
+        let timed_out = stuck.is_some();
+        results.extend(stuck.into_iter().flatten().map(|(target, test)| TestGrade {
+            target,
+            test,
+            status: Status::Failed,
+            exercise: None,
+            failure: Some(format!(
+                "Still running after {}",
+                humantime::format_duration(TIMEOUT)
+            )),
+        }));
+        let outcome = match report {
+            Report::DoesNotCompile(_) => Outcome::DoesNotCompile,
+            _ if timed_out => Outcome::TimedOut,
+            _ if report.passed() => Outcome::Passing,
+            _ => Outcome::Failing,
+        };
+        let errors = match report {
+            Report::DoesNotCompile(errors) => errors.clone(),
+            Report::Tested(_) => Vec::new(),
+        };
+        PartGrade {
+            part: part.number,
+            title: part.title.clone(),
+            outcome,
+            seconds: elapsed.as_secs_f64(),
+            passed: results
+                .iter()
+                .filter(|result| result.status == Status::Passed)
+                .count(),
+            tests: results
+                .iter()
+                .filter(|result| result.status != Status::Ignored)
+                .count(),
+            errors,
+            results,
+        }
+    }
+
+    /// Tests `part`, stopping it after `TIMEOUT`. Also gives the report, to keep track of progress.
+    pub fn test(part: &Part) -> (Self, Option<Report>) {
+        let start = Instant::now();
+        let output = process::run(report::command(part, None), Some(TIMEOUT));
+        Self::graded(part, output, start.elapsed())
+    }
+
+    /// Grades `part` from how running its tests went, which took `elapsed`
+    pub fn graded(
+        part: &Part,
+        output: io::Result<process::Output>,
+        elapsed: Duration,
+    ) -> (Self, Option<Report>) {
+        let output = match output {
+            Ok(output) => output,
+            Err(error) => return (Self::broken(part, &error.to_string(), elapsed), None),
+        };
+        let report = Report::parse(&output.stdout, &output.stderr);
+        if !output.success && !output.timed_out && report.passed() {
+            // Something went wrong which wasn't a test or the code
+            let error = output
+                .stderr
+                .lines()
+                .find(|line| line.starts_with("error"))
+                .unwrap_or("cargo failed");
+            return (Self::broken(part, error, elapsed), None);
+        }
+        let exercises = exercises::find(part).unwrap_or_default();
+        let stuck = output.timed_out.then(|| {
+            // Cargo runs one test program at a time, so they're all in the last one it started
+            let target = report::targets(&output.stderr).pop().unwrap_or_default();
+            output
+                .stuck_tests()
+                .into_iter()
+                .map(|test| (target.clone(), test))
+                .collect()
+        });
+        (
+            Self::new(part, &report, stuck, elapsed, &exercises),
+            Some(report),
+        )
+    }
+
+    fn broken(part: &Part, error: &str, elapsed: Duration) -> Self {
+        PartGrade {
+            part: part.number,
+            title: part.title.clone(),
+            outcome: Outcome::Broken,
+            seconds: elapsed.as_secs_f64(),
+            passed: 0,
+            tests: 0,
+            errors: vec![error.to_string()],
+            results: Vec::new(),
+        }
+    }
+
+    fn heading(&self) -> String {
+        match &self.title {
+            Some(title) => format!("Part {}: {title}", self.part),
+            None => format!("Part {}", self.part),
+        }
+    }
+}
+
+/// How every part went
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Grades {
+    /// When the tests ran, in RFC 3339
+    pub generated: String,
+    pub parts: Vec<PartGrade>,
+}
+
+impl Grades {
+    pub fn new(parts: Vec<PartGrade>, now: SystemTime) -> Self {
+        Grades {
+            generated: humantime::format_rfc3339_seconds(now).to_string(),
+            parts,
+        }
+    }
+
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Json => {
+                let mut json =
+                    serde_json::to_string_pretty(self).expect("Grades can always be JSON");
+                json.push('\n');
+                json
+            }
+            Format::Markdown => self.markdown(),
+        }
+    }
+
+    fn markdown(&self) -> String {
+        let passing = self
+            .parts
+            .iter()
+            .filter(|part| part.outcome == Outcome::Passing)
+            .count();
+        let mut markdown = String::new();
+        // Writing to a `String` can't fail
+        let _ = writeln!(markdown, "# Workshop report\n");
+        let _ = writeln!(
+            markdown,
+            "{passing} of {} parts passing, tested at {}.\n",
+            self.parts.len(),
+            self.generated
+        );
+        let _ = writeln!(markdown, "| Part | Outcome | Tests passed | Took |");
+        let _ = writeln!(markdown, "| --- | --- | --- | --- |");
+        for part in &self.parts {
+            let _ = writeln!(
+                markdown,
+                "| {} | {} | {} of {} | {:.1}s |",
+                part.heading().replace('|', "\\|"),
+                part.outcome,
+                part.passed,
+                part.tests,
+                part.seconds
+            );
+        }
+
+        for part in &self.parts {
+            let failures: Vec<_> = part
+                .results
+                .iter()
+                .filter(|result| result.status == Status::Failed)
+                .collect();
+            if part.errors.is_empty() && failures.is_empty() {
+                continue;
+            }
+            let _ = writeln!(markdown, "\n## {}\n", part.heading());
+            for error in &part.errors {
+                let _ = writeln!(markdown, "- `{error}`");
+            }
+            for failure in failures {
+                match &failure.exercise {
+                    Some(exercise) => {
+                        let _ = writeln!(
+                            markdown,
+                            "- `{}`: `{exercise}` isn't implemented yet",
+                            failure.test
+                        );
+                    }
+                    None => {
+                        let _ = writeln!(markdown, "- `{}`", failure.test);
+                        let reason = failure.failure.as_deref().unwrap_or("failed");
+                        let _ = writeln!(markdown, "  ```");
+                        for line in reason.lines() {
+                            let _ = writeln!(markdown, "  {line}");
+                        }
+                        let _ = writeln!(markdown, "  ```");
+                    }
+                }
+            }
+        }
+        markdown
+    }
+}
+
+#[cfg(test)]
+fn graded() -> Grades {
+    use std::path::PathBuf;
+
+    let part = |number, title: &str| Part {
+        number,
+        title: Some(title.to_string()),
+        dir: PathBuf::from(format!("part-{number}")),
+        binaries: Vec::new(),
+        tiers: Default::default(),
+    };
+    let result = |name: &str, status, reason: Option<&str>| TestResult {
+        target: "src/lib.rs".to_string(),
+        name: name.to_string(),
+        status,
+        reason: reason.map(str::to_string),
+    };
+    let exercises = [Exercise {
+        file: PathBuf::from("part-2/src/lib.rs"),
+        line: 12,
+        function: "transfer".to_string(),
+        docs: Vec::new(),
+        tier: None,
+    }];
+    let second = Report::Tested(vec![
+        result("deposits", Status::Passed, None),
+        result(
+            "transfers",
+            Status::Failed,
+            Some("Not implemented yet, at part-2/src/lib.rs:12:5"),
+        ),
+        result(
+            "withdraws",
+            Status::Failed,
+            Some("assertion `left == right` failed\n  left: 1\n right: 2"),
+        ),
+        result("slow", Status::Ignored, None),
+    ]);
+    Grades::new(
+        vec![
+            PartGrade::new(
+                &part(1, "threads"),
+                &Report::Tested(vec![result("spawns", Status::Passed, None)]),
+                None,
+                Duration::from_millis(1500),
+                &[],
+            ),
+            PartGrade::new(
+                &part(2, "banks"),
+                &second,
+                None,
+                Duration::from_secs(2),
+                &exercises,
+            ),
+            PartGrade::new(
+                &part(3, "channels"),
+                &Report::Tested(vec![result("sends", Status::Passed, None)]),
+                Some(vec![("src/lib.rs".to_string(), "receives".to_string())]),
+                TIMEOUT,
+                &[],
+            ),
+        ],
+        SystemTime::UNIX_EPOCH + Duration::from_secs(1_800_000_000),
+    )
+}
+
+#[test]
+fn grades_every_test() {
+    let grades = graded();
+    let outcomes: Vec<_> = grades.parts.iter().map(|part| part.outcome).collect();
+    assert_eq!(
+        outcomes,
+        [Outcome::Passing, Outcome::Failing, Outcome::TimedOut]
+    );
+    let second = &grades.parts[1];
+    assert_eq!((second.passed, second.tests), (1, 3));
+    assert_eq!(second.results[1].exercise.as_deref(), Some("transfer"));
+    assert_eq!(second.results[2].exercise, None);
+    let third = &grades.parts[2];
+    assert_eq!((third.passed, third.tests), (1, 2));
+    assert_eq!(
+        third.results[1].failure.as_deref(),
+        Some("Still running after 3m")
+    );
+}
+
+#[test]
+fn renders_json() {
+    let json: serde_json::Value = serde_json::from_str(&graded().render(Format::Json)).unwrap();
+    assert_eq!(json["generated"], "2027-01-15T08:00:00Z");
+    let second = &json["parts"][1];
+    assert_eq!(second["part"], 2);
+    assert_eq!(second["outcome"], "failing");
+    assert_eq!(second["seconds"], 2.0);
+    assert_eq!(second["results"][1]["status"], "failed");
+    assert_eq!(second["results"][1]["exercise"], "transfer");
+    assert_eq!(
+        second["results"][2]["failure"],
+        "assertion `left == right` failed\n  left: 1\n right: 2"
+    );
+    assert!(second["results"][0].get("failure").is_none());
+    assert!(second.get("errors").is_none());
+}
+
+#[test]
+fn renders_markdown() {
+    let markdown = graded().render(Format::Markdown);
+    assert!(markdown.contains("1 of 3 parts passing, tested at 2027-01-15T08:00:00Z."));
+    assert!(markdown.contains("| Part 1: threads | ✓ passing | 1 of 1 | 1.5s |"));
+    assert!(markdown.contains("| Part 3: channels | ✗ timed out | 1 of 2 | 180.0s |"));
+    assert!(!markdown.contains("## Part 1"));
+    assert!(markdown.contains(
+        "## Part 2: banks\n\n\
+         - `transfers`: `transfer` isn't implemented yet\n\
+         - `withdraws`\n  ```\n  assertion `left == right` failed\n    left: 1\n   right: 2\n  ```\n"
+    ));
+}
+
+#[test]
+fn reads_formats() {
+    assert_eq!("json".parse(), Ok(Format::Json));
+    assert_eq!("markdown".parse(), Ok(Format::Markdown));
+    assert!("csv".parse::<Format>().is_err());
+}
@@ -0,0 +1,160 @@
+//! Hints for the exercises, from less to more specific.
+//! They come from the exercises' own doc comments, and from the README, which is compiled in.
+
+use std::fmt;
+
+use crate::{exercises::Exercise, parts::Part};
+
+const README: &str = include_str!("../../README.md");
+
+/// How many levels of hints there are
+pub const LEVELS: usize = 3;
+
+/// What the README says about part `number`: from its heading to the next part's
+fn section(readme: &str, number: u32) -> Option<&str> {
+    let heading = format!("## Part {number}: ");
+    let start = readme.find(&heading)?;
+    let rest = &readme[start + heading.len()..];
+    let end = rest.find("\n## ").unwrap_or(rest.len());
+    Some(&rest[..end])
+}
+
+/// The part's tips, like the ones in `> [!TIP]` blocks in the README
+pub fn tips(number: u32) -> Vec<String> {
+    let Some(section) = section(README, number) else {
+        return Vec::new();
+    };
+    let mut tips = Vec::new();
+    let mut lines = section.lines();
+    while let Some(line) = lines.next() {
+        if line.trim() == "> [!TIP]" {
+            let tip: Vec<&str> = lines
+                .by_ref()
+                .map_while(|line| line.strip_prefix('>'))
+                .map(str::trim)
+                .collect();
+            tips.push(tip.join("\n"));
+        }
+    }
+    tips
+}
+
+/// How the part's solution works, from the explanations after the solutions in the README, without their code
+pub fn explanation(number: u32) -> Vec<String> {
+    let Some(section) = section(README, number) else {
+        return Vec::new();
+    };
+    let mut paragraphs = Vec::new();
+    for solution in section.split("<details>").skip(1) {
+        let solution = solution.split("</details>").next().unwrap_or(solution);
+        let mut in_code = false;
+        let mut paragraph = Vec::new();
+        for line in solution.lines() {
+            if line.trim_start().starts_with("```") {
+                in_code = !in_code;
+                continue;
+            }
+            if in_code || line.trim().starts_with('<') || line.trim() == "Solution" {
+                continue;
+            }
+            if line.trim().is_empty() {
+                if !paragraph.is_empty() {
+                    paragraphs.push(paragraph.join("\n"));
+                    paragraph.clear();
+                }
+            } else {
+                paragraph.push(line.trim());
+            }
+        }
+        if !paragraph.is_empty() {
+            paragraphs.push(paragraph.join("\n"));
+        }
+    }
+    // Without their code, the paragraphs which lead up to it don't make sense
+    paragraphs.retain(|paragraph| !paragraph.ends_with(':'));
+    paragraphs
+}
+
+/// The hint at `level`, from 1 to `LEVELS`, for `part`
+pub struct Hint<'a> {
+    pub part: &'a Part,
+    pub level: usize,
+    pub exercises: Vec<Exercise>,
+}
+
+impl fmt::Display for Hint<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Hint {} of {LEVELS} for {}\n", self.level, self.part)?;
+        let paragraphs = match self.level {
+            1 => {
+                if self.exercises.is_empty() {
+                    writeln!(f, "Every exercise has been implemented.")?;
+                }
+                for exercise in &self.exercises {
+                    write!(
+                        f,
+                        "`{}`, at {}:{}",
+                        exercise.function,
+                        exercise.file.display(),
+                        exercise.line
+                    )?;
+                    match exercise.tier {
+                        Some(tier) => writeln!(f, ", one of the {tier} exercises")?,
+                        None => writeln!(f)?,
+                    }
+                    for doc in &exercise.docs {
+                        writeln!(f, "    {doc}")?;
+                    }
+                }
+                Vec::new()
+            }
+            2 => tips(self.part.number),
+            _ => explanation(self.part.number),
+        };
+        if self.level > 1 && paragraphs.is_empty() {
+            writeln!(f, "There are no hints like this for this part.")?;
+        }
+        if !paragraphs.is_empty() {
+            writeln!(f, "{}", paragraphs.join("\n\n"))?;
+        }
+
+        if self.level < LEVELS {
+            writeln!(
+                f,
+                "\nFor a more specific hint, run `cargo run -p workshop -- hint {} {}`",
+                self.part.number,
+                self.level + 1
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn reads_hints_from_the_readme() {
+    let tips = tips(4);
+    assert!(!tips.is_empty());
+    assert!(tips
+        .iter()
+        .all(|tip| !tip.is_empty() && !tip.contains("[!TIP]")));
+
+    let explanation = explanation(55);
+    assert!(explanation[0].starts_with("The accounts are numbered"));
+    assert!(
+        explanation
+            .iter()
+            .all(|paragraph| !paragraph.contains("```") && !paragraph.contains("fn transfer")),
+        "The solution's code ended up in the explanation"
+    );
+}
+
+#[test]
+fn every_part_has_hints() {
+    let parts = crate::parts::discover(&crate::parts::workspace()).unwrap();
+    for part in &parts {
+        assert!(
+            !tips(part.number).is_empty() || !explanation(part.number).is_empty(),
+            "{part} has no hints"
+        );
+    }
+}
@@ -0,0 +1,30 @@
+//! Finding, testing and keeping track of the workshop's parts.
+//! Used by the runner in `main.rs`, and by `xtask` to verify the whole workshop.
+
+pub mod benchmarks;
+pub mod dependencies;
+pub mod events;
+pub mod exercises;
+pub mod grading;
+pub mod hints;
+pub mod parts;
+pub mod prerequisites;
+pub mod process;
+pub mod profile;
+pub mod progress;
+pub mod quiz;
+pub mod report;
+pub mod sanitize;
+pub mod scaffold;
+pub mod scores;
+pub mod tiers;
+pub mod watch;
+
+use std::{env, process::Command};
+
+/// The cargo this was run with, or whichever is on the path, in the workspace
+pub fn cargo() -> Command {
+    let mut command = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+    command.current_dir(parts::workspace());
+    command
+}
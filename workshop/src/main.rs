@@ -0,0 +1,594 @@
+use std::{
+    env, fs,
+    io::{self, IsTerminal},
+    process::ExitCode,
+    time::{Duration, SystemTime},
+};
+
+use workshop::{
+    benchmarks::{self, Machine, Results},
+    cargo,
+    dependencies::Graph,
+    events, exercises,
+    grading::{Format, Grades, PartGrade, TIMEOUT},
+    hints,
+    parts::{self, Part},
+    prerequisites::Prerequisites,
+    profile,
+    progress::{self, Progress},
+    quiz, report,
+    sanitize::{self, Sanitizer},
+    scaffold::NewPart,
+    scores::{self, Scores},
+    tiers::Tier,
+    watch,
+};
+
+const USAGE: &str = "\
+Usage: cargo run -p workshop -- <command>
+
+Commands:
+  list                             Lists every part of the workshop
+  check <part>                     Runs a part's tests, and shows which pass
+  check <part> --sanitize=thread   Runs a part's tests under ThreadSanitizer on nightly, to find data races
+  check <part> --bonus|--advanced  Runs a part's tests along with those of its bonus or advanced exercises
+  check <part> --skip-prerequisites
+                                   Runs a part's tests even though a part it builds on still fails
+  check <part> --budget=<time>     Stops a part's tests once they've taken longer than <time>, like 30s, 3m by default
+  status [part]                    Shows how far along every part is, or which exercises are left in one
+  report [--format json|markdown]  Tests every part, and writes how each went, to hand in at the end
+  events [part]                    Tests every part, or one, and prints every test's outcome as a line of JSON
+  watch                            Shows every part, and runs a part's tests again whenever it changes
+  hint <part> [level]              Gives a hint for a part, which gets more specific for higher levels, up to 3
+  quiz [part]                      Asks questions about the parts, or one of them, to see what stuck
+  run <part> [program] [-- ...]    Runs a part, or one of its programs, with optimizations
+  profile <part> <exercise>        Runs the tests with <exercise> in their names under `perf`, and draws a flamegraph
+  bench [--csv <file>] [--json <file>] [--score <name>]
+                                   Runs the benchmarks of several parts on several sizes, and compares them
+  scores                           Shows the leaderboard of the fastest times `bench --score` recorded
+  new-part <title>                 Sets up the next part, for adding one to the workshop
+";
+
+/// Run with `cargo run -p workshop -- check 4` to test part 4, or without a command to see the others
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let workspace = parts::workspace();
+    let parts = match parts::discover(&workspace) {
+        Ok(parts) => parts,
+        Err(error) => {
+            eprintln!(
+                "Couldn't find the parts in {}: {error}",
+                workspace.display()
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let part = |name: &str| {
+        let part = parts::find(&parts, name);
+        if part.is_none() {
+            eprintln!("There's no part {name}, see `cargo run -p workshop -- list`");
+        }
+        part
+    };
+    match args.as_slice() {
+        ["list"] => {
+            list(&parts);
+            ExitCode::SUCCESS
+        }
+        ["check", name, options @ ..] => {
+            part(name).map_or(ExitCode::FAILURE, |part| check_with(&parts, part, options))
+        }
+        ["status"] => status(&parts),
+        ["status", name] => part(name).map_or(ExitCode::FAILURE, remaining),
+        ["report"] => report(&parts, "markdown"),
+        ["report", "--format", format] => report(&parts, format),
+        ["events"] => emit_events(&parts),
+        ["events", name] => part(name).map_or(ExitCode::FAILURE, |part| {
+            emit_events(std::slice::from_ref(part))
+        }),
+        ["watch"] => watch(parts),
+        ["hint", name] => part(name).map_or(ExitCode::FAILURE, |part| hint(part, "1")),
+        ["hint", name, level] => part(name).map_or(ExitCode::FAILURE, |part| hint(part, level)),
+        ["quiz"] => quiz(None),
+        ["quiz", name] => part(name).map_or(ExitCode::FAILURE, |part| quiz(Some(part))),
+        ["run", name, rest @ ..] => part(name).map_or(ExitCode::FAILURE, |part| run(part, rest)),
+        ["profile", name, exercise] => {
+            part(name).map_or(ExitCode::FAILURE, |part| profile(part, exercise))
+        }
+        ["bench", options @ ..] => bench(&parts, options),
+        ["scores"] => show_scores(),
+        ["new-part", title @ ..] if !title.is_empty() => new_part(&parts, &title.join(" ")),
+        _ => {
+            eprint!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn list(parts: &[Part]) {
+    for part in parts {
+        println!("{part}");
+        let programs: Vec<&str> = part
+            .binaries
+            .iter()
+            .filter(|binary| !part.tiers.programs.contains_key(*binary))
+            .map(String::as_str)
+            .collect();
+        if !programs.is_empty() {
+            println!("    programs: {}", programs.join(", "));
+        }
+        for tier in &part.tiers.tiers {
+            println!(
+                "    {tier} exercises, checked with `check {} --{tier}`",
+                part.number
+            );
+            let programs: Vec<&str> = part
+                .tiers
+                .programs
+                .iter()
+                .filter(|(_, program_tier)| *program_tier == tier)
+                .map(|(program, _)| program.as_str())
+                .collect();
+            if !programs.is_empty() {
+                println!("    {tier} programs: {}", programs.join(", "));
+            }
+        }
+    }
+}
+
+/// `check <part>` with any of its options, after making sure the parts it builds on pass
+fn check_with(parts: &[Part], part: &Part, options: &[&str]) -> ExitCode {
+    let (mut sanitizer, mut tier, mut skip_prerequisites) = (None, None, false);
+    let mut budget = TIMEOUT;
+    for option in options {
+        if *option == "--skip-prerequisites" {
+            skip_prerequisites = true;
+        } else if let Some(time) = option.strip_prefix("--budget=") {
+            match humantime::parse_duration(time) {
+                Ok(time) => budget = time,
+                Err(error) => {
+                    eprintln!("Couldn't read the budget {time}: {error}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        } else if let Some(name) = option.strip_prefix("--sanitize=") {
+            sanitizer = Some(name);
+        } else if let Some(Ok(option)) = option.strip_prefix("--").map(str::parse::<Tier>) {
+            tier = Some(option);
+        } else {
+            eprint!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    }
+    if !skip_prerequisites && !prerequisites_pass(parts, part) {
+        return ExitCode::FAILURE;
+    }
+    match (sanitizer, tier) {
+        (None, tier) => check(part, tier, budget),
+        (Some(sanitizer), None) => check_sanitized(part, sanitizer),
+        (Some(_), Some(tier)) => {
+            eprintln!("The sanitizers only run a part's own tests, without its {tier} exercises");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Warns about the parts `part` builds on which haven't been checked, and refuses when some of them
+/// fail, from what `check` recorded. When that can't be found out, it doesn't get in the way.
+fn prerequisites_pass(parts: &[Part], part: &Part) -> bool {
+    let workspace = parts::workspace();
+    let loaded = Graph::load(&workspace)
+        .and_then(|graph| Ok((graph, Progress::load(&progress::file(&workspace))?)));
+    match loaded {
+        Ok((graph, progress)) => {
+            let prerequisites = Prerequisites::of(part, parts, &graph, &progress);
+            eprint!("{prerequisites}");
+            !prerequisites.block()
+        }
+        Err(error) => {
+            eprintln!("Couldn't find out whether the parts {part} builds on pass: {error}");
+            true
+        }
+    }
+}
+
+fn check(part: &Part, tier: Option<Tier>, budget: Duration) -> ExitCode {
+    if let Some(tier) = tier {
+        if part.features(tier).is_empty() {
+            eprintln!("{part} has no {tier} exercises, see `cargo run -p workshop -- list`");
+            return ExitCode::FAILURE;
+        }
+        println!("Testing {part}, with its {tier} exercises");
+    } else {
+        println!("Testing {part}");
+    }
+    let (report, output, exceeded) = match report::test_within(part, tier, budget) {
+        Ok(tested) => tested,
+        Err(error) => {
+            eprintln!("Couldn't run cargo: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    print!("{report}");
+    if let Some(exceeded) = exceeded {
+        // The tests which didn't get to finish may well pass, so the progress isn't saved
+        println!(
+            "✗ Stopped after {}, with {} tests finished",
+            humantime::format_duration(exceeded.budget),
+            exceeded.completed
+        );
+        for test in output.stuck_tests() {
+            println!("    {test} was still running");
+        }
+        println!("Add --budget=<time> to give them longer");
+        return ExitCode::FAILURE;
+    }
+    if let Err(error) = progress::record(part, tier, &report) {
+        eprintln!("Couldn't save the progress: {error}");
+    }
+    if !output.success && report.passed() {
+        // Something went wrong which wasn't a test or the code, so show everything cargo said
+        eprint!("{}", output.stderr);
+        return ExitCode::FAILURE;
+    }
+    if report.passed() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Doesn't save the progress, since the tests do less work under a sanitizer
+fn check_sanitized(part: &Part, sanitizer: &str) -> ExitCode {
+    let sanitizer: Sanitizer = match sanitizer.parse() {
+        Ok(sanitizer) => sanitizer,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    println!(
+        "Testing {part} with the {} sanitizer, which takes a while the first time, \
+        since it builds the standard library too",
+        sanitizer.name()
+    );
+    let sanitized = match sanitize::test(part, sanitizer) {
+        Ok(sanitized) => sanitized,
+        Err(error) => {
+            eprintln!("Couldn't run cargo on nightly: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    print!("{}", sanitized.report);
+    if !sanitized.races.is_empty() {
+        println!(
+            "✗ ThreadSanitizer found {} data race{}:",
+            sanitized.races.len(),
+            if sanitized.races.len() == 1 { "" } else { "s" }
+        );
+        for race in &sanitized.races {
+            println!("    {race}");
+        }
+        return ExitCode::FAILURE;
+    }
+    if !sanitized.success && sanitized.report.passed() {
+        match sanitize::setup_problem(&sanitized.stderr) {
+            Some(problem) => eprintln!("{problem}"),
+            None => eprint!("{}", sanitized.stderr),
+        }
+        return ExitCode::FAILURE;
+    }
+    if sanitized.report.passed() {
+        println!("✓ ThreadSanitizer found no data races");
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// From what `check` found, and from which exercises are left in the code
+fn status(parts: &[Part]) -> ExitCode {
+    match Progress::load(&progress::file(&parts::workspace())) {
+        Ok(progress) => {
+            let mut overview = progress.overview(parts, SystemTime::now());
+            for part in parts {
+                // A part which can't be read just doesn't get a count
+                if let Ok(exercises) = exercises::find(part) {
+                    overview.left(part, &exercises);
+                }
+            }
+            print!("{overview}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Couldn't read the progress: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn remaining(part: &Part) -> ExitCode {
+    match exercises::find(part) {
+        Ok(exercises) => {
+            print!(
+                "{}",
+                exercises::Remaining {
+                    part,
+                    exercises: &exercises
+                }
+            );
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Couldn't read {}: {error}", part.dir.display());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Writes the report to stdout, so that it can go straight into a file, and what's going on to stderr
+fn report(parts: &[Part], format: &str) -> ExitCode {
+    let format: Format = match format.parse() {
+        Ok(format) => format,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut grades = Vec::new();
+    for part in parts {
+        eprintln!("Testing {part}");
+        let (grade, report) = PartGrade::test(part);
+        if let Some(report) = report {
+            if let Err(error) = progress::record(part, None, &report) {
+                eprintln!("Couldn't save the progress: {error}");
+            }
+        }
+        grades.push(grade);
+    }
+    print!("{}", Grades::new(grades, SystemTime::now()).render(format));
+    ExitCode::SUCCESS
+}
+
+/// Writes the events to stdout as they happen, one per line, for grading tools to read
+fn emit_events(parts: &[Part]) -> ExitCode {
+    for part in parts {
+        let report = events::test(part, |event| println!("{}", event.json()));
+        if let Some(report) = report {
+            if let Err(error) = progress::record(part, None, &report) {
+                eprintln!("Couldn't save the progress: {error}");
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn watch(parts: Vec<Part>) -> ExitCode {
+    match watch::run(parts) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Couldn't watch the parts: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn hint(part: &Part, level: &str) -> ExitCode {
+    let level = match level.parse() {
+        Ok(level) if (1..=hints::LEVELS).contains(&level) => level,
+        _ => {
+            eprintln!("The hint level must be from 1 to {}", hints::LEVELS);
+            return ExitCode::FAILURE;
+        }
+    };
+    let exercises = match exercises::find(part) {
+        Ok(exercises) => exercises,
+        Err(error) => {
+            eprintln!("Couldn't read {}: {error}", part.dir.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    print!(
+        "{}",
+        hints::Hint {
+            part,
+            level,
+            exercises
+        }
+    );
+    ExitCode::SUCCESS
+}
+
+fn profile(part: &Part, exercise: &str) -> ExitCode {
+    println!("Profiling the tests of {part} with {exercise} in their names");
+    match profile::profile(part, exercise) {
+        Ok(file) => {
+            println!("Open {} in a browser to see the flamegraph", file.display());
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Couldn't profile {part}: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Runs every benchmark in `benchmarks::BENCHMARKS`, and saves the results to the files in `options`,
+/// and to the leaderboard under the name after `--score`
+fn bench(parts: &[Part], options: &[&str]) -> ExitCode {
+    let (mut csv, mut json, mut score) = (None, None, None);
+    let mut options = options.iter();
+    while let Some(option) = options.next() {
+        match (*option, options.next()) {
+            ("--csv", Some(file)) => csv = Some(*file),
+            ("--json", Some(file)) => json = Some(*file),
+            ("--score", Some(name)) if !name.trim().is_empty() => score = Some(name.trim()),
+            _ => {
+                eprint!("{USAGE}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let mut measured = Vec::new();
+    for benchmark in benchmarks::BENCHMARKS {
+        let Some(part) = parts.iter().find(|part| part.number == benchmark.part) else {
+            continue;
+        };
+        for &size in benchmark.sizes {
+            eprintln!("Benchmarking {part} with a size of {size}");
+            match benchmarks::run(part, size) {
+                Ok(results) => measured.extend(results),
+                Err(error) => {
+                    eprintln!("Skipping {part}, since {error}");
+                    break;
+                }
+            }
+        }
+    }
+    let results = Results {
+        machine: Machine::this(),
+        measured,
+    };
+    print!("{results}");
+
+    for (file, text) in [(csv, results.csv()), (json, results.json())] {
+        if let Some(file) = file {
+            if let Err(error) = fs::write(file, text) {
+                eprintln!("Couldn't save the results to {file}: {error}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    if let Some(name) = score {
+        let path = scores::file(&parts::workspace());
+        let saved = Scores::load(&path).and_then(|mut scores| {
+            let improved = scores.record(name, &results.measured, SystemTime::now());
+            scores.save(&path)?;
+            Ok(improved)
+        });
+        match saved {
+            Ok(0) => println!("None of {name}'s best times were beaten, see `workshop scores`"),
+            Ok(improved) => println!("{name} has {improved} new best times, see `workshop scores`"),
+            Err(error) => {
+                eprintln!("Couldn't save the scores: {error}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn show_scores() -> ExitCode {
+    match Scores::load(&scores::file(&parts::workspace())) {
+        Ok(scores) if scores.is_empty() => {
+            println!(
+                "Nobody is on the leaderboard yet, run `cargo run -p workshop -- bench --score <name>`"
+            );
+            ExitCode::SUCCESS
+        }
+        Ok(scores) => {
+            print!("{scores}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Couldn't read the scores: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new_part(parts: &[Part], title: &str) -> ExitCode {
+    let part = NewPart::next(parts.iter().map(|part| part.number), title);
+    match part.create(&parts::workspace()) {
+        Ok(()) => {
+            println!(
+                "Added part-{0}, see its section in the README and `cargo run -p workshop -- check {0}`",
+                part.number
+            );
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Couldn't add part {}: {error}", part.number);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Asks the questions about `part`, or about every part
+fn quiz(part: Option<&Part>) -> ExitCode {
+    let questions: Vec<_> = quiz::QUESTIONS
+        .iter()
+        .filter(|question| part.is_none_or(|part| part.number == question.part))
+        .collect();
+    if questions.is_empty() {
+        eprintln!("There are no questions about that part yet");
+        return ExitCode::FAILURE;
+    }
+    if io::stdin().is_terminal() {
+        println!("Answer every question, and press enter.\n");
+    }
+    let mut right = 0;
+    for question in &questions {
+        match quiz::ask(question, &mut io::stdin().lock(), &mut io::stdout()) {
+            Ok(true) => right += 1,
+            Ok(false) => {}
+            Err(error) => {
+                eprintln!("\n{error}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    println!("You got {right} of {} right", questions.len());
+    ExitCode::SUCCESS
+}
+
+/// Runs the part with `--release`, since lots of parts compare how fast things are.
+/// `args` is the program to run, for parts with several, and the arguments for it after `--`.
+fn run(part: &Part, args: &[&str]) -> ExitCode {
+    let (program, args) = match args {
+        ["--", args @ ..] => (None, args),
+        [program, "--", args @ ..] => (Some(*program), args),
+        [program] => (Some(*program), &[][..]),
+        [] => (None, &[][..]),
+        _ => {
+            eprint!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut command = cargo();
+    command.args(["run", "--release", "-p", &part.package()]);
+    match program {
+        Some(program) if part.binaries.iter().any(|binary| binary == program) => {
+            command.args(["--bin", program]);
+            if let Some(&tier) = part.tiers.programs.get(program) {
+                command.args(["--features", &part.features(tier).join(",")]);
+            }
+        }
+        Some(program) => {
+            eprintln!("{part} has no program called {program}");
+            return ExitCode::FAILURE;
+        }
+        None if !part.binaries.is_empty() => {
+            eprintln!(
+                "{part} has several programs, pick one of: {}",
+                part.binaries.join(", ")
+            );
+            return ExitCode::FAILURE;
+        }
+        None => {}
+    }
+    command.arg("--").args(args);
+
+    match command.status() {
+        Ok(status) if status.success() => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("Couldn't run cargo: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Finding the workshop's parts in the workspace
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::tiers::{Tier, Tiers};
+
+/// One part of the workshop, in a `part-N` directory of the workspace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Part {
+    pub number: u32,
+    /// From the part's heading in the README, if it has one
+    pub title: Option<String>,
+    pub dir: PathBuf,
+    /// The names of the part's programs, for parts with more than one
+    pub binaries: Vec<String>,
+    /// The bonus and advanced exercises the part has, if any
+    pub tiers: Tiers,
+}
+
+impl Part {
+    /// The name of the part's package, for `cargo -p`
+    pub fn package(&self) -> String {
+        format!("part-{}", self.number)
+    }
+
+    /// The packages with the part's tests, for `cargo test -p`: its own, and the one in its
+    /// `compile-fail` directory if it has one, where the cases which mustn't compile are kept away
+    /// from its `solutions` feature
+    pub fn test_packages(&self) -> Vec<String> {
+        let mut packages = vec![self.package()];
+        if self.dir.join("compile-fail").join("Cargo.toml").is_file() {
+            packages.push(format!("{}-compile-fail", self.package()));
+        }
+        packages
+    }
+
+    /// The features which build the part's exercises up to `tier`, which is none if it has none
+    pub fn features(&self, tier: Tier) -> Vec<&'static str> {
+        self.tiers
+            .tiers
+            .iter()
+            .filter(|&&other| other <= tier)
+            .map(|tier| tier.feature())
+            .collect()
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.title {
+            Some(title) => write!(f, "Part {}: {title}", self.number),
+            None => write!(f, "Part {}", self.number),
+        }
+    }
+}
+
+/// The workspace this crate is in
+pub fn workspace() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("The workshop crate is inside the workspace")
+        .to_path_buf()
+}
+
+/// Every part in `workspace`, in order
+pub fn discover(workspace: &Path) -> io::Result<Vec<Part>> {
+    let readme = fs::read_to_string(workspace.join("README.md")).unwrap_or_default();
+    let mut parts = Vec::new();
+    for entry in fs::read_dir(workspace)? {
+        let dir = entry?.path();
+        let Some(number) = dir
+            .file_name()
+            .and_then(|name| name.to_str()?.strip_prefix("part-")?.parse().ok())
+        else {
+            continue;
+        };
+        if !dir.join("Cargo.toml").is_file() {
+            continue;
+        }
+        parts.push(Part {
+            number,
+            title: title(&readme, number),
+            binaries: binaries(&dir)?,
+            tiers: Tiers::read(&dir)?,
+            dir,
+        });
+    }
+    parts.sort_by_key(|part| part.number);
+    Ok(parts)
+}
+
+/// Finds a part from what was given on the command line: `55`, `part-55` or `part_55`
+pub fn find<'a>(parts: &'a [Part], name: &str) -> Option<&'a Part> {
+    let number = name
+        .strip_prefix("part-")
+        .or_else(|| name.strip_prefix("part_"))
+        .unwrap_or(name)
+        .parse::<u32>()
+        .ok()?;
+    parts.iter().find(|part| part.number == number)
+}
+
+/// The title from the part's `## Part N: title` heading in the README
+fn title(readme: &str, number: u32) -> Option<String> {
+    let heading = format!("## Part {number}: ");
+    readme
+        .lines()
+        .find_map(|line| line.strip_prefix(&heading))
+        .map(|title| title.trim().to_string())
+}
+
+/// The names of the programs in the part's `src/bin`, sorted
+fn binaries(dir: &Path) -> io::Result<Vec<String>> {
+    let bin = dir.join("src").join("bin");
+    if !bin.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut binaries = Vec::new();
+    for entry in fs::read_dir(bin)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "rs") {
+            if let Some(name) = path.file_stem().and_then(|name| name.to_str()) {
+                binaries.push(name.to_string());
+            }
+        }
+    }
+    // With programs in `src/bin`, the one in `src/main.rs` has to be picked by the package's name too
+    if dir.join("src").join("main.rs").is_file() {
+        if let Some(package) = dir.file_name().and_then(|name| name.to_str()) {
+            binaries.push(package.to_string());
+        }
+    }
+    binaries.sort();
+    Ok(binaries)
+}
+
+#[test]
+fn discovers_every_part_in_order() {
+    let parts = discover(&workspace()).unwrap();
+    let numbers: Vec<_> = parts.iter().map(|part| part.number).collect();
+    assert_eq!(numbers[..3], [1, 2, 3]);
+    assert!(numbers.windows(2).all(|pair| pair[0] < pair[1]));
+
+    let first = &parts[0];
+    assert_eq!(first.title.as_deref(), Some("concurrent threads"));
+    assert_eq!(first.binaries, ["part-1", "scheduling", "scoped"]);
+    assert_eq!(first.to_string(), "Part 1: concurrent threads");
+    assert_eq!(first.tiers.tiers, [Tier::Bonus]);
+    assert_eq!(first.tiers.programs.get("scoped"), Some(&Tier::Bonus));
+    assert_eq!(first.features(Tier::Advanced), ["bonus"]);
+
+    let stealing = find(&parts, "17").unwrap();
+    assert_eq!(stealing.features(Tier::Bonus), Vec::<&str>::new());
+    assert_eq!(stealing.features(Tier::Advanced), ["advanced"]);
+
+    assert_eq!(first.test_packages(), ["part-1"]);
+    let interior = find(&parts, "40").unwrap();
+    assert_eq!(
+        interior.test_packages(),
+        ["part-40", "part-40-compile-fail"]
+    );
+}
+
+#[test]
+fn finds_parts_by_name() {
+    let parts = discover(&workspace()).unwrap();
+    for name in ["4", "part-4", "part_4"] {
+        assert_eq!(
+            find(&parts, name).map(|part| part.number),
+            Some(4),
+            "{name}"
+        );
+    }
+    assert_eq!(find(&parts, "0"), None);
+    assert_eq!(find(&parts, "four"), None);
+}
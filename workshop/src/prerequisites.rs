@@ -0,0 +1,140 @@
+//! The parts a part builds on, like part 5 for part 6 and part 26's executor for part 97, from the
+//! dependencies between their crates, and whether they pass yet, so that `check` can send attendees
+//! back to them before a failing test in part 5 shows up as a mysterious one in part 6
+
+use std::fmt;
+
+use crate::{
+    dependencies::Graph,
+    parts::Part,
+    progress::{PartStatus, Progress},
+};
+
+/// The parts `part` builds on which don't pass yet, by how far along they are
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Prerequisites<'a> {
+    pub part: &'a Part,
+    /// Checked, but a test failed or they didn't compile
+    pub failing: Vec<&'a Part>,
+    /// Not checked yet, so they might be done, or not started
+    pub unchecked: Vec<&'a Part>,
+}
+
+impl<'a> Prerequisites<'a> {
+    /// The parts among `parts` which `part` builds on, according to `graph`, and which `progress`
+    /// doesn't have passing
+    pub fn of(part: &'a Part, parts: &'a [Part], graph: &Graph, progress: &Progress) -> Self {
+        let mut prerequisites = Self {
+            part,
+            failing: Vec::new(),
+            unchecked: Vec::new(),
+        };
+        let names = graph.prerequisites(&part.package());
+        for prerequisite in parts
+            .iter()
+            .filter(|other| names.contains(&other.package()))
+        {
+            match progress.get(prerequisite).map(|progress| progress.status) {
+                Some(PartStatus::Passing) => {}
+                Some(PartStatus::Failing) => prerequisites.failing.push(prerequisite),
+                Some(PartStatus::NotStarted) | None => prerequisites.unchecked.push(prerequisite),
+            }
+        }
+        prerequisites
+    }
+
+    /// Whether checking the part should wait until the failing parts it builds on pass, which it
+    /// doesn't for ones which haven't been checked, since they may well be done
+    pub fn block(&self) -> bool {
+        !self.failing.is_empty()
+    }
+}
+
+impl fmt::Display for Prerequisites<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (parts, what) in [
+            (&self.failing, "still fails"),
+            (&self.unchecked, "hasn't been checked yet"),
+        ] {
+            for prerequisite in parts {
+                writeln!(
+                    f,
+                    "! {} builds on {prerequisite}, which {what}, see `check {}`",
+                    self.part.package(),
+                    prerequisite.number
+                )?;
+            }
+        }
+        if self.block() {
+            writeln!(
+                f,
+                "Get the parts it builds on to pass first, or add --skip-prerequisites to check it anyway"
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn finds_the_parts_which_dont_pass_yet() {
+    use std::{path::PathBuf, time::SystemTime};
+
+    use crate::report::{Report, Status, TestResult};
+
+    let part = |number: u32| Part {
+        number,
+        title: Some(format!("title {number}")),
+        dir: PathBuf::from(format!("part-{number}")),
+        binaries: Vec::new(),
+        tiers: Default::default(),
+    };
+    let report = |status| {
+        Report::Tested(vec![TestResult {
+            target: "src/lib.rs".to_string(),
+            name: "test".to_string(),
+            status,
+            reason: None,
+        }])
+    };
+    let json = r#"{"packages": [
+        {"name": "common", "manifest_path": "/w/common/Cargo.toml", "dependencies": []},
+        {"name": "part-4", "manifest_path": "/w/part-4/Cargo.toml", "dependencies": []},
+        {"name": "part-5", "manifest_path": "/w/part-5/Cargo.toml", "dependencies": [
+            {"name": "common", "path": "/w/common"},
+            {"name": "part-4", "path": "/w/part-4"}
+        ]},
+        {"name": "part-6", "manifest_path": "/w/part-6/Cargo.toml", "dependencies": [
+            {"name": "part-5", "path": "/w/part-5"}
+        ]}
+    ]}"#;
+    let graph = Graph::parse(json).unwrap();
+    let parts = [part(4), part(5), part(6)];
+    let mut progress = Progress::default();
+
+    let prerequisites = Prerequisites::of(&parts[2], &parts, &graph, &progress);
+    assert_eq!(prerequisites.unchecked, [&parts[0], &parts[1]]);
+    assert!(!prerequisites.block(), "Unchecked parts may well be done");
+
+    progress.record(&parts[0], None, &report(Status::Passed), SystemTime::now());
+    progress.record(&parts[1], None, &report(Status::Failed), SystemTime::now());
+    let prerequisites = Prerequisites::of(&parts[2], &parts, &graph, &progress);
+    assert_eq!(prerequisites.failing, [&parts[1]]);
+    assert!(prerequisites.unchecked.is_empty());
+    assert!(prerequisites.block());
+    assert_eq!(
+        prerequisites.to_string(),
+        "\
+! part-6 builds on Part 5: title 5, which still fails, see `check 5`
+Get the parts it builds on to pass first, or add --skip-prerequisites to check it anyway
+"
+    );
+
+    assert_eq!(
+        Prerequisites::of(&parts[0], &parts, &graph, &progress),
+        Prerequisites {
+            part: &parts[0],
+            failing: Vec::new(),
+            unchecked: Vec::new()
+        }
+    );
+}
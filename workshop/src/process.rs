@@ -0,0 +1,147 @@
+//! Running cargo, and stopping it when a test gets stuck
+
+use std::{
+    io::{self, Read},
+    process::{Child, Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// What cargo printed, and how it stopped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Output {
+    pub success: bool,
+    pub stdout: String,
+    pub stderr: String,
+    pub timed_out: bool,
+}
+
+impl Output {
+    /// The tests which were still running when it was stopped. With a single test thread, the test
+    /// harness prints `test name ... ` when a test starts and the rest of the line once it's done,
+    /// so those are the ones without the rest. With more, it only prints a test once it's done, but
+    /// warns about every test which has been running for over a minute.
+    pub fn stuck_tests(&self) -> Vec<String> {
+        let mut running: Vec<&str> = Vec::new();
+        for line in self.stdout.lines() {
+            let Some(line) = line.strip_prefix("test ") else {
+                continue;
+            };
+            if let Some(name) = line.strip_suffix(" has been running for over 60 seconds") {
+                running.push(name);
+            } else if let Some((name, result)) = line.split_once(" ... ") {
+                let name = name.trim_end_matches(" - should panic");
+                if result.trim().is_empty() {
+                    running.push(name);
+                } else if let Some(done) = running.iter().position(|&test| test == name) {
+                    // A test which was slow, but finished after all
+                    running.remove(done);
+                }
+            }
+        }
+        running.dedup();
+        running.into_iter().map(str::to_string).collect()
+    }
+}
+
+/// Stops `child` along with the tests it's running, which would otherwise keep its output open
+fn kill(child: &mut Child) {
+    // The test programs are cargo's children. Without `pkill` they're left running.
+    let _ = Command::new("pkill")
+        .args(["-KILL", "-P", &child.id().to_string()])
+        .status();
+    let _ = child.kill();
+}
+
+/// Runs `command`, stopping it once it has taken longer than `timeout`
+pub fn run(command: Command, timeout: Option<Duration>) -> io::Result<Output> {
+    run_observed(command, timeout, |_| {})
+}
+
+/// Like `run`, but also gives `observe` what the command prints to stdout as soon as it does, in
+/// however many pieces it comes in
+pub fn run_observed(
+    mut command: Command,
+    timeout: Option<Duration>,
+    mut observe: impl FnMut(&[u8]) + Send,
+) -> io::Result<Output> {
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let mut stdout_pipe = child.stdout.take().expect("stdout is piped");
+    let mut stderr_pipe = child.stderr.take().expect("stderr is piped");
+    thread::scope(|s| {
+        // Read while it runs, so it isn't blocked by a full pipe
+        let stdout = s.spawn(move || {
+            let (mut text, mut buffer) = (Vec::new(), [0; 4096]);
+            while let Ok(read @ 1..) = stdout_pipe.read(&mut buffer) {
+                observe(&buffer[..read]);
+                text.extend_from_slice(&buffer[..read]);
+            }
+            String::from_utf8_lossy(&text).into_owned()
+        });
+        let stderr = s.spawn(move || {
+            let mut text = Vec::new();
+            let _ = stderr_pipe.read_to_end(&mut text);
+            String::from_utf8_lossy(&text).into_owned()
+        });
+
+        let start = Instant::now();
+        let (status, timed_out) = loop {
+            if let Some(status) = child.try_wait()? {
+                break (status, false);
+            }
+            if timeout.is_some_and(|timeout| start.elapsed() > timeout) {
+                kill(&mut child);
+                break (child.wait()?, true);
+            }
+            thread::sleep(Duration::from_millis(10));
+        };
+        Ok(Output {
+            success: status.success(),
+            stdout: stdout.join().unwrap_or_default(),
+            stderr: stderr.join().unwrap_or_default(),
+            timed_out,
+        })
+    })
+}
+
+#[test]
+fn finds_the_tests_which_never_finished() {
+    let output = |stdout: &str| Output {
+        success: false,
+        stdout: stdout.to_string(),
+        stderr: String::new(),
+        timed_out: true,
+    };
+    // One test thread
+    let single = output("\nrunning 3 tests\ntest sums ... ok\ntest waits - should panic ... ");
+    assert_eq!(single.stuck_tests(), ["waits"]);
+    // Several, where only the slow ones are printed before they're done
+    let several = output(
+        "\nrunning 3 tests\n\
+         test waits has been running for over 60 seconds\n\
+         test slow has been running for over 60 seconds\n\
+         test sums ... ok\n\
+         test slow ... ok\n",
+    );
+    assert_eq!(several.stuck_tests(), ["waits"]);
+}
+
+#[test]
+fn stops_commands_which_take_too_long() {
+    let mut command = Command::new("sh");
+    command.args(["-c", "echo started; sleep 10"]);
+    let output = run(command, Some(Duration::from_millis(200))).unwrap();
+    assert!(output.timed_out);
+    assert!(!output.success);
+    assert_eq!(output.stdout, "started\n");
+
+    let mut command = Command::new("sh");
+    command.args(["-c", "echo done >&2"]);
+    let output = run(command, Some(Duration::from_secs(10))).unwrap();
+    assert!(!output.timed_out);
+    assert!(output.success);
+    assert_eq!(output.stderr, "done\n");
+}
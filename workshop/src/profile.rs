@@ -0,0 +1,228 @@
+//! `workshop profile`: runs the tests of an exercise under `perf`, and draws a flamegraph of where
+//! the time went, with a tower for every worker thread. Only on Linux, and only with the runner
+//! built with `--features profile`, which brings in `inferno` to draw it.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use serde::Deserialize;
+
+use crate::parts::{self, Part};
+
+/// How often `perf` looks at what every thread is doing, per second
+pub const FREQUENCY: u32 = 999;
+
+#[derive(Deserialize)]
+struct Message {
+    reason: String,
+    #[serde(default)]
+    profile: Option<ArtifactProfile>,
+    #[serde(default)]
+    executable: Option<PathBuf>,
+}
+
+#[derive(Deserialize)]
+struct ArtifactProfile {
+    test: bool,
+}
+
+/// The test programs in what `cargo test --no-run --message-format json` printed
+pub fn test_executables(messages: &str) -> Vec<PathBuf> {
+    messages
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Message>(line).ok())
+        .filter(|message| {
+            message.reason == "compiler-artifact"
+                && message.profile.as_ref().is_some_and(|profile| profile.test)
+        })
+        .filter_map(|message| message.executable)
+        .collect()
+}
+
+/// Builds the tests of `part` with optimizations, and with debug info so the flamegraph has names
+fn build_tests(part: &Part) -> io::Result<Vec<PathBuf>> {
+    let output = crate::cargo()
+        .args(["test", "--release", "--no-run", "-p", &part.package()])
+        .args(["--message-format", "json"])
+        .env("CARGO_PROFILE_RELEASE_DEBUG", "true")
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "{part} doesn't build:\n{}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(test_executables(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// How many tests in the test program `executable` have `filter` in their names
+fn matching_tests(executable: &Path, filter: &str) -> io::Result<usize> {
+    let output = Command::new(executable).args([filter, "--list"]).output()?;
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| line.ends_with(": test"))
+        .count())
+}
+
+/// Where the flamegraph of `exercise` in `part` goes
+pub fn flamegraph_file(part: &Part, exercise: &str) -> PathBuf {
+    let exercise: String = exercise
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    parts::workspace()
+        .join(".workshop")
+        .join("flamegraphs")
+        .join(format!("{}-{exercise}.svg", part.package()))
+}
+
+/// Profiles the tests of `part` which have `exercise` in their names, and gives where the
+/// flamegraph was written
+pub fn profile(part: &Part, exercise: &str) -> io::Result<PathBuf> {
+    supported()?;
+    let executables = build_tests(part)?;
+    let mut matching = Vec::new();
+    for executable in executables {
+        if matching_tests(&executable, exercise)? > 0 {
+            matching.push(executable);
+        }
+    }
+    if matching.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("None of the tests of {part} have {exercise} in their names"),
+        ));
+    }
+
+    let file = flamegraph_file(part, exercise);
+    let dir = file.parent().expect("The flamegraph is in a directory");
+    std::fs::create_dir_all(dir)?;
+    let mut stacks = Vec::new();
+    for (index, executable) in matching.iter().enumerate() {
+        let data = dir.join(format!("perf-{index}.data"));
+        let status = Command::new("perf")
+            .args([
+                "record",
+                "--freq",
+                &FREQUENCY.to_string(),
+                "--call-graph",
+                "dwarf",
+            ])
+            .arg("--output")
+            .arg(&data)
+            .arg("--")
+            .arg(executable)
+            .arg(exercise)
+            .status()?;
+        if !status.success() {
+            // The tests might fail, since the exercise might not be done, but there's still
+            // something to look at, unless `perf` itself failed
+            if !data.is_file() {
+                return Err(io::Error::other(
+                    "perf couldn't record, see what it said above. It might need \
+                    `sudo sysctl kernel.perf_event_paranoid=1` first.",
+                ));
+            }
+        }
+        let script = Command::new("perf")
+            .arg("script")
+            .arg("--input")
+            .arg(&data)
+            .output()?;
+        stacks.extend(script.stdout);
+        let _ = std::fs::remove_file(&data);
+    }
+    let title = format!("{part}, {exercise}");
+    std::fs::write(&file, flamegraph(&title, &stacks)?)?;
+    Ok(file)
+}
+
+/// Whether profiling works here, and what to do about it if it doesn't
+fn supported() -> io::Result<()> {
+    if !cfg!(target_os = "linux") {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Profiling uses `perf`, which is only on Linux. On macOS, Instruments or \
+            `samply` show the same thing.",
+        ));
+    }
+    if !cfg!(feature = "profile") {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Drawing flamegraphs needs the `profile` feature: \
+            `cargo run -p workshop --features profile -- profile <part> <exercise>`",
+        ));
+    }
+    match Command::new("perf").arg("--version").output() {
+        Ok(output) if output.status.success() => Ok(()),
+        _ => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "Couldn't find `perf`. It's usually in a package called `linux-tools` or `perf`.",
+        )),
+    }
+}
+
+/// Draws the stacks from `perf script` as a flamegraph, in SVG
+#[cfg(feature = "profile")]
+pub fn flamegraph(title: &str, stacks: &[u8]) -> io::Result<Vec<u8>> {
+    use inferno::{
+        collapse::{perf::Folder, Collapse},
+        flamegraph::{self, Options},
+    };
+
+    let mut folded = Vec::new();
+    Folder::default().collapse(stacks, &mut folded)?;
+    let mut options = Options::default();
+    options.title = title.to_string();
+    let mut svg = Vec::new();
+    flamegraph::from_reader(&mut options, folded.as_slice(), &mut svg)?;
+    Ok(svg)
+}
+
+#[cfg(not(feature = "profile"))]
+pub fn flamegraph(_title: &str, _stacks: &[u8]) -> io::Result<Vec<u8>> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "Drawing flamegraphs needs the `profile` feature",
+    ))
+}
+
+#[test]
+fn finds_the_test_programs() {
+    let messages = r#"{"reason":"compiler-artifact","profile":{"test":false},"executable":null}
+{"reason":"compiler-artifact","profile":{"test":true},"executable":"/w/target/release/deps/part_48-1a"}
+{"reason":"compiler-artifact","profile":{"test":false},"executable":"/w/target/release/part-48"}
+{"reason":"compiler-artifact","profile":{"test":true},"executable":"/w/target/release/deps/solutions-2b"}
+{"reason":"build-finished","success":true}"#;
+    assert_eq!(
+        test_executables(messages),
+        [
+            PathBuf::from("/w/target/release/deps/part_48-1a"),
+            PathBuf::from("/w/target/release/deps/solutions-2b")
+        ]
+    );
+}
+
+#[test]
+fn names_flamegraphs_after_the_exercise() {
+    let parts = parts::discover(&parts::workspace()).unwrap();
+    let file = flamegraph_file(&parts[0], "tests::sorts in parallel");
+    assert!(file.ends_with(".workshop/flamegraphs/part-1-tests__sorts_in_parallel.svg"));
+}
+
+#[cfg(feature = "profile")]
+#[test]
+fn draws_flamegraphs() {
+    let stacks = "\
+part_48-1a 4242/4243 1000.0: 1001001 cycles:
+\t    5563aa6d3a41 part_48::merge_sort+0x41 (/w/target/release/deps/part_48-1a)
+\t    5563aa6d3b00 std::thread::Builder::spawn+0x20 (/w/target/release/deps/part_48-1a)
+
+";
+    let svg = String::from_utf8(flamegraph("Part 48", stacks.as_bytes()).unwrap()).unwrap();
+    assert!(svg.contains("<svg"));
+    assert!(svg.contains("merge_sort"));
+}
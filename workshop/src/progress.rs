@@ -0,0 +1,492 @@
+//! What's been checked so far, kept in `.workshop/progress.toml` so it lasts between sessions
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    exercises::Exercise,
+    parts::Part,
+    report::{Report, Status},
+    tiers::Tier,
+};
+
+/// Where the progress is kept in the workspace
+pub fn file(workspace: &Path) -> PathBuf {
+    workspace.join(".workshop").join("progress.toml")
+}
+
+/// Adds how `part`, or its exercises up to `tier`, did to the progress file in the workspace
+pub fn record(part: &Part, tier: Option<Tier>, report: &Report) -> io::Result<()> {
+    let path = file(&crate::parts::workspace());
+    let mut progress = Progress::load(&path)?;
+    progress.record(part, tier, report, SystemTime::now());
+    progress.save(&path)
+}
+
+/// What the progress of `part` is kept under: its package name, like `part-17`, and `part-17-advanced`
+/// for its advanced exercises
+fn key(part: &Part, tier: Option<Tier>) -> String {
+    match tier {
+        Some(tier) => format!("{}-{tier}", part.package()),
+        None => part.package(),
+    }
+}
+
+/// How far along a part is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PartStatus {
+    /// Not checked yet
+    NotStarted,
+    /// A test failed, or the part didn't compile, the last time it was checked
+    Failing,
+    Passing,
+}
+
+/// How a part did the last time it was checked, and when
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartProgress {
+    pub status: PartStatus,
+    pub passed: usize,
+    /// How many tests ran, not counting ignored ones
+    pub tests: usize,
+    /// How many tests failed on an exercise which isn't implemented yet
+    pub unimplemented: usize,
+    /// In RFC 3339, like every time in here
+    pub first_checked: String,
+    pub last_checked: String,
+    /// When every test passed for the first time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed: Option<String>,
+}
+
+/// The progress on every part which has been checked, by package name, with the bonus and
+/// advanced exercises kept apart from the part's own
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Progress {
+    parts: BTreeMap<String, PartProgress>,
+}
+
+impl Progress {
+    /// Reads the progress from `path`, which is empty before anything has been checked
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text).map_err(|error| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{} is broken: {error}", path.display()),
+                )
+            }),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let text = toml::to_string(self).map_err(io::Error::other)?;
+        fs::write(path, text)
+    }
+
+    pub fn get(&self, part: &Part) -> Option<&PartProgress> {
+        self.parts.get(&part.package())
+    }
+
+    /// The progress on the exercises of `part` up to `tier`
+    pub fn get_tier(&self, part: &Part, tier: Tier) -> Option<&PartProgress> {
+        self.parts.get(&key(part, Some(tier)))
+    }
+
+    /// Remembers how `part`, or its exercises up to `tier`, did when it was checked at `now`
+    pub fn record(&mut self, part: &Part, tier: Option<Tier>, report: &Report, now: SystemTime) {
+        let now = humantime::format_rfc3339_seconds(now).to_string();
+        let previous = self.parts.remove(&key(part, tier));
+        let status = if report.passed() {
+            PartStatus::Passing
+        } else {
+            PartStatus::Failing
+        };
+        let tests = match report {
+            // Which tests there are isn't known until it compiles again
+            Report::DoesNotCompile(_) => previous.as_ref().map_or(0, |previous| previous.tests),
+            Report::Tested(results) => results.len() - report.count(Status::Ignored),
+        };
+        let completed = previous
+            .as_ref()
+            .and_then(|previous| previous.completed.clone())
+            .or_else(|| (status == PartStatus::Passing).then(|| now.clone()));
+
+        let progress = PartProgress {
+            status,
+            passed: report.count(Status::Passed),
+            tests,
+            unimplemented: report
+                .results()
+                .iter()
+                .filter(|result| result.is_unimplemented())
+                .count(),
+            first_checked: previous.map_or_else(|| now.clone(), |previous| previous.first_checked),
+            last_checked: now,
+            completed,
+        };
+        self.parts.insert(key(part, tier), progress);
+    }
+
+    /// An overview of every part in `parts`, as of `now`
+    pub fn overview<'a>(&'a self, parts: &'a [Part], now: SystemTime) -> Overview<'a> {
+        Overview {
+            progress: self,
+            parts,
+            now,
+            left: BTreeMap::new(),
+        }
+    }
+}
+
+/// What `workshop status` shows
+pub struct Overview<'a> {
+    progress: &'a Progress,
+    parts: &'a [Part],
+    now: SystemTime,
+    /// How many exercises are left in the parts which have been looked at, and in their tiers
+    left: BTreeMap<(u32, Option<Tier>), usize>,
+}
+
+impl Overview<'_> {
+    /// Shows how many of `exercises` are left in `part`, from `exercises::find`, which is up to
+    /// date even when the part hasn't been checked in a while
+    pub fn left(&mut self, part: &Part, exercises: &[Exercise]) {
+        self.left.insert((part.number, None), 0);
+        for &tier in &part.tiers.tiers {
+            self.left.insert((part.number, Some(tier)), 0);
+        }
+        for exercise in exercises {
+            *self.left.entry((part.number, exercise.tier)).or_default() += 1;
+        }
+    }
+
+    /// How long ago `time` was, to the minute
+    fn ago(&self, time: &str) -> String {
+        let Ok(time) = humantime::parse_rfc3339(time) else {
+            return time.to_string();
+        };
+        let elapsed = self.now.duration_since(time).unwrap_or_default();
+        if elapsed < Duration::from_secs(60) {
+            return "just now".to_string();
+        }
+        let minutes = Duration::from_secs(elapsed.as_secs() / 60 * 60);
+        format!("{} ago", humantime::format_duration(minutes))
+    }
+
+    /// A line about how `name` is going, indented by `indent`, and its status
+    fn line(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indent: &str,
+        name: &dyn fmt::Display,
+        progress: Option<&PartProgress>,
+        left: Option<usize>,
+    ) -> Result<PartStatus, fmt::Error> {
+        let left = match left {
+            Some(0) => ", no exercises left".to_string(),
+            Some(1) => ", 1 exercise left".to_string(),
+            Some(left) => format!(", {left} exercises left"),
+            None => String::new(),
+        };
+        let Some(progress) = progress else {
+            writeln!(f, "{indent}  {name}: not started{left}")?;
+            return Ok(PartStatus::NotStarted);
+        };
+        match (progress.status, &progress.completed) {
+            (PartStatus::Passing, Some(completed)) => writeln!(
+                f,
+                "{indent}✓ {name}: all {} tests pass{left}, done {}",
+                progress.tests,
+                self.ago(completed)
+            )?,
+            _ => {
+                write!(
+                    f,
+                    "{indent}✗ {name}: {} of {} tests pass",
+                    progress.passed, progress.tests
+                )?;
+                if progress.unimplemented > 0 {
+                    write!(f, ", {} on unimplemented exercises", progress.unimplemented)?;
+                }
+                if progress.completed.is_some() {
+                    write!(f, ", passed before")?;
+                }
+                writeln!(f, "{left}, checked {}", self.ago(&progress.last_checked))?;
+            }
+        }
+        Ok(progress.status)
+    }
+}
+
+impl fmt::Display for Overview<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut totals = BTreeMap::<PartStatus, usize>::new();
+        // How many parts have each tier, and how many of them pass it
+        let mut tiers = BTreeMap::<Tier, (usize, usize)>::new();
+        for part in self.parts {
+            let left = self.left.get(&(part.number, None)).copied();
+            let status = self.line(f, "", part, self.progress.get(part), left)?;
+            *totals.entry(status).or_default() += 1;
+            // With the part's own tests, since the features build on the part
+            for &tier in &part.tiers.tiers {
+                let name = format!("{tier} exercises");
+                let left = self.left.get(&(part.number, Some(tier))).copied();
+                let progress = self.progress.get_tier(part, tier);
+                let status = self.line(f, "    ", &name, progress, left)?;
+                let (passing, parts) = tiers.entry(tier).or_default();
+                *passing += usize::from(status == PartStatus::Passing);
+                *parts += 1;
+            }
+        }
+
+        let total = |status| totals.get(&status).copied().unwrap_or(0);
+        writeln!(
+            f,
+            "\n{} of {} parts passing, {} failing and {} not started",
+            total(PartStatus::Passing),
+            self.parts.len(),
+            total(PartStatus::Failing),
+            total(PartStatus::NotStarted)
+        )?;
+        for (tier, (passing, parts)) in tiers {
+            writeln!(
+                f,
+                "{passing} of the {parts} parts with {tier} exercises passing them"
+            )?;
+        }
+        let left: usize = self.left.values().sum();
+        if left > 0 {
+            let parts: BTreeSet<u32> = self
+                .left
+                .iter()
+                .filter(|(_, &left)| left > 0)
+                .map(|((part, _), _)| *part)
+                .collect();
+            writeln!(
+                f,
+                "{left} exercise{} left in {} part{}",
+                if left == 1 { "" } else { "s" },
+                parts.len(),
+                if parts.len() == 1 { "" } else { "s" }
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn part(number: u32) -> Part {
+    Part {
+        number,
+        title: Some(format!("title {number}")),
+        dir: PathBuf::from(format!("part-{number}")),
+        binaries: Vec::new(),
+        tiers: Default::default(),
+    }
+}
+
+#[cfg(test)]
+fn report(statuses: &[Status]) -> Report {
+    use crate::report::TestResult;
+
+    Report::Tested(
+        statuses
+            .iter()
+            .enumerate()
+            .map(|(i, &status)| TestResult {
+                target: "src/lib.rs".to_string(),
+                name: format!("test_{i}"),
+                status,
+                reason: (status == Status::Failed)
+                    .then(|| "Not implemented yet, at src/lib.rs:1:1".to_string()),
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn records_when_parts_pass() {
+    let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let hour = Duration::from_secs(3600);
+    let mut progress = Progress::default();
+    let part = part(4);
+
+    progress.record(
+        &part,
+        None,
+        &report(&[Status::Passed, Status::Failed]),
+        start,
+    );
+    let failing = progress.get(&part).unwrap();
+    assert_eq!(failing.status, PartStatus::Failing);
+    assert_eq!(
+        (failing.passed, failing.tests, failing.unimplemented),
+        (1, 2, 1)
+    );
+    assert_eq!(failing.completed, None);
+
+    progress.record(
+        &part,
+        None,
+        &report(&[Status::Passed, Status::Passed]),
+        start + hour,
+    );
+    progress.record(
+        &part,
+        None,
+        &Report::DoesNotCompile(Vec::new()),
+        start + 2 * hour,
+    );
+    let broken = progress.get(&part).unwrap();
+    assert_eq!(broken.status, PartStatus::Failing);
+    assert_eq!((broken.passed, broken.tests), (0, 2));
+    assert_eq!(broken.first_checked, "2023-11-14T22:13:20Z");
+    assert_eq!(broken.last_checked, "2023-11-15T00:13:20Z");
+    assert_eq!(
+        broken.completed.as_deref(),
+        Some("2023-11-14T23:13:20Z"),
+        "Passing once should be remembered"
+    );
+}
+
+#[test]
+fn saves_and_loads_progress() {
+    let path = std::env::temp_dir()
+        .join(format!("workshop-{}", std::process::id()))
+        .join("progress.toml");
+    assert_eq!(Progress::load(&path).unwrap(), Progress::default());
+
+    let mut progress = Progress::default();
+    progress.record(
+        &part(1),
+        None,
+        &report(&[Status::Passed]),
+        SystemTime::now(),
+    );
+    progress.record(
+        &part(2),
+        None,
+        &report(&[Status::Failed]),
+        SystemTime::now(),
+    );
+    progress.save(&path).unwrap();
+    let text = fs::read_to_string(&path).unwrap();
+    assert!(text.contains("[part-1]\nstatus = \"passing\""), "{text}");
+    assert_eq!(Progress::load(&path).unwrap(), progress);
+
+    fs::write(&path, "status = ").unwrap();
+    assert!(
+        Progress::load(&path).is_err(),
+        "Broken files should be an error"
+    );
+    fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn shows_an_overview() {
+    use crate::tiers::Tiers;
+
+    let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let mut parts = [part(1), part(2), part(3)];
+    parts[0].tiers = Tiers {
+        tiers: vec![Tier::Bonus, Tier::Advanced],
+        programs: BTreeMap::new(),
+    };
+    let mut progress = Progress::default();
+    progress.record(&parts[0], None, &report(&[Status::Passed]), start);
+    progress.record(
+        &parts[0],
+        Some(Tier::Bonus),
+        &report(&[Status::Passed, Status::Passed]),
+        start,
+    );
+    progress.record(
+        &parts[1],
+        None,
+        &report(&[Status::Passed, Status::Failed, Status::Ignored]),
+        start,
+    );
+
+    let overview = progress
+        .overview(&parts, start + Duration::from_secs(2 * 3600 + 90))
+        .to_string();
+    assert_eq!(
+        overview,
+        "\
+✓ Part 1: title 1: all 1 tests pass, done 2h 1m ago
+    ✓ bonus exercises: all 2 tests pass, done 2h 1m ago
+      advanced exercises: not started
+✗ Part 2: title 2: 1 of 2 tests pass, 1 on unimplemented exercises, checked 2h 1m ago
+  Part 3: title 3: not started
+
+1 of 3 parts passing, 1 failing and 1 not started
+1 of the 1 parts with bonus exercises passing them
+0 of the 1 parts with advanced exercises passing them
+"
+    );
+    assert_eq!(
+        progress.get(&parts[0]).unwrap().tests,
+        1,
+        "The bonus exercises are kept apart"
+    );
+}
+
+#[test]
+fn shows_the_exercises_left() {
+    use crate::tiers::Tiers;
+
+    let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let mut parts = [part(1), part(2), part(3)];
+    parts[1].tiers = Tiers {
+        tiers: vec![Tier::Advanced],
+        programs: BTreeMap::new(),
+    };
+    let exercise = |function: &str, tier| Exercise {
+        file: PathBuf::from("part-2/src/lib.rs"),
+        line: 1,
+        function: function.to_string(),
+        docs: Vec::new(),
+        tier,
+    };
+    let mut progress = Progress::default();
+    progress.record(&parts[0], None, &report(&[Status::Passed]), start);
+    progress.record(&parts[1], None, &report(&[Status::Failed]), start);
+
+    let mut overview = progress.overview(&parts, start);
+    overview.left(&parts[0], &[]);
+    overview.left(
+        &parts[1],
+        &[
+            exercise("push", None),
+            exercise("pop", None),
+            exercise("lock_free_push", Some(Tier::Advanced)),
+        ],
+    );
+    assert_eq!(
+        overview.to_string(),
+        "\
+✓ Part 1: title 1: all 1 tests pass, no exercises left, done just now
+✗ Part 2: title 2: 0 of 1 tests pass, 1 on unimplemented exercises, 2 exercises left, checked just now
+      advanced exercises: not started, 1 exercise left
+  Part 3: title 3: not started
+
+1 of 3 parts passing, 1 failing and 1 not started
+0 of the 1 parts with advanced exercises passing them
+3 exercises left in 1 part
+"
+    );
+}
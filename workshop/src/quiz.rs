@@ -0,0 +1,361 @@
+//! `workshop quiz`: questions about the parts, to check what stuck. Some have options to pick
+//! from, and some show a snippet of code and ask what it prints, which is checked by running it.
+
+mod snippets;
+
+use std::{
+    io::{self, BufRead, Write},
+    sync::{Mutex, PoisonError},
+    thread,
+};
+
+/// How a question is answered
+#[derive(Debug, Clone, Copy)]
+pub enum Answer {
+    /// By picking one of `options`, where the one at `correct` is right
+    Choice {
+        options: &'static [&'static str],
+        correct: usize,
+    },
+    /// By saying what the function `name` in `quiz/snippets.rs` prints
+    Predict { name: &'static str, run: fn() },
+}
+
+/// The question about what a snippet prints, from the name of its function
+macro_rules! predict {
+    ($name:ident) => {
+        Answer::Predict {
+            name: stringify!($name),
+            run: snippets::$name,
+        }
+    };
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Question {
+    pub part: u32,
+    pub prompt: &'static str,
+    pub answer: Answer,
+    /// Why the answer is what it is, shown once it's been answered
+    pub explanation: &'static str,
+}
+
+/// Every question, in the order of the parts
+pub const QUESTIONS: [Question; 14] = [
+    Question {
+        part: 1,
+        prompt: "What happens to threads which are still running when `main` returns?",
+        answer: Answer::Choice {
+            options: &[
+                "They keep running until they're done",
+                "They're stopped, whether they're done or not",
+                "`main` waits for them before the program exits",
+            ],
+            correct: 1,
+        },
+        explanation: "The process exits when `main` returns, and takes every thread with it. \
+            Joining the threads is how to wait for them.",
+    },
+    Question {
+        part: 1,
+        prompt: "What does this print?",
+        answer: predict!(joins_in_order),
+        explanation: "The threads may finish in any order, but each `join` waits for its own \
+            thread, so the results come out in the order the threads were spawned.",
+    },
+    Question {
+        part: 2,
+        prompt: "What does this print?",
+        answer: predict!(receives_until_dropped),
+        explanation: "Iterating over a receiver ends once every sender is dropped, which \
+            happens when the thread returns, after sending everything.",
+    },
+    Question {
+        part: 2,
+        prompt: "What does this print?",
+        answer: predict!(receives_from_a_closed_channel),
+        explanation: "With every sender gone, nothing more can arrive, so `recv` gives an \
+            error instead of waiting forever.",
+    },
+    Question {
+        part: 4,
+        prompt: "What does this print?",
+        answer: predict!(counts_with_a_mutex),
+        explanation: "Every increment happens while holding the lock, so none of them get \
+            lost, and the scope waits for all four threads.",
+    },
+    Question {
+        part: 4,
+        prompt: "Why do threads usually share a `Mutex` through an `Arc`, outside of scoped threads?",
+        answer: Answer::Choice {
+            options: &[
+                "The mutex doesn't work unless it's on the heap",
+                "Spawned threads need `'static` data, so every thread needs to own the mutex",
+                "`Arc` makes locking faster",
+            ],
+            correct: 1,
+        },
+        explanation: "`thread::spawn` may outlive the function which spawned it, so it can't \
+            borrow. `Arc` lets every thread own the same mutex, and frees it after the last one.",
+    },
+    Question {
+        part: 7,
+        prompt: "What happens when an `async fn` is called, but its future is never awaited?",
+        answer: Answer::Choice {
+            options: &[
+                "It runs on another thread",
+                "It runs until its first `.await`",
+                "Nothing, futures only run when they're polled",
+            ],
+            correct: 2,
+        },
+        explanation: "Futures are lazy. Calling the function only creates the future, \
+            and an executor has to poll it for any of its code to run.",
+    },
+    Question {
+        part: 9,
+        prompt: "What does this print?",
+        answer: predict!(fetch_add_returns_the_old_value),
+        explanation: "`fetch_add` gives the value from before the addition, which is what \
+            makes it useful for things like handing out unique ids.",
+    },
+    Question {
+        part: 10,
+        prompt: "A thread stores a flag with `Release` after writing some data. \
+            Which ordering does another thread need to load the flag with to be sure to see the data?",
+        answer: Answer::Choice {
+            options: &["Relaxed", "Acquire", "Release"],
+            correct: 1,
+        },
+        explanation: "An `Acquire` load which sees a `Release` store also sees everything \
+            written before that store. `Relaxed` only promises something about the flag itself.",
+    },
+    Question {
+        part: 13,
+        prompt: "What does this print?",
+        answer: predict!(scoped_threads_borrow),
+        explanation: "Scoped threads can borrow from outside the scope, even mutably, since \
+            the scope doesn't end until they're all done.",
+    },
+    Question {
+        part: 18,
+        prompt: "What does this print?",
+        answer: predict!(initializes_once),
+        explanation: "Only the first `get_or_init` runs its closure. \
+            Every later one gets the value which is already there.",
+    },
+    Question {
+        part: 19,
+        prompt: "What does this print?",
+        answer: predict!(thread_locals_are_per_thread),
+        explanation: "Every thread has its own `COUNT`, starting at 0, \
+            so the spawned thread's increment doesn't change the main thread's.",
+    },
+    Question {
+        part: 20,
+        prompt: "One thread locks `a` and then `b`, and another locks `b` and then `a`. What can happen?",
+        answer: Answer::Choice {
+            options: &[
+                "Nothing, mutexes are fair",
+                "They can each get their first lock, and wait for each other forever",
+                "One of them panics",
+            ],
+            correct: 1,
+        },
+        explanation: "That's a deadlock. Always taking the locks in the same order rules it out.",
+    },
+    Question {
+        part: 39,
+        prompt: "Why isn't `Rc<T>` `Send`?",
+        answer: Answer::Choice {
+            options: &[
+                "Its reference count isn't atomic, so two threads could change it at the same time",
+                "It's not `'static`",
+                "It's too big to move between threads",
+            ],
+            correct: 0,
+        },
+        explanation: "Cloning or dropping an `Rc` in two threads at once could lose an update \
+            to the count, and free the value too early. `Arc` uses an atomic count instead.",
+    },
+];
+
+/// What the snippets have printed, see `output`
+static PRINTED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// What `println!` does in the snippets
+fn record(line: String) {
+    PRINTED
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(line);
+}
+
+/// Runs a snippet, and gives the lines it printed
+pub fn output(run: fn()) -> Vec<String> {
+    // One snippet at a time, so that what they print doesn't get mixed up
+    static RUNNING: Mutex<()> = Mutex::new(());
+    let _running = RUNNING.lock().unwrap_or_else(PoisonError::into_inner);
+    PRINTED
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clear();
+    // On a thread of its own, so that it starts with fresh thread locals every time
+    let _ = thread::spawn(run).join();
+    std::mem::take(&mut *PRINTED.lock().unwrap_or_else(PoisonError::into_inner))
+}
+
+/// The body of the snippet `name`, without the indentation
+pub fn source(name: &str) -> Option<String> {
+    const SNIPPETS: &str = include_str!("quiz/snippets.rs");
+    let start = format!("pub fn {name}() {{\n");
+    let (_, body) = SNIPPETS.split_once(&start)?;
+    let (body, _) = body.split_once("\n}\n")?;
+    Some(
+        body.lines()
+            .map(|line| line.strip_prefix("    ").unwrap_or(line))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
+/// Whether `answer` is what `printed`, ignoring how it's split into lines and spaced
+fn matches(answer: &str, printed: &[String]) -> bool {
+    answer
+        .split_whitespace()
+        .eq(printed.iter().flat_map(|line| line.split_whitespace()))
+}
+
+/// Asks `question` on `output`, and reads the answer from `input`. Gives whether it was right.
+pub fn ask(
+    question: &Question,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> io::Result<bool> {
+    writeln!(output, "Part {}: {}", question.part, question.prompt)?;
+    let right = match question.answer {
+        Answer::Choice { options, correct } => {
+            for (number, option) in options.iter().enumerate() {
+                writeln!(output, "  {}) {option}", number + 1)?;
+            }
+            write!(output, "Your answer, 1 to {}: ", options.len())?;
+            output.flush()?;
+            let answer = read_line(input)?;
+            let right = answer.trim().parse::<usize>().ok() == Some(correct + 1);
+            if !right {
+                writeln!(output, "✗ It's {}) {}", correct + 1, options[correct])?;
+            }
+            right
+        }
+        Answer::Predict { name, run } => {
+            let source = source(name).unwrap_or_default();
+            writeln!(output)?;
+            for line in source.lines() {
+                writeln!(output, "    {line}")?;
+            }
+            writeln!(output)?;
+            write!(output, "What it prints, with the lines on one line: ")?;
+            output.flush()?;
+            let answer = read_line(input)?;
+            let printed = self::output(run);
+            let right = matches(&answer, &printed);
+            if !right {
+                writeln!(output, "✗ It prints:")?;
+                for line in &printed {
+                    writeln!(output, "    {line}")?;
+                }
+            }
+            right
+        }
+    };
+    if right {
+        writeln!(output, "✓ Right!")?;
+    }
+    writeln!(output, "{}\n", question.explanation)?;
+    Ok(right)
+}
+
+fn read_line(input: &mut impl BufRead) -> io::Result<String> {
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "Stopped before the quiz was over",
+        ));
+    }
+    Ok(line)
+}
+
+#[test]
+fn every_question_can_be_answered() {
+    for question in QUESTIONS {
+        match question.answer {
+            Answer::Choice { options, correct } => {
+                assert!(correct < options.len(), "{}", question.prompt)
+            }
+            Answer::Predict { name, run } => {
+                let source = source(name).unwrap_or_else(|| panic!("No snippet called {name}"));
+                assert!(!source.is_empty(), "{name}");
+                assert!(!output(run).is_empty(), "{name} prints nothing");
+            }
+        }
+    }
+    assert!(QUESTIONS
+        .windows(2)
+        .all(|pair| pair[0].part <= pair[1].part));
+}
+
+#[test]
+fn runs_snippets_to_check_predictions() {
+    assert_eq!(
+        output(snippets::receives_until_dropped),
+        ["1", "2", "3", "done"]
+    );
+    assert_eq!(output(snippets::thread_locals_are_per_thread), ["1", "2"]);
+    assert!(matches(
+        "1 2\n3  done",
+        &output(snippets::receives_until_dropped)
+    ));
+    assert!(!matches(
+        "3 2 1 done",
+        &output(snippets::receives_until_dropped)
+    ));
+    assert_eq!(
+        source("initializes_once").unwrap(),
+        "let cell = OnceLock::new();\n\
+         println!(\"{}\", cell.get_or_init(|| 1));\n\
+         println!(\"{}\", cell.get_or_init(|| 2));"
+    );
+}
+
+#[test]
+fn asks_questions() {
+    let choice = &QUESTIONS[0];
+    let mut output = Vec::new();
+    assert!(ask(choice, &mut "2\n".as_bytes(), &mut output).unwrap());
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("  2) They're stopped, whether they're done or not\n"));
+    assert!(output.contains("✓ Right!"));
+
+    let mut output = Vec::new();
+    assert!(!ask(choice, &mut "1\n".as_bytes(), &mut output).unwrap());
+    assert!(String::from_utf8(output)
+        .unwrap()
+        .contains("✗ It's 2) They're stopped"));
+
+    let predict = QUESTIONS
+        .iter()
+        .find(|question| question.part == 9)
+        .unwrap();
+    let mut output = Vec::new();
+    assert!(ask(predict, &mut "5 6\n".as_bytes(), &mut output).unwrap());
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("    let number = AtomicU32::new(5);\n"));
+
+    assert_eq!(
+        ask(predict, &mut "".as_bytes(), &mut Vec::new())
+            .unwrap_err()
+            .kind(),
+        io::ErrorKind::UnexpectedEof
+    );
+}
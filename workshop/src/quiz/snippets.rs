@@ -0,0 +1,92 @@
+//! The code of the predict-the-output questions. The quiz shows the body of one of these
+//! functions, and runs it to check the answer, with `println!` writing down what it printed.
+
+use std::{
+    cell::Cell,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        mpsc, Mutex, OnceLock,
+    },
+    thread,
+};
+
+macro_rules! println {
+    ($($arg:tt)*) => {
+        super::record(format!($($arg)*))
+    };
+}
+
+pub fn joins_in_order() {
+    let handles: Vec<_> = (1..=3).map(|i| thread::spawn(move || i * 10)).collect();
+    for handle in handles {
+        println!("{}", handle.join().unwrap());
+    }
+}
+
+pub fn receives_until_dropped() {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for i in 1..=3 {
+            sender.send(i).unwrap();
+        }
+    });
+    for received in receiver {
+        println!("{received}");
+    }
+    println!("done");
+}
+
+pub fn receives_from_a_closed_channel() {
+    let (sender, receiver) = mpsc::channel::<u32>();
+    drop(sender);
+    println!("{:?}", receiver.recv());
+}
+
+pub fn counts_with_a_mutex() {
+    let count = Mutex::new(0);
+    thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..1000 {
+                    *count.lock().unwrap() += 1;
+                }
+            });
+        }
+    });
+    println!("{}", count.into_inner().unwrap());
+}
+
+pub fn fetch_add_returns_the_old_value() {
+    let number = AtomicU32::new(5);
+    println!("{}", number.fetch_add(1, Ordering::Relaxed));
+    println!("{}", number.load(Ordering::Relaxed));
+}
+
+pub fn scoped_threads_borrow() {
+    let mut numbers = vec![1, 2, 3];
+    thread::scope(|s| {
+        s.spawn(|| numbers.push(4));
+    });
+    println!("{numbers:?}");
+}
+
+pub fn initializes_once() {
+    let cell = OnceLock::new();
+    println!("{}", cell.get_or_init(|| 1));
+    println!("{}", cell.get_or_init(|| 2));
+}
+
+pub fn thread_locals_are_per_thread() {
+    thread_local! {
+        static COUNT: Cell<u32> = const { Cell::new(0) };
+    }
+    COUNT.set(COUNT.get() + 1);
+    thread::spawn(|| {
+        COUNT.set(COUNT.get() + 1);
+        println!("{}", COUNT.get());
+    })
+    .join()
+    .unwrap();
+    COUNT.set(COUNT.get() + 1);
+    println!("{}", COUNT.get());
+}
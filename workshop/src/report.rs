@@ -0,0 +1,490 @@
+//! Making sense of what `cargo test` prints
+
+use std::{
+    fmt, io,
+    process::{Command, Output},
+    time::Duration,
+};
+
+use common::budget::BudgetExceeded;
+use serde::Serialize;
+
+use crate::{parts::Part, process, tiers::Tier};
+
+/// How a single test went
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Status {
+    Passed,
+    Failed,
+    Ignored,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestResult {
+    /// The test program it's in, like `src/lib.rs` or `tests/solutions.rs`, since tests in
+    /// different ones can have the same name. Empty if cargo didn't say.
+    pub target: String,
+    pub name: String,
+    pub status: Status,
+    /// Why a failed test failed, from what it printed when it panicked
+    pub reason: Option<String>,
+}
+
+/// How the reason a test failed starts, when it failed on an exercise's `todo!()`
+const UNIMPLEMENTED: &str = "Not implemented yet";
+
+impl TestResult {
+    /// Whether the test failed because an exercise isn't implemented yet
+    pub fn is_unimplemented(&self) -> bool {
+        self.reason
+            .as_deref()
+            .is_some_and(|reason| reason.starts_with(UNIMPLEMENTED))
+    }
+}
+
+/// What came out of testing a part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Report {
+    /// The part didn't compile, with the compiler's errors, one line each
+    DoesNotCompile(Vec<String>),
+    Tested(Vec<TestResult>),
+}
+
+impl Report {
+    /// Reads the output of `cargo test --message-format short`
+    pub fn parse(stdout: &str, stderr: &str) -> Self {
+        if stderr.contains("error: could not compile") {
+            let mut errors: Vec<String> = stderr
+                .lines()
+                .filter(|line| is_compile_error(line))
+                .map(str::to_string)
+                .collect();
+            // Tests and programs share the code, so the same error often comes up for each of them
+            errors.dedup();
+            return Self::DoesNotCompile(errors);
+        }
+
+        let targets = targets(stderr);
+        let mut results = Vec::new();
+        for (i, output) in programs(stdout).into_iter().enumerate() {
+            let target = targets.get(i).cloned().unwrap_or_default();
+            results.extend(output.lines().filter_map(|line| {
+                let (name, status) = line.strip_prefix("test ")?.rsplit_once(" ... ")?;
+                let status = match status {
+                    "ok" => Status::Passed,
+                    "FAILED" => Status::Failed,
+                    _ if status.starts_with("ignored") => Status::Ignored,
+                    _ => return None,
+                };
+                let name = name.trim_end_matches(" - should panic").to_string();
+                Some(TestResult {
+                    target: target.clone(),
+                    reason: (status == Status::Failed)
+                        .then(|| failure_reason(output, &name))
+                        .flatten(),
+                    name,
+                    status,
+                })
+            }));
+        }
+        Self::Tested(results)
+    }
+
+    /// Every test that was found, which is none if the part didn't compile
+    pub fn results(&self) -> &[TestResult] {
+        match self {
+            Self::DoesNotCompile(_) => &[],
+            Self::Tested(results) => results,
+        }
+    }
+
+    /// How many tests went like `status`
+    pub fn count(&self, status: Status) -> usize {
+        self.results()
+            .iter()
+            .filter(|result| result.status == status)
+            .count()
+    }
+
+    /// Whether every test that ran passed
+    pub fn passed(&self) -> bool {
+        match self {
+            Self::DoesNotCompile(_) => false,
+            Self::Tested(results) => results.iter().all(|result| result.status != Status::Failed),
+        }
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let results = match self {
+            Self::DoesNotCompile(errors) => {
+                writeln!(f, "Doesn't compile yet:")?;
+                for error in errors {
+                    writeln!(f, "  {error}")?;
+                }
+                return Ok(());
+            }
+            Self::Tested(results) => results,
+        };
+
+        for result in results {
+            let mark = match result.status {
+                Status::Passed => '✓',
+                Status::Failed => '✗',
+                Status::Ignored => '-',
+            };
+            writeln!(f, "  {mark} {}", result.name)?;
+            for line in result.reason.iter().flat_map(|reason| reason.lines()) {
+                writeln!(f, "      {line}")?;
+            }
+        }
+        let ran = results.len() - self.count(Status::Ignored);
+        write!(f, "{} of {ran} tests passed", self.count(Status::Passed))?;
+        if self.count(Status::Ignored) > 0 {
+            write!(f, ", {} ignored", self.count(Status::Ignored))?;
+        }
+        writeln!(f)
+    }
+}
+
+/// The `cargo test` which tests `part`, in the format `Report::parse` reads, along with the
+/// exercises up to `tier`
+pub fn command(part: &Part, tier: Option<Tier>) -> Command {
+    let mut command = crate::cargo();
+    command.arg("test");
+    for package in part.test_packages() {
+        command.args(["-p", &package]);
+    }
+    command
+        .arg("--no-fail-fast")
+        .args(["--message-format", "short", "--color", "never"])
+        .env("RUST_BACKTRACE", "0");
+    if let Some(tier) = tier {
+        command.args(["--features", &part.features(tier).join(",")]);
+    }
+    command
+}
+
+/// Runs the tests of `part`, and of its exercises up to `tier`, with everything cargo printed
+pub fn test(part: &Part, tier: Option<Tier>) -> io::Result<(Report, Output)> {
+    let output = command(part, tier).output()?;
+    let report = Report::parse(
+        &String::from_utf8_lossy(&output.stdout),
+        &String::from_utf8_lossy(&output.stderr),
+    );
+    Ok((report, output))
+}
+
+/// Runs the tests like `test`, but stops them once they've taken longer than `budget`, with how far
+/// they got by then. They're built first, which doesn't count towards the budget.
+pub fn test_within(
+    part: &Part,
+    tier: Option<Tier>,
+    budget: Duration,
+) -> io::Result<(Report, process::Output, Option<BudgetExceeded>)> {
+    let mut build = command(part, tier);
+    build.arg("--no-run");
+    let built = process::run(build, None)?;
+    if !built.success {
+        return Ok((Report::parse(&built.stdout, &built.stderr), built, None));
+    }
+    let output = process::run(command(part, tier), Some(budget))?;
+    let report = Report::parse(&output.stdout, &output.stderr);
+    let exceeded = output.timed_out.then(|| exceeded(&report, budget));
+    Ok((report, output, exceeded))
+}
+
+/// How far the tests in `report` got before running out of `budget`. They ran in another process,
+/// so its threads can't be counted, but `process::Output::stuck_tests` has the ones still running.
+fn exceeded(report: &Report, budget: Duration) -> BudgetExceeded {
+    BudgetExceeded {
+        budget,
+        completed: report.count(Status::Passed) + report.count(Status::Failed),
+        threads: None,
+    }
+}
+
+/// A compiler error in the short message format: `path:line:column: error[E0308]: mismatched types`
+fn is_compile_error(line: &str) -> bool {
+    line.split_once(": error")
+        .is_some_and(|(location, _)| location.split(':').count() == 3)
+}
+
+/// The test programs cargo ran, in order, from what it printed before running each of them:
+/// `Running unittests src/lib.rs (target/debug/deps/part_4-…)` is `src/lib.rs`, and the
+/// documentation's examples are `doc-tests`
+pub fn targets(stderr: &str) -> Vec<String> {
+    stderr
+        .lines()
+        .map(str::trim_start)
+        .filter_map(|line| {
+            if line.starts_with("Doc-tests ") {
+                return Some("doc-tests".to_string());
+            }
+            let running = line.strip_prefix("Running ")?;
+            let (target, _) = running.rsplit_once(" (").unwrap_or((running, ""));
+            Some(target.trim_start_matches("unittests ").to_string())
+        })
+        .collect()
+}
+
+/// What every test program printed, in the order they ran, each from its `running 5 tests` on
+fn programs(stdout: &str) -> Vec<&str> {
+    let mut starts: Vec<usize> = stdout
+        .match_indices("running ")
+        .map(|(start, _)| start)
+        .filter(|&start| start == 0 || stdout[..start].ends_with('\n'))
+        .filter(|&start| {
+            let line = stdout[start..].lines().next().unwrap_or_default();
+            line.ends_with(" tests") || line.ends_with(" test")
+        })
+        .collect();
+    if starts.is_empty() {
+        return vec![stdout];
+    }
+    starts.push(stdout.len());
+    starts
+        .windows(2)
+        .map(|window| &stdout[window[0]..window[1]])
+        .collect()
+}
+
+/// What the failed test `name` printed
+fn output<'a>(stdout: &'a str, name: &str) -> Option<&'a str> {
+    let header = format!("---- {name} stdout ----");
+    let (_, section) = stdout.split_once(&header)?;
+    let section = section.split("\n---- ").next().unwrap_or(section);
+    Some(section.split("\nfailures:").next().unwrap_or(section))
+}
+
+/// The panics in the output of a test, with where they happened and their messages
+fn panics(section: &str) -> Vec<(&str, Vec<&str>)> {
+    let mut panics = Vec::new();
+    let mut lines = section.lines();
+    while let Some(line) = lines.next() {
+        let Some((_, location)) = line.split_once(" panicked at ") else {
+            continue;
+        };
+        let message = lines
+            .by_ref()
+            .take_while(|line| !line.is_empty())
+            .filter(|line| !line.starts_with("note: "))
+            .collect();
+        panics.push((location.trim_end_matches(':'), message));
+    }
+    panics
+}
+
+/// Why the test `name` failed. An unimplemented exercise is the most likely reason, even when
+/// it makes something else panic first, like a thread joining the one that hit the `todo!()`.
+fn failure_reason(stdout: &str, name: &str) -> Option<String> {
+    const MAX_LINES: usize = 6;
+
+    let output = output(stdout, name)?;
+    let panics = panics(output);
+    if let Some((location, _)) = panics
+        .iter()
+        .find(|(_, message)| message.first() == Some(&"not yet implemented"))
+    {
+        return Some(format!("{UNIMPLEMENTED}, at {location}"));
+    }
+    let Some((location, message)) = panics.first() else {
+        // Like a `should_panic` test which didn't
+        return output
+            .lines()
+            .find(|line| line.starts_with("note: "))
+            .map(|note| note.trim_start_matches("note: ").to_string());
+    };
+    let mut reason = message
+        .iter()
+        .take(MAX_LINES)
+        .copied()
+        .collect::<Vec<_>>()
+        .join("\n");
+    reason.push_str(&format!("\n(at {location})"));
+    Some(reason)
+}
+
+#[cfg(test)]
+const STDOUT: &str = "
+running 5 tests
+test conserves_money ... FAILED
+test global_bank ... ok
+test slow ... ignored, takes minutes
+test transfers_money ... FAILED
+test unmoved - should panic ... FAILED
+
+failures:
+
+---- conserves_money stdout ----
+
+thread '<unnamed>' (28390) panicked at part-55/src/main.rs:40:5:
+a scoped thread panicked
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+thread '<unnamed>' (28392) panicked at part-55/src/lib.rs:72:9:
+not yet implemented
+
+---- transfers_money stdout ----
+
+thread 'transfers_money' (28401) panicked at part-55/src/main.rs:67:5:
+assertion `left == right` failed
+  left: 1
+ right: 2
+
+---- unmoved stdout ----
+note: test did not panic as expected
+
+failures:
+    conserves_money
+    transfers_money
+    unmoved
+
+test result: FAILED. 1 passed; 3 failed; 1 ignored; 0 measured; 0 filtered out; finished in 0.01s
+";
+
+#[test]
+fn parses_test_results() {
+    let report = Report::parse(STDOUT, "");
+    assert!(!report.passed());
+    let Report::Tested(results) = &report else {
+        panic!("{report:?}");
+    };
+    let statuses: Vec<_> = results
+        .iter()
+        .map(|result| (result.name.as_str(), result.status))
+        .collect();
+    assert_eq!(
+        statuses,
+        [
+            ("conserves_money", Status::Failed),
+            ("global_bank", Status::Passed),
+            ("slow", Status::Ignored),
+            ("transfers_money", Status::Failed),
+            ("unmoved", Status::Failed),
+        ]
+    );
+    assert!(report
+        .to_string()
+        .ends_with("1 of 4 tests passed, 1 ignored\n"));
+}
+
+#[test]
+fn explains_failures() {
+    let Report::Tested(results) = Report::parse(STDOUT, "") else {
+        panic!("Didn't find the tests");
+    };
+    assert_eq!(
+        results[0].reason.as_deref(),
+        Some("Not implemented yet, at part-55/src/lib.rs:72:9")
+    );
+    assert_eq!(
+        results[3].reason.as_deref(),
+        Some(
+            "assertion `left == right` failed\n  left: 1\n right: 2\n(at part-55/src/main.rs:67:5)"
+        )
+    );
+    assert_eq!(
+        results[4].reason.as_deref(),
+        Some("test did not panic as expected")
+    );
+    assert_eq!(results[1].reason, None);
+    let unimplemented: Vec<_> = results.iter().map(TestResult::is_unimplemented).collect();
+    assert_eq!(unimplemented, [true, false, false, false, false]);
+}
+
+#[test]
+fn counts_the_tests_finished_within_the_budget() {
+    let (finished, _) = STDOUT.split_at(STDOUT.find("test transfers_money").unwrap());
+    let exceeded = exceeded(&Report::parse(finished, ""), Duration::from_secs(30));
+    assert_eq!(
+        exceeded,
+        BudgetExceeded {
+            budget: Duration::from_secs(30),
+            completed: 2,
+            threads: None,
+        }
+    );
+}
+
+#[test]
+fn tells_tests_in_different_programs_apart() {
+    let stdout = "
+running 1 test
+test sums ... FAILED
+
+failures:
+
+---- sums stdout ----
+
+thread 'sums' (101) panicked at part-4/src/lib.rs:9:5:
+not yet implemented
+
+failures:
+    sums
+
+test result: FAILED. 0 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+
+
+running 1 test
+test sums ... FAILED
+
+failures:
+
+---- sums stdout ----
+
+thread 'sums' (102) panicked at part-4/tests/solutions.rs:7:5:
+assertion failed: sum > 0
+
+failures:
+    sums
+
+test result: FAILED. 0 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+";
+    let stderr = "\
+    Finished `test` profile [unoptimized + debuginfo] target(s) in 0.05s
+     Running unittests src/lib.rs (target/debug/deps/part_4-0123456789abcdef)
+error: test failed, to rerun pass `-p part-4 --lib`
+     Running tests/solutions.rs (target/debug/deps/solutions-0123456789abcdef)
+error: test failed, to rerun pass `-p part-4 --test solutions`
+";
+    assert_eq!(targets(stderr), ["src/lib.rs", "tests/solutions.rs"]);
+    let Report::Tested(results) = Report::parse(stdout, stderr) else {
+        panic!("Didn't find the tests");
+    };
+    let tests: Vec<_> = results
+        .iter()
+        .map(|result| (result.target.as_str(), result.name.as_str()))
+        .collect();
+    assert_eq!(
+        tests,
+        [("src/lib.rs", "sums"), ("tests/solutions.rs", "sums")]
+    );
+    assert!(results[0].is_unimplemented());
+    assert_eq!(
+        results[1].reason.as_deref(),
+        Some("assertion failed: sum > 0\n(at part-4/tests/solutions.rs:7:5)")
+    );
+}
+
+#[test]
+fn summarizes_compile_errors() {
+    let stderr = "\
+   Compiling part-55 v0.1.0 (/workshop/part-55)
+part-55/src/lib.rs:71:24: warning: unused variable: `from`
+part-55/src/lib.rs:73:9: error[E0425]: cannot find value `balance` in this scope
+part-55/src/lib.rs:73:9: error[E0425]: cannot find value `balance` in this scope
+error: could not compile `part-55` (lib) due to 1 previous error
+";
+    let report = Report::parse("", stderr);
+    assert_eq!(
+        report,
+        Report::DoesNotCompile(vec![
+            "part-55/src/lib.rs:73:9: error[E0425]: cannot find value `balance` in this scope"
+                .to_string()
+        ])
+    );
+    assert!(!report.passed());
+}
@@ -0,0 +1,162 @@
+//! `workshop check <part> --sanitize=thread`: runs a part's tests under ThreadSanitizer, which
+//! finds data races in `unsafe` code even when the tests happen to pass. Sanitizers need the
+//! nightly compiler, and the standard library built with them too, so the first run is slow.
+
+use std::{collections::BTreeSet, io, process::Command, str::FromStr};
+
+use crate::{parts::Part, report::Report};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sanitizer {
+    Thread,
+}
+
+impl Sanitizer {
+    /// What `-Zsanitizer` and `common::sanitize::VARIABLE` are set to
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Thread => "thread",
+        }
+    }
+}
+
+impl FromStr for Sanitizer {
+    type Err = String;
+
+    fn from_str(sanitizer: &str) -> Result<Self, Self::Err> {
+        match sanitizer {
+            "thread" => Ok(Self::Thread),
+            _ => Err(format!(
+                "There's no sanitizer {sanitizer} in the workshop, pick thread"
+            )),
+        }
+    }
+}
+
+/// The target the compiler builds for by default, like `x86_64-unknown-linux-gnu`, from `rustc -vV`
+pub fn host() -> io::Result<String> {
+    let output = Command::new("rustc").arg("-vV").output()?;
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| line.strip_prefix("host: "))
+        .map(str::to_string)
+        .ok_or_else(|| io::Error::other("rustc -vV didn't say what the host is"))
+}
+
+/// `cargo test` for `part` with `sanitizer`, on nightly. The standard library is built with the
+/// sanitizer too, which needs a target even though it's the host, and everything goes in a target
+/// directory of its own so that it doesn't replace the normal builds.
+pub fn command(part: &Part, sanitizer: Sanitizer, host: &str) -> Command {
+    let mut command = Command::new("rustup");
+    command
+        .current_dir(crate::parts::workspace())
+        .args(["run", "nightly", "cargo", "test", "-Zbuild-std"])
+        .args(["--target", host, "-p", &part.package(), "--no-fail-fast"])
+        .args(["--lib", "--bins", "--tests"])
+        .args(["--message-format", "short", "--color", "never"])
+        .env("RUSTFLAGS", format!("-Zsanitizer={}", sanitizer.name()))
+        .env("RUSTDOCFLAGS", format!("-Zsanitizer={}", sanitizer.name()))
+        .env("CARGO_TARGET_DIR", "target/sanitize")
+        .env(common::sanitize::VARIABLE, sanitizer.name())
+        .env("RUST_BACKTRACE", "0");
+    command
+}
+
+/// What the sanitizer found, from the summary it prints after every report, like
+/// `SUMMARY: ThreadSanitizer: data race src/lib.rs:42:9 in part_21::SpinLock::lock`.
+/// The same race is often reported many times, but it's only listed once.
+pub fn races(stderr: &str) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    stderr
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("SUMMARY: ThreadSanitizer: "))
+        .filter(|race| seen.insert(*race))
+        .map(str::to_string)
+        .collect()
+}
+
+/// What went wrong when nothing was tested, and how to fix it, if it's a missing toolchain
+pub fn setup_problem(stderr: &str) -> Option<&'static str> {
+    if stderr.contains("toolchain 'nightly") && stderr.contains("not installed") {
+        Some("Sanitizers need the nightly compiler: `rustup toolchain install nightly`")
+    } else if stderr.contains("rust-src") {
+        Some(
+            "Building the standard library with the sanitizer needs its source: \
+            `rustup component add rust-src --toolchain nightly`",
+        )
+    } else {
+        None
+    }
+}
+
+/// How a part went under a sanitizer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sanitized {
+    pub report: Report,
+    pub races: Vec<String>,
+    pub success: bool,
+    pub stderr: String,
+}
+
+/// Runs the tests of `part` under `sanitizer`
+pub fn test(part: &Part, sanitizer: Sanitizer) -> io::Result<Sanitized> {
+    let output = command(part, sanitizer, &host()?).output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+    Ok(Sanitized {
+        report: Report::parse(&stdout, &stderr),
+        races: races(&stderr),
+        success: output.status.success(),
+        stderr,
+    })
+}
+
+#[test]
+fn parses_sanitizers() {
+    assert_eq!("thread".parse(), Ok(Sanitizer::Thread));
+    assert!("address".parse::<Sanitizer>().is_err());
+}
+
+#[test]
+fn lists_every_race_once() {
+    let stderr = "\
+running 2 tests
+==================
+WARNING: ThreadSanitizer: data race (pid=4242)
+  Write of size 8 at 0x7b0800000010 by thread T2:
+    #0 part_21::SpinLock::lock src/lib.rs:42:9 (part_21-1a+0x1234)
+SUMMARY: ThreadSanitizer: data race src/lib.rs:42:9 in part_21::SpinLock::lock
+==================
+==================
+WARNING: ThreadSanitizer: data race (pid=4242)
+SUMMARY: ThreadSanitizer: data race src/lib.rs:42:9 in part_21::SpinLock::lock
+==================
+SUMMARY: ThreadSanitizer: data race src/lib.rs:57:13 in part_21::SpinLock::unlock
+ThreadSanitizer: reported 3 warnings
+";
+    assert_eq!(
+        races(stderr),
+        [
+            "data race src/lib.rs:42:9 in part_21::SpinLock::lock",
+            "data race src/lib.rs:57:13 in part_21::SpinLock::unlock"
+        ]
+    );
+    assert!(races("test result: ok. 2 passed").is_empty());
+}
+
+#[test]
+fn explains_missing_toolchains() {
+    assert!(
+        setup_problem("error: toolchain 'nightly-x86_64-unknown-linux-gnu' is not installed")
+            .unwrap()
+            .contains("rustup toolchain install nightly")
+    );
+    assert!(setup_problem(
+        "error: \"/root/.rustup/toolchains/nightly/lib/rustlib/src/rust/library/Cargo.lock\" \
+         does not exist, unable to build with the standard library, try:\n\
+         rustup component add rust-src --toolchain nightly"
+    )
+    .unwrap()
+    .contains("rust-src"));
+    assert_eq!(setup_problem("error[E0308]: mismatched types"), None);
+}
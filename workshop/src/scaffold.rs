@@ -0,0 +1,181 @@
+//! `workshop new-part`: sets up the next part, with the layout every part has
+
+use std::{fs, io, path::Path};
+
+/// The files of a new part, relative to its directory. `{number}` and `{title}` get filled in.
+const FILES: [(&str, &str); 5] = [
+    (
+        "Cargo.toml",
+        include_str!("../templates/Cargo.toml.template"),
+    ),
+    ("src/lib.rs", include_str!("../templates/lib.rs.template")),
+    ("src/main.rs", include_str!("../templates/main.rs.template")),
+    (
+        "src/solutions.rs",
+        include_str!("../templates/solutions.rs.template"),
+    ),
+    (
+        "tests/solutions.rs",
+        include_str!("../templates/tests-solutions.rs.template"),
+    ),
+];
+
+/// The part's section in the README, which goes before the conclusion
+const SECTION: &str = include_str!("../templates/README.md.template");
+const CONCLUSION: &str = "## Conclusion";
+
+/// A part which is about to be added to the workshop
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewPart {
+    pub number: u32,
+    pub title: String,
+}
+
+impl NewPart {
+    /// The part after the last one in the workshop
+    pub fn next(numbers: impl IntoIterator<Item = u32>, title: &str) -> Self {
+        NewPart {
+            number: numbers.into_iter().max().unwrap_or(0) + 1,
+            title: title.trim().to_string(),
+        }
+    }
+
+    fn fill(&self, template: &str) -> String {
+        template
+            .replace("{number}", &self.number.to_string())
+            .replace("{title}", &self.title)
+    }
+
+    /// Writes the part's crate into `workspace`, adds it to the workspace and gives it a section in the README.
+    /// Doesn't touch anything if there's already a directory for the part.
+    pub fn create(&self, workspace: &Path) -> io::Result<()> {
+        let dir = workspace.join(format!("part-{}", self.number));
+        if dir.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", dir.display()),
+            ));
+        }
+        let readme = workspace.join("README.md");
+        let readme_contents = self.add_section(&fs::read_to_string(&readme)?)?;
+        let manifest = workspace.join("Cargo.toml");
+        let manifest_contents = self.register(&fs::read_to_string(&manifest)?)?;
+
+        for (file, template) in FILES {
+            let path = dir.join(file);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, self.fill(template))?;
+        }
+        fs::write(readme, readme_contents)?;
+        if let Some(manifest_contents) = manifest_contents {
+            fs::write(manifest, manifest_contents)?;
+        }
+        Ok(())
+    }
+
+    /// The README with the part's section before the conclusion
+    fn add_section(&self, readme: &str) -> io::Result<String> {
+        let Some(conclusion) = readme.find(CONCLUSION) else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("The README has no `{CONCLUSION}` to put the part before"),
+            ));
+        };
+        let mut readme = readme.to_string();
+        readme.insert_str(conclusion, &self.fill(SECTION));
+        Ok(readme)
+    }
+
+    /// The workspace's manifest with the part in its members,
+    /// or `None` if they already cover it, like with `part-*`
+    fn register(&self, manifest: &str) -> io::Result<Option<String>> {
+        let package = format!("\"part-{}\"", self.number);
+        if manifest.contains("\"part-*\"") || manifest.contains(&package) {
+            return Ok(None);
+        }
+        let members = "members = [";
+        let Some(start) = manifest.find(members) else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "The workspace's Cargo.toml has no members",
+            ));
+        };
+        let mut manifest = manifest.to_string();
+        manifest.insert_str(start + members.len(), &format!("{package}, "));
+        Ok(Some(manifest))
+    }
+}
+
+#[cfg(test)]
+fn temporary_workspace(name: &str, members: &str) -> std::path::PathBuf {
+    let workspace = std::env::temp_dir().join(format!("workshop-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&workspace);
+    fs::create_dir_all(&workspace).unwrap();
+    fs::write(
+        workspace.join("Cargo.toml"),
+        format!("[workspace]\nmembers = [{members}]\n"),
+    )
+    .unwrap();
+    fs::write(
+        workspace.join("README.md"),
+        "## Part 1: threads\n\n---\n\n## Conclusion\n\nThat's it!\n",
+    )
+    .unwrap();
+    workspace
+}
+
+#[test]
+fn numbers_the_part_after_the_last() {
+    assert_eq!(NewPart::next([1, 3, 2], " channels ").number, 4);
+    assert_eq!(NewPart::next([], "threads").number, 1);
+    assert_eq!(NewPart::next([], " channels ").title, "channels");
+}
+
+#[test]
+fn creates_a_part_like_the_others() {
+    let workspace = temporary_workspace("new-part", "\"part-*\"");
+    let part = NewPart::next([1], "parallel sums");
+    part.create(&workspace).unwrap();
+
+    let dir = workspace.join("part-2");
+    for (file, _) in FILES {
+        let contents = fs::read_to_string(dir.join(file)).unwrap();
+        assert!(!contents.contains("{number}"), "{file}");
+    }
+    let manifest = fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+    assert!(manifest.contains("name = \"part-2\""));
+    assert!(manifest.contains("solutions = []"));
+    assert!(fs::read_to_string(dir.join("src/lib.rs"))
+        .unwrap()
+        .contains("todo!()"));
+    assert!(fs::read_to_string(dir.join("src/main.rs"))
+        .unwrap()
+        .contains("use part_2::"));
+
+    let readme = fs::read_to_string(workspace.join("README.md")).unwrap();
+    let section = readme.find("## Part 2: parallel sums").unwrap();
+    assert!(readme.find("## Part 1: threads").unwrap() < section);
+    assert!(section < readme.find("## Conclusion").unwrap());
+    // The glob already covers it
+    assert_eq!(
+        fs::read_to_string(workspace.join("Cargo.toml")).unwrap(),
+        "[workspace]\nmembers = [\"part-*\"]\n"
+    );
+
+    let error = part.create(&workspace).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+    fs::remove_dir_all(workspace).unwrap();
+}
+
+#[test]
+fn registers_parts_which_the_members_miss() {
+    let workspace = temporary_workspace("register", "\"part-1\", \"common\"");
+    NewPart::next([1], "channels").create(&workspace).unwrap();
+    assert_eq!(
+        fs::read_to_string(workspace.join("Cargo.toml")).unwrap(),
+        "[workspace]\nmembers = [\"part-2\", \"part-1\", \"common\"]\n"
+    );
+    fs::remove_dir_all(workspace).unwrap();
+}
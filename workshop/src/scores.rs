@@ -0,0 +1,241 @@
+//! `workshop scores`: a leaderboard of the fastest implementations in the parts `workshop bench`
+//! runs, kept in `.workshop/scores.json`. Nobody is on it unless they run
+//! `workshop bench --score <name>`, and only their best time on each part's own size counts.
+
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::benchmarks::{Kind, Measured, BENCHMARKS};
+
+/// Where the scores are kept in the workspace
+pub fn file(workspace: &Path) -> PathBuf {
+    workspace.join(".workshop").join("scores.json")
+}
+
+/// Somebody's best time on one of the implementations in a part
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Score {
+    pub name: String,
+    pub part: u32,
+    pub benchmark: String,
+    pub size: usize,
+    #[serde(rename = "median_ns", with = "nanos")]
+    pub median: Duration,
+    /// When it was measured, in RFC 3339
+    pub recorded: String,
+}
+
+mod nanos {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u128(duration.as_nanos())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+/// The size a part is scored on: its own, which is the biggest it's benchmarked with
+pub fn standard_size(part: u32) -> Option<usize> {
+    BENCHMARKS
+        .iter()
+        .find(|benchmark| benchmark.part == part)
+        .and_then(|benchmark| benchmark.sizes.last().copied())
+}
+
+/// Everybody's best times
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Scores {
+    scores: Vec<Score>,
+}
+
+impl Scores {
+    /// Reads the scores from `path`, which is empty before anybody has been scored
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text).map_err(|error| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{} is broken: {error}", path.display()),
+                )
+            }),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        json.push('\n');
+        fs::write(path, json)
+    }
+
+    /// Keeps `name`'s times from `measured` which beat their best, and gives how many did. Only
+    /// the standard size of each part counts, and not the implementations which are there for
+    /// reference, like the standard library's sort.
+    pub fn record(&mut self, name: &str, measured: &[Measured], now: SystemTime) -> usize {
+        let now = humantime::format_rfc3339_seconds(now).to_string();
+        let mut improved = 0;
+        for measured in measured {
+            if measured.kind == Kind::Other || standard_size(measured.part) != Some(measured.size) {
+                continue;
+            }
+            let previous = self.scores.iter_mut().find(|score| {
+                score.name == name
+                    && score.part == measured.part
+                    && score.benchmark == measured.benchmark
+                    && score.size == measured.size
+            });
+            let score = Score {
+                name: name.to_string(),
+                part: measured.part,
+                benchmark: measured.benchmark.clone(),
+                size: measured.size,
+                median: measured.median,
+                recorded: now.clone(),
+            };
+            match previous {
+                Some(previous) if previous.median <= measured.median => continue,
+                Some(previous) => *previous = score,
+                None => self.scores.push(score),
+            }
+            improved += 1;
+        }
+        improved
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.scores.is_empty()
+    }
+}
+
+/// A table for every implementation, with the fastest first
+impl fmt::Display for Scores {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut tables: BTreeMap<(u32, usize, &str), Vec<&Score>> = BTreeMap::new();
+        for score in &self.scores {
+            tables
+                .entry((score.part, score.size, &score.benchmark))
+                .or_default()
+                .push(score);
+        }
+        let width = self
+            .scores
+            .iter()
+            .map(|score| score.name.chars().count())
+            .max()
+            .unwrap_or(0);
+        for (index, ((part, size, benchmark), mut scores)) in tables.into_iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "Part {part}, {benchmark}, with a size of {size}:")?;
+            scores.sort_by(|a, b| a.median.cmp(&b.median).then(a.name.cmp(&b.name)));
+            for (rank, score) in scores.into_iter().enumerate() {
+                writeln!(
+                    f,
+                    "{:>4}. {:<width$}  {:>10}",
+                    rank + 1,
+                    score.name,
+                    format!("{:.2?}", score.median)
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn measured(part: u32, size: usize, benchmark: &str, millis: u64) -> Measured {
+    Measured {
+        part,
+        size,
+        benchmark: benchmark.to_string(),
+        kind: Kind::of(benchmark),
+        median: Duration::from_millis(millis),
+        fastest: Duration::from_millis(millis),
+    }
+}
+
+#[test]
+fn keeps_the_best_times_on_the_standard_size() {
+    let mut scores = Scores::default();
+    let now = SystemTime::UNIX_EPOCH;
+    let first = [
+        measured(48, 4_000_000, "Threaded, cutoff 100", 30),
+        measured(48, 4_000_000, "sort_unstable", 10),
+        measured(48, 100_000, "Threaded, cutoff 100", 1),
+        measured(16, 10_000, "Thread pool", 5),
+    ];
+    assert_eq!(scores.record("alice", &first, now), 2);
+    let second = [
+        measured(48, 4_000_000, "Threaded, cutoff 100", 40),
+        measured(16, 10_000, "Thread pool", 4),
+    ];
+    assert_eq!(scores.record("alice", &second, now), 1);
+    assert_eq!(scores.record("bob", &second, now), 2);
+
+    assert_eq!(scores.scores.len(), 4);
+    assert_eq!(scores.scores[0].median, Duration::from_millis(30));
+    assert_eq!(scores.scores[1].median, Duration::from_millis(4));
+    assert_eq!(scores.scores[0].recorded, "1970-01-01T00:00:00Z");
+}
+
+#[test]
+fn ranks_the_fastest_first() {
+    let mut scores = Scores::default();
+    let now = SystemTime::UNIX_EPOCH;
+    scores.record(
+        "alice",
+        &[
+            measured(16, 10_000, "Thread pool", 5),
+            measured(48, 4_000_000, "Rayon, cutoff 100", 20),
+        ],
+        now,
+    );
+    scores.record("bob", &[measured(16, 10_000, "Thread pool", 3)], now);
+    assert_eq!(
+        scores.to_string(),
+        "Part 16, Thread pool, with a size of 10000:\n   \
+            1. bob        3.00ms\n   \
+            2. alice      5.00ms\n\
+         \n\
+         Part 48, Rayon, cutoff 100, with a size of 4000000:\n   \
+            1. alice     20.00ms\n"
+    );
+}
+
+#[test]
+fn saves_and_loads_scores() {
+    let path = std::env::temp_dir()
+        .join(format!("workshop-scores-{}", std::process::id()))
+        .join("scores.json");
+    assert!(Scores::load(&path).unwrap().is_empty());
+
+    let mut scores = Scores::default();
+    scores.record(
+        "alice",
+        &[measured(16, 10_000, "Thread pool", 5)],
+        SystemTime::UNIX_EPOCH,
+    );
+    scores.save(&path).unwrap();
+    assert_eq!(Scores::load(&path).unwrap(), scores);
+    let json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(json[0]["median_ns"], 5_000_000);
+    fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
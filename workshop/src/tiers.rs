@@ -0,0 +1,126 @@
+//! The exercises which go beyond a part, behind cargo features of the part, so that the same
+//! workshop works for beginners and for those who want more: `bonus` ones, like the bonus
+//! programs in the first parts, and `advanced` ones, like the lock-free variants
+
+use std::{collections::BTreeMap, fmt, fs, io, path::Path, str::FromStr};
+
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Tier {
+    Bonus,
+    Advanced,
+}
+
+impl Tier {
+    pub const ALL: [Tier; 2] = [Tier::Bonus, Tier::Advanced];
+
+    /// The part's feature which builds the tier's exercises, and their tests
+    pub fn feature(self) -> &'static str {
+        match self {
+            Self::Bonus => "bonus",
+            Self::Advanced => "advanced",
+        }
+    }
+}
+
+impl fmt::Display for Tier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.feature())
+    }
+}
+
+impl FromStr for Tier {
+    type Err = String;
+
+    fn from_str(tier: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|candidate| candidate.feature() == tier)
+            .ok_or_else(|| format!("There's no {tier} exercises, pick bonus or advanced"))
+    }
+}
+
+#[derive(Deserialize)]
+struct Manifest {
+    #[serde(default)]
+    features: BTreeMap<String, toml::Value>,
+    #[serde(default)]
+    bin: Vec<Bin>,
+}
+
+#[derive(Deserialize)]
+struct Bin {
+    name: String,
+    #[serde(default, rename = "required-features")]
+    required_features: Vec<String>,
+}
+
+/// Which tiers a part has, from the features in its `Cargo.toml`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tiers {
+    /// In order, from `Bonus` to `Advanced`
+    pub tiers: Vec<Tier>,
+    /// The programs which are only built with a tier's feature
+    pub programs: BTreeMap<String, Tier>,
+}
+
+impl Tiers {
+    /// Reads the `Cargo.toml` in `dir`
+    pub fn read(dir: &Path) -> io::Result<Self> {
+        Self::parse(&fs::read_to_string(dir.join("Cargo.toml"))?)
+    }
+
+    pub fn parse(manifest: &str) -> io::Result<Self> {
+        let manifest: Manifest = toml::from_str(manifest)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        let tier = |feature: &String| Tier::from_str(feature).ok();
+        Ok(Self {
+            tiers: Tier::ALL
+                .into_iter()
+                .filter(|tier| manifest.features.contains_key(tier.feature()))
+                .collect(),
+            programs: manifest
+                .bin
+                .into_iter()
+                .filter_map(|bin| {
+                    let tier = bin.required_features.iter().filter_map(tier).max()?;
+                    Some((bin.name, tier))
+                })
+                .collect(),
+        })
+    }
+}
+
+#[test]
+fn reads_tiers_from_manifests() {
+    let tiers = Tiers::parse(
+        r#"
+[package]
+name = "part-1"
+
+[[bin]]
+name = "scoped"
+required-features = ["bonus"]
+
+[[bin]]
+name = "plain"
+
+[features]
+solutions = []
+bonus = []
+advanced = ["dep:crossbeam-deque"]
+"#,
+    )
+    .unwrap();
+    assert_eq!(tiers.tiers, [Tier::Bonus, Tier::Advanced]);
+    assert_eq!(
+        tiers.programs,
+        BTreeMap::from([("scoped".to_string(), Tier::Bonus)])
+    );
+
+    let none = Tiers::parse("[package]\nname = \"part-5\"\n[features]\nsolutions = []").unwrap();
+    assert_eq!(none, Tiers::default());
+    assert_eq!("advanced".parse(), Ok(Tier::Advanced));
+    assert!("expert".parse::<Tier>().is_err());
+}
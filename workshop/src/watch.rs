@@ -0,0 +1,431 @@
+//! `workshop watch`: a dashboard of every part, which runs a part's tests again whenever one of
+//! its files changes
+
+use std::{
+    collections::VecDeque,
+    io,
+    path::PathBuf,
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+    time::{Duration, Instant},
+};
+
+use notify::{EventKind, RecursiveMode, Watcher};
+use ratatui::{
+    crossterm::event::{self, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style, Stylize},
+    text::Line,
+    widgets::{Cell, Row, Table, TableState},
+    DefaultTerminal, Frame,
+};
+
+use crate::{
+    dependencies::Graph,
+    parts::{self, Part},
+    progress::{self, PartStatus, Progress},
+    report::{self, Report, Status},
+};
+
+/// The kinds of files a part is made of, so that editors' swap files and backups are left alone
+const SOURCE_EXTENSIONS: [&str; 4] = ["rs", "toml", "stderr", "wgsl"];
+
+/// How a part did while watching
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum State {
+    /// Not tested since the dashboard started, but maybe by `check` before
+    Untested(Option<PartStatus>),
+    Queued,
+    Running,
+    Tested {
+        report: Report,
+        elapsed: Duration,
+    },
+    /// Cargo couldn't be run at all
+    Broken(String),
+}
+
+/// What happens while watching, besides keys being pressed
+enum Message {
+    Changed(Vec<PathBuf>),
+    Tested {
+        index: usize,
+        report: io::Result<Report>,
+        elapsed: Duration,
+    },
+}
+
+/// Every part, and how it's doing
+pub struct Dashboard {
+    parts: Vec<Part>,
+    states: Vec<State>,
+    /// The parts waiting for their turn, since they're tested one at a time
+    queue: VecDeque<usize>,
+    running: Option<usize>,
+    table: TableState,
+    /// Which packages depend on which, to test the parts built on a part which changed
+    graph: Graph,
+}
+
+impl Dashboard {
+    pub fn new(parts: Vec<Part>, progress: &Progress, graph: Graph) -> Self {
+        let states = parts
+            .iter()
+            .map(|part| State::Untested(progress.get(part).map(|progress| progress.status)))
+            .collect();
+        Self {
+            parts,
+            states,
+            queue: VecDeque::new(),
+            running: None,
+            table: TableState::default().with_selected(0),
+            graph,
+        }
+    }
+
+    /// The parts which `paths` belong to, and the ones which depend on those, in order
+    pub fn affected(&self, paths: &[PathBuf]) -> Vec<usize> {
+        let mut affected: Vec<usize> = paths
+            .iter()
+            .filter(|path| {
+                path.extension()
+                    .and_then(|extension| extension.to_str())
+                    .is_some_and(|extension| SOURCE_EXTENSIONS.contains(&extension))
+            })
+            .filter_map(|path| self.graph.package_of(path))
+            .flat_map(|package| self.graph.affected(package))
+            .filter_map(|package| {
+                self.parts
+                    .iter()
+                    .position(|part| part.test_packages().contains(&package))
+            })
+            .collect();
+        affected.sort_unstable();
+        affected.dedup();
+        affected
+    }
+
+    /// Tests the part again once it's its turn, unless it's waiting already
+    pub fn queue(&mut self, index: usize) {
+        if !self.queue.contains(&index) {
+            self.queue.push_back(index);
+            if self.running != Some(index) {
+                self.states[index] = State::Queued;
+            }
+        }
+    }
+
+    /// Starts testing the next part in the queue, if nothing's being tested
+    fn start_next(&mut self, sender: &Sender<Message>) {
+        if self.running.is_some() {
+            return;
+        }
+        let Some(index) = self.queue.pop_front() else {
+            return;
+        };
+        self.running = Some(index);
+        self.states[index] = State::Running;
+        let part = self.parts[index].clone();
+        let sender = sender.clone();
+        thread::spawn(move || {
+            let start = Instant::now();
+            let report = report::test(&part, None).map(|(report, _)| report);
+            if let Ok(report) = &report {
+                // The dashboard is no place to complain about the progress file, `check` does that
+                let _ = progress::record(&part, None, report);
+            }
+            let _ = sender.send(Message::Tested {
+                index,
+                report,
+                elapsed: start.elapsed(),
+            });
+        });
+    }
+
+    fn finish(&mut self, index: usize, report: io::Result<Report>, elapsed: Duration) {
+        self.running = None;
+        self.states[index] = match report {
+            // It changed again while it was being tested, and is waiting for another go
+            _ if self.queue.contains(&index) => State::Queued,
+            Ok(report) => State::Tested { report, elapsed },
+            Err(error) => State::Broken(format!("Couldn't run cargo: {error}")),
+        };
+    }
+
+    fn row(&self, index: usize) -> Row<'static> {
+        let state = &self.states[index];
+        let (status, color) = match state {
+            State::Untested(None) => ("", Color::Reset),
+            State::Untested(Some(PartStatus::Passing)) => ("✓ passed before", Color::Green),
+            State::Untested(Some(_)) => ("✗ failed before", Color::Red),
+            State::Queued => ("queued", Color::Yellow),
+            State::Running => ("running", Color::Yellow),
+            State::Tested {
+                report: Report::DoesNotCompile(_),
+                ..
+            } => ("✗ doesn't compile", Color::Red),
+            State::Tested { report, .. } if report.passed() => ("✓ passing", Color::Green),
+            State::Tested { .. } => ("✗ failing", Color::Red),
+            State::Broken(_) => ("✗ broken", Color::Red),
+        };
+        let (tests, took, details) = match state {
+            State::Tested { report, elapsed } => (
+                format!(
+                    "{}/{}",
+                    report.count(Status::Passed),
+                    report.results().len() - report.count(Status::Ignored)
+                ),
+                format!("{elapsed:.1?}"),
+                details(report).unwrap_or_default(),
+            ),
+            State::Broken(error) => (String::new(), String::new(), error.clone()),
+            _ => Default::default(),
+        };
+        Row::new([
+            Cell::from(self.parts[index].to_string()),
+            Cell::from(status).fg(color),
+            Cell::from(tests),
+            Cell::from(took),
+            Cell::from(details),
+        ])
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [header, table, footer] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        frame.render_widget(
+            Line::from(
+                "Watching for changes. ↑↓ picks a part, r or enter tests it again, q quits.",
+            )
+            .bold(),
+            header,
+        );
+        let rows: Vec<_> = (0..self.parts.len()).map(|index| self.row(index)).collect();
+        let widths = [
+            Constraint::Percentage(30),
+            Constraint::Length(18),
+            Constraint::Length(7),
+            Constraint::Length(8),
+            Constraint::Fill(1),
+        ];
+        let table_widget = Table::new(rows, widths)
+            .header(Row::new(["Part", "Status", "Tests", "Took", "Details"]).underlined())
+            .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(table_widget, table, &mut self.table);
+
+        let status = match self.running {
+            Some(index) if self.queue.is_empty() => format!("Testing {}", self.parts[index]),
+            Some(index) => format!(
+                "Testing {}, and {} more after it",
+                self.parts[index],
+                self.queue.len()
+            ),
+            None => "Waiting for changes".to_string(),
+        };
+        frame.render_widget(Line::from(status).italic(), footer);
+    }
+}
+
+/// The first thing to fix: the first compiler error, or why the first failing test failed
+pub fn details(report: &Report) -> Option<String> {
+    match report {
+        Report::DoesNotCompile(errors) => errors.first().cloned(),
+        Report::Tested(results) => {
+            let failed = results
+                .iter()
+                .find(|result| result.status == Status::Failed)?;
+            let reason = failed.reason.as_deref().unwrap_or("failed");
+            Some(format!(
+                "{}: {}",
+                failed.name,
+                reason.lines().next().unwrap_or(reason)
+            ))
+        }
+    }
+}
+
+/// Shows the dashboard until `q` is pressed, testing parts again whenever they change
+pub fn run(parts: Vec<Part>) -> io::Result<()> {
+    let workspace = parts::workspace();
+    let progress = Progress::load(&progress::file(&workspace))?;
+    let mut dashboard = Dashboard::new(parts, &progress, Graph::load(&workspace)?);
+
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = {
+        let sender = sender.clone();
+        notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            if let Ok(event) = event {
+                if matches!(
+                    event.kind,
+                    EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+                ) {
+                    let _ = sender.send(Message::Changed(event.paths));
+                }
+            }
+        })
+        .map_err(io::Error::other)?
+    };
+    watcher
+        .watch(&workspace, RecursiveMode::Recursive)
+        .map_err(io::Error::other)?;
+
+    let mut terminal = ratatui::init();
+    let result = show(&mut terminal, &mut dashboard, &sender, &receiver);
+    ratatui::restore();
+    result
+}
+
+fn show(
+    terminal: &mut DefaultTerminal,
+    dashboard: &mut Dashboard,
+    sender: &Sender<Message>,
+    receiver: &Receiver<Message>,
+) -> io::Result<()> {
+    loop {
+        terminal.draw(|frame| dashboard.draw(frame))?;
+
+        if event::poll(Duration::from_millis(100))? {
+            if let event::Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    match key.code {
+                        KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                        KeyCode::Up | KeyCode::Char('k') => dashboard.table.select_previous(),
+                        KeyCode::Down | KeyCode::Char('j') => dashboard.table.select_next(),
+                        KeyCode::Enter | KeyCode::Char('r') => {
+                            if let Some(index) = dashboard.table.selected() {
+                                dashboard.queue(index.min(dashboard.parts.len() - 1));
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        for message in receiver.try_iter() {
+            match message {
+                Message::Changed(paths) => {
+                    if paths.iter().any(|path| path.ends_with("Cargo.toml")) {
+                        // A dependency might have been added. If the manifest's broken, the
+                        // part's test says so, so stick with the graph from before.
+                        if let Ok(graph) = Graph::load(&parts::workspace()) {
+                            dashboard.graph = graph;
+                        }
+                    }
+                    for index in dashboard.affected(&paths) {
+                        dashboard.queue(index);
+                    }
+                }
+                Message::Tested {
+                    index,
+                    report,
+                    elapsed,
+                } => dashboard.finish(index, report, elapsed),
+            }
+        }
+        dashboard.start_next(sender);
+    }
+}
+
+#[cfg(test)]
+fn dashboard() -> Dashboard {
+    let workspace = parts::workspace();
+    Dashboard::new(
+        parts::discover(&workspace).unwrap(),
+        &Progress::default(),
+        Graph::load(&workspace).unwrap(),
+    )
+}
+
+#[test]
+fn finds_the_parts_which_changed() {
+    let dashboard = dashboard();
+    let workspace = parts::workspace();
+    let part = |number: u32| {
+        dashboard
+            .parts
+            .iter()
+            .position(|part| part.number == number)
+            .unwrap()
+    };
+    let changed = [
+        workspace.join("part-1/src/bin/scoped.rs"),
+        workspace.join("part-5/src/.lib.rs.swp"),
+        workspace.join("part-40/compile-fail/tests/ui/cell_counter_on_many_threads.stderr"),
+        workspace.join("target/debug/build/part-5.d"),
+        workspace.join("README.md"),
+    ];
+    assert_eq!(dashboard.affected(&changed), [part(1), part(40)]);
+    assert!(dashboard
+        .affected(&[std::path::Path::new("part-5/src/lib.rs").to_path_buf()])
+        .is_empty());
+
+    // Other parts use part 5, like part 6 does in its tests
+    let affected = dashboard.affected(&[
+        workspace.join("part-5/src/lib.rs"),
+        workspace.join("part-5/src/main.rs"),
+    ]);
+    assert_eq!(affected[..2], [part(5), part(6)]);
+    assert!(affected.contains(&part(17)));
+    assert!(!affected.contains(&part(4)));
+    assert_eq!(
+        dashboard.affected(&[workspace.join("part-6/src/lib.rs")]),
+        [part(6)]
+    );
+}
+
+#[test]
+fn tests_parts_one_at_a_time() {
+    let mut dashboard = dashboard();
+    dashboard.queue(3);
+    dashboard.queue(1);
+    dashboard.queue(3);
+    assert_eq!(dashboard.queue, [3, 1]);
+    assert_eq!(dashboard.states[1], State::Queued);
+
+    dashboard.queue.pop_front();
+    dashboard.running = Some(3);
+    dashboard.states[3] = State::Running;
+    // Changing while it's tested has it tested again afterwards
+    dashboard.queue(3);
+    assert_eq!(dashboard.states[3], State::Running);
+    dashboard.finish(3, Ok(Report::DoesNotCompile(Vec::new())), Duration::ZERO);
+    assert_eq!(dashboard.running, None);
+    assert_eq!(dashboard.queue, [1, 3]);
+    assert_eq!(dashboard.states[3], State::Queued);
+}
+
+#[test]
+fn details_the_first_problem() {
+    use crate::report::TestResult;
+
+    let error = "part-4/src/lib.rs:3:9: error[E0425]: cannot find value `balance` in this scope";
+    assert_eq!(
+        details(&Report::DoesNotCompile(vec![error.to_string()])).as_deref(),
+        Some(error)
+    );
+    let result = |name: &str, status, reason: Option<&str>| TestResult {
+        target: "src/lib.rs".to_string(),
+        name: name.to_string(),
+        status,
+        reason: reason.map(str::to_string),
+    };
+    let report = Report::Tested(vec![
+        result("deposits", Status::Passed, None),
+        result(
+            "withdraws",
+            Status::Failed,
+            Some("assertion `left == right` failed\n  left: 1\n right: 2"),
+        ),
+    ]);
+    assert_eq!(
+        details(&report).as_deref(),
+        Some("withdraws: assertion `left == right` failed")
+    );
+    assert_eq!(details(&Report::Tested(Vec::new())), None);
+}
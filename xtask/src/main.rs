@@ -0,0 +1,52 @@
+use std::{env, process::ExitCode};
+
+use workshop::parts;
+
+mod verify;
+
+const USAGE: &str = "\
+Usage: cargo xtask <command>
+
+Commands:
+  verify [part...]  Builds and tests every part, or just the ones given, with and without
+                    `--features solutions`, and fails if anything broke
+";
+
+/// Tasks for working on the workshop itself, rather than doing it.
+/// Run with `cargo xtask verify` after changing something every part uses, like `common`.
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let workspace = parts::workspace();
+    let all = match parts::discover(&workspace) {
+        Ok(parts) => parts,
+        Err(error) => {
+            eprintln!(
+                "Couldn't find the parts in {}: {error}",
+                workspace.display()
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    match args.as_slice() {
+        ["verify"] => verify::run_all(&all),
+        ["verify", names @ ..] => {
+            let mut parts = Vec::new();
+            for name in names {
+                match parts::find(&all, name) {
+                    Some(part) => parts.push(part.clone()),
+                    None => {
+                        eprintln!("There's no part {name}");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+            verify::run_all(&parts)
+        }
+        _ => {
+            eprint!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
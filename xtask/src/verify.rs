@@ -0,0 +1,561 @@
+//! Building and testing every part, to check that nothing broke.
+//! The exercises aren't implemented in the workshop itself, so tests failing on a `todo!()` are
+//! expected, like the ones in `expected-failures.toml`. Any other failing test, or a part which
+//! doesn't compile, is a regression.
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use serde::Deserialize;
+use workshop::{
+    parts::Part,
+    process,
+    report::{Report, Status, TestResult},
+    tiers::Tier,
+};
+
+/// One thing cargo does for every part, in order
+struct Stage {
+    name: &'static str,
+    args: &'static [&'static str],
+    /// Built along with the features of every bonus and advanced exercise the part has
+    features: &'static [&'static str],
+    /// How long it may take before it's stopped, for the stages which run tests
+    timeout: Option<Duration>,
+}
+
+/// Long enough for every part's tests, as long as none of them are stuck
+const TEST_TIMEOUT: Duration = Duration::from_secs(180);
+
+const STAGES: [Stage; 3] = [
+    Stage {
+        name: "build",
+        args: &["build", "--all-targets"],
+        features: &[],
+        timeout: None,
+    },
+    Stage {
+        name: "test",
+        args: &["test", "--no-fail-fast"],
+        features: &[],
+        timeout: Some(TEST_TIMEOUT),
+    },
+    Stage {
+        name: "solutions",
+        args: &["test", "--no-fail-fast"],
+        features: &["solutions"],
+        timeout: Some(TEST_TIMEOUT),
+    },
+];
+
+/// What `common::ensure_can_run_parallel_test` panics with, which is up to the machine and not the code
+const SINGLE_CORE: &str = "Please run on a system with more than 1 core";
+
+/// What's expected to go wrong in a part's tests, from `expected-failures.toml`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Expected {
+    /// Tests which fail without hitting a `todo!()`
+    pub tests: Vec<String>,
+    /// Whether the tests get stuck
+    pub hangs: bool,
+    /// Whether the tests abort, which cargo reports as a signal
+    pub aborts: bool,
+}
+
+/// What's expected to go wrong in every part, by package name
+pub fn expected_failures() -> BTreeMap<String, Expected> {
+    toml::from_str(include_str!("../expected-failures.toml"))
+        .expect("xtask/expected-failures.toml is broken")
+}
+
+/// How cargo stopped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ending {
+    /// On its own, whether everything passed or not
+    Finished,
+    /// Failed without a failing test or a compiler error, with what it said
+    Error(String),
+    /// The tests were killed by a signal, like when they abort
+    Aborted(String),
+    /// It was stopped for taking too long, with the tests which were still running
+    TimedOut(Vec<String>),
+}
+
+/// How one stage went for a part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StageResult {
+    pub stage: &'static str,
+    pub elapsed: Duration,
+    pub report: Report,
+    pub ending: Ending,
+}
+
+/// What a test's failure means for the verification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Failure {
+    Unimplemented,
+    /// Listed in `expected-failures.toml`
+    Expected,
+    /// Needs more cores than the machine has
+    SingleCore,
+    Regression,
+}
+
+fn failure(result: &TestResult, expected: &Expected) -> Failure {
+    if result.is_unimplemented() {
+        Failure::Unimplemented
+    } else if expected.tests.contains(&result.name) {
+        Failure::Expected
+    } else if result
+        .reason
+        .as_deref()
+        .is_some_and(|reason| reason.starts_with(SINGLE_CORE))
+    {
+        Failure::SingleCore
+    } else {
+        Failure::Regression
+    }
+}
+
+impl StageResult {
+    /// Whether what's left of the output can't be trusted, like when the tests got stuck
+    fn cut_short(&self, expected: &Expected) -> bool {
+        match self.ending {
+            Ending::TimedOut(_) => expected.hangs,
+            Ending::Aborted(_) => expected.aborts,
+            Ending::Finished | Ending::Error(_) => false,
+        }
+    }
+
+    /// Everything that went wrong, except what's `expected`
+    pub fn regressions(&self, expected: &Expected) -> Vec<String> {
+        if self.cut_short(expected) {
+            return Vec::new();
+        }
+        let mut regressions = match &self.ending {
+            Ending::Finished => Vec::new(),
+            Ending::Error(error) | Ending::Aborted(error) => {
+                vec![format!("{}: {error}", self.stage)]
+            }
+            Ending::TimedOut(running) if running.is_empty() => {
+                vec![format!("{}: timed out after {TEST_TIMEOUT:?}", self.stage)]
+            }
+            Ending::TimedOut(running) => vec![format!(
+                "{}: timed out after {TEST_TIMEOUT:?}, while running {}",
+                self.stage,
+                running.join(", ")
+            )],
+        };
+        match &self.report {
+            Report::DoesNotCompile(errors) => regressions.push(format!(
+                "{}: doesn't compile: {}",
+                self.stage,
+                errors.first().map_or("see `cargo build`", String::as_str)
+            )),
+            Report::Tested(results) => regressions.extend(
+                results
+                    .iter()
+                    .filter(|result| {
+                        result.status == Status::Failed
+                            && failure(result, expected) == Failure::Regression
+                    })
+                    .map(|result| {
+                        let reason = result.reason.as_deref().unwrap_or("failed");
+                        let reason = reason.lines().next().unwrap_or(reason);
+                        format!("{}: {} {reason}", self.stage, result.name)
+                    }),
+            ),
+        }
+        regressions
+    }
+
+    /// How long the stage took and how its tests went, like `test 1.2s (3 passed, 2 unimplemented)`
+    pub fn describe(&self, expected: &Expected) -> String {
+        let mut description = format!("{} {:.1?}", self.stage, self.elapsed);
+        if self.cut_short(expected) {
+            description.push_str(match self.ending {
+                Ending::TimedOut(_) => " (stuck, as expected)",
+                _ => " (aborted, as expected)",
+            });
+            return description;
+        }
+        if self.report.results().is_empty() {
+            return description;
+        }
+
+        let failures: Vec<_> = self
+            .report
+            .results()
+            .iter()
+            .filter(|result| result.status == Status::Failed)
+            .map(|result| failure(result, expected))
+            .collect();
+        let count = |kind| failures.iter().filter(|&&failure| failure == kind).count();
+        let mut counts = vec![format!("{} passed", self.report.count(Status::Passed))];
+        for (kind, name) in [
+            (Failure::Unimplemented, "unimplemented"),
+            (Failure::Expected, "expected to fail"),
+            (Failure::SingleCore, "need more cores"),
+            (Failure::Regression, "failed"),
+        ] {
+            if count(kind) > 0 {
+                counts.push(format!("{} {name}", count(kind)));
+            }
+        }
+        description.push_str(&format!(" ({})", counts.join(", ")));
+        description
+    }
+}
+
+/// How every stage went for a part, which stops at the first one that doesn't compile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub part: Part,
+    pub expected: Expected,
+    pub stages: Vec<StageResult>,
+}
+
+impl PartResult {
+    pub fn regressions(&self) -> Vec<String> {
+        self.stages
+            .iter()
+            .flat_map(|stage| stage.regressions(&self.expected))
+            .collect()
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.stages.iter().map(|stage| stage.elapsed).sum()
+    }
+}
+
+impl fmt::Display for PartResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let regressions = self.regressions();
+        let mark = if regressions.is_empty() { '✓' } else { '✗' };
+        let stages: Vec<_> = self
+            .stages
+            .iter()
+            .map(|stage| stage.describe(&self.expected))
+            .collect();
+        writeln!(f, "{mark} {}: {}", self.part.package(), stages.join(", "))?;
+        for regression in regressions {
+            writeln!(f, "    {regression}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Everything that was verified, with where the time went and what broke
+pub struct Summary<'a> {
+    pub results: &'a [PartResult],
+    pub elapsed: Duration,
+}
+
+impl Summary<'_> {
+    /// How many of the slowest parts to show
+    const SLOWEST: usize = 5;
+}
+
+impl fmt::Display for Summary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Verified {} parts in {:.1?}",
+            self.results.len(),
+            self.elapsed
+        )?;
+        for stage in &STAGES {
+            let elapsed: Duration = self
+                .results
+                .iter()
+                .flat_map(|result| &result.stages)
+                .filter(|result| result.stage == stage.name)
+                .map(|result| result.elapsed)
+                .sum();
+            writeln!(f, "  {} {elapsed:.1?}", stage.name)?;
+        }
+
+        let mut slowest: Vec<_> = self.results.iter().collect();
+        slowest.sort_by_key(|result| std::cmp::Reverse(result.elapsed()));
+        let slowest: Vec<_> = slowest
+            .iter()
+            .take(Self::SLOWEST)
+            .map(|result| format!("{} {:.1?}", result.part.package(), result.elapsed()))
+            .collect();
+        writeln!(f, "Slowest: {}", slowest.join(", "))?;
+
+        let broken: Vec<_> = self
+            .results
+            .iter()
+            .filter(|result| !result.regressions().is_empty())
+            .collect();
+        if broken.is_empty() {
+            return writeln!(f, "No regressions");
+        }
+        writeln!(f, "\n{} parts with regressions:", broken.len())?;
+        for result in broken {
+            write!(f, "{result}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The `--features` for `stage` of `part`, if it needs any
+fn features(part: &Part, stage: &Stage) -> Vec<String> {
+    let mut features = stage.features.to_vec();
+    features.extend(part.features(Tier::Advanced));
+    if features.is_empty() {
+        Vec::new()
+    } else {
+        vec!["--features".to_string(), features.join(",")]
+    }
+}
+
+fn run_stage(part: &Part, stage: &Stage) -> StageResult {
+    let start = Instant::now();
+    let mut command = workshop::cargo();
+    command.args(stage.args);
+    for package in part.test_packages() {
+        command.args(["-p", &package]);
+    }
+    command
+        .args(features(part, stage))
+        .args(["--message-format", "short", "--color", "never"])
+        .env("RUST_BACKTRACE", "0");
+    let output = process::run(command, stage.timeout);
+    let elapsed = start.elapsed();
+    let output = match output {
+        Ok(output) => output,
+        Err(error) => {
+            return StageResult {
+                stage: stage.name,
+                elapsed,
+                report: Report::Tested(Vec::new()),
+                ending: Ending::Error(format!("couldn't run cargo: {error}")),
+            }
+        }
+    };
+
+    let report = Report::parse(&output.stdout, &output.stderr);
+    let ending = if output.timed_out {
+        Ending::TimedOut(output.stuck_tests())
+    } else if output.success {
+        Ending::Finished
+    } else if let Some(line) = output
+        .stderr
+        .lines()
+        .find(|line| line.contains("process didn't exit successfully") && line.contains("signal"))
+    {
+        Ending::Aborted(line.trim().to_string())
+    } else if report.count(Status::Failed) == 0 && !matches!(report, Report::DoesNotCompile(_)) {
+        Ending::Error(
+            output
+                .stderr
+                .lines()
+                .find(|line| line.starts_with("error"))
+                .unwrap_or("cargo failed")
+                .to_string(),
+        )
+    } else {
+        Ending::Finished
+    };
+    StageResult {
+        stage: stage.name,
+        elapsed,
+        report,
+        ending,
+    }
+}
+
+pub fn verify(part: &Part, expected: Expected) -> PartResult {
+    let mut stages = Vec::new();
+    for stage in &STAGES {
+        let result = run_stage(part, stage);
+        let compiled = !matches!(result.report, Report::DoesNotCompile(_));
+        stages.push(result);
+        if !compiled {
+            // The later stages wouldn't compile either
+            break;
+        }
+    }
+    PartResult {
+        part: part.clone(),
+        expected,
+        stages,
+    }
+}
+
+/// Verifies every part in `parts`, showing how each went as it goes, and a summary at the end
+pub fn run_all(parts: &[Part]) -> ExitCode {
+    let mut expected = expected_failures();
+    let start = Instant::now();
+    let mut results = Vec::new();
+    for part in parts {
+        let result = verify(part, expected.remove(&part.package()).unwrap_or_default());
+        print!("{result}");
+        results.push(result);
+    }
+
+    let summary = Summary {
+        results: &results,
+        elapsed: start.elapsed(),
+    };
+    print!("\n{summary}");
+    if results.iter().all(|result| result.regressions().is_empty()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+fn stage(stage: &'static str, report: Report) -> StageResult {
+    StageResult {
+        stage,
+        elapsed: Duration::from_millis(1500),
+        report,
+        ending: Ending::Finished,
+    }
+}
+
+#[cfg(test)]
+fn result(name: &str, status: Status, reason: Option<&str>) -> TestResult {
+    TestResult {
+        target: "src/lib.rs".to_string(),
+        name: name.to_string(),
+        status,
+        reason: reason.map(str::to_string),
+    }
+}
+
+#[test]
+fn only_counts_unexpected_failures() {
+    let tested = stage(
+        "solutions",
+        Report::Tested(vec![
+            result("counts", Status::Passed, None),
+            result(
+                "counts_like_the_solution",
+                Status::Failed,
+                Some("Not implemented yet, at part-9/src/lib.rs:12:5"),
+            ),
+            result(
+                "counts_in_parallel",
+                Status::Failed,
+                Some("assertion failed: total == 100\n(at part-9/src/main.rs:30:5)"),
+            ),
+            result("is_aligned", Status::Failed, Some("assertion failed")),
+            result("is_faster", Status::Failed, Some(SINGLE_CORE)),
+        ]),
+    );
+    let expected = Expected {
+        tests: vec!["is_aligned".to_string()],
+        ..Expected::default()
+    };
+    assert_eq!(
+        tested.regressions(&expected),
+        ["solutions: counts_in_parallel assertion failed: total == 100"]
+    );
+    assert_eq!(
+        tested.describe(&expected),
+        "solutions 1.5s (1 passed, 1 unimplemented, 1 expected to fail, 1 need more cores, 1 failed)"
+    );
+
+    let broken = stage(
+        "build",
+        Report::DoesNotCompile(vec![
+            "common/src/lib.rs:3:5: error[E0425]: cannot find value `x`".to_string(),
+        ]),
+    );
+    assert_eq!(
+        broken.regressions(&expected),
+        ["build: doesn't compile: common/src/lib.rs:3:5: error[E0425]: cannot find value `x`"]
+    );
+    assert_eq!(broken.describe(&expected), "build 1.5s");
+}
+
+#[test]
+fn tests_which_get_stuck_are_regressions_unless_expected() {
+    let stuck = StageResult {
+        ending: Ending::TimedOut(vec!["replays_aba".to_string()]),
+        ..stage(
+            "test",
+            Report::Tested(vec![result("replays_aba", Status::Failed, None)]),
+        )
+    };
+    assert_eq!(
+        stuck.regressions(&Expected::default()),
+        [
+            "test: timed out after 180s, while running replays_aba",
+            "test: replays_aba failed"
+        ]
+    );
+
+    let hangs = Expected {
+        hangs: true,
+        ..Expected::default()
+    };
+    assert!(stuck.regressions(&hangs).is_empty());
+    assert_eq!(stuck.describe(&hangs), "test 1.5s (stuck, as expected)");
+}
+
+#[test]
+fn reads_the_expected_failures() {
+    let expected = expected_failures();
+    assert!(expected["part-87"]
+        .tests
+        .contains(&"counts_every_view".to_string()));
+    let parts = workshop::parts::discover(&workshop::parts::workspace()).unwrap();
+    for package in expected.keys() {
+        assert!(
+            parts.iter().any(|part| &part.package() == package),
+            "There's no {package}"
+        );
+    }
+}
+
+#[test]
+fn summarizes_every_part() {
+    let parts = workshop::parts::discover(&workshop::parts::workspace()).unwrap();
+    let results: Vec<_> = parts[..2]
+        .iter()
+        .map(|part| PartResult {
+            part: part.clone(),
+            expected: Expected::default(),
+            stages: vec![stage("build", Report::Tested(Vec::new()))],
+        })
+        .collect();
+    let summary = Summary {
+        results: &results,
+        elapsed: Duration::from_secs(3),
+    }
+    .to_string();
+    assert_eq!(
+        summary,
+        "\
+Verified 2 parts in 3.0s
+  build 3.0s
+  test 0.0ns
+  solutions 0.0ns
+Slowest: part-1 1.5s, part-2 1.5s
+No regressions
+"
+    );
+}
+
+#[test]
+fn builds_every_tier_of_exercises() {
+    let parts = workshop::parts::discover(&workshop::parts::workspace()).unwrap();
+    let part = |number| parts.iter().find(|part| part.number == number).unwrap();
+    assert_eq!(features(part(6), &STAGES[1]), Vec::<String>::new());
+    assert_eq!(features(part(1), &STAGES[1]), ["--features", "bonus"]);
+    assert_eq!(
+        features(part(17), &STAGES[2]),
+        ["--features", "solutions,advanced"]
+    );
+}